@@ -36,6 +36,7 @@ use std::sync::Arc;
 
 const RAW_TRANSFER_TOKEN_ENV: &str = "SETU_RAW_TRANSFER_API_TOKEN";
 const RAW_EVENT_TOKEN_ENV: &str = "SETU_RAW_EVENT_API_TOKEN";
+const ADMIN_TOKEN_ENV: &str = "SETU_ADMIN_API_TOKEN";
 const RAW_TRANSFER_TOKEN_HEADER: &str = "x-setu-admin-token";
 
 fn raw_admin_auth_error(headers: &HeaderMap, token_env: &str, surface: &str) -> Option<String> {
@@ -69,6 +70,10 @@ fn raw_event_auth_error(headers: &HeaderMap) -> Option<String> {
     raw_admin_auth_error(headers, RAW_EVENT_TOKEN_ENV, "Raw event")
 }
 
+fn admin_auth_error(headers: &HeaderMap) -> Option<String> {
+    raw_admin_auth_error(headers, ADMIN_TOKEN_ENV, "Admin")
+}
+
 fn infra_admission_error(detail: impl AsRef<str>) -> String {
     stable_error("INFRA_ADMISSION", detail)
 }
@@ -126,8 +131,8 @@ fn raw_event_admission_error(
 #[cfg(test)]
 mod tests {
     use super::{
-        raw_event_admission_error, raw_transfer_auth_error, RAW_EVENT_TOKEN_ENV,
-        RAW_TRANSFER_TOKEN_ENV, RAW_TRANSFER_TOKEN_HEADER,
+        admin_auth_error, raw_event_admission_error, raw_transfer_auth_error, ADMIN_TOKEN_ENV,
+        RAW_EVENT_TOKEN_ENV, RAW_TRANSFER_TOKEN_ENV, RAW_TRANSFER_TOKEN_HEADER,
     };
     use crate::types::SubmitEventRequest;
     use axum::http::{HeaderMap, HeaderValue, StatusCode};
@@ -185,6 +190,23 @@ mod tests {
         SubmitEventRequest { event }
     }
 
+    #[test]
+    fn admin_auth_is_gated_by_its_own_token() {
+        let _guard = ENV_LOCK.lock().expect("env test lock poisoned");
+        std::env::remove_var(ADMIN_TOKEN_ENV);
+        std::env::set_var(RAW_TRANSFER_TOKEN_ENV, "expected-token");
+        let mut headers = HeaderMap::new();
+        headers.insert(RAW_TRANSFER_TOKEN_HEADER, HeaderValue::from_static("expected-token"));
+
+        let error = admin_auth_error(&headers).expect("unset admin token must reject");
+        assert!(error.contains("Admin API disabled"));
+
+        std::env::set_var(ADMIN_TOKEN_ENV, "expected-token");
+        assert!(admin_auth_error(&headers).is_none());
+        std::env::remove_var(ADMIN_TOKEN_ENV);
+        std::env::remove_var(RAW_TRANSFER_TOKEN_ENV);
+    }
+
     fn raw_event_headers() -> HeaderMap {
         let mut headers = HeaderMap::new();
         headers.insert(RAW_TRANSFER_TOKEN_HEADER, HeaderValue::from_static("expected-token"));
@@ -286,8 +308,12 @@ pub trait ValidatorService: Send + Sync {
     /// Submit batch of transfers (optimized: 2 locks instead of 5-6N)
     fn submit_transfers_batch(&self, request: SubmitTransfersBatchRequest) -> impl std::future::Future<Output = SubmitTransfersBatchResponse> + Send;
     
-    /// Get transfer status
-    fn get_transfer_status(&self, transfer_id: &str) -> GetTransferStatusResponse;
+    /// Get transfer status (falls back to persisted events when the
+    /// in-memory tracker no longer knows the transfer, e.g. after restart)
+    fn get_transfer_status(&self, transfer_id: &str) -> impl std::future::Future<Output = GetTransferStatusResponse> + Send;
+    
+    /// Dry-run coin selection and fee computation for a transfer
+    fn estimate_transfer(&self, request: EstimateTransferRequest) -> EstimateTransferResponse;
     
     /// Submit event
     fn submit_event(&self, request: SubmitEventRequest) -> impl std::future::Future<Output = SubmitEventResponse> + Send;
@@ -299,12 +325,126 @@ pub trait ValidatorService: Send + Sync {
     /// Returns `None` if the event is not known to this validator.
     fn get_event_by_id(&self, event_id: &str) -> Option<GetEventResponse>;
     
+    /// Probe storage and consensus for the health endpoint.
+    fn health_report(&self) -> impl std::future::Future<Output = HealthReport> + Send;
+    
+    /// Render node metrics in the Prometheus text exposition format.
+    fn metrics_text(&self) -> String;
+    
+    /// Get the live consensus status.
+    /// Returns `None` if this validator runs without a consensus engine.
+    fn get_consensus_status(&self) -> impl std::future::Future<Output = Option<GetConsensusStatusResponse>> + Send;
+    
+    /// Get the consensus configuration the engine is running with.
+    /// Returns `None` if this validator runs without a consensus engine.
+    fn get_consensus_config(&self) -> impl std::future::Future<Output = Option<GetConsensusConfigResponse>> + Send;
+    
+    /// Get how far the local anchor chain trails the network.
+    /// Returns `None` if this validator runs without a consensus engine.
+    fn get_sync_progress(&self) -> impl std::future::Future<Output = Option<GetSyncProgressResponse>> + Send;
+    
+    /// Get DAG size and fan-in/fan-out histograms.
+    /// Returns `None` if this validator runs without a consensus engine.
+    fn get_dag_stats(&self) -> impl std::future::Future<Output = Option<GetDagStatsResponse>> + Send;
+    
+    /// Get pending events that no anchor has folded for several anchors.
+    /// Returns `None` if this validator runs without a consensus engine.
+    fn get_orphaned_events(&self) -> impl std::future::Future<Output = Option<GetOrphanedEventsResponse>> + Send;
+    
+    /// Get the ancestor subgraph of an event up to `max_depth` parent hops.
+    /// Returns `None` if the event is unknown.
+    fn get_causal_subgraph(
+        &self,
+        event_id: &str,
+        max_depth: usize,
+    ) -> impl std::future::Future<Output = Option<GetCausalSubgraphResponse>> + Send;
+    
+    /// Render the active DAG as Graphviz DOT, at most `max_events` events.
+    /// Returns `None` if this validator runs without a consensus engine.
+    fn export_dag_dot(&self, max_events: usize) -> impl std::future::Future<Output = Option<String>> + Send;
+    
+    /// List stored events whose timestamp (ms) lies in `[from_ts, to_ts]`,
+    /// oldest first, at most `limit` of them. With `after`, resume past the
+    /// event `(from_ts, after)` of a previous page.
+    /// Returns `None` if consensus (and so the event store) is not enabled.
+    fn get_events_in_range(
+        &self,
+        from_ts: u64,
+        to_ts: u64,
+        after: Option<String>,
+        limit: usize,
+    ) -> impl std::future::Future<Output = Option<GetEventsInRangeResponse>> + Send;
+    
+    /// Get display metadata (symbol, decimals) for a coin type.
+    /// Returns `None` if the coin type was never registered.
+    fn get_token_metadata(&self, coin_type: &str) -> Option<GetTokenMetadataResponse>;
+    
+    /// Get a user's interaction summary within a subnet.
+    /// Returns `None` if the user has no recorded activity there.
+    fn get_subnet_activity(&self, address: &str, subnet_id: &str) -> Option<GetSubnetActivityResponse>;
+    
+    /// Get an anchor with its full Merkle root breakdown.
+    /// Returns `None` if the anchor is not stored locally.
+    fn get_anchor_detail(
+        &self,
+        anchor_id: &str,
+    ) -> impl std::future::Future<Output = Option<GetAnchorDetailResponse>> + Send;
+    
+    /// Get the newest finalized anchor in compact form.
+    /// Returns `None` without consensus or before the first finalization.
+    fn get_explorer_tip(&self) -> Option<GetExplorerTipResponse>;
+    
+    /// Get the vote certificate of the CF that finalized an anchor.
+    /// Returns `None` without consensus or if no certificate has been
+    /// persisted for the anchor.
+    fn get_vote_certificate(
+        &self,
+        anchor_id: &str,
+    ) -> impl std::future::Future<Output = Option<GetVoteCertificateResponse>> + Send;
+    
+    /// Prove a subnet's state root is included in an anchor's global state root.
+    /// Returns `None` if the anchor is unknown or does not commit that subnet.
+    fn get_subnet_inclusion_proof(
+        &self,
+        anchor_id: &str,
+        subnet_id: &str,
+    ) -> impl std::future::Future<Output = Option<GetSubnetInclusionProofResponse>> + Send;
+    
+    /// List registered subnets with their configuration.
+    fn get_explorer_subnets(&self) -> GetExplorerSubnetsResponse;
+    
+    /// Get committed state stats for a subnet.
+    /// Returns `None` if the subnet is not registered.
+    fn get_subnet_stats(&self, subnet_id: &str) -> Option<GetSubnetStatsResponse>;
+    
+    /// Get committed event count and rolling per-minute rate for a subnet.
+    /// Returns `None` if the subnet is not registered.
+    fn get_subnet_throughput(&self, subnet_id: &str) -> Option<GetSubnetThroughputResponse>;
+    
+    /// Get balances by coin type for several addresses from one state snapshot.
+    fn get_account_balances(&self, addresses: &[String]) -> GetAccountBalancesResponse;
+    
+    /// Get the current status and finalizing anchor of each event id.
+    fn get_event_statuses(
+        &self,
+        event_ids: &[String],
+    ) -> impl std::future::Future<Output = GetEventStatusesResponse> + Send;
+    
+    /// Rebuild the owner/coin-type index from the Merkle trees.
+    fn rebuild_coin_index(&self) -> RebuildCoinIndexResponse;
+    
+    /// Dump coins and profile of an address with proofs (admin debugging).
+    fn get_address_state(&self, address: &str) -> GetAddressStateResponse;
+    
     /// Get balance (state query)
     fn get_balance(&self, account: &str) -> GetBalanceResponse;
     
     /// Get object (state query)
     fn get_object(&self, key: &str) -> GetObjectResponse;
 
+    /// Get a committed object from the default subnet with its Merkle proof
+    fn get_object_with_proof(&self, object_id: &setu_types::ObjectId) -> Option<GetObjectWithProofResponse>;
+
     /// Submit a Move function call
     fn submit_move_call(&self, request: MoveCallRequest) -> impl std::future::Future<Output = MoveCallResponse> + Send;
 
@@ -444,6 +584,10 @@ pub async fn http_get_subnets<S: ValidatorService>(
 // ============================================
 
 /// Submit a transfer
+///
+/// With `"wait_for_finalization": true` the response is held until the
+/// containing CF finalizes (or a timeout), so a balance query issued after
+/// it returns observes the transfer.
 pub async fn http_submit_transfer<S: ValidatorService>(
     State(service): State<Arc<S>>,
     headers: HeaderMap,
@@ -457,6 +601,7 @@ pub async fn http_submit_transfer<S: ValidatorService>(
             event_id: None,
             solver_id: None,
             processing_steps: vec![],
+            correlation_id: None,
         });
     }
 
@@ -500,12 +645,23 @@ pub async fn http_submit_transfers_batch<S: ValidatorService>(
     Json(service.submit_transfers_batch(request).await)
 }
 
+/// Estimate a transfer without submitting it
+///
+/// Read-only: runs coin selection and fee computation against current state
+/// without reserving coins or creating events, so no raw-transfer token is needed.
+pub async fn http_estimate_transfer<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    Json(request): Json<EstimateTransferRequest>,
+) -> Json<EstimateTransferResponse> {
+    Json(service.estimate_transfer(request))
+}
+
 /// Get transfer status
 pub async fn http_get_transfer_status<S: ValidatorService>(
     State(service): State<Arc<S>>,
     Json(request): Json<GetTransferStatusRequest>,
 ) -> Json<GetTransferStatusResponse> {
-    Json(service.get_transfer_status(&request.transfer_id))
+    Json(service.get_transfer_status(&request.transfer_id).await)
 }
 
 // ============================================
@@ -526,6 +682,7 @@ pub async fn http_submit_event<S: ValidatorService>(
                 message,
                 event_id: None,
                 vlc_time: None,
+                correlation_id: None,
             }),
         ));
     }
@@ -578,6 +735,437 @@ pub async fn http_get_event_by_id<S: ValidatorService>(
     }
 }
 
+// ============================================
+// Consensus Status
+// ============================================
+
+/// Get the current round, leader and quorum of this validator
+pub async fn http_get_consensus_status<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+) -> Result<Json<GetConsensusStatusResponse>, (StatusCode, Json<serde_json::Value>)> {
+    match service.get_consensus_status().await {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({
+                "error": "consensus not enabled",
+            })),
+        )),
+    }
+}
+
+/// Get the effective consensus configuration of this validator
+pub async fn http_get_consensus_config<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+) -> Result<Json<GetConsensusConfigResponse>, (StatusCode, Json<serde_json::Value>)> {
+    match service.get_consensus_config().await {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({
+                "error": "consensus not enabled",
+            })),
+        )),
+    }
+}
+
+/// Get the catch-up progress of this validator against its peers
+pub async fn http_get_sync_progress<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+) -> Result<Json<GetSyncProgressResponse>, (StatusCode, Json<serde_json::Value>)> {
+    match service.get_sync_progress().await {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({
+                "error": "consensus not enabled",
+            })),
+        )),
+    }
+}
+
+/// Get the DAG shape (size, depth, fan-in/fan-out histograms) of this validator
+pub async fn http_get_dag_stats<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+) -> Result<Json<GetDagStatsResponse>, (StatusCode, Json<serde_json::Value>)> {
+    match service.get_dag_stats().await {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({
+                "error": "consensus not enabled",
+            })),
+        )),
+    }
+}
+
+/// List pending events that have gone unfolded for several anchors
+pub async fn http_get_orphaned_events<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+) -> Result<Json<GetOrphanedEventsResponse>, (StatusCode, Json<serde_json::Value>)> {
+    match service.get_orphaned_events().await {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({
+                "error": "consensus not enabled",
+            })),
+        )),
+    }
+}
+
+/// Query parameters for the causal subgraph lookup.
+#[derive(Debug, Deserialize, Default)]
+pub struct CausalSubgraphQuery {
+    /// Maximum parent hops to walk. Defaults to [`DEFAULT_CAUSAL_DEPTH`] and is
+    /// capped at [`MAX_CAUSAL_DEPTH`].
+    pub max_depth: Option<usize>,
+}
+
+/// Default number of parent hops returned by the causal subgraph lookup.
+pub const DEFAULT_CAUSAL_DEPTH: usize = 16;
+
+/// Upper bound on `max_depth`; keeps a single request from walking the whole history.
+pub const MAX_CAUSAL_DEPTH: usize = 64;
+
+/// Get the causal ancestor subgraph (nodes + edges) of an event
+pub async fn http_get_causal_subgraph<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    axum::extract::Path(event_id): axum::extract::Path<String>,
+    axum::extract::Query(q): axum::extract::Query<CausalSubgraphQuery>,
+) -> Result<Json<GetCausalSubgraphResponse>, (StatusCode, Json<serde_json::Value>)> {
+    let max_depth = q.max_depth.unwrap_or(DEFAULT_CAUSAL_DEPTH).min(MAX_CAUSAL_DEPTH);
+    match service.get_causal_subgraph(&event_id, max_depth).await {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "event not found",
+                "event_id": event_id,
+            })),
+        )),
+    }
+}
+
+/// Query parameters for the DAG export.
+#[derive(Debug, Deserialize, Default)]
+pub struct DagExportQuery {
+    /// Output format; only `dot` (the default) is supported.
+    pub format: Option<String>,
+    /// Maximum events exported, deepest first. Defaults to
+    /// [`DEFAULT_DAG_EXPORT_EVENTS`] and is capped at [`MAX_DAG_EXPORT_EVENTS`].
+    pub max_events: Option<usize>,
+}
+
+/// Default number of events in a DAG export.
+pub const DEFAULT_DAG_EXPORT_EVENTS: usize = 500;
+
+/// Upper bound on `max_events` for a DAG export.
+pub const MAX_DAG_EXPORT_EVENTS: usize = 5000;
+
+/// Export the active DAG as Graphviz DOT for offline rendering
+pub async fn http_export_dag<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    axum::extract::Query(q): axum::extract::Query<DagExportQuery>,
+) -> Result<([(axum::http::header::HeaderName, &'static str); 1], String), (StatusCode, Json<serde_json::Value>)> {
+    let format = q.format.as_deref().unwrap_or("dot");
+    if format != "dot" {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "unsupported export format",
+                "format": format,
+                "supported": ["dot"],
+            })),
+        ));
+    }
+
+    let max_events = q.max_events.unwrap_or(DEFAULT_DAG_EXPORT_EVENTS).min(MAX_DAG_EXPORT_EVENTS);
+    match service.export_dag_dot(max_events).await {
+        Some(dot) => Ok(([(axum::http::header::CONTENT_TYPE, "text/vnd.graphviz")], dot)),
+        None => Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({
+                "error": "consensus not enabled",
+            })),
+        )),
+    }
+}
+
+/// Query parameters for the events-by-time-range listing.
+#[derive(Debug, Deserialize, Default)]
+pub struct EventsInRangeQuery {
+    /// Inclusive lower bound on the event timestamp (ms). Defaults to 0.
+    pub from: Option<u64>,
+    /// Inclusive upper bound on the event timestamp (ms). Defaults to no bound.
+    pub to: Option<u64>,
+    /// Maximum events returned. Defaults to [`DEFAULT_EVENTS_RANGE_LIMIT`] and
+    /// is capped at [`MAX_EVENTS_RANGE_LIMIT`].
+    pub limit: Option<usize>,
+    /// Event id cursor: skip events at timestamp `from` up to and including
+    /// this id. Pass the `next_from`/`next_after` of the previous page.
+    pub after: Option<String>,
+}
+
+/// Default number of events returned by the time-range listing.
+pub const DEFAULT_EVENTS_RANGE_LIMIT: usize = 100;
+
+/// Upper bound on `limit` for the time-range listing.
+pub const MAX_EVENTS_RANGE_LIMIT: usize = 1000;
+
+/// List stored events within a timestamp window
+pub async fn http_get_events_in_range<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    axum::extract::Query(q): axum::extract::Query<EventsInRangeQuery>,
+) -> Result<Json<GetEventsInRangeResponse>, (StatusCode, Json<serde_json::Value>)> {
+    let from = q.from.unwrap_or(0);
+    let to = q.to.unwrap_or(u64::MAX);
+    if from > to {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "`from` must not be after `to`",
+                "from": from,
+                "to": to,
+            })),
+        ));
+    }
+
+    let limit = q.limit.unwrap_or(DEFAULT_EVENTS_RANGE_LIMIT).min(MAX_EVENTS_RANGE_LIMIT);
+    match service.get_events_in_range(from, to, q.after, limit).await {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({
+                "error": "consensus not enabled",
+            })),
+        )),
+    }
+}
+
+/// Get display metadata for a coin type
+pub async fn http_get_token_metadata<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    axum::extract::Path(coin_type): axum::extract::Path<String>,
+) -> Result<Json<GetTokenMetadataResponse>, (StatusCode, Json<serde_json::Value>)> {
+    match service.get_token_metadata(&coin_type) {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "coin type not registered",
+                "coin_type": coin_type,
+            })),
+        )),
+    }
+}
+
+/// Get a user's interaction summary within a subnet
+pub async fn http_get_subnet_activity<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    axum::extract::Path((address, subnet_id)): axum::extract::Path<(String, String)>,
+) -> Result<Json<GetSubnetActivityResponse>, (StatusCode, Json<serde_json::Value>)> {
+    match service.get_subnet_activity(&address, &subnet_id) {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "no activity recorded",
+                "address": address,
+                "subnet_id": subnet_id,
+            })),
+        )),
+    }
+}
+
+/// Get an anchor with its Merkle roots and previous chain root
+pub async fn http_get_anchor_detail<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    axum::extract::Path(anchor_id): axum::extract::Path<String>,
+) -> Result<Json<GetAnchorDetailResponse>, (StatusCode, Json<serde_json::Value>)> {
+    match service.get_anchor_detail(&anchor_id).await {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "anchor not found",
+                "anchor_id": anchor_id,
+            })),
+        )),
+    }
+}
+
+/// Get the newest finalized anchor for light clients to pin to
+pub async fn http_get_explorer_tip<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+) -> Result<Json<GetExplorerTipResponse>, (StatusCode, Json<serde_json::Value>)> {
+    match service.get_explorer_tip() {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "no finalized anchor",
+            })),
+        )),
+    }
+}
+
+/// Get the vote certificate (approvers and voting power) behind an anchor
+pub async fn http_get_vote_certificate<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    axum::extract::Path(anchor_id): axum::extract::Path<String>,
+) -> Result<Json<GetVoteCertificateResponse>, (StatusCode, Json<serde_json::Value>)> {
+    match service.get_vote_certificate(&anchor_id).await {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "no vote certificate for anchor",
+                "anchor_id": anchor_id,
+            })),
+        )),
+    }
+}
+
+/// Get an inclusion proof of a subnet root within an anchor's global root
+pub async fn http_get_subnet_inclusion_proof<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    axum::extract::Path((anchor_id, subnet_id)): axum::extract::Path<(String, String)>,
+) -> Result<Json<GetSubnetInclusionProofResponse>, (StatusCode, Json<serde_json::Value>)> {
+    match service.get_subnet_inclusion_proof(&anchor_id, &subnet_id).await {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "anchor or subnet root not found",
+                "anchor_id": anchor_id,
+                "subnet_id": subnet_id,
+            })),
+        )),
+    }
+}
+
+/// List registered subnets
+pub async fn http_get_explorer_subnets<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+) -> Json<GetExplorerSubnetsResponse> {
+    Json(service.get_explorer_subnets())
+}
+
+/// Get committed state stats for a subnet
+pub async fn http_get_subnet_stats<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    axum::extract::Path(subnet_id): axum::extract::Path<String>,
+) -> Result<Json<GetSubnetStatsResponse>, (StatusCode, Json<serde_json::Value>)> {
+    match service.get_subnet_stats(&subnet_id) {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "subnet not registered",
+                "subnet_id": subnet_id,
+            })),
+        )),
+    }
+}
+
+/// Get committed event throughput for a subnet
+pub async fn http_get_subnet_throughput<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    axum::extract::Path(subnet_id): axum::extract::Path<String>,
+) -> Result<Json<GetSubnetThroughputResponse>, (StatusCode, Json<serde_json::Value>)> {
+    match service.get_subnet_throughput(&subnet_id) {
+        Some(resp) => Ok(Json(resp)),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "subnet not registered",
+                "subnet_id": subnet_id,
+            })),
+        )),
+    }
+}
+
+/// Upper bound on addresses per bulk balance request.
+pub const MAX_BALANCE_ADDRESSES: usize = 100;
+
+/// Get balances by coin type for a list of addresses
+pub async fn http_get_account_balances<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    Json(request): Json<GetAccountBalancesRequest>,
+) -> Result<Json<GetAccountBalancesResponse>, (StatusCode, Json<serde_json::Value>)> {
+    if request.addresses.len() > MAX_BALANCE_ADDRESSES {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "too many addresses",
+                "max": MAX_BALANCE_ADDRESSES,
+                "requested": request.addresses.len(),
+            })),
+        ));
+    }
+    Ok(Json(service.get_account_balances(&request.addresses)))
+}
+
+/// Upper bound on event ids per bulk status request.
+pub const MAX_STATUS_EVENT_IDS: usize = 100;
+
+/// Get the status and finalizing anchor of a list of events
+pub async fn http_get_event_statuses<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    Json(request): Json<GetEventStatusesRequest>,
+) -> Result<Json<GetEventStatusesResponse>, (StatusCode, Json<serde_json::Value>)> {
+    if request.event_ids.len() > MAX_STATUS_EVENT_IDS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "too many event ids",
+                "max": MAX_STATUS_EVENT_IDS,
+                "requested": request.event_ids.len(),
+            })),
+        ));
+    }
+    Ok(Json(service.get_event_statuses(&request.event_ids).await))
+}
+
+/// Rebuild the coin-type index (admin)
+///
+/// Recovers balance and coin lookups after index drift, e.g. a manual DB edit
+/// or partial import. Requires `X-Setu-Admin-Token` matching
+/// `SETU_ADMIN_API_TOKEN`; disabled when that variable is unset.
+pub async fn http_admin_rebuild_coin_index<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+) -> Result<Json<RebuildCoinIndexResponse>, (StatusCode, Json<serde_json::Value>)> {
+    if let Some(message) = admin_auth_error(&headers) {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            Json(serde_json::json!({ "error": message })),
+        ));
+    }
+
+    Ok(Json(service.rebuild_coin_index()))
+}
+
+/// Dump everything committed state holds on an address (admin)
+///
+/// Coins, profile, last modifying events and Merkle proofs, for debugging
+/// balance or ownership reports. Same auth as the other admin endpoints.
+pub async fn http_admin_get_address_state<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+    axum::extract::Path(address): axum::extract::Path<String>,
+    headers: HeaderMap,
+) -> Result<Json<GetAddressStateResponse>, (StatusCode, Json<serde_json::Value>)> {
+    if let Some(message) = admin_auth_error(&headers) {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            Json(serde_json::json!({ "error": message })),
+        ));
+    }
+
+    Ok(Json(service.get_address_state(&address)))
+}
+
 // ============================================
 // Heartbeat & Health
 // ============================================
@@ -592,24 +1180,68 @@ pub async fn http_heartbeat<S: ValidatorService>(
 }
 
 /// Health check endpoint
+///
+/// Returns `200` when every critical subsystem is healthy and `503` otherwise,
+/// with a per-component breakdown either way (usable as a readiness probe).
 pub async fn http_health<S: ValidatorService>(
     State(service): State<Arc<S>>,
-) -> Json<serde_json::Value> {
+) -> (StatusCode, Json<serde_json::Value>) {
     let now = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
         .as_secs();
+    let report = service.health_report().await;
+    let ready = report.is_ready();
+    let status = if ready { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
     
-    Json(serde_json::json!({
-        "status": "healthy",
+    (status, Json(serde_json::json!({
+        "status": if ready { "healthy" } else { "unhealthy" },
+        "ready": ready,
         "validator_id": service.validator_id(),
         "uptime_seconds": now - service.start_time(),
         "solver_count": service.solver_count(),
         "validator_count": service.validator_count(),
         "dag_events_count": service.dag_events_count(),
+        "components": {
+            "storage": report.storage,
+            "consensus": report.consensus,
+            "catch_up": report.catch_up,
+        },
+        "last_anchor_age_ms": report.last_anchor_age_ms,
+        "sync_lag": report.sync_lag,
+    })))
+}
+
+/// Liveness endpoint
+///
+/// Always `200` while the HTTP server is serving. Container healthchecks use
+/// this so a node waiting on peers or catching up is not treated as dead;
+/// `/api/v1/health` remains the readiness probe.
+pub async fn http_live<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+) -> Json<serde_json::Value> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    Json(serde_json::json!({
+        "status": "alive",
+        "validator_id": service.validator_id(),
+        "uptime_seconds": now - service.start_time(),
     }))
 }
 
+/// Prometheus metrics (text exposition format)
+pub async fn http_metrics<S: ValidatorService>(
+    State(service): State<Arc<S>>,
+) -> ([(axum::http::header::HeaderName, &'static str); 1], String) {
+    (
+        [(axum::http::header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        service.metrics_text(),
+    )
+}
+
 // ============================================
 // State Query Handlers (Scheme B)
 // ============================================
@@ -622,19 +1254,53 @@ pub async fn http_get_balance<S: ValidatorService>(
     Json(service.get_balance(&account))
 }
 
+/// Query parameters for the object lookup.
+#[derive(Debug, Deserialize, Default)]
+pub struct GetObjectQuery {
+    /// Return the raw object bytes with a Merkle proof. The plain key lookup
+    /// is no longer supported, so this must be `true`.
+    pub with_proof: Option<bool>,
+}
+
 /// Query object by key
+///
+/// With `with_proof=true`, `key` is a hex object id and the response is a
+/// [`GetObjectWithProofResponse`] read from committed state.
 pub async fn http_get_object<S: ValidatorService>(
-    State(_service): State<Arc<S>>,
+    State(service): State<Arc<S>>,
     axum::extract::Path(key): axum::extract::Path<String>,
-) -> Result<Json<GetObjectResponse>, (StatusCode, Json<serde_json::Value>)> {
-    Err((
-        StatusCode::GONE,
-        Json(serde_json::json!({
-            "key": key,
-            "supported": false,
-            "error": stable_error(ERROR_CONSENSUS_STORAGE, "unsupported raw object query"),
-        })),
-    ))
+    axum::extract::Query(q): axum::extract::Query<GetObjectQuery>,
+) -> Result<Json<GetObjectWithProofResponse>, (StatusCode, Json<serde_json::Value>)> {
+    if !q.with_proof.unwrap_or(false) {
+        return Err((
+            StatusCode::GONE,
+            Json(serde_json::json!({
+                "key": key,
+                "supported": false,
+                "error": stable_error(ERROR_CONSENSUS_STORAGE, "unsupported raw object query"),
+            })),
+        ));
+    }
+
+    let object_id = setu_types::ObjectId::from_hex(&key).map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "key": key,
+                "error": stable_error(ERROR_PREPARE_INPUT, format!("Invalid object ID hex: {}", key)),
+            })),
+        )
+    })?;
+
+    service.get_object_with_proof(&object_id).map(Json).ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "key": key,
+                "error": "object not found",
+            })),
+        )
+    })
 }
 
 // ============================================
@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 use setu_types::event::{DynamicFieldAccess, Event};
+use std::collections::BTreeMap;
+use setu_types::{Anchor, AnchorMerkleRoots, VoteCertificate};
 
 // ============================================
 // Event Submission
@@ -27,6 +29,59 @@ pub struct SubmitEventResponse {
     pub event_id: Option<String>,
     /// VLC time assigned
     pub vlc_time: Option<u64>,
+    /// Id carried by every log line of this submission
+    #[serde(default)]
+    pub correlation_id: Option<String>,
+}
+
+// ============================================
+// Transfer estimate (POST /api/v1/transfer/estimate)
+// ============================================
+
+/// Dry-run request; same fields as a transfer submission
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimateTransferRequest {
+    pub from: String,
+    pub to: String,
+    pub amount: u64,
+    #[serde(default)]
+    pub subnet_id: Option<String>,
+}
+
+/// A coin the transfer would consume
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimatedCoin {
+    /// Hex object id
+    pub object_id: String,
+    pub balance: u64,
+    pub version: u64,
+}
+
+/// Why an estimated transfer would fail
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum EstimateFailure {
+    InsufficientBalance { required: u64, available: u64 },
+    /// Every usable coin is held by an in-flight transfer; retry shortly
+    AllCoinsReserved { coin_count: usize },
+    NoCoins,
+    InvalidRequest { message: String },
+}
+
+/// Result of a transfer dry run. Nothing is reserved or submitted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimateTransferResponse {
+    pub feasible: bool,
+    /// Coins that would be spent; the first is the spent (or merge target) coin
+    pub selected_coins: Vec<EstimatedCoin>,
+    pub total_fee: u64,
+    /// Part of `total_fee` that is burned
+    #[serde(default)]
+    pub burn: u64,
+    /// Sender balance in the coin namespace after the amount and fee
+    pub resulting_balance: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure: Option<EstimateFailure>,
 }
 
 // ============================================
@@ -55,6 +110,27 @@ pub struct GetObjectResponse {
     pub exists: bool,
 }
 
+/// A committed object with its Merkle proof
+/// (`GET /api/v1/state/object/:object_id?with_proof=true`, hex-encoded).
+///
+/// Verify by hashing the leaf for `(leaf_key, value)` up through `siblings`
+/// and comparing with `state_root`, the root of `subnet_id`'s SMT when the
+/// object was read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetObjectWithProofResponse {
+    pub object_id: String,
+    /// Full 32-byte hex subnet id
+    pub subnet_id: String,
+    /// Raw object bytes as stored in the subnet SMT
+    pub value: String,
+    pub state_root: String,
+    /// Global root read from the same snapshot
+    pub global_state_root: String,
+    pub leaf_key: String,
+    /// Sibling hashes from leaf to root
+    pub siblings: Vec<String>,
+}
+
 // ============================================
 // Move VM Types (Phase 4)
 // ============================================
@@ -421,6 +497,10 @@ pub struct EventMetadata {
     pub timestamp: u64,
     pub vlc_time: u64,
     pub parent_count: usize,
+    /// Correlation id of the submission that created the event, if this
+    /// validator received it (not part of the persisted event)
+    #[serde(default)]
+    pub correlation_id: Option<String>,
 }
 
 /// Response for `GET /api/v1/event/:id`.
@@ -437,6 +517,654 @@ pub struct GetEventResponse {
     pub metadata: EventMetadata,
 }
 
+// ============================================
+// Consensus status (GET /api/v1/consensus/status)
+// ============================================
+
+/// Live consensus position of the serving validator.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetConsensusStatusResponse {
+    /// Current consensus round
+    pub round: u64,
+    /// Valid proposer for `round`
+    pub current_leader: Option<String>,
+    /// Whether the serving validator is the proposer for `round`
+    pub is_leader: bool,
+    /// Number of validators in the active set
+    pub validator_count: usize,
+    /// Votes required to finalize a CF
+    pub quorum: usize,
+    /// Anchor ID of the most recently finalized CF
+    pub last_finalized_anchor: Option<String>,
+}
+
+// ============================================
+// Consensus config (GET /api/v1/consensus/config)
+// ============================================
+
+/// Effective consensus configuration of the serving validator.
+///
+/// The configured values are flattened into the top level; the active set
+/// size and quorum are live, since validators can join or leave after startup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetConsensusConfigResponse {
+    /// Configuration the consensus engine is running with
+    #[serde(flatten)]
+    pub config: setu_types::ConsensusConfig,
+    /// Number of validators in the active set
+    pub active_validator_count: usize,
+    /// Votes required to finalize a CF
+    pub quorum: usize,
+}
+
+// ============================================
+// Sync progress (GET /api/v1/consensus/sync)
+// ============================================
+
+/// How far the serving validator's anchor chain trails its peers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetSyncProgressResponse {
+    /// Depth of the newest locally stored anchor
+    pub local_max_anchor_depth: u64,
+    /// Highest anchor depth reported by any peer (or local, if higher)
+    pub network_max_anchor_depth: u64,
+    /// Anchors still to catch up on
+    pub lag: u64,
+    /// Whether `lag` is within the readiness threshold
+    pub caught_up: bool,
+}
+
+// ============================================
+// DAG statistics (GET /api/v1/consensus/dag/stats)
+// ============================================
+
+/// Shape of the serving validator's in-memory DAG.
+///
+/// `fan_in[i]` / `fan_out[i]` count events with exactly `i` parents /
+/// children; the last bucket also counts every event with more.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetDagStatsResponse {
+    /// Events currently held in the DAG
+    pub event_count: usize,
+    /// Events not yet finalized
+    pub pending_count: usize,
+    /// Events without children
+    pub tip_count: usize,
+    /// Greatest depth in the DAG
+    pub max_depth: u64,
+    /// Parent-count histogram
+    pub fan_in: Vec<u64>,
+    /// Child-count histogram
+    pub fan_out: Vec<u64>,
+}
+
+// ============================================
+// Orphaned events (GET /api/v1/dag/orphans)
+// ============================================
+
+/// A pending event that no anchor has folded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrphanedEventInfo {
+    pub event_id: String,
+    /// DAG depth of the event
+    pub depth: u64,
+    /// Latest finalized anchor depth when the event entered the DAG
+    pub added_at_anchor: u64,
+    /// Anchors finalized since then without including the event
+    pub anchors_missed: u64,
+}
+
+/// Events left pending for at least `anchor_threshold` finalized anchors.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetOrphanedEventsResponse {
+    pub anchor_threshold: u64,
+    pub orphans: Vec<OrphanedEventInfo>,
+}
+
+// ============================================
+// DAG causal subgraph (GET /api/v1/explorer/dag/path/:event_id)
+// ============================================
+
+/// One event in a causal subgraph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CausalNode {
+    pub event_id: String,
+    pub event_type: String,
+    pub creator: String,
+    pub vlc_time: u64,
+    /// Distance in parent hops from the queried event
+    pub distance: usize,
+}
+
+/// A `child → parent` link in a causal subgraph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CausalEdge {
+    pub child: String,
+    pub parent: String,
+}
+
+/// Ancestor subgraph of an event, bounded by `max_depth`.
+///
+/// `truncated` is set when ancestors exist beyond `max_depth` or could not be
+/// found locally, so a UI can show that the cone continues.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetCausalSubgraphResponse {
+    pub root: String,
+    pub max_depth: usize,
+    pub nodes: Vec<CausalNode>,
+    pub edges: Vec<CausalEdge>,
+    pub truncated: bool,
+}
+
+// ============================================
+// Events by time range (GET /api/v1/explorer/events?from=&to=)
+// ============================================
+
+/// Summary of one event in a time-range listing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExplorerEvent {
+    pub event_id: String,
+    pub event_type: String,
+    pub creator: String,
+    pub status: String,
+    /// Creation time in milliseconds
+    pub timestamp: u64,
+    pub vlc_time: u64,
+}
+
+/// Stored events with `from <= timestamp <= to`, oldest first.
+///
+/// `truncated` is set when more in-window events exist beyond `limit`; page
+/// on by re-querying with `from=next_from&after=next_after`, the
+/// `(timestamp, event_id)` of the last returned event. `decode_errors` is
+/// the node's running count of stored events skipped because they failed to
+/// decode.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEventsInRangeResponse {
+    pub from: u64,
+    pub to: u64,
+    pub limit: usize,
+    pub events: Vec<ExplorerEvent>,
+    pub truncated: bool,
+    /// Timestamp of the last returned event, set when `truncated`
+    #[serde(default)]
+    pub next_from: Option<u64>,
+    /// Id of the last returned event, set when `truncated`
+    #[serde(default)]
+    pub next_after: Option<String>,
+    #[serde(default)]
+    pub decode_errors: u64,
+}
+
+// ============================================
+// Token metadata (GET /api/v1/explorer/token/:coin_type)
+// ============================================
+
+/// Display metadata for a coin type from the coin type registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetTokenMetadataResponse {
+    pub coin_type: String,
+    pub symbol: String,
+    pub name: String,
+    /// Decimal places: a raw balance of `1000000` with 6 decimals is `1.000000`
+    pub decimals: u8,
+    /// Addresses holding at least one coin of this type (tombstoned coins excluded)
+    #[serde(default)]
+    pub holder_count: usize,
+}
+
+// ============================================
+// Subnet activity (GET /api/v1/explorer/activity/:address/:subnet_id)
+// ============================================
+
+/// A user's engagement within one subnet, from its `UserSubnetActivity` record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetSubnetActivityResponse {
+    pub address: String,
+    pub subnet_id: String,
+    /// All interactions ever recorded, including ones no longer in `recent_partners`
+    pub total_interactions: u64,
+    pub unique_users: u64,
+    /// Interaction count per type (e.g. `"transfer"`)
+    pub interaction_counts: std::collections::BTreeMap<String, u64>,
+    /// Addresses of the users most recently interacted with
+    pub recent_partners: Vec<String>,
+    pub joined_at: u64,
+    pub last_activity: u64,
+}
+
+// ============================================
+// Anchor detail (GET /api/v1/explorer/anchor/:id)
+// ============================================
+
+/// Hex-encoded `AnchorMerkleRoots` committed in an anchor.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnchorMerkleRootsView {
+    pub events_root: String,
+    pub global_state_root: String,
+    pub anchor_chain_root: String,
+    /// State root per subnet, keyed by hex subnet id
+    pub subnet_roots: std::collections::BTreeMap<String, String>,
+}
+
+impl From<&AnchorMerkleRoots> for AnchorMerkleRootsView {
+    fn from(roots: &AnchorMerkleRoots) -> Self {
+        Self {
+            events_root: hex_root(&roots.events_root),
+            global_state_root: hex_root(&roots.global_state_root),
+            anchor_chain_root: hex_root(&roots.anchor_chain_root),
+            subnet_roots: roots
+                .subnet_roots
+                .iter()
+                .map(|(subnet_id, root)| (subnet_id.to_string(), hex_root(root)))
+                .collect(),
+        }
+    }
+}
+
+/// Full anchor detail with its Merkle root breakdown.
+///
+/// `previous_anchor_chain_root` is the `anchor_chain_root` committed by the
+/// previous anchor, so clients can check chain linkage without a second
+/// request. `merkle_roots` is `None` for legacy anchors built without roots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAnchorDetailResponse {
+    pub anchor_id: String,
+    pub depth: u64,
+    pub timestamp: u64,
+    pub vlc_time: u64,
+    pub state_root: String,
+    pub event_ids: Vec<String>,
+    pub previous_anchor: Option<String>,
+    pub merkle_roots: Option<AnchorMerkleRootsView>,
+    pub previous_anchor_chain_root: Option<String>,
+}
+
+impl GetAnchorDetailResponse {
+    /// Build the response from an anchor and its predecessor (if found locally)
+    pub fn new(anchor: &Anchor, previous: Option<&Anchor>) -> Self {
+        Self {
+            anchor_id: anchor.id.clone(),
+            depth: anchor.depth,
+            timestamp: anchor.timestamp,
+            vlc_time: anchor.vlc_snapshot.logical_time,
+            state_root: anchor.state_root.clone(),
+            event_ids: anchor.event_ids.clone(),
+            previous_anchor: anchor.previous_anchor.clone(),
+            merkle_roots: anchor.merkle_roots.as_ref().map(AnchorMerkleRootsView::from),
+            previous_anchor_chain_root: previous
+                .and_then(|prev| prev.merkle_roots.as_ref())
+                .map(|roots| hex_root(&roots.anchor_chain_root)),
+        }
+    }
+}
+
+fn hex_root(root: &[u8; 32]) -> String {
+    root.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// ============================================
+// Finalized tip (GET /api/v1/explorer/tip)
+// ============================================
+
+/// Newest finalized anchor, without its event list.
+///
+/// Light clients poll this and pin `anchor_id` before requesting inclusion
+/// proofs against `global_state_root`. `proposer_signature` is the proposer's
+/// hex vote signature on the finalizing CF (empty if it did not sign).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetExplorerTipResponse {
+    pub anchor_id: String,
+    pub depth: u64,
+    pub global_state_root: String,
+    pub timestamp: u64,
+    pub proposer: String,
+    pub proposer_signature: String,
+}
+
+// ============================================
+// Vote certificate (GET /api/v1/explorer/anchor/:id/certificate)
+// ============================================
+
+/// Who approved the CF that finalized an anchor, and with how much voting
+/// power. `approved_power` reaching quorum of `total_power` is the evidence
+/// that the anchor was legitimately finalized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetVoteCertificateResponse {
+    pub anchor_id: String,
+    pub cf_id: String,
+    /// Approving validators, sorted by validator id
+    pub approvals: Vec<CertifiedVoteView>,
+    pub approved_power: u128,
+    pub total_power: u128,
+}
+
+/// One approving vote, with its hex signature (empty if unsigned)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CertifiedVoteView {
+    pub validator_id: String,
+    pub voting_power: u128,
+    pub signature: String,
+}
+
+impl From<&VoteCertificate> for GetVoteCertificateResponse {
+    fn from(cert: &VoteCertificate) -> Self {
+        Self {
+            anchor_id: cert.anchor_id.clone(),
+            cf_id: cert.cf_id.clone(),
+            approvals: cert
+                .approvals
+                .iter()
+                .map(|vote| CertifiedVoteView {
+                    validator_id: vote.validator_id.clone(),
+                    voting_power: vote.voting_power,
+                    signature: hex::encode(&vote.signature),
+                })
+                .collect(),
+            approved_power: cert.approved_power,
+            total_power: cert.total_power,
+        }
+    }
+}
+
+// ============================================
+// Subnet inclusion proof (GET /api/v1/explorer/anchor/:id/subnet/:subnet_id/proof)
+// ============================================
+
+/// Proof that a subnet's state root is included in an anchor's
+/// `global_state_root` (subnet aggregation tree, hex-encoded).
+///
+/// Verify by hashing the leaf `BLAKE3(AGGREGATION_LEAF_PREFIX || subnet_id ||
+/// subnet_root)` up through `siblings`, taking `leaf_index` bit by bit to pick
+/// left/right, and comparing with `global_state_root`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetSubnetInclusionProofResponse {
+    pub anchor_id: String,
+    /// Full 32-byte hex subnet id
+    pub subnet_id: String,
+    pub subnet_root: String,
+    pub global_state_root: String,
+    /// Position of the subnet in the sorted aggregation leaves
+    pub leaf_index: usize,
+    /// Sibling hashes from leaf to root
+    pub siblings: Vec<String>,
+}
+
+// ============================================
+// Subnets (GET /api/v1/explorer/subnets, GET /api/v1/explorer/subnet/:id/stats)
+// ============================================
+
+/// A registered subnet with its configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExplorerSubnet {
+    pub subnet_id: String,
+    pub name: String,
+    pub owner: String,
+    /// Subnet type (e.g. `"App"`, `"Organization"`)
+    pub subnet_type: String,
+    /// Native token symbol; empty if the subnet has no token
+    pub token_symbol: String,
+    pub status: String,
+    /// Registration time in seconds
+    pub registered_at: u64,
+}
+
+/// All registered subnets, sorted by `subnet_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetExplorerSubnetsResponse {
+    pub subnets: Vec<ExplorerSubnet>,
+}
+
+/// Committed state summary of one subnet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetSubnetStatsResponse {
+    pub subnet_id: String,
+    /// Objects in the subnet's SMT
+    pub object_count: u64,
+    /// Hex-encoded latest SMT root
+    pub state_root: String,
+    /// Anchor that last committed changes to the subnet
+    pub anchor_depth: u64,
+    /// Total balance of the subnet's own token
+    pub token_supply: u64,
+}
+
+/// Committed event throughput of one subnet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetSubnetThroughputResponse {
+    pub subnet_id: String,
+    /// Events committed to the subnet since the validator started
+    pub event_count: u64,
+    /// Events committed in the last minute
+    pub events_per_minute: u64,
+}
+
+// ============================================
+// Bulk balances (POST /api/v1/explorer/accounts/balances)
+// ============================================
+
+/// Addresses to look up; at most [`crate::MAX_BALANCE_ADDRESSES`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAccountBalancesRequest {
+    pub addresses: Vec<String>,
+}
+
+/// Balances of each requested address, keyed by address then coin type.
+/// Addresses without coins map to an empty object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAccountBalancesResponse {
+    pub balances: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+// ============================================
+// Bulk event status (POST /api/v1/explorer/events/status)
+// ============================================
+
+/// Event ids to look up; at most [`crate::MAX_STATUS_EVENT_IDS`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEventStatusesRequest {
+    pub event_ids: Vec<String>,
+}
+
+/// Current status of one event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventStatusItem {
+    /// `EventStatus` name (e.g. `"Pending"`, `"Finalized"`)
+    pub status: String,
+    /// Anchor that finalized the event, if any
+    pub anchor_id: Option<String>,
+}
+
+/// Status of each requested event, keyed by event id. Unknown ids map to
+/// `null`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEventStatusesResponse {
+    pub statuses: BTreeMap<String, Option<EventStatusItem>>,
+}
+
+// ============================================
+// Admin (POST /api/v1/admin/rebuild-coin-index)
+// ============================================
+
+/// Result of rebuilding the owner/coin-type index from the Merkle trees.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RebuildCoinIndexResponse {
+    /// Objects indexed by the rebuild
+    pub indexed_count: usize,
+    /// Distinct owners in the coin-type index afterwards
+    pub indexed_addresses: usize,
+    /// Total (owner, coin type) entries in the index afterwards
+    pub indexed_entries: usize,
+}
+
+// ============================================
+// Admin (GET /api/v1/admin/state/:address)
+// ============================================
+
+/// Proof that a subnet root is aggregated into the global state root
+/// (hex-encoded). Verified the same way as [`GetSubnetInclusionProofResponse`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminSubnetProof {
+    /// Position of the subnet in the sorted aggregation leaves
+    pub leaf_index: usize,
+    /// Sibling hashes from leaf to root
+    pub siblings: Vec<String>,
+}
+
+/// A committed object with its inclusion proof (hex-encoded).
+///
+/// Verify by hashing the leaf for `(leaf_key, value)` up through `siblings`
+/// and comparing with `subnet_root`, then `subnet_proof` carries
+/// `subnet_root` up to the response's `global_state_root`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminProvenObject {
+    pub object_id: String,
+    /// Full 32-byte hex subnet id
+    pub subnet_id: String,
+    /// Raw object bytes as stored in the subnet SMT
+    pub value: String,
+    pub subnet_root: String,
+    pub leaf_key: String,
+    /// Sibling hashes from leaf to root
+    pub siblings: Vec<String>,
+    /// Event that last modified the object, if tracked
+    pub last_modified_by: Option<String>,
+    /// Inclusion of `subnet_root` in the global state root
+    pub subnet_proof: Option<AdminSubnetProof>,
+}
+
+/// A coin owned by the address, with its proven object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminStateCoin {
+    pub coin_type: String,
+    pub balance: u64,
+    pub version: u64,
+    pub object: AdminProvenObject,
+}
+
+/// Everything committed state holds on one address, read from a single
+/// snapshot. Credentials are not stored on-chain yet and are not listed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAddressStateResponse {
+    pub address: String,
+    pub global_state_root: String,
+    pub coins: Vec<AdminStateCoin>,
+    pub profile: Option<AdminProvenObject>,
+}
+
+// ============================================
+// Health (GET /api/v1/health)
+// ============================================
+
+/// Status of one subsystem in the health report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentHealth {
+    pub healthy: bool,
+    pub detail: String,
+}
+
+impl ComponentHealth {
+    pub fn ok(detail: impl Into<String>) -> Self {
+        Self { healthy: true, detail: detail.into() }
+    }
+
+    pub fn failed(detail: impl Into<String>) -> Self {
+        Self { healthy: false, detail: detail.into() }
+    }
+}
+
+/// Component-level health used for readiness probes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// Storage reachable (cheap anchor store read)
+    pub storage: ComponentHealth,
+    /// Consensus engine responsive and finalizing
+    pub consensus: ComponentHealth,
+    /// Milliseconds since the newest finalized anchor (None = no anchor yet)
+    pub last_anchor_age_ms: Option<u64>,
+    /// Logical-time gap between local VLC and the newest finalized anchor
+    pub sync_lag: u64,
+    /// Anchor chain caught up with the depth peers report
+    pub catch_up: ComponentHealth,
+}
+
+impl HealthReport {
+    /// Ready only when every critical subsystem is healthy
+    pub fn is_ready(&self) -> bool {
+        self.storage.healthy && self.consensus.healthy && self.catch_up.healthy
+    }
+}
+
+// ============================================
+// Anchor detail tests
+// ============================================
+
+#[cfg(test)]
+mod anchor_detail_tests {
+    use super::*;
+    use consensus::{compute_anchor_chain_root, AnchorMerkleRootsBuilder};
+    use setu_merkle::HashValue;
+    use setu_types::{SubnetId, VLCSnapshot};
+
+    fn build_chain(len: u64) -> Vec<Anchor> {
+        let mut chain: Vec<Anchor> = Vec::new();
+        for depth in 0..len {
+            let roots = AnchorMerkleRootsBuilder::new()
+                .with_anchor_chain(chain.clone())
+                .with_subnet_root(SubnetId::ROOT, HashValue::new([depth as u8 + 1; 32]))
+                .build();
+            chain.push(Anchor::with_merkle_roots(
+                vec![format!("event-{}", depth)],
+                VLCSnapshot::new(),
+                roots,
+                chain.last().map(|a| a.id.clone()),
+                depth,
+            ));
+        }
+        chain
+    }
+
+    #[test]
+    fn anchor_detail_exposes_all_roots() {
+        let chain = build_chain(3);
+        let anchor = &chain[2];
+        let resp = GetAnchorDetailResponse::new(anchor, Some(&chain[1]));
+
+        let json = serde_json::to_value(&resp).unwrap();
+        for field in ["events_root", "global_state_root", "anchor_chain_root", "subnet_roots"] {
+            assert!(json["merkle_roots"].get(field).is_some(), "missing {}", field);
+        }
+        assert_eq!(resp.previous_anchor.as_deref(), Some(chain[1].id.as_str()));
+
+        let roots = resp.merkle_roots.unwrap();
+        let stored = anchor.merkle_roots.as_ref().unwrap();
+        assert_eq!(roots.events_root, hex::encode(stored.events_root));
+        assert_eq!(roots.global_state_root, hex::encode(stored.global_state_root));
+        assert_eq!(
+            roots.subnet_roots.get(&SubnetId::ROOT.to_string()),
+            Some(&hex::encode([3u8; 32]))
+        );
+
+        // Each anchor commits the chain root over its predecessors
+        let expected = compute_anchor_chain_root(&[&chain[0], &chain[1]]);
+        assert_eq!(roots.anchor_chain_root, hex::encode(expected.as_bytes()));
+        let expected_prev = compute_anchor_chain_root(&[&chain[0]]);
+        assert_eq!(
+            resp.previous_anchor_chain_root,
+            Some(hex::encode(expected_prev.as_bytes()))
+        );
+    }
+
+    #[test]
+    fn anchor_detail_without_previous() {
+        let chain = build_chain(1);
+        let resp = GetAnchorDetailResponse::new(&chain[0], None);
+        assert!(resp.previous_anchor.is_none());
+        assert!(resp.previous_anchor_chain_root.is_none());
+        assert!(resp.merkle_roots.is_some());
+    }
+}
+
 // ============================================
 // M5-Pre tests — MoveCallRequest.dynamic_field_accesses
 // ============================================
@@ -16,17 +16,16 @@
 //! This ensures that rejected/timeout CFs don't corrupt state.
 
 use crate::dag::Dag;
-use crate::merkle_integration::compute_events_root;
+use crate::merkle_integration::{append_anchor_chain_root, compute_events_root};
 use crate::outcome_sink::OutcomeSink;
 use crate::router::{EventRouter, RoutedEvents};
 use crate::vlc::VLC;
 use setu_merkle::HashValue;
 use setu_storage::{GlobalStateManager, SharedStateManager, StateApplyError, StateApplySummary};
 use setu_types::{
-    event::StateChange, Anchor, AnchorMerkleRoots, ConsensusConfig, ConsensusFrame, Event, EventId,
-    ExecutionOutcome, SubnetId,
+    event::StateChange, sort_events_canonically, Anchor, AnchorMerkleRoots, ConsensusConfig,
+    ConsensusFrame, Event, EventId, ExecutionOutcome, SubnetId,
 };
-use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
@@ -79,6 +78,34 @@ fn collect_event_write_keys(event: &Event) -> HashSet<ClaimedWriteKey> {
     keys
 }
 
+/// Drop every event that has a pending parent outside `events`.
+///
+/// Repeats until no such event is left, so descendants of a deferred event
+/// are held back with it. The dropped events stay pending in the DAG and are
+/// reconsidered on the next `prepare_build` round.
+fn defer_unclosed_children(dag: &Dag, events: &mut Vec<Event>) -> Vec<Event> {
+    let mut deferred = Vec::new();
+    loop {
+        let selected: HashSet<&EventId> = events.iter().map(|e| &e.id).collect();
+        let open: Vec<usize> = events
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| {
+                e.parent_ids
+                    .iter()
+                    .any(|parent| !selected.contains(parent) && dag.is_pending(parent))
+            })
+            .map(|(i, _)| i)
+            .collect();
+        if open.is_empty() {
+            return deferred;
+        }
+        for i in open.into_iter().rev() {
+            deferred.push(events.remove(i));
+        }
+    }
+}
+
 /// γ strict same-key CF fold policy.
 ///
 /// Greedy scan of VLC-sorted events; an event is **kept** only if its write-key
@@ -87,10 +114,10 @@ fn collect_event_write_keys(event: &Event) -> HashSet<ClaimedWriteKey> {
 /// reconsidered on the next `prepare_build` round (once previously-kept events
 /// move to `in_flight_event_ids`).
 ///
-/// **Inv-γ-Sort**: events are sorted by `(vlc_snapshot.logical_time asc, id asc)`
-/// BEFORE scanning. This MUST stay identical to the sort inside
-/// `apply_committed_events` in storage/src/state/manager.rs (leader-follower
-/// consensus depends on the two layers agreeing on order).
+/// **Inv-γ-Sort**: events are sorted by [`Event::canonical_cmp`] BEFORE
+/// scanning, the same order `apply_committed_events` in
+/// storage/src/state/manager.rs applies them in (leader-follower consensus
+/// depends on the two layers agreeing on order).
 ///
 /// **tie-break**: event_id equality would require a BLAKE3 collision; treated
 /// as impossible. See design §3.2.
@@ -98,16 +125,7 @@ fn collect_event_write_keys(event: &Event) -> HashSet<ClaimedWriteKey> {
 /// See `docs/feat/strict-same-key-cf-fold/design.md` §3.2.
 fn apply_strict_same_key_fold_policy(events: Vec<Event>) -> (Vec<Event>, Vec<Event>) {
     let mut sorted = events;
-    sorted.sort_by(|a, b| {
-        match a
-            .vlc_snapshot
-            .logical_time
-            .cmp(&b.vlc_snapshot.logical_time)
-        {
-            Ordering::Equal => a.id.cmp(&b.id),
-            other => other,
-        }
-    });
+    sort_events_canonically(&mut sorted);
 
     let mut claimed: HashSet<ClaimedWriteKey> = HashSet::new();
     let mut kept: Vec<Event> = Vec::with_capacity(sorted.len());
@@ -578,11 +596,16 @@ impl AnchorBuilder {
         } else {
             Vec::new()
         };
-        if !deferred_same_key.is_empty() || !deferred_capacity.is_empty() {
+        // Close the selection over parents: a child whose parent was just
+        // deferred (or is still in flight) must wait for it, or followers
+        // reject the CF as not causally closed (`Dag::unclosed_parents`).
+        let deferred_parent = defer_unclosed_children(dag, &mut events);
+        if !deferred_same_key.is_empty() || !deferred_capacity.is_empty() || !deferred_parent.is_empty() {
             tracing::info!(
                 kept = events.len(),
                 deferred_same_key = deferred_same_key.len(),
                 deferred_capacity = deferred_capacity.len(),
+                deferred_parent = deferred_parent.len(),
                 "γ fold: deferred events to next CF"
             );
         }
@@ -691,8 +714,7 @@ impl AnchorBuilder {
             .insert(anchor.id.clone(), prepare_base);
 
         // Compute what the new chain root will be
-        let anchor_hash = anchor.compute_hash();
-        let new_anchor_chain_root = Self::chain_hash(&self.last_anchor_chain_root, &anchor_hash);
+        let new_anchor_chain_root = append_anchor_chain_root(&self.last_anchor_chain_root, &anchor);
 
         Ok(PendingAnchorBuild {
             anchor,
@@ -785,7 +807,7 @@ impl AnchorBuilder {
             Self::diag_h4_probes(&cf_id, "leader", &guard, &events);
 
             let summary = guard.apply_committed_events(&events);
-            match guard.commit(anchor_id) {
+            match guard.commit_anchor(anchor_id, &cf_id) {
                 Ok(()) => {
                     // DIAG H1: after the real apply+commit, the write GSM's
                     // actual root must match what was declared in the anchor
@@ -966,7 +988,7 @@ impl AnchorBuilder {
                         }
                     }
 
-                    match guard.commit(anchor_id) {
+                    match guard.commit_anchor(anchor_id, &cf.anchor.id) {
                         Ok(()) => {
                             // DIAG P5 (cf_apply_progress): see leader-side note.
                             #[cfg(feature = "diag-root-drift")]
@@ -991,7 +1013,7 @@ impl AnchorBuilder {
             } else {
                 // No merkle_roots to verify — apply directly
                 let summary = guard.apply_committed_events(events);
-                match guard.commit(anchor_id) {
+                match guard.commit_anchor(anchor_id, &cf.anchor.id) {
                     Ok(()) => {
                         // DIAG P5 (cf_apply_progress): see leader-side note.
                         #[cfg(feature = "diag-root-drift")]
@@ -1088,19 +1110,10 @@ impl AnchorBuilder {
         );
 
         // P3 — per-event post-apply deltas on a sandbox clone. Replicates the
-        // VLC-then-id sort done by `apply_committed_events` so the logged
+        // canonical sort done by `apply_committed_events` so the logged
         // sequence matches production order exactly.
         let mut sorted: Vec<&Event> = events.iter().collect();
-        sorted.sort_by(|a, b| {
-            match a
-                .vlc_snapshot
-                .logical_time
-                .cmp(&b.vlc_snapshot.logical_time)
-            {
-                std::cmp::Ordering::Equal => a.id.cmp(&b.id),
-                other => other,
-            }
-        });
+        sorted.sort_by(|a, b| a.canonical_cmp(b));
         let mut sandbox = guard.clone();
         for (idx, ev) in sorted.iter().enumerate() {
             let _ = sandbox.apply_committed_events(std::slice::from_ref(*ev));
@@ -1433,9 +1446,8 @@ impl AnchorBuilder {
         // Update anchor chain root by hashing the stored root with this anchor's hash
         // The anchor stores the "before" root, we compute the "after" root
         if let Some(ref merkle_roots) = anchor.merkle_roots {
-            let anchor_hash = anchor.compute_hash();
             self.last_anchor_chain_root =
-                Self::chain_hash(&merkle_roots.anchor_chain_root, &anchor_hash);
+                append_anchor_chain_root(&merkle_roots.anchor_chain_root, anchor);
         }
 
         // Update other state to match the finalized anchor
@@ -1458,17 +1470,6 @@ impl AnchorBuilder {
         let (root, _) = snapshot.compute_global_root_bytes();
         root
     }
-
-    /// Chain hash: combines previous chain root with new anchor hash
-    ///
-    /// Anchor chain append hash.
-    ///
-    /// new_root = BLAKE3("SETU_ANCHOR_CHAIN:" || prev_root || anchor_hash)
-    ///
-    /// Delegates to the canonical implementation in `hash_utils::chain_hash`.
-    fn chain_hash(prev_root: &[u8; 32], anchor_hash: &[u8; 32]) -> [u8; 32] {
-        setu_types::hash_utils::chain_hash(prev_root, anchor_hash)
-    }
 }
 
 #[cfg(test)]
@@ -1770,6 +1771,46 @@ mod tests {
         assert!(last_anchor.previous_anchor.is_some());
     }
 
+    #[test]
+    fn test_incremental_chain_root_matches_full_recompute() {
+        use crate::merkle_integration::{recompute_anchor_chain_root, AnchorChainAudit};
+        use setu_types::AnchorMerkleRoots;
+
+        let mut builder = AnchorBuilder::new(ConsensusConfig::default());
+        let mut anchors: Vec<Anchor> = Vec::new();
+        for depth in 0..100u64 {
+            let roots = AnchorMerkleRoots {
+                events_root: [depth as u8; 32],
+                global_state_root: [depth as u8 ^ 0xff; 32],
+                // Each anchor commits the chain root before it
+                anchor_chain_root: builder.anchor_chain_root(),
+                subnet_roots: HashMap::new(),
+            };
+            let anchor = Anchor::with_merkle_roots(
+                vec![format!("event-{}", depth)],
+                VLCSnapshot::default(),
+                roots,
+                anchors.last().map(|a| a.id.clone()),
+                depth,
+            );
+            builder.synchronize_finalized_anchor(&anchor);
+            anchors.push(anchor);
+        }
+
+        assert_eq!(recompute_anchor_chain_root(&anchors), Ok(builder.anchor_chain_root()));
+
+        // Streaming one anchor at a time gives the same root
+        let mut audit = AnchorChainAudit::new();
+        anchors.iter().try_for_each(|anchor| audit.push(anchor)).unwrap();
+        assert_eq!(audit.root(), builder.anchor_chain_root());
+        assert_eq!(audit.anchor_count(), 100);
+
+        // A broken link is reported at the first anchor that does not chain
+        let mut tampered = anchors.clone();
+        tampered[40].merkle_roots.as_mut().unwrap().anchor_chain_root = [7u8; 32];
+        assert_eq!(recompute_anchor_chain_root(&tampered).unwrap_err().depth, 40);
+    }
+
     // ============================================
     // R5 · ingest_outcomes tests (U3–U8)
     // ============================================
@@ -2327,6 +2368,45 @@ mod tests {
         );
     }
 
+    /// A child must not be folded while its parent is deferred to a later CF
+    #[test]
+    fn d1_prepare_build_defers_children_of_deferred_parent() {
+        let builder = AnchorBuilder::new(d1_config());
+        let vlc_at = |logical_time| VLCSnapshot {
+            logical_time,
+            ..Default::default()
+        };
+        let with_write = |id: &str, parents: Vec<EventId>, logical_time, key: &str| {
+            let mut ev = create_event_with_result(
+                SubnetId::ROOT,
+                vec![StateChange {
+                    key: test_oid_key(key),
+                    old_value: None,
+                    new_value: Some(vec![1; 8]),
+                    target_subnet: None,
+                }],
+            );
+            ev.id = id.to_string();
+            ev.parent_ids = parents;
+            ev.vlc_snapshot = vlc_at(logical_time);
+            ev
+        };
+
+        // "p" writes the same key as "a" and sorts after it, so γ defers it;
+        // "c" builds on "p" but writes an unrelated key
+        let mut dag = ConsensusDag::new();
+        dag.add_event(with_write("a", vec![], 1, "shared")).unwrap();
+        dag.add_event(with_write("p", vec![], 1, "shared")).unwrap();
+        dag.add_event(with_write("c", vec!["p".to_string()], 2, "other")).unwrap();
+        dag.add_event(with_write("g", vec!["c".to_string()], 3, "third")).unwrap();
+
+        let pending = builder
+            .prepare_build(&dag, &create_vlc("n1", 10), &HashSet::new())
+            .expect("prepare_build");
+        assert_eq!(pending.anchor.event_ids, vec!["a".to_string()]);
+        assert!(dag.unclosed_parents(&pending.anchor.event_ids).is_empty());
+    }
+
     /// T7: All pending events are in-flight → `InsufficientEvents`.
     #[test]
     fn d1_prepare_build_insufficient_when_all_in_flight() {
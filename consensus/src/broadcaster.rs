@@ -27,10 +27,15 @@
 //!     (production)                 (testing)
 //! ```
 
+use crate::validator_set::ValidatorSetChange;
+use setu_router_core::SelectionRng;
 use setu_types::{ConsensusFrame, Event, EventId, Vote};
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
+use tracing::{debug, warn};
 
 /// Error type for broadcast operations
 #[derive(Debug, Error)]
@@ -56,6 +61,13 @@ pub enum BroadcastError {
     SerializationError(String),
 }
 
+impl BroadcastError {
+    /// Whether resending the same message may succeed
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::NetworkError(_) | Self::Timeout(_) | Self::AllFailed(_))
+    }
+}
+
 /// Result of a broadcast operation
 #[derive(Debug, Clone)]
 pub struct BroadcastResult {
@@ -65,6 +77,8 @@ pub struct BroadcastResult {
     pub total_peers: usize,
     /// Details of failures (peer_id, error message)
     pub failures: Vec<(String, String)>,
+    /// Attempts it took to get this result (1 = first try)
+    pub attempts: u32,
 }
 
 impl BroadcastResult {
@@ -74,6 +88,7 @@ impl BroadcastResult {
             success_count: count,
             total_peers: total,
             failures: Vec::new(),
+            attempts: 1,
         }
     }
 
@@ -83,9 +98,16 @@ impl BroadcastResult {
             success_count,
             total_peers: total,
             failures,
+            attempts: 1,
         }
     }
 
+    /// Record how many attempts the broadcast took
+    pub fn with_attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts;
+        self
+    }
+
     /// Check if all broadcasts succeeded
     pub fn all_succeeded(&self) -> bool {
         self.success_count == self.total_peers
@@ -151,6 +173,18 @@ pub trait ConsensusBroadcaster: Send + Sync + Debug {
     /// Returns the events that were successfully fetched.
     async fn request_events(&self, event_ids: &[EventId]) -> Result<Vec<Event>, BroadcastError>;
 
+    /// Announce a scheduled validator set change to all peer validators
+    ///
+    /// Every validator must schedule the same change before its activation
+    /// round, so the scheduling node gossips it to the rest of the set.
+    /// Broadcasters without a network (single-node, tests) keep the default.
+    async fn broadcast_validator_set_change(
+        &self,
+        _change: &ValidatorSetChange,
+    ) -> Result<BroadcastResult, BroadcastError> {
+        Ok(BroadcastResult::success(0, 0))
+    }
+
     /// Get the number of connected peer validators
     fn peer_count(&self) -> usize;
 
@@ -205,7 +239,73 @@ impl ConsensusBroadcaster for NoOpBroadcaster {
     }
 }
 
+/// A message captured by the simulated network of a `MockBroadcaster`
+#[derive(Debug, Clone)]
+pub enum SimulatedMessage {
+    Cf(ConsensusFrame),
+    Vote(Vote),
+    Finalized(ConsensusFrame),
+    Event(Box<Event>),
+}
+
+/// One transmission attempt through the simulated network
+#[derive(Debug, Clone)]
+pub struct SimulatedDelivery {
+    /// Monotonic transmission sequence number (reproducible for a given seed)
+    pub seq: u64,
+    /// The transmitted message
+    pub message: SimulatedMessage,
+    /// Whether the simulated network delivered or dropped the message
+    pub delivered: bool,
+}
+
+/// Granularity of drop draws: `drop_rate` is applied in steps of 1/`DROP_DRAW_RANGE`
+const DROP_DRAW_RANGE: usize = 1_000_000;
+
+/// Network model: fixed latency plus random message drops.
+///
+/// All randomness comes from one injected [`SelectionRng`] consumed in
+/// transmission order, so with a `SeededSelectionRng` the same seed and the
+/// same sequence of broadcasts always yield the same drop pattern and
+/// delivery order.
+struct NetworkModel {
+    rng: Arc<dyn SelectionRng>,
+    drop_rate: f64,
+    latency: Duration,
+    /// Next transmission sequence number; also serializes RNG draws
+    next_seq: std::sync::Mutex<u64>,
+}
+
+impl Debug for NetworkModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NetworkModel")
+            .field("drop_rate", &self.drop_rate)
+            .field("latency", &self.latency)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NetworkModel {
+    /// Draw the fate of the next transmission. Returns `(seq, delivered)`.
+    fn transmit(&self) -> (u64, bool) {
+        // Sequence number and RNG draw are taken under the same lock so the
+        // (seq, fate) pairing is stable even with concurrent callers.
+        let mut next_seq = self.next_seq.lock().unwrap();
+        let seq = *next_seq;
+        *next_seq += 1;
+        let draw = self.rng.gen_index(DROP_DRAW_RANGE) as f64;
+        let dropped = draw < self.drop_rate * DROP_DRAW_RANGE as f64;
+        (seq, !dropped)
+    }
+}
+
 /// A mock broadcaster for testing that records all broadcasts
+///
+/// By default every broadcast succeeds. With [`MockBroadcaster::with_network_model`]
+/// the broadcaster simulates an unreliable network: each transmission is
+/// delayed by a fixed latency and dropped with a seeded probability. Only
+/// delivered messages appear in the recorded broadcast lists; every attempt
+/// (delivered or not) is appended to the delivery log.
 #[derive(Debug)]
 pub struct MockBroadcaster {
     local_id: String,
@@ -218,8 +318,14 @@ pub struct MockBroadcaster {
     pub finalized_broadcasts: std::sync::Mutex<Vec<String>>,
     /// Recorded event broadcasts
     pub event_broadcasts: std::sync::Mutex<Vec<Event>>,
+    /// Recorded validator set change announcements
+    pub validator_set_change_broadcasts: std::sync::Mutex<Vec<ValidatorSetChange>>,
     /// Whether to simulate failures
     pub simulate_failure: std::sync::atomic::AtomicBool,
+    /// Optional simulated network (latency + seeded drops)
+    network_model: Option<NetworkModel>,
+    /// Every transmission attempt made through the network model, in order
+    delivery_log: std::sync::Mutex<Vec<SimulatedDelivery>>,
 }
 
 impl MockBroadcaster {
@@ -232,10 +338,91 @@ impl MockBroadcaster {
             vote_broadcasts: std::sync::Mutex::new(Vec::new()),
             finalized_broadcasts: std::sync::Mutex::new(Vec::new()),
             event_broadcasts: std::sync::Mutex::new(Vec::new()),
+            validator_set_change_broadcasts: std::sync::Mutex::new(Vec::new()),
             simulate_failure: std::sync::atomic::AtomicBool::new(false),
+            network_model: None,
+            delivery_log: std::sync::Mutex::new(Vec::new()),
         }
     }
 
+    /// Enable the simulated network model.
+    ///
+    /// * `rng` - source of drop decisions; a `SeededSelectionRng` (feature
+    ///   `deterministic-rng`) reproduces identical drop patterns per seed
+    /// * `drop_rate` - probability in `[0.0, 1.0]` that a transmission is dropped
+    /// * `latency_ms` - fixed delay applied to every delivered transmission
+    pub fn with_network_model(
+        mut self,
+        rng: Arc<dyn SelectionRng>,
+        drop_rate: f64,
+        latency_ms: u64,
+    ) -> Self {
+        self.network_model = Some(NetworkModel {
+            rng,
+            drop_rate: drop_rate.clamp(0.0, 1.0),
+            latency: Duration::from_millis(latency_ms),
+            next_seq: std::sync::Mutex::new(0),
+        });
+        self
+    }
+
+    /// Get every transmission attempt recorded by the network model
+    pub fn get_delivery_log(&self) -> Vec<SimulatedDelivery> {
+        self.delivery_log.lock().unwrap().clone()
+    }
+
+    /// Get the messages that the network model dropped
+    pub fn get_dropped(&self) -> Vec<SimulatedMessage> {
+        self.delivery_log
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|d| !d.delivered)
+            .map(|d| d.message.clone())
+            .collect()
+    }
+
+    /// Retransmit a previously dropped message through the network model.
+    ///
+    /// This mirrors a sender-side retry: the retransmission is subject to the
+    /// same latency and drop probability as the original attempt.
+    pub async fn retransmit(&self, message: &SimulatedMessage) -> Result<BroadcastResult, BroadcastError> {
+        match message {
+            SimulatedMessage::Cf(cf) => self.broadcast_cf(cf).await,
+            SimulatedMessage::Vote(vote) => self.broadcast_vote(vote).await,
+            SimulatedMessage::Finalized(cf) => self.broadcast_finalized(cf).await,
+            SimulatedMessage::Event(event) => self.broadcast_event(event).await,
+        }
+    }
+
+    /// Run a message through the network model (if configured).
+    ///
+    /// Returns `Err` when the message is dropped. Delivered messages incur
+    /// the configured latency before returning `Ok`.
+    async fn simulate_network(&self, message: SimulatedMessage) -> Result<(), BroadcastError> {
+        let Some(ref model) = self.network_model else {
+            return Ok(());
+        };
+
+        let (seq, delivered) = model.transmit();
+        self.delivery_log.lock().unwrap().push(SimulatedDelivery {
+            seq,
+            message,
+            delivered,
+        });
+
+        if !delivered {
+            return Err(BroadcastError::NetworkError(format!(
+                "Simulated drop (seq {})",
+                seq
+            )));
+        }
+        if !model.latency.is_zero() {
+            tokio::time::sleep(model.latency).await;
+        }
+        Ok(())
+    }
+
     /// Set whether to simulate failures
     pub fn set_simulate_failure(&self, fail: bool) {
         self.simulate_failure.store(fail, std::sync::atomic::Ordering::SeqCst);
@@ -260,6 +447,11 @@ impl MockBroadcaster {
     pub fn get_event_broadcasts(&self) -> Vec<Event> {
         self.event_broadcasts.lock().unwrap().clone()
     }
+
+    /// Get recorded validator set change announcements
+    pub fn get_validator_set_change_broadcasts(&self) -> Vec<ValidatorSetChange> {
+        self.validator_set_change_broadcasts.lock().unwrap().clone()
+    }
 }
 
 #[async_trait::async_trait]
@@ -268,6 +460,7 @@ impl ConsensusBroadcaster for MockBroadcaster {
         if self.simulate_failure.load(std::sync::atomic::Ordering::SeqCst) {
             return Err(BroadcastError::AllFailed("Simulated failure".to_string()));
         }
+        self.simulate_network(SimulatedMessage::Cf(cf.clone())).await?;
         self.cf_broadcasts.lock().unwrap().push(cf.clone());
         Ok(BroadcastResult::success(self.peer_count, self.peer_count))
     }
@@ -276,6 +469,7 @@ impl ConsensusBroadcaster for MockBroadcaster {
         if self.simulate_failure.load(std::sync::atomic::Ordering::SeqCst) {
             return Err(BroadcastError::AllFailed("Simulated failure".to_string()));
         }
+        self.simulate_network(SimulatedMessage::Vote(vote.clone())).await?;
         self.vote_broadcasts.lock().unwrap().push(vote.clone());
         Ok(BroadcastResult::success(self.peer_count, self.peer_count))
     }
@@ -284,6 +478,7 @@ impl ConsensusBroadcaster for MockBroadcaster {
         if self.simulate_failure.load(std::sync::atomic::Ordering::SeqCst) {
             return Err(BroadcastError::AllFailed("Simulated failure".to_string()));
         }
+        self.simulate_network(SimulatedMessage::Finalized(cf.clone())).await?;
         self.finalized_broadcasts.lock().unwrap().push(cf.id.clone());
         Ok(BroadcastResult::success(self.peer_count, self.peer_count))
     }
@@ -292,6 +487,7 @@ impl ConsensusBroadcaster for MockBroadcaster {
         if self.simulate_failure.load(std::sync::atomic::Ordering::SeqCst) {
             return Err(BroadcastError::AllFailed("Simulated failure".to_string()));
         }
+        self.simulate_network(SimulatedMessage::Event(Box::new(event.clone()))).await?;
         self.event_broadcasts.lock().unwrap().push(event.clone());
         Ok(BroadcastResult::success(self.peer_count, self.peer_count))
     }
@@ -304,6 +500,17 @@ impl ConsensusBroadcaster for MockBroadcaster {
         Ok(Vec::new())
     }
 
+    async fn broadcast_validator_set_change(
+        &self,
+        change: &ValidatorSetChange,
+    ) -> Result<BroadcastResult, BroadcastError> {
+        if self.simulate_failure.load(std::sync::atomic::Ordering::SeqCst) {
+            return Err(BroadcastError::AllFailed("Simulated failure".to_string()));
+        }
+        self.validator_set_change_broadcasts.lock().unwrap().push(change.clone());
+        Ok(BroadcastResult::success(self.peer_count, self.peer_count))
+    }
+
     fn peer_count(&self) -> usize {
         self.peer_count
     }
@@ -313,6 +520,232 @@ impl ConsensusBroadcaster for MockBroadcaster {
     }
 }
 
+/// Exponential backoff schedule for re-sending failed broadcasts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts per message, including the first (1 disables retries)
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for every retry after it
+    pub initial_backoff: Duration,
+    /// Upper bound on a single delay
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before the `retry`-th retry (1-based)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u32.checked_shl(retry.saturating_sub(1)).unwrap_or(u32::MAX);
+        self.initial_backoff
+            .checked_mul(factor)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+}
+
+/// Final delivery outcomes of a [`RetryingBroadcaster`]
+#[derive(Debug, Default)]
+pub struct DeliveryStats {
+    delivered: AtomicU64,
+    failed: AtomicU64,
+    retries: AtomicU64,
+}
+
+impl DeliveryStats {
+    /// Messages delivered, on the first attempt or after retrying
+    pub fn delivered(&self) -> u64 {
+        self.delivered.load(Ordering::Relaxed)
+    }
+
+    /// Messages given up on after exhausting the retry budget
+    pub fn failed(&self) -> u64 {
+        self.failed.load(Ordering::Relaxed)
+    }
+
+    /// Re-send attempts made in the background
+    pub fn retries(&self) -> u64 {
+        self.retries.load(Ordering::Relaxed)
+    }
+}
+
+/// Owned copy of a message queued for background re-sending
+enum Outbound {
+    Cf(Box<ConsensusFrame>),
+    Vote(Vote),
+    Event(Box<Event>),
+}
+
+impl Outbound {
+    fn id(&self) -> &str {
+        match self {
+            Self::Cf(cf) => &cf.id,
+            Self::Vote(vote) => &vote.cf_id,
+            Self::Event(event) => &event.id,
+        }
+    }
+
+    async fn send(&self, broadcaster: &dyn ConsensusBroadcaster) -> Result<BroadcastResult, BroadcastError> {
+        match self {
+            Self::Cf(cf) => broadcaster.broadcast_cf(cf).await,
+            Self::Vote(vote) => broadcaster.broadcast_vote(vote).await,
+            Self::Event(event) => broadcaster.broadcast_event(event).await,
+        }
+    }
+}
+
+/// Broadcaster decorator that re-sends failed CFs, votes and events.
+///
+/// The first attempt runs inline and its result is returned unchanged. When
+/// it fails with a transient error, or reaches only some of the peers (the
+/// Anemo transport reports per-peer failures as `success < total` rather
+/// than an error), the remaining attempts run in a spawned task with
+/// exponential backoff, so a network blip never holds up the consensus hot
+/// path. Re-sends go to every peer; receivers already dedupe by id. Final
+/// outcomes are counted in [`DeliveryStats`].
+/// Finalization notices, validator set announcements and event fetches are
+/// passed straight through.
+#[derive(Debug)]
+pub struct RetryingBroadcaster {
+    inner: Arc<dyn ConsensusBroadcaster>,
+    policy: RetryPolicy,
+    stats: Arc<DeliveryStats>,
+}
+
+impl RetryingBroadcaster {
+    pub fn new(inner: Arc<dyn ConsensusBroadcaster>, policy: RetryPolicy) -> Self {
+        Self {
+            inner,
+            policy,
+            stats: Arc::new(DeliveryStats::default()),
+        }
+    }
+
+    /// Delivery counters, shared with the background retry tasks
+    pub fn stats(&self) -> Arc<DeliveryStats> {
+        Arc::clone(&self.stats)
+    }
+
+    async fn deliver(&self, message: Outbound) -> Result<BroadcastResult, BroadcastError> {
+        match message.send(self.inner.as_ref()).await {
+            Ok(result) if !result.all_succeeded() && self.policy.max_attempts > 1 => {
+                debug!(
+                    id = %message.id(),
+                    success = result.success_count,
+                    total = result.total_peers,
+                    "Broadcast missed some peers, retrying"
+                );
+                self.spawn_retries(message);
+                Ok(result)
+            }
+            Ok(result) => {
+                self.stats.delivered.fetch_add(1, Ordering::Relaxed);
+                Ok(result)
+            }
+            Err(e) if e.is_transient() && self.policy.max_attempts > 1 => {
+                self.spawn_retries(message);
+                Err(e)
+            }
+            Err(e) => {
+                self.stats.failed.fetch_add(1, Ordering::Relaxed);
+                Err(e)
+            }
+        }
+    }
+
+    fn spawn_retries(&self, message: Outbound) {
+        let inner = Arc::clone(&self.inner);
+        let policy = self.policy;
+        let stats = Arc::clone(&self.stats);
+
+        tokio::spawn(async move {
+            for attempt in 2..=policy.max_attempts {
+                tokio::time::sleep(policy.backoff(attempt - 1)).await;
+                stats.retries.fetch_add(1, Ordering::Relaxed);
+
+                match message.send(inner.as_ref()).await {
+                    Ok(result) if !result.all_succeeded() => {
+                        debug!(
+                            id = %message.id(),
+                            attempt,
+                            success = result.success_count,
+                            total = result.total_peers,
+                            "Broadcast retry missed some peers"
+                        );
+                    }
+                    Ok(result) => {
+                        let result = result.with_attempts(attempt);
+                        debug!(
+                            id = %message.id(),
+                            attempts = result.attempts,
+                            success = result.success_count,
+                            total = result.total_peers,
+                            "Broadcast delivered after retry"
+                        );
+                        stats.delivered.fetch_add(1, Ordering::Relaxed);
+                        return;
+                    }
+                    Err(e) if e.is_transient() => {
+                        debug!(id = %message.id(), attempt, error = %e, "Broadcast retry failed");
+                    }
+                    Err(e) => {
+                        warn!(id = %message.id(), attempt, error = %e, "Broadcast retry aborted");
+                        break;
+                    }
+                }
+            }
+            warn!(id = %message.id(), max_attempts = policy.max_attempts, "Giving up on broadcast");
+            stats.failed.fetch_add(1, Ordering::Relaxed);
+        });
+    }
+}
+
+#[async_trait::async_trait]
+impl ConsensusBroadcaster for RetryingBroadcaster {
+    async fn broadcast_cf(&self, cf: &ConsensusFrame) -> Result<BroadcastResult, BroadcastError> {
+        self.deliver(Outbound::Cf(Box::new(cf.clone()))).await
+    }
+
+    async fn broadcast_vote(&self, vote: &Vote) -> Result<BroadcastResult, BroadcastError> {
+        self.deliver(Outbound::Vote(vote.clone())).await
+    }
+
+    async fn broadcast_finalized(&self, cf: &ConsensusFrame) -> Result<BroadcastResult, BroadcastError> {
+        self.inner.broadcast_finalized(cf).await
+    }
+
+    async fn broadcast_event(&self, event: &Event) -> Result<BroadcastResult, BroadcastError> {
+        self.deliver(Outbound::Event(Box::new(event.clone()))).await
+    }
+
+    async fn request_events(&self, event_ids: &[EventId]) -> Result<Vec<Event>, BroadcastError> {
+        self.inner.request_events(event_ids).await
+    }
+
+    async fn broadcast_validator_set_change(
+        &self,
+        change: &ValidatorSetChange,
+    ) -> Result<BroadcastResult, BroadcastError> {
+        self.inner.broadcast_validator_set_change(change).await
+    }
+
+    fn peer_count(&self) -> usize {
+        self.inner.peer_count()
+    }
+
+    fn local_validator_id(&self) -> &str {
+        self.inner.local_validator_id()
+    }
+}
+
 /// Helper type for optional broadcaster
 pub type OptionalBroadcaster = Option<Arc<dyn ConsensusBroadcaster>>;
 
@@ -364,4 +797,343 @@ mod tests {
         let result = BroadcastResult::success(2, 4);
         assert!(!result.reached_quorum(4)); // 2 < 3 ✗
     }
+
+    /// Fails every broadcast until `fail_first` attempts have been made,
+    /// then misses one peer on the next `lossy_next` attempts
+    #[derive(Debug)]
+    struct FlakyBroadcaster {
+        inner: MockBroadcaster,
+        fail_first: u64,
+        lossy_next: u64,
+        attempts: std::sync::atomic::AtomicU64,
+    }
+
+    #[async_trait::async_trait]
+    impl ConsensusBroadcaster for FlakyBroadcaster {
+        async fn broadcast_cf(&self, cf: &ConsensusFrame) -> Result<BroadcastResult, BroadcastError> {
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst);
+            if attempt < self.fail_first {
+                return Err(BroadcastError::AllFailed("link down".to_string()));
+            }
+            let result = self.inner.broadcast_cf(cf).await?;
+            if attempt < self.fail_first + self.lossy_next {
+                let mut failures = result.failures;
+                failures.push(("validator-3".to_string(), "peer unreachable".to_string()));
+                return Ok(BroadcastResult::with_failures(result.success_count - 1, result.total_peers, failures));
+            }
+            Ok(result)
+        }
+
+        async fn broadcast_vote(&self, vote: &Vote) -> Result<BroadcastResult, BroadcastError> {
+            self.inner.broadcast_vote(vote).await
+        }
+
+        async fn broadcast_finalized(&self, cf: &ConsensusFrame) -> Result<BroadcastResult, BroadcastError> {
+            self.inner.broadcast_finalized(cf).await
+        }
+
+        async fn broadcast_event(&self, event: &Event) -> Result<BroadcastResult, BroadcastError> {
+            self.inner.broadcast_event(event).await
+        }
+
+        async fn request_events(&self, event_ids: &[EventId]) -> Result<Vec<Event>, BroadcastError> {
+            self.inner.request_events(event_ids).await
+        }
+
+        fn peer_count(&self) -> usize {
+            self.inner.peer_count()
+        }
+
+        fn local_validator_id(&self) -> &str {
+            self.inner.local_validator_id()
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retrying_broadcaster_delivers_after_transient_failures() {
+        let flaky = Arc::new(FlakyBroadcaster {
+            inner: MockBroadcaster::new("validator-1".to_string(), 3),
+            fail_first: 2,
+            lossy_next: 0,
+            attempts: std::sync::atomic::AtomicU64::new(0),
+        });
+        let policy = RetryPolicy {
+            max_attempts: 4,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_millis(200),
+        };
+        let broadcaster = RetryingBroadcaster::new(flaky.clone(), policy);
+        let stats = broadcaster.stats();
+
+        let anchor = setu_types::Anchor::new(
+            vec!["event-1".to_string()],
+            setu_vlc::VLCSnapshot::default(),
+            "state_root".to_string(),
+            None,
+            0,
+        );
+        let cf = ConsensusFrame::new(anchor, "validator-1".to_string());
+
+        // The inline attempt fails and hands off to the background task
+        assert!(broadcaster.broadcast_cf(&cf).await.is_err());
+        assert_eq!(stats.delivered(), 0);
+
+        // 50ms + 100ms of backoff covers the two retries needed
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(stats.delivered(), 1);
+        assert_eq!(stats.failed(), 0);
+        assert_eq!(stats.retries(), 2);
+        assert_eq!(flaky.inner.get_cf_broadcasts().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retrying_broadcaster_resends_when_a_peer_misses() {
+        let flaky = Arc::new(FlakyBroadcaster {
+            inner: MockBroadcaster::new("validator-1".to_string(), 3),
+            fail_first: 0,
+            lossy_next: 2,
+            attempts: std::sync::atomic::AtomicU64::new(0),
+        });
+        let policy = RetryPolicy {
+            max_attempts: 4,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_millis(200),
+        };
+        let broadcaster = RetryingBroadcaster::new(flaky.clone(), policy);
+        let stats = broadcaster.stats();
+
+        let anchor = setu_types::Anchor::new(
+            vec!["event-1".to_string()],
+            setu_vlc::VLCSnapshot::default(),
+            "state_root".to_string(),
+            None,
+            0,
+        );
+        let cf = ConsensusFrame::new(anchor, "validator-1".to_string());
+
+        // The transport answers Ok, but one peer never got the frame
+        let first = broadcaster.broadcast_cf(&cf).await.unwrap();
+        assert!(!first.all_succeeded());
+        assert_eq!(stats.delivered(), 0);
+
+        // Second attempt still misses the peer, the third reaches everyone
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(stats.delivered(), 1);
+        assert_eq!(stats.failed(), 0);
+        assert_eq!(stats.retries(), 2);
+        assert_eq!(flaky.attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retrying_broadcaster_gives_up_after_max_attempts() {
+        let mock = Arc::new(MockBroadcaster::new("validator-1".to_string(), 3));
+        mock.set_simulate_failure(true);
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(10),
+        };
+        let broadcaster = RetryingBroadcaster::new(mock, policy);
+        let stats = broadcaster.stats();
+
+        let vote = Vote::new("validator-1".to_string(), "cf-1".to_string(), true);
+        assert!(broadcaster.broadcast_vote(&vote).await.is_err());
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(stats.delivered(), 0);
+        assert_eq!(stats.failed(), 1);
+        assert_eq!(stats.retries(), 2);
+    }
+
+    #[test]
+    fn test_retry_policy_backoff_doubles_up_to_cap() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff(40), Duration::from_millis(500));
+    }
+
+    // ---- Simulated network model ----
+
+    use crate::engine::ConsensusEngine;
+    use crate::validator_set::ValidatorSet;
+    use setu_types::{ConsensusConfig, EventType, NodeInfo, ValidatorInfo};
+    use setu_vlc::{VLCSnapshot, VectorClock};
+
+    const IDS: [&str; 3] = ["v1", "v2", "v3"];
+
+    fn seeded(seed: u64) -> Arc<dyn SelectionRng> {
+        Arc::new(setu_router_core::SeededSelectionRng::new(seed))
+    }
+
+    fn three_validator_set() -> ValidatorSet {
+        let mut set = ValidatorSet::new();
+        for (i, id) in IDS.iter().enumerate() {
+            let node = NodeInfo::new_validator(id.to_string(), "127.0.0.1".to_string(), 8000 + i as u16);
+            set.add_validator(ValidatorInfo::new(node, false));
+        }
+        set
+    }
+
+    /// Run one consensus round across three engines connected by simulated
+    /// networks. Dropped messages are retransmitted up to `max_retries` times.
+    /// Returns whether the leader finalized the CF.
+    async fn simulate_round(seed: u64, drop_rate: f64, max_retries: usize) -> bool {
+        let config = ConsensusConfig {
+            vlc_delta_threshold: 1,
+            min_events_per_cf: 1,
+            max_events_per_cf: 1000,
+            cf_timeout_ms: 60_000,
+            validator_count: 3,
+            ..Default::default()
+        };
+
+        let mut engines = Vec::new();
+        let mut broadcasters = Vec::new();
+        for (i, id) in IDS.iter().enumerate() {
+            let engine = ConsensusEngine::new(config, id.to_string(), three_validator_set());
+            let broadcaster = Arc::new(
+                MockBroadcaster::new(id.to_string(), 2).with_network_model(seeded(seed + i as u64), drop_rate, 0),
+            );
+            engine.set_broadcaster(broadcaster.clone()).await;
+            engines.push(engine);
+            broadcasters.push(broadcaster);
+        }
+
+        let event = Event::new(
+            EventType::System,
+            vec![],
+            VLCSnapshot {
+                vector_clock: VectorClock::new(),
+                logical_time: 1,
+                physical_time: 0,
+            },
+            "v1".to_string(),
+        );
+        for follower in &engines[1..] {
+            follower.receive_event_from_network(event.clone()).await.unwrap();
+        }
+        // Leader adds the event, creates the CF and broadcasts CF + self-vote.
+        engines[0].add_event(event).await.unwrap();
+
+        let mut cursors = vec![0usize; IDS.len()];
+        let mut retry_cursors = vec![0usize; IDS.len()];
+        let mut leader_finalized = false;
+        for _ in 0..=max_retries {
+            // Pump delivered messages to every other validator until quiescent.
+            loop {
+                let mut progressed = false;
+                for sender in 0..IDS.len() {
+                    let log = broadcasters[sender].get_delivery_log();
+                    let fresh: Vec<_> = log[cursors[sender]..].to_vec();
+                    cursors[sender] = log.len();
+                    for delivery in fresh.into_iter().filter(|d| d.delivered) {
+                        progressed = true;
+                        for (receiver, engine) in engines.iter().enumerate() {
+                            if receiver == sender {
+                                continue;
+                            }
+                            let outcome = match &delivery.message {
+                                SimulatedMessage::Cf(cf) => engine.receive_cf(cf.clone()).await.ok(),
+                                SimulatedMessage::Vote(vote) => engine.receive_vote(vote.clone()).await.ok(),
+                                _ => None,
+                            };
+                            if receiver == 0 && matches!(outcome, Some((true, _))) {
+                                leader_finalized = true;
+                            }
+                        }
+                    }
+                }
+                if !progressed {
+                    break;
+                }
+            }
+
+            if leader_finalized {
+                break;
+            }
+
+            // Sender-side retry: retransmit each drop seen since the last pass.
+            for (sender, broadcaster) in broadcasters.iter().enumerate() {
+                let log = broadcaster.get_delivery_log();
+                let dropped: Vec<_> = log[retry_cursors[sender]..]
+                    .iter()
+                    .filter(|d| !d.delivered)
+                    .map(|d| d.message.clone())
+                    .collect();
+                retry_cursors[sender] = log.len();
+                for message in dropped {
+                    let _ = broadcaster.retransmit(&message).await;
+                }
+            }
+        }
+
+        leader_finalized
+    }
+
+    #[tokio::test]
+    async fn test_network_model_is_reproducible_for_seed() {
+        let pattern = |seed: u64| async move {
+            let broadcaster = MockBroadcaster::new("v1".to_string(), 2).with_network_model(seeded(seed), 0.5, 0);
+            for i in 0..32 {
+                let vote = Vote::new("v1".to_string(), format!("cf-{}", i), true);
+                let _ = broadcaster.broadcast_vote(&vote).await;
+            }
+            broadcaster
+                .get_delivery_log()
+                .iter()
+                .map(|d| (d.seq, d.delivered))
+                .collect::<Vec<_>>()
+        };
+
+        let first = pattern(42).await;
+        let second = pattern(42).await;
+        assert_eq!(first, second, "same seed must reproduce the same delivery pattern");
+        assert!(first.iter().any(|(_, delivered)| *delivered));
+        assert!(first.iter().any(|(_, delivered)| !*delivered));
+    }
+
+    #[tokio::test]
+    async fn test_network_model_drops_are_not_recorded() {
+        let broadcaster = MockBroadcaster::new("v1".to_string(), 2).with_network_model(seeded(7), 1.0, 0);
+        let vote = Vote::new("v1".to_string(), "cf-1".to_string(), true);
+
+        let result = broadcaster.broadcast_vote(&vote).await;
+        assert!(matches!(result, Err(BroadcastError::NetworkError(_))));
+        assert!(broadcaster.get_vote_broadcasts().is_empty());
+        assert_eq!(broadcaster.get_dropped().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_network_model_applies_latency() {
+        let broadcaster = MockBroadcaster::new("v1".to_string(), 2).with_network_model(seeded(1), 0.0, 250);
+        let vote = Vote::new("v1".to_string(), "cf-1".to_string(), true);
+
+        let start = tokio::time::Instant::now();
+        broadcaster.broadcast_vote(&vote).await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(250));
+        assert_eq!(broadcaster.get_vote_broadcasts().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_consensus_finalizes_with_half_drops_and_retries() {
+        assert!(
+            simulate_round(2024, 0.5, 20).await,
+            "with retries, a 50% drop rate must not prevent finalization"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_consensus_stalls_with_total_drops() {
+        assert!(
+            !simulate_round(2024, 1.0, 20).await,
+            "with every message dropped, consensus cannot finalize"
+        );
+    }
 }
@@ -7,6 +7,7 @@
 //! in the Setu consensus protocol.
 
 use setu_types::{Event, EventId, EventStatus};
+use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet, VecDeque};
 
 /// DAG (Directed Acyclic Graph) for storing events
@@ -35,11 +36,35 @@ pub struct Dag {
     
     /// Events pending confirmation
     pending: HashSet<EventId>,
+
+    /// Parent-count distribution of events in the DAG
+    fan_in: FanHistogram,
+
+    /// Child-count distribution of events in the DAG
+    fan_out: FanHistogram,
+
+    /// Depth of the latest anchor finalized over this DAG
+    anchor_depth: u64,
+
+    /// Anchor depth at which each event entered the DAG (for orphan detection)
+    added_at_anchor: HashMap<EventId, u64>,
+
+    /// Most parents an event may reference (0 = unlimited)
+    max_parents: usize,
+
+    /// Public keys of validators whose signed stamps order by logical time
+    stamp_keys: HashMap<String, Vec<u8>>,
 }
 
 impl Dag {
     /// Create a new empty DAG
     pub fn new() -> Self {
+        Self::with_max_parents(0)
+    }
+
+    /// Create a new empty DAG that rejects events with more than
+    /// `max_parents` parents (0 = unlimited)
+    pub fn with_max_parents(max_parents: usize) -> Self {
         Self {
             events: HashMap::new(),
             children: HashMap::new(),
@@ -47,11 +72,30 @@ impl Dag {
             tips: HashSet::new(),
             max_depth: 0,
             pending: HashSet::new(),
+            fan_in: FanHistogram::default(),
+            fan_out: FanHistogram::default(),
+            anchor_depth: 0,
+            added_at_anchor: HashMap::new(),
+            max_parents,
+            stamp_keys: HashMap::new(),
         }
     }
 
+    /// Replace the validator keys used to verify logical-time stamps
+    /// (validator id -> ed25519 public key)
+    pub fn set_stamp_keys(&mut self, keys: HashMap<String, Vec<u8>>) {
+        self.stamp_keys = keys;
+    }
+
+    /// The parent cap this DAG enforces (0 = unlimited)
+    pub fn max_parents(&self) -> usize {
+        self.max_parents
+    }
+
     /// Add an event to the DAG
     ///
+    /// Rejects events with more parents than the configured cap, and events
+    /// whose VLC does not happen after every parent's VLC.
     /// Returns the event ID if successful
     pub fn add_event(&mut self, event: Event) -> Result<EventId, DagError> {
         let event_id = event.id.clone();
@@ -60,6 +104,8 @@ impl Dag {
         if self.events.contains_key(&event_id) {
             return Err(DagError::DuplicateEvent(event_id));
         }
+        self.check_parent_count(&event)?;
+        self.check_vlc_order(&event)?;
 
         // Calculate depth based on parents
         let depth = if event.parent_ids.is_empty() {
@@ -78,23 +124,77 @@ impl Dag {
 
         // Update children relationships and remove parents from tips
         for parent_id in &event.parent_ids {
-            self.children
-                .entry(parent_id.clone())
-                .or_insert_with(HashSet::new)
-                .insert(event_id.clone());
+            self.link_child(parent_id, &event_id);
             self.tips.remove(parent_id);
         }
+        self.fan_in.record(event.parent_ids.len());
+        self.fan_out.record(0);
 
         // Store the event
         self.events.insert(event_id.clone(), event);
         self.depths.insert(event_id.clone(), depth);
         self.tips.insert(event_id.clone());
         self.pending.insert(event_id.clone());
+        self.added_at_anchor.insert(event_id.clone(), self.anchor_depth);
         self.max_depth = self.max_depth.max(depth);
 
         Ok(event_id)
     }
 
+    fn check_parent_count(&self, event: &Event) -> Result<(), DagError> {
+        if self.max_parents > 0 && event.parent_ids.len() > self.max_parents {
+            return Err(DagError::TooManyParents {
+                event_id: event.id.clone(),
+                count: event.parent_ids.len(),
+                max: self.max_parents,
+            });
+        }
+        Ok(())
+    }
+
+    /// Reject events whose VLC does not follow a parent's VLC
+    ///
+    /// Only parents still in the DAG are checked; GC'd parents in the cache
+    /// or store were ordered when they were admitted.
+    pub fn check_vlc_order(&self, event: &Event) -> Result<(), DagError> {
+        for parent_id in &event.parent_ids {
+            if let Some(parent) = self.events.get(parent_id) {
+                if !self.happens_after(event, parent) {
+                    return Err(DagError::InvalidVlc {
+                        event_id: event.id.clone(),
+                        parent_id: parent_id.clone(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether `event`'s VLC strictly follows `parent`'s
+    ///
+    /// - Greater vector clock: follows.
+    /// - Equal, less or concurrent: follows only if the event is a validator
+    ///   stamp signed by its creator (see `set_stamp_keys`) and its logical
+    ///   time is strictly greater. Validators assign logical time after
+    ///   merging the parents', so a verified stamp can be trusted to order by
+    ///   it; anyone else could pick an arbitrary logical time.
+    fn happens_after(&self, event: &Event, parent: &Event) -> bool {
+        let (child, parent) = (&event.vlc_snapshot, &parent.vlc_snapshot);
+        match child.compare(parent) {
+            Some(Ordering::Greater) => true,
+            Some(Ordering::Equal) | Some(Ordering::Less) | None => {
+                self.is_verified_stamp(event) && child.logical_time > parent.logical_time
+            }
+        }
+    }
+
+    /// Whether `event` is signed by a validator with a registered stamp key
+    fn is_verified_stamp(&self, event: &Event) -> bool {
+        self.stamp_keys
+            .get(&event.creator)
+            .is_some_and(|key| !key.is_empty() && event.verify_signature(key))
+    }
+
     /// Get an event by ID
     pub fn get_event(&self, event_id: &EventId) -> Option<&Event> {
         self.events.get(event_id)
@@ -120,6 +220,52 @@ impl Dag {
         self.tips.iter().cloned().collect()
     }
 
+    /// Choose parents for a new event from the current tips
+    ///
+    /// Used when an event has no object dependencies to derive parents from.
+    /// Never returns more than the DAG's parent cap; when a strategy picks
+    /// more, the most recent ones are kept. The result is sorted so that
+    /// every validator picks the same parents for the same DAG.
+    pub fn select_parents(&self, strategy: ParentSelectionStrategy) -> Vec<EventId> {
+        let mut parents: Vec<EventId> = match strategy {
+            ParentSelectionStrategy::AllTips => self.get_tips(),
+            ParentSelectionStrategy::NMostRecent { n } => self.most_recent(self.tips.iter(), n),
+            ParentSelectionStrategy::ByVlcFrontier => {
+                let tips: Vec<&Event> =
+                    self.tips.iter().filter_map(|id| self.events.get(id)).collect();
+                // Drop tips whose clock is already covered by another tip
+                tips.iter()
+                    .filter(|tip| {
+                        !tips.iter().any(|other| {
+                            tip.vlc_snapshot
+                                .vector_clock
+                                .happens_before(&other.vlc_snapshot.vector_clock)
+                        })
+                    })
+                    .map(|tip| tip.id.clone())
+                    .collect()
+            }
+        };
+        if self.max_parents > 0 && parents.len() > self.max_parents {
+            parents = self.most_recent(parents.iter(), self.max_parents);
+        }
+        parents.sort();
+        parents
+    }
+
+    /// The `n` most recent of `ids`: highest logical time, then deepest
+    fn most_recent<'a>(&self, ids: impl Iterator<Item = &'a EventId>, n: usize) -> Vec<EventId> {
+        let mut events: Vec<&Event> = ids.filter_map(|id| self.events.get(id)).collect();
+        events.sort_by(|a, b| {
+            b.vlc_snapshot
+                .logical_time
+                .cmp(&a.vlc_snapshot.logical_time)
+                .then_with(|| self.depths.get(&b.id).cmp(&self.depths.get(&a.id)))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        events.into_iter().take(n).map(|e| e.id.clone()).collect()
+    }
+
     /// Get the maximum depth in the DAG
     pub fn max_depth(&self) -> u64 {
         self.max_depth
@@ -130,11 +276,34 @@ impl Dag {
         self.events.len()
     }
 
+    /// Distribution of parent counts over the events in the DAG
+    pub fn fan_in(&self) -> &FanHistogram {
+        &self.fan_in
+    }
+
+    /// Distribution of child counts over the events in the DAG
+    pub fn fan_out(&self) -> &FanHistogram {
+        &self.fan_out
+    }
+
+    /// Record `child` under `parent`, keeping the fan-out histogram in step
+    fn link_child(&mut self, parent: &EventId, child: &EventId) {
+        let children = self.children.entry(parent.clone()).or_default();
+        if children.insert(child.clone()) {
+            self.fan_out.shift_up(children.len() - 1);
+        }
+    }
+
     /// Get the number of pending events
     pub fn get_pending_count(&self) -> usize {
         self.pending.len()
     }
 
+    /// Whether `event_id` is in this DAG and not yet finalized
+    pub fn is_pending(&self, event_id: &EventId) -> bool {
+        self.pending.contains(event_id)
+    }
+
     /// Snapshot all currently-pending event references.
     ///
     /// An event is "pending" iff it has been added to this DAG but has not
@@ -246,16 +415,15 @@ impl Dag {
         if self.events.contains_key(&event_id) {
             return Err(DagError::DuplicateEvent(event_id));
         }
+        self.check_parent_count(&event)?;
+        self.check_vlc_order(&event)?;
 
         // Update children relationships (only for parents still in DAG)
         // Note: Parents may have been GC'd (now in Cache/Store)
         for parent_id in &event.parent_ids {
             // Only update if parent is still in DAG
             if self.events.contains_key(parent_id) {
-                self.children
-                    .entry(parent_id.clone())
-                    .or_insert_with(HashSet::new)
-                    .insert(event_id.clone());
+                self.link_child(parent_id, &event_id);
                 // Remove from tips (no longer a leaf)
                 self.tips.remove(parent_id);
             }
@@ -263,12 +431,15 @@ impl Dag {
             // - Don't need to update children (GC'd events don't maintain children)
             // - This is expected behavior, cross-CF refs are safe via depth_diff limit
         }
+        self.fan_in.record(event.parent_ids.len());
+        self.fan_out.record(0);
 
         // Store the event
         self.events.insert(event_id.clone(), event);
         self.depths.insert(event_id.clone(), depth);
         self.tips.insert(event_id.clone());
         self.pending.insert(event_id.clone());
+        self.added_at_anchor.insert(event_id.clone(), self.anchor_depth);
         self.max_depth = self.max_depth.max(depth);
 
         Ok(event_id)
@@ -298,18 +469,23 @@ impl Dag {
         self.depths.remove(event_id);
         self.tips.remove(event_id);
         self.pending.remove(event_id);
+        self.added_at_anchor.remove(event_id);
 
         // Remove self from parent's children lists
         for parent_id in &event.parent_ids {
             if let Some(children) = self.children.get_mut(parent_id) {
-                children.remove(event_id);
+                if children.remove(event_id) {
+                    self.fan_out.shift_down(children.len() + 1);
+                }
                 // Note: Don't automatically add parent to tips
                 // Parent may be finalized, shouldn't become a tip
             }
         }
 
         // Remove own children index
-        self.children.remove(event_id);
+        let child_count = self.children.remove(event_id).map_or(0, |c| c.len());
+        self.fan_in.unrecord(event.parent_ids.len());
+        self.fan_out.unrecord(child_count);
 
         Some(event.parent_ids)
     }
@@ -413,6 +589,104 @@ impl Dag {
         false
     }
 
+    /// Parents of `event_ids` that are neither in the set nor finalized
+    ///
+    /// An anchor must be causally closed: every parent of an included event
+    /// is either included as well or was finalized by an earlier anchor.
+    /// Finalized parents may already be GC'd, so only parents still pending
+    /// in this DAG count as open. Returned sorted and deduplicated.
+    pub fn unclosed_parents(&self, event_ids: &[EventId]) -> Vec<EventId> {
+        let included: HashSet<&EventId> = event_ids.iter().collect();
+        let mut open: Vec<EventId> = event_ids
+            .iter()
+            .filter_map(|id| self.events.get(id))
+            .flat_map(|event| event.parent_ids.iter())
+            .filter(|parent| !included.contains(parent) && self.pending.contains(*parent))
+            .cloned()
+            .collect();
+        open.sort();
+        open.dedup();
+        open
+    }
+
+    /// Record that an anchor at `depth` was finalized
+    ///
+    /// Idempotent: the DAG keeps the highest depth seen, so reporting the
+    /// same anchor from several finalization paths does not age events.
+    pub fn observe_anchor_depth(&mut self, depth: u64) {
+        self.anchor_depth = self.anchor_depth.max(depth);
+    }
+
+    /// Pending events that have outlived `threshold` finalized anchors
+    ///
+    /// An event that is still pending after that many anchors have been
+    /// finalized since it entered the DAG was never folded (e.g. its creator
+    /// left the validator set or it lost a conflict) and likely never will
+    /// be. Returned sorted by event ID.
+    pub fn orphaned_events(&self, threshold: u64) -> Vec<OrphanedEvent> {
+        let mut orphans: Vec<OrphanedEvent> = self
+            .pending
+            .iter()
+            .filter_map(|id| {
+                let added_at = *self.added_at_anchor.get(id)?;
+                let anchors_missed = self.anchor_depth.saturating_sub(added_at);
+                (anchors_missed >= threshold).then(|| OrphanedEvent {
+                    event_id: id.clone(),
+                    depth: self.depths.get(id).copied().unwrap_or(0),
+                    added_at_anchor: added_at,
+                    anchors_missed,
+                })
+            })
+            .collect();
+        orphans.sort_by(|a, b| a.event_id.cmp(&b.event_id));
+        orphans
+    }
+
+    /// Render the DAG as Graphviz DOT for offline analysis
+    ///
+    /// Exports at most `max_events` events, keeping the deepest ones (ties
+    /// broken by event ID). Nodes are labeled with a short event ID, the
+    /// creator and the depth; edges run child → parent and are only drawn
+    /// when both ends are exported.
+    pub fn to_dot(&self, max_events: usize) -> String {
+        let mut ids: Vec<(&EventId, u64)> = self
+            .events
+            .keys()
+            .map(|id| (id, self.depths.get(id).copied().unwrap_or(0)))
+            .collect();
+        ids.sort_by(|(a, da), (b, db)| db.cmp(da).then_with(|| a.cmp(b)));
+        ids.truncate(max_events);
+        // Oldest first so the output reads top-down from genesis
+        ids.reverse();
+        let exported: HashSet<&EventId> = ids.iter().map(|(id, _)| *id).collect();
+
+        let mut dot = String::from("digraph dag {\n    rankdir=BT;\n");
+        for (id, depth) in &ids {
+            let event = &self.events[*id];
+            let short_id: String = id.chars().take(8).collect();
+            dot.push_str(&format!(
+                "    \"{}\" [label=\"{}\\n{}\\ndepth {}\"];\n",
+                dot_escape(id),
+                dot_escape(&short_id),
+                dot_escape(&event.creator),
+                depth
+            ));
+        }
+        for (id, _) in &ids {
+            let mut parents: Vec<&EventId> = self.events[*id]
+                .parent_ids
+                .iter()
+                .filter(|parent| exported.contains(parent))
+                .collect();
+            parents.sort();
+            for parent in parents {
+                dot.push_str(&format!("    \"{}\" -> \"{}\";\n", dot_escape(id), dot_escape(parent)));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
     /// Get all ancestors of an event
     pub fn get_ancestors(&self, event_id: &EventId) -> HashSet<EventId> {
         let mut ancestors = HashSet::new();
@@ -459,12 +733,89 @@ impl Dag {
     }
 }
 
+/// Default number of finalized anchors after which a pending event is
+/// reported by [`Dag::orphaned_events`]
+pub const DEFAULT_ORPHAN_ANCHOR_THRESHOLD: u64 = 3;
+
+/// A pending event no anchor has folded (see [`Dag::orphaned_events`])
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrphanedEvent {
+    pub event_id: EventId,
+    /// DAG depth of the event
+    pub depth: u64,
+    /// Latest finalized anchor depth when the event entered the DAG
+    pub added_at_anchor: u64,
+    /// Anchors finalized since then without including the event
+    pub anchors_missed: u64,
+}
+
 impl Default for Dag {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Escape a string for use inside a quoted DOT identifier or label
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Number of buckets in a [`FanHistogram`]; the last one is open-ended
+pub const FAN_HISTOGRAM_BUCKETS: usize = 16;
+
+/// Histogram of per-event edge counts (parents or children)
+///
+/// Bucket `i` counts events with exactly `i` edges, except the last bucket,
+/// which counts every event with `FAN_HISTOGRAM_BUCKETS - 1` or more. The
+/// `Dag` updates it incrementally as events are added and removed, so
+/// reading it never walks the graph.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FanHistogram {
+    buckets: [u64; FAN_HISTOGRAM_BUCKETS],
+}
+
+impl FanHistogram {
+    fn bucket(edges: usize) -> usize {
+        edges.min(FAN_HISTOGRAM_BUCKETS - 1)
+    }
+
+    fn record(&mut self, edges: usize) {
+        self.buckets[Self::bucket(edges)] += 1;
+    }
+
+    fn unrecord(&mut self, edges: usize) {
+        let bucket = &mut self.buckets[Self::bucket(edges)];
+        *bucket = bucket.saturating_sub(1);
+    }
+
+    /// An event went from `edges` to `edges + 1`
+    fn shift_up(&mut self, edges: usize) {
+        self.unrecord(edges);
+        self.record(edges + 1);
+    }
+
+    /// An event went from `edges` to `edges - 1`
+    fn shift_down(&mut self, edges: usize) {
+        self.unrecord(edges);
+        self.record(edges - 1);
+    }
+
+    /// Per-bucket event counts
+    pub fn buckets(&self) -> &[u64] {
+        &self.buckets
+    }
+
+    /// Number of events with `edges` edges (or more, for the last bucket)
+    pub fn count(&self, edges: usize) -> u64 {
+        self.buckets[Self::bucket(edges)]
+    }
+
+    /// Total number of events recorded
+    pub fn total(&self) -> u64 {
+        self.buckets.iter().sum()
+    }
+}
+
 /// Errors that can occur when working with the DAG
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum DagError {
@@ -479,6 +830,48 @@ pub enum DagError {
 
     #[error("Invalid event: {0}")]
     InvalidEvent(String),
+
+    #[error("Event {event_id} VLC does not happen after parent {parent_id}")]
+    InvalidVlc { event_id: EventId, parent_id: EventId },
+
+    #[error("Event {event_id} has {count} parents, more than the maximum {max}")]
+    TooManyParents { event_id: EventId, count: usize, max: usize },
+}
+
+/// How parents are chosen for an event that has no object dependencies
+///
+/// Wider parent sets fold more of the DAG into each event but make events
+/// larger; narrower ones keep events small at the cost of leaving tips open
+/// for longer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParentSelectionStrategy {
+    /// Reference every current tip
+    #[default]
+    AllTips,
+    /// Reference the `n` most recent tips (by logical time, then depth)
+    NMostRecent { n: usize },
+    /// Reference only tips on the VLC frontier, i.e. tips whose vector clock
+    /// does not happen-before another tip's
+    ByVlcFrontier,
+}
+
+impl std::str::FromStr for ParentSelectionStrategy {
+    type Err = String;
+
+    /// Parse `all_tips`, `n_most_recent:<n>` or `vlc_frontier`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "all_tips" => Ok(Self::AllTips),
+            "vlc_frontier" => Ok(Self::ByVlcFrontier),
+            other => match other.strip_prefix("n_most_recent:") {
+                Some(n) => match n.parse::<usize>() {
+                    Ok(n) if n > 0 => Ok(Self::NMostRecent { n }),
+                    _ => Err(format!("invalid parent count: {}", n)),
+                },
+                None => Err(format!("unknown parent selection strategy: {}", other)),
+            },
+        }
+    }
 }
 
 /// Statistics from a GC operation
@@ -494,13 +887,19 @@ pub struct GCStats {
 mod tests {
     use super::*;
     use setu_vlc::VLCSnapshot;
+    use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 
+    /// Each call ticks a shared test clock, so an event's VLC is greater
+    /// than that of every event built before it.
     fn create_event(id: &str, parents: Vec<&str>, creator: &str) -> Event {
+        static TICK: AtomicU64 = AtomicU64::new(0);
         let parent_ids: Vec<EventId> = parents.iter().map(|s| s.to_string()).collect();
+        let mut vlc = VLCSnapshot::new();
+        vlc.vector_clock.set("test", TICK.fetch_add(1, AtomicOrdering::Relaxed) + 1);
         let mut event = Event::new(
             setu_types::EventType::Transfer,
             parent_ids,
-            VLCSnapshot::new(),
+            vlc,
             creator.to_string(),
         );
         // Override ID for testing
@@ -657,4 +1056,338 @@ mod tests {
         assert!(ids.contains("g"));
         assert!(!ids.contains("e1"));
     }
+
+    // ========================================================================
+    // Parent selection
+    // ========================================================================
+
+    fn create_event_with_clock(
+        id: &str,
+        parents: Vec<&str>,
+        clock: &[(&str, u64)],
+        logical_time: u64,
+    ) -> Event {
+        let mut event = create_event(id, parents, "node1");
+        for (node, time) in clock {
+            event.vlc_snapshot.vector_clock.set(node, *time);
+        }
+        event.vlc_snapshot.logical_time = logical_time;
+        event
+    }
+
+    /// g → a, g → b, g → c → d; tips are a, b and d.
+    /// a's clock happens-before b's; d is concurrent with both.
+    fn dag_with_several_tips() -> Dag {
+        several_tips_in(Dag::new())
+    }
+
+    fn several_tips_in(mut dag: Dag) -> Dag {
+        dag.add_event(create_event("g", vec![], "node1")).unwrap();
+        dag.add_event(create_event_with_clock("a", vec!["g"], &[("n1", 1)], 1)).unwrap();
+        dag.add_event(create_event_with_clock("b", vec!["g"], &[("n1", 2)], 2)).unwrap();
+        dag.add_event(create_event_with_clock("c", vec!["g"], &[("n2", 1)], 1)).unwrap();
+        dag.add_event(create_event_with_clock("d", vec!["c"], &[("n2", 2)], 2)).unwrap();
+        dag
+    }
+
+    #[test]
+    fn test_select_parents_all_tips() {
+        let dag = dag_with_several_tips();
+        assert_eq!(
+            dag.select_parents(ParentSelectionStrategy::AllTips),
+            vec!["a".to_string(), "b".to_string(), "d".to_string()]
+        );
+        assert_eq!(ParentSelectionStrategy::default(), ParentSelectionStrategy::AllTips);
+    }
+
+    #[test]
+    fn test_select_parents_n_most_recent() {
+        let dag = dag_with_several_tips();
+
+        // b and d tie on logical time; d is deeper so it ranks first
+        assert_eq!(
+            dag.select_parents(ParentSelectionStrategy::NMostRecent { n: 1 }),
+            vec!["d".to_string()]
+        );
+        assert_eq!(
+            dag.select_parents(ParentSelectionStrategy::NMostRecent { n: 2 }),
+            vec!["b".to_string(), "d".to_string()]
+        );
+        // Asking for more than there are tips returns all of them
+        assert_eq!(dag.select_parents(ParentSelectionStrategy::NMostRecent { n: 10 }).len(), 3);
+    }
+
+    #[test]
+    fn test_select_parents_by_vlc_frontier() {
+        let dag = dag_with_several_tips();
+
+        // a happens-before b, so only b and the concurrent d are on the frontier
+        assert_eq!(
+            dag.select_parents(ParentSelectionStrategy::ByVlcFrontier),
+            vec!["b".to_string(), "d".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_add_event_rejects_vlc_not_after_parent() {
+        let mut dag = dag_with_several_tips();
+
+        // Concurrent with parent b
+        let concurrent = create_event_with_clock("e", vec!["b"], &[("n2", 3)], 3);
+        match dag.add_event(concurrent) {
+            Err(DagError::InvalidVlc { event_id, parent_id }) => {
+                assert_eq!(event_id, "e");
+                assert_eq!(parent_id, "b");
+            }
+            other => panic!("expected InvalidVlc, got {:?}", other),
+        }
+
+        // Causally before parent b
+        let before = create_event_with_clock("f", vec!["b"], &[("n1", 1)], 3);
+        assert!(matches!(dag.add_event(before), Err(DagError::InvalidVlc { .. })));
+
+        // Rejected events leave the DAG untouched
+        assert!(!dag.contains(&"e".to_string()));
+        assert!(!dag.contains(&"f".to_string()));
+        assert_eq!(dag.node_count(), 5);
+        assert_eq!(dag.get_tips().len(), 3);
+    }
+
+    #[test]
+    fn test_add_event_accepts_vlc_after_all_parents() {
+        let mut dag = dag_with_several_tips();
+
+        let merged = create_event_with_clock("e", vec!["b", "d"], &[("n1", 2), ("n2", 3)], 3);
+        assert_eq!(dag.add_event(merged).unwrap(), "e");
+        assert_eq!(dag.get_depth(&"e".to_string()), Some(3));
+    }
+
+    #[test]
+    fn test_signed_validator_stamp_orders_by_strict_logical_time() {
+        let validator_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let mut dag = dag_with_several_tips();
+        dag.set_stamp_keys(HashMap::from([(
+            "validator-1".to_string(),
+            validator_key.verifying_key().as_bytes().to_vec(),
+        )]));
+        let stamped = |id: &str, logical_time: u64, signed: bool| {
+            let mut event = create_event(id, vec!["b"], "validator-1");
+            event.vlc_snapshot = VLCSnapshot::for_node("validator-1".to_string());
+            event.vlc_snapshot.logical_time = logical_time;
+            if signed {
+                event.sign(&validator_key.to_bytes()).unwrap();
+            }
+            event
+        };
+
+        // No clock ticks, so b's clock is never less than the stamp's
+        assert!(matches!(dag.add_event(stamped("e", 1, true)), Err(DagError::InvalidVlc { .. })));
+        assert!(matches!(dag.add_event(stamped("e", 2, true)), Err(DagError::InvalidVlc { .. })));
+        assert!(matches!(dag.add_event(stamped("e", 3, false)), Err(DagError::InvalidVlc { .. })));
+        assert_eq!(dag.add_event(stamped("f", 3, true)).unwrap(), "f");
+    }
+
+    #[test]
+    fn test_stamp_from_unknown_creator_is_not_trusted() {
+        let validator_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let mut dag = dag_with_several_tips();
+
+        let mut event = create_event("e", vec!["b"], "validator-1");
+        event.vlc_snapshot = VLCSnapshot::for_node("validator-1".to_string());
+        event.vlc_snapshot.logical_time = 10;
+        event.sign(&validator_key.to_bytes()).unwrap();
+
+        // Signed, but the creator has no stamp key registered
+        assert!(matches!(dag.add_event(event), Err(DagError::InvalidVlc { .. })));
+    }
+
+    #[test]
+    fn test_equal_clock_does_not_follow_parent() {
+        let mut dag = dag_with_several_tips();
+
+        // Same clock as b, even with a higher logical time
+        let mut same = create_event("e", vec!["b"], "node1");
+        same.vlc_snapshot = dag.get_event(&"b".to_string()).unwrap().vlc_snapshot.clone();
+        same.vlc_snapshot.logical_time = 5;
+        assert!(matches!(dag.add_event(same), Err(DagError::InvalidVlc { .. })));
+    }
+
+    #[test]
+    fn test_add_event_with_depth_checks_vlc_of_dag_parents() {
+        let mut dag = dag_with_several_tips();
+
+        let concurrent = create_event_with_clock("e", vec!["b"], &[("n2", 3)], 3);
+        assert!(matches!(
+            dag.add_event_with_depth(concurrent, 3),
+            Err(DagError::InvalidVlc { .. })
+        ));
+        assert!(!dag.contains(&"e".to_string()));
+
+        // Parents no longer in the DAG (GC'd) are not checked here
+        let gc_parent = create_event_with_clock("f", vec!["gone"], &[("n2", 3)], 3);
+        assert_eq!(dag.add_event_with_depth(gc_parent, 3).unwrap(), "f");
+    }
+
+    #[test]
+    fn test_add_event_enforces_max_parents() {
+        let mut dag = several_tips_in(Dag::with_max_parents(2));
+        let clock = [("n1", 2), ("n2", 3)];
+
+        let wide = create_event_with_clock("wide", vec!["a", "b", "d"], &clock, 3);
+        assert!(matches!(
+            dag.add_event(wide),
+            Err(DagError::TooManyParents { count: 3, max: 2, .. })
+        ));
+        assert!(!dag.contains(&"wide".to_string()));
+
+        let at_cap = create_event_with_clock("at_cap", vec!["b", "d"], &clock, 3);
+        assert_eq!(dag.add_event(at_cap).unwrap(), "at_cap");
+    }
+
+    #[test]
+    fn test_select_parents_respects_max_parents() {
+        let dag = several_tips_in(Dag::with_max_parents(2));
+
+        // Strategies that would pick all three tips keep the two most recent
+        for strategy in [
+            ParentSelectionStrategy::AllTips,
+            ParentSelectionStrategy::NMostRecent { n: 10 },
+        ] {
+            assert_eq!(dag.select_parents(strategy), vec!["b".to_string(), "d".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_select_parents_empty_dag() {
+        let dag = Dag::new();
+        for strategy in [
+            ParentSelectionStrategy::AllTips,
+            ParentSelectionStrategy::NMostRecent { n: 2 },
+            ParentSelectionStrategy::ByVlcFrontier,
+        ] {
+            assert!(dag.select_parents(strategy).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_parent_selection_strategy_from_str() {
+        assert_eq!("all_tips".parse(), Ok(ParentSelectionStrategy::AllTips));
+        assert_eq!("vlc_frontier".parse(), Ok(ParentSelectionStrategy::ByVlcFrontier));
+        assert_eq!(
+            "n_most_recent:3".parse(),
+            Ok(ParentSelectionStrategy::NMostRecent { n: 3 })
+        );
+        assert!("n_most_recent:0".parse::<ParentSelectionStrategy>().is_err());
+        assert!("random".parse::<ParentSelectionStrategy>().is_err());
+    }
+
+    // ========================================================================
+    // DOT export
+    // ========================================================================
+
+    #[test]
+    fn test_to_dot_lists_nodes_and_parent_edges() {
+        let mut dag = Dag::new();
+        dag.add_event(create_event("genesis-event", vec![], "node1")).unwrap();
+        dag.add_event(create_event("a", vec!["genesis-event"], "node2")).unwrap();
+        dag.add_event(create_event("b", vec!["genesis-event", "a"], "node1")).unwrap();
+
+        let dot = dag.to_dot(10);
+        assert!(dot.starts_with("digraph dag {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains("    \"genesis-event\" [label=\"genesis-\\nnode1\\ndepth 0\"];\n"));
+        assert!(dot.contains("    \"a\" [label=\"a\\nnode2\\ndepth 1\"];\n"));
+        assert!(dot.contains("    \"b\" [label=\"b\\nnode1\\ndepth 2\"];\n"));
+        assert!(dot.contains("    \"a\" -> \"genesis-event\";\n"));
+        assert!(dot.contains("    \"b\" -> \"a\";\n"));
+        assert!(dot.contains("    \"b\" -> \"genesis-event\";\n"));
+        assert_eq!(dot.matches(" -> ").count(), 3);
+
+        // Bounded export keeps the deepest events and drops edges leaving the set
+        let bounded = dag.to_dot(2);
+        assert!(!bounded.contains("\"genesis-event\" ["));
+        assert!(bounded.contains("    \"b\" -> \"a\";\n"));
+        assert_eq!(bounded.matches(" -> ").count(), 1);
+    }
+
+    // ========================================================================
+    // Fan-in / fan-out histograms
+    // ========================================================================
+
+    #[test]
+    fn test_fan_histograms_track_dag_shape() {
+        //        g
+        //      / | \
+        //     a  b  e
+        //      \ /
+        //       c
+        //       |
+        //       d
+        let mut dag = Dag::new();
+        dag.add_event(create_event("g", vec![], "n1")).unwrap();
+        dag.add_event(create_event("a", vec!["g"], "n1")).unwrap();
+        dag.add_event(create_event("b", vec!["g"], "n1")).unwrap();
+        dag.add_event(create_event("e", vec!["g"], "n1")).unwrap();
+        dag.add_event(create_event("c", vec!["a", "b"], "n1")).unwrap();
+        dag.add_event(create_event("d", vec!["c"], "n1")).unwrap();
+
+        assert_eq!(&dag.fan_in().buckets()[..3], &[1, 4, 1]);
+        assert_eq!(&dag.fan_out().buckets()[..4], &[2, 3, 0, 1]);
+        assert_eq!(dag.fan_in().total(), 6);
+        assert_eq!(dag.fan_out().total(), 6);
+
+        // Removing d drops its parent count and leaves c childless
+        dag.remove_event(&"d".to_string()).unwrap();
+        assert_eq!(&dag.fan_in().buckets()[..3], &[1, 3, 1]);
+        assert_eq!(&dag.fan_out().buckets()[..4], &[2, 2, 0, 1]);
+    }
+
+    #[test]
+    fn test_fan_histogram_last_bucket_is_open_ended() {
+        let mut dag = Dag::new();
+        dag.add_event(create_event("g", vec![], "n1")).unwrap();
+        for i in 0..FAN_HISTOGRAM_BUCKETS + 4 {
+            dag.add_event(create_event(&format!("c{}", i), vec!["g"], "n1")).unwrap();
+        }
+
+        assert_eq!(dag.fan_out().count(FAN_HISTOGRAM_BUCKETS - 1), 1);
+        assert_eq!(dag.fan_out().count(FAN_HISTOGRAM_BUCKETS + 4), 1);
+        assert_eq!(dag.fan_out().count(0), FAN_HISTOGRAM_BUCKETS as u64 + 4);
+    }
+
+    #[test]
+    fn test_unfolded_event_becomes_orphan_after_threshold() {
+        let mut dag = Dag::new();
+        dag.add_event(create_event("g", vec![], "n1")).unwrap();
+        dag.add_event(create_event("stranded", vec!["g"], "gone")).unwrap();
+
+        // Each anchor folds the fresh events but never the stranded one
+        for anchor in 1..=DEFAULT_ORPHAN_ANCHOR_THRESHOLD {
+            assert!(dag.orphaned_events(DEFAULT_ORPHAN_ANCHOR_THRESHOLD).is_empty());
+            let folded = format!("e{}", anchor);
+            dag.add_event(create_event(&folded, vec!["g"], "n1")).unwrap();
+            dag.finalize_events(&[folded]);
+            if anchor == 1 {
+                dag.finalize_events(&["g".to_string()]);
+            }
+            dag.observe_anchor_depth(anchor);
+            // A repeated report of the same anchor does not age events
+            dag.observe_anchor_depth(anchor);
+        }
+
+        let orphans = dag.orphaned_events(DEFAULT_ORPHAN_ANCHOR_THRESHOLD);
+        assert_eq!(
+            orphans,
+            vec![OrphanedEvent {
+                event_id: "stranded".to_string(),
+                depth: 1,
+                added_at_anchor: 0,
+                anchors_missed: DEFAULT_ORPHAN_ANCHOR_THRESHOLD,
+            }]
+        );
+
+        // Once folded it is no longer an orphan
+        dag.finalize_events(&["stranded".to_string()]);
+        assert!(dag.orphaned_events(DEFAULT_ORPHAN_ANCHOR_THRESHOLD).is_empty());
+    }
 }
@@ -11,10 +11,11 @@
 //! - Layer 2: Recent Cache (warm data, finalized metadata)
 //! - Layer 3: Event Store (cold data, persistent full events)
 
-use crate::dag::{Dag, DagError};
+use crate::dag::{Dag, DagError, FanHistogram};
 use crate::recent_cache::{FinalizedEventMeta, RecentEventCache, CacheStatsSnapshot};
 use setu_storage::EventStoreBackend;
 use setu_types::{Anchor, AnchorId, Event, EventId};
+use std::collections::{HashMap, VecDeque};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
@@ -39,6 +40,11 @@ pub struct DagManagerConfig {
     
     /// Maximum size of pending queue during warmup (default: 10,000)
     pub max_pending_queue_size: usize,
+    
+    /// Number of recent event content hashes kept for duplicate-by-content
+    /// detection (default: 0 = disabled)
+    /// See `Event::content_hash`; complements same-id `DuplicateEvent`.
+    pub content_dedup_window: usize,
 }
 
 impl Default for DagManagerConfig {
@@ -49,6 +55,7 @@ impl Default for DagManagerConfig {
             enable_disk_fallback: true,
             warmup_anchor_count: 10,
             max_pending_queue_size: 10_000,
+            content_dedup_window: 0,
         }
     }
 }
@@ -115,6 +122,12 @@ pub enum DagManagerError {
     #[error("Duplicate event: {0}")]
     DuplicateEvent(EventId),
     
+    #[error("Duplicate event content: {event_id} matches recent event {existing}")]
+    DuplicateContent {
+        event_id: EventId,
+        existing: EventId,
+    },
+    
     #[error("Warmup pending queue is full (max: {max_size})")]
     WarmupQueueFull { max_size: usize },
     
@@ -128,6 +141,51 @@ pub enum DagManagerError {
 /// Maximum retry count for TOCTOU issues
 const MAX_RETRY: usize = 3;
 
+/// Bounded FIFO index of recent event content hashes
+///
+/// Oldest entries are evicted once `capacity` is reached, so duplicates are
+/// only detected within the recent window.
+#[derive(Debug, Default)]
+struct ContentIndex {
+    by_hash: HashMap<[u8; 32], EventId>,
+    order: VecDeque<[u8; 32]>,
+    capacity: usize,
+}
+
+impl ContentIndex {
+    fn new(capacity: usize) -> Self {
+        Self { capacity, ..Default::default() }
+    }
+
+    /// Record `hash` for `event_id`, or return the other event already holding it
+    ///
+    /// `Ok(false)` means `event_id` itself already held the hash.
+    fn reserve(&mut self, hash: [u8; 32], event_id: &EventId) -> Result<bool, EventId> {
+        if let Some(existing) = self.by_hash.get(&hash) {
+            if existing != event_id {
+                return Err(existing.clone());
+            }
+            return Ok(false);
+        }
+        if self.order.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.by_hash.remove(&oldest);
+            }
+        }
+        self.by_hash.insert(hash, event_id.clone());
+        self.order.push_back(hash);
+        Ok(true)
+    }
+
+    /// Drop a reservation made for an event that was not added after all
+    fn release(&mut self, hash: &[u8; 32], event_id: &EventId) {
+        if self.by_hash.get(hash) == Some(event_id) {
+            self.by_hash.remove(hash);
+            self.order.retain(|h| h != hash);
+        }
+    }
+}
+
 /// DagManager: Three-layer storage manager
 ///
 /// Responsibilities:
@@ -166,6 +224,9 @@ pub struct DagManager {
     
     /// Events queued during warmup
     pending_queue: Mutex<Vec<Event>>,
+    
+    /// Recent content hashes (only used when `content_dedup_window > 0`)
+    content_index: parking_lot::Mutex<ContentIndex>,
 }
 
 impl DagManager {
@@ -178,6 +239,7 @@ impl DagManager {
         let recent_cache = Arc::new(Mutex::new(
             RecentEventCache::new(config.recent_cache_capacity)
         ));
+        let content_index = parking_lot::Mutex::new(ContentIndex::new(config.content_dedup_window));
         
         Self {
             dag,
@@ -187,6 +249,7 @@ impl DagManager {
             min_depth: AtomicU64::new(0),
             warming_up: AtomicBool::new(false),
             pending_queue: Mutex::new(Vec::new()),
+            content_index,
         }
     }
     
@@ -409,6 +472,34 @@ impl DagManager {
             }
         }
         
+        // Reject a resubmission of recent content under a new id. The hash is
+        // reserved up front so concurrent duplicates cannot both pass.
+        let mut reserved_hash = None;
+        if self.config.content_dedup_window > 0 {
+            let hash = event.content_hash();
+            match self.content_index.lock().reserve(hash, &event_id) {
+                Ok(newly_reserved) => {
+                    if newly_reserved {
+                        reserved_hash = Some(hash);
+                    }
+                }
+                Err(existing) => {
+                    return Err(DagManagerError::DuplicateContent { event_id, existing });
+                }
+            }
+        }
+        
+        let result = self.insert_event(event).await;
+        if let (Err(_), Some(hash)) = (&result, reserved_hash) {
+            self.content_index.lock().release(&hash, &event_id);
+        }
+        result
+    }
+    
+    /// Resolve parents and write the event to the DAG
+    async fn insert_event(&self, event: Event) -> Result<EventId, DagManagerError> {
+        let event_id = event.id.clone();
+        
         // Phase 1: Resolve all parents
         let resolved = self.resolve_parents(&event).await?;
         
@@ -652,6 +743,8 @@ impl DagManager {
             pending_count: dag.get_pending_count(),
             tip_count: dag.get_tips().len(),
             max_depth: dag.max_depth(),
+            fan_in: dag.fan_in().clone(),
+            fan_out: dag.fan_out().clone(),
         }
     }
 }
@@ -663,6 +756,10 @@ pub struct DagStatsSnapshot {
     pub pending_count: usize,
     pub tip_count: usize,
     pub max_depth: u64,
+    /// Parent-count distribution (see [`FanHistogram`])
+    pub fan_in: FanHistogram,
+    /// Child-count distribution (see [`FanHistogram`])
+    pub fan_out: FanHistogram,
 }
 
 #[cfg(test)]
@@ -671,14 +768,20 @@ mod tests {
     use setu_storage::EventStore;
     use setu_types::{EventType, VectorClock, VLCSnapshot};
 
+    /// Each call ticks a shared test clock, so an event's VLC is greater
+    /// than that of every event built before it.
     fn create_event(id: &str, parents: Vec<&str>, creator: &str) -> Event {
+        static TICK: AtomicU64 = AtomicU64::new(0);
         let parent_ids: Vec<EventId> = parents.iter().map(|s| s.to_string()).collect();
+        let tick = TICK.fetch_add(1, Ordering::Relaxed) + 1;
+        let mut vector_clock = VectorClock::new();
+        vector_clock.set("test", tick);
         let mut event = Event::new(
             EventType::Transfer,
             parent_ids,
             VLCSnapshot {
-                vector_clock: VectorClock::new(),
-                logical_time: 1,
+                vector_clock,
+                logical_time: tick,
                 physical_time: 1000,
             },
             creator.to_string(),
@@ -724,6 +827,40 @@ mod tests {
         assert_eq!(dag.get_depth(&"child".to_string()), Some(1));
     }
 
+    #[tokio::test]
+    async fn test_duplicate_content_rejected_within_window() {
+        let dag = Arc::new(RwLock::new(Dag::new()));
+        let event_store: Arc<dyn EventStoreBackend> = Arc::new(EventStore::new());
+        let config = DagManagerConfig { content_dedup_window: 16, ..Default::default() };
+        let manager = DagManager::new(dag, event_store, config);
+        manager.add_event(create_event("genesis", vec![], "node1")).await.unwrap();
+
+        let mut first = create_event("placeholder", vec!["genesis"], "solver-1");
+        first.set_execution_result(setu_types::ExecutionResult::success());
+        first.timestamp = 1_000;
+        first.recompute_id();
+        let mut retry = first.clone();
+        retry.timestamp = 2_000;
+        retry.recompute_id();
+        assert_ne!(first.id, retry.id);
+        assert_eq!(first.content_hash(), retry.content_hash());
+
+        let first_id = manager.add_event(first.clone()).await.unwrap();
+        match manager.add_event(retry.clone()).await {
+            Err(DagManagerError::DuplicateContent { event_id, existing }) => {
+                assert_eq!(event_id, retry.id);
+                assert_eq!(existing, first_id);
+            }
+            other => panic!("expected DuplicateContent, got {:?}", other),
+        }
+        // Same-id resubmission still reports the plain duplicate
+        assert!(matches!(
+            manager.add_event(first).await,
+            Err(DagManagerError::Dag(DagError::DuplicateEvent(_)))
+        ));
+        assert_eq!(manager.dag.read().await.node_count(), 2);
+    }
+
     #[tokio::test]
     async fn test_missing_parent_error() {
         let manager = create_manager().await;
@@ -16,10 +16,31 @@
 //! 6. Other validators vote on the fold validity
 //! 7. After quorum votes, the ConsensusFrame is finalized
 //! 8. Next round begins with the finalized frame as anchor
+//!
+//! ## Lock ordering
+//!
+//! Code paths that hold more than one engine lock at a time must acquire them
+//! in this order:
+//!
+//! ```text
+//! vlc → consensus_manager → dag → validator_set → private_key → broadcaster
+//! ```
+//!
+//! A lock may be skipped, but never taken while a lock later in the order is
+//! held. Locks that are only held in a short scope and released before the
+//! next acquisition (e.g. the proposer check on `validator_set` at the top of
+//! `try_create_cf`) are not constrained. `DagManager` takes `dag` internally,
+//! so calling it counts as acquiring `dag`. Network awaits must not run while
+//! holding `vlc` or `dag`; clone the broadcaster out with `get_broadcaster()`
+//! when a path needs it alongside earlier locks.
+//!
+//! Acquire engine locks through `lock_order::ordered`, which panics in debug
+//! builds when a task takes one engine's locks out of order.
 
 use setu_storage::{EventStore, EventStoreBackend, SharedStateManager};
-use setu_types::{ConsensusConfig, ConsensusFrame, Event, EventId, SetuResult, Vote};
+use setu_types::{ConsensusConfig, ConsensusFrame, Event, EventId, SetuResult, Vote, VoteCertificate};
 use setu_vlc::VLCSnapshot;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
@@ -27,14 +48,21 @@ use tokio::sync::{broadcast, mpsc, Mutex, RwLock};
 use tracing::{debug, info, warn};
 
 use crate::broadcaster::ConsensusBroadcaster;
-use crate::dag::Dag;
+use crate::dag::{Dag, DagError, FanHistogram, OrphanedEvent, ParentSelectionStrategy};
 use crate::dag_manager::{DagManager, DagManagerError};
 use crate::folder::ConsensusManager;
 use crate::liveness::Round;
+use crate::lock_order::{ordered, EngineLock, LockOwner};
 use crate::outcome_sink::OutcomeSink;
-use crate::validator_set::ValidatorSet;
+use crate::validator_set::{ValidatorSet, ValidatorSetChange};
 use crate::vlc::VLC;
 
+/// `try_read` attempts [`ConsensusEngine::try_select_parents`] makes before giving up
+const PARENT_SELECTION_ATTEMPTS: u32 = 20;
+
+/// Wait between [`ConsensusEngine::try_select_parents`] attempts
+const PARENT_SELECTION_RETRY_DELAY: Duration = Duration::from_millis(1);
+
 /// Messages exchanged between consensus components
 #[derive(Debug, Clone)]
 pub enum ConsensusMessage {
@@ -48,6 +76,8 @@ pub enum ConsensusMessage {
     FrameFinalized(ConsensusFrame),
     /// Leader rotation occurred
     LeaderChanged { round: Round, new_leader: String },
+    /// Validator set change scheduled for a future CF boundary
+    ValidatorSetChange(ValidatorSetChange),
 }
 
 /// The main consensus engine
@@ -101,19 +131,68 @@ pub struct ConsensusEngine {
     /// step only advances the round if it still matches `expected_round`,
     /// making the call idempotent under restart / duplicate dispatch.
     pending_completions: Arc<Mutex<Vec<(ConsensusFrame, Round)>>>,
+    /// Validator set changes waiting for their activation round. Applied in
+    /// `complete_pending_finalizations` right after the round advances, so
+    /// all validators reconfigure at the same CF boundary.
+    pending_validator_changes: Arc<Mutex<Vec<ValidatorSetChange>>>,
     /// Broadcast channel for CF finalization notifications.
     /// Injected by caller (ConsensusValidator) via set_finalization_tx().
     /// Uses parking_lot::RwLock: broadcast::Sender::send() is synchronous.
     finalization_tx: parking_lot::RwLock<Option<broadcast::Sender<ConsensusFrame>>>,
+    /// Broadcast channel for finalized anchors, fired once per anchor after
+    /// it is durably persisted. Injected via set_anchor_finalization_tx().
+    anchor_finalization_tx: parking_lot::RwLock<Option<broadcast::Sender<setu_types::Anchor>>>,
+    /// How `create_event` picks parents when the caller supplies none.
+    parent_selection: ParentSelectionStrategy,
+    /// Newest durably finalized anchor in compact form (see `finalized_tip`).
+    /// Set in `complete_pending_finalizations`, i.e. only after persistence.
+    finalized_tip: parking_lot::RwLock<Option<FinalizedTip>>,
+    /// Vote certificates of recently finalized CFs, oldest first. Bounded by
+    /// `finalized_cf_retention`; the persister writes each one to the CF
+    /// store together with its CF.
+    vote_certificates: parking_lot::RwLock<VecDeque<VoteCertificate>>,
+    /// Scopes the debug lock-order check to this engine's locks
+    lock_owner: LockOwner,
 }
 
 impl ConsensusEngine {
+    /// Empty DAG with the configured parent cap, trusting the stamps of
+    /// `validator_set`
+    fn new_dag(config: &ConsensusConfig, validator_set: &ValidatorSet) -> Dag {
+        let mut dag = Dag::with_max_parents(config.max_parents_per_event);
+        dag.set_stamp_keys(Self::stamp_keys(validator_set));
+        dag
+    }
+
+    /// Validator id -> public key, for verifying logical-time stamps
+    fn stamp_keys(validator_set: &ValidatorSet) -> HashMap<String, Vec<u8>> {
+        validator_set
+            .all_validators()
+            .into_iter()
+            .map(|v| (v.node.id.clone(), v.node.public_key.clone()))
+            .collect()
+    }
+
+    /// Re-sync the DAG's stamp keys after a validator set change
+    async fn sync_stamp_keys(&self) {
+        let keys = {
+            let vs = ordered(
+                self.lock_owner,
+                EngineLock::ValidatorSet,
+                self.validator_set.read(),
+            )
+            .await;
+            Self::stamp_keys(&vs)
+        };
+        ordered(self.lock_owner, EngineLock::Dag, self.dag.write()).await.set_stamp_keys(keys);
+    }
+
     /// Create a new consensus engine
     pub fn new(config: ConsensusConfig, validator_id: String, validator_set: ValidatorSet) -> Self {
         let (tx, rx) = mpsc::channel(1000);
 
         // Create shared DAG
-        let dag = Arc::new(RwLock::new(Dag::new()));
+        let dag = Arc::new(RwLock::new(Self::new_dag(&config, &validator_set)));
 
         // Create EventStore (in-memory for now)
         let event_store = Arc::new(EventStore::new());
@@ -141,7 +220,13 @@ impl ConsensusEngine {
             pending_persist_anchors: Arc::new(Mutex::new(Vec::new())),
             pending_persist_cfs: Arc::new(Mutex::new(Vec::new())),
             pending_completions: Arc::new(Mutex::new(Vec::new())),
+            pending_validator_changes: Arc::new(Mutex::new(Vec::new())),
             finalization_tx: parking_lot::RwLock::new(None),
+            anchor_finalization_tx: parking_lot::RwLock::new(None),
+            parent_selection: ParentSelectionStrategy::default(),
+            finalized_tip: parking_lot::RwLock::new(None),
+            vote_certificates: parking_lot::RwLock::new(VecDeque::new()),
+            lock_owner: LockOwner::new(),
         }
     }
 
@@ -158,7 +243,7 @@ impl ConsensusEngine {
         let (tx, rx) = mpsc::channel(1000);
 
         // Create shared DAG
-        let dag = Arc::new(RwLock::new(Dag::new()));
+        let dag = Arc::new(RwLock::new(Self::new_dag(&config, &validator_set)));
 
         // Create EventStore (in-memory for now)
         let event_store = Arc::new(EventStore::new());
@@ -187,7 +272,13 @@ impl ConsensusEngine {
             pending_persist_anchors: Arc::new(Mutex::new(Vec::new())),
             pending_persist_cfs: Arc::new(Mutex::new(Vec::new())),
             pending_completions: Arc::new(Mutex::new(Vec::new())),
+            pending_validator_changes: Arc::new(Mutex::new(Vec::new())),
             finalization_tx: parking_lot::RwLock::new(None),
+            anchor_finalization_tx: parking_lot::RwLock::new(None),
+            parent_selection: ParentSelectionStrategy::default(),
+            finalized_tip: parking_lot::RwLock::new(None),
+            vote_certificates: parking_lot::RwLock::new(VecDeque::new()),
+            lock_owner: LockOwner::new(),
         }
     }
 
@@ -204,7 +295,7 @@ impl ConsensusEngine {
         let (tx, rx) = mpsc::channel(1000);
 
         // Create shared DAG
-        let dag = Arc::new(RwLock::new(Dag::new()));
+        let dag = Arc::new(RwLock::new(Self::new_dag(&config, &validator_set)));
 
         // Create DagManager with the shared DAG and external EventStore
         let dag_manager = Arc::new(DagManager::with_defaults(Arc::clone(&dag), event_store));
@@ -229,7 +320,13 @@ impl ConsensusEngine {
             pending_persist_anchors: Arc::new(Mutex::new(Vec::new())),
             pending_persist_cfs: Arc::new(Mutex::new(Vec::new())),
             pending_completions: Arc::new(Mutex::new(Vec::new())),
+            pending_validator_changes: Arc::new(Mutex::new(Vec::new())),
             finalization_tx: parking_lot::RwLock::new(None),
+            anchor_finalization_tx: parking_lot::RwLock::new(None),
+            parent_selection: ParentSelectionStrategy::default(),
+            finalized_tip: parking_lot::RwLock::new(None),
+            vote_certificates: parking_lot::RwLock::new(VecDeque::new()),
+            lock_owner: LockOwner::new(),
         }
     }
 
@@ -244,7 +341,7 @@ impl ConsensusEngine {
         let (tx, rx) = mpsc::channel(1000);
 
         // Create shared DAG
-        let dag = Arc::new(RwLock::new(Dag::new()));
+        let dag = Arc::new(RwLock::new(Self::new_dag(&config, &validator_set)));
 
         // Create DagManager with the shared DAG and external EventStore
         let dag_manager = Arc::new(DagManager::with_defaults(Arc::clone(&dag), event_store));
@@ -270,7 +367,13 @@ impl ConsensusEngine {
             pending_persist_anchors: Arc::new(Mutex::new(Vec::new())),
             pending_persist_cfs: Arc::new(Mutex::new(Vec::new())),
             pending_completions: Arc::new(Mutex::new(Vec::new())),
+            pending_validator_changes: Arc::new(Mutex::new(Vec::new())),
             finalization_tx: parking_lot::RwLock::new(None),
+            anchor_finalization_tx: parking_lot::RwLock::new(None),
+            parent_selection: ParentSelectionStrategy::default(),
+            finalized_tip: parking_lot::RwLock::new(None),
+            vote_certificates: parking_lot::RwLock::new(VecDeque::new()),
+            lock_owner: LockOwner::new(),
         }
     }
 
@@ -279,12 +382,42 @@ impl ConsensusEngine {
     /// The private key should be 32 bytes for ed25519 signatures.
     /// If not set, votes will not be signed (backward compatibility mode).
     pub async fn set_private_key(&self, key: Vec<u8>) {
-        *self.private_key.write().await = Some(key);
+        *ordered(
+            self.lock_owner,
+            EngineLock::PrivateKey,
+            self.private_key.write(),
+        )
+        .await = Some(key);
     }
 
     /// Clear the private key (disable vote signing)
     pub async fn clear_private_key(&self) {
-        *self.private_key.write().await = None;
+        *ordered(self.lock_owner, EngineLock::PrivateKey, self.private_key.write()).await = None;
+    }
+
+    /// Copy of the vote signing key
+    ///
+    /// Vote paths use this instead of holding the `private_key` lock, which
+    /// ranks after `dag` and would otherwise block finalization from taking it.
+    async fn signing_key(&self) -> Option<Vec<u8>> {
+        ordered(self.lock_owner, EngineLock::PrivateKey, self.private_key.read()).await.clone()
+    }
+
+    /// Sign an event this node created with the node key
+    ///
+    /// Returns false, leaving the event unsigned, until `set_private_key`
+    /// has been called. Sign only once the content is final.
+    pub async fn sign_local_event(&self, event: &mut Event) -> bool {
+        let Some(key) = self.signing_key().await else {
+            return false;
+        };
+        match event.sign(&key) {
+            Ok(()) => true,
+            Err(e) => {
+                warn!(event_id = %event.id, error = %e, "Failed to sign local event");
+                false
+            }
+        }
     }
 
     /// Enable production vote signature enforcement.
@@ -350,6 +483,9 @@ impl ConsensusEngine {
         for (cf, expected_round) in pending {
             let cf_id = cf.id.clone();
 
+            // Light-client tip: the CF is durable by now.
+            self.record_finalized_tip(&cf);
+
             // Internal channel (legacy local listeners).
             let _ = self
                 .message_tx
@@ -367,7 +503,12 @@ impl ConsensusEngine {
 
             // Network broadcast.
             {
-                let broadcaster = self.broadcaster.read().await;
+                let broadcaster = ordered(
+                    self.lock_owner,
+                    EngineLock::Broadcaster,
+                    self.broadcaster.read(),
+                )
+                .await;
                 if let Some(ref b) = *broadcaster {
                     match b.broadcast_finalized(&cf).await {
                         Ok(result) => {
@@ -386,9 +527,32 @@ impl ConsensusEngine {
 
             // Advance round — last step, idempotent under restart / duplicate
             // dispatch via the `current_round() == expected_round` guard.
-            let mut vs = self.validator_set.write().await;
+            let mut vs = ordered(
+                self.lock_owner,
+                EngineLock::ValidatorSet,
+                self.validator_set.write(),
+            )
+            .await;
             if vs.current_round() == expected_round {
-                vs.advance_round();
+                let new_round = vs.advance_round();
+                let new_count = self.apply_validator_set_changes(&mut vs, new_round).await;
+                drop(vs);
+                if new_count.is_some() {
+                    self.sync_stamp_keys().await;
+                }
+
+                // Quorum follows membership. The validator set lock is released
+                // first to keep the manager → validator_set lock order used by
+                // `handle_finalization`.
+                if let Some(count) = new_count {
+                    let mut cm = ordered(
+                        self.lock_owner,
+                        EngineLock::ConsensusManager,
+                        self.consensus_manager.write(),
+                    )
+                    .await;
+                    cm.update_validator_count(count);
+                }
             } else {
                 debug!(
                     cf_id = %cf_id,
@@ -401,6 +565,183 @@ impl ConsensusEngine {
         Ok(())
     }
 
+    /// Schedule a validator set change (membership or rotation) for a future
+    /// CF boundary and announce it to peer validators.
+    ///
+    /// The change must carry approvals from a quorum of the current validator
+    /// set (`ValidatorSetChange::approve`); peers verify them again on
+    /// receipt. The change takes effect when finalization advances the round
+    /// to `change.activation_round`. Changes whose activation round has
+    /// already started are rejected because they could no longer be applied
+    /// consistently across nodes.
+    pub async fn schedule_validator_set_change(&self, change: ValidatorSetChange) -> SetuResult<()> {
+        self.verify_validator_set_change(&change).await?;
+        if !self.enqueue_validator_set_change(change.clone()).await? {
+            return Ok(());
+        }
+
+        if let Some(broadcaster) = self.get_broadcaster().await {
+            match broadcaster.broadcast_validator_set_change(&change).await {
+                Ok(result) => {
+                    debug!(
+                        activation_round = change.activation_round,
+                        success = result.success_count,
+                        total = result.total_peers,
+                        "Validator set change announced to peers"
+                    );
+                }
+                Err(e) => {
+                    warn!(
+                        activation_round = change.activation_round,
+                        error = %e,
+                        "Failed to announce validator set change"
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Schedule a validator set change announced by a peer validator.
+    ///
+    /// Only members of the current validator set may announce changes, and
+    /// the change must carry valid approvals from a quorum of that set.
+    /// Duplicate announcements are ignored and nothing is re-broadcast: the
+    /// announcing node already gossips the change to every peer.
+    pub async fn receive_validator_set_change(
+        &self,
+        sender_id: &str,
+        change: ValidatorSetChange,
+    ) -> SetuResult<()> {
+        {
+            let vs = ordered(
+                self.lock_owner,
+                EngineLock::ValidatorSet,
+                self.validator_set.read(),
+            )
+            .await;
+            if vs.get_validator(sender_id).is_none() {
+                return Err(setu_types::SetuError::InvalidData(format!(
+                    "Validator set change announced by non-validator {}",
+                    sender_id
+                )));
+            }
+        }
+        self.verify_validator_set_change(&change).await?;
+        self.enqueue_validator_set_change(change).await.map(|_| ())
+    }
+
+    /// Sign `change` with this validator's key, adding its approval.
+    pub async fn approve_validator_set_change(&self, change: &mut ValidatorSetChange) -> SetuResult<()> {
+        let Some(key) = self.signing_key().await else {
+            return Err(setu_types::SetuError::InvalidData(
+                "No private key configured to approve validator set change".to_string(),
+            ));
+        };
+        change
+            .approve(self.local_validator_id.clone(), &key)
+            .map_err(setu_types::SetuError::InvalidData)
+    }
+
+    /// Reject a change that lacks valid approvals from a quorum of the
+    /// current validator set.
+    async fn verify_validator_set_change(&self, change: &ValidatorSetChange) -> SetuResult<()> {
+        let vs = ordered(
+            self.lock_owner,
+            EngineLock::ValidatorSet,
+            self.validator_set.read(),
+        )
+        .await;
+        change.verify_approvals(&vs).map_err(|e| {
+            warn!(
+                activation_round = change.activation_round,
+                change = ?change.kind,
+                error = %e,
+                "Rejecting unapproved validator set change"
+            );
+            setu_types::SetuError::InvalidData(format!("Validator set change not approved: {}", e))
+        })
+    }
+
+    /// Add `change` to the pending set. Returns `false` if an identical change
+    /// was already scheduled.
+    async fn enqueue_validator_set_change(&self, change: ValidatorSetChange) -> SetuResult<bool> {
+        let current_round = self.current_round().await;
+        if change.activation_round <= current_round {
+            return Err(setu_types::SetuError::InvalidData(format!(
+                "Validator set change activation round {} must be after current round {}",
+                change.activation_round, current_round
+            )));
+        }
+
+        {
+            let mut pending = self.pending_validator_changes.lock().await;
+            if pending.iter().any(|c| c.same_as(&change)) {
+                debug!(
+                    activation_round = change.activation_round,
+                    change = ?change.kind,
+                    "Validator set change already scheduled"
+                );
+                return Ok(false);
+            }
+            pending.push(change.clone());
+        }
+
+        info!(
+            activation_round = change.activation_round,
+            change = ?change.kind,
+            "Validator set change scheduled"
+        );
+
+        let _ = self
+            .message_tx
+            .send(ConsensusMessage::ValidatorSetChange(change))
+            .await;
+        Ok(true)
+    }
+
+    /// Apply every scheduled validator set change whose activation round has
+    /// been reached. Returns the resulting validator count if any change ran.
+    async fn apply_validator_set_changes(
+        &self,
+        vs: &mut ValidatorSet,
+        round: Round,
+    ) -> Option<usize> {
+        let due: Vec<ValidatorSetChange> = {
+            let mut pending = self.pending_validator_changes.lock().await;
+            let (due, later) = std::mem::take(&mut *pending)
+                .into_iter()
+                .partition(|c| c.activation_round <= round);
+            *pending = later;
+            due
+        };
+
+        if due.is_empty() {
+            return None;
+        }
+
+        for change in &due {
+            if !vs.apply_change(change) {
+                warn!(
+                    round = round,
+                    change = ?change.kind,
+                    "Validator set change was a no-op"
+                );
+            }
+        }
+
+        let count = vs.count();
+        info!(
+            round = round,
+            applied = due.len(),
+            validator_count = count,
+            quorum = vs.quorum_size(),
+            leader = ?vs.get_leader_id(),
+            "Validator set changes applied at CF boundary"
+        );
+        Some(count)
+    }
+
     /// Test-only: how many completions are queued waiting for `complete_pending_finalizations`.
     #[cfg(test)]
     pub async fn pending_completions_len(&self) -> usize {
@@ -414,8 +755,9 @@ impl ConsensusEngine {
     /// a locally-finalized event can be selected by another CF before the
     /// persistence path gets to run.
     async fn mark_anchor_events_finalized_in_active_dag(&self, anchor: &setu_types::Anchor) {
-        let mut dag = self.dag.write().await;
+        let mut dag = ordered(self.lock_owner, EngineLock::Dag, self.dag.write()).await;
         dag.finalize_events(&anchor.event_ids);
+        dag.observe_anchor_depth(anchor.depth);
     }
 
     /// Set the network broadcaster for P2P message delivery
@@ -423,14 +765,38 @@ impl ConsensusEngine {
     /// This should be called after the network layer is initialized.
     /// Without a broadcaster, consensus messages are only sent to internal channels.
     pub async fn set_broadcaster(&self, broadcaster: Arc<dyn ConsensusBroadcaster>) {
-        let mut b = self.broadcaster.write().await;
+        let mut b = ordered(
+            self.lock_owner,
+            EngineLock::Broadcaster,
+            self.broadcaster.write(),
+        )
+        .await;
         *b = Some(broadcaster);
         info!("Consensus broadcaster configured");
     }
 
     /// Check if a broadcaster is configured
     pub async fn has_broadcaster(&self) -> bool {
-        self.broadcaster.read().await.is_some()
+        ordered(self.lock_owner, EngineLock::Broadcaster, self.broadcaster.read()).await.is_some()
+    }
+
+    /// Set the strategy `create_event` uses to pick parents when none are given
+    pub fn with_parent_selection(mut self, strategy: ParentSelectionStrategy) -> Self {
+        self.parent_selection = strategy;
+        self
+    }
+
+    /// Time VLC ticks with `clock` instead of the system clock
+    ///
+    /// Replaces the local VLC, so call it right after construction.
+    pub fn with_clock(mut self, clock: Arc<dyn setu_vlc::Clock>) -> Self {
+        self.vlc = Arc::new(RwLock::new(VLC::with_clock(self.local_validator_id.clone(), clock)));
+        self
+    }
+
+    /// The configured parent-selection strategy
+    pub fn parent_selection(&self) -> ParentSelectionStrategy {
+        self.parent_selection
     }
 
     /// Inject a broadcast sender for CF finalization notifications.
@@ -439,6 +805,22 @@ impl ConsensusEngine {
         *self.finalization_tx.write() = Some(tx);
     }
 
+    /// Inject a broadcast sender for finalized-anchor notifications.
+    /// Called by ConsensusValidator after engine construction.
+    pub fn set_anchor_finalization_tx(&self, tx: broadcast::Sender<setu_types::Anchor>) {
+        *self.anchor_finalization_tx.write() = Some(tx);
+    }
+
+    /// Notify anchor subscribers that `anchor` is finalized and persisted.
+    ///
+    /// Never blocks: the channel is bounded and a slow subscriber observes
+    /// `RecvError::Lagged` instead of holding up finalization.
+    pub fn publish_finalized_anchor(&self, anchor: &setu_types::Anchor) {
+        if let Some(ref tx) = *self.anchor_finalization_tx.read() {
+            let _ = tx.send(anchor.clone());
+        }
+    }
+
     /// R5 · Inject an outcome sink for apply-phase observability.
     ///
     /// Called by `ConsensusValidator::new` after engine construction. Forwards
@@ -461,7 +843,7 @@ impl ConsensusEngine {
     /// Returns None if no broadcaster is configured.
     /// Used for fetching missing events or other network operations.
     pub async fn get_broadcaster(&self) -> Option<Arc<dyn ConsensusBroadcaster>> {
-        let b = self.broadcaster.read().await;
+        let b = ordered(self.lock_owner, EngineLock::Broadcaster, self.broadcaster.read()).await;
         b.as_ref().cloned()
     }
 
@@ -469,12 +851,23 @@ impl ConsensusEngine {
     /// ConsensusManager.validator_count atomically.
     pub async fn add_consensus_validator(&self, info: setu_types::ValidatorInfo) {
         let count = {
-            let mut vs = self.validator_set.write().await;
+            let mut vs = ordered(
+                self.lock_owner,
+                EngineLock::ValidatorSet,
+                self.validator_set.write(),
+            )
+            .await;
             vs.add_validator(info.clone());
             vs.count()
         };
+        self.sync_stamp_keys().await;
         {
-            let mut cm = self.consensus_manager.write().await;
+            let mut cm = ordered(
+                self.lock_owner,
+                EngineLock::ConsensusManager,
+                self.consensus_manager.write(),
+            )
+            .await;
             cm.update_validator_count(count);
         }
         info!(
@@ -493,10 +886,18 @@ impl ConsensusEngine {
     ///
     /// This method uses DagManager as the single entry point for adding events,
     /// ensuring proper depth calculation and three-layer storage management.
+    ///
+    /// Returns the retryable `SetuError::Overloaded` when the DAG or the
+    /// pending (not yet finalized) set has reached the limits in
+    /// `ConsensusConfig`. Genesis events are exempt.
     pub async fn add_event(&self, event: Event) -> SetuResult<EventId> {
+        if !event.is_genesis() {
+            self.check_capacity().await?;
+        }
+
         // Update local VLC by merging with the event's VLC
         {
-            let mut vlc = self.vlc.write().await;
+            let mut vlc = ordered(self.lock_owner, EngineLock::Vlc, self.vlc.write()).await;
             vlc.merge(&event.vlc_snapshot);
             vlc.tick();
         }
@@ -535,7 +936,12 @@ impl ConsensusEngine {
         // We broadcast regardless of whether we are the leader, as all validators
         // need the event for their DAGs.
         {
-            let broadcaster = self.broadcaster.read().await;
+            let broadcaster = ordered(
+                self.lock_owner,
+                EngineLock::Broadcaster,
+                self.broadcaster.read(),
+            )
+            .await;
             if let Some(ref b) = *broadcaster {
                 // Background this to avoid blocking?
                 // For now, we await it but log errors instead of failing.
@@ -560,6 +966,31 @@ impl ConsensusEngine {
         Ok(event_id)
     }
 
+    /// Backpressure check for locally submitted events
+    ///
+    /// Finalization only ever shrinks the DAG, so it is never blocked by this.
+    async fn check_capacity(&self) -> SetuResult<()> {
+        let (dag_events, pending_events) = {
+            let dag = ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await;
+            (dag.node_count(), dag.get_pending_count())
+        };
+        let max_dag = self.config.max_dag_events;
+        if max_dag > 0 && dag_events >= max_dag {
+            return Err(setu_types::SetuError::Overloaded(format!(
+                "DAG holds {} events (max {})",
+                dag_events, max_dag
+            )));
+        }
+        let max_pending = self.config.max_pending_events;
+        if max_pending > 0 && pending_events >= max_pending {
+            return Err(setu_types::SetuError::Overloaded(format!(
+                "{} events pending finalization (max {})",
+                pending_events, max_pending
+            )));
+        }
+        Ok(())
+    }
+
     /// Receive an event from the network (does not broadcast again)
     ///
     /// This is used when receiving events from other validators.
@@ -567,7 +998,7 @@ impl ConsensusEngine {
     pub async fn receive_event_from_network(&self, event: Event) -> SetuResult<EventId> {
         // Update local VLC by merging with the event's VLC
         {
-            let mut vlc = self.vlc.write().await;
+            let mut vlc = ordered(self.lock_owner, EngineLock::Vlc, self.vlc.write()).await;
             vlc.merge(&event.vlc_snapshot);
             vlc.tick();
         }
@@ -611,9 +1042,17 @@ impl ConsensusEngine {
     }
 
     /// Create a new event with the given parent IDs
+    ///
+    /// When `parent_ids` is empty the parents are chosen from the current
+    /// tips by the configured [`ParentSelectionStrategy`].
     pub async fn create_event(&self, parent_ids: Vec<EventId>) -> SetuResult<Event> {
+        let parent_ids = if parent_ids.is_empty() {
+            self.select_parents().await
+        } else {
+            parent_ids
+        };
         let vlc_snapshot = {
-            let mut vlc = self.vlc.write().await;
+            let mut vlc = ordered(self.lock_owner, EngineLock::Vlc, self.vlc.write()).await;
             vlc.tick();
             vlc.snapshot()
         };
@@ -630,31 +1069,56 @@ impl ConsensusEngine {
 
     /// Check if this validator is the current leader
     pub async fn is_current_leader(&self) -> bool {
-        let validator_set = self.validator_set.read().await;
+        let validator_set = ordered(
+            self.lock_owner,
+            EngineLock::ValidatorSet,
+            self.validator_set.read(),
+        )
+        .await;
         validator_set.is_leader(&self.local_validator_id)
     }
 
     /// Check if this validator is the valid proposer for a specific round
     pub async fn is_valid_proposer_for_round(&self, round: Round) -> bool {
-        let validator_set = self.validator_set.read().await;
+        let validator_set = ordered(
+            self.lock_owner,
+            EngineLock::ValidatorSet,
+            self.validator_set.read(),
+        )
+        .await;
         validator_set.is_valid_proposer(&self.local_validator_id, round)
     }
 
     /// Get the current round
     pub async fn current_round(&self) -> Round {
-        let validator_set = self.validator_set.read().await;
+        let validator_set = ordered(
+            self.lock_owner,
+            EngineLock::ValidatorSet,
+            self.validator_set.read(),
+        )
+        .await;
         validator_set.current_round()
     }
 
     /// Get the valid proposer for a specific round
     pub async fn get_valid_proposer(&self, round: Round) -> Option<String> {
-        let validator_set = self.validator_set.read().await;
+        let validator_set = ordered(
+            self.lock_owner,
+            EngineLock::ValidatorSet,
+            self.validator_set.read(),
+        )
+        .await;
         validator_set.get_valid_proposer(round)
     }
 
     /// Advance to the next round
     pub async fn advance_round(&self) -> Round {
-        let mut validator_set = self.validator_set.write().await;
+        let mut validator_set = ordered(
+            self.lock_owner,
+            EngineLock::ValidatorSet,
+            self.validator_set.write(),
+        )
+        .await;
         let new_round = validator_set.advance_round();
 
         // Notify about leader change
@@ -674,7 +1138,12 @@ impl ConsensusEngine {
     /// Try to create a ConsensusFrame if conditions are met
     async fn try_create_cf(&self) -> SetuResult<Option<ConsensusFrame>> {
         let _current_round = {
-            let validator_set = self.validator_set.read().await;
+            let validator_set = ordered(
+                self.lock_owner,
+                EngineLock::ValidatorSet,
+                self.validator_set.read(),
+            )
+            .await;
             let round = validator_set.current_round();
 
             // Check if we are the valid proposer for the current round
@@ -692,8 +1161,13 @@ impl ConsensusEngine {
             round
         };
 
-        let vlc = self.vlc.read().await;
-        let mut manager = self.consensus_manager.write().await;
+        let vlc = ordered(self.lock_owner, EngineLock::Vlc, self.vlc.read()).await;
+        let mut manager = ordered(
+            self.lock_owner,
+            EngineLock::ConsensusManager,
+            self.consensus_manager.write(),
+        )
+        .await;
 
         let should_fold = manager.should_fold(&vlc);
         if !should_fold {
@@ -712,7 +1186,7 @@ impl ConsensusEngine {
             "try_create_cf: starting CF creation"
         );
 
-        let dag = self.dag.read().await;
+        let dag = ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await;
         // AnchorBuilder now handles all Merkle tree computation internally
         let cf = manager.try_create_cf(&dag, &vlc);
         drop(dag);
@@ -727,8 +1201,8 @@ impl ConsensusEngine {
 
             // Leader auto-votes for their own CF
             // Get private key for signing (if available)
-            let private_key = self.private_key.read().await;
-            let key_ref = private_key.as_ref().map(|k| k.as_slice());
+            let private_key = self.signing_key().await;
+            let key_ref = private_key.as_deref();
 
             let self_vote = manager.vote_for_cf(&frame.id, true, key_ref);
             if self_vote.is_some() {
@@ -764,6 +1238,7 @@ impl ConsensusEngine {
                             let mut pending = self.pending_persist_cfs.lock().await;
                             pending.push(cf.clone());
                         }
+                        self.record_vote_certificate(cf).await;
                     }
 
                     // Notify finalization subscribers (single-node mode)
@@ -778,6 +1253,11 @@ impl ConsensusEngine {
                 }
             }
 
+            // Release the VLC and consensus locks before any network await so a
+            // slow peer cannot stall event intake or vote handling.
+            drop(manager);
+            drop(vlc);
+
             // Send to internal channel (legacy, not consumed in production)
             let _ = self
                 .message_tx
@@ -794,8 +1274,7 @@ impl ConsensusEngine {
             }
 
             // Broadcast to network via broadcaster (if configured)
-            let broadcaster = self.broadcaster.read().await;
-            if let Some(ref b) = *broadcaster {
+            if let Some(b) = self.get_broadcaster().await {
                 match b.broadcast_cf(&broadcast_frame).await {
                     Ok(result) => {
                         info!(
@@ -841,6 +1320,7 @@ impl ConsensusEngine {
     /// 1. Verify proposer is valid for current round
     /// 2. Check if we've already processed this CF (idempotency)
     /// 3. **Ensure all referenced events are in local DAG (fetch if missing)**
+    ///    and that the set is causally closed (no pending parent left out)
     /// 4. Verify the CF's merkle roots are valid
     /// 5. Apply the state changes from the anchor's events to local SMT
     /// 6. Verify resulting state matches the anchor's state root
@@ -864,7 +1344,12 @@ impl ConsensusEngine {
         // Step 1: Verify proposer is valid for current round
         // This prevents malicious nodes from creating fake CFs
         {
-            let validator_set = self.validator_set.read().await;
+            let validator_set = ordered(
+                self.lock_owner,
+                EngineLock::ValidatorSet,
+                self.validator_set.read(),
+            )
+            .await;
             let current_round = validator_set.current_round();
             if !validator_set.is_valid_proposer(&cf.proposer, current_round) {
                 return Err(setu_types::SetuError::InvalidData(format!(
@@ -879,7 +1364,12 @@ impl ConsensusEngine {
         // The CF's anchor_chain_root must match our local chain root
         if let Some(ref merkle_roots) = cf.anchor.merkle_roots {
             let local_chain_root = {
-                let manager = self.consensus_manager.read().await;
+                let manager = ordered(
+                    self.lock_owner,
+                    EngineLock::ConsensusManager,
+                    self.consensus_manager.read(),
+                )
+                .await;
                 manager.anchor_builder().anchor_chain_root()
             };
 
@@ -902,7 +1392,12 @@ impl ConsensusEngine {
 
         // Step 2: Idempotency check (before acquiring write lock)
         {
-            let manager = self.consensus_manager.read().await;
+            let manager = ordered(
+                self.lock_owner,
+                EngineLock::ConsensusManager,
+                self.consensus_manager.read(),
+            )
+            .await;
             if manager.has_cf(&cf.id) {
                 return Ok((false, None));
             }
@@ -912,21 +1407,38 @@ impl ConsensusEngine {
 
         self.filter_embedded_votes_for_strict_mode(&mut cf).await;
 
-        if self.require_vote_signatures() && self.private_key.read().await.is_none() {
+        if self.require_vote_signatures() && self.signing_key().await.is_none() {
             return Err(setu_types::SetuError::InvalidData(
                 "Strict vote signature mode requires a local private key before voting on CF proposals".to_string(),
             ));
         }
 
         // Now we have all events, proceed with verification
-        let dag = self.dag.read().await;
-        let mut manager = self.consensus_manager.write().await;
+        let mut manager = ordered(
+            self.lock_owner,
+            EngineLock::ConsensusManager,
+            self.consensus_manager.write(),
+        )
+        .await;
+        let dag = ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await;
 
         // Double-check idempotency (another thread may have processed while we fetched)
         if manager.has_cf(&cf.id) {
             return Ok((false, None));
         }
 
+        // Step 3: The event set must be causally closed, otherwise applying it
+        // would run a child's state changes without its parent's
+        let open_parents = dag.unclosed_parents(&cf.anchor.event_ids);
+        if !open_parents.is_empty() {
+            return Err(setu_types::SetuError::InvalidData(format!(
+                "CF {} is not causally closed: {} parent events are neither included nor finalized (first: {})",
+                cf.id,
+                open_parents.len(),
+                open_parents[0]
+            )));
+        }
+
         // Step 4: Verify the CF's merkle roots are internally consistent
         if !manager.verify_cf_merkle_roots(&cf) {
             return Err(setu_types::SetuError::InvalidData(
@@ -946,12 +1458,12 @@ impl ConsensusEngine {
         manager.receive_cf(cf.clone());
 
         // Vote for the CF (in MVP, we always approve valid CFs)
-        let private_key = self.private_key.read().await;
-        let vote = manager.vote_for_cf(&cf_id, true, private_key.as_ref().map(|k| k.as_slice()));
+        let private_key = self.signing_key().await;
+        let vote = manager.vote_for_cf(&cf_id, true, private_key.as_deref());
         if let Some(ref v) = vote {
-            // Broadcast vote to network via broadcaster (if configured)
-            let broadcaster = self.broadcaster.read().await;
-            if let Some(ref b) = *broadcaster {
+            // Broadcast vote to network via broadcaster (if configured).
+            // Cloned out so no broadcaster lock is held into handle_finalization.
+            if let Some(b) = self.get_broadcaster().await {
                 match b.broadcast_vote(v).await {
                     Ok(result) => {
                         debug!(
@@ -992,7 +1504,12 @@ impl ConsensusEngine {
         self.verify_finalized_cf_votes(&cf).await?;
 
         {
-            let manager = self.consensus_manager.read().await;
+            let manager = ordered(
+                self.lock_owner,
+                EngineLock::ConsensusManager,
+                self.consensus_manager.read(),
+            )
+            .await;
             if manager.is_finalized_cf(&cf.id) {
                 return Ok((false, None));
             }
@@ -1000,7 +1517,12 @@ impl ConsensusEngine {
 
         if let Some(ref merkle_roots) = cf.anchor.merkle_roots {
             let local_chain_root = {
-                let manager = self.consensus_manager.read().await;
+                let manager = ordered(
+                    self.lock_owner,
+                    EngineLock::ConsensusManager,
+                    self.consensus_manager.read(),
+                )
+                .await;
                 manager.anchor_builder().anchor_chain_root()
             };
 
@@ -1017,8 +1539,13 @@ impl ConsensusEngine {
 
         self.ensure_cf_events_available(&cf).await?;
 
-        let dag = self.dag.read().await;
-        let mut manager = self.consensus_manager.write().await;
+        let mut manager = ordered(
+            self.lock_owner,
+            EngineLock::ConsensusManager,
+            self.consensus_manager.write(),
+        )
+        .await;
+        let dag = ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await;
 
         if manager.is_finalized_cf(&cf.id) {
             return Ok((false, None));
@@ -1043,7 +1570,12 @@ impl ConsensusEngine {
     }
 
     async fn verify_finalized_cf_votes(&self, cf: &ConsensusFrame) -> SetuResult<()> {
-        let validator_set = self.validator_set.read().await;
+        let validator_set = ordered(
+            self.lock_owner,
+            EngineLock::ValidatorSet,
+            self.validator_set.read(),
+        )
+        .await;
         let validator_count = validator_set.count();
         if !cf.check_quorum(validator_count) {
             return Err(setu_types::SetuError::InvalidData(format!(
@@ -1089,7 +1621,12 @@ impl ConsensusEngine {
         }
 
         let validators = {
-            let validator_set = self.validator_set.read().await;
+            let validator_set = ordered(
+                self.lock_owner,
+                EngineLock::ValidatorSet,
+                self.validator_set.read(),
+            )
+            .await;
             validator_set
                 .all_validators()
                 .into_iter()
@@ -1200,7 +1737,7 @@ impl ConsensusEngine {
 
     async fn ensure_cf_events_available(&self, cf: &ConsensusFrame) -> SetuResult<()> {
         let mut missing_event_ids = {
-            let dag = self.dag.read().await;
+            let dag = ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await;
             cf.anchor
                 .event_ids
                 .iter()
@@ -1213,8 +1750,9 @@ impl ConsensusEngine {
             return Ok(());
         }
 
-        let broadcaster = self.broadcaster.read().await;
-        let Some(ref b) = *broadcaster else {
+        // Clone the broadcaster out so its lock is not held while the fetched
+        // events take `vlc` and `dag` (see "Lock ordering" in the module docs).
+        let Some(b) = self.get_broadcaster().await else {
             return Err(setu_types::SetuError::InvalidData(format!(
                 "CF {} references {} events not in local DAG (no broadcaster to fetch)",
                 cf.id,
@@ -1236,7 +1774,12 @@ impl ConsensusEngine {
                 Ok(fetched_events) => {
                     for event in fetched_events {
                         {
-                            let mut vlc = self.vlc.write().await;
+                            let mut vlc = ordered(
+                                self.lock_owner,
+                                EngineLock::Vlc,
+                                self.vlc.write(),
+                            )
+                            .await;
                             vlc.merge(&event.vlc_snapshot);
                         }
 
@@ -1250,7 +1793,7 @@ impl ConsensusEngine {
                     }
 
                     missing_event_ids = {
-                        let dag = self.dag.read().await;
+                        let dag = ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await;
                         cf.anchor
                             .event_ids
                             .iter()
@@ -1314,10 +1857,12 @@ impl ConsensusEngine {
     /// 4. push `(cf, expected_round)` to `pending_completions` (post-persist queue)
     ///
     /// Note: This method extracts data from manager before acquiring other locks
-    /// to avoid potential deadlock from holding multiple write locks.
+    /// to avoid potential deadlock from holding multiple write locks. It runs
+    /// under the caller's `consensus_manager` write lock and then takes `dag`
+    /// and `validator_set`, following the engine lock order.
     async fn handle_finalization(
         &self,
-        manager: &mut tokio::sync::RwLockWriteGuard<'_, ConsensusManager>,
+        manager: &mut ConsensusManager,
     ) -> SetuResult<(bool, Option<setu_types::Anchor>)> {
         // Extract data from manager first, before acquiring other locks
         let cf_data = manager
@@ -1336,13 +1881,19 @@ impl ConsensusEngine {
                 let mut pending = self.pending_persist_cfs.lock().await;
                 pending.push(cf.clone());
             }
+            self.record_vote_certificate(&cf).await;
 
             // Layer A: capture the round at which this CF finalized so
             // `complete_pending_finalizations` can advance the round
             // idempotently after the caller has durably persisted the anchor.
             // We do NOT broadcast or advance here — those happen post-persist.
             let expected_round = {
-                let vs = self.validator_set.read().await;
+                let vs = ordered(
+                    self.lock_owner,
+                    EngineLock::ValidatorSet,
+                    self.validator_set.read(),
+                )
+                .await;
                 vs.current_round()
             };
             {
@@ -1380,7 +1931,12 @@ impl ConsensusEngine {
     /// so the caller can persist it to storage.
     pub async fn receive_vote(&self, vote: Vote) -> SetuResult<(bool, Option<setu_types::Anchor>)> {
         let validator = {
-            let validator_set = self.validator_set.read().await;
+            let validator_set = ordered(
+                self.lock_owner,
+                EngineLock::ValidatorSet,
+                self.validator_set.read(),
+            )
+            .await;
             let all_validators = validator_set.all_validators();
             all_validators
                 .into_iter()
@@ -1398,7 +1954,12 @@ impl ConsensusEngine {
         self.verify_vote_signature_policy(&vote, &validator.node.public_key, "vote")?;
 
         let cf_id = vote.cf_id.clone();
-        let mut manager = self.consensus_manager.write().await;
+        let mut manager = ordered(
+            self.lock_owner,
+            EngineLock::ConsensusManager,
+            self.consensus_manager.write(),
+        )
+        .await;
         let finalized = manager.receive_vote(vote)
             && Self::manager_last_finalized_matches(&manager, &cf_id);
 
@@ -1427,14 +1988,19 @@ impl ConsensusEngine {
     /// Compute the state root (async version, legacy)
     #[deprecated(since = "0.2.0", note = "Use get_global_state_root() instead")]
     pub async fn compute_state_root(&self) -> String {
-        let dag = self.dag.read().await;
+        let dag = ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await;
         #[allow(deprecated)]
         self.compute_state_root_internal(&dag)
     }
 
     /// Get the current global state root from AnchorBuilder
     pub async fn get_global_state_root(&self) -> [u8; 32] {
-        let manager = self.consensus_manager.read().await;
+        let manager = ordered(
+            self.lock_owner,
+            EngineLock::ConsensusManager,
+            self.consensus_manager.read(),
+        )
+        .await;
         manager.get_global_root()
     }
 
@@ -1443,7 +2009,12 @@ impl ConsensusEngine {
         &self,
         subnet_id: &setu_types::SubnetId,
     ) -> Option<[u8; 32]> {
-        let manager = self.consensus_manager.read().await;
+        let manager = ordered(
+            self.lock_owner,
+            EngineLock::ConsensusManager,
+            self.consensus_manager.read(),
+        )
+        .await;
         manager.get_subnet_root(subnet_id)
     }
 
@@ -1452,22 +2023,149 @@ impl ConsensusEngine {
     /// This returns the cumulative chain root that commits to the entire anchor history.
     /// Used for verifying anchor chain consistency when receiving CFs from other validators.
     pub async fn get_anchor_chain_root(&self) -> [u8; 32] {
-        let manager = self.consensus_manager.read().await;
+        let manager = ordered(
+            self.lock_owner,
+            EngineLock::ConsensusManager,
+            self.consensus_manager.read(),
+        )
+        .await;
         manager.anchor_builder().anchor_chain_root()
     }
 
+    /// Compact summary of the newest durably finalized anchor, if any
+    pub fn finalized_tip(&self) -> Option<FinalizedTip> {
+        self.finalized_tip.read().clone()
+    }
+
+    /// Cache `cf` as the finalized tip unless a deeper anchor is already
+    /// cached. Also used on recovery to seed the tip from storage.
+    pub fn record_finalized_tip(&self, cf: &ConsensusFrame) {
+        let mut tip = self.finalized_tip.write();
+        if tip.as_ref().is_some_and(|t| t.depth > cf.anchor.depth) {
+            return;
+        }
+        *tip = Some(FinalizedTip::from_cf(cf));
+    }
+
+    /// Vote certificate of the finalized CF that produced `anchor_id`, if it
+    /// is still retained
+    pub fn vote_certificate(&self, anchor_id: &str) -> Option<VoteCertificate> {
+        self.vote_certificates
+            .read()
+            .iter()
+            .rev()
+            .find(|cert| cert.anchor_id == anchor_id)
+            .cloned()
+    }
+
+    /// Take a just-finalized CF's vote certificate against the current
+    /// validator set, i.e. before the round advance applies membership changes
+    async fn record_vote_certificate(&self, cf: &ConsensusFrame) {
+        let certificate = {
+            let vs = ordered(
+                self.lock_owner,
+                EngineLock::ValidatorSet,
+                self.validator_set.read(),
+            )
+            .await;
+            cf.vote_certificate(
+                |validator_id| vs.get_voting_power(validator_id),
+                vs.total_voting_power(),
+            )
+        };
+        let retention = self.config.finalized_cf_retention.max(1);
+        let mut certificates = self.vote_certificates.write();
+        certificates.push_back(certificate);
+        while certificates.len() > retention {
+            certificates.pop_front();
+        }
+    }
+
+    /// Get the anchor ID of the most recently finalized CF, if any
+    pub async fn last_finalized_anchor_id(&self) -> Option<String> {
+        let manager = ordered(
+            self.lock_owner,
+            EngineLock::ConsensusManager,
+            self.consensus_manager.read(),
+        )
+        .await;
+        manager.last_finalized_cf().map(|cf| cf.anchor.id.clone())
+    }
+
+    /// Summarize finalization progress for health/readiness probes.
+    ///
+    /// The engine is considered stalled when the DAG holds events that no
+    /// finalized anchor covers yet and the newest finalized anchor is older
+    /// than `max_anchor_age_ms` (or no anchor was ever finalized). An idle
+    /// DAG with nothing pending is never stalled.
+    pub async fn health(&self, now_ms: u64, max_anchor_age_ms: u64) -> ConsensusHealth {
+        let round = self.current_round().await;
+        let last_anchor = {
+            let manager = ordered(
+                self.lock_owner,
+                EngineLock::ConsensusManager,
+                self.consensus_manager.read(),
+            )
+            .await;
+            manager
+                .last_finalized_cf()
+                .map(|cf| (cf.anchor.timestamp, cf.anchor.vlc_snapshot.logical_time))
+        };
+        let pending_events = ordered(self.lock_owner, EngineLock::Dag, self.dag.read())
+            .await
+            .get_pending_count();
+        let logical_time = ordered(self.lock_owner, EngineLock::Vlc, self.vlc.read())
+            .await
+            .snapshot()
+            .logical_time;
+
+        let last_anchor_age_ms = last_anchor.map(|(ts, _)| now_ms.saturating_sub(ts));
+        let anchored_time = last_anchor.map_or(0, |(_, lt)| lt);
+        let stalled = pending_events > 0
+            && last_anchor_age_ms.is_none_or(|age| age > max_anchor_age_ms);
+
+        ConsensusHealth {
+            round,
+            last_anchor_age_ms,
+            pending_events,
+            finalization_lag: logical_time.saturating_sub(anchored_time),
+            stalled,
+        }
+    }
+
     /// Get the number of anchors created
     pub async fn get_anchor_count(&self) -> usize {
-        let manager = self.consensus_manager.read().await;
+        let manager = ordered(
+            self.lock_owner,
+            EngineLock::ConsensusManager,
+            self.consensus_manager.read(),
+        )
+        .await;
         manager.anchor_count()
     }
 
+    /// Get the number of CFs held in `ConsensusManager` memory
+    pub async fn get_in_memory_cf_count(&self) -> usize {
+        let manager = ordered(
+            self.lock_owner,
+            EngineLock::ConsensusManager,
+            self.consensus_manager.read(),
+        )
+        .await;
+        manager.in_memory_cf_count()
+    }
+
     /// Mark an anchor as successfully persisted to storage
     ///
     /// Call this after successfully storing the anchor to AnchorStore.
     /// This enables safe garbage collection of finalized CFs from memory.
     pub async fn mark_anchor_persisted(&self, anchor_id: &str) {
-        let mut manager = self.consensus_manager.write().await;
+        let mut manager = ordered(
+            self.lock_owner,
+            EngineLock::ConsensusManager,
+            self.consensus_manager.write(),
+        )
+        .await;
         manager.mark_anchor_persisted(anchor_id);
     }
 
@@ -1481,16 +2179,26 @@ impl ConsensusEngine {
     ) -> SetuResult<Option<ConsensusFrame>> {
         // Leader check
         {
-            let validator_set = self.validator_set.read().await;
+            let validator_set = ordered(
+                self.lock_owner,
+                EngineLock::ValidatorSet,
+                self.validator_set.read(),
+            )
+            .await;
             let round = validator_set.current_round();
             if !validator_set.is_valid_proposer(&self.local_validator_id, round) {
                 return Ok(None);
             }
         }
 
-        let vlc = self.vlc.read().await;
-        let mut manager = self.consensus_manager.write().await;
-        let dag = self.dag.read().await;
+        let vlc = ordered(self.lock_owner, EngineLock::Vlc, self.vlc.read()).await;
+        let mut manager = ordered(
+            self.lock_owner,
+            EngineLock::ConsensusManager,
+            self.consensus_manager.write(),
+        )
+        .await;
+        let dag = ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await;
 
         let cf = manager.try_create_cf_heartbeat(&dag, &vlc, heartbeat_interval);
         drop(dag);
@@ -1503,8 +2211,8 @@ impl ConsensusEngine {
             );
 
             // Leader self-vote + inline finalization (same logic as try_create_cf)
-            let private_key = self.private_key.read().await;
-            let key_ref = private_key.as_ref().map(|k| k.as_slice());
+            let private_key = self.signing_key().await;
+            let key_ref = private_key.as_deref();
 
             let self_vote = manager.vote_for_cf(&frame.id, true, key_ref);
             if self_vote.is_some() {
@@ -1527,6 +2235,7 @@ impl ConsensusEngine {
                             let mut pending = self.pending_persist_cfs.lock().await;
                             pending.push(cf.clone());
                         }
+                        self.record_vote_certificate(cf).await;
                     }
 
                     // Notify finalization subscribers
@@ -1541,6 +2250,10 @@ impl ConsensusEngine {
                 }
             }
 
+            // Same as try_create_cf: no engine locks held across the broadcast
+            drop(manager);
+            drop(vlc);
+
             // Send to internal channel (legacy)
             let _ = self
                 .message_tx
@@ -1552,8 +2265,7 @@ impl ConsensusEngine {
             if let Some(ref v) = self_vote {
                 broadcast_frame.add_vote(v.clone());
             }
-            let broadcaster = self.broadcaster.read().await;
-            if let Some(ref b) = *broadcaster {
+            if let Some(b) = self.get_broadcaster().await {
                 match b.broadcast_cf(&broadcast_frame).await {
                     Ok(result) => {
                         info!(
@@ -1579,18 +2291,33 @@ impl ConsensusEngine {
 
     /// Get DAG statistics
     pub async fn get_dag_stats(&self) -> DagStats {
-        let dag = self.dag.read().await;
+        let dag = ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await;
         DagStats {
             node_count: dag.node_count(),
             max_depth: dag.max_depth(),
             tip_count: dag.get_tips().len(),
             pending_count: dag.get_pending_count(),
+            fan_in: dag.fan_in().clone(),
+            fan_out: dag.fan_out().clone(),
         }
     }
 
+    /// Pending events left unfolded for at least `anchor_threshold` anchors
+    pub async fn get_orphaned_events(&self, anchor_threshold: u64) -> Vec<OrphanedEvent> {
+        ordered(self.lock_owner, EngineLock::Dag, self.dag.read())
+            .await
+            .orphaned_events(anchor_threshold)
+    }
+
+    /// The active DAG as Graphviz DOT, bounded to `max_events` events
+    /// (see [`Dag::to_dot`])
+    pub async fn export_dag_dot(&self, max_events: usize) -> String {
+        ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await.to_dot(max_events)
+    }
+
     /// Get the current VLC snapshot
     pub async fn get_vlc_snapshot(&self) -> VLCSnapshot {
-        self.vlc.read().await.snapshot()
+        ordered(self.lock_owner, EngineLock::Vlc, self.vlc.read()).await.snapshot()
     }
 
     /// Allocate a logical time using lock-free atomic counter (FAST PATH)
@@ -1637,14 +2364,45 @@ impl ConsensusEngine {
     /// NOTE: For high-performance local event creation, prefer `allocate_logical_time()`
     /// which uses a lock-free atomic counter.
     pub async fn tick_and_get_vlc(&self) -> VLCSnapshot {
-        let mut vlc = self.vlc.write().await;
+        let mut vlc = ordered(self.lock_owner, EngineLock::Vlc, self.vlc.write()).await;
         vlc.tick(); // VLC::tick() uses the node_id internally
         vlc.snapshot()
     }
 
     /// Get the current tips of the DAG
     pub async fn get_tips(&self) -> Vec<EventId> {
-        self.dag.read().await.get_tips()
+        ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await.get_tips()
+    }
+
+    /// Pick parents for a new event from the current tips
+    ///
+    /// Uses the configured [`ParentSelectionStrategy`]; see
+    /// [`Dag::select_parents`].
+    pub async fn select_parents(&self) -> Vec<EventId> {
+        ordered(self.lock_owner, EngineLock::Dag, self.dag.read())
+            .await
+            .select_parents(self.parent_selection)
+    }
+
+    /// Synchronous [`select_parents`](Self::select_parents) for callers
+    /// outside the async runtime (task preparation)
+    ///
+    /// Retries `try_read` with short waits (about 20ms in total) while a
+    /// writer holds the DAG, then fails with a retryable `Overloaded` error
+    /// rather than handing back an empty parent list.
+    pub fn try_select_parents(&self) -> SetuResult<Vec<EventId>> {
+        for attempt in 0..PARENT_SELECTION_ATTEMPTS {
+            if let Ok(dag) = self.dag.try_read() {
+                return Ok(dag.select_parents(self.parent_selection));
+            }
+            if attempt + 1 < PARENT_SELECTION_ATTEMPTS {
+                std::thread::sleep(PARENT_SELECTION_RETRY_DELAY);
+            }
+        }
+        Err(setu_types::SetuError::Overloaded(format!(
+            "DAG stayed write-locked for {} parent selection attempts",
+            PARENT_SELECTION_ATTEMPTS
+        )))
     }
 
     /// Get events by their IDs from the DAG
@@ -1652,13 +2410,22 @@ impl ConsensusEngine {
     /// This is used to retrieve events for persistence when a CF is finalized.
     /// Returns events that exist in the DAG.
     pub async fn get_events_by_ids(&self, event_ids: &[EventId]) -> Vec<Event> {
-        let dag = self.dag.read().await;
+        let dag = ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await;
         event_ids
             .iter()
             .filter_map(|id| dag.get_event(id).cloned())
             .collect()
     }
 
+    /// Check that `event`'s VLC follows its parents still in the DAG
+    ///
+    /// Adding the event runs the same check; calling this first lets callers
+    /// reject with their own error before the engine merges the event's VLC.
+    pub async fn check_vlc_order(&self, event: &Event) -> Result<(), DagError> {
+        let dag = ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await;
+        dag.check_vlc_order(event)
+    }
+
     /// Get events by their IDs using three-layer query (DAG → Store)
     ///
     /// This method queries both the active DAG and the persistent EventStore,
@@ -1671,7 +2438,7 @@ impl ConsensusEngine {
 
         // Step 1: Query DAG (hot data)
         {
-            let dag = self.dag.read().await;
+            let dag = ordered(self.lock_owner, EngineLock::Dag, self.dag.read()).await;
             for id in event_ids {
                 if let Some(event) = dag.get_event(id) {
                     results.push(event.clone());
@@ -1694,6 +2461,82 @@ impl ConsensusEngine {
         results
     }
 
+    /// Get the ancestor subgraph ("causal cone") of an event
+    ///
+    /// Walks parent links breadth-first from `event_id` for at most
+    /// `max_depth` hops, resolving each level through the three-layer query so
+    /// ancestors that were GC'd from the active DAG are still included.
+    /// Returns None if the root event is unknown.
+    pub async fn get_causal_subgraph(
+        &self,
+        event_id: &EventId,
+        max_depth: usize,
+    ) -> Option<CausalSubgraph> {
+        let root = self
+            .get_events_by_ids_three_layer(std::slice::from_ref(event_id))
+            .await
+            .into_iter()
+            .next()?;
+
+        let mut seen: HashSet<EventId> = HashSet::from([root.id.clone()]);
+        let mut missing: HashSet<EventId> = HashSet::new();
+        let mut subgraph = CausalSubgraph {
+            root: root.id.clone(),
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            truncated: false,
+        };
+        let mut frontier = vec![root];
+
+        for distance in 0..=max_depth {
+            if frontier.is_empty() {
+                break;
+            }
+
+            let mut next_ids = Vec::new();
+            for event in &frontier {
+                for parent_id in &event.parent_ids {
+                    if distance < max_depth {
+                        subgraph.edges.push((event.id.clone(), parent_id.clone()));
+                    }
+                    if seen.insert(parent_id.clone()) {
+                        next_ids.push(parent_id.clone());
+                    }
+                }
+            }
+
+            subgraph
+                .nodes
+                .extend(frontier.drain(..).map(|event| (event, distance)));
+
+            if distance == max_depth {
+                // Look one hop past the limit: the result is only truncated if
+                // an unseen ancestor actually resolves.
+                if !next_ids.is_empty()
+                    && !self.get_events_by_ids_three_layer(&next_ids).await.is_empty()
+                {
+                    subgraph.truncated = true;
+                }
+                break;
+            }
+
+            if !next_ids.is_empty() {
+                frontier = self.get_events_by_ids_three_layer(&next_ids).await;
+                if frontier.len() < next_ids.len() {
+                    // Some ancestors are neither in the DAG nor in the store.
+                    let found: HashSet<&EventId> = frontier.iter().map(|e| &e.id).collect();
+                    missing.extend(next_ids.into_iter().filter(|id| !found.contains(id)));
+                    subgraph.truncated = true;
+                }
+            }
+        }
+
+        if !missing.is_empty() {
+            subgraph.edges.retain(|(_, parent)| !missing.contains(parent));
+        }
+        Some(subgraph)
+    }
+
     /// Get the DagManager reference
     ///
     /// Used for direct access to three-layer storage operations,
@@ -1727,20 +2570,84 @@ impl ConsensusEngine {
     }
 }
 
-/// DAG statistics
+/// Ancestor subgraph of an event, as returned by `get_causal_subgraph`
 #[derive(Debug, Clone)]
-pub struct DagStats {
-    pub node_count: usize,
-    pub max_depth: u64,
-    pub tip_count: usize,
-    pub pending_count: usize,
+pub struct CausalSubgraph {
+    /// The event the subgraph was built from
+    pub root: EventId,
+    /// Events in breadth-first order with their distance (in parent hops) from `root`
+    pub nodes: Vec<(Event, usize)>,
+    /// `(child, parent)` edges; both ends are always present in `nodes`
+    pub edges: Vec<(EventId, EventId)>,
+    /// Whether ancestors were cut off by `max_depth` or could not be found
+    pub truncated: bool,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use setu_types::{Anchor, AnchorMerkleRoots, EventType, NodeInfo, ValidatorInfo};
-    use setu_vlc::VectorClock;
+/// Newest finalized anchor without its event list, as returned by
+/// `finalized_tip`. Light clients pin to this before requesting proofs.
+#[derive(Debug, Clone)]
+pub struct FinalizedTip {
+    pub anchor_id: String,
+    pub depth: u64,
+    /// Hex global state root committed by the anchor
+    pub global_state_root: String,
+    /// Anchor creation time (milliseconds)
+    pub timestamp: u64,
+    pub proposer: String,
+    /// The proposer's vote signature on the CF (empty if it did not sign)
+    pub proposer_signature: Vec<u8>,
+}
+
+impl FinalizedTip {
+    fn from_cf(cf: &ConsensusFrame) -> Self {
+        Self {
+            anchor_id: cf.anchor.id.clone(),
+            depth: cf.anchor.depth,
+            global_state_root: cf.anchor.state_root.clone(),
+            timestamp: cf.anchor.timestamp,
+            proposer: cf.proposer.clone(),
+            proposer_signature: cf
+                .votes
+                .get(&cf.proposer)
+                .map(|vote| vote.signature.clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Finalization progress snapshot, as returned by `health`
+#[derive(Debug, Clone)]
+pub struct ConsensusHealth {
+    pub round: Round,
+    /// Milliseconds since the newest finalized anchor was created (None = no anchor yet)
+    pub last_anchor_age_ms: Option<u64>,
+    /// DAG events not yet covered by a finalized anchor
+    pub pending_events: usize,
+    /// Logical-time gap between the local VLC and the newest finalized anchor
+    pub finalization_lag: u64,
+    /// Pending work exists but no anchor was finalized within the allowed age
+    pub stalled: bool,
+}
+
+/// DAG statistics
+#[derive(Debug, Clone)]
+pub struct DagStats {
+    pub node_count: usize,
+    pub max_depth: u64,
+    pub tip_count: usize,
+    pub pending_count: usize,
+    /// Parent-count distribution (see [`FanHistogram`])
+    pub fan_in: FanHistogram,
+    /// Child-count distribution (see [`FanHistogram`])
+    pub fan_out: FanHistogram,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::broadcaster::{BroadcastError, BroadcastResult};
+    use setu_types::{Anchor, AnchorMerkleRoots, EventType, NodeInfo, ValidatorInfo};
+    use setu_vlc::VectorClock;
     use std::collections::HashMap;
 
     fn create_validator_set() -> ValidatorSet {
@@ -1756,6 +2663,33 @@ mod tests {
         set
     }
 
+    fn validator_key(i: u8) -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&[i; 32])
+    }
+
+    /// `create_validator_set` with v1..v3 holding `validator_key(1..=3)`
+    fn keyed_validator_set() -> ValidatorSet {
+        let mut set = ValidatorSet::new();
+        for i in 1..=3u8 {
+            let mut node = NodeInfo::new_validator(
+                format!("v{}", i),
+                "127.0.0.1".to_string(),
+                8000 + i as u16,
+            );
+            node.public_key = validator_key(i).verifying_key().as_bytes().to_vec();
+            set.add_validator(ValidatorInfo::new(node, false));
+        }
+        set
+    }
+
+    /// `change` approved by the given validators of `keyed_validator_set`
+    fn approved_by(mut change: ValidatorSetChange, approvers: &[u8]) -> ValidatorSetChange {
+        for &i in approvers {
+            change.approve(format!("v{}", i), &validator_key(i).to_bytes()).unwrap();
+        }
+        change
+    }
+
     #[tokio::test]
     async fn test_engine_create_event() {
         let config = ConsensusConfig::default();
@@ -1765,6 +2699,66 @@ mod tests {
         assert_eq!(event.creator, "v1");
     }
 
+    #[tokio::test]
+    async fn test_mock_clock_controls_event_physical_time() {
+        let clock = Arc::new(setu_vlc::MockClock::new(1_000));
+        let config = ConsensusConfig::default();
+        let engine = ConsensusEngine::new(config, "v1".to_string(), create_validator_set())
+            .with_clock(clock.clone());
+
+        let mut events = Vec::new();
+        for step in [0, 250, 0, 40] {
+            clock.advance(step);
+            events.push(engine.create_event(vec!["p".to_string()]).await.unwrap());
+        }
+
+        let physical: Vec<u64> = events.iter().map(|e| e.vlc_snapshot.physical_time).collect();
+        assert_eq!(physical, vec![1_000, 1_250, 1_250, 1_290]);
+
+        // Sorting by (physical, logical) time reproduces creation order
+        let mut sorted = events.clone();
+        sorted.reverse();
+        sorted.sort_by_key(|e| (e.vlc_snapshot.physical_time, e.vlc_snapshot.logical_time));
+        let logical = |evs: &[Event]| evs.iter().map(|e| e.vlc_snapshot.logical_time).collect::<Vec<_>>();
+        assert_eq!(logical(&sorted), logical(&events));
+    }
+
+    #[tokio::test]
+    async fn test_create_event_falls_back_to_parent_selection() {
+        let config = ConsensusConfig::default();
+        let engine = ConsensusEngine::new(config, "v1".to_string(), create_validator_set())
+            .with_parent_selection(ParentSelectionStrategy::NMostRecent { n: 1 });
+
+        let genesis = engine.create_event(vec![]).await.unwrap();
+        assert!(genesis.parent_ids.is_empty());
+        let genesis_id = engine.dag_manager.add_event_with_retry(genesis).await.unwrap();
+
+        let event = engine.create_event(vec![]).await.unwrap();
+        assert_eq!(event.parent_ids, vec![genesis_id.clone()]);
+
+        // Explicit parents are kept as given
+        let event = engine.create_event(vec!["other".to_string()]).await.unwrap();
+        assert_eq!(event.parent_ids, vec!["other".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_try_select_parents_matches_async_selection() {
+        let engine = ConsensusEngine::new(ConsensusConfig::default(), "v1".to_string(), create_validator_set())
+            .with_parent_selection(ParentSelectionStrategy::NMostRecent { n: 1 });
+        let genesis = engine.create_event(vec![]).await.unwrap();
+        let genesis_id = engine.dag_manager.add_event_with_retry(genesis).await.unwrap();
+
+        assert_eq!(engine.try_select_parents().unwrap(), vec![genesis_id.clone()]);
+        assert_eq!(engine.try_select_parents().unwrap(), engine.select_parents().await);
+
+        // A writer held past every retry surfaces an error instead of no parents
+        let dag = engine.dag.write().await;
+        let err = engine.try_select_parents().expect_err("DAG is write-locked");
+        assert!(err.is_retryable(), "{}", err);
+        drop(dag);
+        assert_eq!(engine.try_select_parents().unwrap(), vec![genesis_id]);
+    }
+
     #[tokio::test]
     async fn test_engine_add_event() {
         let config = ConsensusConfig::default();
@@ -1793,6 +2787,7 @@ mod tests {
             max_events_per_cf: 1000,
             cf_timeout_ms: 5000,
             validator_count: 3,
+            ..Default::default()
         };
         let engine = ConsensusEngine::new(config, "v2".to_string(), create_validator_set());
         let anchor = Anchor::new(
@@ -1832,6 +2827,7 @@ mod tests {
             max_events_per_cf: 1000,
             cf_timeout_ms: 5000,
             validator_count: 3,
+            ..Default::default()
         };
         let engine = ConsensusEngine::new(config, "v1".to_string(), create_validator_set());
         let anchor = Anchor::new(
@@ -1857,6 +2853,314 @@ mod tests {
         assert_eq!(engine.current_round().await, 1);
     }
 
+    #[tokio::test]
+    async fn test_validator_set_change_applies_at_cf_boundary() {
+        let config = ConsensusConfig {
+            vlc_delta_threshold: 1,
+            min_events_per_cf: 1,
+            max_events_per_cf: 1000,
+            cf_timeout_ms: 5000,
+            validator_count: 3,
+            ..Default::default()
+        };
+        let engine = ConsensusEngine::new(config, "v2".to_string(), keyed_validator_set());
+
+        let v4 = NodeInfo::new_validator("v4".to_string(), "127.0.0.1".to_string(), 8004);
+        let add_v4 = ValidatorSetChange::add(1, ValidatorInfo::new(v4, false));
+
+        // Without a quorum of approvals the change is refused.
+        assert!(engine
+            .schedule_validator_set_change(approved_by(add_v4.clone(), &[2]))
+            .await
+            .is_err());
+        engine
+            .schedule_validator_set_change(approved_by(add_v4, &[1, 2, 3]))
+            .await
+            .unwrap();
+
+        // A change for a round that has already started cannot be applied consistently.
+        assert!(engine
+            .schedule_validator_set_change(approved_by(ValidatorSetChange::remove(0, "v3"), &[1, 2, 3]))
+            .await
+            .is_err());
+
+        // Anchor of round 0 finalizes under the old 3-node membership.
+        let anchor = Anchor::new(
+            vec![],
+            VLCSnapshot::default(),
+            "state-root".to_string(),
+            None,
+            0,
+        );
+        let mut cf = ConsensusFrame::new(anchor, "v1".to_string());
+        cf.add_vote(Vote::new("v1".to_string(), cf.id.clone(), true));
+        cf.add_vote(Vote::new("v2".to_string(), cf.id.clone(), true));
+        cf.add_vote(Vote::new("v3".to_string(), cf.id.clone(), true));
+        cf.finalize();
+
+        let (finalized, _) = engine.receive_finalized_cf(cf).await.unwrap();
+        assert!(finalized);
+        assert_eq!(engine.validator_set.read().await.count(), 3);
+        assert!(engine
+            .receive_vote(Vote::new("v4".to_string(), "cf-early".to_string(), true))
+            .await
+            .is_err());
+
+        // Crossing the CF boundary into round 1 applies the change.
+        engine.complete_pending_finalizations().await.unwrap();
+        assert_eq!(engine.current_round().await, 1);
+        {
+            let vs = engine.validator_set.read().await;
+            assert_eq!(vs.count(), 4);
+            assert_eq!(vs.quorum_size(), 3);
+            assert_eq!(vs.get_valid_proposer(1), Some("v2".to_string()));
+        }
+        assert_eq!(engine.consensus_manager.read().await.validator_count(), 4);
+
+        // The next anchor needs 3 of 4 votes.
+        let event = Event::new(
+            EventType::System,
+            vec![],
+            VLCSnapshot {
+                vector_clock: VectorClock::new(),
+                logical_time: 1,
+                physical_time: 0,
+            },
+            "v2".to_string(),
+        );
+        {
+            let mut vlc = engine.vlc.write().await;
+            vlc.merge(&event.vlc_snapshot);
+            vlc.tick();
+        }
+        engine.dag_manager.add_event_with_retry(event).await.unwrap();
+        let cf_id = engine
+            .try_create_cf()
+            .await
+            .unwrap()
+            .expect("v2 should propose a CF for round 1")
+            .id;
+
+        let (finalized, _) = engine
+            .receive_vote(Vote::new("v4".to_string(), cf_id.clone(), true))
+            .await
+            .unwrap();
+        assert!(!finalized, "2 of 4 votes must not finalize");
+
+        let (finalized, anchor) = engine
+            .receive_vote(Vote::new("v3".to_string(), cf_id, true))
+            .await
+            .unwrap();
+        assert!(finalized, "3 of 4 votes reach the new quorum");
+        assert!(anchor.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_validator_set_change_is_announced_to_peers() {
+        let config = ConsensusConfig {
+            validator_count: 3,
+            ..Default::default()
+        };
+        let leader = ConsensusEngine::new(config.clone(), "v1".to_string(), keyed_validator_set());
+        let broadcaster = Arc::new(crate::broadcaster::MockBroadcaster::new("v1".to_string(), 2));
+        leader.set_broadcaster(broadcaster.clone()).await;
+        leader.set_private_key(validator_key(1).to_bytes().to_vec()).await;
+
+        // Approvals from v2 and v3 are gathered first; the leader adds its own.
+        let mut change = approved_by(ValidatorSetChange::remove(1, "v3"), &[2, 3]);
+        leader.approve_validator_set_change(&mut change).await.unwrap();
+        leader.schedule_validator_set_change(change).await.unwrap();
+        let announced = broadcaster.get_validator_set_change_broadcasts();
+        assert_eq!(announced.len(), 1);
+
+        // A peer schedules the gossiped change exactly once.
+        let peer = ConsensusEngine::new(config, "v2".to_string(), keyed_validator_set());
+        let peer_broadcaster = Arc::new(crate::broadcaster::MockBroadcaster::new("v2".to_string(), 2));
+        peer.set_broadcaster(peer_broadcaster.clone()).await;
+        peer.receive_validator_set_change("v1", announced[0].clone()).await.unwrap();
+        peer.receive_validator_set_change("v1", announced[0].clone()).await.unwrap();
+        assert_eq!(peer.pending_validator_changes.lock().await.len(), 1);
+        assert!(peer_broadcaster.get_validator_set_change_broadcasts().is_empty());
+
+        // Announcements from outside the validator set are rejected.
+        assert!(peer
+            .receive_validator_set_change("intruder", approved_by(ValidatorSetChange::remove(1, "v2"), &[1, 2, 3]))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unapproved_validator_set_change_from_peer_rejected() {
+        let config = ConsensusConfig {
+            validator_count: 3,
+            ..Default::default()
+        };
+        let peer = ConsensusEngine::new(config, "v2".to_string(), keyed_validator_set());
+
+        // A single validator cannot reconfigure the set on its own
+        let solo = approved_by(ValidatorSetChange::remove(1, "v2"), &[1]);
+        assert!(peer.receive_validator_set_change("v1", solo).await.is_err());
+
+        // Nor can it forge the other approvals
+        let mut forged = approved_by(ValidatorSetChange::remove(1, "v2"), &[1]);
+        for id in ["v2", "v3"] {
+            forged.approve(id, &validator_key(1).to_bytes()).unwrap();
+        }
+        let err = peer.receive_validator_set_change("v1", forged).await.unwrap_err();
+        assert!(err.to_string().contains("invalid approval signature"));
+
+        assert!(peer.pending_validator_changes.lock().await.is_empty());
+    }
+
+    /// Broadcaster whose `broadcast_cf` parks until the test releases it
+    #[derive(Debug, Default)]
+    struct GatedBroadcaster {
+        entered: tokio::sync::Notify,
+        release: tokio::sync::Notify,
+    }
+
+    #[async_trait::async_trait]
+    impl ConsensusBroadcaster for GatedBroadcaster {
+        async fn broadcast_cf(&self, _cf: &ConsensusFrame) -> Result<BroadcastResult, BroadcastError> {
+            self.entered.notify_one();
+            self.release.notified().await;
+            Ok(BroadcastResult::success(2, 2))
+        }
+
+        async fn broadcast_vote(&self, _vote: &Vote) -> Result<BroadcastResult, BroadcastError> {
+            Ok(BroadcastResult::success(2, 2))
+        }
+
+        async fn broadcast_finalized(&self, _cf: &ConsensusFrame) -> Result<BroadcastResult, BroadcastError> {
+            Ok(BroadcastResult::success(2, 2))
+        }
+
+        async fn broadcast_event(&self, _event: &Event) -> Result<BroadcastResult, BroadcastError> {
+            Ok(BroadcastResult::success(2, 2))
+        }
+
+        async fn request_events(&self, _event_ids: &[EventId]) -> Result<Vec<Event>, BroadcastError> {
+            Ok(Vec::new())
+        }
+
+        fn peer_count(&self) -> usize {
+            2
+        }
+
+        fn local_validator_id(&self) -> &str {
+            "v1"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cf_broadcast_does_not_hold_engine_locks() {
+        let config = ConsensusConfig {
+            vlc_delta_threshold: 1,
+            validator_count: 3,
+            ..Default::default()
+        };
+        let engine = Arc::new(ConsensusEngine::new(config, "v1".to_string(), create_validator_set()));
+        let broadcaster = Arc::new(GatedBroadcaster::default());
+        engine.set_broadcaster(broadcaster.clone()).await;
+
+        let event = Event::new(
+            EventType::System,
+            vec![],
+            VLCSnapshot {
+                vector_clock: VectorClock::new(),
+                logical_time: 1,
+                physical_time: 0,
+            },
+            "v1".to_string(),
+        );
+        {
+            let mut vlc = engine.vlc.write().await;
+            vlc.merge(&event.vlc_snapshot);
+            vlc.tick();
+        }
+        engine.dag_manager.add_event_with_retry(event).await.unwrap();
+
+        let proposer = {
+            let engine = engine.clone();
+            tokio::spawn(async move { engine.try_create_cf().await })
+        };
+        broadcaster.entered.notified().await;
+
+        // While the broadcast is parked, the VLC and consensus manager stay available
+        let within = Duration::from_secs(1);
+        tokio::time::timeout(within, engine.tick_and_get_vlc())
+            .await
+            .expect("VLC lock held across broadcast");
+        tokio::time::timeout(within, engine.mark_anchor_persisted("unknown"))
+            .await
+            .expect("consensus manager lock held across broadcast");
+
+        broadcaster.release.notify_one();
+        let cf = proposer.await.unwrap().unwrap();
+        assert!(cf.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_vote_certificate_lists_all_approvers_with_total_power() {
+        let config = ConsensusConfig {
+            vlc_delta_threshold: 1,
+            validator_count: 3,
+            ..Default::default()
+        };
+        let mut validator_set = ValidatorSet::new();
+        for (i, stake) in [(1u16, 100u64), (2, 200), (3, 150)] {
+            let mut node = NodeInfo::new_validator(format!("v{}", i), "127.0.0.1".to_string(), 8000 + i);
+            node.stake = stake;
+            validator_set.add_validator(ValidatorInfo::new(node, false));
+        }
+        let engine = ConsensusEngine::new(config, "v1".to_string(), validator_set);
+
+        let event = Event::new(
+            EventType::System,
+            vec![],
+            VLCSnapshot {
+                vector_clock: VectorClock::new(),
+                logical_time: 1,
+                physical_time: 0,
+            },
+            "v1".to_string(),
+        );
+        {
+            let mut vlc = engine.vlc.write().await;
+            vlc.merge(&event.vlc_snapshot);
+            vlc.tick();
+        }
+        engine.dag_manager.add_event_with_retry(event).await.unwrap();
+        let cf = engine
+            .try_create_cf()
+            .await
+            .unwrap()
+            .expect("v1 should propose a CF for round 0");
+        assert!(engine.vote_certificate(&cf.anchor.id).is_none());
+
+        let (finalized, _) = engine
+            .receive_vote(Vote::new("v2".to_string(), cf.id.clone(), true))
+            .await
+            .unwrap();
+        assert!(!finalized, "2 of 3 votes must not finalize");
+        let (finalized, anchor) = engine
+            .receive_vote(Vote::new("v3".to_string(), cf.id.clone(), true))
+            .await
+            .unwrap();
+        assert!(finalized);
+
+        let cert = engine.vote_certificate(&anchor.unwrap().id).unwrap();
+        assert_eq!(cert.cf_id, cf.id);
+        let approvers: Vec<(&str, u128)> = cert
+            .approvals
+            .iter()
+            .map(|a| (a.validator_id.as_str(), a.voting_power))
+            .collect();
+        assert_eq!(approvers, vec![("v1", 100), ("v2", 200), ("v3", 150)]);
+        assert_eq!(cert.approved_power, 450);
+        assert_eq!(cert.total_power, 450);
+    }
+
     #[tokio::test]
     async fn test_f4_receive_finalized_cf_rejects_unsigned_in_strict_mode() {
         let config = ConsensusConfig {
@@ -1865,6 +3169,7 @@ mod tests {
             max_events_per_cf: 1000,
             cf_timeout_ms: 5000,
             validator_count: 3,
+            ..Default::default()
         };
         let engine = ConsensusEngine::new(config, "v1".to_string(), create_validator_set());
         engine.enable_strict_vote_signatures();
@@ -1940,6 +3245,7 @@ mod tests {
             max_events_per_cf: 1000,
             cf_timeout_ms: 5000,
             validator_count: 3,
+            ..Default::default()
         };
         let engine = ConsensusEngine::new(config, "v1".to_string(), create_validator_set());
         engine.enable_strict_vote_signatures();
@@ -1972,6 +3278,7 @@ mod tests {
             max_events_per_cf: 1000,
             cf_timeout_ms: 1,
             validator_count: 3,
+            ..Default::default()
         };
         let engine = ConsensusEngine::new(config, "v1".to_string(), create_validator_set());
         let anchor = Anchor::new(
@@ -2000,6 +3307,39 @@ mod tests {
         assert_eq!(engine.current_round().await, 0);
     }
 
+    #[tokio::test]
+    async fn test_add_event_rejects_when_dag_full() {
+        let config = ConsensusConfig {
+            vlc_delta_threshold: 1_000,
+            max_dag_events: 3,
+            ..Default::default()
+        };
+        let engine = ConsensusEngine::new(config, "v1".to_string(), create_validator_set());
+
+        for _ in 0..3 {
+            let event = engine.create_event(vec![]).await.unwrap();
+            engine.add_event(event).await.unwrap();
+        }
+
+        let event = engine.create_event(vec![]).await.unwrap();
+        let err = engine.add_event(event).await.unwrap_err();
+        assert!(matches!(err, setu_types::SetuError::Overloaded(_)));
+        assert!(err.is_retryable());
+        assert_eq!(engine.get_dag_stats().await.node_count, 3);
+
+        // Genesis bypasses backpressure
+        let genesis = Event::genesis(
+            "v1".to_string(),
+            VLCSnapshot {
+                vector_clock: VectorClock::new(),
+                logical_time: 0,
+                physical_time: 0,
+            },
+        );
+        engine.add_event(genesis).await.unwrap();
+        assert_eq!(engine.get_dag_stats().await.node_count, 4);
+    }
+
     #[tokio::test]
     async fn test_inline_finalization_removes_events_from_pending_before_persistence() {
         let config = ConsensusConfig {
@@ -2008,6 +3348,7 @@ mod tests {
             max_events_per_cf: 1000,
             cf_timeout_ms: 5000,
             validator_count: 1,
+            ..Default::default()
         };
         let engine = ConsensusEngine::new(config, "v1".to_string(), create_validator_set());
 
@@ -2045,6 +3386,7 @@ mod tests {
             max_events_per_cf: 1000,
             cf_timeout_ms: 5000,
             validator_count: 3,
+            ..Default::default()
         };
 
         let leader = ConsensusEngine::new(config.clone(), "v1".to_string(), create_validator_set());
@@ -2107,6 +3449,222 @@ mod tests {
         );
     }
 
+    /// Hammers the multi-lock paths (`receive_cf`, `receive_vote`, event
+    /// ingestion, leader CF creation) concurrently on a multi-threaded runtime.
+    /// An inversion on a path the debug lock-order checker does not cover
+    /// shows up as a timeout.
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_consensus_paths_do_not_deadlock() {
+        let config = ConsensusConfig {
+            vlc_delta_threshold: 1,
+            min_events_per_cf: 1,
+            max_events_per_cf: 1000,
+            cf_timeout_ms: 5000,
+            validator_count: 3,
+            ..Default::default()
+        };
+        let make_event = |creator: &str, logical_time: u64| {
+            Event::new(
+                EventType::System,
+                vec![],
+                VLCSnapshot {
+                    vector_clock: VectorClock::new(),
+                    logical_time,
+                    physical_time: 0,
+                },
+                creator.to_string(),
+            )
+        };
+
+        for iteration in 0..20u64 {
+            let leader = Arc::new(ConsensusEngine::new(
+                config,
+                "v1".to_string(),
+                create_validator_set(),
+            ));
+            let follower = Arc::new(ConsensusEngine::new(
+                config,
+                "v2".to_string(),
+                create_validator_set(),
+            ));
+
+            let seed = make_event("v1", 1);
+            {
+                let mut vlc = leader.vlc.write().await;
+                vlc.merge(&seed.vlc_snapshot);
+                vlc.tick();
+            }
+            leader.dag_manager.add_event_with_retry(seed.clone()).await.unwrap();
+            follower.receive_event_from_network(seed).await.unwrap();
+            let mut cf = leader
+                .try_create_cf()
+                .await
+                .unwrap()
+                .expect("leader should have created a pending CF");
+            cf.add_vote(Vote::new("v1".to_string(), cf.id.clone(), true));
+
+            let mut tasks = Vec::new();
+            for _ in 0..4 {
+                let (f, cf) = (Arc::clone(&follower), cf.clone());
+                tasks.push(tokio::spawn(async move {
+                    // Duplicates may be rejected once the first copy finalizes
+                    let _ = f.receive_cf(cf).await;
+                }));
+            }
+            for validator in ["v2", "v3"] {
+                let (l, f, cf_id) = (Arc::clone(&leader), Arc::clone(&follower), cf.id.clone());
+                tasks.push(tokio::spawn(async move {
+                    let vote = Vote::new(validator.to_string(), cf_id, true);
+                    let _ = l.receive_vote(vote.clone()).await;
+                    // The follower casts its own v2 vote in receive_cf
+                    if validator != "v2" {
+                        let _ = f.receive_vote(vote).await;
+                    }
+                }));
+            }
+            for i in 0..8u64 {
+                let (l, f) = (Arc::clone(&leader), Arc::clone(&follower));
+                let event = make_event("v3", 100 + iteration * 10 + i);
+                tasks.push(tokio::spawn(async move {
+                    let _ = f.receive_event_from_network(event.clone()).await;
+                    let _ = l.add_event(event).await;
+                    let _ = f.health(0, u64::MAX).await;
+                }));
+            }
+
+            let joined = tokio::time::timeout(tokio::time::Duration::from_secs(10), async {
+                for task in tasks {
+                    task.await.expect("consensus task panicked");
+                }
+            })
+            .await;
+            assert!(
+                joined.is_ok(),
+                "iteration {}: concurrent consensus calls deadlocked",
+                iteration
+            );
+            assert_eq!(
+                follower.pending_completions_len().await,
+                1,
+                "iteration {}: follower should finalize the CF exactly once",
+                iteration
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_health_reports_stalled_finalization() {
+        let engine = ConsensusEngine::new(ConsensusConfig::default(), "v1".to_string(), create_validator_set());
+
+        // Idle DAG: nothing to finalize, never stalled.
+        let idle = engine.health(1_000, 5_000).await;
+        assert!(!idle.stalled);
+        assert_eq!(idle.last_anchor_age_ms, None);
+
+        let event = Event::new(
+            EventType::System,
+            vec![],
+            VLCSnapshot {
+                vector_clock: VectorClock::new(),
+                logical_time: 1,
+                physical_time: 0,
+            },
+            "v1".to_string(),
+        );
+        engine.dag_manager.add_event_with_retry(event).await.unwrap();
+
+        // Pending work and no anchor ever finalized.
+        let health = engine.health(1_000, 5_000).await;
+        assert_eq!(health.pending_events, 1);
+        assert!(health.stalled);
+
+        let anchor = Anchor::new(vec![], VLCSnapshot::default(), "state-root".to_string(), None, 0);
+        let anchor_ts = anchor.timestamp;
+        let mut cf = ConsensusFrame::new(anchor, "v1".to_string());
+        for v in ["v1", "v2", "v3"] {
+            cf.add_vote(Vote::new(v.to_string(), cf.id.clone(), true));
+        }
+        cf.finalize();
+        assert!(engine.receive_finalized_cf(cf).await.unwrap().0);
+
+        // A recent anchor keeps the engine healthy...
+        let health = engine.health(anchor_ts + 100, 5_000).await;
+        assert_eq!(health.last_anchor_age_ms, Some(100));
+        assert!(!health.stalled);
+
+        // ...but once anchors stop arriving while work is pending, it is stalled.
+        let health = engine.health(anchor_ts + 60_000, 5_000).await;
+        assert!(health.stalled);
+    }
+
+    #[tokio::test]
+    async fn test_causal_subgraph_of_diamond() {
+        let config = ConsensusConfig::default();
+        let engine = ConsensusEngine::new(config, "v1".to_string(), create_validator_set());
+
+        let vlc = |t: u64| {
+            let mut vector_clock = VectorClock::new();
+            vector_clock.set("v1", t);
+            VLCSnapshot { vector_clock, logical_time: t, physical_time: 0 }
+        };
+        //      g
+        //     / \
+        //    a   b
+        //     \ /
+        //      d
+        let g = Event::genesis("v1".to_string(), vlc(0));
+        let a = Event::new(EventType::System, vec![g.id.clone()], vlc(1), "v1".to_string());
+        let b = Event::new(EventType::System, vec![g.id.clone()], vlc(1), "v2".to_string());
+        let d = Event::new(
+            EventType::System,
+            vec![a.id.clone(), b.id.clone()],
+            vlc(2),
+            "v3".to_string(),
+        );
+        for event in [&g, &a, &b, &d] {
+            engine.dag_manager.add_event_with_retry(event.clone()).await.unwrap();
+        }
+
+        let subgraph = engine.get_causal_subgraph(&d.id, 8).await.unwrap();
+        assert_eq!(subgraph.root, d.id);
+        assert!(!subgraph.truncated);
+
+        let nodes: HashMap<EventId, usize> = subgraph
+            .nodes
+            .iter()
+            .map(|(event, distance)| (event.id.clone(), *distance))
+            .collect();
+        assert_eq!(nodes.len(), 4);
+        assert_eq!(nodes[&d.id], 0);
+        assert_eq!(nodes[&a.id], 1);
+        assert_eq!(nodes[&b.id], 1);
+        assert_eq!(nodes[&g.id], 2);
+
+        let mut edges = subgraph.edges.clone();
+        edges.sort();
+        let mut expected = vec![
+            (d.id.clone(), a.id.clone()),
+            (d.id.clone(), b.id.clone()),
+            (a.id.clone(), g.id.clone()),
+            (b.id.clone(), g.id.clone()),
+        ];
+        expected.sort();
+        assert_eq!(edges, expected);
+
+        // Depth bound cuts off the shared grandparent.
+        let shallow = engine.get_causal_subgraph(&d.id, 1).await.unwrap();
+        assert_eq!(shallow.nodes.len(), 3);
+        assert_eq!(shallow.edges.len(), 2);
+        assert!(shallow.truncated);
+
+        // A bound that exactly reaches the oldest ancestor is not truncated.
+        let exact = engine.get_causal_subgraph(&d.id, 2).await.unwrap();
+        assert_eq!(exact.nodes.len(), 4);
+        assert!(!exact.truncated);
+
+        assert!(engine.get_causal_subgraph(&"unknown".to_string(), 8).await.is_none());
+    }
+
     #[tokio::test]
     async fn test_engine_leader_check() {
         let config = ConsensusConfig::default();
@@ -2146,6 +3704,42 @@ mod tests {
         assert_ne!(proposer_0, proposer_1);
     }
 
+    #[tokio::test]
+    async fn test_receive_cf_rejects_event_set_missing_pending_parent() {
+        let config = ConsensusConfig::default();
+        let engine = ConsensusEngine::new(config, "v2".to_string(), create_validator_set());
+        let vlc = |logical_time| {
+            let mut vector_clock = VectorClock::new();
+            vector_clock.set("v1", logical_time);
+            VLCSnapshot { vector_clock, logical_time, physical_time: 0 }
+        };
+
+        let parent = Event::new(EventType::System, vec![], vlc(1), "v1".to_string());
+        let child = Event::new(
+            EventType::System,
+            vec![parent.id.clone()],
+            vlc(2),
+            "v1".to_string(),
+        );
+        engine.receive_event_from_network(parent.clone()).await.unwrap();
+        engine.receive_event_from_network(child.clone()).await.unwrap();
+
+        // Child included without its still-pending parent
+        let anchor = Anchor::new(vec![child.id.clone()], vlc(2), "root".to_string(), None, 0);
+        let err = engine
+            .receive_cf(ConsensusFrame::new(anchor, "v1".to_string()))
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("not causally closed"), "unexpected error: {}", err);
+        assert!(err.contains(&parent.id));
+
+        // Including the parent closes the set
+        let anchor = Anchor::new(vec![parent.id, child.id], vlc(2), "root".to_string(), None, 0);
+        let cf = ConsensusFrame::new(anchor, "v1".to_string());
+        engine.receive_cf(cf).await.expect("causally closed CF must be accepted");
+    }
+
     #[tokio::test]
     async fn test_anchor_chain_root_verification() {
         use setu_types::{merkle::AnchorMerkleRoots, Anchor};
@@ -2357,6 +3951,7 @@ mod tests {
             max_events_per_cf: 100,
             cf_timeout_ms: 5000,
             validator_count: 4, // 4 validators: need 2 rejects to reject (1/3+1)
+            ..Default::default()
         };
 
         let validator_set = create_validator_set();
@@ -2419,6 +4014,7 @@ mod tests {
             max_events_per_cf: 100,
             cf_timeout_ms: 100, // 100ms timeout for testing
             validator_count: 4,
+            ..Default::default()
         };
 
         let validator_set = create_validator_set();
@@ -2473,6 +4069,7 @@ mod tests {
             max_events_per_cf: 100,
             cf_timeout_ms: 100, // 100ms timeout
             validator_count: 4,
+            ..Default::default()
         };
 
         let validator_set = create_validator_set();
@@ -2525,6 +4122,7 @@ mod tests {
             max_events_per_cf: 100,
             cf_timeout_ms: 5000,
             validator_count: 4,
+            ..Default::default()
         };
 
         let validator_set = create_validator_set();
@@ -512,17 +512,22 @@ impl ConsensusManager {
         self.persisted_anchor_ids.insert(anchor_id.to_string());
         // Note: persisted_anchor_ids is cleaned up when corresponding CFs are GC'd
         // in gc_finalized_cfs(), so it won't grow unbounded
+        
+        // CFs finalized while persistence lagged could not be evicted at
+        // finalization time; retry now that one more anchor is durable.
+        self.gc_finalized_cfs();
     }
     
-    /// Garbage collect finalized CFs, only removing those that have been persisted
+    /// Garbage collect finalized CFs down to `config.finalized_cf_retention`,
+    /// oldest first, only removing those that have been persisted
     fn gc_finalized_cfs(&mut self) {
-        const MAX_FINALIZED_CFS: usize = 1000;
+        let retention = self.config.finalized_cf_retention;
         
-        if self.finalized_cfs.len() <= MAX_FINALIZED_CFS {
+        if self.finalized_cfs.len() <= retention {
             return;
         }
         
-        let excess = self.finalized_cfs.len() - MAX_FINALIZED_CFS;
+        let excess = self.finalized_cfs.len() - retention;
         
         // Collect anchor IDs that will be removed (for cleaning persisted_anchor_ids)
         let mut removed_anchor_ids = Vec::new();
@@ -593,6 +598,11 @@ impl ConsensusManager {
         self.finalized_cfs.len()
     }
 
+    /// CFs currently held in memory (pending votes + retained finalized)
+    pub fn in_memory_cf_count(&self) -> usize {
+        self.pending_cfs.len() + self.finalized_cfs.len()
+    }
+
     pub fn last_finalized_cf(&self) -> Option<&ConsensusFrame> {
         self.finalized_cfs.last()
     }
@@ -870,4 +880,57 @@ mod tests {
         assert!(manager.is_finalized_cf(&cf_id));
         assert!(!manager.receive_finalized_cf(duplicate_finalized_cf));
     }
+
+    fn finalize_synthetic_cf(manager: &mut ConsensusManager, depth: u64) -> String {
+        let anchor = Anchor::new(
+            vec![format!("event-{}", depth)],
+            VLCSnapshot::default(),
+            "state-root".to_string(),
+            None,
+            depth,
+        );
+        let anchor_id = anchor.id.clone();
+        let mut cf = ConsensusFrame::new(anchor, "validator1".to_string());
+        let cf_id = cf.id.clone();
+        cf.add_vote(Vote::new("validator1".to_string(), cf_id, true));
+        cf.finalize();
+        assert!(manager.receive_finalized_cf(cf));
+        anchor_id
+    }
+
+    #[test]
+    fn test_finalized_cf_retention_evicts_persisted_cfs() {
+        let config = ConsensusConfig {
+            validator_count: 1,
+            finalized_cf_retention: 5,
+            ..Default::default()
+        };
+        let mut manager = ConsensusManager::new(config, "validator1".to_string());
+
+        // Nothing persisted yet: every finalized CF stays in memory
+        let anchor_ids: Vec<String> = (0..20)
+            .map(|depth| finalize_synthetic_cf(&mut manager, depth))
+            .collect();
+        assert_eq!(manager.finalized_count(), 20);
+
+        // Persisting catches up: memory shrinks to the retention bound,
+        // keeping the newest CFs
+        for anchor_id in &anchor_ids {
+            manager.mark_anchor_persisted(anchor_id);
+        }
+        assert_eq!(manager.finalized_count(), 5);
+        assert_eq!(manager.in_memory_cf_count(), 5);
+        assert_eq!(
+            manager.get_last_finalized_anchor().unwrap().id,
+            *anchor_ids.last().unwrap()
+        );
+
+        // Steady state: finalize + persist keeps memory at the bound
+        for depth in 20..40 {
+            let anchor_id = finalize_synthetic_cf(&mut manager, depth);
+            manager.mark_anchor_persisted(&anchor_id);
+            assert_eq!(manager.finalized_count(), 5);
+        }
+        assert!(manager.persisted_anchor_ids.len() <= 5);
+    }
 }
@@ -38,6 +38,7 @@ pub mod dag_manager;
 pub mod engine;
 pub mod folder;
 pub mod liveness;
+mod lock_order;
 pub mod merkle_integration;
 pub mod outcome_sink;
 pub mod recent_cache;
@@ -55,24 +56,37 @@ pub use anchor_builder::{
 pub use broadcaster::{
     ConsensusBroadcaster, BroadcastError, BroadcastResult,
     NoOpBroadcaster, MockBroadcaster, OptionalBroadcaster,
+    SimulatedDelivery, SimulatedMessage,
+    RetryingBroadcaster, RetryPolicy, DeliveryStats,
+};
+pub use dag::{
+    Dag, DagError, FanHistogram, GCStats, OrphanedEvent, ParentSelectionStrategy,
+    DEFAULT_ORPHAN_ANCHOR_THRESHOLD, FAN_HISTOGRAM_BUCKETS,
 };
-pub use dag::{Dag, DagError, GCStats};
 pub use dag_manager::{
     DagManager, DagManagerConfig, DagManagerError,
     ParentInfo, ResolvedParents, GcStats, WarmupStats, DagStatsSnapshot,
 };
-pub use engine::{ConsensusEngine, ConsensusMessage, DagStats};
+pub use engine::{
+    CausalSubgraph, ConsensusEngine, ConsensusHealth, ConsensusMessage, DagStats, FinalizedTip,
+};
 pub use folder::{ConsensusManager, DagFolder};
 pub use merkle_integration::{
     compute_events_root, compute_anchor_chain_root, compute_global_state_root,
-    AnchorMerkleRootsBuilder,
+    verify_subnet_in_global, AnchorMerkleRootsBuilder,
+    append_anchor_chain_root, recompute_anchor_chain_root, AnchorChainAudit, AnchorChainMismatch,
 };
 pub use outcome_sink::OutcomeSink;
-pub use root_executor::{RootSubnetExecutor, RootExecutorError, RootExecutionResult};
+pub use root_executor::{RootSubnetExecutor, RootExecutorError, RootExecutionResult, RootEventOutcome};
 pub use router::{EventRouter, RoutedEvents, SubnetExecutionBatch, create_execution_batches};
 pub use recent_cache::{RecentEventCache, FinalizedEventMeta, CacheStats, CacheStatsSnapshot};
-pub use tee_verifier::{TeeVerifier, TeeAttestation, VerificationResult, SolverRegistry, SolverInfo};
-pub use validator_set::{ElectionStrategy, ValidatorSet};
+pub use tee_verifier::{
+    TeeVerifier, TeeAttestation, VerificationResult, SolverRegistry, SolverInfo,
+    AgreementThreshold, ReconcileResult,
+};
+pub use validator_set::{
+    ElectionStrategy, ValidatorSet, ValidatorSetChange, ValidatorSetChangeApproval, ValidatorSetChangeKind,
+};
 pub use vlc::VLC;
 
 // Re-export liveness types
@@ -0,0 +1,240 @@
+// Copyright (c) Hetu Project
+// SPDX-License-Identifier: Apache-2.0
+
+//! Debug-only checker for the `ConsensusEngine` lock order
+//!
+//! The engine acquires its locks in a fixed order (see "Lock ordering" in
+//! `engine.rs`). Every acquisition goes through [`ordered`], which records the
+//! lock against the owning engine and the current task. In debug builds,
+//! taking a lock that ranks at or before one the task already holds on the
+//! same engine panics immediately, so an inversion fails the first test that
+//! exercises it instead of deadlocking only under contention. Locks of two
+//! different engines never conflict, since they cannot deadlock each other.
+//! Release builds skip the bookkeeping entirely.
+
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Identifies the engine whose locks are being ordered
+///
+/// Each `ConsensusEngine` takes a fresh one at construction, so tests and
+/// nodes that drive several engines from one task are checked per engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct LockOwner(u64);
+
+impl LockOwner {
+    pub(crate) fn new() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Engine locks, declared in their canonical acquisition order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum EngineLock {
+    Vlc,
+    ConsensusManager,
+    Dag,
+    ValidatorSet,
+    PrivateKey,
+    Broadcaster,
+}
+
+/// A lock guard paired with its lock-order registration
+///
+/// Derefs to the locked value like the wrapped guard. Dropping it releases
+/// the lock first, then unregisters it.
+pub(crate) struct OrderedGuard<G> {
+    guard: G,
+    _token: OrderToken,
+}
+
+impl<G: Deref> Deref for OrderedGuard<G> {
+    type Target = G::Target;
+
+    fn deref(&self) -> &G::Target {
+        &self.guard
+    }
+}
+
+impl<G: DerefMut> DerefMut for OrderedGuard<G> {
+    fn deref_mut(&mut self) -> &mut G::Target {
+        &mut self.guard
+    }
+}
+
+/// Acquire a lock of `owner`, checking it against the locks of `owner` the
+/// task already holds
+///
+/// The check runs before awaiting `acquire`, because the wait is where an
+/// inversion would deadlock.
+pub(crate) async fn ordered<G, F>(owner: LockOwner, lock: EngineLock, acquire: F) -> OrderedGuard<G>
+where
+    F: Future<Output = G>,
+{
+    let token = OrderToken::register(owner, lock);
+    OrderedGuard {
+        guard: acquire.await,
+        _token: token,
+    }
+}
+
+#[cfg(debug_assertions)]
+struct OrderToken {
+    holder: registry::Holder,
+    owner: LockOwner,
+    lock: EngineLock,
+}
+
+#[cfg(debug_assertions)]
+impl OrderToken {
+    fn register(owner: LockOwner, lock: EngineLock) -> Self {
+        let holder = registry::Holder::current();
+        if let Err(held) = registry::push(holder, owner, lock) {
+            panic!(
+                "engine lock order violated: acquiring {:?} while holding {:?} \
+                 (see \"Lock ordering\" in consensus/src/engine.rs)",
+                lock, held
+            );
+        }
+        Self {
+            holder,
+            owner,
+            lock,
+        }
+    }
+}
+
+#[cfg(debug_assertions)]
+impl Drop for OrderToken {
+    fn drop(&mut self) {
+        registry::pop(self.holder, self.owner, self.lock);
+    }
+}
+
+#[cfg(not(debug_assertions))]
+struct OrderToken;
+
+#[cfg(not(debug_assertions))]
+impl OrderToken {
+    fn register(_owner: LockOwner, _lock: EngineLock) -> Self {
+        Self
+    }
+}
+
+#[cfg(debug_assertions)]
+mod registry {
+    use super::{EngineLock, LockOwner};
+    use parking_lot::Mutex;
+    use std::collections::HashMap;
+    use std::sync::OnceLock;
+
+    /// Who holds the locks: the tokio task, or the thread when running
+    /// outside a task (e.g. a `block_on` body)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub(super) enum Holder {
+        Task(tokio::task::Id),
+        Thread(std::thread::ThreadId),
+    }
+
+    impl Holder {
+        pub(super) fn current() -> Self {
+            match tokio::task::try_id() {
+                Some(id) => Self::Task(id),
+                None => Self::Thread(std::thread::current().id()),
+            }
+        }
+    }
+
+    /// Locks held per (task or thread, engine)
+    type Held = HashMap<(Holder, LockOwner), Vec<EngineLock>>;
+
+    fn held() -> &'static Mutex<Held> {
+        static HELD: OnceLock<Mutex<Held>> = OnceLock::new();
+        HELD.get_or_init(Default::default)
+    }
+
+    /// Record `lock` as held, or return the held lock it conflicts with
+    pub(super) fn push(
+        holder: Holder,
+        owner: LockOwner,
+        lock: EngineLock,
+    ) -> Result<(), EngineLock> {
+        let mut held = held().lock();
+        let locks = held.entry((holder, owner)).or_default();
+        if let Some(&conflict) = locks.iter().find(|&&h| h >= lock) {
+            return Err(conflict);
+        }
+        locks.push(lock);
+        Ok(())
+    }
+
+    pub(super) fn pop(holder: Holder, owner: LockOwner, lock: EngineLock) {
+        let mut held = held().lock();
+        if let Some(locks) = held.get_mut(&(holder, owner)) {
+            if let Some(pos) = locks.iter().rposition(|&h| h == lock) {
+                locks.remove(pos);
+            }
+            if locks.is_empty() {
+                held.remove(&(holder, owner));
+            }
+        }
+    }
+}
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use super::*;
+    use tokio::sync::RwLock;
+
+    #[tokio::test]
+    async fn test_canonical_order_is_accepted() {
+        let owner = LockOwner::new();
+        let (vlc, dag) = (RwLock::new(()), RwLock::new(()));
+        let v = ordered(owner, EngineLock::Vlc, vlc.read()).await;
+        let d = ordered(owner, EngineLock::Dag, dag.write()).await;
+        drop(d);
+        drop(v);
+
+        // Released locks no longer constrain later acquisitions
+        let _d = ordered(owner, EngineLock::Dag, dag.read()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "acquiring Vlc while holding Dag")]
+    async fn test_inversion_panics() {
+        let owner = LockOwner::new();
+        let (vlc, dag) = (RwLock::new(()), RwLock::new(()));
+        let _d = ordered(owner, EngineLock::Dag, dag.read()).await;
+        let _v = ordered(owner, EngineLock::Vlc, vlc.write()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "acquiring Dag while holding Dag")]
+    async fn test_reentrant_acquisition_panics() {
+        let owner = LockOwner::new();
+        let dag = RwLock::new(());
+        let _a = ordered(owner, EngineLock::Dag, dag.read()).await;
+        let _b = ordered(owner, EngineLock::Dag, dag.read()).await;
+    }
+
+    #[tokio::test]
+    async fn test_engines_on_one_task_are_checked_separately() {
+        let (a, b) = (LockOwner::new(), LockOwner::new());
+        let (dag_a, vlc_b) = (RwLock::new(()), RwLock::new(()));
+        let _d = ordered(a, EngineLock::Dag, dag_a.read()).await;
+        // Engine `b` starts from an empty lock set even though `a` holds Dag
+        let _v = ordered(b, EngineLock::Vlc, vlc_b.write()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "acquiring Vlc while holding Dag")]
+    async fn test_other_engine_does_not_mask_inversion() {
+        let (a, b) = (LockOwner::new(), LockOwner::new());
+        let (vlc_a, dag_a, dag_b) = (RwLock::new(()), RwLock::new(()), RwLock::new(()));
+        let _db = ordered(b, EngineLock::Dag, dag_b.read()).await;
+        let _da = ordered(a, EngineLock::Dag, dag_a.read()).await;
+        let _va = ordered(a, EngineLock::Vlc, vlc_a.write()).await;
+    }
+}
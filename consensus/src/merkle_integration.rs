@@ -11,8 +11,11 @@
 //! This ensures all validators compute the same events_root.
 //! Sort order: VLC.logical_time (ascending), then event_id (lexicographic)
 
-use setu_merkle::{BinaryMerkleTree, HashValue as MerkleHash, SubnetAggregationTree, SubnetStateEntry};
-use setu_types::{Anchor, AnchorMerkleRoots, Event, SubnetId, HashValue as TypesHash};
+use setu_merkle::{
+    BinaryMerkleTree, HashValue as MerkleHash, SubnetAggregationProof, SubnetAggregationTree,
+    SubnetStateEntry,
+};
+use setu_types::{sort_events_canonically, Anchor, AnchorMerkleRoots, Event, SubnetId, HashValue as TypesHash};
 use std::collections::HashMap;
 
 /// Convert MerkleHash to TypesHash ([u8; 32])
@@ -20,25 +23,6 @@ fn to_types_hash(h: MerkleHash) -> TypesHash {
     *h.as_bytes()
 }
 
-/// Sort events by VLC for deterministic ordering
-/// 
-/// All validators must apply events in the same order to get consistent state.
-/// Sort order:
-/// 1. Primary: VLC logical_time (ascending)
-/// 2. Secondary: event_id (lexicographic, for tie-breaking)
-fn sort_events_by_vlc(events: &mut [Event]) {
-    events.sort_by(|a, b| {
-        // Primary sort by VLC logical_time
-        match a.vlc_snapshot.logical_time.cmp(&b.vlc_snapshot.logical_time) {
-            std::cmp::Ordering::Equal => {
-                // Tie-breaker: sort by event_id lexicographically
-                a.id.cmp(&b.id)
-            }
-            other => other,
-        }
-    });
-}
-
 /// Computes the events Merkle root from a list of events
 ///
 /// According to mkt-3.md, events are committed using a Binary Merkle Tree
@@ -53,7 +37,7 @@ pub fn compute_events_root(events: &[Event]) -> MerkleHash {
     
     // Sort events by VLC for deterministic ordering
     let mut sorted_events = events.to_vec();
-    sort_events_by_vlc(&mut sorted_events);
+    sort_events_canonically(&mut sorted_events);
     
     // Build tree from sorted event ID bytes
     let leaves: Vec<&[u8]> = sorted_events
@@ -76,7 +60,7 @@ pub fn compute_events_root_with_sorted(events: &[Event]) -> (MerkleHash, Vec<Eve
     
     // Sort events by VLC for deterministic ordering
     let mut sorted_events = events.to_vec();
-    sort_events_by_vlc(&mut sorted_events);
+    sort_events_canonically(&mut sorted_events);
     
     // Build tree from sorted event ID bytes
     let leaves: Vec<&[u8]> = sorted_events
@@ -124,6 +108,88 @@ pub fn compute_anchor_chain_root_from_hashes(anchor_hashes: &[[u8; 32]]) -> [u8;
     *tree.root().as_bytes()
 }
 
+/// Extends the anchor chain root with one finalized anchor
+///
+/// `new_root = chain_hash(prev_root, anchor.compute_hash())` — O(1) per
+/// anchor. This is the linkage `AnchorBuilder` maintains as anchors finalize
+/// and that each anchor commits (as the "before" value) in
+/// `merkle_roots.anchor_chain_root`.
+pub fn append_anchor_chain_root(prev_root: &[u8; 32], anchor: &Anchor) -> [u8; 32] {
+    setu_types::hash_utils::chain_hash(prev_root, &anchor.compute_hash())
+}
+
+/// An anchor whose committed chain root does not link to its predecessors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnchorChainMismatch {
+    pub depth: u64,
+    /// Chain root recomputed over the anchors before this one
+    pub expected: [u8; 32],
+    /// Chain root the anchor committed to
+    pub committed: [u8; 32],
+}
+
+/// Streaming form of [`recompute_anchor_chain_root`]
+///
+/// Feed anchors in chain order with [`push`](Self::push); only the running
+/// root is kept, so an audit over a long chain never holds more than one
+/// anchor in memory.
+#[derive(Debug, Clone, Default)]
+pub struct AnchorChainAudit {
+    root: [u8; 32],
+    anchors: u64,
+}
+
+impl AnchorChainAudit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check that `anchor` commits to the chain so far, then append it.
+    ///
+    /// Anchors without Merkle roots do not take part in the chain, matching
+    /// `AnchorBuilder`.
+    pub fn push(&mut self, anchor: &Anchor) -> Result<(), AnchorChainMismatch> {
+        let Some(roots) = anchor.merkle_roots.as_ref() else {
+            return Ok(());
+        };
+        if roots.anchor_chain_root != self.root {
+            return Err(AnchorChainMismatch {
+                depth: anchor.depth,
+                expected: self.root,
+                committed: roots.anchor_chain_root,
+            });
+        }
+        self.root = append_anchor_chain_root(&self.root, anchor);
+        self.anchors += 1;
+        Ok(())
+    }
+
+    /// Chain root over every anchor pushed so far
+    pub fn root(&self) -> [u8; 32] {
+        self.root
+    }
+
+    /// Number of anchors that took part in the chain
+    pub fn anchor_count(&self) -> u64 {
+        self.anchors
+    }
+}
+
+/// Recomputes the incremental anchor chain root from genesis (audit path)
+///
+/// Folds [`append_anchor_chain_root`] over `anchors` in chain order and
+/// checks that every anchor committed to the root of the anchors before it.
+/// O(n) in the chain length, so reserve it for audits and recovery checks
+/// rather than the per-anchor path; use [`AnchorChainAudit`] directly when
+/// the anchors come from storage.
+pub fn recompute_anchor_chain_root(anchors: &[Anchor]) -> Result<[u8; 32], AnchorChainMismatch> {
+    let mut audit = AnchorChainAudit::new();
+    for anchor in anchors {
+        audit.push(anchor)?;
+    }
+    Ok(audit.root())
+}
+
 /// Computes the global state root from subnet state roots
 ///
 /// Uses SubnetAggregationTree to combine all subnet roots into a single
@@ -133,6 +199,11 @@ pub fn compute_global_state_root(subnet_roots: &HashMap<SubnetId, MerkleHash>) -
         return MerkleHash::zero();
     }
     
+    build_aggregation_tree(subnet_roots).root()
+}
+
+/// Build the SubnetAggregationTree behind `compute_global_state_root`
+fn build_aggregation_tree(subnet_roots: &HashMap<SubnetId, MerkleHash>) -> SubnetAggregationTree {
     let entries: Vec<SubnetStateEntry> = subnet_roots
         .iter()
         .map(|(subnet_id, root)| {
@@ -142,8 +213,22 @@ pub fn compute_global_state_root(subnet_roots: &HashMap<SubnetId, MerkleHash>) -
         })
         .collect();
     
-    let tree = SubnetAggregationTree::build(entries);
-    tree.root()
+    SubnetAggregationTree::build(entries)
+}
+
+/// Verify that `subnet_id` had state root `subnet_root` within `global_root`
+///
+/// Checks both that the proof is about the claimed subnet/root pair and that
+/// it hashes up to the global state root committed in the anchor.
+pub fn verify_subnet_in_global(
+    proof: &SubnetAggregationProof,
+    subnet_id: &SubnetId,
+    subnet_root: &MerkleHash,
+    global_root: &MerkleHash,
+) -> bool {
+    proof.entry.subnet_id == subnet_id.to_bytes()
+        && &proof.entry.state_root == subnet_root
+        && proof.verify(global_root).is_ok()
 }
 
 /// Builder for constructing AnchorMerkleRoots
@@ -189,12 +274,32 @@ impl AnchorMerkleRootsBuilder {
         self
     }
     
+    /// Add subnet state roots as committed in an anchor's `AnchorMerkleRoots`
+    pub fn with_committed_subnet_roots(mut self, roots: &HashMap<SubnetId, TypesHash>) -> Self {
+        self.subnet_roots
+            .extend(roots.iter().map(|(id, root)| (*id, MerkleHash::new(*root))));
+        self
+    }
+    
     /// Set the current anchor ID for metadata
     pub fn with_anchor_id(mut self, anchor_id: u64) -> Self {
         self.current_anchor_id = anchor_id;
         self
     }
     
+    /// Prove that a subnet's state root is included in the global state root
+    ///
+    /// Returns `None` if the subnet has no root in this builder. Check the
+    /// proof with [`verify_subnet_in_global`].
+    pub fn prove_subnet_in_global(&self, subnet_id: &SubnetId) -> Option<SubnetAggregationProof> {
+        if !self.subnet_roots.contains_key(subnet_id) {
+            return None;
+        }
+        build_aggregation_tree(&self.subnet_roots)
+            .get_proof(&subnet_id.to_bytes())
+            .ok()
+    }
+    
     /// Build the AnchorMerkleRoots
     pub fn build(self) -> AnchorMerkleRoots {
         let events_root = compute_events_root(&self.events);
@@ -289,4 +394,38 @@ mod tests {
         let global_root = compute_global_state_root(&subnet_roots);
         assert_ne!(global_root, MerkleHash::zero());
     }
+
+    #[test]
+    fn test_prove_subnet_in_global() {
+        let app_a = SubnetId::new_app_simple(1);
+        let app_b = SubnetId::new_app_simple(2);
+        let builder = AnchorMerkleRootsBuilder::new()
+            .with_subnet_root(SubnetId::ROOT, blake3_hash(b"root_state"))
+            .with_subnet_root(app_a, blake3_hash(b"app_a_state"))
+            .with_subnet_root(app_b, blake3_hash(b"app_b_state"));
+
+        let proof = builder.prove_subnet_in_global(&app_a).unwrap();
+        let roots = builder.build();
+        let global_root = MerkleHash::new(roots.global_state_root);
+        let app_a_root = blake3_hash(b"app_a_state");
+
+        assert!(verify_subnet_in_global(&proof, &app_a, &app_a_root, &global_root));
+        // Rebuilding from the roots committed in the anchor yields the same proof
+        let from_anchor = AnchorMerkleRootsBuilder::new()
+            .with_committed_subnet_roots(&roots.subnet_roots)
+            .prove_subnet_in_global(&app_a)
+            .unwrap();
+        assert_eq!(from_anchor, proof);
+        // Wrong subnet root, wrong subnet, or wrong global root must all fail
+        assert!(!verify_subnet_in_global(&proof, &app_a, &blake3_hash(b"forged"), &global_root));
+        assert!(!verify_subnet_in_global(&proof, &app_b, &app_a_root, &global_root));
+        assert!(!verify_subnet_in_global(&proof, &app_a, &app_a_root, &blake3_hash(b"other")));
+    }
+
+    #[test]
+    fn test_prove_subnet_in_global_unknown_subnet() {
+        let builder = AnchorMerkleRootsBuilder::new()
+            .with_subnet_root(SubnetId::ROOT, blake3_hash(b"root_state"));
+        assert!(builder.prove_subnet_in_global(&SubnetId::new_app_simple(9)).is_none());
+    }
 }
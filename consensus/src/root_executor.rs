@@ -53,8 +53,27 @@ pub struct RootExecutionResult {
     /// New state root after execution
     pub new_state_root: TypesHash,
     
+    /// Event that was executed (for batch results, the last event in the batch)
+    pub event_id: String,
+    
+    /// Per-event outcomes, in execution order
+    pub event_outcomes: Vec<RootEventOutcome>,
+}
+
+/// Outcome of a single event within a ROOT subnet execution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RootEventOutcome {
     /// Event that was executed
     pub event_id: String,
+    
+    /// Whether execution succeeded
+    pub success: bool,
+    
+    /// Error message if execution failed
+    pub error: Option<String>,
+    
+    /// Number of objects updated or deleted by the event
+    pub change_count: usize,
 }
 
 impl RootExecutionResult {
@@ -65,6 +84,7 @@ impl RootExecutionResult {
             deleted_objects: Vec::new(),
             new_state_root: current_root,
             event_id,
+            event_outcomes: Vec::new(),
         }
     }
     
@@ -72,6 +92,16 @@ impl RootExecutionResult {
     pub fn has_changes(&self) -> bool {
         !self.updated_objects.is_empty() || !self.deleted_objects.is_empty()
     }
+    
+    /// Number of objects updated or deleted
+    pub fn change_count(&self) -> usize {
+        self.updated_objects.len() + self.deleted_objects.len()
+    }
+    
+    /// Outcomes of events that failed
+    pub fn failed_events(&self) -> impl Iterator<Item = &RootEventOutcome> {
+        self.event_outcomes.iter().filter(|o| !o.success)
+    }
 }
 
 /// ROOT Subnet Executor handles system-level operations
@@ -120,7 +150,7 @@ impl RootSubnetExecutor {
             return Err(RootExecutorError::NotRootEvent);
         }
         
-        match event.event_type {
+        let result = match event.event_type {
             EventType::ValidatorRegister => self.execute_validator_register(event),
             EventType::ValidatorUnregister => self.execute_validator_unregister(event),
             EventType::SolverRegister => self.execute_solver_register(event),
@@ -134,7 +164,17 @@ impl RootSubnetExecutor {
                     self.current_state_root,
                 ))
             }
-        }
+        };
+        
+        result.map(|mut r| {
+            r.event_outcomes.push(RootEventOutcome {
+                event_id: event.id.clone(),
+                success: true,
+                error: None,
+                change_count: r.change_count(),
+            });
+            r
+        })
     }
     
     /// Execute multiple events in sequence
@@ -142,6 +182,40 @@ impl RootSubnetExecutor {
         events.iter().map(|e| self.execute(e)).collect()
     }
     
+    /// Execute multiple events in sequence and merge them into one result
+    ///
+    /// A failing event does not abort the batch: it is recorded in
+    /// `event_outcomes` with its error and leaves the state untouched,
+    /// and execution continues with the next event.
+    pub fn execute_batch_merged(&mut self, events: &[Event]) -> RootExecutionResult {
+        let mut merged = RootExecutionResult::empty(String::new(), self.current_state_root);
+        
+        for event in events {
+            match self.execute(event) {
+                Ok(result) => {
+                    for key in &result.deleted_objects {
+                        merged.updated_objects.remove(key);
+                    }
+                    merged.updated_objects.extend(result.updated_objects);
+                    merged.deleted_objects.extend(result.deleted_objects);
+                    merged.event_outcomes.extend(result.event_outcomes);
+                }
+                Err(e) => {
+                    merged.event_outcomes.push(RootEventOutcome {
+                        event_id: event.id.clone(),
+                        success: false,
+                        error: Some(e.to_string()),
+                        change_count: 0,
+                    });
+                }
+            }
+            merged.event_id = event.id.clone();
+        }
+        
+        merged.new_state_root = self.current_state_root;
+        merged
+    }
+    
     /// Execute validator registration
     fn execute_validator_register(&mut self, event: &Event) -> Result<RootExecutionResult, RootExecutorError> {
         // Generate object key from event
@@ -170,6 +244,7 @@ impl RootSubnetExecutor {
             deleted_objects: Vec::new(),
             new_state_root: new_root,
             event_id: event.id.clone(),
+            event_outcomes: Vec::new(),
         })
     }
     
@@ -187,6 +262,7 @@ impl RootSubnetExecutor {
             deleted_objects: vec![key],
             new_state_root: new_root,
             event_id: event.id.clone(),
+            event_outcomes: Vec::new(),
         })
     }
     
@@ -215,6 +291,7 @@ impl RootSubnetExecutor {
             deleted_objects: Vec::new(),
             new_state_root: new_root,
             event_id: event.id.clone(),
+            event_outcomes: Vec::new(),
         })
     }
     
@@ -232,6 +309,7 @@ impl RootSubnetExecutor {
             deleted_objects: vec![key],
             new_state_root: new_root,
             event_id: event.id.clone(),
+            event_outcomes: Vec::new(),
         })
     }
     
@@ -275,6 +353,7 @@ impl RootSubnetExecutor {
             deleted_objects: Vec::new(),
             new_state_root: new_root,
             event_id: event.id.clone(),
+            event_outcomes: Vec::new(),
         })
     }
 
@@ -303,6 +382,7 @@ impl RootSubnetExecutor {
             deleted_objects: Vec::new(),
             new_state_root: new_root,
             event_id: event.id.clone(),
+            event_outcomes: Vec::new(),
         })
     }
     
@@ -476,4 +556,50 @@ mod tests {
         let final_root = executor.state_root();
         assert_ne!(new_root, final_root);
     }
+
+    #[test]
+    fn test_execute_batch_merged_reports_per_event_outcomes() {
+        let mut executor = RootSubnetExecutor::empty();
+        
+        // An App subnet transfer is not a ROOT event and must fail
+        let failing = Event::new(
+            EventType::Transfer,
+            vec![],
+            VLCSnapshot::default(),
+            "user".to_string(),
+        ).with_subnet(SubnetId::new_app_simple(1));
+        
+        let events = vec![
+            create_validator_register_event("v1"),
+            failing.clone(),
+            create_solver_register_event("s1"),
+        ];
+        
+        let result = executor.execute_batch_merged(&events);
+        
+        assert_eq!(result.event_outcomes.len(), 3);
+        assert_eq!(result.event_id, events[2].id);
+        assert_eq!(result.new_state_root, executor.state_root());
+        assert_eq!(result.updated_objects.len(), 2);
+        
+        let outcomes = &result.event_outcomes;
+        assert_eq!(outcomes[0].event_id, events[0].id);
+        assert!(outcomes[0].success);
+        assert_eq!(outcomes[0].change_count, 1);
+        
+        assert_eq!(outcomes[1].event_id, failing.id);
+        assert!(!outcomes[1].success);
+        assert_eq!(
+            outcomes[1].error.as_deref(),
+            Some(RootExecutorError::NotRootEvent.to_string().as_str())
+        );
+        assert_eq!(outcomes[1].change_count, 0);
+        
+        assert!(outcomes[2].success);
+        assert_eq!(outcomes[2].change_count, 1);
+        
+        let failed: Vec<_> = result.failed_events().collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].event_id, failing.id);
+    }
 }
@@ -5,7 +5,7 @@
 //! - Separates ROOT subnet events (validator-executed) from App subnet events (solver/TEE-executed)
 //! - Provides batch processing for efficient DAG folding
 
-use setu_types::{Event, SubnetId};
+use setu_types::{sort_events_canonically, Event, SubnetId};
 use std::collections::HashMap;
 
 /// Result of routing events by subnet
@@ -168,6 +168,12 @@ impl SubnetExecutionBatch {
 }
 
 /// Convert RoutedEvents into execution batches
+///
+/// Produces exactly one batch per subnet (ROOT, system and App subnets),
+/// ordered by `SubnetId`, with each batch's events in canonical order
+/// (see [`Event::canonical_cmp`]). Batches share no events, so each one
+/// can be executed against its own subnet SMT without cross-subnet
+/// interference. Unrouted events are not batched.
 pub fn create_execution_batches(routed: RoutedEvents) -> Vec<SubnetExecutionBatch> {
     let mut batches = Vec::new();
     
@@ -179,11 +185,22 @@ pub fn create_execution_batches(routed: RoutedEvents) -> Vec<SubnetExecutionBatc
         ));
     }
     
+    // System subnet batches (validator-executed, e.g. GOVERNANCE)
+    for (subnet_id, events) in routed.system_events {
+        batches.push(SubnetExecutionBatch::new(subnet_id, events));
+    }
+    
     // App subnet batches
     for (subnet_id, events) in routed.app_events {
         batches.push(SubnetExecutionBatch::new(subnet_id, events));
     }
     
+    // HashMap iteration order is not deterministic; fix batch order by subnet
+    batches.sort_by_key(|b| b.subnet_id);
+    for batch in &mut batches {
+        sort_events_canonically(&mut batch.events);
+    }
+    
     batches
 }
 
@@ -277,4 +294,65 @@ mod tests {
         assert!(!app_batch.validator_executed);
         assert!(app_batch.requires_tee);
     }
+
+    #[test]
+    fn test_create_execution_batches_one_per_subnet() {
+        use setu_storage::GlobalStateManager;
+        use setu_types::{ExecutionResult, StateChange};
+
+        fn event_with_change(subnet_id: SubnetId, seed: &str, logical_time: u64) -> Event {
+            let mut event = create_test_event(Some(subnet_id));
+            event.vlc_snapshot.logical_time = logical_time;
+            let hash = setu_types::hash_utils::setu_hash(seed.as_bytes());
+            event.execution_result = Some(ExecutionResult {
+                success: true,
+                message: None,
+                state_changes: vec![StateChange::insert(
+                    format!("oid:{}", hex::encode(hash)),
+                    seed.as_bytes().to_vec(),
+                )],
+            });
+            event
+        }
+
+        let app_a = SubnetId::new_app_simple(100);
+        let app_b = SubnetId::new_app_simple(200);
+        let events = vec![
+            event_with_change(app_b, "b2", 5),
+            event_with_change(SubnetId::ROOT, "r1", 3),
+            event_with_change(app_a, "a1", 2),
+            event_with_change(app_b, "b1", 1),
+            event_with_change(SubnetId::ROOT, "r2", 4),
+            event_with_change(app_a, "a2", 6),
+        ];
+
+        let batches = create_execution_batches(EventRouter::route_events(&events));
+
+        // One batch per subnet, in SubnetId order
+        assert_eq!(batches.len(), 3);
+        let subnet_ids: Vec<SubnetId> = batches.iter().map(|b| b.subnet_id).collect();
+        assert_eq!(subnet_ids, vec![SubnetId::ROOT, app_a, app_b]);
+
+        // Each batch holds only its own subnet's events, canonically ordered
+        for batch in &batches {
+            assert_eq!(batch.event_count(), 2);
+            assert!(batch.events.iter().all(|e| e.get_subnet_id() == batch.subnet_id));
+            assert!(batch.events.windows(2).all(|w| {
+                w[0].vlc_snapshot.logical_time <= w[1].vlc_snapshot.logical_time
+            }));
+        }
+
+        // Executing each batch in isolation yields the same subnet root as
+        // executing everything together: no batch touches another's state.
+        let mut combined = GlobalStateManager::new();
+        combined.apply_committed_events(&events);
+        for batch in &batches {
+            let mut isolated = GlobalStateManager::new();
+            isolated.apply_committed_events(&batch.events);
+            assert_eq!(
+                isolated.get_subnet_root(&batch.subnet_id),
+                combined.get_subnet_root(&batch.subnet_id),
+            );
+        }
+    }
 }
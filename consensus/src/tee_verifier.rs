@@ -24,13 +24,15 @@
 //! ```
 
 use setu_types::event::{Event, ExecutionResult, StateChange};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// TEE attestation attached to an execution result
 #[derive(Debug, Clone)]
 pub struct TeeAttestation {
     /// The solver that produced this attestation
     pub solver_id: String,
+    /// Task the attestation was produced for (replay protection)
+    pub task_id: [u8; 32],
     /// Attestation quote from the TEE
     pub quote: Vec<u8>,
     /// Signature over the execution result
@@ -53,6 +55,7 @@ impl TeeAttestation {
     /// Create a new attestation (used by Solver)
     pub fn new(
         solver_id: String,
+        task_id: [u8; 32],
         platform: String,
         measurement: [u8; 32],
         read_set_commitment: [u8; 32],
@@ -61,6 +64,7 @@ impl TeeAttestation {
     ) -> Self {
         Self {
             solver_id,
+            task_id,
             quote: Vec::new(),
             signature: Vec::new(),
             platform,
@@ -74,7 +78,8 @@ impl TeeAttestation {
     
     /// Compute commitment for a set of state changes (write-set).
     ///
-    /// Delegates to the canonical implementation in `hash_utils::compute_write_set_commitment`.
+    /// Delegates to `hash_utils::compute_sorted_write_set_commitment`, the same
+    /// key-ordered commitment `StateDiff::commitment` attests to.
     pub fn compute_write_set_commitment(changes: &[StateChange]) -> [u8; 32] {
         let normalized: Vec<(String, Option<Vec<u8>>, Option<Vec<u8>>)> = changes
             .iter()
@@ -84,7 +89,18 @@ impl TeeAttestation {
                 c.new_value.clone(),
             ))
             .collect();
-        setu_types::hash_utils::compute_write_set_commitment(&normalized)
+        setu_types::hash_utils::compute_sorted_write_set_commitment(&normalized)
+    }
+
+    /// Hash of the claimed execution output (post-state root and write-set)
+    ///
+    /// Solvers that executed the same task identically produce the same
+    /// output hash; [`TeeVerifier::reconcile`] groups by it.
+    pub fn output_hash(&self) -> [u8; 32] {
+        let mut data = [0u8; 64];
+        data[..32].copy_from_slice(&self.post_state_root);
+        data[32..].copy_from_slice(&self.write_set_commitment);
+        setu_types::hash_utils::setu_hash_with_domain(b"SETU_TEE_OUTPUT:", &data)
     }
 }
 
@@ -114,6 +130,8 @@ pub enum VerificationError {
     WriteSetMismatch,
     /// State root mismatch
     StateRootMismatch,
+    /// Attestation was produced for a different task
+    TaskIdMismatch,
     /// Attestation too old
     ExpiredAttestation,
     /// Unknown solver
@@ -131,6 +149,7 @@ impl std::fmt::Display for VerificationError {
             VerificationError::ReadSetMismatch => write!(f, "Read-set commitment mismatch"),
             VerificationError::WriteSetMismatch => write!(f, "Write-set commitment mismatch"),
             VerificationError::StateRootMismatch => write!(f, "Post-state root mismatch"),
+            VerificationError::TaskIdMismatch => write!(f, "Attestation bound to a different task"),
             VerificationError::ExpiredAttestation => write!(f, "Attestation expired"),
             VerificationError::UnknownSolver(id) => write!(f, "Unknown solver: {}", id),
             VerificationError::MeasurementMismatch => write!(f, "Enclave measurement mismatch"),
@@ -138,6 +157,63 @@ impl std::fmt::Display for VerificationError {
     }
 }
 
+/// How many solvers must agree on an output for a multi-solver task to commit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AgreementThreshold {
+    /// Strictly more than half of the received attestations
+    #[default]
+    Majority,
+    /// Every received attestation
+    Unanimous,
+    /// At least this many attestations
+    AtLeast(usize),
+}
+
+impl AgreementThreshold {
+    fn is_met(&self, agreeing: usize, total: usize) -> bool {
+        match self {
+            AgreementThreshold::Majority => agreeing * 2 > total,
+            AgreementThreshold::Unanimous => agreeing == total,
+            AgreementThreshold::AtLeast(n) => agreeing >= *n,
+        }
+    }
+}
+
+/// Outcome of reconciling several solvers' attestations for one task
+#[derive(Debug, Clone)]
+pub enum ReconcileResult {
+    /// Enough solvers agree on one output
+    Agreed {
+        /// Output hash the agreeing solvers produced
+        output_hash: [u8; 32],
+        /// Post-state root the agreeing solvers committed to
+        post_state_root: [u8; 32],
+        /// Write-set commitment the agreeing solvers committed to
+        write_set_commitment: [u8; 32],
+        /// Solvers that produced the agreed output
+        agreeing: Vec<String>,
+        /// Solvers that produced anything else; reported to reputation
+        dissenting: Vec<String>,
+    },
+    /// No output reached the threshold, or the leading outputs tied;
+    /// the task must be aborted
+    Indeterminate {
+        /// Attestation count per distinct output hash, largest first
+        groups: Vec<([u8; 32], usize)>,
+    },
+}
+
+impl ReconcileResult {
+    /// Solvers to flag for the reputation system (empty when indeterminate,
+    /// since no output is known to be correct)
+    pub fn dissenting_solvers(&self) -> &[String] {
+        match self {
+            ReconcileResult::Agreed { dissenting, .. } => dissenting,
+            ReconcileResult::Indeterminate { .. } => &[],
+        }
+    }
+}
+
 /// Registry of known solvers and their expected measurements
 #[derive(Debug, Clone, Default)]
 pub struct SolverRegistry {
@@ -182,6 +258,8 @@ pub struct TeeVerifier {
     max_attestation_age_ms: u64,
     /// Whether to skip verification (for testing)
     skip_verification: bool,
+    /// Agreement required by [`reconcile`](Self::reconcile)
+    agreement_threshold: AgreementThreshold,
 }
 
 impl TeeVerifier {
@@ -191,6 +269,7 @@ impl TeeVerifier {
             solver_registry,
             max_attestation_age_ms: 5 * 60 * 1000, // 5 minutes
             skip_verification: false,
+            agreement_threshold: AgreementThreshold::default(),
         }
     }
     
@@ -200,8 +279,15 @@ impl TeeVerifier {
             solver_registry: SolverRegistry::new(),
             max_attestation_age_ms: u64::MAX,
             skip_verification: true,
+            agreement_threshold: AgreementThreshold::default(),
         }
     }
+
+    /// Set the agreement required when reconciling multi-solver results
+    pub fn with_agreement_threshold(mut self, threshold: AgreementThreshold) -> Self {
+        self.agreement_threshold = threshold;
+        self
+    }
     
     /// Verify an event's execution result
     ///
@@ -255,15 +341,25 @@ impl TeeVerifier {
     }
     
     /// Verify attestation against an execution result
+    ///
+    /// `expected_task_id` is the task_id the validator generated when
+    /// preparing the task. An attestation for another task is rejected even
+    /// if it describes an identical state transition.
     pub fn verify_attestation(
         &self,
         attestation: &TeeAttestation,
         result: &ExecutionResult,
+        expected_task_id: &[u8; 32],
     ) -> VerificationResult {
         if self.skip_verification {
             return VerificationResult::Verified;
         }
         
+        // Check the attestation is bound to this task
+        if attestation.task_id != *expected_task_id {
+            return VerificationResult::Failed(VerificationError::TaskIdMismatch);
+        }
+        
         // Check solver is registered
         if !self.solver_registry.is_registered(&attestation.solver_id) {
             return VerificationResult::Failed(
@@ -295,6 +391,83 @@ impl TeeVerifier {
         VerificationResult::Verified
     }
     
+    /// Reconcile attestations from several solvers that executed the same task
+    ///
+    /// Each solver gets one vote: repeated attestations from the same
+    /// `solver_id` are ignored, and a solver whose attestations disagree with
+    /// each other counts against agreement and is reported as dissenting.
+    ///
+    /// Votes are grouped by [`TeeAttestation::output_hash`]. The largest
+    /// group wins if it meets the configured threshold and is not tied with
+    /// another group; every other solver is reported as dissenting. An
+    /// attestation bound to a different task counts against agreement and
+    /// its solver is reported as dissenting.
+    ///
+    /// Attestations should already have passed [`verify_attestation`](Self::verify_attestation).
+    pub fn reconcile(&self, task_id: &[u8; 32], attestations: &[TeeAttestation]) -> ReconcileResult {
+        // One vote per solver, in first-seen order
+        let mut votes: Vec<&TeeAttestation> = Vec::new();
+        let mut equivocating: HashSet<&str> = HashSet::new();
+        for attestation in attestations {
+            match votes.iter().find(|v| v.solver_id == attestation.solver_id) {
+                Some(first) => {
+                    if first.task_id != attestation.task_id
+                        || first.output_hash() != attestation.output_hash()
+                    {
+                        equivocating.insert(&attestation.solver_id);
+                    }
+                }
+                None => votes.push(attestation),
+            }
+        }
+        let total = votes.len();
+
+        // Group by output hash, preserving first-seen order for stable output
+        let mut groups: Vec<([u8; 32], Vec<&TeeAttestation>)> = Vec::new();
+        for attestation in votes
+            .iter()
+            .copied()
+            .filter(|a| a.task_id == *task_id && !equivocating.contains(a.solver_id.as_str()))
+        {
+            let hash = attestation.output_hash();
+            match groups.iter_mut().find(|(h, _)| *h == hash) {
+                Some((_, members)) => members.push(attestation),
+                None => groups.push((hash, vec![attestation])),
+            }
+        }
+        groups.sort_by_key(|(_, members)| std::cmp::Reverse(members.len()));
+
+        let tied = groups.len() > 1 && groups[0].1.len() == groups[1].1.len();
+        let winner = groups
+            .first()
+            .filter(|(_, members)| !tied && self.agreement_threshold.is_met(members.len(), total));
+
+        match winner {
+            Some((output_hash, members)) => {
+                let agreeing: Vec<String> = members.iter().map(|a| a.solver_id.clone()).collect();
+                let dissenting = votes
+                    .iter()
+                    .filter(|a| {
+                        a.task_id != *task_id
+                            || a.output_hash() != *output_hash
+                            || equivocating.contains(a.solver_id.as_str())
+                    })
+                    .map(|a| a.solver_id.clone())
+                    .collect();
+                ReconcileResult::Agreed {
+                    output_hash: *output_hash,
+                    post_state_root: members[0].post_state_root,
+                    write_set_commitment: members[0].write_set_commitment,
+                    agreeing,
+                    dissenting,
+                }
+            }
+            None => ReconcileResult::Indeterminate {
+                groups: groups.iter().map(|(h, members)| (*h, members.len())).collect(),
+            },
+        }
+    }
+
     /// Get the solver registry
     pub fn solver_registry(&self) -> &SolverRegistry {
         &self.solver_registry
@@ -423,4 +596,180 @@ mod tests {
         assert!(registry.is_registered("solver-1"));
         assert!(!registry.is_registered("solver-2"));
     }
+    
+    fn attestation_with_root(solver_id: &str, task_id: [u8; 32], root: u8) -> TeeAttestation {
+        TeeAttestation::new(
+            solver_id.to_string(),
+            task_id,
+            "Mock".to_string(),
+            [0u8; 32],
+            [0u8; 32],
+            [0u8; 32],
+            [root; 32],
+        )
+    }
+
+    #[test]
+    fn test_reconcile_unanimous() {
+        let task = [1u8; 32];
+        let attestations: Vec<_> = ["s1", "s2", "s3"]
+            .iter()
+            .map(|id| attestation_with_root(id, task, 5))
+            .collect();
+
+        match TeeVerifier::permissive().reconcile(&task, &attestations) {
+            ReconcileResult::Agreed { output_hash, agreeing, dissenting, .. } => {
+                assert_eq!(output_hash, attestations[0].output_hash());
+                assert_eq!(agreeing, vec!["s1", "s2", "s3"]);
+                assert!(dissenting.is_empty());
+            }
+            other => panic!("Expected Agreed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_reconcile_majority_flags_dissenter() {
+        let task = [1u8; 32];
+        let attestations = vec![
+            attestation_with_root("s1", task, 5),
+            attestation_with_root("s2", task, 6),
+            attestation_with_root("s3", task, 5),
+        ];
+        let verifier = TeeVerifier::permissive();
+
+        let result = verifier.reconcile(&task, &attestations);
+        assert_eq!(result.dissenting_solvers(), ["s2".to_string()]);
+        match result {
+            ReconcileResult::Agreed { post_state_root, agreeing, .. } => {
+                assert_eq!(post_state_root, [5u8; 32]);
+                assert_eq!(agreeing, vec!["s1", "s3"]);
+            }
+            other => panic!("Expected Agreed, got {:?}", other),
+        }
+
+        // The same split is not enough when unanimity is required
+        let strict = TeeVerifier::permissive().with_agreement_threshold(AgreementThreshold::Unanimous);
+        assert!(matches!(
+            strict.reconcile(&task, &attestations),
+            ReconcileResult::Indeterminate { .. }
+        ));
+    }
+
+    #[test]
+    fn test_reconcile_no_majority_is_indeterminate() {
+        let task = [1u8; 32];
+        let verifier = TeeVerifier::permissive();
+
+        // Even split
+        let tie = vec![
+            attestation_with_root("s1", task, 5),
+            attestation_with_root("s2", task, 6),
+        ];
+        match verifier.reconcile(&task, &tie) {
+            ReconcileResult::Indeterminate { groups } => {
+                assert_eq!(groups.iter().map(|(_, n)| *n).collect::<Vec<_>>(), vec![1, 1]);
+            }
+            other => panic!("Expected Indeterminate, got {:?}", other),
+        }
+
+        // Plurality without majority, with one attestation for another task
+        let split = vec![
+            attestation_with_root("s1", task, 5),
+            attestation_with_root("s2", task, 5),
+            attestation_with_root("s3", task, 6),
+            attestation_with_root("s4", task, 7),
+            attestation_with_root("s5", [2u8; 32], 5),
+        ];
+        let result = verifier.reconcile(&task, &split);
+        assert!(matches!(result, ReconcileResult::Indeterminate { .. }));
+        assert!(result.dissenting_solvers().is_empty());
+
+        assert!(matches!(verifier.reconcile(&task, &[]), ReconcileResult::Indeterminate { .. }));
+    }
+
+    #[test]
+    fn test_reconcile_counts_each_solver_once() {
+        let task = [1u8; 32];
+        let verifier = TeeVerifier::permissive();
+
+        // One solver repeating its attestation cannot outvote two others
+        let stuffed = vec![
+            attestation_with_root("s1", task, 6),
+            attestation_with_root("s1", task, 6),
+            attestation_with_root("s1", task, 6),
+            attestation_with_root("s2", task, 5),
+            attestation_with_root("s3", task, 5),
+        ];
+        match verifier.reconcile(&task, &stuffed) {
+            ReconcileResult::Agreed { post_state_root, agreeing, dissenting, .. } => {
+                assert_eq!(post_state_root, [5u8; 32]);
+                assert_eq!(agreeing, vec!["s2", "s3"]);
+                assert_eq!(dissenting, vec!["s1"]);
+            }
+            other => panic!("Expected Agreed, got {:?}", other),
+        }
+
+        // Repeats from one solver do not satisfy a two-solver threshold
+        let strict = TeeVerifier::permissive().with_agreement_threshold(AgreementThreshold::AtLeast(2));
+        let repeated = vec![
+            attestation_with_root("s1", task, 5),
+            attestation_with_root("s1", task, 5),
+        ];
+        assert!(matches!(
+            strict.reconcile(&task, &repeated),
+            ReconcileResult::Indeterminate { .. }
+        ));
+
+        // A solver attesting to two outputs is a dissenter, not a vote
+        let equivocating = vec![
+            attestation_with_root("s1", task, 5),
+            attestation_with_root("s1", task, 6),
+            attestation_with_root("s2", task, 5),
+            attestation_with_root("s3", task, 5),
+        ];
+        match verifier.reconcile(&task, &equivocating) {
+            ReconcileResult::Agreed { agreeing, dissenting, .. } => {
+                assert_eq!(agreeing, vec!["s2", "s3"]);
+                assert_eq!(dissenting, vec!["s1"]);
+            }
+            other => panic!("Expected Agreed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_attestation_rejected_for_other_task() {
+        let mut registry = SolverRegistry::new();
+        registry.register(SolverInfo {
+            solver_id: "solver-1".to_string(),
+            public_key: vec![],
+            expected_measurement: [7u8; 32],
+            platform: "Mock".to_string(),
+            registered_at: 0,
+        });
+        let verifier = TeeVerifier::new(registry);
+        
+        let result = create_app_event().execution_result.unwrap();
+        let task_a = [0xAAu8; 32];
+        let task_b = [0xBBu8; 32];
+        let attestation = TeeAttestation::new(
+            "solver-1".to_string(),
+            task_a,
+            "Mock".to_string(),
+            [7u8; 32],
+            [0u8; 32],
+            TeeAttestation::compute_write_set_commitment(&result.state_changes),
+            [0u8; 32],
+        );
+        
+        match verifier.verify_attestation(&attestation, &result, &task_a) {
+            VerificationResult::Verified => {}
+            other => panic!("Expected Verified, got {:?}", other),
+        }
+        
+        // Same state transition, replayed for another task
+        match verifier.verify_attestation(&attestation, &result, &task_b) {
+            VerificationResult::Failed(VerificationError::TaskIdMismatch) => {}
+            other => panic!("Expected TaskIdMismatch, got {:?}", other),
+        }
+    }
 }
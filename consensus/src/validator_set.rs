@@ -6,6 +6,7 @@
 //! This module manages the set of validators participating in consensus.
 //! It integrates with the liveness module for leader election.
 
+use serde::{Deserialize, Serialize};
 use setu_types::ValidatorInfo;
 #[cfg(test)]
 use setu_types::NodeInfo;
@@ -38,6 +39,159 @@ impl Default for ElectionStrategy {
     }
 }
 
+/// Operation carried by a `ValidatorSetChange`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ValidatorSetChangeKind {
+    /// Add a validator (replaces an existing entry with the same ID)
+    Add(ValidatorInfo),
+    /// Remove a validator by ID
+    Remove(ValidatorId),
+    /// Change how many consecutive rounds each proposer leads
+    SetContiguousRounds(u32),
+}
+
+/// A validator's ed25519 signature over a `ValidatorSetChange`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidatorSetChangeApproval {
+    /// Approving validator
+    pub validator_id: ValidatorId,
+    /// Signature over the change's signing message
+    pub signature: Vec<u8>,
+}
+
+/// A validator set reconfiguration scheduled for a specific round.
+///
+/// Changes are applied at CF boundaries: when finalization advances the set
+/// into `activation_round`, every validator applies the same change before
+/// any CF of that round is proposed, so membership and quorum stay identical
+/// across nodes. A change is only scheduled once it carries approvals from a
+/// quorum of the current validator set (see `verify_approvals`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidatorSetChange {
+    /// First round in which the change is in effect
+    pub activation_round: Round,
+    /// The operation to apply
+    pub kind: ValidatorSetChangeKind,
+    /// Validator signatures approving this change
+    pub approvals: Vec<ValidatorSetChangeApproval>,
+}
+
+impl ValidatorSetChange {
+    /// Schedule adding a validator at `activation_round`.
+    pub fn add(activation_round: Round, info: ValidatorInfo) -> Self {
+        Self {
+            activation_round,
+            kind: ValidatorSetChangeKind::Add(info),
+            approvals: Vec::new(),
+        }
+    }
+
+    /// Schedule removing a validator at `activation_round`.
+    pub fn remove(activation_round: Round, validator_id: impl Into<ValidatorId>) -> Self {
+        Self {
+            activation_round,
+            kind: ValidatorSetChangeKind::Remove(validator_id.into()),
+            approvals: Vec::new(),
+        }
+    }
+
+    /// Schedule a new contiguous-rounds setting at `activation_round`.
+    pub fn contiguous_rounds(activation_round: Round, contiguous_rounds: u32) -> Self {
+        Self {
+            activation_round,
+            kind: ValidatorSetChangeKind::SetContiguousRounds(contiguous_rounds),
+            approvals: Vec::new(),
+        }
+    }
+
+    /// Whether `other` schedules the same operation for the same round.
+    ///
+    /// Used to drop duplicate announcements received from several peers.
+    pub fn same_as(&self, other: &ValidatorSetChange) -> bool {
+        if self.activation_round != other.activation_round {
+            return false;
+        }
+        match (&self.kind, &other.kind) {
+            (ValidatorSetChangeKind::Add(a), ValidatorSetChangeKind::Add(b)) => a.node.id == b.node.id,
+            (ValidatorSetChangeKind::Remove(a), ValidatorSetChangeKind::Remove(b)) => a == b,
+            (
+                ValidatorSetChangeKind::SetContiguousRounds(a),
+                ValidatorSetChangeKind::SetContiguousRounds(b),
+            ) => a == b,
+            _ => false,
+        }
+    }
+
+    /// Sign the change as `validator_id`, replacing any earlier approval
+    /// from the same validator.
+    pub fn approve(
+        &mut self,
+        validator_id: impl Into<ValidatorId>,
+        private_key: &[u8],
+    ) -> Result<(), String> {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let key: [u8; 32] = private_key.try_into().map_err(|_| {
+            format!("Invalid private key length: expected 32, got {}", private_key.len())
+        })?;
+        let signature = SigningKey::from_bytes(&key).sign(&self.signing_message());
+
+        let validator_id = validator_id.into();
+        self.approvals.retain(|a| a.validator_id != validator_id);
+        self.approvals.push(ValidatorSetChangeApproval {
+            validator_id,
+            signature: signature.to_bytes().to_vec(),
+        });
+        Ok(())
+    }
+
+    /// Check that the change is approved by a quorum of `validators`.
+    ///
+    /// Every approval must come from a distinct member of the set and verify
+    /// against that member's public key; one bad approval rejects the change.
+    pub fn verify_approvals(&self, validators: &ValidatorSet) -> Result<(), String> {
+        use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+        let message = self.signing_message();
+        let mut approvers = std::collections::HashSet::new();
+        for approval in &self.approvals {
+            let Some(validator) = validators.get_validator(&approval.validator_id) else {
+                return Err(format!("approval from non-validator {}", approval.validator_id));
+            };
+            if !approvers.insert(approval.validator_id.as_str()) {
+                return Err(format!("duplicate approval from {}", approval.validator_id));
+            }
+            let verified = <[u8; 32]>::try_from(validator.node.public_key.as_slice())
+                .ok()
+                .and_then(|key| VerifyingKey::from_bytes(&key).ok())
+                .zip(Signature::from_slice(&approval.signature).ok())
+                .is_some_and(|(key, signature)| key.verify(&message, &signature).is_ok());
+            if !verified {
+                return Err(format!("invalid approval signature from {}", approval.validator_id));
+            }
+        }
+
+        if !validators.has_quorum(approvers.len()) {
+            return Err(format!(
+                "{} approvals, quorum is {}",
+                approvers.len(),
+                validators.quorum_size()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Bytes signed by approvals: domain tag, activation round and operation.
+    fn signing_message(&self) -> Vec<u8> {
+        let mut message = b"SETU_VALIDATOR_SET_CHANGE_V1".to_vec();
+        message.extend_from_slice(&self.activation_round.to_le_bytes());
+        message.extend_from_slice(
+            &serde_json::to_vec(&self.kind).expect("validator set change kind serializes"),
+        );
+        message
+    }
+}
+
 /// Manages the set of validators and leader election.
 #[derive(Debug, Clone)]
 pub struct ValidatorSet {
@@ -111,6 +265,28 @@ impl ValidatorSet {
         removed
     }
 
+    /// Apply a validator set change and re-derive the leader for the current round.
+    ///
+    /// Returns `false` if the change was a no-op (removing an unknown validator,
+    /// or setting contiguous rounds on a non-rotating strategy).
+    pub fn apply_change(&mut self, change: &ValidatorSetChange) -> bool {
+        let applied = match &change.kind {
+            ValidatorSetChangeKind::Add(info) => {
+                self.add_validator(info.clone());
+                true
+            }
+            ValidatorSetChangeKind::Remove(validator_id) => {
+                self.remove_validator(validator_id).is_some()
+            }
+            ValidatorSetChangeKind::SetContiguousRounds(n) => self.set_contiguous_rounds(*n),
+        };
+
+        if applied {
+            self.update_leader_for_round(self.current_round);
+        }
+        applied
+    }
+
     /// Get a validator by ID.
     pub fn get_validator(&self, validator_id: &str) -> Option<&ValidatorInfo> {
         self.validators.get(validator_id)
@@ -149,6 +325,22 @@ impl ValidatorSet {
         }
     }
 
+    /// First round after `after` in which `validator_id` is the valid proposer.
+    ///
+    /// Searches one full rotation; `None` if the validator is unknown or
+    /// never proposes (e.g. not the fixed leader).
+    pub fn next_proposer_round(&self, validator_id: &str, after: Round) -> Option<Round> {
+        if !self.validators.contains_key(validator_id) {
+            return None;
+        }
+        let contiguous_rounds = match &self.strategy {
+            ElectionStrategy::Rotating { contiguous_rounds } => (*contiguous_rounds).max(1) as Round,
+            _ => 1,
+        };
+        let rotation = self.count() as Round * contiguous_rounds;
+        (after + 1..=after + rotation).find(|&round| self.is_valid_proposer(validator_id, round))
+    }
+
     /// Advance to the next round and update the leader.
     pub fn advance_round(&mut self) -> Round {
         self.current_round += 1;
@@ -292,6 +484,27 @@ impl ValidatorSet {
         self.rebuild_election();
     }
 
+    /// Change how many consecutive rounds each proposer leads.
+    ///
+    /// The rotation is a pure function of the round number, so the new
+    /// schedule takes effect immediately, including for the current round.
+    /// To keep validators consistent this must be applied at the same round
+    /// on every node, via `ValidatorSetChange::contiguous_rounds`.
+    ///
+    /// Only applies to the `Rotating` strategy; returns `false` otherwise.
+    pub fn set_contiguous_rounds(&mut self, contiguous_rounds: u32) -> bool {
+        if !matches!(self.strategy, ElectionStrategy::Rotating { .. }) {
+            return false;
+        }
+
+        self.strategy = ElectionStrategy::Rotating {
+            contiguous_rounds: contiguous_rounds.max(1),
+        };
+        self.rebuild_election();
+        self.update_leader_for_round(self.current_round);
+        true
+    }
+
     /// Notify the set that a round was completed (for reputation tracking).
     pub fn on_round_completed(&mut self, round: Round, proposer: &ValidatorId, success: bool) {
         // TODO: Update reputation metrics when reputation-based election is implemented
@@ -330,6 +543,12 @@ mod tests {
         ValidatorInfo::new(node, false)
     }
 
+    fn create_keyed_validator(id: &str, key: &ed25519_dalek::SigningKey) -> ValidatorInfo {
+        let mut node = NodeInfo::new_validator(id.to_string(), "127.0.0.1".to_string(), 8000);
+        node.public_key = key.verifying_key().as_bytes().to_vec();
+        ValidatorInfo::new(node, false)
+    }
+
     fn create_validator_with_stake(id: &str, stake: u64) -> ValidatorInfo {
         let mut node = NodeInfo::new_validator(id.to_string(), "127.0.0.1".to_string(), 8000);
         node.stake = stake;
@@ -404,6 +623,45 @@ mod tests {
         assert_ne!(set.get_valid_proposer(0), set.get_valid_proposer(2));
     }
 
+    #[test]
+    fn test_set_contiguous_rounds_at_boundary() {
+        let mut set = ValidatorSet::new();
+        set.add_validator(create_validator("v1"));
+        set.add_validator(create_validator("v2"));
+        set.add_validator(create_validator("v3"));
+
+        // Rounds 0..=2 under contiguous_rounds = 1
+        let mut schedule = vec![set.get_leader_id().cloned().unwrap()];
+        for _ in 0..2 {
+            set.advance_round();
+            schedule.push(set.get_leader_id().cloned().unwrap());
+        }
+        assert_eq!(schedule, ["v1", "v2", "v3"]);
+
+        // Boundary: entering round 3 applies contiguous_rounds = 2
+        set.advance_round();
+        assert!(set.apply_change(&ValidatorSetChange::contiguous_rounds(3, 2)));
+        assert_eq!(set.get_leader_id().map(String::as_str), Some("v2"));
+
+        let mut schedule = Vec::new();
+        for _ in 0..4 {
+            set.advance_round();
+            schedule.push(set.get_leader_id().cloned().unwrap());
+        }
+        // (round / 2) % 3 for rounds 4..=7
+        assert_eq!(schedule, ["v3", "v3", "v1", "v1"]);
+        assert_eq!(set.get_valid_proposer(3), Some("v2".to_string()));
+    }
+
+    #[test]
+    fn test_set_contiguous_rounds_requires_rotating_strategy() {
+        let mut set = ValidatorSet::with_strategy(ElectionStrategy::Fixed("v1".to_string()));
+        set.add_validator(create_validator("v1"));
+
+        assert!(!set.set_contiguous_rounds(2));
+        assert!(matches!(set.strategy(), ElectionStrategy::Fixed(_)));
+    }
+
     #[test]
     fn test_is_valid_proposer() {
         let mut set = ValidatorSet::new();
@@ -459,4 +717,36 @@ mod tests {
         assert_eq!(new_round, 1);
         assert_eq!(set.current_round(), 1);
     }
+
+    #[test]
+    fn test_change_requires_quorum_of_valid_approvals() {
+        let keys: Vec<_> = (1..=4u8).map(|i| ed25519_dalek::SigningKey::from_bytes(&[i; 32])).collect();
+        let mut set = ValidatorSet::new();
+        for (i, key) in keys.iter().enumerate() {
+            set.add_validator(create_keyed_validator(&format!("v{}", i + 1), key));
+        }
+        let mut change = ValidatorSetChange::remove(5, "v4");
+
+        change.approve("v1", &keys[0].to_bytes()).unwrap();
+        change.approve("v2", &keys[1].to_bytes()).unwrap();
+        // Re-approving replaces the earlier signature instead of counting twice
+        change.approve("v2", &keys[1].to_bytes()).unwrap();
+        assert!(change.verify_approvals(&set).unwrap_err().contains("quorum is 3"));
+
+        change.approve("v3", &keys[2].to_bytes()).unwrap();
+        assert!(change.verify_approvals(&set).is_ok());
+
+        // Approvals do not carry over to a different change
+        let mut retargeted = change.clone();
+        retargeted.kind = ValidatorSetChangeKind::Remove("v1".to_string());
+        assert!(retargeted.verify_approvals(&set).is_err());
+
+        // A signature by the wrong key, or from outside the set, rejects the change
+        let mut forged = change.clone();
+        forged.approve("v4", &keys[0].to_bytes()).unwrap();
+        assert!(forged.verify_approvals(&set).unwrap_err().contains("invalid approval"));
+        let mut outsider = change.clone();
+        outsider.approve("v9", &keys[3].to_bytes()).unwrap();
+        assert!(outsider.verify_approvals(&set).unwrap_err().contains("non-validator"));
+    }
 }
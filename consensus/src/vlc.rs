@@ -7,7 +7,8 @@
 //! a node-local clock management interface with convenient methods for
 //! the consensus engine.
 
-use setu_vlc::{VLCSnapshot, VectorClock};
+use setu_vlc::{Clock, SystemClock, VLCSnapshot, VectorClock};
+use std::sync::Arc;
 
 /// VLC - Local Vector Logical Clock manager for a node
 ///
@@ -22,25 +23,36 @@ pub struct VLC {
     
     /// The underlying VLC snapshot
     snapshot: VLCSnapshot,
+
+    /// Source of `physical_time` for ticks and merges
+    clock: Arc<dyn Clock>,
 }
 
 impl VLC {
-    /// Create a new VLC for a node
+    /// Create a new VLC for a node, timed by the system clock
     pub fn new(node_id: String) -> Self {
+        Self::with_clock(node_id, Arc::new(SystemClock))
+    }
+
+    /// Create a new VLC for a node, timed by `clock`
+    pub fn with_clock(node_id: String, clock: Arc<dyn Clock>) -> Self {
+        let mut snapshot = VLCSnapshot::for_node(node_id.clone());
+        snapshot.physical_time = clock.now_millis();
         Self {
-            node_id: node_id.clone(),
-            snapshot: VLCSnapshot::for_node(node_id),
+            node_id,
+            snapshot,
+            clock,
         }
     }
 
     /// Increment the logical time (tick the clock)
     pub fn tick(&mut self) {
-        self.snapshot.increment(&self.node_id);
+        self.snapshot.increment_with_clock(&self.node_id, self.clock.as_ref());
     }
 
     /// Merge with another VLC snapshot (receive operation)
     pub fn merge(&mut self, other: &VLCSnapshot) {
-        self.snapshot.receive(other, &self.node_id);
+        self.snapshot.receive_with_clock(other, &self.node_id, self.clock.as_ref());
     }
 
     /// Get the current logical time
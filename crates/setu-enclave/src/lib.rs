@@ -119,7 +119,7 @@ pub use setu_types::task::{
 
 // Re-export implementations based on features
 #[cfg(feature = "mock")]
-pub use mock::MockEnclave;
+pub use mock::{MockEnclave, MockEnclaveSnapshot};
 
 #[cfg(feature = "nitro")]
 pub use nitro::NitroEnclave;
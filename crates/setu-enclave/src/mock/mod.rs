@@ -41,9 +41,9 @@ use crate::{
 use async_trait::async_trait;
 use setu_runtime::{
     ExecutionContext, InMemoryObjectStore, InMemoryStateStore, ObjectStore, RawStore,
-    RuntimeExecutor, StateStore,
+    RuntimeExecutor, StateStore, ZeroBalanceCoinPolicy,
 };
-use setu_types::task::{Attestation, AttestationData, GasUsage, ReadSetEntry, ResolvedInputs};
+use setu_types::task::{Attestation, AttestationData, FeeQuote, GasUsage, ReadSetEntry, ResolvedInputs};
 use setu_types::{create_coin, Address, Balance, CoinData, CoinType, EventId, Object, ObjectId};
 use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
@@ -63,26 +63,6 @@ use setu_move_vm::object_runtime::InputObject;
 #[cfg(feature = "move-vm")]
 use std::str::FromStr;
 
-/// CoinState from storage layer - matches storage/src/state_provider.rs
-///
-/// TEE receives raw CoinState (BCS) from read_set so it can:
-/// 1. Verify Merkle proof (hash must match what's stored in tree)
-/// 2. Convert to Object<CoinData> for runtime execution
-#[allow(dead_code)]
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct CoinState {
-    pub owner: String,
-    pub balance: u64,
-    pub version: u64,
-    #[serde(default = "default_coin_type")]
-    pub coin_type: String,
-}
-
-#[allow(dead_code)]
-fn default_coin_type() -> String {
-    "ROOT".to_string() // ROOT subnet's native token (consistent with storage)
-}
-
 /// Translate `setu_move_vm::engine::ModuleChange` entries into `WriteSetEntry`s
 /// on the supplied `StateDiff`.
 ///
@@ -252,6 +232,17 @@ const MOCK_MEASUREMENT: [u8; 32] = [
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
 ];
 
+/// Serializable copy of a [`MockEnclave`]'s simulated state.
+///
+/// Produced by [`MockEnclave::export_state`] and loaded back through
+/// [`MockEnclave::import_state`] or [`MockEnclaveBuilder::with_snapshot`], so
+/// a scenario can be paused and resumed across enclave instances or runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MockEnclaveSnapshot {
+    /// Simulated key/value state the post-state root is computed over
+    pub state: BTreeMap<String, Vec<u8>>,
+}
+
 /// Mock enclave for development and testing
 ///
 /// This enclave simulates TEE execution by calling setu-runtime
@@ -266,6 +257,10 @@ pub struct MockEnclave {
     legacy_state: Arc<RwLock<HashMap<String, Vec<u8>>>>,
     /// Execution counter for statistics
     execution_count: Arc<RwLock<u64>>,
+    /// Sum of STF execution times (µs), for the mean alongside `execution_count`
+    total_execution_time_us: Arc<RwLock<u64>>,
+    /// Artificial delay before each event (ms), to exercise execution timeouts
+    execution_delay_ms: u64,
     /// Move VM engine (optional, enabled via `move-vm` feature)
     #[cfg(feature = "move-vm")]
     move_engine: Option<Arc<SetuMoveEngine>>,
@@ -275,13 +270,15 @@ impl MockEnclave {
     /// Create a new mock enclave with the given configuration
     pub fn new(config: EnclaveConfig) -> Self {
         let store = InMemoryStateStore::new();
-        let runtime = RuntimeExecutor::new(store);
+        let runtime = Self::new_runtime(&config, store);
 
         Self {
             config,
             runtime: Arc::new(RwLock::new(runtime)),
             legacy_state: Arc::new(RwLock::new(HashMap::new())),
             execution_count: Arc::new(RwLock::new(0)),
+            total_execution_time_us: Arc::new(RwLock::new(0)),
+            execution_delay_ms: 0,
             #[cfg(feature = "move-vm")]
             move_engine: SetuMoveEngine::new_with_embedded_stdlib()
                 .ok()
@@ -289,6 +286,20 @@ impl MockEnclave {
         }
     }
 
+    /// Runtime executor over `store`, with the configured coin freeze
+    /// authority and fee collector
+    fn new_runtime<S: StateStore>(config: &EnclaveConfig, store: S) -> RuntimeExecutor<S> {
+        let runtime = RuntimeExecutor::new(store);
+        let runtime = match config.coin_freeze_authority {
+            Some(authority) => runtime.with_freeze_authority(authority),
+            None => runtime,
+        };
+        match config.fee_collector {
+            Some(collector) => runtime.with_fee_collector(collector),
+            None => runtime,
+        }
+    }
+
     /// Create a mock enclave with default configuration
     pub fn default_with_solver_id(solver_id: String) -> Self {
         let config = EnclaveConfig::default().with_solver_id(solver_id);
@@ -311,11 +322,36 @@ impl MockEnclave {
         info!(address = %address, balance = balance, coin_id = %coin_id, "Initialized account");
     }
 
+    /// Snapshot the simulated state
+    pub async fn export_state(&self) -> MockEnclaveSnapshot {
+        let state = self.legacy_state.read().await;
+        MockEnclaveSnapshot {
+            state: state.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+        }
+    }
+
+    /// Replace the simulated state with a snapshot
+    pub async fn import_state(&self, snapshot: MockEnclaveSnapshot) {
+        *self.legacy_state.write().await = snapshot.state.into_iter().collect();
+    }
+
     /// Get the current execution count
     pub async fn execution_count(&self) -> u64 {
         *self.execution_count.read().await
     }
 
+    /// Get the total STF execution time across all executions (µs)
+    pub async fn total_execution_time_us(&self) -> u64 {
+        *self.total_execution_time_us.read().await
+    }
+
+    /// Sleep for the configured per-event delay (no-op unless set via the builder)
+    async fn apply_execution_delay(&self) {
+        if self.execution_delay_ms > 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(self.execution_delay_ms)).await;
+        }
+    }
+
     /// Build temporary state from read_set (solver-tee3 architecture)
     ///
     /// This is the key function that loads objects from read_set into
@@ -360,9 +396,9 @@ impl MockEnclave {
                 // Deserialize CoinState from BCS (raw storage format)
                 // Non-CoinState entries (e.g. FluxState/PowerState JSON) will fail BCS
                 // deserialization — skip them (they're read separately for Power/Flux).
-                let coin_state: CoinState = match bcs::from_bytes(&entry.value) {
-                    Ok(cs) => cs,
-                    Err(_) => {
+                let coin_state = match setu_types::coin::CoinState::from_bytes(&entry.value) {
+                    Some(cs) => cs,
+                    None => {
                         debug!(key = %entry.key, "Skipping non-CoinState read_set entry");
                         continue;
                     }
@@ -395,6 +431,7 @@ impl MockEnclave {
                         ownership: setu_types::Ownership::AddressOwner(owner),
                         created_at: 0,
                         updated_at: 0,
+                        frozen: coin_state.frozen,
                     },
                     data: coin_data,
                 };
@@ -518,10 +555,10 @@ impl MockEnclave {
                     let magic = u16::from_le_bytes([entry.value[0], entry.value[1]]);
                     if magic == ENVELOPE_MAGIC {
                         // BCS ObjectEnvelope
-                        let env: ObjectEnvelope = bcs::from_bytes(&entry.value).map_err(|e| {
+                        let env = ObjectEnvelope::from_bytes(&entry.value).ok_or_else(|| {
                             StfError::InvalidResolvedInputs(format!(
-                                "Failed to deserialize ObjectEnvelope {}: {}",
-                                hex_id, e
+                                "Failed to deserialize ObjectEnvelope {}",
+                                hex_id
                             ))
                         })?;
                         store.set_envelope(object_id, env).map_err(|e| {
@@ -533,20 +570,25 @@ impl MockEnclave {
                 }
 
                 // Fallback: legacy CoinState BCS
-                // Non-CoinState entries (FluxState, PowerState, ResourceParams) are JSON-serialized
-                // and will fail BCS deserialization. Detect them by trying JSON first: if the
-                // bytes start with '{', it's a JSON object — skip it. These entries are handled
-                // separately by extract_power_flux() and extract_resource_params().
+                // Non-CoinState entries (FluxState, PowerState, ResourceParams,
+                // UserSubnetActivity) are JSON-serialized and will fail BCS deserialization.
+                // Detect them by trying JSON first: if the bytes start with '{', it's a JSON
+                // object — load it as a raw object so the runtime can read it back via
+                // get_raw_object (UserSubnetActivity). Power/Flux/ResourceParams are still
+                // handled separately by extract_power_flux() and extract_resource_params().
                 // This preserves error detection for genuinely corrupted BCS CoinState data.
                 if entry.value.first() == Some(&b'{') {
-                    debug!(key = %entry.key, "Skipping JSON read_set entry (Power/Flux/ResourceParams)");
+                    debug!(key = %entry.key, "Loading JSON read_set entry as raw object");
+                    store.set_raw_object(object_id, entry.value.clone()).map_err(|e| {
+                        StfError::InternalError(format!("Failed to store raw object: {}", e))
+                    })?;
                     continue;
                 }
-                let coin_state: setu_types::coin::CoinState = bcs::from_bytes(&entry.value)
-                    .map_err(|e| {
+                let coin_state = setu_types::coin::CoinState::from_bytes(&entry.value)
+                    .ok_or_else(|| {
                         StfError::InvalidResolvedInputs(format!(
-                            "Failed to deserialize CoinState {}: {}",
-                            hex_id, e
+                            "Failed to deserialize CoinState {}",
+                            hex_id
                         ))
                     })?;
                 let env = ObjectEnvelope::from_legacy_coin_state(object_id, &coin_state).map_err(
@@ -566,11 +608,11 @@ impl MockEnclave {
                 let object_id = ObjectId::from_hex(hex_id).map_err(|e| {
                     StfError::InvalidResolvedInputs(format!("Invalid object ID: {}", e))
                 })?;
-                let coin_state: setu_types::coin::CoinState = bcs::from_bytes(&entry.value)
-                    .map_err(|e| {
+                let coin_state = setu_types::coin::CoinState::from_bytes(&entry.value)
+                    .ok_or_else(|| {
                         StfError::InvalidResolvedInputs(format!(
-                            "Failed to deserialize CoinState {}: {}",
-                            hex_id, e
+                            "Failed to deserialize CoinState {}",
+                            hex_id
                         ))
                     })?;
                 let env = ObjectEnvelope::from_legacy_coin_state(object_id, &coin_state).map_err(
@@ -637,6 +679,7 @@ impl MockEnclave {
         _local_runtime: Option<RuntimeExecutor<InMemoryStateStore>>,
     ) -> StfResult<(StateDiff, Vec<EventId>, Vec<FailedEvent>)> {
         let start = std::time::Instant::now();
+        let timeout_ms = self.config.execution_timeout_ms(input.execution_deadline_ms);
         let mut diff = StateDiff::new();
         let mut processed = Vec::new();
         let mut failed = Vec::new();
@@ -648,9 +691,10 @@ impl MockEnclave {
 
         for event in &input.events {
             // Check timeout
-            if start.elapsed().as_millis() as u64 > self.config.max_execution_time_ms {
+            if start.elapsed().as_millis() as u64 > timeout_ms {
                 return Err(StfError::ExecutionTimeout);
             }
+            self.apply_execution_delay().await;
 
             // Execute event using shared runtime (acquired write lock above)
             let result = self
@@ -659,6 +703,8 @@ impl MockEnclave {
                     &input.resolved_inputs,
                     &mut diff,
                     &mut *runtime_guard,
+                    input.gas_budget.fee_quote(),
+                    input.zero_balance_policy,
                 )
                 .await;
 
@@ -691,6 +737,7 @@ impl MockEnclave {
         mut local_runtime: RuntimeExecutor<InMemoryObjectStore>,
     ) -> StfResult<(StateDiff, Vec<EventId>, Vec<FailedEvent>)> {
         let start = std::time::Instant::now();
+        let timeout_ms = self.config.execution_timeout_ms(input.execution_deadline_ms);
         let mut diff = StateDiff::new();
         let mut processed = Vec::new();
         let mut failed = Vec::new();
@@ -711,9 +758,10 @@ impl MockEnclave {
         // Process each event with the isolated local runtime
         for event in &input.events {
             // Check timeout
-            if start.elapsed().as_millis() as u64 > self.config.max_execution_time_ms {
+            if start.elapsed().as_millis() as u64 > timeout_ms {
                 return Err(StfError::ExecutionTimeout);
             }
+            self.apply_execution_delay().await;
 
             let consumes_power = setu_runtime::should_consume_power(&event.event_type);
 
@@ -745,6 +793,8 @@ impl MockEnclave {
                     &mut diff,
                     &mut local_runtime,
                     input.gas_budget.max_gas_units,
+                    input.gas_budget.fee_quote(),
+                    input.zero_balance_policy,
                 )
                 .await;
 
@@ -815,6 +865,10 @@ impl MockEnclave {
         resolved_inputs: &ResolvedInputs,
         diff: &mut StateDiff,
         local_runtime: &mut RuntimeExecutor<InMemoryStateStore>,
+        // Task `GasBudget` fee, withdrawn on top of the transfer amount
+        transfer_fee: FeeQuote,
+        // Task policy for sender coins a transfer drains to zero
+        zero_balance_policy: ZeroBalanceCoinPolicy,
     ) -> Result<(), String> {
         debug!(event_id = %event.id, event_type = ?event.event_type, "Executing event via isolated runtime");
 
@@ -827,6 +881,8 @@ impl MockEnclave {
                     resolved_inputs,
                     diff,
                     local_runtime,
+                    transfer_fee,
+                    zero_balance_policy,
                 )
                 .await;
         }
@@ -936,12 +992,15 @@ impl MockEnclave {
                 return Ok(());
             }
             setu_types::event::EventPayload::CoinMergeThenTransfer { .. } => {
+                // The merge ignores the fee; the transfer step withdraws it
                 let ctx = ExecutionContext::new(
                     self.config.solver_id.clone(),
                     event.timestamp,
                     false,
                     Self::derive_tx_hash(&event.id),
-                );
+                )
+                .with_fee_quote(transfer_fee)
+                .with_zero_balance_policy(zero_balance_policy);
                 if let setu_types::OperationType::MergeThenTransfer {
                     target_index,
                     source_indices,
@@ -1041,6 +1100,10 @@ impl MockEnclave {
         // `MAX_GAS_BUDGET / 5` default the validator applies when the
         // API caller omits the field.
         gas_budget: u64,
+        // Task `GasBudget` fee, withdrawn on top of the transfer amount
+        transfer_fee: FeeQuote,
+        // Task policy for sender coins a transfer drains to zero
+        zero_balance_policy: ZeroBalanceCoinPolicy,
     ) -> Result<(), String> {
         debug!(event_id = %event.id, event_type = ?event.event_type, "Executing event via object store runtime");
 
@@ -1053,6 +1116,8 @@ impl MockEnclave {
                     resolved_inputs,
                     diff,
                     local_runtime,
+                    transfer_fee,
+                    zero_balance_policy,
                 )
                 .await;
         }
@@ -1168,12 +1233,15 @@ impl MockEnclave {
 
             // CoinMergeThenTransfer
             setu_types::event::EventPayload::CoinMergeThenTransfer { .. } => {
+                // The merge ignores the fee; the transfer step withdraws it
                 let ctx = ExecutionContext::new(
                     self.config.solver_id.clone(),
                     event.timestamp,
                     false,
                     Self::derive_tx_hash(&event.id),
-                );
+                )
+                .with_fee_quote(transfer_fee)
+                .with_zero_balance_policy(zero_balance_policy);
                 if let setu_types::OperationType::MergeThenTransfer {
                     target_index,
                     source_indices,
@@ -1597,13 +1665,17 @@ impl MockEnclave {
         resolved_inputs: &ResolvedInputs,
         diff: &mut StateDiff,
         local_runtime: &mut RuntimeExecutor<S>,
+        transfer_fee: FeeQuote,
+        zero_balance_policy: ZeroBalanceCoinPolicy,
     ) -> Result<(), String> {
         let ctx = ExecutionContext::new(
             self.config.solver_id.clone(),
             event.timestamp,
             false, // Mock enclave
             Self::derive_tx_hash(&event.id),
-        );
+        )
+        .with_fee_quote(transfer_fee)
+        .with_zero_balance_policy(zero_balance_policy);
 
         // solver-tee3: resolved_inputs MUST have primary_coin
         let resolved_coin = resolved_inputs.primary_coin().ok_or_else(|| {
@@ -1717,9 +1789,12 @@ impl MockEnclave {
 
     /// Compute hash of output for attestation user_data
     ///
-    /// Reserved for future use: binding attestation to output commitment
+    /// Reserved for future use: binding attestation to output commitment.
+    /// The task_id is included so an output commitment cannot be reused for
+    /// another task with an identical state transition.
     #[allow(dead_code)]
     fn compute_output_hash(
+        task_id: &[u8; 32],
         subnet_id: &setu_types::SubnetId,
         pre_state_root: &[u8; 32],
         post_state_root: &[u8; 32],
@@ -1727,6 +1802,7 @@ impl MockEnclave {
     ) -> [u8; 32] {
         let mut hasher = blake3::Hasher::new();
         hasher.update(b"SETU_OUTPUT_HASH:");
+        hasher.update(task_id);
         hasher.update(subnet_id.as_bytes());
         hasher.update(pre_state_root);
         hasher.update(post_state_root);
@@ -1765,25 +1841,37 @@ impl EnclaveRuntime for MockEnclave {
             .any(|e| matches!(e.payload, setu_types::event::EventPayload::MovePtb(_)));
         let use_read_set_state = use_read_set_state || has_move_ptb;
 
-        let (diff, events_processed, events_failed) = if use_read_set_state {
-            // Build temporary state from read_set into a LOCAL ObjectStore
-            let local_store =
-                self.build_object_store_from_read_set(&input.read_set, &input.module_read_set)?;
-            let local_runtime = RuntimeExecutor::new(local_store);
-
-            info!(
-                read_set_entries = input.read_set.len(),
-                module_entries = input.module_read_set.len(),
-                "Using isolated read_set state for execution (solver-tee3 mode)"
-            );
-
-            // Execute using the ISOLATED local runtime (not self.runtime!)
-            self.simulate_execution_isolated(&input, local_runtime)
-                .await?
-        } else {
-            // Legacy mode: use shared self.runtime (only for backward compatibility)
-            self.simulate_execution(&input, None).await?
+        // Bound the whole execution by the task deadline (capped by the enclave
+        // limit). The per-event checks inside only fire between events; this
+        // also stops a single long-running event from holding the solver slot.
+        let timeout_ms = self.config.execution_timeout_ms(input.execution_deadline_ms);
+        let execution = async {
+            if use_read_set_state {
+                // Build temporary state from read_set into a LOCAL ObjectStore
+                let local_store = self
+                    .build_object_store_from_read_set(&input.read_set, &input.module_read_set)?;
+                let local_runtime = Self::new_runtime(&self.config, local_store);
+
+                info!(
+                    read_set_entries = input.read_set.len(),
+                    module_entries = input.module_read_set.len(),
+                    "Using isolated read_set state for execution (solver-tee3 mode)"
+                );
+
+                // Execute using the ISOLATED local runtime (not self.runtime!)
+                self.simulate_execution_isolated(&input, local_runtime).await
+            } else {
+                // Legacy mode: use shared self.runtime (only for backward compatibility)
+                self.simulate_execution(&input, None).await
+            }
         };
+        let (diff, events_processed, events_failed) =
+            tokio::time::timeout(std::time::Duration::from_millis(timeout_ms), execution)
+                .await
+                .map_err(|_| {
+                    warn!(timeout_ms, "STF execution exceeded its deadline");
+                    StfError::ExecutionTimeout
+                })??;
 
         // Compute post-state root from legacy state
         // Note: For full object model, should compute from RuntimeExecutor state
@@ -1809,6 +1897,8 @@ impl EnclaveRuntime for MockEnclave {
             .with_solver_id(self.config.solver_id.clone());
 
         let execution_time = start.elapsed();
+        let execution_time_us = execution_time.as_micros() as u64;
+        *self.total_execution_time_us.write().await += execution_time_us;
         let writes_count = diff.writes.len() as u64;
 
         // B6c follow-up fix: gas_used must be **deterministic** across
@@ -1835,7 +1925,7 @@ impl EnclaveRuntime for MockEnclave {
             gas_usage,
             attestation,
             stats: ExecutionStats {
-                execution_time_us: execution_time.as_micros() as u64,
+                execution_time_us,
                 reads: input.read_set.len() as u64,
                 writes: writes_count,
                 peak_memory_bytes: 0, // Not tracked in mock
@@ -1878,6 +1968,9 @@ pub struct MockEnclaveBuilder {
     max_memory_bytes: u64,
     debug_logging: bool,
     initial_state: HashMap<String, Vec<u8>>,
+    execution_delay_ms: u64,
+    coin_freeze_authority: Option<Address>,
+    fee_collector: Option<Address>,
 }
 
 impl MockEnclaveBuilder {
@@ -1888,6 +1981,9 @@ impl MockEnclaveBuilder {
             max_memory_bytes: 512 * 1024 * 1024,
             debug_logging: false,
             initial_state: HashMap::new(),
+            execution_delay_ms: 0,
+            coin_freeze_authority: None,
+            fee_collector: None,
         }
     }
 
@@ -1911,6 +2007,29 @@ impl MockEnclaveBuilder {
         self
     }
 
+    /// Start from a snapshot taken with [`MockEnclave::export_state`],
+    /// replacing any state added via `with_initial_state`
+    pub fn with_snapshot(mut self, snapshot: MockEnclaveSnapshot) -> Self {
+        self.initial_state = snapshot.state.into_iter().collect();
+        self
+    }
+
+    /// Delay every event by `ms` (for exercising execution timeouts in tests)
+    pub fn execution_delay(mut self, ms: u64) -> Self {
+        self.execution_delay_ms = ms;
+        self
+    }
+
+    pub fn coin_freeze_authority(mut self, authority: Address) -> Self {
+        self.coin_freeze_authority = Some(authority);
+        self
+    }
+
+    pub fn fee_collector(mut self, collector: Address) -> Self {
+        self.fee_collector = Some(collector);
+        self
+    }
+
     pub fn build(self) -> MockEnclave {
         let config = EnclaveConfig {
             enclave_id: format!("mock-{}", uuid::Uuid::new_v4()),
@@ -1918,16 +2037,20 @@ impl MockEnclaveBuilder {
             max_execution_time_ms: self.max_execution_time_ms,
             max_memory_bytes: self.max_memory_bytes,
             enable_debug_logging: self.debug_logging,
+            coin_freeze_authority: self.coin_freeze_authority,
+            fee_collector: self.fee_collector,
         };
 
         let store = InMemoryStateStore::new();
-        let runtime = RuntimeExecutor::new(store);
+        let runtime = MockEnclave::new_runtime(&config, store);
 
         let enclave = MockEnclave {
             config,
             runtime: Arc::new(RwLock::new(runtime)),
             legacy_state: Arc::new(RwLock::new(self.initial_state)),
             execution_count: Arc::new(RwLock::new(0)),
+            total_execution_time_us: Arc::new(RwLock::new(0)),
+            execution_delay_ms: self.execution_delay_ms,
             #[cfg(feature = "move-vm")]
             move_engine: SetuMoveEngine::new_with_embedded_stdlib()
                 .ok()
@@ -2002,6 +2125,190 @@ mod tests {
         assert!(output.attestation.is_mock());
     }
 
+    #[tokio::test]
+    async fn test_snapshot_restore_preserves_post_state_root() {
+        use crate::solver_task::{GasBudget, ResolvedInputs};
+
+        let input = |task: u8, event: &str| {
+            StfInput::new(
+                [task; 32],
+                SubnetId::ROOT,
+                [0u8; 32],
+                ResolvedInputs::new(),
+                GasBudget::default(),
+            )
+            .with_events(vec![create_test_event(event)])
+        };
+
+        let original = MockEnclave::default_with_solver_id("solver1".to_string());
+        let first = original.execute_stf(input(1, "evt1")).await.unwrap();
+
+        // The snapshot survives a serialization round trip
+        let json = serde_json::to_string(&original.export_state().await).unwrap();
+        let snapshot: MockEnclaveSnapshot = serde_json::from_str(&json).unwrap();
+        assert!(!snapshot.state.is_empty());
+
+        let restored = MockEnclaveBuilder::new("solver1").with_snapshot(snapshot.clone()).build();
+        #[allow(deprecated)]
+        let restored_root = MockEnclave::compute_state_root(&*restored.legacy_state.read().await);
+        assert_eq!(restored_root, first.post_state_root);
+
+        // Both instances continue from the same state
+        let next = input(2, "evt2");
+        let resumed = restored.execute_stf(next.clone()).await.unwrap();
+        let continued = original.execute_stf(next).await.unwrap();
+        assert_eq!(resumed.post_state_root, continued.post_state_root);
+        assert_ne!(resumed.post_state_root, first.post_state_root);
+
+        // import_state rewinds an existing enclave
+        original.import_state(snapshot.clone()).await;
+        assert_eq!(original.export_state().await, snapshot);
+    }
+
+    #[tokio::test]
+    async fn test_mock_enclave_transfer_moves_balances_in_state_diff() {
+        use crate::solver_task::{GasBudget, ResolvedInputs, ResolvedObject};
+        use setu_types::coin::CoinState;
+        use setu_types::Transfer;
+
+        let enclave = MockEnclave::default_with_solver_id("solver1".to_string());
+        let sender = Address::new([1u8; 32]);
+        let recipient = Address::new([2u8; 32]);
+        let coin_id = ObjectId::new([7u8; 32]);
+        let coin_state = CoinState::new(sender.to_string(), 1_000);
+
+        let transfer = Transfer::new("tx-1", sender.to_string(), recipient.to_string(), 300);
+        let event = Event::transfer(
+            transfer,
+            vec![],
+            VLCSnapshot::default(),
+            "solver1".to_string(),
+        );
+        let input = StfInput::new(
+            [2u8; 32],
+            SubnetId::ROOT,
+            [0u8; 32],
+            ResolvedInputs::transfer(ResolvedObject::coin(coin_id), 300),
+            GasBudget::default(),
+        )
+        .with_events(vec![event])
+        .with_read_set(vec![ReadSetEntry::new(
+            format!("oid:{}", hex::encode(coin_id.as_bytes())),
+            bcs::to_bytes(&coin_state).unwrap(),
+        )]);
+
+        let output = enclave.execute_stf(input).await.unwrap();
+        assert!(output.events_failed.is_empty(), "{:?}", output.events_failed);
+
+        // Sum post-transfer coin balances per owner from the write set
+        let mut balances: HashMap<Address, u64> = HashMap::new();
+        for write in &output.state_diff.writes {
+            if !write.key.starts_with("oid:") {
+                continue;
+            }
+            let coin = CoinState::from_bytes(&write.new_value).unwrap();
+            *balances
+                .entry(Address::from_hex(&coin.owner).unwrap())
+                .or_default() += coin.balance;
+        }
+        assert_eq!(balances.get(&sender), Some(&700));
+        assert_eq!(balances.get(&recipient), Some(&300));
+    }
+
+    #[tokio::test]
+    async fn test_mock_enclave_transfer_charges_estimated_fee() {
+        use crate::solver_task::{ResolvedInputs, ResolvedObject};
+        use setu_types::coin::CoinState;
+        use setu_types::{FeeCalculator, ResourceGovernanceMode, ResourceParams, Transfer};
+
+        let collector = Address::new([9u8; 32]);
+        let enclave = MockEnclaveBuilder::new("solver1").fee_collector(collector).build();
+        let sender = Address::new([1u8; 32]);
+        let recipient = Address::new([2u8; 32]);
+        let coin_id = ObjectId::new([7u8; 32]);
+        let coin_state = CoinState::new(sender.to_string(), 1_000);
+        // The same calculator the task preparer prices transfers with
+        let calculator = FeeCalculator::new(&ResourceParams {
+            gas_mode: ResourceGovernanceMode::Enabled,
+            gas_base_price: 3,
+            transfer_fee_rate_bps: 250,
+            ..ResourceParams::default()
+        });
+
+        let run = |task: u8, amount: u64| {
+            let transfer = Transfer::new(format!("tx-{}", task), sender.to_string(), recipient.to_string(), amount);
+            let event = Event::transfer(transfer, vec![], VLCSnapshot::default(), "solver1".to_string());
+            let input = StfInput::new(
+                [task; 32],
+                SubnetId::ROOT,
+                [0u8; 32],
+                ResolvedInputs::transfer(ResolvedObject::coin(coin_id), amount),
+                calculator.transfer_gas_budget(amount, 1),
+            )
+            .with_events(vec![event])
+            .with_read_set(vec![ReadSetEntry::new(
+                format!("oid:{}", hex::encode(coin_id.as_bytes())),
+                bcs::to_bytes(&coin_state).unwrap(),
+            )]);
+            enclave.execute_stf(input)
+        };
+
+        // fee = 3 * 1 coin + 400 * 2.5% = 13
+        let output = run(3, 400).await.unwrap();
+        assert!(output.events_failed.is_empty(), "{:?}", output.events_failed);
+        let mut balances: HashMap<Address, u64> = HashMap::new();
+        for write in &output.state_diff.writes {
+            if !write.key.starts_with("oid:") {
+                continue;
+            }
+            let coin = CoinState::from_bytes(&write.new_value).unwrap();
+            *balances
+                .entry(Address::from_hex(&coin.owner).unwrap())
+                .or_default() += coin.balance;
+        }
+        assert_eq!(balances.get(&sender), Some(&(1_000 - 400 - 13)));
+        assert_eq!(balances.get(&recipient), Some(&400));
+        // The gas part reaches the collector, the 10 rate part is burned
+        assert_eq!(balances.get(&collector), Some(&3));
+
+        // 980 + fee 27 exceeds the coin: the event fails instead of overdrawing
+        let output = run(4, 980).await.unwrap();
+        assert_eq!(output.events_failed.len(), 1);
+        assert!(output.events_failed[0].reason.contains("Insufficient"), "{}", output.events_failed[0].reason);
+    }
+
+    #[tokio::test]
+    async fn test_mock_enclave_attestation_bound_to_task_id() {
+        use crate::solver_task::{GasBudget, ResolvedInputs};
+
+        let enclave = MockEnclave::default_with_solver_id("solver1".to_string());
+        let task_a = [0xAAu8; 32];
+        let task_b = [0xBBu8; 32];
+
+        let run = |task_id| {
+            let input = StfInput::new(
+                task_id,
+                SubnetId::ROOT,
+                [0u8; 32],
+                ResolvedInputs::new(),
+                GasBudget::default(),
+            )
+            .with_events(vec![create_test_event("evt1")]);
+            enclave.execute_stf(input)
+        };
+        let att_a = run(task_a).await.unwrap().attestation;
+        let att_b = run(task_b).await.unwrap().attestation;
+
+        // Identical state transition, different tasks → different user_data
+        assert_ne!(att_a.user_data, att_b.user_data);
+
+        assert!(att_a.verify_task_binding(&task_a).is_ok());
+        assert!(matches!(
+            att_a.verify_task_binding(&task_b),
+            Err(setu_types::task::AttestationError::TaskIdMismatch)
+        ));
+    }
+
     #[tokio::test]
     async fn test_mock_enclave_generates_attestation() {
         let enclave = MockEnclave::default_with_solver_id("solver1".to_string());
@@ -2024,6 +2331,46 @@ mod tests {
         assert_eq!(enclave.measurement(), MOCK_MEASUREMENT);
     }
 
+    #[tokio::test]
+    async fn test_task_deadline_times_out_delayed_execution() {
+        use crate::solver_task::{GasBudget, ResolvedInputs};
+
+        // Each event takes ~200ms; the enclave itself would allow 5s
+        let enclave = MockEnclaveBuilder::new("slow_solver")
+            .max_execution_time(5000)
+            .execution_delay(200)
+            .build();
+
+        let input = StfInput::new(
+            [2u8; 32],
+            SubnetId::ROOT,
+            [0u8; 32],
+            ResolvedInputs::new(),
+            GasBudget::default(),
+        )
+        .with_events(vec![create_test_event("evt1")])
+        .with_execution_deadline(50);
+
+        let started = std::time::Instant::now();
+        let result = enclave.execute_stf(input).await;
+
+        assert!(matches!(result, Err(StfError::ExecutionTimeout)));
+        // Cut off at the task deadline, not the 200ms event or the enclave limit
+        assert!(started.elapsed() < std::time::Duration::from_millis(200));
+
+        // Without a task deadline the enclave limit applies and the event completes
+        let input = StfInput::new(
+            [3u8; 32],
+            SubnetId::ROOT,
+            [0u8; 32],
+            ResolvedInputs::new(),
+            GasBudget::default(),
+        )
+        .with_events(vec![create_test_event("evt2")]);
+        let output = enclave.execute_stf(input).await.unwrap();
+        assert_eq!(output.events_processed.len(), 1);
+    }
+
     #[cfg(feature = "move-vm")]
     #[test]
     fn module_changes_coalesce_publish_linkage_per_package() {
@@ -177,7 +177,9 @@ impl EnclaveRuntime for NitroEnclave {
     }
 
     async fn generate_attestation(&self, _user_data: [u8; 32]) -> StfResult<Attestation> {
-        // TODO: Use NSM API to generate attestation
+        // TODO: Use NSM API to generate attestation. `execute_stf` must pass
+        // `AttestationData::to_user_data()` here so the document binds the
+        // task_id, as the mock enclave does.
         // let nsm_fd = nsm_driver::nsm_init();
         // let response = nsm_driver::nsm_process_request(nsm_fd, request);
 
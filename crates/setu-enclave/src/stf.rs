@@ -70,7 +70,7 @@
 //! ```
 
 use serde::{Deserialize, Serialize};
-use setu_types::{Event, EventId, SubnetId};
+use setu_types::{Event, EventId, SubnetId, ZeroBalanceCoinPolicy};
 use thiserror::Error;
 // Use types from setu-types (canonical source)
 use setu_types::task::{
@@ -182,6 +182,15 @@ pub struct StfInput {
     /// Empty for non-MoveCall transactions.
     #[serde(default)]
     pub module_read_set: Vec<ReadSetEntry>,
+
+    /// Optional per-task execution deadline in milliseconds (from `SolverTask`).
+    /// Capped by the enclave's `max_execution_time_ms`.
+    #[serde(default)]
+    pub execution_deadline_ms: Option<u64>,
+
+    /// Handling of sender coins a transfer drains to zero (from `SolverTask`).
+    #[serde(default)]
+    pub zero_balance_policy: ZeroBalanceCoinPolicy,
 }
 
 impl StfInput {
@@ -203,6 +212,8 @@ impl StfInput {
             gas_budget,
             anchor_id: None,
             module_read_set: Vec::new(),
+            execution_deadline_ms: None,
+            zero_balance_policy: ZeroBalanceCoinPolicy::default(),
         }
     }
 
@@ -226,6 +237,11 @@ impl StfInput {
         self
     }
 
+    pub fn with_execution_deadline(mut self, deadline_ms: u64) -> Self {
+        self.execution_deadline_ms = Some(deadline_ms);
+        self
+    }
+
     /// Compute input hash for attestation binding
     /// This hash covers all inputs to ensure attestation is bound to specific execution
     pub fn input_hash(&self) -> Hash {
@@ -318,7 +334,8 @@ impl StateDiff {
     /// This converts runtime's object-based StateChange to enclave's WriteSetEntry.
     /// NOTE: Duplicate keys may occur for the same ObjectId (e.g., Power decremented
     /// per event in a batch). Consumer (GlobalStateManager) applies writes in order
-    /// with last-write-wins semantics. The `commitment()` hash includes all writes.
+    /// with last-write-wins semantics. The `commitment()` hash includes all writes,
+    /// keeping same-key writes in their original order.
     pub fn add_state_changes(&mut self, state_changes: &[setu_runtime::StateChange]) {
         for change in state_changes {
             let entry = WriteSetEntry::from_state_change(
@@ -340,8 +357,10 @@ impl StateDiff {
 
     /// Compute commitment hash of this state diff.
     ///
-    /// Uses the canonical `hash_utils::compute_write_set_commitment` to ensure
-    /// validator and enclave produce identical commitments.
+    /// Entries are hashed in key order (via the canonical
+    /// `hash_utils::compute_sorted_write_set_commitment`), so the commitment
+    /// doesn't depend on the order writes were added and matches the
+    /// validator's recomputation.
     pub fn commitment(&self) -> Hash {
         let changes: Vec<(String, Option<Vec<u8>>, Option<Vec<u8>>)> = self
             .writes
@@ -361,7 +380,7 @@ impl StateDiff {
                 )
             }))
             .collect();
-        setu_types::hash_utils::compute_write_set_commitment(&changes)
+        setu_types::hash_utils::compute_sorted_write_set_commitment(&changes)
     }
 }
 
@@ -528,6 +547,25 @@ mod tests {
         assert_ne!(commitment1, diff2.commitment());
     }
 
+    #[test]
+    fn test_state_diff_commitment_independent_of_write_order() {
+        let writes = [
+            WriteSetEntry::new("oid:bb".to_string(), vec![2]).with_old_value(vec![1]),
+            WriteSetEntry::new("oid:aa".to_string(), vec![3]),
+            WriteSetEntry::new("oid:cc".to_string(), vec![4]),
+        ];
+
+        let mut forward = StateDiff::new();
+        writes.iter().cloned().for_each(|w| forward.add_write(w));
+        forward.add_delete("oid:dd".to_string());
+
+        let mut reversed = StateDiff::new();
+        reversed.add_delete("oid:dd".to_string());
+        writes.iter().rev().cloned().for_each(|w| reversed.add_write(w));
+
+        assert_eq!(forward.commitment(), reversed.commitment());
+    }
+
     #[test]
     fn test_read_set_entry() {
         let entry =
@@ -5,6 +5,7 @@
 use crate::stf::{StfInput, StfOutput, StfResult};
 use async_trait::async_trait;
 use setu_types::task::Attestation;
+use setu_types::Address;
 
 /// Configuration for enclave initialization
 #[derive(Debug, Clone)]
@@ -19,6 +20,12 @@ pub struct EnclaveConfig {
     pub max_memory_bytes: u64,
     /// Whether to enable detailed logging (may leak info in production)
     pub enable_debug_logging: bool,
+    /// Address allowed to freeze/unfreeze coins (`None` = nobody). Must be
+    /// the same on every solver so they agree on freeze outcomes.
+    pub coin_freeze_authority: Option<Address>,
+    /// Address credited with the non-burned part of transfer fees (`None` =
+    /// transfers carrying such a fee fail). Must be the same on every solver.
+    pub fee_collector: Option<Address>,
 }
 
 impl Default for EnclaveConfig {
@@ -29,6 +36,8 @@ impl Default for EnclaveConfig {
             max_execution_time_ms: 30_000,        // 30 seconds
             max_memory_bytes: 1024 * 1024 * 1024, // 1 GB
             enable_debug_logging: false,
+            coin_freeze_authority: None,
+            fee_collector: None,
         }
     }
 }
@@ -45,6 +54,24 @@ impl EnclaveConfig {
         self.solver_id = solver_id;
         self
     }
+
+    pub fn with_coin_freeze_authority(mut self, authority: Address) -> Self {
+        self.coin_freeze_authority = Some(authority);
+        self
+    }
+
+    pub fn with_fee_collector(mut self, collector: Address) -> Self {
+        self.fee_collector = Some(collector);
+        self
+    }
+
+    /// Time limit for one STF execution: the task's own deadline if it is
+    /// tighter than `max_execution_time_ms`, otherwise the enclave limit.
+    pub fn execution_timeout_ms(&self, task_deadline_ms: Option<u64>) -> u64 {
+        task_deadline_ms.map_or(self.max_execution_time_ms, |deadline| {
+            deadline.min(self.max_execution_time_ms)
+        })
+    }
 }
 
 /// Information about the enclave
@@ -158,6 +185,10 @@ mod tests {
         let config = EnclaveConfig::default();
         assert_eq!(config.max_execution_time_ms, 30_000);
         assert_eq!(config.max_memory_bytes, 1024 * 1024 * 1024);
+        // A task deadline can only tighten the enclave limit
+        assert_eq!(config.execution_timeout_ms(None), 30_000);
+        assert_eq!(config.execution_timeout_ms(Some(500)), 500);
+        assert_eq!(config.execution_timeout_ms(Some(60_000)), 30_000);
     }
 
     #[test]
@@ -305,6 +305,42 @@ impl SubnetAggregationTree {
         Self::build(new_entries)
     }
 
+    /// Bring the tree in line with `roots` in place and return the new
+    /// global root.
+    ///
+    /// Only subnets whose state root changed are rehashed (O(log n) each);
+    /// the tree is rebuilt when the set of subnets itself changed. The result
+    /// is identical to `build` over the same roots.
+    pub fn sync_roots(&mut self, roots: &std::collections::HashMap<SubnetId, HashValue>) -> HashValue {
+        let same_subnets = roots.len() == self.entries.len()
+            && self.entries.iter().all(|e| roots.contains_key(&e.subnet_id));
+        if !same_subnets {
+            *self = Self::build(
+                roots
+                    .iter()
+                    .map(|(id, root)| SubnetStateEntry::new(*id, *root))
+                    .collect(),
+            );
+            return self.global_root;
+        }
+
+        let mut changed = false;
+        for (index, entry) in self.entries.iter_mut().enumerate() {
+            let root = roots[&entry.subnet_id];
+            if entry.state_root != root {
+                entry.state_root = root;
+                self.tree
+                    .update_leaf_hash(index, entry.leaf_hash())
+                    .expect("entry index is a valid leaf index");
+                changed = true;
+            }
+        }
+        if changed {
+            self.global_root = self.tree.root();
+        }
+        self.global_root
+    }
+
     /// Get a map of SubnetId -> StateRoot for convenience.
     pub fn to_map(&self) -> std::collections::HashMap<SubnetId, HashValue> {
         self.entries
@@ -444,6 +480,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sync_roots_matches_full_build() {
+        let mut roots: std::collections::HashMap<SubnetId, HashValue> = (0..10u8)
+            .map(|i| (make_subnet_id(i), make_state_root(i + 1)))
+            .collect();
+        let mut tree = SubnetAggregationTree::default();
+        let initial = tree.sync_roots(&roots);
+
+        roots.insert(make_subnet_id(4), make_state_root(200));
+        let updated = tree.sync_roots(&roots);
+        assert_ne!(updated, initial);
+        assert_eq!(
+            updated,
+            SubnetAggregationTree::build(
+                roots.iter().map(|(id, r)| SubnetStateEntry::new(*id, *r)).collect()
+            )
+            .root()
+        );
+        assert_eq!(tree.get_subnet_root(&make_subnet_id(4)), Some(make_state_root(200)));
+
+        // Adding a subnet falls back to a rebuild
+        roots.insert(make_subnet_id(42), make_state_root(42));
+        assert_eq!(tree.sync_roots(&roots), SubnetAggregationTree::build(
+            roots.iter().map(|(id, r)| SubnetStateEntry::new(*id, *r)).collect()
+        ).root());
+    }
+
     #[test]
     fn test_add_new_subnet() {
         let entries = vec![SubnetStateEntry::new(ROOT_SUBNET, make_state_root(0))];
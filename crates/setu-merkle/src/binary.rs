@@ -259,6 +259,36 @@ impl BinaryMerkleTree {
         Self { nodes, n_leaves }
     }
 
+    /// Replace the hash of the leaf at `leaf_index` and rehash its path to
+    /// the root, O(log n). The result is identical to rebuilding the tree
+    /// with the new leaf.
+    pub fn update_leaf_hash(&mut self, leaf_index: usize, leaf_hash: HashValue) -> MerkleResult<()> {
+        if leaf_index >= self.n_leaves {
+            return Err(MerkleError::IndexOutOfBounds {
+                index: leaf_index,
+                size: self.n_leaves,
+            });
+        }
+
+        self.nodes[leaf_index] = Node::from(leaf_hash);
+
+        let mut level_start = 0;
+        let mut level_nodes = self.n_leaves;
+        let mut index = leaf_index;
+        while level_nodes > 1 {
+            // Levels are padded to even length, as in `build_from_leaf_nodes`
+            let padded = level_nodes + level_nodes % 2;
+            let left = level_start + (index & !1);
+            let parent = Self::compute_parent(&self.nodes[left], &self.nodes[left + 1]);
+
+            level_start += padded;
+            index /= 2;
+            self.nodes[level_start + index] = parent;
+            level_nodes = padded / 2;
+        }
+        Ok(())
+    }
+
     /// Compute parent node from two children
     fn compute_parent(left: &Node, right: &Node) -> Node {
         match (left, right) {
@@ -478,6 +508,19 @@ mod tests {
         assert!(proof.is_rightmost(3), "Leaf 3 should be rightmost");
     }
 
+    #[test]
+    fn test_update_leaf_hash_matches_rebuild() {
+        let mut hashes: Vec<HashValue> = (0..7u8).map(|i| HashValue::new([i + 1; 32])).collect();
+        let mut tree = BinaryMerkleTree::build_from_hashes(hashes.clone());
+
+        for index in [0, 3, 6] {
+            hashes[index] = HashValue::new([0xC0 + index as u8; 32]);
+            tree.update_leaf_hash(index, hashes[index]).unwrap();
+            assert_eq!(tree.root(), BinaryMerkleTree::build_from_hashes(hashes.clone()).root());
+        }
+        assert!(tree.update_leaf_hash(7, HashValue::new([1; 32])).is_err());
+    }
+
     #[test]
     fn test_deterministic() {
         let leaves = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()];
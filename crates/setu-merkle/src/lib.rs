@@ -38,7 +38,9 @@ pub use aggregation::{SubnetAggregationProof, SubnetAggregationTree, SubnetState
 pub use binary::{BinaryMerkleProof, BinaryMerkleTree};
 pub use error::{MerkleError, MerkleResult};
 pub use hash::{HashValue, blake3_hash};
-pub use sparse::{IncrementalSparseMerkleTree, LeafChanges, SparseMerkleProof, SparseMerkleTree};
+pub use sparse::{
+    empty_subtree_hash, IncrementalSparseMerkleTree, LeafChanges, SparseMerkleProof, SparseMerkleTree,
+};
 pub use storage::{
     B4Store, InMemoryBatch, InMemoryMerkleStore, MerkleLeafStore, MerkleMetaStore,
     MerkleNodeStore, MerkleRootStore, MerkleStore,
@@ -51,6 +51,15 @@ fn empty_hash() -> HashValue {
     *EMPTY_HASH
 }
 
+/// Hash of an empty subtree at `level` of a proof path (0 = just below the root).
+///
+/// An internal node with two empty children collapses to the placeholder
+/// itself, so every level shares BLAKE3("SPARSE_EMPTY"). Proof encoders use
+/// this to recognise default siblings rather than hard-coding the value.
+pub fn empty_subtree_hash(_level: usize) -> HashValue {
+    empty_hash()
+}
+
 lazy_static::lazy_static! {
     static ref EMPTY_HASH: HashValue = {
         let mut bytes = [0u8; HASH_LENGTH];
@@ -92,6 +92,11 @@ pub trait MerkleStore: MerkleNodeStore + MerkleRootStore {
 
     /// Prune old data before a given anchor.
     fn prune_before(&self, anchor_id: AnchorId) -> MerkleResult<u64>;
+
+    /// Prune one subnet's roots before a given anchor.
+    ///
+    /// Unlike `prune_before`, other subnets and the global roots are untouched.
+    fn prune_subnet_before(&self, subnet_id: &SubnetId, anchor_id: AnchorId) -> MerkleResult<u64>;
 }
 
 /// B4 Scheme: A trait for storing and retrieving raw leaf data.
@@ -257,7 +262,12 @@ pub trait B4Store: MerkleLeafStore + MerkleMetaStore + MerkleRootStore + Send +
     ) -> MerkleResult<()>;
 }
 
-/// An in-memory implementation of MerkleStore for testing.
+/// An in-memory implementation of `MerkleStore` and `B4Store`.
+///
+/// Mirrors the observable behavior of the RocksDB store (latest-root
+/// tracking, registry-backed `list_subnets`, in-order batch application) so
+/// it can stand in as the store backend for unit tests and memory-mode
+/// nodes. Nothing survives a restart.
 #[derive(Clone, Default)]
 pub struct InMemoryMerkleStore {
     nodes: Arc<std::sync::RwLock<std::collections::HashMap<(SubnetId, HashValue), SparseMerkleNode>>>,
@@ -405,6 +415,13 @@ impl MerkleStore for InMemoryMerkleStore {
 
         Ok(count)
     }
+
+    fn prune_subnet_before(&self, subnet_id: &SubnetId, anchor_id: AnchorId) -> MerkleResult<u64> {
+        let mut store = self.subnet_roots.write().unwrap();
+        let before = store.len();
+        store.retain(|(sid, aid), _| sid != subnet_id || *aid >= anchor_id);
+        Ok((before - store.len()) as u64)
+    }
 }
 
 impl MerkleLeafStore for InMemoryMerkleStore {
@@ -443,15 +460,8 @@ impl MerkleLeafStore for InMemoryMerkleStore {
     }
 
     fn list_subnets(&self) -> MerkleResult<Vec<SubnetId>> {
-        let store = self.leaves.read().unwrap();
-        let mut subnets: Vec<SubnetId> = store
-            .keys()
-            .map(|(sid, _)| *sid)
-            .collect::<std::collections::HashSet<_>>()
-            .into_iter()
-            .collect();
-        subnets.sort();
-        Ok(subnets)
+        // Like the RocksDB store, answer from the registry rather than scanning leaves
+        self.list_registered_subnets()
     }
 
     fn get_leaf(&self, subnet_id: &SubnetId, object_id: &HashValue) -> MerkleResult<Option<Vec<u8>>> {
@@ -514,8 +524,9 @@ impl MerkleMetaStore for InMemoryMerkleStore {
 /// In-memory batch for B4Store implementation
 #[derive(Default)]
 pub struct InMemoryBatch {
-    leaf_upserts: Vec<(SubnetId, HashValue, Vec<u8>)>,
-    leaf_deletes: Vec<(SubnetId, HashValue)>,
+    /// Leaf writes in call order (`None` = delete), replayed in order on
+    /// commit like a RocksDB WriteBatch
+    leaf_ops: Vec<(SubnetId, HashValue, Option<Vec<u8>>)>,
     subnet_registrations: Vec<SubnetId>,
     last_anchors: Vec<(SubnetId, AnchorId)>,
     subnet_roots: Vec<(SubnetId, AnchorId, HashValue)>,
@@ -535,19 +546,14 @@ impl B4Store for InMemoryMerkleStore {
         // Apply all operations atomically (for in-memory, just apply in sequence)
         // In production RocksDB, this would use WriteBatch::write()
         
-        // Leaf upserts
-        {
-            let mut store = self.leaves.write().unwrap();
-            for (subnet_id, object_id, value) in batch.leaf_upserts {
-                store.insert((subnet_id, object_id), value);
-            }
-        }
-
-        // Leaf deletes
+        // Leaf upserts and deletes, in the order they were batched
         {
             let mut store = self.leaves.write().unwrap();
-            for (subnet_id, object_id) in batch.leaf_deletes {
-                store.remove(&(subnet_id, object_id));
+            for (subnet_id, object_id, value) in batch.leaf_ops {
+                match value {
+                    Some(value) => store.insert((subnet_id, object_id), value),
+                    None => store.remove(&(subnet_id, object_id)),
+                };
             }
         }
 
@@ -595,7 +601,7 @@ impl B4Store for InMemoryMerkleStore {
         let batch = batch.downcast_mut::<InMemoryBatch>()
             .ok_or_else(|| crate::error::MerkleError::InvalidInput("Invalid batch type".to_string()))?;
         for (object_id, value) in leaves {
-            batch.leaf_upserts.push((*subnet_id, **object_id, value.to_vec()));
+            batch.leaf_ops.push((*subnet_id, **object_id, Some(value.to_vec())));
         }
         Ok(())
     }
@@ -609,7 +615,7 @@ impl B4Store for InMemoryMerkleStore {
         let batch = batch.downcast_mut::<InMemoryBatch>()
             .ok_or_else(|| crate::error::MerkleError::InvalidInput("Invalid batch type".to_string()))?;
         for object_id in object_ids {
-            batch.leaf_deletes.push((*subnet_id, **object_id));
+            batch.leaf_ops.push((*subnet_id, **object_id, None));
         }
         Ok(())
     }
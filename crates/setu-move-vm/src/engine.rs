@@ -3820,8 +3820,8 @@ mod tests {
                 MoveStateChangeType::Update,
                 "source coin must be Update (post-split)"
             );
-            let new_env: setu_types::ObjectEnvelope =
-                bcs::from_bytes(src_change.new_state.as_ref().expect("new_state"))
+            let new_env =
+                setu_types::ObjectEnvelope::from_bytes(src_change.new_state.as_ref().expect("new_state"))
                     .expect("envelope decode");
             // Coin<T> BCS = 32-byte UID || 8-byte LE u64 balance
             let post_balance =
@@ -3951,8 +3951,8 @@ mod tests {
                 .find(|sc| sc.object_id == ObjectId::new(a_id))
                 .expect("state_changes must contain the target coin");
             assert_eq!(tgt_change.change_type, MoveStateChangeType::Update);
-            let new_env: setu_types::ObjectEnvelope =
-                bcs::from_bytes(tgt_change.new_state.as_ref().expect("new_state"))
+            let new_env =
+                setu_types::ObjectEnvelope::from_bytes(tgt_change.new_state.as_ref().expect("new_state"))
                     .expect("envelope decode");
             let post_balance =
                 u64::from_le_bytes(new_env.data[32..40].try_into().expect("balance slice"));
@@ -110,6 +110,7 @@ pub use state_sync::{
     Handle as StateSyncHandle,
     LocalState,
     PeerSyncInfo,
+    PeerSyncObserver,
     PeerSyncState,
     StateSyncConfig,
     StateSyncEventLoop,
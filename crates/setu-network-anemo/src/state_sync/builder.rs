@@ -4,7 +4,7 @@
 //! Builder pattern for StateSync service
 
 use super::{
-    Handle, Server, StateSyncConfig, StateSyncEventLoop, StateSyncMessage, StateSyncStore, SyncState,
+    Handle, PeerSyncObserver, Server, StateSyncConfig, StateSyncEventLoop, StateSyncMessage, StateSyncStore, SyncState,
     metrics::StateSyncMetrics,
 };
 use anemo::NetworkRef;
@@ -33,6 +33,7 @@ pub struct Builder<S> {
     store: Option<Arc<S>>,
     config: Option<StateSyncConfig>,
     metrics: Option<StateSyncMetrics>,
+    observer: Option<Arc<dyn PeerSyncObserver>>,
 }
 
 impl Builder<()> {
@@ -42,6 +43,7 @@ impl Builder<()> {
             store: None,
             config: None,
             metrics: None,
+            observer: None,
         }
     }
 }
@@ -59,6 +61,7 @@ impl<S> Builder<S> {
             store: Some(store),
             config: self.config,
             metrics: self.metrics,
+            observer: self.observer,
         }
     }
 
@@ -73,6 +76,12 @@ impl<S> Builder<S> {
         self.metrics = Some(StateSyncMetrics::new(registry));
         self
     }
+
+    /// Report peer sync info to `observer` as it arrives
+    pub fn observer(mut self, observer: Arc<dyn PeerSyncObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
 }
 
 impl<S> Builder<S>
@@ -92,7 +101,10 @@ where
         
         let handle = Handle { sender: tx };
 
-        let state = Arc::new(SyncState::new());
+        let state = Arc::new(match self.observer {
+            Some(observer) => SyncState::with_observer(observer),
+            None => SyncState::new(),
+        });
 
         let server = Server {
             state: state.clone(),
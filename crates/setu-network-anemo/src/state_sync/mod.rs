@@ -227,13 +227,36 @@ impl<S: StateSyncStore> StateSyncStore for Arc<S> {
     }
 }
 
+/// Receives peer sync info as the sync layer learns it
+///
+/// Lets the application layer (e.g. the validator's catch-up tracking) follow
+/// peer heights without polling `PeerSyncState`.
+pub trait PeerSyncObserver: Send + Sync + 'static {
+    /// A peer reported new sync info
+    fn peer_updated(&self, peer_id: PeerId, info: &PeerSyncInfo);
+
+    /// A peer disconnected; its last report no longer applies
+    fn peer_removed(&self, peer_id: &PeerId);
+}
+
 /// Peer synchronization state tracking
 ///
 /// Similar to Sui's PeerHeights but tracks Event/CF heights instead of checkpoints
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct PeerSyncState {
     /// Map from peer ID to their sync info
     peers: DashMap<PeerId, PeerSyncInfo>,
+    /// Notified on every update/removal
+    observer: Option<Arc<dyn PeerSyncObserver>>,
+}
+
+impl std::fmt::Debug for PeerSyncState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PeerSyncState")
+            .field("peers", &self.peers)
+            .field("has_observer", &self.observer.is_some())
+            .finish()
+    }
 }
 
 impl PeerSyncState {
@@ -241,14 +264,28 @@ impl PeerSyncState {
         Self::default()
     }
 
+    /// Create a peer state that forwards every change to `observer`
+    pub fn with_observer(observer: Arc<dyn PeerSyncObserver>) -> Self {
+        Self {
+            peers: DashMap::new(),
+            observer: Some(observer),
+        }
+    }
+
     /// Update peer's sync info
     pub fn update_peer(&self, peer_id: PeerId, info: PeerSyncInfo) {
+        if let Some(observer) = &self.observer {
+            observer.peer_updated(peer_id, &info);
+        }
         self.peers.insert(peer_id, info);
     }
 
     /// Remove a peer
     pub fn remove_peer(&self, peer_id: &PeerId) {
         self.peers.remove(peer_id);
+        if let Some(observer) = &self.observer {
+            observer.peer_removed(peer_id);
+        }
     }
 
     /// Get peer's sync info
@@ -283,6 +320,43 @@ pub struct PeerSyncInfo {
     pub vlc_snapshot: Option<Vec<u8>>,
     /// Timestamp of last update (ms since epoch)
     pub last_update_ms: u64,
+    /// Depth of the newest finalized anchor this peer has.
+    ///
+    /// Trailing so that older peers, which stop decoding after
+    /// `last_update_ms`, still read the rest of the struct; see `from_bytes`
+    /// for the other direction.
+    #[serde(default)]
+    pub highest_anchor_depth: u64,
+}
+
+/// `PeerSyncInfo` as sent by peers that predate `highest_anchor_depth`
+#[derive(Deserialize)]
+struct LegacyPeerSyncInfo {
+    highest_event_seq: u64,
+    highest_synced_cf: u64,
+    highest_verified_cf: u64,
+    vlc_snapshot: Option<Vec<u8>>,
+    last_update_ms: u64,
+}
+
+impl PeerSyncInfo {
+    /// Decode bincode bytes from any peer version.
+    ///
+    /// bincode cannot default a missing trailing field, so bytes from a peer
+    /// without `highest_anchor_depth` are retried in the legacy layout.
+    pub fn from_bytes(bytes: &[u8]) -> bincode::Result<Self> {
+        bincode::deserialize(bytes).or_else(|err| {
+            let legacy: LegacyPeerSyncInfo = bincode::deserialize(bytes).map_err(|_| err)?;
+            Ok(Self {
+                highest_event_seq: legacy.highest_event_seq,
+                highest_synced_cf: legacy.highest_synced_cf,
+                highest_verified_cf: legacy.highest_verified_cf,
+                vlc_snapshot: legacy.vlc_snapshot,
+                last_update_ms: legacy.last_update_ms,
+                highest_anchor_depth: 0,
+            })
+        })
+    }
 }
 
 /// Configuration for state synchronization
@@ -332,6 +406,8 @@ pub struct LocalState {
     pub highest_verified_cf: u64,
     /// Current VLC state (serialized)
     pub current_vlc: Option<Vec<u8>>,
+    /// Depth of the newest anchor we have finalized
+    pub highest_anchor_depth: u64,
 }
 
 /// State for the sync system
@@ -346,9 +422,18 @@ pub struct SyncState {
 
 impl SyncState {
     pub fn new() -> Self {
+        Self::with_peer_heights(PeerSyncState::new())
+    }
+
+    /// Create sync state whose peer tracking reports to `observer`
+    pub fn with_observer(observer: Arc<dyn PeerSyncObserver>) -> Self {
+        Self::with_peer_heights(PeerSyncState::with_observer(observer))
+    }
+
+    fn with_peer_heights(peer_heights: PeerSyncState) -> Self {
         Self {
             local: RwLock::new(LocalState::default()),
-            peer_heights: Arc::new(PeerSyncState::new()),
+            peer_heights: Arc::new(peer_heights),
             pending_requests: DashMap::new(),
         }
     }
@@ -392,6 +477,11 @@ impl Handle {
         let _ = self.sender.try_send(StateSyncMessage::CFFinalized(cf_seq));
     }
 
+    /// Notify that an anchor at `depth` has been finalized
+    pub fn notify_anchor_finalized(&self, depth: u64) {
+        let _ = self.sender.try_send(StateSyncMessage::AnchorFinalized(depth));
+    }
+
     /// Request sync with a specific peer
     pub fn sync_with_peer(&self, peer_id: PeerId) {
         let _ = self.sender.try_send(StateSyncMessage::SyncWithPeer(peer_id));
@@ -402,6 +492,7 @@ impl Handle {
 pub enum StateSyncMessage {
     NewEvent(u64),
     CFFinalized(u64),
+    AnchorFinalized(u64),
     SyncWithPeer(PeerId),
     PeerConnected(PeerId),
     PeerDisconnected(PeerId),
@@ -514,6 +605,10 @@ where
                 let mut local = self.state.local.write().await;
                 local.highest_finalized_cf = local.highest_finalized_cf.max(seq);
             }
+            StateSyncMessage::AnchorFinalized(depth) => {
+                let mut local = self.state.local.write().await;
+                local.highest_anchor_depth = local.highest_anchor_depth.max(depth);
+            }
             StateSyncMessage::SyncWithPeer(peer_id) => {
                 tracing::debug!("Requested sync with peer {:?}", peer_id);
                 // TODO: Initiate sync
@@ -527,3 +622,69 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingObserver {
+        events: Mutex<Vec<(PeerId, Option<u64>)>>,
+    }
+
+    impl PeerSyncObserver for RecordingObserver {
+        fn peer_updated(&self, peer_id: PeerId, info: &PeerSyncInfo) {
+            self.events.lock().unwrap().push((peer_id, Some(info.highest_anchor_depth)));
+        }
+
+        fn peer_removed(&self, peer_id: &PeerId) {
+            self.events.lock().unwrap().push((*peer_id, None));
+        }
+    }
+
+    #[test]
+    fn test_peer_sync_info_decodes_legacy_bytes() {
+        #[derive(Serialize)]
+        struct Legacy {
+            highest_event_seq: u64,
+            highest_synced_cf: u64,
+            highest_verified_cf: u64,
+            vlc_snapshot: Option<Vec<u8>>,
+            last_update_ms: u64,
+        }
+        let old_bytes = bincode::serialize(&Legacy {
+            highest_event_seq: 10,
+            highest_synced_cf: 4,
+            highest_verified_cf: 5,
+            vlc_snapshot: Some(vec![1, 2]),
+            last_update_ms: 99,
+        })
+        .unwrap();
+
+        let decoded = PeerSyncInfo::from_bytes(&old_bytes).unwrap();
+        assert_eq!(decoded.highest_synced_cf, 4);
+        assert_eq!(decoded.last_update_ms, 99);
+        assert_eq!(decoded.highest_anchor_depth, 0);
+
+        // New bytes round-trip, and an old peer still reads the prefix
+        let info = PeerSyncInfo { highest_anchor_depth: 7, ..decoded };
+        let new_bytes = bincode::serialize(&info).unwrap();
+        assert_eq!(PeerSyncInfo::from_bytes(&new_bytes).unwrap().highest_anchor_depth, 7);
+        let seen_by_old: LegacyPeerSyncInfo = bincode::deserialize(&new_bytes).unwrap();
+        assert_eq!(seen_by_old.last_update_ms, 99);
+    }
+
+    #[test]
+    fn test_peer_sync_state_notifies_observer() {
+        let observer = Arc::new(RecordingObserver::default());
+        let state = PeerSyncState::with_observer(observer.clone());
+        let peer = PeerId([7; 32]);
+
+        state.update_peer(peer, PeerSyncInfo { highest_anchor_depth: 3, ..Default::default() });
+        state.remove_peer(&peer);
+
+        assert_eq!(*observer.events.lock().unwrap(), vec![(peer, Some(3)), (peer, None)]);
+        assert!(state.get_peer(&peer).is_none());
+    }
+}
@@ -317,6 +317,7 @@ where
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or_default()
                 .as_millis() as u64,
+            highest_anchor_depth: local.highest_anchor_depth,
         };
         
         Ok(Response::new(GetSyncStateResponse { sync_info }))
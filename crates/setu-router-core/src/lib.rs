@@ -29,6 +29,8 @@
 //! ## Solver Selection (strategy module)
 //! - **ConsistentHashStrategy**: Deterministic routing based on keys
 //! - **LoadBalancedStrategy**: Routes to least loaded solver
+//! - **RandomStrategy**: Uniform random routing; randomness is injected via
+//!   [`SelectionRng`] (seeded under the `deterministic-rng` feature)
 //!
 //! # Example
 //!
@@ -51,6 +53,7 @@ mod error;
 mod types;
 mod shard;
 mod solver;
+mod rng;
 
 // Strategy module (contains all routing strategies)
 mod strategy;
@@ -77,12 +80,17 @@ pub use shard::{ShardConfig, ShardRouter, SingleShardRouter};
 // Re-exports: Solver management  
 pub use solver::{SolverInfo, SolverId, SolverRegistry, SolverStatus};
 
+// Re-exports: Injectable randomness
+pub use rng::{SelectionRng, ThreadSelectionRng, default_selection_rng};
+#[cfg(any(test, feature = "deterministic-rng"))]
+pub use rng::SeededSelectionRng;
+
 // Re-exports: Strategy traits and implementations
 pub use strategy::{
     // Traits
     SolverStrategy, ShardStrategy,
     // Solver selection strategies
-    ConsistentHashStrategy, LoadBalancedStrategy,
+    ConsistentHashStrategy, LoadBalancedStrategy, RandomStrategy, RingPosition,
     // Shard selection strategies
     SubnetShardStrategy, SubnetShardRouter, ObjectShardStrategy,
     CrossSubnetRoutingDecision, ShardLoadMetrics,
@@ -92,6 +100,6 @@ pub use strategy::{
 pub use router::{Router, RouterConfig, RoutingDecision};
 pub use unified_router::{
     UnifiedRouter, UnifiedRoutingStrategy, RoutingContext,
-    ShardRoutingResult, DetailedRoutingResult,
+    ShardRoutingResult, DetailedRoutingResult, RoutingReason, SolverSelection,
 };
 
@@ -0,0 +1,76 @@
+//! Injectable randomness for solver selection
+//!
+//! Randomized strategies draw through [`SelectionRng`] rather than calling
+//! `rand::thread_rng()` directly, so a simulation can inject a seeded source
+//! and replay the exact same routing and fan-out decisions.
+//!
+//! - [`ThreadSelectionRng`]: production default, nondeterministic
+//! - `SeededSelectionRng`: reproducible for a given seed; requires the
+//!   `deterministic-rng` feature
+
+use std::sync::Arc;
+
+/// Source of random indices for selection strategies
+pub trait SelectionRng: Send + Sync {
+    /// Uniform index in `0..len`. `len` must be non-zero.
+    fn gen_index(&self, len: usize) -> usize;
+}
+
+/// Thread-local RNG, used wherever no RNG is injected
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ThreadSelectionRng;
+
+impl SelectionRng for ThreadSelectionRng {
+    fn gen_index(&self, len: usize) -> usize {
+        rand::Rng::gen_range(&mut rand::thread_rng(), 0..len)
+    }
+}
+
+/// Default RNG for strategies constructed without one
+pub fn default_selection_rng() -> Arc<dyn SelectionRng> {
+    Arc::new(ThreadSelectionRng)
+}
+
+/// Pick `n` distinct indices from `0..len` in draw order (partial
+/// Fisher-Yates). Returns `min(n, len)` indices.
+pub(crate) fn sample_indices(rng: &dyn SelectionRng, len: usize, n: usize) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..len).collect();
+    let target = n.min(len);
+    for i in 0..target {
+        let j = i + rng.gen_index(len - i);
+        indices.swap(i, j);
+    }
+    indices.truncate(target);
+    indices
+}
+
+#[cfg(any(test, feature = "deterministic-rng"))]
+pub use seeded::SeededSelectionRng;
+
+#[cfg(any(test, feature = "deterministic-rng"))]
+mod seeded {
+    use parking_lot::Mutex;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::SelectionRng;
+
+    /// Seeded RNG: the same seed and the same sequence of draws always yield
+    /// the same indices
+    #[derive(Debug)]
+    pub struct SeededSelectionRng {
+        rng: Mutex<StdRng>,
+    }
+
+    impl SeededSelectionRng {
+        pub fn new(seed: u64) -> Self {
+            Self { rng: Mutex::new(StdRng::seed_from_u64(seed)) }
+        }
+    }
+
+    impl SelectionRng for SeededSelectionRng {
+        fn gen_index(&self, len: usize) -> usize {
+            self.rng.lock().gen_range(0..len)
+        }
+    }
+}
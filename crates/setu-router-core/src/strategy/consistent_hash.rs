@@ -12,6 +12,17 @@ use crate::error::RouterError;
 use crate::solver::SolverInfo;
 use super::SolverStrategy;
 
+/// Where a routing key landed on the hash ring
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RingPosition {
+    /// Hash of the routing key
+    pub key_hash: u64,
+    /// Virtual node that owns the key (first node >= `key_hash`)
+    pub node_hash: u64,
+    /// Whether the lookup wrapped past the end of the ring
+    pub wrapped: bool,
+}
+
 /// Consistent hash routing strategy with cached hash ring
 pub struct ConsistentHashStrategy {
     /// Number of virtual nodes per solver for better distribution
@@ -90,8 +101,8 @@ impl ConsistentHashStrategy {
         ring
     }
 
-    /// Find solver index in the ring for a given hash
-    fn find_in_ring(ring: &BTreeMap<u64, usize>, hash: u64) -> Option<usize> {
+    /// Find the ring node owning a given hash, returning (node hash, solver index)
+    fn find_in_ring(ring: &BTreeMap<u64, usize>, hash: u64) -> Option<(u64, usize)> {
         if ring.is_empty() {
             return None;
         }
@@ -100,7 +111,64 @@ impl ConsistentHashStrategy {
         ring.range(hash..)
             .next()
             .or_else(|| ring.iter().next())
-            .map(|(_, &idx)| idx)
+            .map(|(&node, &idx)| (node, idx))
+    }
+
+    /// Select a solver and report where the key landed on the ring
+    ///
+    /// The position is `None` when the ring is skipped because only one
+    /// solver is available.
+    pub fn select_with_position(
+        &self,
+        available: &[SolverInfo],
+        routing_key: &str,
+    ) -> Result<(SolverInfo, Option<RingPosition>), RouterError> {
+        if available.is_empty() {
+            return Err(RouterError::NoSolverAvailable);
+        }
+
+        if available.len() == 1 {
+            return Ok((available[0].clone(), None));
+        }
+
+        let ring = self.get_or_build_ring(available);
+        let hash = Self::hash_key(routing_key);
+        
+        trace!(routing_key = %routing_key, hash = %hash, "Consistent hash lookup");
+
+        let (node_hash, idx) = Self::find_in_ring(&ring, hash)
+            .ok_or(RouterError::NoSolverAvailable)?;
+
+        let position = RingPosition {
+            key_hash: hash,
+            node_hash,
+            wrapped: node_hash < hash,
+        };
+        Ok((available[idx].clone(), Some(position)))
+    }
+
+    /// Walk the ring clockwise from the key's position, collecting the first
+    /// `n` distinct solvers
+    ///
+    /// Every validator with the same solver set derives the same ordered
+    /// subset, and the primary is always the solver `select` picks.
+    fn walk_ring(
+        ring: &BTreeMap<u64, usize>,
+        hash: u64,
+        n: usize,
+        solver_count: usize,
+    ) -> Vec<usize> {
+        let target = n.min(solver_count);
+        let mut picked = Vec::with_capacity(target);
+        for (_, &idx) in ring.range(hash..).chain(ring.range(..hash)) {
+            if picked.len() == target {
+                break;
+            }
+            if !picked.contains(&idx) {
+                picked.push(idx);
+            }
+        }
+        picked
     }
 }
 
@@ -112,23 +180,30 @@ impl Default for ConsistentHashStrategy {
 
 impl SolverStrategy for ConsistentHashStrategy {
     fn select(&self, available: &[SolverInfo], routing_key: &str) -> Result<SolverInfo, RouterError> {
+        self.select_with_position(available, routing_key)
+            .map(|(solver, _)| solver)
+    }
+
+    fn select_n(
+        &self,
+        available: &[SolverInfo],
+        routing_key: &str,
+        n: usize,
+    ) -> Result<Vec<SolverInfo>, RouterError> {
         if available.is_empty() {
             return Err(RouterError::NoSolverAvailable);
         }
 
         if available.len() == 1 {
-            return Ok(available[0].clone());
+            return Ok(if n == 0 { Vec::new() } else { vec![available[0].clone()] });
         }
 
         let ring = self.get_or_build_ring(available);
         let hash = Self::hash_key(routing_key);
-        
-        trace!(routing_key = %routing_key, hash = %hash, "Consistent hash lookup");
-
-        let idx = Self::find_in_ring(&ring, hash)
-            .ok_or(RouterError::NoSolverAvailable)?;
-
-        Ok(available[idx].clone())
+        Ok(Self::walk_ring(&ring, hash, n, available.len())
+            .into_iter()
+            .map(|idx| available[idx].clone())
+            .collect())
     }
 
     fn name(&self) -> &'static str {
@@ -179,6 +254,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_select_n_deterministic_and_balanced() {
+        let strategy = ConsistentHashStrategy::default();
+        let solvers = create_test_solvers(6);
+
+        let first = strategy.select_n(&solvers, "task:42", 3).unwrap();
+        let again = strategy.select_n(&solvers, "task:42", 3).unwrap();
+        let ids: Vec<_> = first.iter().map(|s| s.id.clone()).collect();
+        assert_eq!(ids, again.iter().map(|s| s.id.clone()).collect::<Vec<_>>());
+        assert_eq!(ids.len(), 3);
+        assert_eq!(ids.iter().collect::<std::collections::HashSet<_>>().len(), 3, "solvers must be distinct");
+        assert_eq!(ids[0], strategy.select(&solvers, "task:42").unwrap().id, "primary matches select()");
+
+        // Each solver should appear in roughly n/len = half of the fan-out sets
+        let mut membership = std::collections::HashMap::new();
+        for i in 0..1000 {
+            for solver in strategy.select_n(&solvers, &format!("task:{}", i), 3).unwrap() {
+                *membership.entry(solver.id).or_insert(0) += 1;
+            }
+        }
+        assert_eq!(membership.len(), 6);
+        for count in membership.values() {
+            assert!(*count > 300 && *count < 700, "count={} is outside expected range", count);
+        }
+    }
+
+    #[test]
+    fn test_select_n_clamps_to_available() {
+        let strategy = ConsistentHashStrategy::default();
+        let solvers = create_test_solvers(2);
+
+        assert_eq!(strategy.select_n(&solvers, "key", 5).unwrap().len(), 2);
+        assert!(strategy.select_n(&solvers, "key", 0).unwrap().is_empty());
+        assert!(matches!(strategy.select_n(&[], "key", 1), Err(RouterError::NoSolverAvailable)));
+    }
+
     #[test]
     fn test_empty_solvers() {
         let strategy = ConsistentHashStrategy::default();
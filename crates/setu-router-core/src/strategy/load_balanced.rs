@@ -2,10 +2,18 @@
 //!
 //! Selects the solver with the lowest current load.
 //! Falls back to weighted selection if all solvers are above threshold.
+//!
+//! Ties are broken by the lexicographically smallest solver id, so every
+//! validator picks the same solver regardless of registry iteration order.
+//! With [`LoadBalancedStrategy::with_rng`], ties among the best-scoring
+//! solvers are broken by the injected [`SelectionRng`] instead.
+
+use std::sync::Arc;
 
 use tracing::trace;
 
 use crate::error::RouterError;
+use crate::rng::SelectionRng;
 use crate::solver::SolverInfo;
 use super::SolverStrategy;
 
@@ -13,17 +21,53 @@ use super::SolverStrategy;
 pub struct LoadBalancedStrategy {
     /// Load threshold for weighted selection
     load_threshold: f64,
+    /// Random tie-break among equally scored candidates; `None` = lowest id
+    tie_break_rng: Option<Arc<dyn SelectionRng>>,
 }
 
 impl LoadBalancedStrategy {
     /// Create a new load balanced strategy
     pub fn new() -> Self {
-        Self { load_threshold: 0.9 }
+        Self { load_threshold: 0.9, tie_break_rng: None }
     }
 
     /// Create with custom load threshold
     pub fn with_threshold(threshold: f64) -> Self {
-        Self { load_threshold: threshold }
+        Self { load_threshold: threshold, tie_break_rng: None }
+    }
+
+    /// Break score ties with `rng` rather than by solver id
+    pub fn with_rng(mut self, rng: Arc<dyn SelectionRng>) -> Self {
+        self.tie_break_rng = Some(rng);
+        self
+    }
+
+    fn capacity_score(solver: &SolverInfo) -> f64 {
+        (1.0 - solver.load_ratio()) * solver.weight as f64
+    }
+
+    /// Re-pick among the candidates scoring the same as `best` (ordered by
+    /// id) when a tie-break RNG is injected
+    fn break_tie<'a>(
+        &self,
+        candidates: &[&'a SolverInfo],
+        best: &'a SolverInfo,
+        score: impl Fn(&SolverInfo) -> f64,
+    ) -> &'a SolverInfo {
+        let Some(rng) = &self.tie_break_rng else {
+            return best;
+        };
+        let best_score = score(best);
+        let mut tied: Vec<&SolverInfo> = candidates
+            .iter()
+            .copied()
+            .filter(|s| score(s).partial_cmp(&best_score) == Some(std::cmp::Ordering::Equal))
+            .collect();
+        if tied.len() <= 1 {
+            return best;
+        }
+        tied.sort_by(|a, b| a.id.cmp(&b.id));
+        tied[rng.gen_index(tied.len())]
     }
 }
 
@@ -47,28 +91,34 @@ impl SolverStrategy for LoadBalancedStrategy {
 
         if !candidates.is_empty() {
             // Select based on weighted capacity: (1 - load) * weight
-            let solver = candidates
-                .into_iter()
+            let best = candidates
+                .iter()
+                .copied()
                 .max_by(|a, b| {
-                    let score_a = (1.0 - a.load_ratio()) * a.weight as f64;
-                    let score_b = (1.0 - b.load_ratio()) * b.weight as f64;
-                    score_a.partial_cmp(&score_b).unwrap_or(std::cmp::Ordering::Equal)
+                    Self::capacity_score(a)
+                        .partial_cmp(&Self::capacity_score(b))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                        .then_with(|| b.id.cmp(&a.id))
                 })
                 .unwrap();
+            let solver = self.break_tie(&candidates, best, Self::capacity_score);
             
             trace!(solver_id = %solver.id, load = %solver.load_ratio(), "Selected by weighted capacity");
             return Ok(solver.clone());
         }
 
         // Fallback: select least loaded
-        let solver = available
+        let best = available
             .iter()
             .min_by(|a, b| {
                 a.load_ratio()
                     .partial_cmp(&b.load_ratio())
                     .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| a.id.cmp(&b.id))
             })
             .unwrap();
+        let all: Vec<&SolverInfo> = available.iter().collect();
+        let solver = self.break_tie(&all, best, SolverInfo::load_ratio);
         
         trace!(solver_id = %solver.id, load = %solver.load_ratio(), "Selected least loaded");
         Ok(solver.clone())
@@ -105,6 +155,57 @@ mod tests {
         assert_eq!(result.id, "solver-2");
     }
 
+    #[test]
+    fn test_equal_load_ties_broken_by_solver_id() {
+        let strategy = LoadBalancedStrategy::default();
+        let solvers = create_test_solvers(3);
+        for s in &solvers {
+            assert_eq!(s.load_ratio(), 0.0);
+        }
+
+        // Same key, any registration order, repeated calls: always solver-1
+        let mut reversed = solvers.clone();
+        reversed.reverse();
+        for order in [&solvers, &reversed] {
+            for _ in 0..10 {
+                assert_eq!(strategy.select(order, "task-1").unwrap().id, "solver-1");
+            }
+        }
+
+        // Fallback path (all above threshold) breaks ties the same way
+        let mut saturated = reversed;
+        for s in &mut saturated {
+            s.pending_load = s.max_capacity;
+        }
+        assert_eq!(strategy.select(&saturated, "task-1").unwrap().id, "solver-1");
+    }
+
+    #[test]
+    fn test_seeded_tie_break_is_reproducible() {
+        use crate::rng::SeededSelectionRng;
+
+        let solvers = create_test_solvers(4);
+        let picks = |seed: u64| -> Vec<String> {
+            let strategy = LoadBalancedStrategy::default()
+                .with_rng(Arc::new(SeededSelectionRng::new(seed)));
+            (0..50).map(|_| strategy.select(&solvers, "task-1").unwrap().id).collect()
+        };
+
+        let first = picks(7);
+        assert_eq!(first, picks(7));
+        // All four solvers tie at zero load, so the RNG spreads the picks
+        assert!(first.iter().collect::<std::collections::HashSet<_>>().len() > 1);
+
+        // A strictly better solver still wins regardless of the RNG
+        let mut loaded = solvers.clone();
+        for s in loaded.iter_mut().skip(1) {
+            s.pending_load = 50;
+        }
+        let strategy = LoadBalancedStrategy::default()
+            .with_rng(Arc::new(SeededSelectionRng::new(7)));
+        assert_eq!(strategy.select(&loaded, "task-1").unwrap().id, "solver-1");
+    }
+
     #[test]
     fn test_empty_solvers() {
         let strategy = LoadBalancedStrategy::default();
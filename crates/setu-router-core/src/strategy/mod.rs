@@ -4,6 +4,7 @@
 //!
 //! - `ConsistentHashStrategy`: Deterministic routing based on resource keys
 //! - `LoadBalancedStrategy`: Routes to least loaded solver
+//! - `RandomStrategy`: Uniform random routing through an injected RNG
 //! - `SubnetShardStrategy`: Routes subnets to shards
 //! - `ObjectShardStrategy`: Routes objects to shards
 //!
@@ -27,11 +28,13 @@
 
 mod consistent_hash;
 mod load_balanced;
+mod random;
 mod subnet_shard;
 mod object_shard;
 
-pub use consistent_hash::ConsistentHashStrategy;
+pub use consistent_hash::{ConsistentHashStrategy, RingPosition};
 pub use load_balanced::LoadBalancedStrategy;
+pub use random::RandomStrategy;
 pub use subnet_shard::{SubnetShardStrategy, SubnetShardRouter, CrossSubnetRoutingDecision, ShardLoadMetrics};
 pub use object_shard::ObjectShardStrategy;
 
@@ -42,6 +45,31 @@ use crate::solver::SolverInfo;
 pub trait SolverStrategy: Send + Sync {
     /// Select a solver from available solvers based on routing key
     fn select(&self, available: &[SolverInfo], routing_key: &str) -> Result<SolverInfo, RouterError>;
+
+    /// Select up to `n` distinct solvers for multi-solver fan-out
+    ///
+    /// The first entry is the solver [`select`](Self::select) would return.
+    /// Fewer than `n` are returned when fewer are available. The default
+    /// repeatedly applies `select` to the not-yet-chosen solvers.
+    fn select_n(
+        &self,
+        available: &[SolverInfo],
+        routing_key: &str,
+        n: usize,
+    ) -> Result<Vec<SolverInfo>, RouterError> {
+        if available.is_empty() {
+            return Err(RouterError::NoSolverAvailable);
+        }
+
+        let mut remaining = available.to_vec();
+        let mut selected = Vec::with_capacity(n.min(available.len()));
+        while selected.len() < n && !remaining.is_empty() {
+            let solver = self.select(&remaining, routing_key)?;
+            remaining.retain(|s| s.id != solver.id);
+            selected.push(solver);
+        }
+        Ok(selected)
+    }
     
     /// Strategy name for logging
     fn name(&self) -> &'static str;
@@ -0,0 +1,133 @@
+//! Random Strategy for Solver Selection
+//!
+//! Picks solvers uniformly at random through an injected [`SelectionRng`].
+//! Candidates are ordered by solver id before drawing, so with a seeded RNG
+//! the decisions depend only on the seed and the solver set, not on registry
+//! iteration order.
+
+use std::sync::Arc;
+
+use tracing::trace;
+
+use crate::error::RouterError;
+use crate::rng::{default_selection_rng, sample_indices, SelectionRng};
+use crate::solver::SolverInfo;
+use super::SolverStrategy;
+
+/// Uniform random routing strategy
+pub struct RandomStrategy {
+    rng: Arc<dyn SelectionRng>,
+}
+
+impl RandomStrategy {
+    /// Create a random strategy backed by the thread-local RNG
+    pub fn new() -> Self {
+        Self::with_rng(default_selection_rng())
+    }
+
+    /// Create with an injected RNG (e.g. a seeded one for simulations)
+    pub fn with_rng(rng: Arc<dyn SelectionRng>) -> Self {
+        Self { rng }
+    }
+
+    fn sorted_by_id(available: &[SolverInfo]) -> Vec<&SolverInfo> {
+        let mut sorted: Vec<_> = available.iter().collect();
+        sorted.sort_by(|a, b| a.id.cmp(&b.id));
+        sorted
+    }
+}
+
+impl Default for RandomStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SolverStrategy for RandomStrategy {
+    fn select(&self, available: &[SolverInfo], _routing_key: &str) -> Result<SolverInfo, RouterError> {
+        if available.is_empty() {
+            return Err(RouterError::NoSolverAvailable);
+        }
+
+        let sorted = Self::sorted_by_id(available);
+        let solver = sorted[self.rng.gen_index(sorted.len())];
+        trace!(solver_id = %solver.id, "Selected at random");
+        Ok(solver.clone())
+    }
+
+    fn select_n(
+        &self,
+        available: &[SolverInfo],
+        _routing_key: &str,
+        n: usize,
+    ) -> Result<Vec<SolverInfo>, RouterError> {
+        if available.is_empty() {
+            return Err(RouterError::NoSolverAvailable);
+        }
+
+        let sorted = Self::sorted_by_id(available);
+        Ok(sample_indices(self.rng.as_ref(), sorted.len(), n)
+            .into_iter()
+            .map(|idx| sorted[idx].clone())
+            .collect())
+    }
+
+    fn name(&self) -> &'static str {
+        "Random"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::SeededSelectionRng;
+
+    fn create_test_solvers(count: usize) -> Vec<SolverInfo> {
+        (1..=count)
+            .map(|i| SolverInfo::new(format!("solver-{}", i), format!("127.0.0.1:{}", 9000 + i)))
+            .collect()
+    }
+
+    /// Primary pick plus a 3-way fan-out for each of `rounds` keys
+    fn run_decisions(seed: u64, solvers: &[SolverInfo], rounds: usize) -> Vec<Vec<String>> {
+        let strategy = RandomStrategy::with_rng(Arc::new(SeededSelectionRng::new(seed)));
+        (0..rounds)
+            .map(|i| {
+                let key = format!("task:{}", i);
+                let mut ids = vec![strategy.select(solvers, &key).unwrap().id];
+                ids.extend(strategy.select_n(solvers, &key, 3).unwrap().into_iter().map(|s| s.id));
+                ids
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_same_seed_reproduces_routing_and_fan_out() {
+        let solvers = create_test_solvers(6);
+        let mut reversed = solvers.clone();
+        reversed.reverse();
+
+        let first = run_decisions(42, &solvers, 100);
+        assert_eq!(first, run_decisions(42, &solvers, 100));
+        // Registry order does not change seeded decisions
+        assert_eq!(first, run_decisions(42, &reversed, 100));
+
+        // The draws are not degenerate: every solver is picked at some point
+        let picked: std::collections::HashSet<_> = first.iter().map(|round| &round[0]).collect();
+        assert_eq!(picked.len(), 6);
+        for round in &first {
+            let fan_out: std::collections::HashSet<_> = round[1..].iter().collect();
+            assert_eq!(fan_out.len(), 3, "fan-out solvers must be distinct");
+        }
+    }
+
+    #[test]
+    fn test_select_n_clamps_to_available() {
+        let strategy = RandomStrategy::new();
+        let solvers = create_test_solvers(2);
+
+        assert_eq!(strategy.select_n(&solvers, "key", 5).unwrap().len(), 2);
+        assert!(strategy.select_n(&solvers, "key", 0).unwrap().is_empty());
+        assert!(matches!(strategy.select(&[], "key"), Err(RouterError::NoSolverAvailable)));
+    }
+}
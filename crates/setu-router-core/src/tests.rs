@@ -106,3 +106,29 @@ fn test_router_config() {
     assert_eq!(router.config().virtual_nodes, 200);
     assert!(!router.config().load_aware);
 }
+
+#[test]
+fn test_load_balanced_ties_route_identically_across_registries() {
+    let build_router = |order: &[usize]| {
+        let solvers = std::sync::Arc::new(crate::SolverRegistry::new());
+        for &i in order {
+            let id = format!("solver-{}", i);
+            solvers.register(crate::SolverInfo::new(id.clone(), format!("127.0.0.1:{}", 9000 + i)));
+            // Equal load above the router threshold forces LoadBalanced routing
+            solvers.update_load(&id, 8500);
+        }
+        Router::new(solvers)
+    };
+
+    let mut chosen = Vec::new();
+    for order in [[1, 2, 3], [3, 1, 2], [2, 3, 1]] {
+        let router = build_router(&order);
+        for _ in 0..5 {
+            let decision = router.route_by_key("task-42").unwrap();
+            assert_eq!(decision.strategy_name, "LoadBalanced");
+            chosen.push(decision.solver_id);
+        }
+    }
+
+    assert!(chosen.iter().all(|id| id == "solver-1"));
+}
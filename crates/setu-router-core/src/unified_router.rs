@@ -29,9 +29,17 @@
 //! - This ensures state locality and minimizes cross-shard coordination
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
+use parking_lot::RwLock;
+
+use crate::error::RouterError;
+use crate::solver::{SolverId, SolverInfo};
 use crate::types::{SubnetId, ObjectId, ShardId, RoutingMethod, ROOT_SUBNET, DEFAULT_SHARD_COUNT};
-use crate::strategy::{SubnetShardRouter, SubnetShardStrategy, ObjectShardStrategy};
+use crate::strategy::{
+    ConsistentHashStrategy, ObjectShardStrategy, RingPosition, ShardLoadMetrics, ShardStrategy,
+    SolverStrategy, SubnetShardRouter, SubnetShardStrategy,
+};
 
 /// Routing context for a transaction
 #[derive(Debug, Clone)]
@@ -113,37 +121,18 @@ impl Default for UnifiedRoutingStrategy {
     }
 }
 
-/// Unified router that handles both subnet and object routing
-pub struct UnifiedRouter {
+/// Strategy plus the routers derived from it.
+///
+/// Swapped as a whole by [`UnifiedRouter::set_strategy`], so a single routing
+/// decision never mixes components of two strategies.
+struct RoutingState {
     strategy: UnifiedRoutingStrategy,
     subnet_router: SubnetShardRouter,
     object_strategy: ObjectShardStrategy,
 }
 
-impl UnifiedRouter {
-    /// Create with default strategy (subnet-first)
-    pub fn new() -> Self {
-        Self {
-            strategy: UnifiedRoutingStrategy::default(),
-            subnet_router: SubnetShardRouter::new(DEFAULT_SHARD_COUNT),
-            object_strategy: ObjectShardStrategy::new(),
-        }
-    }
-    
-    /// Create with custom shard count
-    pub fn with_shard_count(shard_count: u16) -> Self {
-        Self {
-            strategy: UnifiedRoutingStrategy::SubnetFirst {
-                subnet_strategy: SubnetShardStrategy::HashBased { shard_count },
-                object_shard_count: shard_count,
-            },
-            subnet_router: SubnetShardRouter::new(shard_count),
-            object_strategy: ObjectShardStrategy::with_shard_count(shard_count),
-        }
-    }
-    
-    /// Create with custom strategy
-    pub fn with_strategy(strategy: UnifiedRoutingStrategy) -> Self {
+impl RoutingState {
+    fn from_strategy(strategy: UnifiedRoutingStrategy) -> Self {
         let (subnet_router, object_strategy) = match &strategy {
             UnifiedRoutingStrategy::SubnetFirst { subnet_strategy, object_shard_count } => {
                 (
@@ -168,45 +157,63 @@ impl UnifiedRouter {
         Self { strategy, subnet_router, object_strategy }
     }
     
-    /// Route a transaction to a shard
-    pub fn route(&self, ctx: &RoutingContext) -> ShardRoutingResult {
-        match &self.strategy {
-            UnifiedRoutingStrategy::SubnetFirst { .. } => {
+    fn route(&self, ctx: &RoutingContext) -> ShardRoutingResult {
+        let (primary_shard, routing_method, strategy, routing_key, is_cross_shard) = match &self.strategy {
+            UnifiedRoutingStrategy::SubnetFirst { subnet_strategy, .. } => {
                 if let Some(subnet_id) = &ctx.subnet_id {
                     // Has subnet - route by subnet
                     let shard = self.subnet_router.route(subnet_id);
-                    ShardRoutingResult {
-                        primary_shard: shard,
-                        routing_method: RoutingMethod::BySubnet,
-                        is_cross_shard: self.check_cross_shard_objects(ctx, shard),
-                    }
+                    let cross = self.check_cross_shard_objects(ctx, shard);
+                    (shard, RoutingMethod::BySubnet, subnet_strategy.name(), *subnet_id, cross)
                 } else {
                     // No subnet - route by primary object
                     let shard = self.object_strategy.route_object(&ctx.primary_object);
-                    ShardRoutingResult {
-                        primary_shard: shard,
-                        routing_method: RoutingMethod::ByObject,
-                        is_cross_shard: self.check_cross_shard_objects(ctx, shard),
-                    }
+                    let cross = self.check_cross_shard_objects(ctx, shard);
+                    (shard, RoutingMethod::ByObject, self.object_strategy.name(), ctx.primary_object, cross)
                 }
             }
             UnifiedRoutingStrategy::ObjectOnly { .. } => {
                 let shard = self.object_strategy.route_object(&ctx.primary_object);
-                ShardRoutingResult {
-                    primary_shard: shard,
-                    routing_method: RoutingMethod::ByObject,
-                    is_cross_shard: self.check_cross_shard_objects(ctx, shard),
-                }
+                let cross = self.check_cross_shard_objects(ctx, shard);
+                (shard, RoutingMethod::ByObject, self.object_strategy.name(), ctx.primary_object, cross)
             }
-            UnifiedRoutingStrategy::SubnetOnly { .. } => {
+            UnifiedRoutingStrategy::SubnetOnly { subnet_strategy } => {
                 let subnet_id = ctx.subnet_id.as_ref().unwrap_or(&ROOT_SUBNET);
                 let shard = self.subnet_router.route(subnet_id);
-                ShardRoutingResult {
-                    primary_shard: shard,
-                    routing_method: RoutingMethod::BySubnet,
-                    is_cross_shard: false, // Subnet-only doesn't check object shards
-                }
+                // Subnet-only doesn't check object shards
+                (shard, RoutingMethod::BySubnet, subnet_strategy.name(), *subnet_id, false)
             }
+        };
+        
+        ShardRoutingResult {
+            primary_shard,
+            routing_method,
+            is_cross_shard,
+            reason: self.explain(ctx, primary_shard, strategy, routing_key),
+        }
+    }
+    
+    /// Record the inputs behind a shard decision
+    fn explain(
+        &self,
+        ctx: &RoutingContext,
+        primary_shard: ShardId,
+        strategy: &'static str,
+        routing_key: [u8; 32],
+    ) -> RoutingReason {
+        let mut candidate_shards: Vec<ShardId> = ctx.touched_objects.iter()
+            .map(|obj| self.object_strategy.route_object(obj))
+            .collect();
+        candidate_shards.push(primary_shard);
+        candidate_shards.sort();
+        candidate_shards.dedup();
+        
+        RoutingReason {
+            strategy,
+            routing_key,
+            candidate_shards,
+            shard_load: self.subnet_router.get_load_metrics().remove(&primary_shard),
+            solver: None,
         }
     }
     
@@ -216,14 +223,68 @@ impl UnifiedRouter {
             self.object_strategy.route_object(obj) != primary_shard
         })
     }
+}
+
+/// Unified router that handles both subnet and object routing
+///
+/// The strategy can be replaced at runtime with [`set_strategy`](Self::set_strategy).
+/// Each routing call works on a snapshot taken when it starts, so a
+/// concurrent switch affects only later calls.
+pub struct UnifiedRouter {
+    state: RwLock<Arc<RoutingState>>,
+}
+
+impl UnifiedRouter {
+    /// Create with default strategy (subnet-first)
+    pub fn new() -> Self {
+        Self::with_strategy(UnifiedRoutingStrategy::default())
+    }
+    
+    /// Create with custom shard count
+    pub fn with_shard_count(shard_count: u16) -> Self {
+        Self::with_strategy(UnifiedRoutingStrategy::SubnetFirst {
+            subnet_strategy: SubnetShardStrategy::HashBased { shard_count },
+            object_shard_count: shard_count,
+        })
+    }
+    
+    /// Create with custom strategy
+    pub fn with_strategy(strategy: UnifiedRoutingStrategy) -> Self {
+        Self {
+            state: RwLock::new(Arc::new(RoutingState::from_strategy(strategy))),
+        }
+    }
+    
+    /// Replace the routing strategy (e.g. during a shard rebalance)
+    ///
+    /// Routing calls already in progress finish on the previous strategy.
+    pub fn set_strategy(&self, strategy: UnifiedRoutingStrategy) {
+        let state = Arc::new(RoutingState::from_strategy(strategy));
+        *self.state.write() = state;
+    }
+    
+    /// Current routing strategy
+    pub fn strategy(&self) -> UnifiedRoutingStrategy {
+        self.snapshot().strategy.clone()
+    }
+    
+    fn snapshot(&self) -> Arc<RoutingState> {
+        Arc::clone(&self.state.read())
+    }
+    
+    /// Route a transaction to a shard
+    pub fn route(&self, ctx: &RoutingContext) -> ShardRoutingResult {
+        self.snapshot().route(ctx)
+    }
     
     /// Get detailed routing for all objects in a transaction
     pub fn route_detailed(&self, ctx: &RoutingContext) -> DetailedRoutingResult {
-        let primary_result = self.route(ctx);
+        let state = self.snapshot();
+        let primary_result = state.route(ctx);
         
         // Map each object to its shard
         let object_shards: HashMap<ObjectId, ShardId> = ctx.touched_objects.iter()
-            .map(|obj| (*obj, self.object_strategy.route_object(obj)))
+            .map(|obj| (*obj, state.object_strategy.route_object(obj)))
             .collect();
         
         // Find all unique shards
@@ -240,8 +301,41 @@ impl UnifiedRouter {
             object_shards,
             all_shards,
             requires_coordination,
+            reason: primary_result.reason,
         }
     }
+    
+    /// Get detailed routing and pick a solver for the primary shard
+    ///
+    /// The solver choice, including where `routing_key` landed on the hash
+    /// ring, is recorded in `reason.solver`.
+    pub fn route_to_solver(
+        &self,
+        ctx: &RoutingContext,
+        routing_key: &str,
+        available: &[SolverInfo],
+        selector: &ConsistentHashStrategy,
+    ) -> Result<DetailedRoutingResult, RouterError> {
+        let mut result = self.route_detailed(ctx);
+        let (solver, ring_position) = selector.select_with_position(available, routing_key)?;
+        
+        result.reason.solver = Some(SolverSelection {
+            strategy: selector.name(),
+            routing_key: routing_key.to_string(),
+            ring_position,
+            candidates: available.iter().map(|s| s.id.clone()).collect(),
+            load_ratio: solver.load_ratio(),
+            chosen: solver.id,
+        });
+        Ok(result)
+    }
+    
+    /// Update load metrics for a shard (reported in [`RoutingReason::shard_load`])
+    ///
+    /// Metrics are kept per strategy and start empty after [`set_strategy`](Self::set_strategy).
+    pub fn record_tx(&self, shard: ShardId, latency_ms: f64) {
+        self.snapshot().subnet_router.record_tx(shard, latency_ms);
+    }
 }
 
 impl Default for UnifiedRouter {
@@ -261,6 +355,9 @@ pub struct ShardRoutingResult {
     
     /// Whether this transaction touches multiple shards
     pub is_cross_shard: bool,
+    
+    /// Why this shard was chosen
+    pub reason: RoutingReason,
 }
 
 /// Detailed routing result with per-object shard mapping
@@ -280,6 +377,50 @@ pub struct DetailedRoutingResult {
     
     /// Whether multi-shard coordination is needed
     pub requires_coordination: bool,
+    
+    /// Why this shard (and solver, if one was selected) was chosen
+    pub reason: RoutingReason,
+}
+
+/// Inputs behind a routing decision, for inspecting why a shard was chosen
+#[derive(Debug, Clone)]
+pub struct RoutingReason {
+    /// Name of the shard strategy that made the decision
+    pub strategy: &'static str,
+    
+    /// Key fed to that strategy (subnet ID or primary object ID)
+    pub routing_key: [u8; 32],
+    
+    /// Shards considered: the primary shard plus those of all touched objects
+    pub candidate_shards: Vec<ShardId>,
+    
+    /// Load of the primary shard at decision time, if any was recorded
+    pub shard_load: Option<ShardLoadMetrics>,
+    
+    /// Solver selection, set by [`UnifiedRouter::route_to_solver`]
+    pub solver: Option<SolverSelection>,
+}
+
+/// How a solver was picked within the primary shard
+#[derive(Debug, Clone)]
+pub struct SolverSelection {
+    /// Name of the solver strategy
+    pub strategy: &'static str,
+    
+    /// Key hashed onto the ring
+    pub routing_key: String,
+    
+    /// Ring lookup result (`None` when only one solver was available)
+    pub ring_position: Option<RingPosition>,
+    
+    /// Solvers that were available at decision time
+    pub candidates: Vec<SolverId>,
+    
+    /// Chosen solver
+    pub chosen: SolverId,
+    
+    /// Load ratio of the chosen solver at decision time
+    pub load_ratio: f64,
 }
 
 #[cfg(test)]
@@ -368,4 +509,73 @@ mod tests {
         
         assert_eq!(result.routing_method, RoutingMethod::ByObject);
     }
+    
+    #[test]
+    fn test_set_strategy_switches_routing() {
+        let subnet = make_subnet_id("defi-app");
+        let obj = make_object_id("coin-1");
+        let ctx = RoutingContext::with_subnet(subnet, obj);
+        
+        let mut mapping = HashMap::new();
+        mapping.insert(subnet, 7);
+        let router = UnifiedRouter::with_strategy(UnifiedRoutingStrategy::SubnetOnly {
+            subnet_strategy: SubnetShardStrategy::Dedicated { mapping, default_shard: 0 },
+        });
+        
+        let before = router.route(&ctx);
+        assert_eq!(before.routing_method, RoutingMethod::BySubnet);
+        assert_eq!(before.primary_shard, 7);
+        
+        router.set_strategy(UnifiedRoutingStrategy::ObjectOnly { shard_count: 4 });
+        assert!(matches!(router.strategy(), UnifiedRoutingStrategy::ObjectOnly { shard_count: 4 }));
+        
+        let after = router.route(&ctx);
+        assert_eq!(after.routing_method, RoutingMethod::ByObject);
+        assert_eq!(
+            after.primary_shard,
+            ObjectShardStrategy::with_shard_count(4).route_object(&obj)
+        );
+        assert_ne!(after.primary_shard, before.primary_shard);
+    }
+    
+    #[test]
+    fn test_detailed_result_explains_consistent_hash_decision() {
+        let router = UnifiedRouter::with_shard_count(16);
+        let obj = make_object_id("coin-1");
+        let ctx = RoutingContext::with_object(obj);
+        
+        let primary = router.route(&ctx).primary_shard;
+        router.record_tx(primary, 20.0);
+        
+        let solvers: Vec<SolverInfo> = (1..=6)
+            .map(|i| SolverInfo::new(format!("solver-{}", i), format!("127.0.0.1:{}", 9000 + i)))
+            .collect();
+        let selector = ConsistentHashStrategy::default();
+        let result = router
+            .route_to_solver(&ctx, "coin:alice", &solvers, &selector)
+            .unwrap();
+        
+        let reason = &result.reason;
+        assert_eq!(reason.strategy, "ObjectBased");
+        assert_eq!(reason.routing_key, obj);
+        assert_eq!(reason.candidate_shards, vec![primary]);
+        assert_eq!(reason.shard_load.as_ref().unwrap().tx_count, 1);
+        
+        let solver = reason.solver.as_ref().unwrap();
+        let expected = selector.select(&solvers, "coin:alice").unwrap();
+        assert_eq!(solver.strategy, "ConsistentHash");
+        assert_eq!(solver.chosen, expected.id);
+        assert_eq!(solver.candidates.len(), 6);
+        
+        // The key's ring position is recorded and owned by a virtual node of the chosen solver
+        let position = solver.ring_position.unwrap();
+        assert!(position.wrapped || position.node_hash >= position.key_hash);
+        let owner_nodes: Vec<u64> = (0..150)
+            .map(|vn| {
+                let hash = blake3::hash(format!("{}:{}", solver.chosen, vn).as_bytes());
+                u64::from_le_bytes(hash.as_bytes()[..8].try_into().unwrap())
+            })
+            .collect();
+        assert!(owner_nodes.contains(&position.node_hash));
+    }
 }
@@ -16,6 +16,21 @@ pub enum RuntimeError {
     #[error("Invalid ownership: object {object_id} is not owned by {address}")]
     InvalidOwnership { object_id: ObjectId, address: String },
     
+    #[error("Object {0} is immutable and cannot be transferred")]
+    ImmutableObjectTransfer(ObjectId),
+
+    #[error("Object {0} is shared and cannot be transferred")]
+    SharedObjectTransfer(ObjectId),
+
+    #[error("Object {object_id} is owned by object {parent} and must be transferred through it")]
+    ObjectOwnedTransfer { object_id: ObjectId, parent: ObjectId },
+    
+    #[error("Balance underflow: withdrawing {requested} from {available}")]
+    BalanceUnderflow { available: u64, requested: u64 },
+
+    #[error("Balance overflow: depositing {amount} into {balance}")]
+    BalanceOverflow { balance: u64, amount: u64 },
+    
     #[error("Invalid address: {0}")]
     InvalidAddress(String),
     
@@ -30,6 +45,12 @@ pub enum RuntimeError {
     
     #[error("Account frozen: {0}")]
     AccountFrozen(String),
+
+    #[error("Coin {0} is frozen and cannot be transferred")]
+    CoinFrozen(ObjectId),
+
+    #[error("{0} is not the coin freeze authority")]
+    NotFreezeAuthority(String),
     
     #[error("Unknown error: {0}")]
     Unknown(String),
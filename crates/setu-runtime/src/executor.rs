@@ -11,16 +11,35 @@
 use serde::{Deserialize, Serialize};
 use tracing::{info, debug, warn};
 use setu_types::{
-    ObjectId, Address, CoinType, CoinData, Object,
-    coin_id_from_tx, create_coin_with_id,
+    ObjectId, Address, Balance, CoinType, CoinData, CoinMetadata, Object, Ownership,
+    coin_id_from_tx, coin_metadata_object_id, create_coin_with_id,
     FluxState, PowerState,
     flux_state_object_id, power_state_object_id,
-    EventType,
+    EventType, FeeQuote, InteractionType, SubnetId, SubnetInteraction, UserSubnetActivity,
+    user_subnet_activity_object_id,
 };
 // Note: Coin::to_coin_state_bytes() is used via trait method on Object<CoinData>
 use crate::error::{RuntimeError, RuntimeResult};
-use crate::state::StateStore;
-use crate::transaction::{Transaction, TransactionType, TransferTx, QueryTx, QueryType};
+use crate::state::{StagedStateStore, StateStore};
+use crate::transaction::{Transaction, TransactionType, TransferTx, TransferAmount, MultiTransferTx, QueryTx, QueryType, CoinFreezeTx};
+
+pub use setu_types::ZeroBalanceCoinPolicy;
+
+/// How a partial transfer delivers funds to the recipient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecipientCoinPolicy {
+    /// Always create a new coin object for the recipient (default).
+    ///
+    /// Only the sender's coin is read, so this works with a minimal read set
+    /// (TEE path), at the cost of fragmenting frequent recipients' balances.
+    #[default]
+    Fragment,
+    /// Credit an existing recipient coin of the same type when there is one,
+    /// falling back to creating a new coin otherwise.
+    ///
+    /// Requires the recipient's coins to be visible in the state store.
+    Consolidate,
+}
 
 /// Execution context for a single transaction.
 ///
@@ -42,6 +61,16 @@ pub struct ExecutionContext {
     /// Gas budget (instruction count limit) for Move VM execution.
     /// None = use default (10M instructions). Ignored by native Transfer path.
     pub gas_budget: Option<u64>,
+    /// Recipient coin handling for partial transfers.
+    pub recipient_coin_policy: RecipientCoinPolicy,
+    /// Handling of sender coins drained to zero balance.
+    pub zero_balance_policy: ZeroBalanceCoinPolicy,
+    /// Fee withdrawn from the sender on top of the transfer amount, once per
+    /// transaction (the task's `GasBudget::estimated_fee`). 0 = no fee.
+    pub transfer_fee: u64,
+    /// Part of `transfer_fee` removed from supply; the rest goes to the
+    /// executor's fee collector.
+    pub fee_burn: u64,
 }
 
 impl ExecutionContext {
@@ -63,6 +92,10 @@ impl ExecutionContext {
             tx_hash,
             output_counter: std::cell::Cell::new(0),
             gas_budget: None,
+            recipient_coin_policy: RecipientCoinPolicy::default(),
+            zero_balance_policy: ZeroBalanceCoinPolicy::default(),
+            transfer_fee: 0,
+            fee_burn: 0,
         }
     }
 
@@ -72,6 +105,35 @@ impl ExecutionContext {
         self
     }
 
+    /// Set the fee charged on top of the transfer amount (builder pattern).
+    ///
+    /// The whole fee is credited to the fee collector; use
+    /// [`with_fee_quote`](Self::with_fee_quote) when part of it is burned.
+    pub fn with_transfer_fee(mut self, fee: u64) -> Self {
+        self.transfer_fee = fee;
+        self.fee_burn = 0;
+        self
+    }
+    
+    /// Set the transfer fee and its burned part from a quote (builder pattern).
+    pub fn with_fee_quote(mut self, quote: FeeQuote) -> Self {
+        self.transfer_fee = quote.fee;
+        self.fee_burn = quote.burn.min(quote.fee);
+        self
+    }
+
+    /// Set the recipient coin policy for partial transfers (builder pattern).
+    pub fn with_recipient_coin_policy(mut self, policy: RecipientCoinPolicy) -> Self {
+        self.recipient_coin_policy = policy;
+        self
+    }
+
+    /// Set the policy for coins drained to zero balance (builder pattern).
+    pub fn with_zero_balance_policy(mut self, policy: ZeroBalanceCoinPolicy) -> Self {
+        self.zero_balance_policy = policy;
+        self
+    }
+
     /// Get the next output index and increment counter.
     ///
     /// Panics if counter overflows u32 (> 4 billion coins per tx — impossible
@@ -214,16 +276,82 @@ pub fn penalize_flux(state: &mut FluxState, timestamp: u64, penalty: u64) -> Run
     })
 }
 
+/// Record `interaction` on `user`'s activity in `subnet_id`.
+///
+/// `existing` is the stored JSON `UserSubnetActivity`, if any. Returns a
+/// Create StateChange for a user's first interaction in the subnet and an
+/// Update afterwards.
+pub fn record_subnet_interaction(
+    existing: Option<&[u8]>,
+    user: Address,
+    subnet_id: SubnetId,
+    interaction: SubnetInteraction,
+) -> RuntimeResult<StateChange> {
+    let object_id = user_subnet_activity_object_id(&user.to_string(), &subnet_id);
+    let (mut activity, change_type) = match existing {
+        Some(bytes) => (
+            serde_json::from_slice::<UserSubnetActivity>(bytes)?,
+            StateChangeType::Update,
+        ),
+        None => (
+            UserSubnetActivity::new_at(user, subnet_id, interaction.timestamp),
+            StateChangeType::Create,
+        ),
+    };
+    activity.record_interaction(interaction);
+    Ok(StateChange {
+        change_type,
+        object_id,
+        old_state: existing.map(|b| b.to_vec()),
+        new_state: Some(serde_json::to_vec(&activity)?),
+    })
+}
+
+/// Withdraw `amount` from `balance`, failing with `BalanceUnderflow`.
+fn withdraw_balance(balance: &mut Balance, amount: u64) -> RuntimeResult<Balance> {
+    let available = balance.value();
+    let remaining = available.checked_sub(amount)
+        .ok_or(RuntimeError::BalanceUnderflow { available, requested: amount })?;
+    *balance = Balance::new(remaining);
+    Ok(Balance::new(amount))
+}
+
+/// Deposit `amount` into `balance`, failing with `BalanceOverflow`.
+fn deposit_balance(balance: &mut Balance, amount: u64) -> RuntimeResult<()> {
+    let current = balance.value();
+    let total = current.checked_add(amount)
+        .ok_or(RuntimeError::BalanceOverflow { balance: current, amount })?;
+    *balance = Balance::new(total);
+    Ok(())
+}
+
 /// Runtime executor
 pub struct RuntimeExecutor<S: StateStore> {
     /// State storage
     state: S,
+    /// Only address allowed to freeze/unfreeze coins (`None` = nobody)
+    freeze_authority: Option<Address>,
+    /// Receives the non-burned part of each transfer fee (`None` = no
+    /// collector, transfers with such a fee are rejected)
+    fee_collector: Option<Address>,
 }
 
 impl<S: StateStore> RuntimeExecutor<S> {
     /// 创建新的执行器
     pub fn new(state: S) -> Self {
-        Self { state }
+        Self { state, freeze_authority: None, fee_collector: None }
+    }
+    
+    /// Allow `authority` to issue `FreezeCoin` / `UnfreezeCoin` (builder pattern).
+    pub fn with_freeze_authority(mut self, authority: Address) -> Self {
+        self.freeze_authority = Some(authority);
+        self
+    }
+    
+    /// Credit the non-burned part of transfer fees to `collector` (builder pattern).
+    pub fn with_fee_collector(mut self, collector: Address) -> Self {
+        self.fee_collector = Some(collector);
+        self
     }
     
     /// 执行交易
@@ -245,9 +373,18 @@ impl<S: StateStore> RuntimeExecutor<S> {
             TransactionType::Transfer(transfer_tx) => {
                 self.execute_transfer(tx, transfer_tx, ctx)
             }
+            TransactionType::MultiTransfer(multi_tx) => {
+                self.execute_multi_transfer(tx, multi_tx, ctx)
+            }
             TransactionType::Query(query_tx) => {
                 self.execute_query(tx, query_tx, ctx)
             }
+            TransactionType::FreezeCoin(freeze_tx) => {
+                self.execute_set_coin_frozen(tx, freeze_tx, true)
+            }
+            TransactionType::UnfreezeCoin(freeze_tx) => {
+                self.execute_set_coin_frozen(tx, freeze_tx, false)
+            }
         };
         
         match &result {
@@ -271,12 +408,48 @@ impl<S: StateStore> RuntimeExecutor<S> {
         result
     }
     
+    /// Check that `sender` may transfer `coin`, per its `Ownership`
+    ///
+    /// - `AddressOwner`: only the owning address may transfer.
+    /// - `Immutable`: frozen objects can never change owner.
+    /// - `Shared`: any sender may mutate a shared object, but it has no
+    ///   single owner to hand over, so it cannot be transferred.
+    /// - `ObjectOwner`: the object moves with its parent (e.g. a dynamic
+    ///   field), never on its own.
+    fn authorize_transfer(coin: &Object<CoinData>, sender: &Address) -> RuntimeResult<()> {
+        let object_id = *coin.id();
+        match coin.metadata.ownership {
+            Ownership::AddressOwner(owner) if &owner == sender => Ok(()),
+            Ownership::AddressOwner(_) => Err(RuntimeError::InvalidOwnership {
+                object_id,
+                address: sender.to_string(),
+            }),
+            Ownership::Immutable => Err(RuntimeError::ImmutableObjectTransfer(object_id)),
+            Ownership::Shared { .. } => Err(RuntimeError::SharedObjectTransfer(object_id)),
+            Ownership::ObjectOwner(parent) => {
+                Err(RuntimeError::ObjectOwnedTransfer { object_id, parent })
+            }
+        }
+    }
+    
     /// 执行转账交易
     fn execute_transfer(
         &mut self,
         tx: &Transaction,
         transfer_tx: &TransferTx,
         ctx: &ExecutionContext,
+    ) -> RuntimeResult<ExecutionOutput> {
+        self.transfer_coin(tx, transfer_tx, ctx, true)
+    }
+    
+    /// Move funds out of one coin, withdrawing `ctx.transfer_fee` from the
+    /// same coin when `charge_fee` is set.
+    fn transfer_coin(
+        &mut self,
+        tx: &Transaction,
+        transfer_tx: &TransferTx,
+        ctx: &ExecutionContext,
+        charge_fee: bool,
     ) -> RuntimeResult<ExecutionOutput> {
         let coin_id = transfer_tx.coin_id;
         let recipient = &transfer_tx.recipient;
@@ -285,47 +458,75 @@ impl<S: StateStore> RuntimeExecutor<S> {
         let mut coin = self.state.get_object(&coin_id)?
             .ok_or(RuntimeError::ObjectNotFound(coin_id))?;
         
-        // 1.5. 确保 Coin 是 Owned 对象（防御性检查）
+        // 1.5. 按所有权类型验证转账权限
+        Self::authorize_transfer(&coin, &tx.sender)?;
+        if coin.metadata.frozen {
+            return Err(RuntimeError::CoinFrozen(coin_id));
+        }
+        
+        // 2. 确保 Coin 是 Owned 对象（防御性检查）
         if !coin.is_owned() {
             return Err(RuntimeError::InvalidTransaction(
                 format!("Coin {} is not an owned object — cannot transfer", coin_id)
             ));
         }
         
-        // 2. 验证所有权
-        let owner = coin.metadata.owner.as_ref()
-            .ok_or(RuntimeError::InvalidOwnership {
-                object_id: coin_id,
-                address: tx.sender.to_string(),
-            })?;
-        
-        if owner != &tx.sender {
-            return Err(RuntimeError::InvalidOwnership {
-                object_id: coin_id,
-                address: tx.sender.to_string(),
-            });
-        }
-        
         // 记录旧状态 (BCS format for Merkle tree compatibility)
         let old_state = coin.to_coin_state_bytes();
+        let coin_type_str = coin.data.coin_type.as_str().to_string();
         
         let mut state_changes = Vec::new();
         let mut created_objects = Vec::new();
-        let deleted_objects = Vec::new();
+        let mut deleted_objects = Vec::new();
         
         // 🔴 R13: 拒绝 amount == 0（防止创建 0 余额僵尸 Coin）
-        if let Some(0) = transfer_tx.amount {
+        if transfer_tx.amount == TransferAmount::Exact(0) {
             return Err(RuntimeError::InvalidTransaction(
                 "Transfer amount must be > 0".into()
             ));
         }
         
         // 判断是否全额转账:
-        // - None: 显式全额
-        // - Some(amount) where amount == balance: 隐式全额（避免 0 余额僵尸 Coin）
-        let is_full_transfer = match transfer_tx.amount {
-            None => true,
-            Some(amount) => amount == coin.data.balance.value(),
+        // - Full: 显式全额
+        // - Exact(amount) where amount == balance: 隐式全额（避免 0 余额僵尸 Coin）
+        // - AllBalance: 余额全部转入新 Coin，保留 sender 的 Coin 对象（余额为 0）
+        // 有手续费时 sender 的 Coin 必须留下 fee，不能整体转移所有权；Full 改为转出 balance - fee
+        let fee = if charge_fee { ctx.transfer_fee } else { 0 };
+        let balance = coin.data.balance.value();
+        let (is_full_transfer, split_amount) = match transfer_tx.amount {
+            TransferAmount::Full => (fee == 0, balance.saturating_sub(fee)),
+            TransferAmount::Exact(amount) => (fee == 0 && amount == balance, amount),
+            TransferAmount::AllBalance => {
+                if balance == 0 {
+                    return Err(RuntimeError::InvalidTransaction(
+                        format!("Coin {} has zero balance — nothing to transfer", coin_id)
+                    ));
+                }
+                (false, balance.saturating_sub(fee))
+            }
+        };
+        
+        // 手续费校验: balance 必须覆盖 amount + fee
+        let required = split_amount.saturating_add(fee);
+        if fee > 0 && (is_full_transfer || split_amount == 0 || required > balance) {
+            return Err(RuntimeError::InsufficientBalance {
+                address: tx.sender.to_string(),
+                required,
+                available: balance,
+            });
+        }
+        
+        // 手续费中未销毁的部分入账 fee collector，没有 collector 时拒绝
+        let collected = fee.saturating_sub(ctx.fee_burn);
+        let fee_collector = match (collected, &self.fee_collector) {
+            (0, _) => None,
+            (_, Some(collector)) => Some(*collector),
+            (_, None) => {
+                return Err(RuntimeError::InvalidTransaction(format!(
+                    "Transfer fee of {} has no fee collector to credit",
+                    collected
+                )));
+            }
         };
         
         if is_full_transfer {
@@ -349,40 +550,232 @@ impl<S: StateStore> RuntimeExecutor<S> {
                 new_state: Some(new_state),
             });
         } else {
-            // 部分转账 (amount < balance): always-create-new pattern
-            let amount = transfer_tx.amount.unwrap(); // safe: is_full_transfer=false ⟹ Some
-            let coin_type_str = coin.data.coin_type.as_str().to_string();
+            // 部分转账 (amount < balance) 或 AllBalance: 默认 always-create-new，见 RecipientCoinPolicy
+            let amount = split_amount;
             
             debug!(
                 coin_id = %coin_id,
                 from = %tx.sender,
                 to = %recipient,
                 amount = amount,
-                remaining = coin.data.balance.value() - amount,
+                fee = fee,
+                burned = fee - collected,
+                remaining = balance.saturating_sub(required),
                 "Partial transfer (always-create-new)"
             );
             
-            // 1. 扣减 sender 的 Coin
-            let _ = coin.data.balance.withdraw(amount)
-                .map_err(|e| RuntimeError::InvalidTransaction(e))?;
-            coin.increment_version();
-            let new_state = coin.to_coin_state_bytes();
-            self.state.set_object(coin_id, coin)?;
+            // 1. 扣减 sender 的 Coin（amount + fee）
+            withdraw_balance(&mut coin.data.balance, required)?;
+            if coin.data.balance.value() == 0
+                && ctx.zero_balance_policy == ZeroBalanceCoinPolicy::Tombstone
+            {
+                // Amount plus fee drained the coin: tombstone it instead of keeping an empty object
+                self.state.delete_object(&coin_id)?;
+                deleted_objects.push(coin_id);
+                state_changes.push(StateChange {
+                    change_type: StateChangeType::Delete,
+                    object_id: coin_id,
+                    old_state: Some(old_state),
+                    new_state: None,
+                });
+            } else {
+                coin.increment_version();
+                let new_state = coin.to_coin_state_bytes();
+                self.state.set_object(coin_id, coin)?;
+                
+                state_changes.push(StateChange {
+                    change_type: StateChangeType::Update,
+                    object_id: coin_id,
+                    old_state: Some(old_state),
+                    new_state: Some(new_state),
+                });
+            }
             
-            state_changes.push(StateChange {
+            // 2. 入账 recipient
+            self.credit_coin(
+                recipient, amount, &coin_type_str, &coin_id, ctx,
+                &mut state_changes, &mut created_objects,
+            )?;
+            
+            // 3. 入账 fee collector（burn 部分离开供应量）
+            if let Some(collector) = fee_collector {
+                self.credit_coin(
+                    &collector, collected, &coin_type_str, &coin_id, ctx,
+                    &mut state_changes, &mut created_objects,
+                )?;
+            }
+        }
+        
+        // 4. 记录用户在该 coin 所属 subnet 内的交互（ROOT coin 记在 ROOT subnet）
+        let subnet_id = SubnetId::from_coin_type(&coin_type_str);
+        state_changes.extend(
+            self.record_transfer_interactions(&tx.sender, recipient, subnet_id, ctx.timestamp)?
+        );
+        
+        Ok(ExecutionOutput {
+            success: true,
+            message: Some(format!(
+                "Transfer completed: {} -> {}",
+                tx.sender, recipient
+            )),
+            state_changes,
+            created_objects,
+            deleted_objects,
+            query_result: None,
+        })
+    }
+    
+    /// Execute a multi-transfer atomically.
+    ///
+    /// Items run in order against a [`StagedStateStore`], so later items see
+    /// the effects of earlier ones. The staged writes reach `self.state` only
+    /// after every item succeeded; the first failure is returned and nothing
+    /// is applied. The transfer fee is charged once per transaction, from the
+    /// first item's coin.
+    fn execute_multi_transfer(
+        &mut self,
+        tx: &Transaction,
+        multi_tx: &MultiTransferTx,
+        ctx: &ExecutionContext,
+    ) -> RuntimeResult<ExecutionOutput> {
+        if multi_tx.items.is_empty() {
+            return Err(RuntimeError::InvalidTransaction(
+                "MultiTransfer must contain at least one item".into()
+            ));
+        }
+        
+        let mut staged = RuntimeExecutor {
+            state: StagedStateStore::new(&self.state),
+            freeze_authority: self.freeze_authority,
+            fee_collector: self.fee_collector,
+        };
+        let mut state_changes = Vec::new();
+        let mut created_objects = Vec::new();
+        let mut deleted_objects = Vec::new();
+        
+        for (index, item) in multi_tx.items.iter().enumerate() {
+            let output = staged.transfer_coin(tx, item, ctx, index == 0).map_err(|e| {
+                debug!(tx_id = %tx.id, index, error = %e, "MultiTransfer item failed, rolling back");
+                e
+            })?;
+            state_changes.extend(output.state_changes);
+            created_objects.extend(output.created_objects);
+            deleted_objects.extend(output.deleted_objects);
+        }
+        
+        staged.state.commit().apply_to(&mut self.state)?;
+        
+        Ok(ExecutionOutput {
+            success: true,
+            message: Some(format!(
+                "MultiTransfer completed: {} transfers from {}",
+                multi_tx.items.len(), tx.sender
+            )),
+            state_changes,
+            created_objects,
+            deleted_objects,
+            query_result: None,
+        })
+    }
+    
+    /// Freeze (`frozen = true`) or unfreeze a coin.
+    ///
+    /// Only the configured freeze authority may do this. Ownership and
+    /// balance are untouched; the flag change is committed as a regular
+    /// coin `Update` with a bumped version.
+    fn execute_set_coin_frozen(
+        &mut self,
+        tx: &Transaction,
+        freeze_tx: &CoinFreezeTx,
+        frozen: bool,
+    ) -> RuntimeResult<ExecutionOutput> {
+        if self.freeze_authority.as_ref() != Some(&tx.sender) {
+            return Err(RuntimeError::NotFreezeAuthority(tx.sender.to_string()));
+        }
+        
+        let coin_id = freeze_tx.coin_id;
+        let mut coin = self.state.get_object(&coin_id)?
+            .ok_or(RuntimeError::ObjectNotFound(coin_id))?;
+        if coin.metadata.frozen == frozen {
+            return Err(RuntimeError::InvalidTransaction(format!(
+                "Coin {} is already {}",
+                coin_id,
+                if frozen { "frozen" } else { "unfrozen" }
+            )));
+        }
+        
+        let old_state = coin.to_coin_state_bytes();
+        coin.metadata.frozen = frozen;
+        coin.increment_version();
+        let new_state = coin.to_coin_state_bytes();
+        self.state.set_object(coin_id, coin)?;
+        
+        info!(coin_id = %coin_id, frozen, authority = %tx.sender, "Coin freeze state changed");
+        
+        Ok(ExecutionOutput {
+            success: true,
+            message: Some(format!(
+                "Coin {} {}",
+                coin_id,
+                if frozen { "frozen" } else { "unfrozen" }
+            )),
+            state_changes: vec![StateChange {
                 change_type: StateChangeType::Update,
                 object_id: coin_id,
                 old_state: Some(old_state),
                 new_state: Some(new_state),
-            });
+            }],
+            created_objects: vec![],
+            deleted_objects: vec![],
+            query_result: None,
+        })
+    }
+    
+    /// Credit `amount` of `coin_type` to `owner`.
+    ///
+    /// Consolidate mode merges into an existing coin of the owner when there
+    /// is one; otherwise a new coin with a tx-derived ID is created.
+    /// `exclude` is the coin being debited.
+    #[allow(clippy::too_many_arguments)]
+    fn credit_coin(
+        &mut self,
+        owner: &Address,
+        amount: u64,
+        coin_type: &str,
+        exclude: &ObjectId,
+        ctx: &ExecutionContext,
+        state_changes: &mut Vec<StateChange>,
+        created_objects: &mut Vec<ObjectId>,
+    ) -> RuntimeResult<()> {
+        let existing = match ctx.recipient_coin_policy {
+            RecipientCoinPolicy::Fragment => None,
+            RecipientCoinPolicy::Consolidate => {
+                self.find_recipient_coin(owner, coin_type, exclude)?
+            }
+        };
+        
+        if let Some(mut target) = existing {
+            let target_id = target.metadata.id;
+            let target_old_state = target.to_coin_state_bytes();
+            deposit_balance(&mut target.data.balance, amount)?;
+            target.increment_version();
+            let target_new_state = target.to_coin_state_bytes();
+            self.state.set_object(target_id, target)?;
             
-            // 2. 为 recipient 创建新 Coin（确定性 ID）
+            state_changes.push(StateChange {
+                change_type: StateChangeType::Update,
+                object_id: target_id,
+                old_state: Some(target_old_state),
+                new_state: Some(target_new_state),
+            });
+        } else {
+            // 创建新 Coin（确定性 ID）
             let new_coin_id = ctx.new_coin_id();
             let new_coin = create_coin_with_id(
                 new_coin_id,
-                recipient.clone(),
+                owner.clone(),
                 amount,
-                &coin_type_str,
+                coin_type,
                 ctx.timestamp,
             );
             let new_coin_state = new_coin.to_coin_state_bytes();
@@ -396,18 +789,65 @@ impl<S: StateStore> RuntimeExecutor<S> {
                 new_state: Some(new_coin_state),
             });
         }
+        Ok(())
+    }
+    
+    /// Find the recipient's coin of `coin_type` to credit in consolidate mode.
+    ///
+    /// Picks the smallest ObjectId so every executor selects the same coin
+    /// regardless of ownership-index ordering. `exclude` is the coin being
+    /// debited (relevant for self-transfers).
+    fn find_recipient_coin(
+        &self,
+        recipient: &Address,
+        coin_type: &str,
+        exclude: &ObjectId,
+    ) -> RuntimeResult<Option<Object<CoinData>>> {
+        let mut candidates = self.state.get_owned_objects(recipient)?;
+        candidates.sort();
+        for id in candidates.into_iter().filter(|id| id != exclude) {
+            if let Some(coin) = self.state.get_object(&id)? {
+                if coin.is_owned() && !coin.metadata.frozen && coin.data.coin_type.as_str() == coin_type {
+                    return Ok(Some(coin));
+                }
+            }
+        }
+        Ok(None)
+    }
+    
+    /// Record a committed transfer on both parties' `UserSubnetActivity`.
+    ///
+    /// The records are part of the transfer's write set, so they commit or
+    /// fail with it: a record that cannot be read, decoded or written fails
+    /// the whole transfer.
+    fn record_transfer_interactions(
+        &mut self,
+        sender: &Address,
+        recipient: &Address,
+        subnet_id: SubnetId,
+        timestamp: u64,
+    ) -> RuntimeResult<Vec<StateChange>> {
+        let mut parties = vec![(*sender, *recipient)];
+        if sender != recipient {
+            parties.push((*recipient, *sender));
+        }
         
-        Ok(ExecutionOutput {
-            success: true,
-            message: Some(format!(
-                "Transfer completed: {} -> {}",
-                tx.sender, recipient
-            )),
-            state_changes,
-            created_objects,
-            deleted_objects,
-            query_result: None,
-        })
+        let mut changes = Vec::with_capacity(parties.len());
+        for (user, with_user) in parties {
+            let object_id = user_subnet_activity_object_id(&user.to_string(), &subnet_id);
+            let existing = self.state.get_raw_object(&object_id)?;
+            let change = record_subnet_interaction(
+                existing.as_deref(),
+                user,
+                subnet_id,
+                SubnetInteraction::new_at(with_user, InteractionType::Transfer, timestamp),
+            )?;
+            if let Some(new_state) = &change.new_state {
+                self.state.set_raw_object(object_id, new_state.clone())?;
+            }
+            changes.push(change);
+        }
+        Ok(changes)
     }
     
     /// 执行查询交易（只读）
@@ -433,10 +873,9 @@ impl<S: StateStore> RuntimeExecutor<S> {
                 for obj_id in owned_objects {
                     if let Some(coin) = self.state.get_object(&obj_id)? {
                         let entry = total_balance.entry(coin.data.coin_type.clone()).or_insert(0);
-                        *entry = entry.checked_add(coin.data.balance.value())
-                            .ok_or_else(|| RuntimeError::InvalidTransaction(
-                                "Balance overflow in query".to_string()
-                            ))?;
+                        let amount = coin.data.balance.value();
+                        *entry = entry.checked_add(amount)
+                            .ok_or(RuntimeError::BalanceOverflow { balance: *entry, amount })?;
                     }
                 }
                 
@@ -574,19 +1013,23 @@ impl<S: StateStore> RuntimeExecutor<S> {
         let owned_objects = self.state.get_owned_objects(&sender)?;
         let coin_type_filter = coin_type.unwrap_or("ROOT");
         
-        // Collect coins of the matching type
+        // Collect spendable coins of the matching type; a frozen coin's
+        // balance can be neither transferred nor merged away
         let mut coins: Vec<(ObjectId, Object<CoinData>)> = Vec::new();
         for obj_id in &owned_objects {
             if let Some(coin) = self.state.get_object(obj_id)? {
-                if coin.data.coin_type.as_str() == coin_type_filter {
+                if coin.data.coin_type.as_str() == coin_type_filter && !coin.metadata.frozen {
                     coins.push((*obj_id, coin));
                 }
             }
         }
         
         let total_balance: u64 = coins.iter()
-            .try_fold(0u64, |acc, (_, c)| acc.checked_add(c.data.balance.value()))
-            .ok_or(RuntimeError::InvalidTransaction("Total balance overflow".into()))?;
+            .try_fold(0u64, |acc, (_, c)| {
+                let amount = c.data.balance.value();
+                acc.checked_add(amount)
+                    .ok_or(RuntimeError::BalanceOverflow { balance: acc, amount })
+            })?;
         
         if total_balance < amount {
             return Err(RuntimeError::InsufficientBalance {
@@ -639,12 +1082,18 @@ impl<S: StateStore> RuntimeExecutor<S> {
     // ========== Subnet & User Registration Handlers ==========
     
     /// Execute subnet registration - initializes subnet token if configured
+    ///
+    /// When a token symbol is given, a `CoinMetadata` entry (symbol, name,
+    /// decimals) is also written so clients can render balances for the
+    /// subnet's coin type.
+    #[allow(clippy::too_many_arguments)]
     pub fn execute_subnet_register(
         &mut self,
         subnet_id: &str,
         name: &str,
         owner: &Address,
         token_symbol: Option<&str>,
+        token_decimals: u8,
         initial_supply: Option<u64>,
         ctx: &ExecutionContext,
     ) -> RuntimeResult<ExecutionOutput> {
@@ -672,7 +1121,18 @@ impl<S: StateStore> RuntimeExecutor<S> {
             new_state: Some(serde_json::to_vec(&subnet_data)?),
         });
         
-        // 2. Mint initial token supply via mint_tokens (unified path)
+        // 2. Register coin type display metadata (BCS, lands in ROOT like subnet-meta)
+        if let Some(symbol) = token_symbol {
+            let metadata = CoinMetadata::new(subnet_id, symbol, name, token_decimals);
+            state_changes.push(StateChange {
+                change_type: StateChangeType::Create,
+                object_id: coin_metadata_object_id(subnet_id),
+                old_state: None,
+                new_state: Some(metadata.to_bytes()),
+            });
+        }
+        
+        // 3. Mint initial token supply via mint_tokens (unified path)
         if let Some(supply) = initial_supply {
             if supply > 0 {
                 let mint_output = self.mint_tokens(owner, subnet_id, supply, ctx)?;
@@ -983,7 +1443,8 @@ impl<S: StateStore> RuntimeExecutor<S> {
     /// Merge multiple coins into a target coin.
     ///
     /// All source coins must belong to the same owner and have the same coin_type
-    /// as the target. After merge, target balance += sum(source balances), sources deleted.
+    /// as the target, and none of them may be frozen. After merge, target
+    /// balance += sum(source balances), sources deleted.
     pub fn execute_merge_coins(
         &mut self,
         owner: &Address,
@@ -1039,6 +1500,9 @@ impl<S: StateStore> RuntimeExecutor<S> {
                 address: owner.to_string(),
             });
         }
+        if target.metadata.frozen {
+            return Err(RuntimeError::CoinFrozen(target_coin_id));
+        }
         
         let target_old_state = target.to_coin_state_bytes();
         let target_coin_type = target.data.coin_type.clone();
@@ -1058,6 +1522,9 @@ impl<S: StateStore> RuntimeExecutor<S> {
                     format!("Source coin {} not owned by {}", source_id, owner)
                 ));
             }
+            if source.metadata.frozen {
+                return Err(RuntimeError::CoinFrozen(source_id));
+            }
             
             if source.data.coin_type != target_coin_type {
                 return Err(RuntimeError::InvalidTransaction(
@@ -1067,8 +1534,7 @@ impl<S: StateStore> RuntimeExecutor<S> {
             }
             
             let source_old_state = source.to_coin_state_bytes();
-            target.data.balance.deposit(source.data.balance)
-                .map_err(|e| RuntimeError::InvalidTransaction(e))?;
+            deposit_balance(&mut target.data.balance, source.data.balance.value())?;
             
             self.state.delete_object(&source_id)?;
             deleted_objects.push(source_id);
@@ -1108,7 +1574,8 @@ impl<S: StateStore> RuntimeExecutor<S> {
     /// Split a coin into multiple new coins.
     ///
     /// If total_split == balance (exact split), the source coin is deleted.
-    /// Otherwise, the source coin's balance is reduced.
+    /// Otherwise, the source coin's balance is reduced. A frozen coin cannot
+    /// be split.
     pub fn execute_split_coin(
         &mut self,
         owner: &Address,
@@ -1152,11 +1619,16 @@ impl<S: StateStore> RuntimeExecutor<S> {
                 address: owner.to_string(),
             });
         }
+        if source.metadata.frozen {
+            return Err(RuntimeError::CoinFrozen(source_coin_id));
+        }
         
         let source_old_state = source.to_coin_state_bytes();
         let total_split: u64 = amounts.iter()
-            .try_fold(0u64, |acc, &a| acc.checked_add(a))
-            .ok_or(RuntimeError::InvalidTransaction("Split amounts overflow u64".into()))?;
+            .try_fold(0u64, |acc, &amount| {
+                acc.checked_add(amount)
+                    .ok_or(RuntimeError::BalanceOverflow { balance: acc, amount })
+            })?;
         
         if source.data.balance.value() < total_split {
             return Err(RuntimeError::InsufficientBalance {
@@ -1190,8 +1662,7 @@ impl<S: StateStore> RuntimeExecutor<S> {
                 new_state: Some(new_coin_state),
             });
             
-            let _ = source.data.balance.withdraw(amount)
-                .map_err(|e| RuntimeError::InvalidTransaction(e))?;
+            withdraw_balance(&mut source.data.balance, amount)?;
         }
         
         // 3. Handle source coin
@@ -1262,7 +1733,8 @@ mod tests {
         let output = executor.execute_transaction(&tx, &ctx).unwrap();
         
         assert!(output.success);
-        assert_eq!(output.state_changes.len(), 1);
+        // Coin update + both parties' activity records
+        assert_eq!(output.state_changes.len(), 3);
         
         let coin = executor.state().get_object(&coin_id).unwrap().unwrap();
         assert_eq!(coin.metadata.owner.unwrap(), recipient);
@@ -1287,8 +1759,8 @@ mod tests {
         assert!(output.success);
         // always-create-new: new coin created for recipient
         assert_eq!(output.created_objects.len(), 1);
-        // 2 state changes: sender Update + recipient Create
-        assert_eq!(output.state_changes.len(), 2);
+        // sender Update + recipient Create, then both parties' activity records
+        assert_eq!(output.state_changes.len(), 4);
         assert_eq!(output.state_changes[1].change_type, StateChangeType::Create);
         
         // Verify sender balance reduced
@@ -1302,31 +1774,80 @@ mod tests {
         assert_eq!(new_coin.metadata.owner.unwrap(), recipient);
     }
     
-    /// Balance conservation: sum of all balances must be unchanged after any transfer.
     #[test]
-    fn test_balance_conservation_full_transfer() {
+    fn test_transfer_state_changes_decode_with_storage_codec() {
+        use setu_types::envelope::{detect_and_parse, StorageFormat};
+
         let mut store = InMemoryStateStore::new();
         let sender = Address::from_str_id("alice");
         let recipient = Address::from_str_id("bob");
-        
+
         let coin = setu_types::create_coin(sender.clone(), 1000);
         let coin_id = *coin.id();
         store.set_object(coin_id, coin).unwrap();
+
+        let mut executor = RuntimeExecutor::new(store);
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(300));
+        let output = executor.execute_transaction(&tx, &test_ctx("storage-codec")).unwrap();
+        assert!(output.success);
+
+        // Payloads must be the BCS CoinState that storage parses, not JSON
+        let decode = |bytes: &[u8]| match detect_and_parse(bytes) {
+            StorageFormat::LegacyCoinState(state) => state,
+            _ => panic!("runtime coin payload is not a storage CoinState"),
+        };
+        let sender_change = &output.state_changes[0];
+        let old = decode(sender_change.old_state.as_ref().unwrap());
+        assert_eq!((old.owner.as_str(), old.balance), (sender.to_string().as_str(), 1000));
+        let new = decode(sender_change.new_state.as_ref().unwrap());
+        assert_eq!((new.owner.as_str(), new.balance), (sender.to_string().as_str(), 700));
+
+        let created = decode(output.state_changes[1].new_state.as_ref().unwrap());
+        assert_eq!(created.owner, recipient.to_string());
+        assert_eq!(created.balance, 300);
+        assert_eq!(created.coin_type, "ROOT");
+        assert_eq!(created.to_bytes(), output.state_changes[1].new_state.clone().unwrap());
+    }
+
+    #[test]
+    fn test_all_balance_transfer() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
         
-        let before_total: u64 = 1000;
+        let coin = setu_types::create_coin(sender.clone(), 1000);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
         
         let mut executor = RuntimeExecutor::new(store);
-        let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), None);
-        let ctx = test_ctx("conservation-full");
-        executor.execute_transaction(&tx, &ctx).unwrap();
+        let tx = Transaction::new_transfer_with_amount(
+            sender.clone(), coin_id, recipient.clone(), TransferAmount::AllBalance,
+        );
+        let ctx = test_ctx("all-balance-transfer");
         
-        let coin = executor.state().get_object(&coin_id).unwrap().unwrap();
-        let after_total = coin.data.balance.value();
-        assert_eq!(before_total, after_total, "Balance conservation violated in full transfer");
+        let output = executor.execute_transaction(&tx, &ctx).unwrap();
+        
+        assert!(output.success);
+        assert_eq!(output.created_objects.len(), 1);
+        assert!(output.deleted_objects.is_empty());
+        
+        // Source coin object is kept by the sender, with a zero balance
+        let source = executor.state().get_object(&coin_id).unwrap().unwrap();
+        assert_eq!(source.data.balance.value(), 0);
+        assert_eq!(source.metadata.owner.unwrap(), sender);
+        
+        // Recipient gets a fresh coin holding the full balance
+        let new_coin = executor.state().get_object(&output.created_objects[0]).unwrap().unwrap();
+        assert_eq!(new_coin.data.balance.value(), 1000);
+        assert_eq!(new_coin.metadata.owner.unwrap(), recipient);
+        
+        // Nothing left to move: a second AllBalance transfer is rejected
+        let ctx = test_ctx("all-balance-transfer-again");
+        assert!(executor.execute_transaction(&tx, &ctx).is_err());
     }
-    
+
     #[test]
-    fn test_balance_conservation_partial_transfer() {
+    fn test_all_balance_transfer_tombstones_drained_coin() {
         let mut store = InMemoryStateStore::new();
         let sender = Address::from_str_id("alice");
         let recipient = Address::from_str_id("bob");
@@ -1335,8 +1856,250 @@ mod tests {
         let coin_id = *coin.id();
         store.set_object(coin_id, coin).unwrap();
         
-        let before_total: u64 = 1000;
-        
+        let mut executor = RuntimeExecutor::new(store);
+        let tx = Transaction::new_transfer_with_amount(
+            sender.clone(), coin_id, recipient.clone(), TransferAmount::AllBalance,
+        );
+        let ctx = test_ctx("all-balance-tombstone")
+            .with_zero_balance_policy(ZeroBalanceCoinPolicy::Tombstone);
+        
+        let output = executor.execute_transaction(&tx, &ctx).unwrap();
+        
+        assert!(output.success);
+        assert_eq!(output.deleted_objects, vec![coin_id]);
+        let delete = &output.state_changes[0];
+        assert_eq!(delete.change_type, StateChangeType::Delete);
+        assert_eq!(delete.object_id, coin_id);
+        assert!(delete.new_state.is_none());
+        assert!(delete.to_event_state_change().new_value.is_none());
+        
+        // The drained coin is gone; only the recipient's new coin remains
+        assert!(executor.state().get_object(&coin_id).unwrap().is_none());
+        assert!(executor.state().get_owned_objects(&sender).unwrap().is_empty());
+        assert_eq!(executor.state().get_owned_objects(&recipient).unwrap(), output.created_objects);
+    }
+    
+    #[test]
+    fn test_subnet_transfers_record_activity() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        
+        let coin = setu_types::create_typed_coin(sender.clone(), 1000, "gaming-subnet");
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store);
+        for seed in ["subnet-transfer-1", "subnet-transfer-2"] {
+            let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(100));
+            let output = executor.execute_transaction(&tx, &test_ctx(seed)).unwrap();
+            assert!(output.success);
+        }
+        
+        let subnet_id = SubnetId::from_coin_type("gaming-subnet");
+        let read_activity = |user: &Address| -> UserSubnetActivity {
+            let oid = user_subnet_activity_object_id(&user.to_string(), &subnet_id);
+            let bytes = executor.state().get_raw_object(&oid).unwrap().unwrap();
+            serde_json::from_slice(&bytes).unwrap()
+        };
+        
+        let alice = read_activity(&sender);
+        assert_eq!(alice.interaction_count(&InteractionType::Transfer), 2);
+        let summary = alice.summary();
+        assert_eq!(summary.total_events, 2);
+        assert_eq!(summary.interaction_count, 1);
+        assert_eq!(summary.recent_interactions, vec![recipient.clone()]);
+        
+        // The recipient's side of the interaction is tracked too
+        assert_eq!(read_activity(&recipient).summary().total_events, 2);
+    }
+    
+    #[test]
+    fn test_consolidate_policy_credits_existing_recipient_coin() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        
+        let coin = setu_types::create_coin(sender.clone(), 1000);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store);
+        
+        // First transfer: recipient has no coin yet, so one is created
+        let ctx = test_ctx("consolidate-1")
+            .with_recipient_coin_policy(RecipientCoinPolicy::Consolidate);
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(300));
+        let output = executor.execute_transaction(&tx, &ctx).unwrap();
+        assert_eq!(output.created_objects.len(), 1);
+        let recipient_coin_id = output.created_objects[0];
+        
+        // Second transfer: credited to the existing coin instead of minting
+        let ctx = test_ctx("consolidate-2")
+            .with_recipient_coin_policy(RecipientCoinPolicy::Consolidate);
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(200));
+        let output = executor.execute_transaction(&tx, &ctx).unwrap();
+        assert!(output.created_objects.is_empty());
+        assert!(output.state_changes.iter().any(|c| {
+            c.object_id == recipient_coin_id && c.change_type == StateChangeType::Update
+        }));
+        
+        let owned = executor.state().get_owned_objects(&recipient).unwrap();
+        assert_eq!(owned, vec![recipient_coin_id]);
+        let recipient_coin = executor.state().get_object(&recipient_coin_id).unwrap().unwrap();
+        assert_eq!(recipient_coin.data.balance.value(), 500);
+        assert_eq!(recipient_coin.metadata.version, 2);
+        assert_eq!(executor.state().get_total_balance(&sender), 500);
+    }
+    
+    #[test]
+    fn test_consolidate_overflowing_recipient_balance_rejected() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        
+        let coin = setu_types::create_coin(sender.clone(), u64::MAX);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        let recipient_coin = setu_types::create_coin(recipient.clone(), u64::MAX - 10);
+        store.set_object(*recipient_coin.id(), recipient_coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store);
+        let ctx = test_ctx("consolidate-overflow")
+            .with_recipient_coin_policy(RecipientCoinPolicy::Consolidate);
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(100));
+        let result = executor.execute_transaction(&tx, &ctx);
+        assert!(matches!(
+            result,
+            Err(RuntimeError::BalanceOverflow { balance, amount: 100 }) if balance == u64::MAX - 10
+        ));
+    }
+    
+    #[test]
+    fn test_transfer_exceeding_coin_balance_underflows() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        
+        let coin = setu_types::create_coin(sender.clone(), 1000);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store);
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(1500));
+        let result = executor.execute_transaction(&tx, &test_ctx("underflow"));
+        assert!(matches!(
+            result,
+            Err(RuntimeError::BalanceUnderflow { available: 1000, requested: 1500 })
+        ));
+        assert_eq!(executor.state().get_total_balance(&sender), 1000);
+    }
+    
+    #[test]
+    fn test_fragment_policy_is_default() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        
+        let coin = setu_types::create_coin(sender.clone(), 1000);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store);
+        for seed in ["fragment-1", "fragment-2"] {
+            let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(100));
+            let output = executor.execute_transaction(&tx, &test_ctx(seed)).unwrap();
+            assert_eq!(output.created_objects.len(), 1);
+        }
+        
+        assert_eq!(executor.state().get_owned_objects(&recipient).unwrap().len(), 2);
+        assert_eq!(executor.state().get_total_balance(&recipient), 200);
+    }
+    
+    #[test]
+    fn test_transfers_record_activity_per_subnet() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        
+        let root_coin = setu_types::create_coin(sender.clone(), 1000);
+        let root_coin_id = *root_coin.id();
+        store.set_object(root_coin_id, root_coin).unwrap();
+        let gaming_coin = setu_types::create_typed_coin(sender.clone(), 1000, "gaming-subnet");
+        let gaming_coin_id = *gaming_coin.id();
+        store.set_object(gaming_coin_id, gaming_coin).unwrap();
+        
+        // alice pays bob in ROOT and in the gaming subnet's token
+        let mut executor = RuntimeExecutor::new(store);
+        for (seed, coin_id) in [("root-transfer", root_coin_id), ("gaming-transfer", gaming_coin_id)] {
+            let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(100));
+            let output = executor.execute_transaction(&tx, &test_ctx(seed)).unwrap();
+            // Both parties' records are emitted as state changes
+            let activity_changes = output.state_changes.iter()
+                .filter(|c| serde_json::from_slice::<UserSubnetActivity>(c.new_state.as_deref().unwrap_or_default()).is_ok())
+                .count();
+            assert_eq!(activity_changes, 2);
+        }
+        
+        let read_activity = |user: &Address, subnet_id: &SubnetId| -> Option<UserSubnetActivity> {
+            let oid = user_subnet_activity_object_id(&user.to_string(), subnet_id);
+            executor.state().get_raw_object(&oid).unwrap()
+                .map(|bytes| serde_json::from_slice(&bytes).unwrap())
+        };
+        
+        // Each subnet keeps its own record of the same pair of users
+        let gaming = SubnetId::from_coin_type("gaming-subnet");
+        for subnet_id in [SubnetId::ROOT, gaming] {
+            for user in [&sender, &recipient] {
+                let activity = read_activity(user, &subnet_id).unwrap();
+                assert_eq!(activity.subnet_id, subnet_id);
+                assert_eq!(activity.interaction_count(&InteractionType::Transfer), 1);
+            }
+        }
+        assert_ne!(
+            user_subnet_activity_object_id(&sender.to_string(), &SubnetId::ROOT),
+            user_subnet_activity_object_id(&sender.to_string(), &gaming),
+        );
+        
+        // A subnet neither user transacted in has no record
+        assert!(read_activity(&sender, &SubnetId::from_coin_type("other-subnet")).is_none());
+    }
+    
+    /// Balance conservation: sum of all balances must be unchanged after any transfer.
+    #[test]
+    fn test_balance_conservation_full_transfer() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        
+        let coin = setu_types::create_coin(sender.clone(), 1000);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let before_total: u64 = 1000;
+        
+        let mut executor = RuntimeExecutor::new(store);
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), None);
+        let ctx = test_ctx("conservation-full");
+        executor.execute_transaction(&tx, &ctx).unwrap();
+        
+        let coin = executor.state().get_object(&coin_id).unwrap().unwrap();
+        let after_total = coin.data.balance.value();
+        assert_eq!(before_total, after_total, "Balance conservation violated in full transfer");
+    }
+    
+    #[test]
+    fn test_balance_conservation_partial_transfer() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        
+        let coin = setu_types::create_coin(sender.clone(), 1000);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let before_total: u64 = 1000;
+        
         let mut executor = RuntimeExecutor::new(store);
         let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(300));
         let ctx = test_ctx("conservation-partial");
@@ -1395,6 +2158,197 @@ mod tests {
         assert!(result.is_err(), "Should reject transfer exceeding balance");
     }
     
+    #[test]
+    fn test_transfer_fee_withdrawn_from_sender() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        
+        let coin = setu_types::create_coin(sender.clone(), 1000);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store).with_fee_collector(Address::from_str_id("fees"));
+        let ctx = test_ctx("fee").with_transfer_fee(7);
+        
+        // amount + fee above the balance is rejected, nothing is written
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(995));
+        assert!(matches!(
+            executor.execute_transaction(&tx, &ctx),
+            Err(RuntimeError::InsufficientBalance { required: 1002, available: 1000, .. })
+        ));
+        // An exact-balance transfer can no longer move the whole coin
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(1000));
+        assert!(executor.execute_transaction(&tx, &ctx).is_err());
+        assert_eq!(executor.state().get_object(&coin_id).unwrap().unwrap().data.balance.value(), 1000);
+        
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(300));
+        let output = executor.execute_transaction(&tx, &ctx).unwrap();
+        assert!(output.success);
+        let sender_coin = executor.state().get_object(&coin_id).unwrap().unwrap();
+        assert_eq!(sender_coin.data.balance.value(), 1000 - 300 - 7);
+        let recipient_coin = executor.state().get_object(&output.created_objects[0]).unwrap().unwrap();
+        assert_eq!(recipient_coin.data.balance.value(), 300);
+    }
+    
+    #[test]
+    fn test_partial_transfer_draining_coin_with_fee_tombstones_it() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        
+        let coin = setu_types::create_coin(sender.clone(), 1000);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store).with_fee_collector(Address::from_str_id("fees"));
+        let ctx = test_ctx("fee-drain")
+            .with_transfer_fee(7)
+            .with_zero_balance_policy(ZeroBalanceCoinPolicy::Tombstone);
+        
+        // 993 + 7 empties the coin through the partial branch
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(993));
+        let output = executor.execute_transaction(&tx, &ctx).unwrap();
+        
+        assert!(output.success);
+        assert_eq!(output.deleted_objects, vec![coin_id]);
+        assert_eq!(output.state_changes[0].change_type, StateChangeType::Delete);
+        assert!(executor.state().get_object(&coin_id).unwrap().is_none());
+        assert!(executor.state().get_owned_objects(&sender).unwrap().is_empty());
+        let recipient_coin = executor.state().get_object(&output.created_objects[0]).unwrap().unwrap();
+        assert_eq!(recipient_coin.data.balance.value(), 993);
+    }
+    
+    #[test]
+    fn test_transfer_fee_credits_collector_and_burns_the_rest() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        let collector = Address::from_str_id("fees");
+        
+        let coin = setu_types::create_coin(sender.clone(), 1000);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store).with_fee_collector(collector);
+        let ctx = test_ctx("fee-collect").with_fee_quote(FeeQuote { fee: 10, burn: 4 });
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), Some(300));
+        let output = executor.execute_transaction(&tx, &ctx).unwrap();
+        
+        assert!(output.success);
+        let state = executor.state();
+        assert_eq!(state.get_total_balance(&sender), 690);
+        assert_eq!(state.get_total_balance(&recipient), 300);
+        // fee 10 = 6 to the collector + 4 removed from supply
+        assert_eq!(state.get_total_balance(&collector), 6);
+    }
+    
+    #[test]
+    fn test_transfer_fee_without_collector_rejected() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        
+        let coin = setu_types::create_coin(sender.clone(), 1000);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store);
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, Address::from_str_id("bob"), Some(300));
+        
+        let result = executor.execute_transaction(&tx, &test_ctx("fee-no-collector").with_transfer_fee(7));
+        assert!(matches!(result, Err(RuntimeError::InvalidTransaction(_))));
+        assert_eq!(executor.state().get_total_balance(&sender), 1000);
+        
+        // A fully burned fee needs no collector
+        let ctx = test_ctx("fee-all-burned").with_fee_quote(FeeQuote { fee: 7, burn: 7 });
+        executor.execute_transaction(&tx, &ctx).unwrap();
+        assert_eq!(executor.state().get_total_balance(&sender), 1000 - 300 - 7);
+    }
+    
+    #[test]
+    fn test_full_transfer_with_fee_sends_balance_minus_fee() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        let collector = Address::from_str_id("fees");
+        
+        let coin = setu_types::create_coin(sender.clone(), 1000);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store).with_fee_collector(collector);
+        let ctx = test_ctx("fee-full")
+            .with_transfer_fee(7)
+            .with_zero_balance_policy(ZeroBalanceCoinPolicy::Tombstone);
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, recipient.clone(), None);
+        let output = executor.execute_transaction(&tx, &ctx).unwrap();
+        
+        assert!(output.success);
+        assert_eq!(output.deleted_objects, vec![coin_id]);
+        let state = executor.state();
+        assert_eq!(state.get_total_balance(&sender), 0);
+        assert_eq!(state.get_total_balance(&recipient), 993);
+        assert_eq!(state.get_total_balance(&collector), 7);
+    }
+    
+    #[test]
+    fn test_full_transfer_not_covering_fee_rejected() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        
+        let coin = setu_types::create_coin(sender.clone(), 7);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store).with_fee_collector(Address::from_str_id("fees"));
+        let tx = Transaction::new_transfer(sender.clone(), coin_id, Address::from_str_id("bob"), None);
+        let result = executor.execute_transaction(&tx, &test_ctx("fee-full-short").with_transfer_fee(7));
+        assert!(matches!(result, Err(RuntimeError::InsufficientBalance { .. })));
+    }
+    
+    #[test]
+    fn test_frozen_coin_transfer_rejected_until_unfrozen() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        let authority = Address::from_str_id("compliance");
+        
+        let coin = setu_types::create_coin(sender.clone(), 1000);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store).with_freeze_authority(authority);
+        let ctx = test_ctx("freeze");
+        
+        // Only the authority may freeze
+        let tx = Transaction::new_coin_freeze_deterministic(sender, coin_id, true, ctx.timestamp);
+        assert!(matches!(
+            executor.execute_transaction(&tx, &ctx),
+            Err(RuntimeError::NotFreezeAuthority(_))
+        ));
+        
+        let tx = Transaction::new_coin_freeze_deterministic(authority, coin_id, true, ctx.timestamp);
+        let output = executor.execute_transaction(&tx, &ctx).unwrap();
+        let committed = setu_types::CoinState::from_bytes(
+            output.state_changes[0].new_state.as_ref().unwrap(),
+        )
+        .unwrap();
+        assert!(committed.frozen);
+        assert_eq!(committed.balance, 1000);
+        
+        let transfer = Transaction::new_transfer(sender, coin_id, recipient, Some(100));
+        assert!(matches!(
+            executor.execute_transaction(&transfer, &ctx),
+            Err(RuntimeError::CoinFrozen(id)) if id == coin_id
+        ));
+        
+        let tx = Transaction::new_coin_freeze_deterministic(authority, coin_id, false, ctx.timestamp);
+        executor.execute_transaction(&tx, &ctx).unwrap();
+        let output = executor.execute_transaction(&transfer, &ctx).unwrap();
+        assert!(output.success);
+        assert_eq!(executor.state().get_total_balance(&recipient), 100);
+    }
+    
     #[test]
     fn test_transfer_amount_zero_rejected() {
         let mut store = InMemoryStateStore::new();
@@ -1413,6 +2367,159 @@ mod tests {
         assert!(result.is_err(), "Should reject transfer with amount == 0");
     }
     
+    #[test]
+    fn test_address_owned_transfer_rejected_for_non_owner() {
+        let mut store = InMemoryStateStore::new();
+        let owner = Address::from_str_id("alice");
+        let mallory = Address::from_str_id("mallory");
+        
+        let coin = setu_types::create_coin(owner.clone(), 1000);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store);
+        let tx = Transaction::new_transfer(mallory.clone(), coin_id, mallory.clone(), None);
+        let result = executor.execute_transaction(&tx, &test_ctx("non-owner"));
+        assert!(matches!(result, Err(RuntimeError::InvalidOwnership { .. })));
+        
+        // The owner can still move it
+        let tx = Transaction::new_transfer(owner.clone(), coin_id, mallory.clone(), None);
+        let output = executor.execute_transaction(&tx, &test_ctx("owner")).unwrap();
+        assert!(output.success);
+        let coin = executor.state().get_object(&coin_id).unwrap().unwrap();
+        assert_eq!(coin.metadata.owner.unwrap(), mallory);
+    }
+    
+    #[test]
+    fn test_immutable_and_shared_transfers_rejected() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        
+        let data = setu_types::create_coin(sender.clone(), 1000).data;
+        let frozen = Object::new_immutable(ObjectId::new([1; 32]), data.clone());
+        let shared = Object::new_shared(ObjectId::new([2; 32]), data, 1);
+        let (frozen_id, shared_id) = (*frozen.id(), *shared.id());
+        store.set_object(frozen_id, frozen).unwrap();
+        store.set_object(shared_id, shared).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store);
+        let tx = Transaction::new_transfer(sender.clone(), frozen_id, recipient.clone(), None);
+        let result = executor.execute_transaction(&tx, &test_ctx("immutable"));
+        assert!(matches!(result, Err(RuntimeError::ImmutableObjectTransfer(id)) if id == frozen_id));
+        
+        let tx = Transaction::new_transfer(sender.clone(), shared_id, recipient.clone(), None);
+        let result = executor.execute_transaction(&tx, &test_ctx("shared"));
+        assert!(matches!(result, Err(RuntimeError::SharedObjectTransfer(id)) if id == shared_id));
+    }
+    
+    #[test]
+    fn test_multi_transfer_applies_all_items() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let bob = Address::from_str_id("bob");
+        let carol = Address::from_str_id("carol");
+        
+        let coin_a = setu_types::create_coin(sender.clone(), 1000);
+        let coin_b = setu_types::create_coin(sender.clone(), 500);
+        let (a_id, b_id) = (*coin_a.id(), *coin_b.id());
+        store.set_object(a_id, coin_a).unwrap();
+        store.set_object(b_id, coin_b).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store);
+        let tx = Transaction::new_multi_transfer_deterministic(sender.clone(), vec![
+            TransferTx { coin_id: a_id, recipient: bob.clone(), amount: TransferAmount::Exact(300) },
+            TransferTx { coin_id: a_id, recipient: carol.clone(), amount: TransferAmount::Exact(200) },
+            TransferTx { coin_id: b_id, recipient: carol.clone(), amount: TransferAmount::Full },
+        ], 1);
+        assert_eq!(tx.input_objects, vec![a_id, b_id]);
+        
+        let output = executor.execute_transaction(&tx, &test_ctx("multi")).unwrap();
+        assert!(output.success);
+        assert_eq!(output.created_objects.len(), 2);
+        assert_ne!(output.created_objects[0], output.created_objects[1]);
+        
+        let state = executor.state();
+        assert_eq!(state.get_object(&a_id).unwrap().unwrap().data.balance.value(), 500);
+        assert_eq!(state.get_total_balance(&bob), 300);
+        assert_eq!(state.get_total_balance(&carol), 700);
+        assert_eq!(state.get_total_balance(&sender), 500);
+    }
+    
+    #[test]
+    fn test_multi_transfer_rolls_back_on_failed_item() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let bob = Address::from_str_id("bob");
+        let carol = Address::from_str_id("carol");
+        
+        let coin_a = setu_types::create_coin(sender.clone(), 1000);
+        let coin_b = setu_types::create_coin(sender.clone(), 500);
+        let foreign = setu_types::create_coin(carol.clone(), 800);
+        let (a_id, b_id, foreign_id) = (*coin_a.id(), *coin_b.id(), *foreign.id());
+        store.set_object(a_id, coin_a).unwrap();
+        store.set_object(b_id, coin_b).unwrap();
+        store.set_object(foreign_id, foreign).unwrap();
+        let before = store.snapshot().unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store);
+        // The last item spends carol's coin, which alice does not own
+        let tx = Transaction::new_multi_transfer_deterministic(sender.clone(), vec![
+            TransferTx { coin_id: a_id, recipient: bob.clone(), amount: TransferAmount::Exact(300) },
+            TransferTx { coin_id: b_id, recipient: bob.clone(), amount: TransferAmount::Full },
+            TransferTx { coin_id: foreign_id, recipient: bob.clone(), amount: TransferAmount::Full },
+        ], 1);
+        
+        let result = executor.execute_transaction(&tx, &test_ctx("multi-rollback"));
+        assert!(matches!(result, Err(RuntimeError::InvalidOwnership { object_id, .. }) if object_id == foreign_id));
+        
+        let state = executor.state();
+        assert_eq!(state.snapshot().unwrap(), before);
+        assert_eq!(state.get_total_balance(&bob), 0);
+        assert_eq!(state.get_total_balance(&sender), 1500);
+    }
+    
+    #[test]
+    fn test_multi_transfer_charges_fee_once() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let bob = Address::from_str_id("bob");
+        let carol = Address::from_str_id("carol");
+        let collector = Address::from_str_id("fees");
+        
+        let coin_a = setu_types::create_coin(sender.clone(), 1000);
+        let coin_b = setu_types::create_coin(sender.clone(), 500);
+        let (a_id, b_id) = (*coin_a.id(), *coin_b.id());
+        store.set_object(a_id, coin_a).unwrap();
+        store.set_object(b_id, coin_b).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store).with_fee_collector(collector);
+        let tx = Transaction::new_multi_transfer_deterministic(sender.clone(), vec![
+            TransferTx { coin_id: a_id, recipient: bob.clone(), amount: TransferAmount::Exact(300) },
+            TransferTx { coin_id: a_id, recipient: carol.clone(), amount: TransferAmount::Exact(200) },
+            TransferTx { coin_id: b_id, recipient: carol.clone(), amount: TransferAmount::Full },
+        ], 1);
+        
+        let ctx = test_ctx("multi-fee").with_transfer_fee(10);
+        executor.execute_transaction(&tx, &ctx).unwrap();
+        
+        let state = executor.state();
+        // Fee withdrawn from the first item's coin only; coin B still moves whole
+        assert_eq!(state.get_object(&a_id).unwrap().unwrap().data.balance.value(), 490);
+        assert_eq!(state.get_object(&b_id).unwrap().unwrap().metadata.owner, Some(carol));
+        assert_eq!(state.get_total_balance(&bob), 300);
+        assert_eq!(state.get_total_balance(&carol), 700);
+        assert_eq!(state.get_total_balance(&collector), 10);
+    }
+    
+    #[test]
+    fn test_multi_transfer_without_items_rejected() {
+        let mut executor = RuntimeExecutor::new(InMemoryStateStore::new());
+        let tx = Transaction::new_multi_transfer_deterministic(Address::from_str_id("alice"), vec![], 1);
+        let result = executor.execute_transaction(&tx, &test_ctx("multi-empty"));
+        assert!(matches!(result, Err(RuntimeError::InvalidTransaction(_))));
+    }
+    
     #[test]
     fn test_merge_coins() {
         let mut store = InMemoryStateStore::new();
@@ -1464,6 +2571,93 @@ mod tests {
         assert!(result.is_err(), "Target cannot be in sources");
     }
     
+    #[test]
+    fn test_merge_rejects_frozen_coins() {
+        let mut store = InMemoryStateStore::new();
+        let owner = Address::from_str_id("alice");
+        
+        let target = setu_types::create_coin(owner.clone(), 500);
+        let target_id = *target.id();
+        store.set_object(target_id, target).unwrap();
+        
+        let mut frozen = setu_types::create_coin(owner.clone(), 300);
+        frozen.metadata.frozen = true;
+        let frozen_id = *frozen.id();
+        store.set_object(frozen_id, frozen).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store);
+        let ctx = test_ctx("merge-frozen");
+        
+        // Frozen source into an unfrozen target
+        let result = executor.execute_merge_coins(&owner, target_id, &[frozen_id], &ctx);
+        assert!(matches!(result, Err(RuntimeError::CoinFrozen(id)) if id == frozen_id));
+        
+        // Unfrozen source into a frozen target
+        let result = executor.execute_merge_coins(&owner, frozen_id, &[target_id], &ctx);
+        assert!(matches!(result, Err(RuntimeError::CoinFrozen(id)) if id == frozen_id));
+        
+        assert_eq!(executor.state().get_object(&target_id).unwrap().unwrap().data.balance.value(), 500);
+        assert_eq!(executor.state().get_object(&frozen_id).unwrap().unwrap().data.balance.value(), 300);
+    }
+    
+    #[test]
+    fn test_split_rejects_frozen_coin() {
+        let mut store = InMemoryStateStore::new();
+        let owner = Address::from_str_id("alice");
+        
+        let mut coin = setu_types::create_coin(owner.clone(), 1000);
+        coin.metadata.frozen = true;
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store);
+        let ctx = test_ctx("split-frozen");
+        
+        let result = executor.execute_split_coin(&owner, coin_id, &[200, 300], &ctx);
+        assert!(matches!(result, Err(RuntimeError::CoinFrozen(id)) if id == coin_id));
+        assert_eq!(executor.state().get_object(&coin_id).unwrap().unwrap().data.balance.value(), 1000);
+        assert_eq!(executor.state().get_owned_objects(&owner).unwrap(), vec![coin_id]);
+    }
+    
+    #[test]
+    fn test_simple_transfer_does_not_auto_merge_frozen_coin() {
+        let mut store = InMemoryStateStore::new();
+        let sender = Address::from_str_id("alice");
+        let recipient = Address::from_str_id("bob");
+        
+        let spendable = setu_types::create_coin(sender.clone(), 400);
+        let spendable_id = *spendable.id();
+        store.set_object(spendable_id, spendable).unwrap();
+        
+        let mut frozen = setu_types::create_coin(sender.clone(), 600);
+        frozen.metadata.frozen = true;
+        let frozen_id = *frozen.id();
+        store.set_object(frozen_id, frozen).unwrap();
+        
+        let mut executor = RuntimeExecutor::new(store);
+        let ctx = test_ctx("auto-merge-frozen");
+        
+        // Reaching 700 would need the frozen coin merged in
+        let result = executor.execute_simple_transfer(
+            &sender.to_string(), &recipient.to_string(), 700, &ctx, None,
+        );
+        assert!(matches!(
+            result,
+            Err(RuntimeError::InsufficientBalance { required: 700, available: 400, .. })
+        ));
+        
+        // The unfrozen balance still moves, and the frozen coin is untouched
+        let output = executor.execute_simple_transfer(
+            &sender.to_string(), &recipient.to_string(), 300, &ctx, None,
+        ).unwrap();
+        assert!(output.success);
+        assert!(!output.state_changes.iter().any(|c| c.object_id == frozen_id));
+        let frozen = executor.state().get_object(&frozen_id).unwrap().unwrap();
+        assert!(frozen.metadata.frozen);
+        assert_eq!(frozen.data.balance.value(), 600);
+        assert_eq!(executor.state().get_total_balance(&recipient), 300);
+    }
+    
     #[test]
     fn test_split_coin() {
         let mut store = InMemoryStateStore::new();
@@ -1557,6 +2751,30 @@ mod tests {
         assert_eq!(minted.metadata.owner.unwrap(), owner);
     }
     
+    #[test]
+    fn test_subnet_register_records_coin_metadata() {
+        let store = InMemoryStateStore::new();
+        let mut executor = RuntimeExecutor::new(store);
+        let owner = Address::from_str_id("alice");
+        let ctx = test_ctx("subnet-register-meta");
+
+        let output = executor.execute_subnet_register(
+            "gaming-subnet", "Gaming", &owner, Some("GAME"), 6, Some(1_000_000), &ctx,
+        ).unwrap();
+
+        assert!(output.success);
+        let meta_change = output.state_changes.iter()
+            .find(|c| c.object_id == coin_metadata_object_id("gaming-subnet"))
+            .expect("coin metadata state change");
+        // Metadata lands in ROOT, so it must not be tagged as a minted object
+        assert!(!output.created_objects.contains(&meta_change.object_id));
+
+        let meta = CoinMetadata::from_bytes(meta_change.new_state.as_ref().unwrap()).unwrap();
+        assert_eq!(meta.symbol, "GAME");
+        assert_eq!(meta.decimals, 6);
+        assert_eq!(meta.format_amount(1_000_000), "1.000000");
+    }
+    
     #[test]
     fn test_coin_id_deterministic_from_tx() {
         // Same tx_hash + output_index → same coin_id
@@ -12,8 +12,9 @@ pub mod state;
 pub mod transaction;
 pub mod error;
 
-pub use executor::{RuntimeExecutor, ExecutionContext, ExecutionOutput, StateChange, StateChangeType};
+pub use executor::{RuntimeExecutor, ExecutionContext, ExecutionOutput, RecipientCoinPolicy, ZeroBalanceCoinPolicy, StateChange, StateChangeType};
 pub use executor::{should_consume_power, decrement_power, increment_flux, penalize_flux};
-pub use state::{StateStore, InMemoryStateStore, RawStore, ObjectStore, InMemoryObjectStore};
-pub use transaction::{Transaction, TransactionType, TransferTx, QueryTx};
+pub use executor::record_subnet_interaction;
+pub use state::{StateStore, InMemoryStateStore, RawStore, ObjectStore, InMemoryObjectStore, StagedStateStore, StagedWrites, compute_state_root};
+pub use transaction::{Transaction, TransactionType, TransferTx, TransferAmount, MultiTransferTx, QueryTx, CoinFreezeTx};
 pub use error::{RuntimeError, RuntimeResult};
@@ -5,8 +5,9 @@
 //! - Level 1: `ObjectStore` — ObjectEnvelope-based (Move objects)
 //! - Level 2: `StateStore` — CoinData-specialized (legacy, unchanged)
 
-use std::collections::HashMap;
-use setu_types::{Object, ObjectId, Address, CoinData, ObjectEnvelope};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use setu_types::{Object, ObjectId, Address, CoinData, ObjectDigest, ObjectEnvelope};
+use setu_types::hash_utils::{setu_hash_with_domain, Hash};
 use crate::error::RuntimeResult;
 
 // ─── Level 0: Raw byte storage ───
@@ -31,6 +32,13 @@ pub trait ObjectStore: RawStore {
     fn set_envelope(&mut self, id: ObjectId, envelope: ObjectEnvelope) -> RuntimeResult<()>;
     fn delete_envelope(&mut self, id: &ObjectId) -> RuntimeResult<()>;
     fn get_owned_ids(&self, owner: &Address) -> RuntimeResult<Vec<ObjectId>>;
+
+    /// Look up the current object whose envelope digest is `digest`.
+    ///
+    /// Only the latest version of each object is indexed, so the digest of a
+    /// superseded version returns `None`. The digest covers the envelope's id,
+    /// version and owner, so objects with identical contents do not collide.
+    fn get_by_digest(&self, digest: &ObjectDigest) -> RuntimeResult<Option<ObjectEnvelope>>;
 }
 
 // ─── Level 2: Legacy CoinData-specialized storage (unchanged) ───
@@ -56,12 +64,36 @@ pub trait StateStore {
     /// Write raw bytes by ObjectId (type-agnostic)
     fn set_raw_object(&mut self, object_id: ObjectId, data: Vec<u8>) -> RuntimeResult<()>;
     
+    /// Snapshot every stored object, ordered by ObjectId.
+    ///
+    /// Values use the same encoding as `StateChange::new_state`: coins as BCS
+    /// `CoinState`, raw objects as stored, other envelopes as envelope bytes.
+    fn snapshot(&self) -> RuntimeResult<BTreeMap<ObjectId, Vec<u8>>>;
+    
     /// Check if object exists
     fn exists(&self, object_id: &ObjectId) -> bool {
         self.get_object(object_id).ok().flatten().is_some()
     }
 }
 
+/// Compute a deterministic root over every object in `store`.
+///
+/// Independent of the merkle layer: each `(id, bytes)` entry is hashed into
+/// a leaf and the leaves are hashed in ObjectId order, so the result depends
+/// only on the stored state, never on insertion order. Use it to cross-check
+/// runtime state against the SMT root, not as a substitute for it.
+pub fn compute_state_root<S: StateStore + ?Sized>(store: &S) -> RuntimeResult<Hash> {
+    let snapshot = store.snapshot()?;
+    let mut leaves = Vec::with_capacity(snapshot.len() * 32);
+    for (object_id, bytes) in &snapshot {
+        let mut leaf = Vec::with_capacity(32 + bytes.len());
+        leaf.extend_from_slice(object_id.as_bytes());
+        leaf.extend_from_slice(bytes);
+        leaves.extend_from_slice(&setu_hash_with_domain(b"SETU_RUNTIME_STATE_LEAF:", &leaf));
+    }
+    Ok(setu_hash_with_domain(b"SETU_RUNTIME_STATE_ROOT:", &leaves))
+}
+
 /// In-memory state storage (used for testing and simple scenarios)
 #[derive(Debug, Clone)]
 pub struct InMemoryStateStore {
@@ -167,6 +199,14 @@ impl StateStore for InMemoryStateStore {
         self.raw_objects.insert(object_id, data);
         Ok(())
     }
+    
+    fn snapshot(&self) -> RuntimeResult<BTreeMap<ObjectId, Vec<u8>>> {
+        let mut snapshot: BTreeMap<ObjectId, Vec<u8>> = self.raw_objects.clone().into_iter().collect();
+        for (object_id, coin) in &self.objects {
+            snapshot.insert(*object_id, coin.to_coin_state_bytes());
+        }
+        Ok(snapshot)
+    }
 }
 
 // ════════════════════════════════════════════════════════════════════════════
@@ -188,6 +228,8 @@ pub struct InMemoryObjectStore {
     ownership_index: HashMap<Address, Vec<ObjectId>>,
     /// Reverse index: ObjectId → Address (for O(1) old-owner removal)
     object_owner: HashMap<ObjectId, Address>,
+    /// Digest index: envelope digest → ObjectIds currently holding it
+    digest_index: HashMap<ObjectDigest, BTreeSet<ObjectId>>,
 }
 
 impl InMemoryObjectStore {
@@ -198,6 +240,20 @@ impl InMemoryObjectStore {
             raw_objects: HashMap::new(),
             ownership_index: HashMap::new(),
             object_owner: HashMap::new(),
+            digest_index: HashMap::new(),
+        }
+    }
+
+    /// Drop the digest entry of the envelope currently stored under `id`
+    fn remove_from_digest_index(&mut self, id: &ObjectId) {
+        let Some(digest) = self.envelopes.get(id).map(|env| env.metadata.digest) else {
+            return;
+        };
+        if let Some(ids) = self.digest_index.get_mut(&digest) {
+            ids.remove(id);
+            if ids.is_empty() {
+                self.digest_index.remove(&digest);
+            }
         }
     }
 
@@ -242,11 +298,14 @@ impl ObjectStore for InMemoryObjectStore {
         self.remove_from_ownership_index(&id);
         self.ownership_index.entry(owner).or_default().push(id);
         self.object_owner.insert(id, owner);
+        self.remove_from_digest_index(&id);
+        self.digest_index.entry(envelope.metadata.digest).or_default().insert(id);
         self.envelopes.insert(id, envelope);
         Ok(())
     }
 
     fn delete_envelope(&mut self, id: &ObjectId) -> RuntimeResult<()> {
+        self.remove_from_digest_index(id);
         self.envelopes.remove(id);
         self.remove_from_ownership_index(id);
         Ok(())
@@ -255,6 +314,15 @@ impl ObjectStore for InMemoryObjectStore {
     fn get_owned_ids(&self, owner: &Address) -> RuntimeResult<Vec<ObjectId>> {
         Ok(self.ownership_index.get(owner).cloned().unwrap_or_default())
     }
+
+    fn get_by_digest(&self, digest: &ObjectDigest) -> RuntimeResult<Option<ObjectEnvelope>> {
+        Ok(self
+            .digest_index
+            .get(digest)
+            .and_then(|ids| ids.first())
+            .and_then(|id| self.envelopes.get(id))
+            .cloned())
+    }
 }
 
 /// Backward-compatible `StateStore` implementation via ObjectEnvelope.
@@ -288,6 +356,135 @@ impl StateStore for InMemoryObjectStore {
         self.raw_objects.insert(object_id, data);
         Ok(())
     }
+
+    fn snapshot(&self) -> RuntimeResult<BTreeMap<ObjectId, Vec<u8>>> {
+        let mut snapshot: BTreeMap<ObjectId, Vec<u8>> = self.raw_objects.clone().into_iter().collect();
+        for (object_id, envelope) in &self.envelopes {
+            let bytes = match envelope.try_as_coin_object() {
+                Some(coin) => coin.to_coin_state_bytes(),
+                None => envelope.to_bytes(),
+            };
+            snapshot.insert(*object_id, bytes);
+        }
+        Ok(snapshot)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// StagedStateStore — write buffer for all-or-nothing execution
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Buffers writes over a read-only base store.
+///
+/// Reads see the buffered writes first, then fall through to `base`. Nothing
+/// reaches `base` until [`commit`](Self::commit) is called, so dropping the
+/// staged store discards every write (rollback).
+pub struct StagedStateStore<'a, S: StateStore + ?Sized> {
+    base: &'a S,
+    /// Staged coin writes; `None` marks a deletion
+    objects: BTreeMap<ObjectId, Option<Object<CoinData>>>,
+    /// Staged raw object writes
+    raw_objects: BTreeMap<ObjectId, Vec<u8>>,
+}
+
+/// Writes buffered by a [`StagedStateStore`], detached from its base borrow.
+pub struct StagedWrites {
+    objects: BTreeMap<ObjectId, Option<Object<CoinData>>>,
+    raw_objects: BTreeMap<ObjectId, Vec<u8>>,
+}
+
+impl<'a, S: StateStore + ?Sized> StagedStateStore<'a, S> {
+    pub fn new(base: &'a S) -> Self {
+        Self {
+            base,
+            objects: BTreeMap::new(),
+            raw_objects: BTreeMap::new(),
+        }
+    }
+
+    /// Release the base borrow, keeping the buffered writes.
+    pub fn commit(self) -> StagedWrites {
+        StagedWrites {
+            objects: self.objects,
+            raw_objects: self.raw_objects,
+        }
+    }
+}
+
+impl StagedWrites {
+    /// Apply the buffered writes to `store` in ObjectId order.
+    pub fn apply_to<S: StateStore + ?Sized>(self, store: &mut S) -> RuntimeResult<()> {
+        for (object_id, object) in self.objects {
+            match object {
+                Some(object) => store.set_object(object_id, object)?,
+                None => store.delete_object(&object_id)?,
+            }
+        }
+        for (object_id, data) in self.raw_objects {
+            store.set_raw_object(object_id, data)?;
+        }
+        Ok(())
+    }
+}
+
+impl<S: StateStore + ?Sized> StateStore for StagedStateStore<'_, S> {
+    fn get_object(&self, object_id: &ObjectId) -> RuntimeResult<Option<Object<CoinData>>> {
+        match self.objects.get(object_id) {
+            Some(staged) => Ok(staged.clone()),
+            None => self.base.get_object(object_id),
+        }
+    }
+
+    fn set_object(&mut self, object_id: ObjectId, object: Object<CoinData>) -> RuntimeResult<()> {
+        self.objects.insert(object_id, Some(object));
+        Ok(())
+    }
+
+    fn delete_object(&mut self, object_id: &ObjectId) -> RuntimeResult<()> {
+        self.objects.insert(*object_id, None);
+        Ok(())
+    }
+
+    fn get_owned_objects(&self, owner: &Address) -> RuntimeResult<Vec<ObjectId>> {
+        let mut owned: Vec<ObjectId> = self.base.get_owned_objects(owner)?
+            .into_iter()
+            .filter(|id| !self.objects.contains_key(id))
+            .collect();
+        for (object_id, object) in &self.objects {
+            if let Some(object) = object {
+                if object.metadata.owner.as_ref() == Some(owner) {
+                    owned.push(*object_id);
+                }
+            }
+        }
+        Ok(owned)
+    }
+
+    fn get_raw_object(&self, object_id: &ObjectId) -> RuntimeResult<Option<Vec<u8>>> {
+        match self.raw_objects.get(object_id) {
+            Some(data) => Ok(Some(data.clone())),
+            None => self.base.get_raw_object(object_id),
+        }
+    }
+
+    fn set_raw_object(&mut self, object_id: ObjectId, data: Vec<u8>) -> RuntimeResult<()> {
+        self.raw_objects.insert(object_id, data);
+        Ok(())
+    }
+
+    fn snapshot(&self) -> RuntimeResult<BTreeMap<ObjectId, Vec<u8>>> {
+        let mut snapshot = self.base.snapshot()?;
+        for (object_id, object) in &self.objects {
+            match object {
+                Some(coin) => snapshot.insert(*object_id, coin.to_coin_state_bytes()),
+                None => snapshot.remove(object_id),
+            };
+        }
+        for (object_id, data) in &self.raw_objects {
+            snapshot.insert(*object_id, data.clone());
+        }
+        Ok(snapshot)
+    }
 }
 
 #[cfg(test)]
@@ -323,6 +520,48 @@ mod tests {
         assert_eq!(owned.len(), 0);
     }
 
+    #[test]
+    fn test_state_root_independent_of_insertion_order() {
+        let alice = Address::from_str_id("alice");
+        let bob = Address::from_str_id("bob");
+        let coins: Vec<_> = (1..=4u8)
+            .map(|i| {
+                let owner = if i % 2 == 0 { alice } else { bob };
+                setu_types::create_coin_with_id(ObjectId::new([i; 32]), owner, i as u64 * 100, "ROOT", 0)
+            })
+            .collect();
+        let raw_id = ObjectId::new([0xAA; 32]);
+        let raw = br#"{"flux":1}"#.to_vec();
+
+        let mut forward = InMemoryStateStore::new();
+        for coin in &coins {
+            forward.set_object(*coin.id(), coin.clone()).unwrap();
+        }
+        forward.set_raw_object(raw_id, raw.clone()).unwrap();
+
+        let mut reverse = InMemoryStateStore::new();
+        reverse.set_raw_object(raw_id, raw).unwrap();
+        for coin in coins.iter().rev() {
+            reverse.set_object(*coin.id(), coin.clone()).unwrap();
+        }
+
+        let root = compute_state_root(&forward).unwrap();
+        assert_eq!(forward.snapshot().unwrap().len(), 5);
+        assert_eq!(root, compute_state_root(&reverse).unwrap());
+
+        // Any state difference changes the root
+        reverse.delete_object(coins[0].id()).unwrap();
+        assert_ne!(root, compute_state_root(&reverse).unwrap());
+
+        // The envelope store encodes coins identically
+        let mut envelope_store = InMemoryObjectStore::new();
+        for coin in &coins {
+            envelope_store.set_object(*coin.id(), coin.clone()).unwrap();
+        }
+        envelope_store.set_raw_object(raw_id, br#"{"flux":1}"#.to_vec()).unwrap();
+        assert_eq!(root, compute_state_root(&envelope_store).unwrap());
+    }
+
     // ─── InMemoryObjectStore tests ───
 
     fn make_envelope(id_byte: u8, owner: Address, balance: u64) -> (ObjectId, ObjectEnvelope) {
@@ -395,6 +634,38 @@ mod tests {
         assert_eq!(store.get_owned_ids(&bob).unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_object_store_get_by_digest() {
+        let mut store = InMemoryObjectStore::new();
+        let owner = Address::from_str_id("dave");
+        let (id, env) = make_envelope(1, owner, 300);
+
+        // Digest covers the envelope header (id, version, owner) and the contents
+        let digest = env.metadata.digest;
+        let expected = blake3::Hasher::new()
+            .update(setu_types::ENVELOPE_DIGEST_DOMAIN)
+            .update(id.as_bytes())
+            .update(&env.metadata.version.to_le_bytes())
+            .update(owner.as_bytes())
+            .update(&env.data)
+            .finalize();
+        assert_eq!(digest.as_bytes(), expected.as_bytes());
+        assert!(store.get_by_digest(&digest).unwrap().is_none());
+
+        store.set_envelope(id, env.clone()).unwrap();
+        assert_eq!(store.get_by_digest(&digest).unwrap(), Some(env));
+
+        // After an update only the new digest resolves
+        let updated = setu_types::create_coin_with_id(id, owner, 200, "ROOT", 1);
+        let env2 = ObjectEnvelope::from_coin_object(&updated).unwrap();
+        store.set_envelope(id, env2.clone()).unwrap();
+        assert!(store.get_by_digest(&digest).unwrap().is_none());
+        assert_eq!(store.get_by_digest(&env2.metadata.digest).unwrap(), Some(env2.clone()));
+
+        store.delete_envelope(&id).unwrap();
+        assert!(store.get_by_digest(&env2.metadata.digest).unwrap().is_none());
+    }
+
     #[test]
     fn test_object_store_state_store_compat() {
         let mut store = InMemoryObjectStore::new();
@@ -417,4 +688,28 @@ mod tests {
         store.delete_object(&coin_id).unwrap();
         assert!(store.get_object(&coin_id).unwrap().is_none());
     }
+
+    #[test]
+    fn test_staged_store_buffers_until_applied() {
+        let mut store = InMemoryStateStore::new();
+        let alice = Address::from_str_id("alice");
+        let bob = Address::from_str_id("bob");
+        let mut coin = setu_types::create_coin(alice, 100);
+        let coin_id = *coin.id();
+        store.set_object(coin_id, coin.clone()).unwrap();
+
+        let mut staged = StagedStateStore::new(&store);
+        coin.transfer_to(bob);
+        staged.set_object(coin_id, coin).unwrap();
+        assert_eq!(staged.get_owned_objects(&bob).unwrap(), vec![coin_id]);
+        assert!(staged.get_owned_objects(&alice).unwrap().is_empty());
+
+        // The base is untouched until the writes are applied
+        let writes = staged.commit();
+        assert_eq!(store.get_owned_objects(&alice).unwrap(), vec![coin_id]);
+
+        writes.apply_to(&mut store).unwrap();
+        assert_eq!(store.get_owned_objects(&bob).unwrap(), vec![coin_id]);
+        assert!(store.get_owned_objects(&alice).unwrap().is_empty());
+    }
 }
@@ -8,8 +8,14 @@ use setu_types::{Address, ObjectId};
 pub enum TransactionType {
     /// Transfer transaction
     Transfer(TransferTx),
+    /// Several transfers applied atomically (all or none)
+    MultiTransfer(MultiTransferTx),
     /// Query transaction (read-only)
     Query(QueryTx),
+    /// Freeze a coin (freeze authority only)
+    FreezeCoin(CoinFreezeTx),
+    /// Unfreeze a previously frozen coin (freeze authority only)
+    UnfreezeCoin(CoinFreezeTx),
 }
 
 /// Simplified transaction structure
@@ -34,8 +40,47 @@ pub struct TransferTx {
     pub coin_id: ObjectId,
     /// Recipient address
     pub recipient: Address,
-    /// Transfer amount (if partial transfer)
-    pub amount: Option<u64>,
+    /// How much of the coin to transfer
+    pub amount: TransferAmount,
+}
+
+/// Batch of transfers executed as one transaction.
+///
+/// Items run in order against staged state; if any item fails, none of
+/// the transfers are applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiTransferTx {
+    /// Transfers to apply, in order
+    pub items: Vec<TransferTx>,
+}
+
+/// Target of a `FreezeCoin` / `UnfreezeCoin` transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoinFreezeTx {
+    /// Coin object ID
+    pub coin_id: ObjectId,
+}
+
+/// Amount semantics for a transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransferAmount {
+    /// Transfer ownership of the coin object itself (no new coin).
+    Full,
+    /// Split `n` off into a new recipient coin. `n == balance` is treated as `Full`.
+    Exact(u64),
+    /// Move the entire balance into a new recipient coin, keeping the
+    /// (now zero-balance) source coin object with the sender.
+    AllBalance,
+}
+
+impl From<Option<u64>> for TransferAmount {
+    /// Legacy mapping: `None` = full transfer, `Some(n)` = exact amount.
+    fn from(amount: Option<u64>) -> Self {
+        match amount {
+            None => TransferAmount::Full,
+            Some(n) => TransferAmount::Exact(n),
+        }
+    }
 }
 
 /// Query transaction (read-only)
@@ -67,6 +112,17 @@ impl Transaction {
         coin_id: ObjectId,
         recipient: Address,
         amount: Option<u64>,
+    ) -> Self {
+        Self::new_transfer_with_amount(sender, coin_id, recipient, amount.into())
+    }
+
+    /// Create a transfer transaction with explicit [`TransferAmount`] semantics
+    /// (non-consensus path only, see [`new_transfer`]).
+    pub fn new_transfer_with_amount(
+        sender: Address,
+        coin_id: ObjectId,
+        recipient: Address,
+        amount: TransferAmount,
     ) -> Self {
         let timestamp = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -123,13 +179,68 @@ impl Transaction {
             tx_type: TransactionType::Transfer(TransferTx {
                 coin_id,
                 recipient,
-                amount,
+                amount: amount.into(),
             }),
             input_objects: vec![coin_id],
             timestamp: ctx_timestamp,
         }
     }
     
+    /// Deterministic multi-transfer constructor for TEE/consensus paths
+    /// (see [`new_transfer_deterministic`]).
+    ///
+    /// `input_objects` lists each distinct coin once, in item order.
+    pub fn new_multi_transfer_deterministic(
+        sender: Address,
+        items: Vec<TransferTx>,
+        ctx_timestamp: u64,
+    ) -> Self {
+        let mut input_objects: Vec<ObjectId> = Vec::with_capacity(items.len());
+        for item in &items {
+            if !input_objects.contains(&item.coin_id) {
+                input_objects.push(item.coin_id);
+            }
+        }
+        let id = match input_objects.first() {
+            Some(first) => format!("mtx_{}_{:x}", &first.to_string()[..8], ctx_timestamp),
+            None => format!("mtx_{:x}", ctx_timestamp),
+        };
+
+        Self {
+            id,
+            sender,
+            tx_type: TransactionType::MultiTransfer(MultiTransferTx { items }),
+            input_objects,
+            timestamp: ctx_timestamp,
+        }
+    }
+    
+    /// Deterministic freeze (`frozen = true`) or unfreeze constructor for
+    /// TEE/consensus paths (see [`new_transfer_deterministic`]).
+    ///
+    /// `sender` must be the executor's configured freeze authority.
+    pub fn new_coin_freeze_deterministic(
+        sender: Address,
+        coin_id: ObjectId,
+        frozen: bool,
+        ctx_timestamp: u64,
+    ) -> Self {
+        let (prefix, tx_type) = if frozen {
+            ("frz", TransactionType::FreezeCoin(CoinFreezeTx { coin_id }))
+        } else {
+            ("unfrz", TransactionType::UnfreezeCoin(CoinFreezeTx { coin_id }))
+        };
+        let id = format!("{}_{}_{:x}", prefix, &coin_id.to_string()[..8], ctx_timestamp);
+
+        Self {
+            id,
+            sender,
+            tx_type,
+            input_objects: vec![coin_id],
+            timestamp: ctx_timestamp,
+        }
+    }
+    
     /// Create a new balance query transaction
     pub fn new_balance_query(address: Address) -> Self {
         let timestamp = std::time::SystemTime::now()
@@ -30,7 +30,9 @@
 //! ```
 
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 
 /// Vector Clock - Captures causal relationships of distributed events
 /// 
@@ -122,6 +124,37 @@ impl VectorClock {
         !self.happens_before(other) && !other.happens_before(self) && self != other
     }
     
+    /// Compare two vector clocks under the causal partial order
+    /// 
+    /// Nodes missing from one side count as 0, so `{a: 0}` equals `{}`.
+    /// 
+    /// - `Some(Less)`: self happens before other
+    /// - `Some(Greater)`: other happens before self
+    /// - `Some(Equal)`: same value for every node
+    /// - `None`: concurrent (each side has seen something the other has not)
+    pub fn compare(&self, other: &VectorClock) -> Option<Ordering> {
+        let mut less = false;
+        let mut greater = false;
+        
+        let nodes = self.clocks.keys().chain(other.clocks.keys());
+        for node_id in nodes {
+            match self.get(node_id).cmp(&other.get(node_id)) {
+                Ordering::Less => less = true,
+                Ordering::Greater => greater = true,
+                Ordering::Equal => {}
+            }
+            if less && greater {
+                return None;
+            }
+        }
+        
+        match (less, greater) {
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+            _ => Some(Ordering::Equal),
+        }
+    }
+    
     /// Get all node IDs
     pub fn nodes(&self) -> Vec<&String> {
         self.clocks.keys().collect()
@@ -248,9 +281,14 @@ impl VLCSnapshot {
     
     /// Increment logical time and vector clock
     pub fn increment(&mut self, node_id: &str) {
+        self.increment_with_clock(node_id, &SystemClock);
+    }
+    
+    /// Like [`increment`](Self::increment), reading physical time from `clock`
+    pub fn increment_with_clock(&mut self, node_id: &str, clock: &dyn Clock) {
         self.logical_time += 1;
         self.vector_clock.increment(node_id);
-        self.physical_time = Self::current_physical_time();
+        self.physical_time = clock.now_millis();
     }
     
     /// Receive snapshot from another node and update local clock
@@ -259,6 +297,11 @@ impl VLCSnapshot {
     /// - Merge vector clocks
     /// - logical_time = max(local_logical_time, received_logical_time) + 1
     pub fn receive(&mut self, other: &VLCSnapshot, local_node_id: &str) {
+        self.receive_with_clock(other, local_node_id, &SystemClock);
+    }
+    
+    /// Like [`receive`](Self::receive), reading physical time from `clock`
+    pub fn receive_with_clock(&mut self, other: &VLCSnapshot, local_node_id: &str, clock: &dyn Clock) {
         // Merge vector clocks
         self.vector_clock.merge(&other.vector_clock);
         
@@ -269,7 +312,7 @@ impl VLCSnapshot {
         self.vector_clock.increment(local_node_id);
         
         // Update physical time
-        self.physical_time = Self::current_physical_time();
+        self.physical_time = clock.now_millis();
     }
     
     /// Check if this happens before another snapshot
@@ -292,6 +335,22 @@ impl VLCSnapshot {
         self.vector_clock.is_concurrent(&other.vector_clock)
     }
     
+    /// Compare two snapshots by causal order of their vector clocks
+    /// 
+    /// Returns `None` for concurrent snapshots, i.e. events that may conflict.
+    /// Logical and physical time are ignored: they do not imply causality.
+    pub fn compare(&self, other: &VLCSnapshot) -> Option<Ordering> {
+        self.vector_clock.compare(&other.vector_clock)
+    }
+    
+    /// Check if neither snapshot causally precedes the other
+    /// 
+    /// Unlike `is_concurrent`, nodes missing from one clock count as 0, so
+    /// `{a: 0}` and `{}` are equal rather than concurrent.
+    pub fn concurrent_with(&self, other: &VLCSnapshot) -> bool {
+        self.compare(other).is_none()
+    }
+    
     /// Garbage collection: remove inactive nodes
     /// 
     /// # Parameters
@@ -307,6 +366,31 @@ impl VLCSnapshot {
     
     /// Get current physical time (milliseconds)
     fn current_physical_time() -> u64 {
+        SystemClock.now_millis()
+    }
+}
+
+impl Default for VLCSnapshot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Source of physical time for VLC snapshots
+///
+/// The default [`SystemClock`] reads the wall clock. Tests and simulations
+/// inject a [`MockClock`] so `physical_time` is reproducible.
+pub trait Clock: Send + Sync + std::fmt::Debug {
+    /// Current time as Unix milliseconds
+    fn now_millis(&self) -> u64;
+}
+
+/// Wall-clock time from `SystemTime::now()`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_millis(&self) -> u64 {
         std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
@@ -314,8 +398,101 @@ impl VLCSnapshot {
     }
 }
 
-impl Default for VLCSnapshot {
-    fn default() -> Self {
-        Self::new()
+/// Manually driven clock; time only moves when `set` or `advance` is called
+#[derive(Debug, Default)]
+pub struct MockClock {
+    now: AtomicU64,
+}
+
+impl MockClock {
+    /// Create a clock frozen at `now_millis`
+    pub fn new(now_millis: u64) -> Self {
+        Self {
+            now: AtomicU64::new(now_millis),
+        }
+    }
+
+    /// Jump to an absolute time (may move backwards, to simulate skew)
+    pub fn set(&self, now_millis: u64) {
+        self.now.store(now_millis, AtomicOrdering::SeqCst);
+    }
+
+    /// Move the clock forward by `millis`
+    pub fn advance(&self, millis: u64) {
+        self.now.fetch_add(millis, AtomicOrdering::SeqCst);
+    }
+}
+
+impl Clock for MockClock {
+    fn now_millis(&self) -> u64 {
+        self.now.load(AtomicOrdering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn snapshot(entries: &[(&str, u64)]) -> VLCSnapshot {
+        let mut vc = VectorClock::new();
+        for (node, time) in entries {
+            vc.set(node, *time);
+        }
+        VLCSnapshot::new_with_clock(vc)
+    }
+    
+    #[test]
+    fn test_compare_strictly_before_and_after() {
+        let a = snapshot(&[("node1", 1), ("node2", 2)]);
+        let b = snapshot(&[("node1", 2), ("node2", 2)]);
+        
+        assert_eq!(a.compare(&b), Some(Ordering::Less));
+        assert_eq!(b.compare(&a), Some(Ordering::Greater));
+        assert!(a.happens_before(&b));
+        assert!(!b.happens_before(&a));
+        assert!(!a.concurrent_with(&b));
+        
+        // A node only the later clock has seen still orders the pair
+        let c = snapshot(&[("node1", 2), ("node2", 2), ("node3", 1)]);
+        assert_eq!(b.compare(&c), Some(Ordering::Less));
+    }
+    
+    #[test]
+    fn test_compare_equal() {
+        let a = snapshot(&[("node1", 3), ("node2", 0)]);
+        let b = snapshot(&[("node1", 3)]);
+        
+        assert_eq!(a.compare(&b), Some(Ordering::Equal));
+        assert_eq!(b.compare(&a), Some(Ordering::Equal));
+        assert!(!a.concurrent_with(&b));
+        assert_eq!(snapshot(&[]).compare(&snapshot(&[])), Some(Ordering::Equal));
+    }
+    
+    #[test]
+    fn test_mock_clock_drives_physical_time() {
+        let clock = MockClock::new(1_000);
+        let mut vlc = VLCSnapshot::for_node("node1".to_string());
+        
+        vlc.increment_with_clock("node1", &clock);
+        assert_eq!(vlc.physical_time, 1_000);
+        
+        clock.advance(250);
+        let mut other = VLCSnapshot::for_node("node2".to_string());
+        other.increment_with_clock("node2", &clock);
+        vlc.receive_with_clock(&other, "node1", &clock);
+        assert_eq!(vlc.physical_time, 1_250);
+        assert_eq!(vlc.logical_time, 2);
+    }
+    
+    #[test]
+    fn test_compare_concurrent() {
+        let a = snapshot(&[("node1", 2), ("node2", 1)]);
+        let b = snapshot(&[("node1", 1), ("node2", 2)]);
+        
+        assert_eq!(a.compare(&b), None);
+        assert_eq!(b.compare(&a), None);
+        assert!(a.concurrent_with(&b));
+        assert!(b.concurrent_with(&a));
+        assert!(a.is_concurrent(&b));
     }
 }
@@ -158,7 +158,8 @@ wait_for_health() {
     local max_wait="${3:-30}"
     local elapsed=0
     while [ $elapsed -lt $max_wait ]; do
-        if curl -sf --connect-timeout 2 "http://${host}:${port}/api/v1/health" &>/dev/null; then
+        # 有 HTTP 响应即视为已启动 (/health 在就绪前返回 503)
+        if curl -s -o /dev/null --connect-timeout 2 "http://${host}:${port}/api/v1/health"; then
             return 0
         fi
         sleep 2
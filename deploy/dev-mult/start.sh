@@ -208,7 +208,7 @@ if [ "$NO_SOLVER" = false ]; then
     for i in "${!SERVERS[@]}"; do
         host="${SERVERS[$i]}"
         vid="${VALIDATOR_IDS[$i]}"
-        health=$(curl -sf --connect-timeout 3 "http://${host}:${HTTP_PORT}/api/v1/health" 2>/dev/null || echo "")
+        health=$(curl -s --connect-timeout 3 "http://${host}:${HTTP_PORT}/api/v1/health" 2>/dev/null || echo "")
         solver_count=$(echo "$health" | python3 -c "import sys,json; print(json.load(sys.stdin).get('solver_count',0))" 2>/dev/null || echo "0")
         if [ "$solver_count" -gt 0 ] 2>/dev/null; then
             print_ok "${vid}: solver_count=${solver_count}"
@@ -37,9 +37,11 @@ for i in "${!SERVERS[@]}"; do
 
     # 检查 HTTP 健康
     http_status="✗"
-    health_resp=$(curl -sf --connect-timeout 3 "http://${host}:${HTTP_PORT}/api/v1/health" 2>/dev/null || echo "")
-    if [ -n "$health_resp" ]; then
+    health_resp=$(curl -s --connect-timeout 3 "http://${host}:${HTTP_PORT}/api/v1/health" 2>/dev/null || echo "")
+    if echo "$health_resp" | grep -q '"ready":true'; then
         http_status="✓ 健康"
+    elif [ -n "$health_resp" ]; then
+        http_status="⚠ 未就绪"
     else
         http_status="✗ 无响应"
     fi
@@ -93,7 +95,7 @@ if [ "$VERBOSE" = "--verbose" ] || [ "$VERBOSE" = "-v" ]; then
         remote_exec "$host" "tail -3 ${REMOTE_LOGS}/validator.log 2>/dev/null || echo '    (无日志)'" 2>/dev/null
         
         # 健康详情
-        health=$(curl -sf --connect-timeout 3 "http://${host}:${HTTP_PORT}/api/v1/health" 2>/dev/null || echo "")
+        health=$(curl -s --connect-timeout 3 "http://${host}:${HTTP_PORT}/api/v1/health" 2>/dev/null || echo "")
         if [ -n "$health" ]; then
             echo "  健康响应: ${health}"
         fi
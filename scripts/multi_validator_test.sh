@@ -74,6 +74,8 @@ echo ""
 for port in 8080 8082 8084; do
     if curl -sf "http://127.0.0.1:$port/api/v1/health" > /dev/null 2>&1; then
         echo "  ✓ Validator on port $port healthy"
+    elif curl -sf "http://127.0.0.1:$port/api/v1/health/live" > /dev/null 2>&1; then
+        echo "  ⚠ Validator on port $port alive but not ready"
     else
         echo "  ✗ Validator on port $port not responding (may still be starting)"
     fi
@@ -195,10 +195,24 @@ pub struct ValidatorListItem {
     pub status: String,
 }
 
+/// Consensus view of a validator: voting power and proposer schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidatorSetItem {
+    pub validator_id: String,
+    pub voting_power: u64,
+    /// Valid proposer for the current round
+    pub is_current_leader: bool,
+    /// Next round after the current one in which this validator proposes
+    pub round_as_leader_next: Option<u64>,
+}
+
 /// Response with validator list
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetValidatorListResponse {
     pub validators: Vec<ValidatorListItem>,
+    /// Current consensus validator set (empty when consensus is disabled)
+    #[serde(default)]
+    pub validator_set: Vec<ValidatorSetItem>,
 }
 
 /// Request to get node status
@@ -240,8 +254,20 @@ pub struct SubmitTransferRequest {
     pub shard_id: Option<String>,
     /// Optional subnet ID for subnet-based routing
     pub subnet_id: Option<String>,
+    /// Subnet the recipient is credited in; when it differs from
+    /// `subnet_id` the transfer is rejected (cross-subnet needs a lock)
+    #[serde(default)]
+    pub recipient_subnet_id: Option<String>,
     /// Resources involved in this transfer
     pub resources: Vec<String>,
+    /// Priority fee offered by the sender; orders dispatch under the
+    /// validator's opt-in `fee_priority` mempool policy. Not charged.
+    #[serde(default)]
+    pub fee: u64,
+    /// Hold the response until the containing CF finalizes and the transfer's
+    /// state changes are applied, so a follow-up balance query reflects it
+    #[serde(default)]
+    pub wait_for_finalization: bool,
 }
 
 /// Response to transfer submission
@@ -259,6 +285,10 @@ pub struct SubmitTransferResponse {
     pub solver_id: Option<String>,
     /// Processing steps (for debugging/visualization)
     pub processing_steps: Vec<ProcessingStep>,
+    /// Id carried by every log line of this submission, for grepping one
+    /// transfer across preparation, TEE execution and finalization
+    #[serde(default)]
+    pub correlation_id: Option<String>,
 }
 
 // ============================================
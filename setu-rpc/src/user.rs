@@ -144,6 +144,12 @@ pub struct CoinBalance {
     pub balance: u64,
     /// Number of coin objects
     pub coin_count: u32,
+    /// Display symbol from the coin type registry (None if unregistered)
+    #[serde(default)]
+    pub symbol: Option<String>,
+    /// Decimal places for rendering `balance` (None if unregistered)
+    #[serde(default)]
+    pub decimals: Option<u8>,
 }
 
 /// Response with user balance
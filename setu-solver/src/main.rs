@@ -19,7 +19,9 @@ use setu_solver::{
     SolverTask,
     start_server,
 };
+use setu_enclave::{EnclaveConfig, MockEnclave};
 use setu_keys::{KeyPair, load_keypair};
+use setu_types::Address;
 use std::sync::Arc;
 use tokio::sync::mpsc;
 use tracing::{info, warn, error, Level};
@@ -49,6 +51,12 @@ struct SolverConfig {
     auto_register: bool,
     /// Key file path (optional)
     key_file: Option<String>,
+    /// Hex address allowed to freeze/unfreeze coins; must match the rest of
+    /// the network's solvers
+    coin_freeze_authority: Option<String>,
+    /// Hex address credited with the non-burned part of transfer fees; must
+    /// match the rest of the network's solvers
+    fee_collector: Option<String>,
 }
 
 impl SolverConfig {
@@ -95,6 +103,10 @@ impl SolverConfig {
         
         let key_file = std::env::var("SOLVER_KEY_FILE").ok();
         
+        let coin_freeze_authority = std::env::var("COIN_FREEZE_AUTHORITY").ok();
+        
+        let fee_collector = std::env::var("FEE_COLLECTOR").ok();
+        
         Self {
             solver_id,
             address,
@@ -107,6 +119,8 @@ impl SolverConfig {
             heartbeat_interval_secs,
             auto_register,
             key_file,
+            coin_freeze_authority,
+            fee_collector,
         }
     }
 }
@@ -168,7 +182,25 @@ async fn main() -> anyhow::Result<()> {
     info!("╚════════════════════════════════════════════════════════════╝");
 
     // Initialize TEE Executor
-    let tee_executor = Arc::new(TeeExecutor::new(config.solver_id.clone()));
+    let tee_executor = if config.coin_freeze_authority.is_some() || config.fee_collector.is_some() {
+        let mut enclave_config = EnclaveConfig::new(config.solver_id.clone());
+        if let Some(authority) = &config.coin_freeze_authority {
+            let authority = Address::from_hex(authority)
+                .map_err(|e| anyhow::anyhow!("COIN_FREEZE_AUTHORITY: {}", e))?;
+            enclave_config = enclave_config.with_coin_freeze_authority(authority);
+        }
+        if let Some(collector) = &config.fee_collector {
+            let collector = Address::from_hex(collector)
+                .map_err(|e| anyhow::anyhow!("FEE_COLLECTOR: {}", e))?;
+            enclave_config = enclave_config.with_fee_collector(collector);
+        }
+        Arc::new(TeeExecutor::with_enclave(
+            config.solver_id.clone(),
+            Arc::new(MockEnclave::new(enclave_config)),
+        ))
+    } else {
+        Arc::new(TeeExecutor::new(config.solver_id.clone()))
+    };
     
     info!("┌─────────────────────────────────────────────────────────────┐");
     info!("│                  TEE Executor Initialized                   │");
@@ -140,7 +140,7 @@ impl TeeExecutor {
         );
         
         // Convert SolverTask to StfInput (direct pass-through, no modification)
-        let input = StfInput::new(
+        let mut input = StfInput::new(
             task.task_id,
             task.subnet_id.clone(),
             task.pre_state_root,
@@ -150,6 +150,8 @@ impl TeeExecutor {
         .with_events(vec![task.event])
         .with_read_set(task.read_set)
         .with_module_read_set(task.module_read_set);
+        input.execution_deadline_ms = task.execution_deadline_ms;
+        input.zero_balance_policy = task.zero_balance_policy;
         
         // Execute STF in TEE
         let output = self.enclave.execute_stf(input).await
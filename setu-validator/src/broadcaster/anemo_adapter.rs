@@ -8,7 +8,7 @@
 //! the SetuMessage type from setu_validator::protocol.
 
 use bytes::Bytes;
-use consensus::{BroadcastError, BroadcastResult, ConsensusBroadcaster};
+use consensus::{BroadcastError, BroadcastResult, ConsensusBroadcaster, ValidatorSetChange};
 use setu_network_anemo::{AnemoNetworkService, PeerId};
 use setu_types::{ConsensusFrame, Event, EventId, Vote};
 use std::fmt;
@@ -182,6 +182,48 @@ impl ConsensusBroadcaster for AnemoConsensusBroadcaster {
         }
     }
 
+    async fn broadcast_validator_set_change(
+        &self,
+        change: &ValidatorSetChange,
+    ) -> Result<BroadcastResult, BroadcastError> {
+        let total_peers = self.network.get_peer_count();
+
+        if total_peers == 0 {
+            debug!(
+                activation_round = change.activation_round,
+                "No peers to announce validator set change to"
+            );
+            return Ok(BroadcastResult::success(0, 0));
+        }
+
+        let message = SetuMessage::ValidatorSetChange {
+            change: change.clone(),
+            sender_id: self.local_validator_id.clone(),
+        };
+        let bytes = Self::serialize(&message)?;
+
+        match self.network.broadcast(SETU_ROUTE, bytes).await {
+            Ok((success, total)) => {
+                info!(
+                    activation_round = change.activation_round,
+                    success = success,
+                    total = total,
+                    "Validator set change broadcasted"
+                );
+                Ok(BroadcastResult::success(success, total))
+            }
+            Err(e) => {
+                let error_msg = format!("{}", e);
+                warn!(
+                    activation_round = change.activation_round,
+                    error = %error_msg,
+                    "Failed to broadcast validator set change"
+                );
+                Err(BroadcastError::AllFailed(error_msg))
+            }
+        }
+    }
+
     async fn request_events(&self, event_ids: &[EventId]) -> Result<Vec<Event>, BroadcastError> {
         if event_ids.is_empty() {
             return Ok(Vec::new());
@@ -12,4 +12,5 @@ pub use anemo_adapter::AnemoConsensusBroadcaster;
 pub use consensus::{
     ConsensusBroadcaster, BroadcastError, BroadcastResult,
     NoOpBroadcaster, MockBroadcaster,
+    RetryingBroadcaster, RetryPolicy, DeliveryStats,
 };
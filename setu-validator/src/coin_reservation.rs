@@ -170,6 +170,19 @@ impl CoinReservationManager {
         }
     }
 
+    /// Whether a coin currently holds an unexpired reservation
+    ///
+    /// Read-only counterpart of `try_reserve` for dry runs: it never takes or
+    /// replaces a reservation. Always `false` while reservations are disabled.
+    pub fn is_reserved(&self, coin_id: &ObjectId) -> bool {
+        if !self.enabled.load(Ordering::Relaxed) {
+            return false;
+        }
+        self.reservations
+            .get(coin_id)
+            .is_some_and(|r| r.created_at.elapsed() <= self.ttl)
+    }
+
     /// Release a reservation
     ///
     /// Only releases if the reservation ID matches (prevents releasing another thread's reservation).
@@ -19,25 +19,37 @@
 
 use consensus::{
     ConsensusEngine, ConsensusMessage, DagStats as ConsensusDagStats,
-    ValidatorSet, TeeVerifier, VerificationResult,
-    liveness::Round, ConsensusBroadcaster, OutcomeSink,
+    ValidatorSet, ValidatorSetChange, TeeVerifier, VerificationResult,
+    liveness::{Round, VotingPower}, ConsensusBroadcaster, OutcomeSink, ParentSelectionStrategy,
+    OrphanedEvent,
 };
 use crate::outcome_sink::DashMapOutcomeSink;
 use crate::protocol::NetworkEvent;
+use setu_network_anemo::{PeerId, PeerSyncInfo, PeerSyncObserver};
 use setu_types::{
     Anchor, ConsensusConfig, ConsensusFrame, Event, EventId, Vote,
-    NodeInfo, ValidatorInfo, SetuResult, SetuError, SubnetId, ExecutionOutcome,
+    NodeInfo, ValidatorInfo, SetuResult, SetuError, SubnetId, ExecutionOutcome, EventType,
 };
 use setu_storage::SharedStateManager;
 use setu_storage::subnet_state::GlobalStateManager;
-use setu_storage::{EventStore, CFStore, AnchorStore, EventStoreBackend, AnchorStoreBackend, CFStoreBackend};
+use setu_storage::{
+    EventStore, CFStore, AnchorStore, EventStoreBackend, AnchorStoreBackend, CFStoreBackend, AnchorCommitBackend,
+};
 use crate::network_adapter::MessageRouter;
 use crate::persistence::FinalizationPersister;
-use std::collections::HashMap;
+use crate::ValidationError;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 use std::sync::Arc;
 use tokio::sync::{mpsc, RwLock, Mutex, broadcast};
 use tracing::{debug, info, warn};
 
+/// Buffered anchors per `subscribe_finalized` receiver before it lags
+pub const ANCHOR_FINALIZATION_CAPACITY: usize = 256;
+
+/// How long a peer's reported anchor depth counts towards the network estimate
+pub const PEER_SYNC_STATE_TTL: Duration = Duration::from_secs(60);
+
 /// Configuration for the consensus-integrated validator
 #[derive(Debug, Clone)]
 pub struct ConsensusValidatorConfig {
@@ -49,6 +61,27 @@ pub struct ConsensusValidatorConfig {
     pub is_leader: bool,
     /// Buffer size for consensus message channel
     pub message_buffer_size: usize,
+    /// How parents are chosen for events created without object dependencies
+    pub parent_selection: ParentSelectionStrategy,
+    /// Event creators permitted to submit events (permissioned mode).
+    ///
+    /// `None` accepts any creator. Events signed by a validator key (genesis
+    /// included) are admitted regardless of creator.
+    pub allowed_creators: Option<HashSet<String>>,
+    /// Registered ed25519 public keys of event creators.
+    ///
+    /// `None` skips event signature verification. When set, every event
+    /// must carry a valid signature from its creator; creators with no
+    /// registered key fall back to the validator set. Genesis and System
+    /// events are signed with, and verified against, this node's own key.
+    pub creator_public_keys: Option<HashMap<String, Vec<u8>>>,
+    /// Recompute the anchor chain root from genesis on recovery and refuse to
+    /// start if it does not match the stored chain. O(chain length), so off
+    /// by default.
+    pub verify_anchor_chain_on_recovery: bool,
+    /// Largest object value an event's state changes may write. Taken from
+    /// genesis so every validator admits the same events.
+    pub max_object_bytes: usize,
 }
 
 impl Default for ConsensusValidatorConfig {
@@ -62,6 +95,11 @@ impl Default for ConsensusValidatorConfig {
             ),
             is_leader: false,
             message_buffer_size: 1000,
+            parent_selection: ParentSelectionStrategy::default(),
+            allowed_creators: None,
+            creator_public_keys: None,
+            verify_anchor_chain_on_recovery: false,
+            max_object_bytes: setu_storage::DEFAULT_MAX_OBJECT_BYTES,
         }
     }
 }
@@ -92,6 +130,8 @@ pub struct ConsensusValidator {
     anchor_store: Arc<dyn AnchorStoreBackend>,
     /// Per-CF index-persistence retry counter (Layer D, retry-then-escalate).
     cf_index_retries: Arc<parking_lot::Mutex<std::collections::HashMap<setu_types::CFId, u32>>>,
+    /// Optional atomic anchor committer (events + CF index + anchor in one batch)
+    anchor_committer: Option<Arc<dyn AnchorCommitBackend>>,
 
     /// Channel for sending consensus messages to network
     message_tx: mpsc::Sender<ConsensusMessage>,
@@ -100,9 +140,13 @@ pub struct ConsensusValidator {
     message_rx: Arc<Mutex<mpsc::Receiver<ConsensusMessage>>>,
     /// Broadcast channel for CF finalization notifications
     finalization_tx: broadcast::Sender<ConsensusFrame>,
+    /// Broadcast channel for finalized anchors (see `subscribe_finalized`)
+    anchor_finalization_tx: broadcast::Sender<Anchor>,
     /// R5: shared map of per-event apply outcomes (Applied / StaleRead / ExecutionFailed).
     /// Written by consensus via `DashMapOutcomeSink`, read by RPC handlers.
     execution_outcomes: Arc<dashmap::DashMap<String, ExecutionOutcome>>,
+    /// Latest anchor depth each peer reported and when (see `observe_peer_sync_state`)
+    peer_anchor_depths: Arc<dashmap::DashMap<PeerId, (u64, Instant)>>,
     /// Pending votes awaiting quorum (reserved for future use)
     #[allow(dead_code)]
     pending_votes: Arc<RwLock<HashMap<String, Vec<Vote>>>>,
@@ -120,6 +164,7 @@ impl ConsensusValidator {
     pub fn new(config: ConsensusValidatorConfig) -> Self {
         let (msg_tx, msg_rx) = mpsc::channel(config.message_buffer_size);
         let (finalization_tx, _) = broadcast::channel(100);
+        let (anchor_finalization_tx, _) = broadcast::channel(ANCHOR_FINALIZATION_CAPACITY);
         
         // Initialize validator set
         let mut validator_set = ValidatorSet::new();
@@ -142,10 +187,11 @@ impl ConsensusValidator {
             validator_set.clone(),
             Arc::new(SharedStateManager::new(GlobalStateManager::default())),  // Use default state manager
             Arc::clone(&event_store),        // Share the same EventStore!
-        ));
+        ).with_parent_selection(config.parent_selection));
 
         // Wire finalization broadcast channel into engine
         engine.set_finalization_tx(finalization_tx.clone());
+        engine.set_anchor_finalization_tx(anchor_finalization_tx.clone());
 
         // R5: wire outcome sink (shared between consensus writer and RPC reader).
         let outcomes_sink = Arc::new(DashMapOutcomeSink::new());
@@ -163,11 +209,14 @@ impl ConsensusValidator {
             event_store,  // Use the shared instance
             cf_store,
             cf_index_retries: Arc::new(parking_lot::Mutex::new(std::collections::HashMap::new())),
+            anchor_committer: None,
             anchor_store,
             message_tx: msg_tx,
             message_rx: Arc::new(Mutex::new(msg_rx)),
             finalization_tx,
+            anchor_finalization_tx,
             execution_outcomes,
+            peer_anchor_depths: Arc::new(dashmap::DashMap::new()),
             pending_votes: Arc::new(RwLock::new(HashMap::new())),
             running: Arc::new(RwLock::new(false)),
         }
@@ -184,6 +233,7 @@ impl ConsensusValidator {
     ) -> Self {
         let (msg_tx, msg_rx) = mpsc::channel(config.message_buffer_size);
         let (finalization_tx, _) = broadcast::channel(100);
+        let (anchor_finalization_tx, _) = broadcast::channel(ANCHOR_FINALIZATION_CAPACITY);
         
         let mut validator_set = ValidatorSet::new();
         let validator_info = ValidatorInfo::new(config.node_info.clone(), config.is_leader);
@@ -204,10 +254,11 @@ impl ConsensusValidator {
             validator_set.clone(),
             state_manager,
             Arc::clone(&event_store),  // Share the same EventStore!
-        ));
+        ).with_parent_selection(config.parent_selection));
 
         // Wire finalization broadcast channel into engine
         engine.set_finalization_tx(finalization_tx.clone());
+        engine.set_anchor_finalization_tx(anchor_finalization_tx.clone());
 
         // R5: wire outcome sink (shared between consensus writer and RPC reader).
         let outcomes_sink = Arc::new(DashMapOutcomeSink::new());
@@ -225,11 +276,14 @@ impl ConsensusValidator {
             event_store,  // Use the shared instance
             cf_store,
             cf_index_retries: Arc::new(parking_lot::Mutex::new(std::collections::HashMap::new())),
+            anchor_committer: None,
             anchor_store,
             message_tx: msg_tx,
             message_rx: Arc::new(Mutex::new(msg_rx)),
             finalization_tx,
+            anchor_finalization_tx,
             execution_outcomes,
+            peer_anchor_depths: Arc::new(dashmap::DashMap::new()),
             pending_votes: Arc::new(RwLock::new(HashMap::new())),
             running: Arc::new(RwLock::new(false)),
         }
@@ -253,6 +307,7 @@ impl ConsensusValidator {
     ) -> Self {
         let (msg_tx, msg_rx) = mpsc::channel(config.message_buffer_size);
         let (finalization_tx, _) = broadcast::channel(100);
+        let (anchor_finalization_tx, _) = broadcast::channel(ANCHOR_FINALIZATION_CAPACITY);
         
         let mut validator_set = ValidatorSet::new();
         let validator_info = ValidatorInfo::new(config.node_info.clone(), config.is_leader);
@@ -268,10 +323,11 @@ impl ConsensusValidator {
             validator_set.clone(),
             state_manager,
             Arc::clone(&event_store),  // Share the same EventStore!
-        ));
+        ).with_parent_selection(config.parent_selection));
 
         // Wire finalization broadcast channel into engine
         engine.set_finalization_tx(finalization_tx.clone());
+        engine.set_anchor_finalization_tx(anchor_finalization_tx.clone());
 
         // R5: wire outcome sink (shared between consensus writer and RPC reader).
         let outcomes_sink = Arc::new(DashMapOutcomeSink::new());
@@ -289,11 +345,14 @@ impl ConsensusValidator {
             event_store,
             cf_store,
             cf_index_retries: Arc::new(parking_lot::Mutex::new(std::collections::HashMap::new())),
+            anchor_committer: None,
             anchor_store,
             message_tx: msg_tx,
             message_rx: Arc::new(Mutex::new(msg_rx)),
             finalization_tx,
+            anchor_finalization_tx,
             execution_outcomes,
+            peer_anchor_depths: Arc::new(dashmap::DashMap::new()),
             pending_votes: Arc::new(RwLock::new(HashMap::new())),
             running: Arc::new(RwLock::new(false)),
         }
@@ -335,6 +394,7 @@ impl ConsensusValidator {
     ) -> Self {
         let (msg_tx, msg_rx) = mpsc::channel(config.message_buffer_size);
         let (finalization_tx, _) = broadcast::channel(100);
+        let (anchor_finalization_tx, _) = broadcast::channel(ANCHOR_FINALIZATION_CAPACITY);
         
         let mut validator_set = ValidatorSet::new();
         let validator_info = ValidatorInfo::new(config.node_info.clone(), config.is_leader);
@@ -347,10 +407,11 @@ impl ConsensusValidator {
             validator_set.clone(),
             state_manager,
             Arc::clone(&event_store),  // Share the same EventStore!
-        ));
+        ).with_parent_selection(config.parent_selection));
 
         // Wire finalization broadcast channel into engine
         engine.set_finalization_tx(finalization_tx.clone());
+        engine.set_anchor_finalization_tx(anchor_finalization_tx.clone());
 
         // R5: wire outcome sink (shared between consensus writer and RPC reader).
         let outcomes_sink = Arc::new(DashMapOutcomeSink::new());
@@ -368,11 +429,14 @@ impl ConsensusValidator {
             event_store,
             cf_store,
             cf_index_retries: Arc::new(parking_lot::Mutex::new(std::collections::HashMap::new())),
+            anchor_committer: None,
             anchor_store,
             message_tx: msg_tx,
             message_rx: Arc::new(Mutex::new(msg_rx)),
             finalization_tx,
+            anchor_finalization_tx,
             execution_outcomes,
+            peer_anchor_depths: Arc::new(dashmap::DashMap::new()),
             pending_votes: Arc::new(RwLock::new(HashMap::new())),
             running: Arc::new(RwLock::new(false)),
         }
@@ -412,7 +476,11 @@ impl ConsensusValidator {
             self.event_store.clone(),
             self.anchor_store.clone(),
             self.cf_store.clone(),
-        ));
+        )
+        .with_allowed_creators(self.config.allowed_creators.clone())
+        .with_creator_public_keys(self.config.creator_public_keys.clone())
+        .with_max_object_bytes(self.config.max_object_bytes)
+        .with_anchor_committer(self.anchor_committer.clone()));
         
         info!(
             validator_id = %self.config.node_info.id,
@@ -449,6 +517,25 @@ impl ConsensusValidator {
         self.event_store.get(&event_id).await
     }
     
+    /// Resolve a transfer from persistent storage, e.g. after a restart has
+    /// dropped the in-memory transfer tracker.
+    ///
+    /// Returns the event carrying the transfer and the finalized anchor that
+    /// contains it. The anchor is `None` when the event was stored (e.g. via
+    /// sync) but no persisted anchor includes it yet. The anchor is found
+    /// through the anchor store's persisted event index.
+    pub async fn find_persisted_transfer(&self, transfer_id: &str) -> Option<(Event, Option<Anchor>)> {
+        let event = self.event_store.get_by_transfer_id(transfer_id).await?;
+        let anchor = self.find_persisted_anchor_for_event(&event.id).await;
+        Some((event, anchor))
+    }
+
+    /// Finalized anchor containing `event_id`, from the persisted event index
+    pub async fn find_persisted_anchor_for_event(&self, event_id: &str) -> Option<Anchor> {
+        let anchor_id = self.anchor_store.get_anchor_id_for_event(event_id).await?;
+        self.anchor_store.get(&anchor_id).await
+    }
+
     /// Get the anchor store (for queries of finalized anchors)
     pub fn anchor_store(&self) -> Arc<dyn AnchorStoreBackend> {
         Arc::clone(&self.anchor_store)
@@ -521,6 +608,10 @@ impl ConsensusValidator {
         if let Some((chain_root, depth, count, vlc_time)) = 
             self.anchor_store.get_recovery_state().await 
         {
+            if self.config.verify_anchor_chain_on_recovery {
+                self.verify_anchor_chain(&chain_root, depth).await?;
+            }
+
             let mut cm = self.engine.consensus_manager().write().await;
             cm.anchor_builder_mut().restore_state(chain_root, depth, count, vlc_time);
             drop(cm);
@@ -558,11 +649,52 @@ impl ConsensusValidator {
                 latest_anchor.vlc_snapshot.logical_time
             );
         }
+
+        // 5. Seed the light-client tip from the newest finalized CF
+        if let Some(cf) = self.cf_store.latest_finalized().await {
+            self.engine.record_finalized_tip(&cf);
+        }
         
         info!("Recovery from storage completed successfully");
         Ok(())
     }
 
+    /// Recompute the anchor chain root over every stored anchor up to
+    /// `depth` and check it against the recovered `chain_root`.
+    ///
+    /// Anchors are read one depth at a time and folded into the running
+    /// root, so memory stays flat however long the chain is.
+    async fn verify_anchor_chain(&self, chain_root: &[u8; 32], depth: u64) -> SetuResult<()> {
+        // A missing anchor breaks the linkage of the next one, so gaps are
+        // reported by the audit below
+        let mut audit = consensus::AnchorChainAudit::new();
+        for d in 0..=depth {
+            let Some(anchor) = self.anchor_store.get_by_depth(d).await else {
+                continue;
+            };
+            audit.push(&anchor).map_err(|m| {
+                SetuError::InvalidData(format!(
+                    "anchor chain audit: anchor at depth {} commits chain root {} but its predecessors give {}",
+                    m.depth,
+                    hex::encode(m.committed),
+                    hex::encode(m.expected)
+                ))
+            })?;
+        }
+
+        let recomputed = audit.root();
+        if &recomputed != chain_root {
+            return Err(SetuError::InvalidData(format!(
+                "anchor chain audit: recovered chain root {} != recomputed {}",
+                hex::encode(chain_root),
+                hex::encode(recomputed)
+            )));
+        }
+
+        info!("Anchor chain audit passed over {} anchors", audit.anchor_count());
+        Ok(())
+    }
+
     // =========================================================================
     // Core Operations
     // =========================================================================
@@ -592,6 +724,18 @@ impl ConsensusValidator {
             ));
         }
         
+        // Step 0.5: Permissioned mode — only allowlisted creators may submit
+        self.check_creator_allowed(&event).await?;
+        
+        // Step 0.6: Authenticate the creator (when signature checks are enabled)
+        self.check_event_signature(&event).await?;
+        
+        // Step 0.7: The VLC must follow every parent's
+        self.check_vlc_order(&event).await?;
+        
+        // Step 0.8: No state change may write an oversized object
+        self.check_object_sizes(&event)?;
+        
         // Step 1: Verify execution result is present and successful
         // TEE attestation verification is done by the TeeVerifier when enabled
         if let Some(ref exec_result) = event.execution_result {
@@ -653,6 +797,17 @@ impl ConsensusValidator {
             "Receiving event from network"
         );
         
+        // Same admission checks as the network router
+        if !event.verify_id() {
+            return Err(SetuError::InvalidData(
+                format!("Event ID verification failed - possible tampering: {}", event.id)
+            ));
+        }
+        self.check_creator_allowed(&event).await?;
+        self.check_event_signature(&event).await?;
+        self.check_vlc_order(&event).await?;
+        self.check_object_sizes(&event)?;
+        
         // Use the dedicated network receive method (no re-broadcast)
         // Event stays in DAG memory until CF is finalized
         let event_id = self.engine.receive_event_from_network(event).await?;
@@ -798,12 +953,81 @@ impl ConsensusValidator {
         );
     }
     
+    /// Schedule a validator set change applied at the CF boundary that enters
+    /// `change.activation_round`; quorum is recomputed at the same point.
+    ///
+    /// The change must already carry approvals from a quorum of validators.
+    pub async fn schedule_validator_set_change(&self, change: ValidatorSetChange) -> SetuResult<()> {
+        self.engine.schedule_validator_set_change(change).await
+    }
+    
+    /// Add this node's approval to a proposed validator set change
+    pub async fn approve_validator_set_change(&self, change: &mut ValidatorSetChange) -> SetuResult<()> {
+        self.engine.approve_validator_set_change(change).await
+    }
+    
     /// Get validator count
     pub async fn validator_count(&self) -> usize {
         let vs = self.validator_set.read().await;
         vs.all_validators().len()
     }
     
+    // =========================================================================
+    // Creator Allowlist
+    // =========================================================================
+    
+    /// Reject events whose creator is not in `config.allowed_creators`.
+    ///
+    /// See [`check_creator_allowed`]; the network router applies the same check.
+    async fn check_creator_allowed(&self, event: &Event) -> SetuResult<()> {
+        check_creator_allowed(self.config.allowed_creators.as_ref(), &self.engine, event)
+            .await
+            .map_err(|e| SetuError::InvalidData(e.to_string()))
+    }
+    
+    // =========================================================================
+    // Event Signatures
+    // =========================================================================
+    
+    /// Reject events not signed by their creator's key.
+    ///
+    /// See [`check_event_signature`]; the network router applies the same check.
+    async fn check_event_signature(&self, event: &Event) -> SetuResult<()> {
+        check_event_signature(self.config.creator_public_keys.as_ref(), &self.engine, event)
+            .await
+            .map_err(|e| SetuError::InvalidData(e.to_string()))
+    }
+    
+    /// Sign an event this validator created with the node key.
+    ///
+    /// Validator-created events (transfers built by the task preparer,
+    /// registrations, governance) are signed here once their execution result
+    /// is attached, right before submission. Events attributed to another
+    /// creator are left untouched. No-op until the engine has a private key.
+    pub async fn sign_local_event(&self, event: &mut Event) {
+        if event.creator != self.config.node_info.id {
+            return;
+        }
+        self.engine.sign_local_event(event).await;
+    }
+    
+    /// Reject events whose VLC does not follow their parents'.
+    ///
+    /// See [`check_vlc_order`]; the network router applies the same check.
+    async fn check_vlc_order(&self, event: &Event) -> SetuResult<()> {
+        check_vlc_order(&self.engine, event)
+            .await
+            .map_err(|e| SetuError::InvalidData(e.to_string()))
+    }
+    
+    /// Reject events writing an object over `config.max_object_bytes`.
+    ///
+    /// See [`check_object_sizes`]; the network router applies the same check.
+    fn check_object_sizes(&self, event: &Event) -> SetuResult<()> {
+        check_object_sizes(self.config.max_object_bytes, event)
+            .map_err(|e| SetuError::InvalidData(e.to_string()))
+    }
+    
     // =========================================================================
     // TEE Verification
     // =========================================================================
@@ -834,6 +1058,16 @@ impl ConsensusValidator {
         self.engine.get_dag_stats().await
     }
     
+    /// Pending events no anchor has folded for `anchor_threshold` anchors
+    pub async fn orphaned_events(&self, anchor_threshold: u64) -> Vec<OrphanedEvent> {
+        self.engine.get_orphaned_events(anchor_threshold).await
+    }
+    
+    /// The active DAG as Graphviz DOT, bounded to `max_events` events
+    pub async fn export_dag_dot(&self, max_events: usize) -> String {
+        self.engine.export_dag_dot(max_events).await
+    }
+    
     /// Get current VLC snapshot (read-only)
     pub async fn vlc_snapshot(&self) -> setu_vlc::VLCSnapshot {
         self.engine.get_vlc_snapshot().await
@@ -866,11 +1100,21 @@ impl ConsensusValidator {
         self.engine.get_tips().await
     }
     
+    /// Pick parents for a new event using the configured strategy
+    pub async fn select_parents(&self) -> Vec<EventId> {
+        self.engine.select_parents().await
+    }
+    
     /// Get anchor count
     pub async fn anchor_count(&self) -> usize {
         self.engine.get_anchor_count().await
     }
     
+    /// Get the number of CFs held in consensus memory
+    pub async fn in_memory_cf_count(&self) -> usize {
+        self.engine.get_in_memory_cf_count().await
+    }
+    
     /// Get global state root
     pub async fn global_state_root(&self) -> [u8; 32] {
         self.engine.get_global_state_root().await
@@ -900,6 +1144,17 @@ impl ConsensusValidator {
         self.finalization_tx.subscribe()
     }
 
+    /// Subscribe to finalized anchors, delivered in finalization order once
+    /// each anchor is durably persisted.
+    ///
+    /// The channel is bounded ([`ANCHOR_FINALIZATION_CAPACITY`]); finalization
+    /// never waits for subscribers. A consumer that falls behind receives
+    /// `RecvError::Lagged(n)` and should resync missed anchors from the
+    /// anchor store.
+    pub fn subscribe_finalized(&self) -> broadcast::Receiver<Anchor> {
+        self.anchor_finalization_tx.subscribe()
+    }
+
     /// Rebuild the finalization broadcast channel with a caller-provided capacity.
     ///
     /// This is primarily for lag/catch-up tests that need a tiny buffer. It is a
@@ -912,6 +1167,16 @@ impl ConsensusValidator {
         self
     }
 
+    /// Persist finalized anchors through `committer` in one atomic write.
+    ///
+    /// The committer must share the CF store passed to the constructor so CF
+    /// index sequence numbers stay consistent. The network event handler
+    /// persists through the same committer.
+    pub fn with_anchor_committer(mut self, committer: Arc<dyn AnchorCommitBackend>) -> Self {
+        self.anchor_committer = Some(committer);
+        self
+    }
+
     /// Heartbeat: periodically try to create CF for events stuck below vlc_delta_threshold.
     /// Called by background timer in main.rs. No-op if not Leader or no stale events.
     pub async fn try_heartbeat(&self, heartbeat_interval: std::time::Duration) -> SetuResult<()> {
@@ -932,6 +1197,73 @@ impl ConsensusValidator {
 
         Ok(())
     }
+
+    /// Spawn the fold timer that calls [`Self::try_heartbeat`] with
+    /// `max_fold_interval_ms`, so a leader with pending events proposes a CF
+    /// at least that often regardless of the VLC delta.
+    ///
+    /// Returns `None` when `max_fold_interval_ms` is 0. The timer polls at a
+    /// quarter of the interval, so a fold is at most a quarter late.
+    pub fn spawn_fold_timer(self: &Arc<Self>) -> Option<tokio::task::JoinHandle<()>> {
+        let interval_ms = self.config.consensus.max_fold_interval_ms;
+        if interval_ms == 0 {
+            return None;
+        }
+        let fold_interval = std::time::Duration::from_millis(interval_ms);
+        let poll_interval = (fold_interval / 4).max(std::time::Duration::from_millis(1));
+        let validator = Arc::clone(self);
+        Some(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(poll_interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = validator.try_heartbeat(fold_interval).await {
+                    debug!(error = %e, "Fold timer CF attempt failed");
+                }
+            }
+        }))
+    }
+    
+    /// Flush consensus state before the process exits.
+    ///
+    /// Folds every event still below the VLC threshold into a final CF
+    /// (leader only, finalizes inline in single-node mode), persists anchors
+    /// awaiting storage, then flushes the event/CF/anchor stores so a restart
+    /// recovers from a consistent snapshot. Events that cannot be finalized
+    /// locally (follower, or quorum not reached) stay in memory and are lost,
+    /// exactly as on a crash; their submitters must retry.
+    pub async fn shutdown(&self) -> SetuResult<()> {
+        *self.running.write().await = false;
+
+        if let Err(e) = self.engine.try_create_cf_heartbeat(std::time::Duration::ZERO).await {
+            warn!(error = %e, "Final CF fold on shutdown failed");
+        }
+
+        let mut any_persisted = false;
+        for anchor in self.engine.take_pending_anchors().await {
+            match self.persist_finalized_anchor(&anchor).await {
+                Ok(()) => { any_persisted = true; }
+                Err(e) => {
+                    warn!(
+                        anchor_id = %anchor.id,
+                        error = %e,
+                        "Failed to persist anchor on shutdown"
+                    );
+                }
+            }
+        }
+        if any_persisted {
+            if let Err(e) = self.engine.complete_pending_finalizations().await {
+                warn!(error = %e, "complete_pending_finalizations failed on shutdown");
+            }
+        }
+
+        self.event_store.flush().await?;
+        self.cf_store.flush().await?;
+        self.anchor_store.flush().await?;
+        info!("Consensus state flushed");
+        Ok(())
+    }
     
     /// Get the local validator ID
     pub fn validator_id(&self) -> &str {
@@ -955,10 +1287,166 @@ pub struct ConsensusValidatorStats {
     pub dag_max_depth: u64,
     pub dag_tip_count: usize,
     pub anchor_count: usize,
+    pub in_memory_cf_count: usize,
     pub vlc_logical_time: u64,
 }
 
+/// Live consensus position of this validator, served over HTTP for operators
+#[derive(Debug, Clone)]
+pub struct ConsensusStatus {
+    pub round: Round,
+    pub current_leader: Option<String>,
+    pub is_leader: bool,
+    pub validator_count: usize,
+    pub quorum: usize,
+    pub last_finalized_anchor: Option<String>,
+}
+
+/// One validator in the consensus set, as seen by this node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorSetEntry {
+    pub id: String,
+    pub voting_power: VotingPower,
+    /// Valid proposer for the current round
+    pub is_current_leader: bool,
+    /// Next round after the current one in which this validator proposes
+    pub round_as_leader_next: Option<Round>,
+}
+
+/// How far this validator's finalized anchor chain trails the network
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncProgress {
+    /// Depth of the newest anchor in the local anchor store
+    pub local_max_anchor_depth: u64,
+    /// Highest anchor depth known anywhere (peers or local)
+    pub network_max_anchor_depth: u64,
+    /// Anchors still to catch up on
+    pub lag: u64,
+}
+
+impl SyncProgress {
+    /// Whether the local chain is within `max_lag` anchors of the network
+    pub fn is_caught_up(&self, max_lag: u64) -> bool {
+        self.lag <= max_lag
+    }
+}
+
 impl ConsensusValidator {
+    /// Get the current round, leader and quorum as seen by the engine.
+    ///
+    /// Reads the engine's validator set (not the local mirror) so the numbers
+    /// match what consensus actually enforces.
+    pub async fn consensus_status(&self) -> ConsensusStatus {
+        let (round, current_leader, is_leader, validator_count, quorum) = {
+            let vs = self.engine.validator_set_ref().read().await;
+            let round = vs.current_round();
+            (
+                round,
+                vs.get_valid_proposer(round),
+                vs.is_valid_proposer(self.validator_id(), round),
+                vs.count(),
+                vs.quorum_size(),
+            )
+        };
+
+        ConsensusStatus {
+            round,
+            current_leader,
+            is_leader,
+            validator_count,
+            quorum,
+            last_finalized_anchor: self.engine.last_finalized_anchor_id().await,
+        }
+    }
+
+    /// Compact summary of the newest durably finalized anchor
+    pub fn finalized_tip(&self) -> Option<consensus::FinalizedTip> {
+        self.engine.finalized_tip()
+    }
+
+    /// Vote certificate of the CF that finalized `anchor_id`, as persisted
+    /// with the CF
+    pub async fn vote_certificate(&self, anchor_id: &str) -> Option<setu_types::VoteCertificate> {
+        self.cf_store.get_vote_certificate(&anchor_id.to_string()).await
+    }
+
+    /// Consensus configuration the engine is running with
+    pub fn consensus_config(&self) -> ConsensusConfig {
+        *self.engine.config()
+    }
+
+    /// Validators in the engine's set with their voting power and proposer
+    /// schedule, sorted by id
+    pub async fn validator_set_snapshot(&self) -> Vec<ValidatorSetEntry> {
+        let vs = self.engine.validator_set_ref().read().await;
+        let round = vs.current_round();
+        let leader = vs.get_valid_proposer(round);
+        let mut entries: Vec<ValidatorSetEntry> = vs
+            .all_validator_ids()
+            .into_iter()
+            .map(|id| ValidatorSetEntry {
+                voting_power: vs.get_voting_power(&id),
+                is_current_leader: leader.as_deref() == Some(id.as_str()),
+                round_as_leader_next: vs.next_proposer_round(&id, round),
+                id,
+            })
+            .collect();
+        entries.sort_by(|a, b| a.id.cmp(&b.id));
+        entries
+    }
+
+    /// Record the anchor depth a peer reported in its sync state.
+    ///
+    /// Each peer's latest report replaces its previous one, so a peer that
+    /// falls behind (or rolls back) lowers its own contribution.
+    pub fn observe_peer_sync_state(&self, peer_id: PeerId, info: &PeerSyncInfo) {
+        self.observe_peer_anchor_depth(peer_id, info.highest_anchor_depth, Instant::now());
+    }
+
+    /// Drop a peer's report, e.g. once it disconnects
+    pub fn forget_peer(&self, peer_id: &PeerId) {
+        self.peer_anchor_depths.remove(peer_id);
+    }
+
+    fn observe_peer_anchor_depth(&self, peer_id: PeerId, depth: u64, observed_at: Instant) {
+        self.peer_anchor_depths.insert(peer_id, (depth, observed_at));
+    }
+
+    /// Highest anchor depth among reports younger than `PEER_SYNC_STATE_TTL`.
+    ///
+    /// Expired reports are pruned so one peer that once ran ahead cannot
+    /// hold the node unready forever.
+    fn network_anchor_depth(&self) -> u64 {
+        let now = Instant::now();
+        self.peer_anchor_depths
+            .retain(|_, (_, observed_at)| now.duration_since(*observed_at) < PEER_SYNC_STATE_TTL);
+        self.peer_anchor_depths
+            .iter()
+            .map(|entry| entry.value().0)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Compare the local anchor chain against the highest depth peers reported.
+    ///
+    /// Before any peer has answered (or once every report expired), the
+    /// network max is the local depth and the lag is zero.
+    pub async fn sync_progress(&self) -> SyncProgress {
+        let local_max_anchor_depth = self
+            .anchor_store
+            .get_latest()
+            .await
+            .map(|anchor| anchor.depth)
+            .unwrap_or(0);
+        let network_max_anchor_depth = self.network_anchor_depth().max(local_max_anchor_depth);
+
+        SyncProgress {
+            local_max_anchor_depth,
+            network_max_anchor_depth,
+            lag: network_max_anchor_depth - local_max_anchor_depth,
+        }
+    }
+
     /// Get comprehensive statistics
     pub async fn stats(&self) -> ConsensusValidatorStats {
         let dag_stats = self.dag_stats().await;
@@ -973,6 +1461,7 @@ impl ConsensusValidator {
             dag_max_depth: dag_stats.max_depth,
             dag_tip_count: dag_stats.tip_count,
             anchor_count: self.anchor_count().await,
+            in_memory_cf_count: self.in_memory_cf_count().await,
             vlc_logical_time: vlc.logical_time,
         }
     }
@@ -1002,6 +1491,162 @@ impl FinalizationPersister for ConsensusValidator {
     fn cf_index_retries(&self) -> &Arc<parking_lot::Mutex<std::collections::HashMap<setu_types::CFId, u32>>> {
         &self.cf_index_retries
     }
+
+    fn anchor_committer(&self) -> Option<&Arc<dyn AnchorCommitBackend>> {
+        self.anchor_committer.as_ref()
+    }
+}
+
+/// Feeds anchor depths learned by anemo state sync into `sync_progress`
+impl PeerSyncObserver for ConsensusValidator {
+    fn peer_updated(&self, peer_id: PeerId, info: &PeerSyncInfo) {
+        self.observe_peer_sync_state(peer_id, info);
+    }
+
+    fn peer_removed(&self, peer_id: &PeerId) {
+        self.forget_peer(peer_id);
+    }
+}
+
+/// Permissioned-mode creator check shared by local submission and network ingress
+///
+/// `None` accepts every creator. Otherwise the creator must be allowlisted,
+/// or the event must carry a valid signature from a key in the engine's
+/// validator set (which includes the genesis validators). Genesis and
+/// validator-internal events pass only through that signature, never by
+/// event type alone.
+pub(crate) async fn check_creator_allowed(
+    allowed: Option<&HashSet<String>>,
+    engine: &ConsensusEngine,
+    event: &Event,
+) -> Result<(), ValidationError> {
+    let Some(allowed) = allowed else {
+        return Ok(());
+    };
+    if allowed.contains(&event.creator) {
+        return Ok(());
+    }
+    if !event.signature.is_empty() {
+        let vs = engine.validator_set_ref().read().await;
+        let signed_by_validator = vs.all_validators().into_iter().any(|v| {
+            !v.node.public_key.is_empty() && event.verify_signature(&v.node.public_key)
+        });
+        if signed_by_validator {
+            return Ok(());
+        }
+    }
+    warn!(
+        event_id = %event.id,
+        creator = %event.creator,
+        "Rejecting event from creator not in allowlist"
+    );
+    Err(ValidationError::InvalidCreator(event.creator.clone()))
+}
+
+/// Reject events not signed by their creator's key.
+///
+/// Shared by local submission and the network router. `None` skips the
+/// check. Otherwise the key is the creator's registered key, else the
+/// validator set entry with the creator's id; Genesis and System events with
+/// neither fall back to this node's own key.
+pub(crate) async fn check_event_signature(
+    creator_public_keys: Option<&HashMap<String, Vec<u8>>>,
+    engine: &ConsensusEngine,
+    event: &Event,
+) -> Result<(), ValidationError> {
+    let Some(registered) = creator_public_keys else {
+        return Ok(());
+    };
+    
+    let public_key = match registered.get(&event.creator) {
+        Some(key) => Some(key.clone()),
+        None => {
+            let vs = engine.validator_set_ref().read().await;
+            let key_of = |id: &str| {
+                vs.all_validators()
+                    .into_iter()
+                    .find(|v| v.node.id == id)
+                    .map(|v| v.node.public_key.clone())
+            };
+            key_of(&event.creator).or_else(|| {
+                matches!(event.event_type, EventType::Genesis | EventType::System)
+                    .then(|| key_of(engine.local_validator_id()))
+                    .flatten()
+            })
+        }
+    };
+    
+    if let Some(public_key) = public_key.filter(|k| !k.is_empty()) {
+        if event.verify_signature(&public_key) {
+            return Ok(());
+        }
+    }
+    
+    warn!(
+        event_id = %event.id,
+        creator = %event.creator,
+        "Rejecting event with missing or invalid creator signature"
+    );
+    Err(ValidationError::InvalidSignature(event.creator.clone()))
+}
+
+/// Reject events whose VLC does not happen after their parents'.
+///
+/// Shared by local submission and the network router. The DAG runs the same
+/// check on insert; running it first keeps a bad VLC out of the engine's clock.
+pub(crate) async fn check_vlc_order(
+    engine: &ConsensusEngine,
+    event: &Event,
+) -> Result<(), ValidationError> {
+    engine.check_vlc_order(event).await.map_err(|e| {
+        warn!(
+            event_id = %event.id,
+            error = %e,
+            "Rejecting event with VLC not after its parents"
+        );
+        ValidationError::InvalidVLC
+    })
+}
+
+/// Reject events whose state changes write an object larger than
+/// `max_object_bytes`.
+///
+/// Shared by local submission and the network router. State apply skips such
+/// events under the same genesis cap; checking first keeps them out of the DAG.
+pub(crate) fn check_object_sizes(
+    max_object_bytes: usize,
+    event: &Event,
+) -> Result<(), ValidationError> {
+    let Some(result) = &event.execution_result else {
+        return Ok(());
+    };
+    for change in &result.state_changes {
+        let size = change.new_value.as_ref().map_or(0, Vec::len);
+        if size > max_object_bytes {
+            warn!(
+                event_id = %event.id,
+                key = %change.key,
+                size,
+                max = max_object_bytes,
+                "Rejecting event that writes an oversized object"
+            );
+            return Err(ValidationError::ObjectTooLarge {
+                key: change.key.clone(),
+                size,
+                max: max_object_bytes,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Task preparers fall back to the engine's parent-selection strategy
+impl crate::task_preparer::ParentSelector for ConsensusValidator {
+    fn select_parents(&self) -> Result<Vec<EventId>, crate::task_preparer::TaskPrepareError> {
+        self.engine
+            .try_select_parents()
+            .map_err(|e| crate::task_preparer::TaskPrepareError::ParentSelectionFailed(e.to_string()))
+    }
 }
 
 /// Event handler for processing consensus messages in a background loop
@@ -1088,6 +1733,13 @@ impl ConsensusMessageHandler {
             ConsensusMessage::LeaderChanged { round, new_leader } => {
                 info!(round = round, new_leader = %new_leader, "Leader changed");
             }
+            ConsensusMessage::ValidatorSetChange(change) => {
+                info!(
+                    activation_round = change.activation_round,
+                    change = ?change.kind,
+                    "Validator set change scheduled"
+                );
+            }
         }
     }
     
@@ -1119,6 +1771,11 @@ mod tests {
             ),
             is_leader: true,
             message_buffer_size: 100,
+            parent_selection: ParentSelectionStrategy::default(),
+            allowed_creators: None,
+            creator_public_keys: None,
+            verify_anchor_chain_on_recovery: false,
+            max_object_bytes: setu_storage::DEFAULT_MAX_OBJECT_BYTES,
         }
     }
     
@@ -1156,6 +1813,267 @@ mod tests {
         assert_eq!(stats.node_count, 1);
     }
 
+    fn create_test_transfer_event(creator: &str, logical_time: u64) -> Event {
+        let mut event = Event::new(
+            EventType::Transfer,
+            vec![],
+            setu_vlc::VLCSnapshot {
+                vector_clock: VectorClock::new(),
+                logical_time,
+                physical_time: logical_time,
+            },
+            creator.to_string(),
+        );
+        event.set_execution_result(setu_types::ExecutionResult::success());
+        event
+    }
+
+    #[tokio::test]
+    async fn test_permissionless_mode_accepts_any_creator() {
+        let config = create_test_config();
+        assert!(config.allowed_creators.is_none());
+        let validator = ConsensusValidator::new(config);
+
+        validator.submit_event(create_test_transfer_event("solver-1", 1)).await.unwrap();
+        validator.submit_event(create_test_transfer_event("anyone", 2)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_submit_event_rejects_oversized_object() {
+        let mut config = create_test_config();
+        config.max_object_bytes = 64;
+        let validator = ConsensusValidator::new(config);
+        let with_object = |logical_time: u64, size: usize| {
+            let mut event = create_test_transfer_event("solver-1", logical_time);
+            let key = format!("oid:{}", hex::encode([logical_time as u8; 32]));
+            event.set_execution_result(setu_types::ExecutionResult::success().with_changes(vec![
+                setu_types::event::StateChange::insert(key, vec![0u8; size]),
+            ]));
+            event
+        };
+
+        validator.submit_event(with_object(1, 64)).await.unwrap();
+
+        let err = validator.submit_event(with_object(2, 65)).await.unwrap_err();
+        assert!(err.to_string().contains("is 65 bytes, exceeding the 64-byte limit"), "{}", err);
+        assert!(validator.receive_event(with_object(3, 65)).await.is_err());
+        assert_eq!(validator.dag_stats().await.node_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_permissioned_mode_rejects_unlisted_creator() {
+        let mut config = create_test_config();
+        config.allowed_creators = Some(HashSet::from(["solver-1".to_string()]));
+        let validator = ConsensusValidator::new(config);
+
+        validator.submit_event(create_test_transfer_event("solver-1", 1)).await.unwrap();
+
+        let err = validator
+            .submit_event(create_test_transfer_event("intruder", 2))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid event creator: intruder"));
+
+        // The event type alone does not bypass the allowlist
+        assert!(validator.submit_event(create_test_event("genesis")).await.is_err());
+        let mut system = create_test_transfer_event("validator-2", 3);
+        system.event_type = EventType::System;
+        assert!(validator.submit_event(system).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_permissioned_mode_admits_validator_signed_events() {
+        let node_key = ed25519_dalek::SigningKey::from_bytes(&[8u8; 32]);
+        let outsider_key = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+        let mut config = create_test_config();
+        config.node_info.public_key = node_key.verifying_key().as_bytes().to_vec();
+        config.allowed_creators = Some(HashSet::from(["solver-1".to_string()]));
+        let validator = ConsensusValidator::new(config);
+
+        // Genesis signed by a validator key is admitted
+        let mut genesis = create_test_event("genesis");
+        genesis.sign(&node_key.to_bytes()).unwrap();
+        validator.submit_event(genesis).await.unwrap();
+
+        // A transfer by an unlisted creator passes only with a validator signature
+        let mut forged = create_test_transfer_event("test-validator", 1);
+        forged.sign(&outsider_key.to_bytes()).unwrap();
+        assert!(validator.submit_event(forged).await.is_err());
+        let mut signed = create_test_transfer_event("test-validator", 2);
+        signed.sign(&node_key.to_bytes()).unwrap();
+        validator.submit_event(signed).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_signed_event_from_registered_creator_accepted() {
+        let solver_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let node_key = ed25519_dalek::SigningKey::from_bytes(&[8u8; 32]);
+        let mut config = create_test_config();
+        config.node_info.public_key = node_key.verifying_key().as_bytes().to_vec();
+        config.creator_public_keys = Some(HashMap::from([(
+            "solver-1".to_string(),
+            solver_key.verifying_key().as_bytes().to_vec(),
+        )]));
+        let validator = ConsensusValidator::new(config);
+
+        let mut event = create_test_transfer_event("solver-1", 1);
+        event.sign(&solver_key.to_bytes()).unwrap();
+        validator.submit_event(event).await.unwrap();
+
+        // Genesis is signed with the node's own key
+        let mut genesis = create_test_event("genesis");
+        genesis.sign(&node_key.to_bytes()).unwrap();
+        validator.submit_event(genesis).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_forged_creator_event_rejected() {
+        let solver_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let attacker_key = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+        let mut config = create_test_config();
+        config.creator_public_keys = Some(HashMap::from([(
+            "solver-1".to_string(),
+            solver_key.verifying_key().as_bytes().to_vec(),
+        )]));
+        let validator = ConsensusValidator::new(config);
+
+        let mut forged = create_test_transfer_event("solver-1", 1);
+        forged.sign(&attacker_key.to_bytes()).unwrap();
+        let err = validator.submit_event(forged).await.unwrap_err();
+        assert!(err.to_string().contains("Invalid event signature from creator: solver-1"));
+
+        // Unsigned events and creators without a registered key are rejected too
+        let err = validator
+            .submit_event(create_test_transfer_event("solver-1", 2))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid event signature"));
+        let mut unknown = create_test_transfer_event("intruder", 3);
+        unknown.sign(&attacker_key.to_bytes()).unwrap();
+        assert!(validator.submit_event(unknown).await.is_err());
+
+        assert_eq!(validator.dag_stats().await.node_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_event_with_vlc_behind_parent_rejected() {
+        let mut config = create_test_config();
+        config.consensus.vlc_delta_threshold = 1_000;
+        let validator = ConsensusValidator::new(config);
+        let stamped = |parents: Vec<EventId>, tick: u64| {
+            let mut clock = VectorClock::new();
+            clock.set("solver-1", tick);
+            let mut event = Event::new(
+                EventType::Transfer,
+                parents,
+                setu_vlc::VLCSnapshot { vector_clock: clock, logical_time: tick, physical_time: tick },
+                "solver-1".to_string(),
+            );
+            event.set_execution_result(setu_types::ExecutionResult::success());
+            event
+        };
+
+        let parent_id = validator.submit_event(stamped(vec![], 5)).await.unwrap();
+        let err = validator
+            .submit_event(stamped(vec![parent_id.clone()], 1))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains(&ValidationError::InvalidVLC.to_string()));
+        assert!(validator.receive_event(stamped(vec![parent_id.clone()], 2)).await.is_err());
+        assert_eq!(validator.dag_stats().await.node_count, 1);
+
+        validator.submit_event(stamped(vec![parent_id], 6)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_validator_created_events_signed_with_node_key() {
+        let node_key = ed25519_dalek::SigningKey::from_bytes(&[8u8; 32]);
+        let mut config = create_test_config();
+        config.node_info.public_key = node_key.verifying_key().as_bytes().to_vec();
+        config.creator_public_keys = Some(HashMap::new());
+        let validator = ConsensusValidator::new(config);
+
+        // Without a key the event stays unsigned and is rejected
+        let mut event = create_test_transfer_event("test-validator", 1);
+        validator.sign_local_event(&mut event).await;
+        assert!(event.signature.is_empty());
+        assert!(validator.submit_event(event).await.is_err());
+
+        validator.engine().set_private_key(node_key.to_bytes().to_vec()).await;
+        let mut event = create_test_transfer_event("test-validator", 2);
+        validator.sign_local_event(&mut event).await;
+        validator.submit_event(event).await.unwrap();
+
+        // Events from other creators are never signed with the node key
+        let mut foreign = create_test_transfer_event("solver-1", 3);
+        validator.sign_local_event(&mut foreign).await;
+        assert!(foreign.signature.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_receive_event_rejects_unsigned_peer_event() {
+        let peer_key = ed25519_dalek::SigningKey::from_bytes(&[5u8; 32]);
+        let mut config = create_test_config();
+        config.creator_public_keys = Some(HashMap::new());
+        let validator = ConsensusValidator::new(config);
+        let mut peer = NodeInfo::new_validator("v2".to_string(), "127.0.0.1".to_string(), 9002);
+        peer.public_key = peer_key.verifying_key().as_bytes().to_vec();
+        validator.add_peer_validator(peer).await;
+
+        let err = validator
+            .receive_event(create_test_transfer_event("v2", 1))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid event signature from creator: v2"));
+        assert_eq!(validator.dag_stats().await.node_count, 0);
+
+        // Signed by the peer's validator-set key: admitted
+        let mut signed = create_test_transfer_event("v2", 2);
+        signed.sign(&peer_key.to_bytes()).unwrap();
+        validator.receive_event(signed).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fold_timer_forms_cf_below_vlc_threshold() {
+        let mut config = create_test_config();
+        config.consensus.vlc_delta_threshold = 1_000;
+        config.consensus.max_fold_interval_ms = 200;
+        let validator = Arc::new(ConsensusValidator::new(config));
+        let engine = validator.engine();
+
+        validator.submit_event(create_test_transfer_event("solver-1", 1)).await.unwrap();
+        assert_eq!(engine.get_anchor_count().await, 0);
+
+        let timer = validator.spawn_fold_timer().expect("fold timer enabled");
+        let wait_for_anchors = |count: usize| {
+            let engine = engine.clone();
+            async move {
+                while engine.get_anchor_count().await < count {
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                }
+            }
+        };
+        tokio::time::timeout(std::time::Duration::from_secs(2), wait_for_anchors(1))
+            .await
+            .expect("CF should form once the fold interval elapses");
+
+        // The next sub-threshold event waits for the interval, then folds
+        validator.submit_event(create_test_transfer_event("solver-1", 2)).await.unwrap();
+        assert_eq!(engine.get_anchor_count().await, 1);
+        tokio::time::timeout(std::time::Duration::from_secs(2), wait_for_anchors(2))
+            .await
+            .expect("second CF should form after the fold interval");
+        timer.abort();
+    }
+
+    #[tokio::test]
+    async fn test_fold_timer_disabled_with_zero_interval() {
+        let mut config = create_test_config();
+        config.consensus.max_fold_interval_ms = 0;
+        let validator = Arc::new(ConsensusValidator::new(config));
+        assert!(validator.spawn_fold_timer().is_none());
+    }
+
     #[tokio::test]
     async fn test_round_advancement() {
         let config = create_test_config();
@@ -1178,6 +2096,50 @@ mod tests {
         assert_eq!(stats.current_round, 0);
     }
 
+    #[tokio::test]
+    async fn test_consensus_status_single_node() {
+        let config = create_test_config();
+        let validator = ConsensusValidator::new(config);
+
+        let status = validator.consensus_status().await;
+        assert_eq!(status.round, 0);
+        assert!(status.is_leader);
+        assert_eq!(status.current_leader.as_deref(), Some("test-validator"));
+        assert_eq!(status.validator_count, 1);
+        assert_eq!(status.quorum, 1);
+        assert!(status.last_finalized_anchor.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_validator_set_snapshot_three_nodes() {
+        let mut config = create_test_config();
+        config.node_info.stake = 10;
+        let validator = ConsensusValidator::new(config);
+        for (id, stake) in [("validator-b", 20), ("validator-c", 30)] {
+            let mut node = NodeInfo::new_validator(id.to_string(), "127.0.0.1".to_string(), 8080);
+            node.stake = stake;
+            validator.add_peer_validator(node).await;
+        }
+
+        let snapshot = validator.validator_set_snapshot().await;
+        let ids: Vec<&str> = snapshot.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(ids, vec!["test-validator", "validator-b", "validator-c"]);
+        assert_eq!(snapshot.iter().map(|v| v.voting_power).sum::<VotingPower>(), 60);
+
+        let leaders: Vec<_> = snapshot.iter().filter(|v| v.is_current_leader).collect();
+        assert_eq!(leaders.len(), 1);
+        let status = validator.consensus_status().await;
+        assert_eq!(status.current_leader.as_deref(), Some(leaders[0].id.as_str()));
+
+        // Round-robin over three validators: each proposes within the next
+        // three rounds, the current leader three rounds from now
+        for entry in &snapshot {
+            let next = entry.round_as_leader_next.expect("every validator proposes");
+            assert!(next > status.round && next <= status.round + 3);
+        }
+        assert_eq!(leaders[0].round_as_leader_next, Some(status.round + 3));
+    }
+
     #[tokio::test]
     async fn test_f3_with_finalization_capacity_rewires_engine_sender() {
         let config = create_test_config();
@@ -1242,6 +2204,204 @@ mod tests {
         assert_eq!(validator.allocate_logical_time(), 73);
     }
     
+    #[tokio::test]
+    async fn test_sync_progress_tracks_lag_behind_peers() {
+        let validator = ConsensusValidator::new(create_test_config());
+        let anchor_store = validator.anchor_store();
+        let mut anchor = Anchor::with_merkle_roots(
+            vec!["event-0".to_string()],
+            setu_vlc::VLCSnapshot {
+                vector_clock: VectorClock::new(),
+                logical_time: 10,
+                physical_time: 0,
+            },
+            AnchorMerkleRoots::with_roots([1; 32], [2; 32], [3; 32]),
+            None,
+            4,
+        );
+        anchor.id = "anchor-4".to_string();
+        anchor_store.store(anchor).await.unwrap();
+
+        // No peer has reported yet: nothing to catch up on
+        let progress = validator.sync_progress().await;
+        assert_eq!(progress.local_max_anchor_depth, 4);
+        assert_eq!(progress.lag, 0);
+
+        let peer_a = PeerId([1; 32]);
+        let peer_b = PeerId([2; 32]);
+        let report = |depth| PeerSyncInfo {
+            highest_anchor_depth: depth,
+            ..Default::default()
+        };
+        validator.observe_peer_sync_state(peer_a, &report(12));
+        validator.observe_peer_sync_state(peer_b, &report(6));
+
+        let progress = validator.sync_progress().await;
+        assert_eq!(
+            progress,
+            SyncProgress {
+                local_max_anchor_depth: 4,
+                network_max_anchor_depth: 12,
+                lag: 8,
+            }
+        );
+        assert!(!progress.is_caught_up(2));
+
+        // Once the leading peer disconnects, the estimate follows the rest
+        validator.peer_removed(&peer_a);
+        assert_eq!(validator.sync_progress().await.network_max_anchor_depth, 6);
+        assert!(validator.sync_progress().await.is_caught_up(2));
+    }
+
+    #[tokio::test]
+    async fn test_sync_progress_ignores_expired_peer_reports() {
+        let validator = ConsensusValidator::new(create_test_config());
+        let peer = PeerId([3; 32]);
+        let Some(stale_at) = Instant::now().checked_sub(PEER_SYNC_STATE_TTL + Duration::from_secs(1)) else {
+            return; // monotonic clock too young to backdate
+        };
+
+        validator.observe_peer_anchor_depth(peer, 50, stale_at);
+        let progress = validator.sync_progress().await;
+        assert_eq!(progress.network_max_anchor_depth, 0);
+        assert_eq!(progress.lag, 0);
+
+        // A fresh report from the same peer counts again
+        validator.observe_peer_anchor_depth(peer, 50, Instant::now());
+        assert_eq!(validator.sync_progress().await.lag, 50);
+        assert!(!progress.is_caught_up(2));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_persists_pending_events_for_recovery() {
+        let mut config = create_test_config();
+        // Keep the event below the fold threshold so only shutdown can fold it
+        config.consensus.vlc_delta_threshold = 1_000;
+        let event_store: Arc<dyn EventStoreBackend> = Arc::new(EventStore::new());
+        let cf_store: Arc<dyn CFStoreBackend> = Arc::new(CFStore::new());
+        let anchor_store: Arc<dyn AnchorStoreBackend> = Arc::new(AnchorStore::new());
+        let validator = ConsensusValidator::with_all_backends(
+            config.clone(),
+            Arc::new(SharedStateManager::new(GlobalStateManager::default())),
+            event_store.clone(),
+            cf_store.clone(),
+            anchor_store.clone(),
+        );
+
+        let mut transfer = Event::new(
+            setu_types::EventType::Transfer,
+            vec![],
+            setu_vlc::VLCSnapshot {
+                vector_clock: VectorClock::new(),
+                logical_time: 1,
+                physical_time: 1,
+            },
+            "solver-1".to_string(),
+        );
+        transfer.set_execution_result(setu_types::ExecutionResult::success());
+        let event_id = validator.submit_event(transfer).await.unwrap();
+        assert_eq!(anchor_store.count().await, 0, "event must still be pending");
+
+        validator.shutdown().await.unwrap();
+
+        let anchor = anchor_store.get_latest().await.expect("shutdown must fold a final anchor");
+        assert!(anchor.event_ids.contains(&event_id));
+        assert!(event_store.get(&event_id).await.is_some());
+
+        // A restarted validator picks up from the flushed stores
+        let restarted = ConsensusValidator::with_all_backends(
+            config,
+            Arc::new(SharedStateManager::new(GlobalStateManager::default())),
+            event_store,
+            cf_store,
+            anchor_store,
+        );
+        restarted.recover_from_storage().await.unwrap();
+        assert_eq!(restarted.current_round().await, 1);
+        assert_eq!(
+            restarted.engine().dag_manager().min_depth(),
+            anchor.depth + 1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_finalized_streams_anchors_in_order() {
+        let mut config = create_test_config();
+        // Fold on every event so each submit finalizes its own anchor
+        config.consensus.vlc_delta_threshold = 1;
+        let anchor_store: Arc<dyn AnchorStoreBackend> = Arc::new(AnchorStore::new());
+        let validator = ConsensusValidator::with_all_backends(
+            config,
+            Arc::new(SharedStateManager::new(GlobalStateManager::default())),
+            Arc::new(EventStore::new()),
+            Arc::new(CFStore::new()),
+            anchor_store.clone(),
+        );
+        let mut finalized = validator.subscribe_finalized();
+
+        for logical_time in 1..=3 {
+            validator
+                .submit_event(create_test_transfer_event("solver-1", logical_time))
+                .await
+                .unwrap();
+        }
+
+        let mut received = Vec::new();
+        while let Ok(anchor) = finalized.try_recv() {
+            received.push(anchor);
+        }
+        assert!(received.len() >= 2, "expected several finalized anchors");
+        assert_eq!(received.len(), anchor_store.count().await);
+        for pair in received.windows(2) {
+            assert!(pair[0].depth < pair[1].depth, "anchors must arrive in depth order");
+        }
+        let latest = anchor_store.get_latest().await.unwrap();
+        assert_eq!(received.last().unwrap().id, latest.id);
+    }
+
+    #[tokio::test]
+    async fn test_find_persisted_transfer_after_restart() {
+        let mut config = create_test_config();
+        config.consensus.vlc_delta_threshold = 1;
+        let state_manager = Arc::new(SharedStateManager::new(GlobalStateManager::default()));
+        let event_store: Arc<dyn EventStoreBackend> = Arc::new(EventStore::new());
+        let cf_store: Arc<dyn CFStoreBackend> = Arc::new(CFStore::new());
+        let anchor_store: Arc<dyn AnchorStoreBackend> = Arc::new(AnchorStore::new());
+
+        let validator = ConsensusValidator::with_all_backends(
+            config.clone(),
+            Arc::clone(&state_manager),
+            Arc::clone(&event_store),
+            Arc::clone(&cf_store),
+            Arc::clone(&anchor_store),
+        );
+        for logical_time in 1..=3 {
+            let event = create_test_transfer_event("solver-1", logical_time).with_transfer(
+                setu_types::Transfer::new(&format!("tx-restart-{}", logical_time), "alice", "bob", 1),
+            );
+            validator.submit_event(event).await.unwrap();
+        }
+        drop(validator);
+
+        // Restart: a fresh validator over the same persistent stores
+        let restarted = ConsensusValidator::with_all_backends(
+            config,
+            state_manager,
+            event_store,
+            cf_store,
+            anchor_store,
+        );
+        let (event, anchor) = restarted
+            .find_persisted_transfer("tx-restart-1")
+            .await
+            .expect("finalized transfer must be persisted");
+        assert_eq!(event.transfer.as_ref().unwrap().id, "tx-restart-1");
+        let anchor = anchor.expect("containing anchor must be found");
+        assert!(anchor.event_ids.contains(&event.id));
+
+        assert!(restarted.find_persisted_transfer("tx-unknown").await.is_none());
+    }
+
     #[tokio::test]
     async fn test_network_event_handler_integration() {
         use crate::protocol::NetworkEvent;
@@ -1283,7 +2443,7 @@ mod tests {
     // ---- fix-post-restart-finality-stall-v2 (Layer A + Layer D) ----
 
     use async_trait::async_trait;
-    use setu_types::{CFId, SetuError, SetuResult};
+    use setu_types::{AnchorId, CFId, SetuError, SetuResult, VoteCertificate};
 
     /// CFStore stub that always fails on store(); used by F9 / Layer D test.
     #[derive(Debug)]
@@ -1296,6 +2456,8 @@ mod tests {
         }
         async fn get(&self, _cf_id: &CFId) -> Option<ConsensusFrame> { None }
         async fn mark_finalized(&self, _cf_id: &CFId) -> SetuResult<()> { Ok(()) }
+        async fn store_vote_certificate(&self, _cert: VoteCertificate) -> SetuResult<()> { Ok(()) }
+        async fn get_vote_certificate(&self, _anchor_id: &AnchorId) -> Option<VoteCertificate> { None }
         async fn get_pending(&self) -> Vec<ConsensusFrame> { Vec::new() }
         async fn get_finalized(&self) -> Vec<ConsensusFrame> { Vec::new() }
         async fn latest_finalized(&self) -> Option<ConsensusFrame> { None }
@@ -101,6 +101,7 @@ impl InfraExecutor {
             &registration.name,
             &owner,
             registration.token_symbol.as_deref(),
+            registration.token_config.clone().unwrap_or_default().decimals,
             registration.initial_token_supply,
             &ctx,
         ).map_err(|e| format!("Runtime error: {}", e))?;
@@ -1220,7 +1221,7 @@ mod tests {
             let mut gsm = shared.lock_write();
             let module_key = format!("mod:{}::{}", addr.to_hex_literal(), "counter");
             let sc = EventStateChange::insert(module_key, module_bytes.clone());
-            gsm.apply_state_change(SubnetId::ROOT, &sc);
+            gsm.apply_state_change(SubnetId::ROOT, &sc).unwrap();
             shared.publish_snapshot(&gsm);
         }
 
@@ -1315,8 +1316,8 @@ mod tests {
         let mut gsm = shared.lock_write();
         let sc1 = EventStateChange::insert(mod_key, module_bytes.to_vec());
         let sc2 = EventStateChange::insert(linkage_key, linkage_payload);
-        gsm.apply_state_change(SubnetId::ROOT, &sc1);
-        gsm.apply_state_change(SubnetId::ROOT, &sc2);
+        gsm.apply_state_change(SubnetId::ROOT, &sc1).unwrap();
+        gsm.apply_state_change(SubnetId::ROOT, &sc2).unwrap();
         shared.publish_snapshot(&gsm);
     }
 
@@ -43,34 +43,38 @@ pub mod network_adapter;
 pub mod persistence;
 pub mod protocol;
 pub mod coin_reservation;
+pub mod mempool;
 pub mod dag_replay;
 pub mod governance;
 pub mod outcome_sink;
 
-pub use router_manager::{RouterManager, RouterError, SolverConnection};
+pub use router_manager::{RouterManager, RouterError, SolverConnection, SolverSelectionStrategy};
 pub use network::{
     ValidatorNetworkService, ValidatorRegistrationHandler, NetworkServiceConfig,
     ValidatorInfo, TransferTracker, SubmitEventRequest, SubmitEventResponse,
     GetBalanceResponse, GetObjectResponse, current_timestamp_secs, current_timestamp_millis,
 };
 pub use task_preparer::{
-    TaskPreparer, TaskPrepareError,
+    TaskPreparer, TaskPrepareError, TransferAmountBounds, ParentSelector,
     BatchTaskPreparer, BatchPrepareResult, BatchPrepareStats,
+    PrepareStats, PrepareMetrics,
 };
 pub use user_handler::ValidatorUserHandler;
 pub use infra_executor::InfraExecutor;
 pub use coin_reservation::{CoinReservationManager, ReservationHandle};
+pub use mempool::{DispatchPermit, Mempool, MempoolMetrics, MempoolPolicy, TransferMempool};
 
 // Re-export consensus integration types
 pub use consensus_integration::{
     ConsensusValidator, ConsensusValidatorConfig, ConsensusValidatorStats,
-    ConsensusMessageHandler,
+    ConsensusMessageHandler, ConsensusStatus, SyncProgress,
 };
+pub use consensus::ParentSelectionStrategy;
 
 // Re-export broadcaster types
 pub use broadcaster::{
     AnemoConsensusBroadcaster, ConsensusBroadcaster, BroadcastError, BroadcastResult,
-    NoOpBroadcaster, MockBroadcaster,
+    NoOpBroadcaster, MockBroadcaster, RetryingBroadcaster, RetryPolicy,
 };
 
 // Re-export network adapter types
@@ -110,6 +114,9 @@ pub enum ValidationError {
     #[error("Invalid event creator: {0}")]
     InvalidCreator(String),
     
+    #[error("Invalid event signature from creator: {0}")]
+    InvalidSignature(String),
+    
     #[error("Event timestamp is in the future")]
     FutureTimestamp,
     
@@ -118,4 +125,7 @@ pub enum ValidationError {
     
     #[error("Invalid VLC snapshot")]
     InvalidVLC,
+    
+    #[error("Object {key} is {size} bytes, exceeding the {max}-byte limit")]
+    ObjectTooLarge { key: String, size: usize, max: usize },
 }
@@ -10,9 +10,10 @@
 use setu_core::NodeConfig;
 use setu_validator::{
     RouterManager, 
-    ValidatorNetworkService, NetworkServiceConfig,
-    ConsensusValidator, ConsensusValidatorConfig,
-    AnemoConsensusBroadcaster,
+    ValidatorNetworkService, NetworkServiceConfig, MempoolPolicy, TransferAmountBounds,
+    SolverSelectionStrategy,
+    ConsensusValidator, ConsensusValidatorConfig, ParentSelectionStrategy,
+    AnemoConsensusBroadcaster, RetryingBroadcaster, RetryPolicy,
     ConsensusEngineStore, SetuMessageHandler,
     NetworkEvent,
 };
@@ -23,14 +24,13 @@ use setu_network_anemo::{
     AnemoConfig, NetworkNodeInfo,
 };
 use setu_storage::{
-    SetuDB, RocksDBEventStore, RocksDBCFStore, RocksDBAnchorStore, RocksDBMerkleStore,
-    GlobalStateManager, SharedStateManager, EventStoreBackend, CFStoreBackend, AnchorStoreBackend, B4StoreExt,
-    MerkleStateProvider,
+    SetuDB, RocksDBConfig, ValueFormat, WalSyncMode, RocksDBEventStore, RocksDBCFStore, RocksDBAnchorStore, RocksDBMerkleStore,
+    RocksDBAnchorCommitter,
+    GlobalStateManager, SharedStateManager, EventStoreBackend, CFStoreBackend, AnchorStoreBackend, B4StoreExt, DEFAULT_MAX_OBJECT_BYTES,
 };
 use setu_types::{
     NodeInfo, ConsensusConfig, ConsensusFrame,
-    GenesisConfig, Event, EventPayload, ExecutionResult, StateChange,
-    CoinState, Address, VLCSnapshot,
+    GenesisConfig, Event, EventPayload, ExecutionResult, VLCSnapshot,
 };
 use setu_keys::{load_keypair};
 use std::sync::Arc;
@@ -159,6 +159,17 @@ impl ValidatorConfig {
     }
 }
 
+/// RNG for randomized solver selection. With the `deterministic-rng`
+/// feature, SOLVER_SELECTION_SEED seeds it so routing decisions replay.
+fn solver_selection_rng() -> anyhow::Result<Arc<dyn setu_router_core::SelectionRng>> {
+    #[cfg(feature = "deterministic-rng")]
+    if let Ok(s) = std::env::var("SOLVER_SELECTION_SEED") {
+        let seed: u64 = s.parse().map_err(|e| anyhow::anyhow!("SOLVER_SELECTION_SEED: {}", e))?;
+        return Ok(Arc::new(setu_router_core::SeededSelectionRng::new(seed)));
+    }
+    Ok(setu_router_core::default_selection_rng())
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Initialize tracing with more detailed output
@@ -225,7 +236,14 @@ async fn main() -> anyhow::Result<()> {
     };
 
     // Create router manager (shared between NetworkService components)
-    let router_manager = Arc::new(RouterManager::new());
+    // SOLVER_SELECTION_STRATEGY: consistent_hash (default) | load_balanced | random
+    let solver_selection = match std::env::var("SOLVER_SELECTION_STRATEGY") {
+        Ok(s) => s.parse().map_err(|e: String| anyhow::anyhow!("SOLVER_SELECTION_STRATEGY: {}", e))?,
+        Err(_) => SolverSelectionStrategy::default(),
+    };
+    let router_manager = Arc::new(
+        RouterManager::new().with_solver_strategy(solver_selection.build(solver_selection_rng()?)),
+    );
     
     // Create ConsensusValidator for DAG + VLC + Consensus
     // N3 fix: Use P2P address/port (not HTTP) so all validators in ValidatorSet
@@ -254,11 +272,86 @@ async fn main() -> anyhow::Result<()> {
         .ok()
         .and_then(|s| s.parse().ok())
         .unwrap_or(10);
+    // Leader proposes a CF at least every MAX_FOLD_INTERVAL_MS while events
+    // are pending, even below the VLC threshold (0 = VLC delta only)
+    if let Ok(s) = std::env::var("MAX_FOLD_INTERVAL_MS") {
+        consensus.max_fold_interval_ms = s
+            .parse()
+            .map_err(|e| anyhow::anyhow!("MAX_FOLD_INTERVAL_MS: {}", e))?;
+    }
+    // Events referencing more parents are rejected (0 = unlimited)
+    if let Ok(s) = std::env::var("MAX_PARENTS_PER_EVENT") {
+        consensus.max_parents_per_event = s
+            .parse()
+            .map_err(|e| anyhow::anyhow!("MAX_PARENTS_PER_EVENT: {}", e))?;
+    }
+    
+    // Parents for events without object dependencies:
+    // all_tips (default) | n_most_recent:<n> | vlc_frontier
+    let parent_selection = match std::env::var("PARENT_SELECTION") {
+        Ok(s) => s.parse().map_err(|e: String| anyhow::anyhow!("PARENT_SELECTION: {}", e))?,
+        Err(_) => ParentSelectionStrategy::default(),
+    };
+    
+    // Permissioned mode: comma-separated creators allowed to submit events.
+    // Unset = permissionless.
+    let allowed_creators = std::env::var("ALLOWED_CREATORS")
+        .ok()
+        .map(|s| s.split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect::<std::collections::HashSet<String>>());
+    if let Some(ref creators) = allowed_creators {
+        info!("Permissioned mode: {} allowed event creators", creators.len());
+    }
     
+    // Event signature checks: comma-separated `creator:hex_ed25519_pubkey`.
+    // Set (even empty) = every event must be signed by its creator; creators
+    // not listed are looked up in the validator set. Unset = unchecked.
+    let creator_public_keys = match std::env::var("CREATOR_PUBLIC_KEYS") {
+        Ok(s) => Some(
+            s.split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(|entry| {
+                    let (creator, key) = entry
+                        .split_once(':')
+                        .ok_or_else(|| anyhow::anyhow!("CREATOR_PUBLIC_KEYS: expected creator:key, got {}", entry))?;
+                    let key = hex::decode(key.trim())
+                        .map_err(|e| anyhow::anyhow!("CREATOR_PUBLIC_KEYS: {}: {}", creator, e))?;
+                    Ok((creator.trim().to_string(), key))
+                })
+                .collect::<anyhow::Result<std::collections::HashMap<String, Vec<u8>>>>()?,
+        ),
+        Err(_) => None,
+    };
+    if let Some(ref keys) = creator_public_keys {
+        info!("Event signature checks enabled: {} registered creator keys", keys.len());
+    }
+    
+    // VERIFY_ANCHOR_CHAIN=true recomputes the anchor chain root from genesis
+    // on restart and refuses to start on a broken link. O(chain length).
+    let verify_anchor_chain_on_recovery = std::env::var("VERIFY_ANCHOR_CHAIN")
+        .map(|v| v == "true")
+        .unwrap_or(false);
+    
+    let fold_interval_ms = consensus.max_fold_interval_ms;
+    // The object size cap is a network parameter from genesis, so every
+    // validator rejects and skips the same oversized writes
+    let max_object_bytes = genesis_result
+        .as_ref()
+        .map(|gc| gc.max_object_bytes)
+        .unwrap_or(DEFAULT_MAX_OBJECT_BYTES);
+
     let consensus_config = ConsensusValidatorConfig {
         node_info,
         consensus,
         is_leader: false, // RotatingProposer determines leader; no hardcoded leader
+        parent_selection,
+        allowed_creators,
+        creator_public_keys,
+        verify_anchor_chain_on_recovery,
+        max_object_bytes,
         ..Default::default()
     };
     
@@ -266,8 +359,27 @@ async fn main() -> anyhow::Result<()> {
     // Previously opened twice (for SharedStateManager and ConsensusValidator) causing
     // LOCK file conflict crash in persistence mode.
     let db: Option<Arc<SetuDB>> = if let Some(ref db_path) = config.db_path {
-        info!("Opening RocksDB at: {}", db_path);
-        match SetuDB::open_default(db_path) {
+        // Value serialization: bcs (default) | json (debug deployments only;
+        // a database must always be reopened with the format it was written with)
+        let value_format = match std::env::var("DB_VALUE_FORMAT") {
+            Ok(s) => s.parse().map_err(|e: String| anyhow::anyhow!("DB_VALUE_FORMAT: {}", e))?,
+            Err(_) => ValueFormat::default(),
+        };
+        // WAL durability: none (default) | always | periodic:<interval_ms>
+        let wal_sync_mode = match std::env::var("DB_WAL_SYNC") {
+            Ok(s) => s.parse().map_err(|e: String| anyhow::anyhow!("DB_WAL_SYNC: {}", e))?,
+            Err(_) => WalSyncMode::default(),
+        };
+        info!(
+            "Opening RocksDB at: {} (value format: {:?}, WAL sync: {:?})",
+            db_path, value_format, wal_sync_mode
+        );
+        let db_config = RocksDBConfig {
+            value_format,
+            wal_sync_mode,
+            ..RocksDBConfig::new(db_path)
+        };
+        match SetuDB::open(db_config) {
             Ok(db) => {
                 info!("✓ RocksDB opened successfully");
                 Some(Arc::new(db))
@@ -285,12 +397,14 @@ async fn main() -> anyhow::Result<()> {
     let (shared_state_manager, gsm_recovered) = if let Some(ref db) = db {
         let merkle_store: Arc<dyn B4StoreExt> = Arc::new(RocksDBMerkleStore::from_shared(db.clone()));
         let mut manager = GlobalStateManager::with_store(merkle_store);
+        manager.set_max_object_bytes(max_object_bytes);
         // Recover all subnet SMT trees from persisted state (B4 commit data)
         let recovered = match manager.recover() {
             Ok(summary) => {
                 let has_data = summary.total_leaves > 0;
-                info!("✓ GSM recovered: {} subnets, {} leaves, {} root mismatches",
-                    summary.subnets_recovered, summary.total_leaves, summary.root_mismatches);
+                info!("✓ GSM recovered: {} subnets, {} leaves, {} root mismatches, {} objects indexed",
+                    summary.subnets_recovered, summary.total_leaves, summary.root_mismatches,
+                    summary.objects_indexed);
                 has_data
             }
             Err(e) => {
@@ -300,39 +414,55 @@ async fn main() -> anyhow::Result<()> {
         };
         (Arc::new(SharedStateManager::new(manager)), recovered)
     } else {
-        (Arc::new(SharedStateManager::new(GlobalStateManager::new())), false)
+        let mut manager = GlobalStateManager::new();
+        manager.set_max_object_bytes(max_object_bytes);
+        (Arc::new(SharedStateManager::new(manager)), false)
     };
     
-    // Create task preparer with the SHARED state manager
-    let task_preparer = Arc::new(setu_validator::TaskPreparer::new_with_state_manager(
-        config.node_config.node_id.clone(),
-        Arc::clone(&shared_state_manager),
-    ));
-    info!("✓ TaskPreparer initialized with shared state manager");
+    // TASK_EXECUTION_DEADLINE_MS bounds TEE execution per task (the enclave
+    // still applies its own max_execution_time_ms if that is smaller)
+    let task_execution_deadline_ms = match std::env::var("TASK_EXECUTION_DEADLINE_MS") {
+        Ok(s) => Some(s.parse().map_err(|e| anyhow::anyhow!("TASK_EXECUTION_DEADLINE_MS: {}", e))?),
+        Err(_) => None,
+    };
+    // ZERO_BALANCE_COIN_POLICY: keep (default) | tombstone. Applied by the
+    // enclave to sender coins a transfer drains to zero
+    let zero_balance_policy = match std::env::var("ZERO_BALANCE_COIN_POLICY") {
+        Ok(s) => s.parse().map_err(|e: String| anyhow::anyhow!("ZERO_BALANCE_COIN_POLICY: {}", e))?,
+        Err(_) => setu_types::ZeroBalanceCoinPolicy::default(),
+    };
 
-    // Create batch task preparer sharing the same state (production path)
-    let batch_task_preparer = Arc::new(setu_validator::BatchTaskPreparer::new(
-        config.node_config.node_id.clone(),
-        Arc::new(setu_storage::MerkleStateProvider::new(Arc::clone(&shared_state_manager))),
-    ));
-    info!("✓ BatchTaskPreparer initialized with shared state manager");
-    
     // Create ConsensusValidator with appropriate storage backend
     let consensus_validator = if let Some(ref db) = db {
         // RocksDB persistence mode - reuse the single DB handle
         let event_store: Arc<dyn EventStoreBackend> = Arc::new(RocksDBEventStore::from_shared(db.clone()));
-        let cf_store: Arc<dyn CFStoreBackend> = Arc::new(RocksDBCFStore::from_shared(db.clone()));
+        let rocks_cf_store = Arc::new(RocksDBCFStore::from_shared(db.clone()));
+        let cf_store: Arc<dyn CFStoreBackend> = rocks_cf_store.clone();
         let anchor_store: Arc<dyn AnchorStoreBackend> = Arc::new(RocksDBAnchorStore::from_shared(db.clone()));
         
         info!("✓ RocksDB backends initialized (Events, CF, Anchors, Merkle)");
         
-        Arc::new(ConsensusValidator::with_all_backends(
+        let validator = ConsensusValidator::with_all_backends(
             consensus_config,
             Arc::clone(&shared_state_manager),
             event_store,
             cf_store,
             anchor_store,
-        ))
+        );
+
+        // ATOMIC_ANCHOR_COMMIT=true writes each finalized anchor with its
+        // events, CF index entries and merkle state in a single RocksDB WriteBatch.
+        let atomic_anchor_commit = std::env::var("ATOMIC_ANCHOR_COMMIT")
+            .map(|v| v == "true")
+            .unwrap_or(false);
+        if atomic_anchor_commit {
+            info!("✓ Atomic anchor commit enabled");
+            let committer = RocksDBAnchorCommitter::new(db.clone(), rocks_cf_store)
+                .with_state_manager(Arc::clone(&shared_state_manager));
+            Arc::new(validator.with_anchor_committer(Arc::new(committer)))
+        } else {
+            Arc::new(validator)
+        }
     } else {
         // Memory mode - use shared state manager
         Arc::new(ConsensusValidator::with_shared_state_manager(
@@ -343,6 +473,30 @@ async fn main() -> anyhow::Result<()> {
     
     info!("✓ ConsensusValidator initialized (genesis_validators={})", genesis_validator_count);
     
+    // Create task preparer with the SHARED state manager
+    let task_preparer = Arc::new(
+        setu_validator::TaskPreparer::new_with_state_manager(
+            config.node_config.node_id.clone(),
+            Arc::clone(&shared_state_manager),
+        )
+        .with_execution_deadline_ms(task_execution_deadline_ms)
+        .with_zero_balance_policy(zero_balance_policy)
+        .with_parent_selector(consensus_validator.clone()),
+    );
+    info!("✓ TaskPreparer initialized with shared state manager");
+
+    // Create batch task preparer sharing the same state (production path)
+    let batch_task_preparer = Arc::new(
+        setu_validator::BatchTaskPreparer::new(
+            config.node_config.node_id.clone(),
+            Arc::new(setu_storage::MerkleStateProvider::new(Arc::clone(&shared_state_manager))),
+        )
+        .with_execution_deadline_ms(task_execution_deadline_ms)
+        .with_zero_balance_policy(zero_balance_policy)
+        .with_parent_selector(consensus_validator.clone()),
+    );
+    info!("✓ BatchTaskPreparer initialized with shared state manager");
+    
     // ========================================
     // Register all genesis validators into consensus layer
     // ========================================
@@ -396,14 +550,8 @@ async fn main() -> anyhow::Result<()> {
     // Genesis Event: Initialize seed accounts
     // ========================================
     if gsm_recovered {
+        // Owner/coin-type indexes were already rebuilt by `GlobalStateManager::recover`
         info!("✓ Skipping genesis — state recovered from persistent storage");
-        // Rebuild indexes from recovered SMT data so balance/coin queries work
-        {
-            let mut gsm = shared_state_manager.lock_write();
-            let indexed = gsm.rebuild_coin_type_index();
-            shared_state_manager.publish_snapshot(&gsm);
-            info!("✓ Indexes rebuilt from recovered state: {} objects indexed", indexed);
-        }
     } else {
         match &genesis_result {
             Ok(genesis_config) => {
@@ -415,92 +563,20 @@ async fn main() -> anyhow::Result<()> {
                     genesis_path
                 );
 
-                // Build state changes for each genesis account
-                // Supports multi-coin: when coins_per_account > 1, balance is split
-                // across N coin objects for higher per-sender parallelism.
-                let mut state_changes = Vec::new();
+                // Build state changes for each genesis account: one coin object
+                // per (account, coin_type), split across coins_per_account objects
+                // when > 1 for higher per-sender parallelism.
+                let state_changes = genesis_config
+                    .build_state_changes()
+                    .map_err(|e| anyhow::anyhow!("Invalid genesis config: {}", e))?;
                 for account in &genesis_config.accounts {
-                    // Validate that the address is a proper hex address
-                    let owner_addr = Address::from_hex(&account.address)
-                        .expect("genesis account must have valid hex address");
-                    let owner_hex = owner_addr.to_string();
-                    let num_coins = account.coins_per_account.max(1) as u64;
-
-                    if num_coins == 1 {
-                        // Single coin (legacy path): use deterministic_coin_id
-                        let object_id_bytes = MerkleStateProvider::coin_object_id_with_type(
-                            &owner_hex,
-                            &genesis_config.subnet_id,
-                        );
-                        let coin_state = CoinState::new_with_type(
-                            owner_hex.clone(),
-                            account.balance,
-                            genesis_config.subnet_id.clone(),
-                        );
-                        let key = format!("oid:{}", hex::encode(object_id_bytes));
-                        state_changes.push(StateChange {
-                            key,
-                            old_value: None,
-                            new_value: Some(coin_state.to_bytes()),
-                            target_subnet: None,
-                        });
-                        info!(
-                            name = ?account.name,
-                            owner = %owner_hex,
-                            balance = account.balance,
-                            object_id = %hex::encode(object_id_bytes),
-                            "Genesis account prepared (1 coin)"
-                        );
-                    } else {
-                        // Multi-coin: split balance across N coins
-                        let balance_per_coin = account.balance / num_coins;
-                        let remainder = account.balance - balance_per_coin * (num_coins - 1);
-
-                        for idx in 0..num_coins {
-                            let coin_balance = if idx == num_coins - 1 {
-                                remainder  // last coin absorbs rounding remainder
-                            } else {
-                                balance_per_coin
-                            };
-
-                            let object_id = if idx == 0 {
-                                // Index 0: use legacy deterministic_coin_id for compatibility
-                                setu_types::deterministic_coin_id_from_str(
-                                    &owner_hex,
-                                    &genesis_config.subnet_id,
-                                )
-                            } else {
-                                setu_types::deterministic_genesis_coin_id(
-                                    &owner_hex,
-                                    &genesis_config.subnet_id,
-                                    idx as u32,
-                                )
-                            };
-                            let object_id_bytes = *object_id.as_bytes();
-
-                            let coin_state = CoinState::new_with_type(
-                                owner_hex.clone(),
-                                coin_balance,
-                                genesis_config.subnet_id.clone(),
-                            );
-                            let key = format!("oid:{}", hex::encode(object_id_bytes));
-                            state_changes.push(StateChange {
-                                key,
-                                old_value: None,
-                                new_value: Some(coin_state.to_bytes()),
-                                target_subnet: None,
-                            });
-                        }
-                        info!(
-                            name = ?account.name,
-                            owner = %owner_hex,
-                            total_balance = account.balance,
-                            coins = num_coins,
-                            balance_per_coin = balance_per_coin,
-                            "Genesis account prepared ({} coins)",
-                            num_coins
-                        );
-                    }
+                    info!(
+                        name = ?account.name,
+                        owner = %account.address,
+                        balances = ?account.coin_balances(&genesis_config.subnet_id),
+                        coins_per_type = account.coins_per_account.max(1),
+                        "Genesis account prepared"
+                    );
                 }
 
                 // Build genesis event with pre-computed execution result
@@ -528,6 +604,14 @@ async fn main() -> anyhow::Result<()> {
                 // The ID is computed from (parent_ids, vlc, creator, timestamp) so
                 // payload/execution_result changes don't invalidate it.
 
+                // Genesis is signed with this node's own key; the signature
+                // covers payload and execution_result, so sign last.
+                if let Some(ref kp) = keypair {
+                    if let Err(e) = genesis_event.sign(kp.secret_bytes()) {
+                        warn!("Genesis event left unsigned: {}", e);
+                    }
+                }
+
                 // Submit genesis event to the DAG
                 match consensus_validator.submit_event(genesis_event.clone()).await {
                     Ok(event_id) => {
@@ -553,9 +637,9 @@ async fn main() -> anyhow::Result<()> {
                     let mut gsm = shared_state_manager.lock_write();
                     for change in &state_changes {
                         gsm.apply_state_change(
-                            setu_types::subnet::SubnetId::ROOT,
+                            change.target_subnet.unwrap_or(setu_types::subnet::SubnetId::ROOT),
                             change,
-                        );
+                        ).map_err(|e| anyhow::anyhow!("Genesis state change {}: {}", change.key, e))?;
                         // Record this genesis event as the last modifier of each coin object.
                         // This enables TaskPreparer.derive_dependencies() to set proper
                         // parent_ids on subsequent transfer events, establishing the causal
@@ -649,12 +733,23 @@ async fn main() -> anyhow::Result<()> {
     );
     
     // 2.7 Create and inject broadcaster (P2P → consensus)
-    let broadcaster = Arc::new(AnemoConsensusBroadcaster::new(
-        Arc::clone(&anemo_network),
-        config.node_config.node_id.clone(),
+    // Failed CF/vote/event sends are retried in the background (1 disables)
+    let retry_policy = RetryPolicy {
+        max_attempts: std::env::var("BROADCAST_MAX_ATTEMPTS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(RetryPolicy::default().max_attempts),
+        ..RetryPolicy::default()
+    };
+    let broadcaster = Arc::new(RetryingBroadcaster::new(
+        Arc::new(AnemoConsensusBroadcaster::new(
+            Arc::clone(&anemo_network),
+            config.node_config.node_id.clone(),
+        )),
+        retry_policy,
     ));
     consensus_validator.set_broadcaster(broadcaster).await;
-    info!("✓ Consensus broadcaster connected");
+    info!(max_attempts = retry_policy.max_attempts, "✓ Consensus broadcaster connected");
     
     // 2.8 Start network event handler (network → consensus routing)
     let _network_handler = consensus_validator.start_network_event_handler(network_event_rx);
@@ -710,9 +805,40 @@ async fn main() -> anyhow::Result<()> {
     }
 
     // Create network service configuration
+    // Mempool dispatch order: fifo_fair (default) | fee_priority
+    let mempool_policy = match std::env::var("MEMPOOL_POLICY") {
+        Ok(s) => s.parse().map_err(|e: String| anyhow::anyhow!("MEMPOOL_POLICY: {}", e))?,
+        Err(_) => MempoolPolicy::default(),
+    };
+    // MEMPOOL_MAX_IN_FLIGHT: transfers prepared/dispatched at once before
+    // submissions queue in the mempool (default 200)
+    let mempool_max_in_flight = match std::env::var("MEMPOOL_MAX_IN_FLIGHT") {
+        Ok(s) => s.parse().map_err(|e| anyhow::anyhow!("MEMPOOL_MAX_IN_FLIGHT: {}", e))?,
+        Err(_) => NetworkServiceConfig::default().mempool_max_in_flight,
+    };
+    // MAX_TRANSFER_AMOUNT caps accepted transfer amounts (unset = no cap);
+    // REJECT_ZERO_AMOUNT_TRANSFERS=false lets zero-amount transfers through.
+    let transfer_amount_bounds = TransferAmountBounds {
+        max_transfer_amount: match std::env::var("MAX_TRANSFER_AMOUNT") {
+            Ok(s) => s.parse().map_err(|e| anyhow::anyhow!("MAX_TRANSFER_AMOUNT: {}", e))?,
+            Err(_) => u64::MAX,
+        },
+        reject_zero_amount: std::env::var("REJECT_ZERO_AMOUNT_TRANSFERS")
+            .map(|v| v != "false")
+            .unwrap_or(true),
+    };
+    // SOLVER_REDISPATCH_RETRIES: re-dispatches to another solver after a rejection (default 2)
+    let solver_redispatch_retries = match std::env::var("SOLVER_REDISPATCH_RETRIES") {
+        Ok(s) => s.parse().map_err(|e| anyhow::anyhow!("SOLVER_REDISPATCH_RETRIES: {}", e))?,
+        Err(_) => NetworkServiceConfig::default().solver_redispatch_retries,
+    };
     let network_config = NetworkServiceConfig {
         http_listen_addr: config.http_addr,
         p2p_listen_addr: config.p2p_addr,
+        mempool_policy,
+        mempool_max_in_flight,
+        transfer_amount_bounds,
+        solver_redispatch_retries,
     };
     
     // Create network service with consensus enabled
@@ -1159,21 +1285,15 @@ async fn main() -> anyhow::Result<()> {
         });
         info!("✓ Governance poll + timeout task started (10s interval)");
 
-        // Task C — Heartbeat: periodically flush stale events below vlc_delta_threshold.
+        // Task C — Fold timer: periodically flush stale events below vlc_delta_threshold.
         // Ensures governance events (low-frequency) are folded into CFs within bounded time.
-        let heartbeat_cv = Arc::clone(&consensus_validator);
-        let heartbeat_interval = Duration::from_secs(5);
-        let _heartbeat_handle = tokio::spawn(async move {
-            let mut interval = tokio::time::interval(heartbeat_interval);
-            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-            loop {
-                interval.tick().await;
-                if let Err(e) = heartbeat_cv.try_heartbeat(heartbeat_interval).await {
-                    tracing::debug!(error = %e, "Heartbeat CF attempt failed");
-                }
-            }
-        });
-        info!("✓ Heartbeat CF task started (5s interval)");
+        match consensus_validator.spawn_fold_timer() {
+            Some(_fold_timer_handle) => info!(
+                "✓ Fold timer task started ({}ms max fold interval)",
+                fold_interval_ms
+            ),
+            None => info!("Fold timer disabled (MAX_FOLD_INTERVAL_MS=0)"),
+        }
     }
 
     // Spawn HTTP server
@@ -1211,16 +1331,8 @@ async fn main() -> anyhow::Result<()> {
     }
 
     // ── Graceful shutdown sequence ──
-    // Step 1: Stop batch collector (drain pending entries)
-    info!("Step 1: Stopping batch collector...");
-    network_service.shutdown_batch_collector().await;
-
-    // Step 2: Wait for pending post-execution tasks (consensus + storage)
-    info!("Step 2: Waiting for pending tasks...");
-    match network_service.wait_for_pending_tee_tasks(Duration::from_secs(10)).await {
-        Ok(()) => info!("All pending tasks completed"),
-        Err(e) => warn!("Shutdown timeout: {}", e),
-    }
+    // Stop intake, drain in-flight TEE tasks, then persist and flush consensus state
+    network_service.shutdown(Duration::from_secs(10)).await;
 
     info!("Validator shutdown complete");
     Ok(())
@@ -0,0 +1,510 @@
+//! Transfer Mempool
+//!
+//! Buffers accepted transfers between request validation and task preparation
+//! and hands them out in policy order, so a burst is prepared (and its coins
+//! reserved) in a deterministic, policy-driven sequence before being fed to
+//! the TEE executor.
+//!
+//! The validator owns a single [`TransferMempool`] that both the single and
+//! the batch submission paths admit into. It caps how many transfers are in
+//! preparation/execution at once; when every slot is taken, admitted
+//! transfers wait in the mempool and are granted a [`DispatchPermit`] in
+//! policy order as earlier ones finish, regardless of which path they came in
+//! through.
+//!
+//! ## Policies
+//!
+//! | Policy | Dispatch order |
+//! |--------|----------------|
+//! | `fifo_fair` (default) | Round-robin across senders; each sender's transfers in arrival order |
+//! | `fee_priority` | Highest fee first; equal fees in arrival order |
+//!
+//! The request's `fee` is not charged by the executor, so `fee_priority`
+//! lets any sender jump the queue for free; it is opt-in until the fee is
+//! collected. With every fee at 0 it degenerates to plain FIFO.
+//!
+//! [`MempoolMetrics`] belongs to the service's [`TransferMempool`] and is
+//! rendered into `GET /api/v1/metrics`.
+
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::sync::mpsc;
+
+/// Order in which buffered transfers are dispatched
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MempoolPolicy {
+    /// Highest fee first, ties broken by arrival order
+    FeePriority,
+    /// Round-robin across senders, FIFO within a sender
+    #[default]
+    FairPerSender,
+}
+
+impl std::str::FromStr for MempoolPolicy {
+    type Err = String;
+
+    /// Parse `fee_priority` or `fifo_fair`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "fee_priority" => Ok(Self::FeePriority),
+            "fifo_fair" => Ok(Self::FairPerSender),
+            other => Err(format!("unknown mempool policy: {}", other)),
+        }
+    }
+}
+
+/// Heap entry for [`MempoolPolicy::FeePriority`]
+struct FeeEntry<T> {
+    fee: u64,
+    seq: u64,
+    item: T,
+}
+
+impl<T> PartialEq for FeeEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.fee == other.fee && self.seq == other.seq
+    }
+}
+
+impl<T> Eq for FeeEntry<T> {}
+
+impl<T> PartialOrd for FeeEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for FeeEntry<T> {
+    /// Max-heap on fee; the earlier arrival wins a tie
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        self.fee
+            .cmp(&other.fee)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+enum Queue<T> {
+    Fee(BinaryHeap<FeeEntry<T>>),
+    Fair {
+        /// Per-sender FIFO lanes
+        lanes: HashMap<String, VecDeque<T>>,
+        /// Senders with a non-empty lane, in round-robin order
+        turn: VecDeque<String>,
+    },
+}
+
+/// Policy-ordered buffer of pending transfers
+pub struct Mempool<T> {
+    queue: Queue<T>,
+    len: usize,
+    next_seq: u64,
+    metrics: Arc<MempoolMetrics>,
+}
+
+impl<T> Mempool<T> {
+    pub fn new(policy: MempoolPolicy, metrics: Arc<MempoolMetrics>) -> Self {
+        let queue = match policy {
+            MempoolPolicy::FeePriority => Queue::Fee(BinaryHeap::new()),
+            MempoolPolicy::FairPerSender => Queue::Fair {
+                lanes: HashMap::new(),
+                turn: VecDeque::new(),
+            },
+        };
+        Self {
+            queue,
+            len: 0,
+            next_seq: 0,
+            metrics,
+        }
+    }
+
+    /// Buffer one transfer from `sender` offering `fee`
+    pub fn push(&mut self, sender: &str, fee: u64, item: T) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+
+        match &mut self.queue {
+            Queue::Fee(heap) => heap.push(FeeEntry { fee, seq, item }),
+            Queue::Fair { lanes, turn } => {
+                let lane = lanes.entry(sender.to_string()).or_default();
+                if lane.is_empty() {
+                    turn.push_back(sender.to_string());
+                }
+                lane.push_back(item);
+            }
+        }
+
+        self.len += 1;
+        self.metrics.record_admit();
+    }
+
+    /// Take the next transfer to dispatch
+    pub fn pop(&mut self) -> Option<T> {
+        let item = match &mut self.queue {
+            Queue::Fee(heap) => heap.pop().map(|entry| entry.item),
+            Queue::Fair { lanes, turn } => {
+                let sender = turn.pop_front()?;
+                let lane = lanes.get_mut(&sender)?;
+                let item = lane.pop_front();
+                if lane.is_empty() {
+                    lanes.remove(&sender);
+                } else {
+                    turn.push_back(sender);
+                }
+                item
+            }
+        }?;
+
+        self.len -= 1;
+        self.metrics.record_dispatch();
+        Some(item)
+    }
+
+    /// Dispatch everything currently buffered, in policy order
+    pub fn drain(&mut self) -> Vec<T> {
+        let mut out = Vec::with_capacity(self.len);
+        while let Some(item) = self.pop() {
+            out.push(item);
+        }
+        out
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T> Drop for Mempool<T> {
+    /// Transfers dropped without dispatch no longer count towards the size gauge
+    fn drop(&mut self) {
+        self.metrics.size.fetch_sub(self.len as u64, Ordering::Relaxed);
+    }
+}
+
+/// A transfer waiting in the [`TransferMempool`] for a dispatch slot
+struct Waiter {
+    tag: usize,
+    grant: mpsc::UnboundedSender<(usize, DispatchPermit)>,
+}
+
+struct PoolState {
+    queue: Mempool<Waiter>,
+    in_flight: usize,
+}
+
+/// Validator-wide mempool shared by single and batch transfer submission
+///
+/// At most `max_in_flight` transfers hold a [`DispatchPermit`] at a time;
+/// the rest are queued and granted in [`MempoolPolicy`] order as permits are
+/// dropped.
+pub struct TransferMempool {
+    state: Mutex<PoolState>,
+    max_in_flight: usize,
+    metrics: Arc<MempoolMetrics>,
+}
+
+impl TransferMempool {
+    pub fn new(policy: MempoolPolicy, max_in_flight: usize) -> Arc<Self> {
+        let metrics = Arc::new(MempoolMetrics::new());
+        Arc::new(Self {
+            state: Mutex::new(PoolState {
+                queue: Mempool::new(policy, Arc::clone(&metrics)),
+                in_flight: 0,
+            }),
+            max_in_flight: max_in_flight.max(1),
+            metrics,
+        })
+    }
+
+    pub fn metrics(&self) -> &MempoolMetrics {
+        &self.metrics
+    }
+
+    /// Transfers currently holding a dispatch permit
+    pub fn in_flight(&self) -> usize {
+        self.state.lock().in_flight
+    }
+
+    /// Admit a group of transfers, each given as `(tag, sender, fee)`
+    ///
+    /// The returned [`Admissions`] yields `(tag, permit)` pairs in the order
+    /// the mempool grants them.
+    pub fn admit<'a>(
+        self: &Arc<Self>,
+        transfers: impl IntoIterator<Item = (usize, &'a str, u64)>,
+    ) -> Admissions {
+        let (grant, rx) = mpsc::unbounded_channel();
+        let mut pending = 0;
+
+        let mut state = self.state.lock();
+        for (tag, sender, fee) in transfers {
+            state.queue.push(sender, fee, Waiter { tag, grant: grant.clone() });
+            pending += 1;
+        }
+        self.pump(&mut state);
+
+        Admissions { rx, pending }
+    }
+
+    /// Admit one transfer and wait for its dispatch slot
+    pub async fn admit_one(self: &Arc<Self>, sender: &str, fee: u64) -> DispatchPermit {
+        let (_, permit) = self
+            .admit([(0, sender, fee)])
+            .next()
+            .await
+            .expect("admitted transfer is always granted");
+        permit
+    }
+
+    /// Grant free slots to queued transfers in policy order
+    fn pump(self: &Arc<Self>, state: &mut PoolState) {
+        while state.in_flight < self.max_in_flight {
+            let Some(waiter) = state.queue.pop() else { break };
+            state.in_flight += 1;
+            let permit = DispatchPermit {
+                pool: Arc::clone(self),
+                armed: true,
+            };
+            if let Err(mpsc::error::SendError((_, mut permit))) =
+                waiter.grant.send((waiter.tag, permit))
+            {
+                // The submitter gave up waiting; hand the slot to the next one
+                permit.armed = false;
+                state.in_flight -= 1;
+            }
+        }
+    }
+
+    fn release(self: &Arc<Self>) {
+        let mut state = self.state.lock();
+        state.in_flight -= 1;
+        self.pump(&mut state);
+    }
+}
+
+/// A transfer's claim on one [`TransferMempool`] dispatch slot
+///
+/// Hold it through preparation and TEE dispatch; dropping it grants the slot
+/// to the next queued transfer.
+pub struct DispatchPermit {
+    pool: Arc<TransferMempool>,
+    armed: bool,
+}
+
+impl Drop for DispatchPermit {
+    fn drop(&mut self) {
+        if self.armed {
+            self.pool.release();
+        }
+    }
+}
+
+/// Grants for a group of transfers admitted together
+pub struct Admissions {
+    rx: mpsc::UnboundedReceiver<(usize, DispatchPermit)>,
+    pending: usize,
+}
+
+impl Admissions {
+    /// Wait for the next granted transfer; `None` once every one was granted
+    pub async fn next(&mut self) -> Option<(usize, DispatchPermit)> {
+        if self.pending == 0 {
+            return None;
+        }
+        let granted = self.rx.recv().await?;
+        self.pending -= 1;
+        Some(granted)
+    }
+
+    /// Take a grant that is already available, without waiting
+    pub fn try_next(&mut self) -> Option<(usize, DispatchPermit)> {
+        let granted = self.rx.try_recv().ok()?;
+        self.pending -= 1;
+        Some(granted)
+    }
+
+    /// Transfers still waiting for a grant
+    pub fn pending(&self) -> usize {
+        self.pending
+    }
+}
+
+/// Mempool size gauges and throughput counters
+#[derive(Debug, Default)]
+pub struct MempoolMetrics {
+    size: AtomicU64,
+    peak_size: AtomicU64,
+    admitted: AtomicU64,
+    dispatched: AtomicU64,
+}
+
+impl MempoolMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn record_admit(&self) {
+        self.admitted.fetch_add(1, Ordering::Relaxed);
+        let size = self.size.fetch_add(1, Ordering::Relaxed) + 1;
+        self.peak_size.fetch_max(size, Ordering::Relaxed);
+    }
+
+    fn record_dispatch(&self) {
+        self.dispatched.fetch_add(1, Ordering::Relaxed);
+        self.size.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// Transfers currently buffered awaiting dispatch
+    pub fn size(&self) -> u64 {
+        self.size.load(Ordering::Relaxed)
+    }
+
+    /// Highest [`size`](Self::size) observed since startup
+    pub fn peak_size(&self) -> u64 {
+        self.peak_size.load(Ordering::Relaxed)
+    }
+
+    /// Render gauges and counters in Prometheus text format
+    pub fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        let _ = writeln!(out, "# HELP setu_mempool_size Transfers buffered in the mempool awaiting dispatch.");
+        let _ = writeln!(out, "# TYPE setu_mempool_size gauge");
+        let _ = writeln!(out, "setu_mempool_size {}", self.size());
+        let _ = writeln!(out, "# HELP setu_mempool_peak_size Highest mempool size observed since startup.");
+        let _ = writeln!(out, "# TYPE setu_mempool_peak_size gauge");
+        let _ = writeln!(out, "setu_mempool_peak_size {}", self.peak_size());
+        let _ = writeln!(out, "# HELP setu_mempool_admitted_total Transfers admitted to the mempool.");
+        let _ = writeln!(out, "# TYPE setu_mempool_admitted_total counter");
+        let _ = writeln!(out, "setu_mempool_admitted_total {}", self.admitted.load(Ordering::Relaxed));
+        let _ = writeln!(out, "# HELP setu_mempool_dispatched_total Transfers dispatched from the mempool for preparation.");
+        let _ = writeln!(out, "# TYPE setu_mempool_dispatched_total counter");
+        let _ = writeln!(out, "setu_mempool_dispatched_total {}", self.dispatched.load(Ordering::Relaxed));
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mempool(policy: MempoolPolicy) -> (Mempool<&'static str>, Arc<MempoolMetrics>) {
+        let metrics = Arc::new(MempoolMetrics::new());
+        (Mempool::new(policy, Arc::clone(&metrics)), metrics)
+    }
+
+    #[test]
+    fn test_fee_priority_dispatches_higher_fee_first() {
+        let (mut pool, metrics) = mempool(MempoolPolicy::FeePriority);
+
+        pool.push("alice", 1, "early-low-fee");
+        pool.push("bob", 50, "late-high-fee");
+        pool.push("carol", 1, "later-low-fee");
+        assert_eq!(metrics.size(), 3);
+
+        assert_eq!(pool.pop(), Some("late-high-fee"));
+        // Equal fees fall back to arrival order
+        assert_eq!(pool.pop(), Some("early-low-fee"));
+        assert_eq!(pool.pop(), Some("later-low-fee"));
+        assert_eq!(pool.pop(), None);
+        assert_eq!(metrics.size(), 0);
+        assert_eq!(metrics.peak_size(), 3);
+    }
+
+    #[test]
+    fn test_default_policy_ignores_uncharged_fee() {
+        assert_eq!(MempoolPolicy::default(), MempoolPolicy::FairPerSender);
+        let (mut pool, _) = mempool(MempoolPolicy::default());
+
+        pool.push("alice", 0, "a1");
+        pool.push("bob", u64::MAX, "b1");
+
+        assert_eq!(pool.drain(), vec!["a1", "b1"]);
+    }
+
+    #[test]
+    fn test_fair_policy_round_robins_senders() {
+        let (mut pool, _) = mempool(MempoolPolicy::FairPerSender);
+
+        pool.push("alice", 0, "a1");
+        pool.push("alice", 0, "a2");
+        pool.push("alice", 0, "a3");
+        pool.push("bob", 100, "b1");
+
+        assert_eq!(pool.drain(), vec!["a1", "b1", "a2", "a3"]);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn test_dropped_mempool_releases_size_gauge() {
+        let (mut pool, metrics) = mempool(MempoolPolicy::FeePriority);
+        pool.push("alice", 0, "a1");
+        pool.push("bob", 0, "b1");
+        let _ = pool.pop();
+        drop(pool);
+
+        assert_eq!(metrics.size(), 0);
+        let text = metrics.render_prometheus();
+        assert!(text.contains("setu_mempool_admitted_total 2"));
+        assert!(text.contains("setu_mempool_dispatched_total 1"));
+    }
+
+    #[tokio::test]
+    async fn test_shared_mempool_grants_queued_transfers_by_fee() {
+        let pool = TransferMempool::new(MempoolPolicy::FeePriority, 1);
+
+        // A single-path transfer takes the only slot
+        let single = pool.admit_one("alice", 0).await;
+        assert_eq!(pool.in_flight(), 1);
+
+        // A batch and a later single-path transfer queue behind it
+        let mut batch = pool.admit([(0, "bob", 1), (1, "carol", 5)]);
+        let mut late = pool.admit([(0, "dave", 9)]);
+        assert!(batch.try_next().is_none());
+        assert_eq!(pool.metrics().size(), 3);
+
+        // Slots are granted by fee across both submitters
+        drop(single);
+        let (_, dave) = late.try_next().expect("highest fee granted first");
+        assert!(batch.try_next().is_none());
+
+        drop(dave);
+        let (tag, carol) = batch.try_next().expect("next highest fee");
+        assert_eq!(tag, 1);
+        drop(carol);
+        let (tag, _bob) = batch.next().await.unwrap();
+        assert_eq!(tag, 0);
+        assert!(batch.next().await.is_none());
+        assert_eq!(pool.metrics().size(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_abandoned_admission_passes_slot_on() {
+        let pool = TransferMempool::new(MempoolPolicy::FeePriority, 1);
+        let held = pool.admit_one("alice", 0).await;
+
+        let abandoned = pool.admit([(0, "bob", 10)]);
+        let mut waiting = pool.admit([(0, "carol", 1)]);
+        drop(abandoned);
+
+        drop(held);
+        assert!(waiting.try_next().is_some());
+        assert_eq!(pool.in_flight(), 1);
+    }
+
+    #[test]
+    fn test_policy_from_str() {
+        assert_eq!("fee_priority".parse(), Ok(MempoolPolicy::FeePriority));
+        assert_eq!(" fifo_fair ".parse(), Ok(MempoolPolicy::FairPerSender));
+        assert!("lifo".parse::<MempoolPolicy>().is_err());
+    }
+}
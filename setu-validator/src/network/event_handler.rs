@@ -53,6 +53,7 @@ impl EventHandler {
                 message: format!("Quick check failed: {}", e),
                 event_id: None,
                 vlc_time: None,
+                correlation_id: None,
             };
         }
 
@@ -101,6 +102,7 @@ impl EventHandler {
                         message: "Event verified and added to consensus DAG".to_string(),
                         event_id: Some(event_id),
                         vlc_time: Some(vlc_time),
+                        correlation_id: None,
                     };
                 }
                 Err(e) => {
@@ -111,6 +113,7 @@ impl EventHandler {
                         message: format!("Consensus submission failed: {}", e),
                         event_id: None,
                         vlc_time: None,
+                        correlation_id: None,
                     };
                 }
             }
@@ -139,6 +142,7 @@ impl EventHandler {
             message: "Event verified and added to DAG".to_string(),
             event_id: Some(event_id),
             vlc_time: Some(vlc_time),
+            correlation_id: None,
         }
     }
 
@@ -223,7 +227,7 @@ impl EventHandler {
         events: &Arc<DashMap<String, Event>>,
         dag_events: &Arc<RwLock<Vec<String>>>,
         consensus: Option<&Arc<ConsensusValidator>>,
-        event: Event,
+        mut event: Event,
     ) -> SubmitEventResponse {
         let event_id = event.id.clone();
 
@@ -233,11 +237,14 @@ impl EventHandler {
                 message: format!("Quick check failed: {}", e),
                 event_id: None,
                 vlc_time: None,
+                correlation_id: None,
             };
         }
 
-        // If consensus is enabled, submit to consensus engine
+        // If consensus is enabled, submit to consensus engine.
+        // Events on this path are created by this validator: sign them first.
         let vlc_time = if let Some(consensus_validator) = consensus {
+            consensus_validator.sign_local_event(&mut event).await;
             match consensus_validator.submit_event(event.clone()).await {
                 Ok(_) => {
                     let vlc = consensus_validator.vlc_snapshot().await;
@@ -258,6 +265,7 @@ impl EventHandler {
                         message: format!("Consensus submission failed: {}", e),
                         event_id: None,
                         vlc_time: None,
+                        correlation_id: None,
                     };
                 }
             }
@@ -273,6 +281,7 @@ impl EventHandler {
             message: "Event verified and added to DAG".to_string(),
             event_id: Some(event_id),
             vlc_time,
+            correlation_id: None,
         }
     }
 
@@ -8,6 +8,7 @@
 //! - `types.rs` - Shared types and utilities
 //! - `registration.rs` - Registration handler implementation
 //! - `solver_client.rs` - Solver HTTP client types
+//! - `stf_metrics.rs` - STF execution latency histogram
 
 mod types;
 mod service;
@@ -15,6 +16,7 @@ mod registration;
 mod solver_client;
 mod transfer_handler;
 mod tee_executor;
+mod stf_metrics;
 mod event_handler;
 pub(crate) mod move_handler;
 
@@ -511,6 +511,7 @@ impl RegistrationHandler for ValidatorRegistrationHandler {
     async fn get_validator_list(&self, _request: GetValidatorListRequest) -> GetValidatorListResponse {
         GetValidatorListResponse {
             validators: self.service.get_validator_list(),
+            validator_set: self.service.get_validator_set().await,
         }
     }
 
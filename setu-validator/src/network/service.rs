@@ -26,6 +26,7 @@ use super::event_handler::EventHandler;
 use super::move_handler;
 use crate::{RouterManager, TaskPreparer, BatchTaskPreparer, ConsensusValidator, InfraExecutor};
 use crate::coin_reservation::CoinReservationManager;
+use crate::mempool::TransferMempool;
 use crate::governance::service::{ConfigSource, GovernanceService, SystemSubnetConfig};
 use crate::governance::handler::{
     GovernanceHandler, ProposeRequest, ProposeResponse, CallbackRequest,
@@ -41,24 +42,46 @@ use axum::{
 use dashmap::DashMap;
 use setu_types::Transfer;
 use setu_types::governance::SystemSubnetRegistration;
-use parking_lot::RwLock;
+use lru::LruCache;
+use parking_lot::{Mutex, RwLock};
 use setu_rpc::{
-    GetTransferStatusResponse, RegisterSolverRequest,
-    SubmitTransferRequest, SubmitTransferResponse, ValidatorListItem,
+    GetTransferStatusResponse, ProcessingStep, RegisterSolverRequest,
+    SubmitTransferRequest, SubmitTransferResponse, ValidatorListItem, ValidatorSetItem,
     SubmitTransfersBatchRequest, SubmitTransfersBatchResponse,
 };
 use setu_types::event::{Event, EventPayload, EventStatus};
 use setu_types::ExecutionOutcome;
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
-use tracing::info;
+use tracing::{info, info_span, Instrument};
 
 // Import API handlers
 use setu_api;
 
+/// Upper bound on how long `wait_for_finalization` transfers hold the response
+const TRANSFER_FINALIZATION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Anchors a validator may trail its peers by and still report ready
+const MAX_CATCH_UP_LAG: u64 = 2;
+
+/// Correlation ids remembered for the most recently submitted events
+const EVENT_CORRELATION_CACHE_CAPACITY: usize = 65_536;
+
+/// How a wait for a submitted event's finalization ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FinalizationWait {
+    /// The event's CF was applied with this outcome
+    Finalized(ExecutionOutcome),
+    /// No outcome was recorded within the timeout
+    TimedOut,
+    /// Consensus is disabled, so no outcome will ever be recorded
+    ConsensusDisabled,
+}
+
 /// Validator network service
 ///
 /// Core service handling:
@@ -126,15 +149,29 @@ pub struct ValidatorNetworkService {
     vlc_counter: AtomicU64,
     event_counter: AtomicU64,
 
+    /// Set by [`shutdown`](Self::shutdown); new transfers are rejected
+    shutting_down: AtomicBool,
+
     /// Coin reservation manager for cross-batch double-spend prevention
     coin_reservation_manager: Arc<CoinReservationManager>,
 
     /// TEE executor for parallel task execution
-    tee_executor: TeeExecutor,
+    tee_executor: Arc<TeeExecutor>,
+
+    /// Mempool shared by single and batch submissions; caps transfers in
+    /// flight and grants queued ones dispatch slots in policy order
+    mempool: Arc<TransferMempool>,
 
     /// Governance service for Agent subnet integration (optional)
     governance_service: Option<Arc<GovernanceService>>,
 
+    /// Correlation ids of events submitted through this validator
+    /// (event_id → correlation_id), surfaced in event metadata and
+    /// finalization logs. Kept off the event so its stored layout is unchanged.
+    /// Bounded: the least recently used entry is evicted at capacity, so
+    /// events that never finalize do not accumulate.
+    event_correlation_ids: Mutex<LruCache<String, String>>,
+
     /// R5 · Shared map of per-event apply outcomes, written by consensus layer
     /// (`DashMapOutcomeSink`) and read by `GET /api/v1/event/:id`.
     /// Empty (and forever so) when constructed without consensus.
@@ -204,6 +241,8 @@ impl ValidatorNetworkService {
         // Note: In production, both should share the same MerkleStateProvider
         let batch_task_preparer = batch_task_preparer;
 
+        let mempool = TransferMempool::new(config.mempool_policy, config.mempool_max_in_flight);
+
         Self {
             validator_id,
             router_manager,
@@ -225,9 +264,14 @@ impl ValidatorNetworkService {
             transfer_counter: AtomicU64::new(0),
             vlc_counter: AtomicU64::new(0),
             event_counter: AtomicU64::new(0),
+            shutting_down: AtomicBool::new(false),
             coin_reservation_manager,
-            tee_executor,
+            tee_executor: Arc::new(tee_executor),
+            mempool,
             governance_service: None,
+            event_correlation_ids: Mutex::new(LruCache::new(
+                NonZeroUsize::new(EVENT_CORRELATION_CACHE_CAPACITY).unwrap(),
+            )),
             execution_outcomes: Arc::new(DashMap::new()),
             version_watcher: parking_lot::RwLock::new(None),
             #[cfg(test)]
@@ -292,6 +336,8 @@ impl ValidatorNetworkService {
         // R5: share the consensus validator's outcome map so RPC can read it.
         let execution_outcomes = consensus_validator.execution_outcomes();
 
+        let mempool = TransferMempool::new(config.mempool_policy, config.mempool_max_in_flight);
+
         Self {
             validator_id,
             router_manager,
@@ -313,9 +359,14 @@ impl ValidatorNetworkService {
             transfer_counter: AtomicU64::new(0),
             vlc_counter: AtomicU64::new(0),
             event_counter: AtomicU64::new(0),
+            shutting_down: AtomicBool::new(false),
             coin_reservation_manager,
-            tee_executor,
+            tee_executor: Arc::new(tee_executor),
+            mempool,
             governance_service: None,
+            event_correlation_ids: Mutex::new(LruCache::new(
+                NonZeroUsize::new(EVENT_CORRELATION_CACHE_CAPACITY).unwrap(),
+            )),
             execution_outcomes,
             version_watcher: parking_lot::RwLock::new(None),
             #[cfg(test)]
@@ -340,6 +391,11 @@ impl ValidatorNetworkService {
         self.task_preparer.state_provider()
     }
 
+    /// Get the concrete Merkle state provider (for reads beyond `StateProvider`)
+    pub fn merkle_state_provider(&self) -> &Arc<setu_storage::MerkleStateProvider> {
+        self.batch_task_preparer.merkle_state_provider()
+    }
+
     /// Create an InfraExecutor using the shared MerkleStateProvider
     pub fn infra_executor(&self) -> InfraExecutor {
         InfraExecutor::new(
@@ -487,6 +543,41 @@ impl ValidatorNetworkService {
         self.tee_executor.shutdown_batch_collector().await;
     }
 
+    /// Whether [`shutdown`](Self::shutdown) has started
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    /// Graceful shutdown sequence.
+    ///
+    /// 1. Stop accepting new transfers
+    /// 2. Drain the batch collector
+    /// 3. Wait (up to `drain_timeout`) for in-flight TEE tasks
+    /// 4. Fold, persist and flush consensus state
+    ///
+    /// A drain timeout is logged and does not abort the remaining steps:
+    /// flushing what has finalized is still better than skipping it.
+    pub async fn shutdown(&self, drain_timeout: Duration) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+        info!("Shutdown: no longer accepting transfers");
+
+        self.shutdown_batch_collector().await;
+
+        match self.wait_for_pending_tee_tasks(drain_timeout).await {
+            Ok(()) => info!("Shutdown: all in-flight tasks completed"),
+            Err(e) => tracing::warn!(
+                pending = self.pending_tee_count(),
+                "Shutdown: {}", e
+            ),
+        }
+
+        if let Some(consensus) = &self.consensus_validator {
+            if let Err(e) = consensus.shutdown().await {
+                tracing::error!(error = %e, "Shutdown: failed to flush consensus state");
+            }
+        }
+    }
+
     /// Start background cleanup task for expired coin reservations
     /// 
     /// This spawns a background task that periodically cleans up expired reservations
@@ -539,9 +630,22 @@ impl ValidatorNetworkService {
     pub async fn start_http_server(
         self: Arc<Self>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let app = self.http_router();
+
+        let listener = tokio::net::TcpListener::bind(self.config.http_listen_addr).await?;
+
+        info!(addr = %self.config.http_listen_addr, "HTTP API server started");
+
+        axum::serve(listener, app).await?;
+
+        Ok(())
+    }
+
+    /// Build the HTTP API router served by `start_http_server`.
+    pub fn http_router(self: &Arc<Self>) -> Router {
         let service = self.clone();
 
-        let app = Router::new()
+        Router::new()
             // Registration endpoints
             .route("/api/v1/register/solver", post(setu_api::http_register_solver::<ValidatorNetworkService>))
             .route("/api/v1/register/validator", post(setu_api::http_register_validator::<ValidatorNetworkService>))
@@ -551,6 +655,8 @@ impl ValidatorNetworkService {
             .route("/api/v1/validators", get(setu_api::http_get_validators::<ValidatorNetworkService>))
             .route("/api/v1/subnets", get(setu_api::http_get_subnets::<ValidatorNetworkService>))
             .route("/api/v1/health", get(setu_api::http_health::<ValidatorNetworkService>))
+            .route("/api/v1/health/live", get(setu_api::http_live::<ValidatorNetworkService>))
+            .route("/api/v1/metrics", get(setu_api::http_metrics::<ValidatorNetworkService>))
             // State query endpoints (Scheme B)
             .route("/api/v1/state/balance/:account", get(setu_api::http_get_balance::<ValidatorNetworkService>))
             .route("/api/v1/state/object/:key", get(setu_api::http_get_object::<ValidatorNetworkService>))
@@ -558,10 +664,35 @@ impl ValidatorNetworkService {
             .route("/api/v1/transfer", post(setu_api::http_submit_transfer::<ValidatorNetworkService>))
             .route("/api/v1/transfers/batch", post(setu_api::http_submit_transfers_batch::<ValidatorNetworkService>))
             .route("/api/v1/transfer/status", post(setu_api::http_get_transfer_status::<ValidatorNetworkService>))
+            .route("/api/v1/transfer/estimate", post(setu_api::http_estimate_transfer::<ValidatorNetworkService>))
             // Event endpoints
             .route("/api/v1/event", post(setu_api::http_submit_event::<ValidatorNetworkService>))
             .route("/api/v1/events", get(setu_api::http_get_events::<ValidatorNetworkService>))
             .route("/api/v1/event/:id", get(setu_api::http_get_event_by_id::<ValidatorNetworkService>))
+            // Explorer endpoints
+            .route("/api/v1/explorer/dag/path/:event_id", get(setu_api::http_get_causal_subgraph::<ValidatorNetworkService>))
+            .route("/api/v1/explorer/dag/export", get(setu_api::http_export_dag::<ValidatorNetworkService>))
+            .route("/api/v1/explorer/events", get(setu_api::http_get_events_in_range::<ValidatorNetworkService>))
+            .route("/api/v1/explorer/events/status", post(setu_api::http_get_event_statuses::<ValidatorNetworkService>))
+            .route("/api/v1/explorer/token/:coin_type", get(setu_api::http_get_token_metadata::<ValidatorNetworkService>))
+            .route("/api/v1/explorer/activity/:address/:subnet_id", get(setu_api::http_get_subnet_activity::<ValidatorNetworkService>))
+            .route("/api/v1/explorer/tip", get(setu_api::http_get_explorer_tip::<ValidatorNetworkService>))
+            .route("/api/v1/explorer/anchor/:id", get(setu_api::http_get_anchor_detail::<ValidatorNetworkService>))
+            .route("/api/v1/explorer/anchor/:id/certificate", get(setu_api::http_get_vote_certificate::<ValidatorNetworkService>))
+            .route("/api/v1/explorer/anchor/:id/subnet/:subnet_id/proof", get(setu_api::http_get_subnet_inclusion_proof::<ValidatorNetworkService>))
+            .route("/api/v1/explorer/subnets", get(setu_api::http_get_explorer_subnets::<ValidatorNetworkService>))
+            .route("/api/v1/explorer/subnet/:id/stats", get(setu_api::http_get_subnet_stats::<ValidatorNetworkService>))
+            .route("/api/v1/explorer/subnet/:id/throughput", get(setu_api::http_get_subnet_throughput::<ValidatorNetworkService>))
+            .route("/api/v1/explorer/accounts/balances", post(setu_api::http_get_account_balances::<ValidatorNetworkService>))
+            // Admin endpoints
+            .route("/api/v1/admin/rebuild-coin-index", post(setu_api::http_admin_rebuild_coin_index::<ValidatorNetworkService>))
+            .route("/api/v1/admin/state/:address", get(setu_api::http_admin_get_address_state::<ValidatorNetworkService>))
+            // Consensus status
+            .route("/api/v1/consensus/status", get(setu_api::http_get_consensus_status::<ValidatorNetworkService>))
+            .route("/api/v1/consensus/config", get(setu_api::http_get_consensus_config::<ValidatorNetworkService>))
+            .route("/api/v1/consensus/sync", get(setu_api::http_get_sync_progress::<ValidatorNetworkService>))
+            .route("/api/v1/consensus/dag/stats", get(setu_api::http_get_dag_stats::<ValidatorNetworkService>))
+            .route("/api/v1/dag/orphans", get(setu_api::http_get_orphaned_events::<ValidatorNetworkService>))
             // Heartbeat
             .route("/api/v1/heartbeat", post(setu_api::http_heartbeat::<ValidatorNetworkService>))
             // User RPC endpoints
@@ -596,15 +727,7 @@ impl ValidatorNetworkService {
             .route("/api/v1/move/objects/:object_id", get(setu_api::http_get_move_object::<ValidatorNetworkService>))
             .route("/api/v1/move/modules/:address/:name", get(setu_api::http_get_module_abi::<ValidatorNetworkService>))
             .route("/api/v1/move/modules/:address", get(setu_api::http_list_modules::<ValidatorNetworkService>))
-            .with_state(service);
-
-        let listener = tokio::net::TcpListener::bind(self.config.http_listen_addr).await?;
-
-        info!(addr = %self.config.http_listen_addr, "HTTP API server started");
-
-        axum::serve(listener, app).await?;
-
-        Ok(())
+            .with_state(service)
     }
 
     // ============================================
@@ -612,9 +735,23 @@ impl ValidatorNetworkService {
     // ============================================
 
     pub async fn submit_transfer(&self, request: SubmitTransferRequest) -> SubmitTransferResponse {
+        if self.is_shutting_down() {
+            return SubmitTransferResponse {
+                success: false,
+                message: "Validator is shutting down".to_string(),
+                transfer_id: None,
+                event_id: None,
+                solver_id: None,
+                processing_steps: vec![],
+                correlation_id: None,
+            };
+        }
         let vlc_time = self.get_vlc_time();
+        let wait_for_finalization = request.wait_for_finalization;
+        let correlation_id = new_correlation_id();
+        let span = info_span!("transfer", correlation_id = %correlation_id);
 
-        TransferHandler::submit_transfer(
+        let mut response = TransferHandler::submit_transfer(
             &self.validator_id,
             &self.router_manager,
             &self.task_preparer,
@@ -625,12 +762,125 @@ impl ValidatorNetworkService {
             vlc_time,
             request,
             &self.tee_executor,
+            &self.mempool,
+            &self.config.transfer_amount_bounds,
+            self.config.solver_redispatch_retries,
         )
-        .await
+        .instrument(span.clone())
+        .await;
+
+        if let Some(event_id) = &response.event_id {
+            self.event_correlation_ids.lock().put(event_id.clone(), correlation_id.clone());
+        }
+
+        if wait_for_finalization && response.success {
+            if let Some(event_id) = response.event_id.clone() {
+                let outcome = self
+                    .wait_for_event_finalization(&event_id, TRANSFER_FINALIZATION_TIMEOUT)
+                    .instrument(span)
+                    .await;
+                Self::apply_finalization_outcome(&mut response, outcome);
+            }
+        }
+        response.correlation_id = Some(correlation_id);
+        response
     }
 
-    pub fn get_transfer_status(&self, transfer_id: &str) -> GetTransferStatusResponse {
-        TransferHandler::get_transfer_status(&self.transfer_status, transfer_id)
+    /// Wait until `event_id` has a recorded apply outcome, i.e. its CF has
+    /// finalized and the state changes (if any) are visible to queries.
+    ///
+    /// The outcome is recorded when the CF is applied, before its anchor is
+    /// announced on the finalized-anchor channel, so the map is re-checked
+    /// on every announcement. Without consensus the outcome map is never
+    /// written, so this returns [`FinalizationWait::ConsensusDisabled`] at once.
+    pub async fn wait_for_event_finalization(
+        &self,
+        event_id: &str,
+        timeout: Duration,
+    ) -> FinalizationWait {
+        let recorded = || self.execution_outcomes.get(event_id).map(|o| o.clone());
+        let Some(consensus) = self.consensus_validator.as_ref() else {
+            return recorded().map_or(FinalizationWait::ConsensusDisabled, FinalizationWait::Finalized);
+        };
+        // Subscribe before the first check so a finalization in between is not missed
+        let mut finalized = consensus.subscribe_finalized();
+        let wait = async {
+            loop {
+                if let Some(outcome) = recorded() {
+                    return Some(outcome);
+                }
+                match finalized.recv().await {
+                    Ok(_) | Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => return recorded(),
+                }
+            }
+        };
+        match tokio::time::timeout(timeout, wait).await.ok().flatten() {
+            Some(outcome) => FinalizationWait::Finalized(outcome),
+            None => FinalizationWait::TimedOut,
+        }
+    }
+
+    /// Fold the result of a finalization wait into a submit response
+    fn apply_finalization_outcome(response: &mut SubmitTransferResponse, wait: FinalizationWait) {
+        let (status, details) = match &wait {
+            FinalizationWait::Finalized(ExecutionOutcome::Applied { cf_id }) => {
+                response.message = "Transfer finalized and applied".to_string();
+                ("completed", format!("Applied in CF {}", cf_id))
+            }
+            FinalizationWait::Finalized(other) => {
+                response.success = false;
+                response.message = format!("Transfer finalized but not applied: {}", other.kind());
+                ("failed", format!("Outcome: {}", other.kind()))
+            }
+            FinalizationWait::TimedOut => {
+                response.message = format!(
+                    "Transfer accepted into consensus DAG; not finalized within {}ms",
+                    TRANSFER_FINALIZATION_TIMEOUT.as_millis()
+                );
+                ("timeout", "Finality pending".to_string())
+            }
+            FinalizationWait::ConsensusDisabled => {
+                response.message =
+                    "Transfer accepted; consensus is disabled so it will not be finalized".to_string();
+                ("consensus_disabled", "No consensus to finalize the event".to_string())
+            }
+        };
+        response.processing_steps.push(ProcessingStep {
+            step: "finalize".to_string(),
+            status: status.to_string(),
+            details: Some(details),
+            timestamp: current_timestamp_secs(),
+        });
+    }
+
+    /// Transfer status from the in-memory tracker, or reconstructed from the
+    /// persisted event and its containing anchor once the tracker entry is gone
+    pub async fn get_transfer_status(&self, transfer_id: &str) -> GetTransferStatusResponse {
+        let response = TransferHandler::get_transfer_status(&self.transfer_status, transfer_id);
+        if response.found {
+            return response;
+        }
+
+        let Some(consensus) = self.consensus_validator.as_ref() else {
+            return response;
+        };
+        match consensus.find_persisted_transfer(transfer_id).await {
+            Some((event, anchor)) => {
+                TransferHandler::persisted_transfer_status(transfer_id, &event, anchor.as_ref())
+            }
+            None => response,
+        }
+    }
+
+    /// Dry-run a transfer against current state (no reservation, no event)
+    pub fn estimate_transfer(&self, request: setu_api::EstimateTransferRequest) -> setu_api::EstimateTransferResponse {
+        TransferHandler::estimate_transfer(
+            &self.task_preparer,
+            &self.coin_reservation_manager,
+            request,
+            &self.config.transfer_amount_bounds,
+        )
     }
 
     /// Submit a batch of transfers for optimized processing.
@@ -649,6 +899,17 @@ impl ValidatorNetworkService {
         &self,
         request: SubmitTransfersBatchRequest,
     ) -> SubmitTransfersBatchResponse {
+        if self.is_shutting_down() {
+            return SubmitTransfersBatchResponse {
+                success: false,
+                message: "Validator is shutting down".to_string(),
+                submitted: 0,
+                failed: request.transfers.len(),
+                results: vec![],
+                stats: Default::default(),
+            };
+        }
+
         // Generate VLC time for all transfers in batch
         let _vlc_time = self.vlc_counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
 
@@ -663,6 +924,9 @@ impl ValidatorNetworkService {
             &self.vlc_counter,
             request,
             &self.tee_executor,
+            &self.mempool,
+            &self.config.transfer_amount_bounds,
+            self.config.solver_redispatch_retries,
         )
         .await
     }
@@ -672,7 +936,10 @@ impl ValidatorNetworkService {
     // ============================================
 
     pub async fn submit_event(&self, request: SubmitEventRequest) -> SubmitEventResponse {
-        EventHandler::submit_event(
+        let correlation_id = new_correlation_id();
+        let span = info_span!("submit_event", correlation_id = %correlation_id);
+
+        let mut response = EventHandler::submit_event(
             &self.events,
             &self.pending_events,
             &self.dag_events,
@@ -682,7 +949,14 @@ impl ValidatorNetworkService {
             &self.vlc_counter,
             request,
         )
-        .await
+        .instrument(span)
+        .await;
+
+        if let Some(event_id) = &response.event_id {
+            self.event_correlation_ids.lock().put(event_id.clone(), correlation_id.clone());
+        }
+        response.correlation_id = Some(correlation_id);
+        response
     }
 
     pub fn get_events(&self) -> Vec<Event> {
@@ -729,6 +1003,7 @@ impl ValidatorNetworkService {
             timestamp: event.timestamp,
             vlc_time: event.vlc_snapshot.logical_time,
             parent_count: event.parent_ids.len(),
+            correlation_id: self.event_correlation_ids.lock().get(event_id).cloned(),
         };
 
         Some(setu_api::GetEventResponse {
@@ -1196,6 +1471,25 @@ impl ValidatorNetworkService {
             .collect()
     }
 
+    /// Consensus validator set with voting power and proposer schedule
+    /// (empty when consensus is disabled)
+    pub async fn get_validator_set(&self) -> Vec<ValidatorSetItem> {
+        let Some(consensus) = self.consensus_validator.as_ref() else {
+            return Vec::new();
+        };
+        consensus
+            .validator_set_snapshot()
+            .await
+            .into_iter()
+            .map(|v| ValidatorSetItem {
+                validator_id: v.id,
+                voting_power: u64::try_from(v.voting_power).unwrap_or(u64::MAX),
+                is_current_leader: v.is_current_leader,
+                round_as_leader_next: v.round_as_leader_next,
+            })
+            .collect()
+    }
+
     // ============================================
     // Subnet Management
     // ============================================
@@ -1451,6 +1745,41 @@ impl ValidatorNetworkService {
         }
     }
 
+    /// Status and finalizing anchor of each event, by direct id lookup in the
+    /// query cache, then the DAG and event store. The anchor comes from the
+    /// anchor store's persisted event index, so it survives restarts.
+    pub async fn get_event_statuses(&self, event_ids: &[String]) -> setu_api::GetEventStatusesResponse {
+        let mut statuses = std::collections::BTreeMap::new();
+        for event_id in event_ids {
+            let cached = self.events.get(event_id).map(|event| event.status);
+            let status = match (cached, self.consensus_validator.as_ref()) {
+                (Some(status), _) => Some(status),
+                (None, Some(consensus)) => consensus
+                    .event_for_http_projection(event_id)
+                    .await
+                    .map(|event| event.status),
+                (None, None) => None,
+            };
+            let item = match status {
+                Some(status) => {
+                    let anchor_id = match self.consensus_validator.as_ref() {
+                        Some(consensus) => {
+                            consensus.anchor_store().get_anchor_id_for_event(event_id).await
+                        }
+                        None => None,
+                    };
+                    Some(setu_api::EventStatusItem {
+                        status: format!("{:?}", status),
+                        anchor_id,
+                    })
+                }
+                None => None,
+            };
+            statuses.insert(event_id.clone(), item);
+        }
+        setu_api::GetEventStatusesResponse { statuses }
+    }
+
     pub fn cache_finalized_event_for_query(&self, event: Event) {
         self.cache_finalized_event_for_query_with_outcome(event, None);
     }
@@ -1465,6 +1794,10 @@ impl ValidatorNetworkService {
             self.execution_outcomes.insert(event_id.clone(), outcome);
         }
 
+        if let Some(correlation_id) = self.event_correlation_ids.lock().get(&event_id) {
+            info!(event_id = %event_id, correlation_id = %correlation_id, "Submitted event finalized");
+        }
+
         event.set_status(EventStatus::Finalized);
         self.events.insert(event_id.clone(), event.clone());
 
@@ -1600,6 +1933,52 @@ impl ValidatorNetworkService {
             _ => {}
         }
     }
+
+    /// Build the explorer inclusion proof of `subnet` within `anchor`'s
+    /// committed global state root. `None` for legacy anchors without Merkle
+    /// roots or when the anchor does not commit that subnet.
+    fn subnet_inclusion_proof(
+        anchor: &setu_types::Anchor,
+        subnet: &setu_types::SubnetId,
+    ) -> Option<setu_api::GetSubnetInclusionProofResponse> {
+        let roots = anchor.merkle_roots.as_ref()?;
+        let subnet_root = *roots.subnet_roots.get(subnet)?;
+        let proof = consensus::AnchorMerkleRootsBuilder::new()
+            .with_committed_subnet_roots(&roots.subnet_roots)
+            .prove_subnet_in_global(subnet)?;
+
+        Some(setu_api::GetSubnetInclusionProofResponse {
+            anchor_id: anchor.id.clone(),
+            subnet_id: hex::encode(subnet.as_bytes()),
+            subnet_root: hex::encode(subnet_root),
+            global_state_root: hex::encode(roots.global_state_root),
+            leaf_index: proof.index,
+            siblings: proof.siblings.iter().map(|h| hex::encode(h.as_bytes())).collect(),
+        })
+    }
+
+    fn admin_proven_object(
+        object: &setu_storage::ProvenObject,
+        subnet_roots: &HashMap<setu_types::SubnetId, [u8; 32]>,
+    ) -> setu_api::AdminProvenObject {
+        let subnet_proof = consensus::AnchorMerkleRootsBuilder::new()
+            .with_committed_subnet_roots(subnet_roots)
+            .prove_subnet_in_global(&object.subnet_id)
+            .map(|proof| setu_api::AdminSubnetProof {
+                leaf_index: proof.index,
+                siblings: proof.siblings.iter().map(|h| hex::encode(h.as_bytes())).collect(),
+            });
+        setu_api::AdminProvenObject {
+            object_id: hex::encode(object.object_id.as_bytes()),
+            subnet_id: hex::encode(object.subnet_id.as_bytes()),
+            value: hex::encode(&object.value),
+            subnet_root: hex::encode(object.subnet_root),
+            leaf_key: hex::encode(object.proof.leaf_key),
+            siblings: object.proof.siblings.iter().map(hex::encode).collect(),
+            last_modified_by: object.last_modified_by.clone(),
+            subnet_proof,
+        }
+    }
 }
 
 // ============================================
@@ -1649,8 +2028,12 @@ impl setu_api::ValidatorService for ValidatorNetworkService {
         self.submit_transfers_batch(request).await
     }
 
-    fn get_transfer_status(&self, transfer_id: &str) -> GetTransferStatusResponse {
-        self.get_transfer_status(transfer_id)
+    async fn get_transfer_status(&self, transfer_id: &str) -> GetTransferStatusResponse {
+        self.get_transfer_status(transfer_id).await
+    }
+
+    fn estimate_transfer(&self, request: setu_api::EstimateTransferRequest) -> setu_api::EstimateTransferResponse {
+        self.estimate_transfer(request)
     }
 
     async fn submit_event(&self, request: setu_api::SubmitEventRequest) -> setu_api::SubmitEventResponse {
@@ -1665,6 +2048,374 @@ impl setu_api::ValidatorService for ValidatorNetworkService {
         self.get_event_by_id(event_id)
     }
 
+    async fn get_consensus_status(&self) -> Option<setu_api::GetConsensusStatusResponse> {
+        let consensus = self.consensus_validator.as_ref()?;
+        let status = consensus.consensus_status().await;
+        Some(setu_api::GetConsensusStatusResponse {
+            round: status.round,
+            current_leader: status.current_leader,
+            is_leader: status.is_leader,
+            validator_count: status.validator_count,
+            quorum: status.quorum,
+            last_finalized_anchor: status.last_finalized_anchor,
+        })
+    }
+
+    async fn get_consensus_config(&self) -> Option<setu_api::GetConsensusConfigResponse> {
+        let consensus = self.consensus_validator.as_ref()?;
+        let status = consensus.consensus_status().await;
+        Some(setu_api::GetConsensusConfigResponse {
+            config: consensus.consensus_config(),
+            active_validator_count: status.validator_count,
+            quorum: status.quorum,
+        })
+    }
+
+    async fn get_sync_progress(&self) -> Option<setu_api::GetSyncProgressResponse> {
+        let consensus = self.consensus_validator.as_ref()?;
+        let progress = consensus.sync_progress().await;
+        Some(setu_api::GetSyncProgressResponse {
+            local_max_anchor_depth: progress.local_max_anchor_depth,
+            network_max_anchor_depth: progress.network_max_anchor_depth,
+            lag: progress.lag,
+            caught_up: progress.is_caught_up(MAX_CATCH_UP_LAG),
+        })
+    }
+
+    async fn get_dag_stats(&self) -> Option<setu_api::GetDagStatsResponse> {
+        let consensus = self.consensus_validator.as_ref()?;
+        let stats = consensus.dag_stats().await;
+        Some(setu_api::GetDagStatsResponse {
+            event_count: stats.node_count,
+            pending_count: stats.pending_count,
+            tip_count: stats.tip_count,
+            max_depth: stats.max_depth,
+            fan_in: stats.fan_in.buckets().to_vec(),
+            fan_out: stats.fan_out.buckets().to_vec(),
+        })
+    }
+
+    async fn get_orphaned_events(&self) -> Option<setu_api::GetOrphanedEventsResponse> {
+        let consensus = self.consensus_validator.as_ref()?;
+        let anchor_threshold = consensus::DEFAULT_ORPHAN_ANCHOR_THRESHOLD;
+        let orphans = consensus
+            .orphaned_events(anchor_threshold)
+            .await
+            .into_iter()
+            .map(|orphan| setu_api::OrphanedEventInfo {
+                event_id: orphan.event_id,
+                depth: orphan.depth,
+                added_at_anchor: orphan.added_at_anchor,
+                anchors_missed: orphan.anchors_missed,
+            })
+            .collect();
+        Some(setu_api::GetOrphanedEventsResponse { anchor_threshold, orphans })
+    }
+
+    fn metrics_text(&self) -> String {
+        let mut text = self.tee_executor.stf_latency().render_prometheus();
+        text.push_str(&self.task_preparer.prepare_metrics().render_prometheus());
+        text.push_str(&self.mempool.metrics().render_prometheus());
+        text
+    }
+
+    async fn health_report(&self) -> setu_api::HealthReport {
+        const PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+        const MAX_ANCHOR_AGE_MS: u64 = 30_000;
+
+        let Some(consensus) = self.consensus_validator.as_ref() else {
+            return setu_api::HealthReport {
+                storage: setu_api::ComponentHealth::ok("consensus storage not configured"),
+                consensus: setu_api::ComponentHealth::ok("consensus disabled"),
+                last_anchor_age_ms: None,
+                sync_lag: 0,
+                catch_up: setu_api::ComponentHealth::ok("consensus disabled"),
+            };
+        };
+
+        let anchor_store = consensus.anchor_store();
+        let storage = match tokio::time::timeout(PROBE_TIMEOUT, anchor_store.probe()).await {
+            Ok(Ok(())) => setu_api::ComponentHealth::ok("anchor store reachable"),
+            Ok(Err(e)) => setu_api::ComponentHealth::failed(format!("anchor store read failed: {}", e)),
+            Err(_) => setu_api::ComponentHealth::failed("anchor store read timed out"),
+        };
+        let catch_up = match tokio::time::timeout(PROBE_TIMEOUT, consensus.sync_progress()).await {
+            Ok(progress) if progress.is_caught_up(MAX_CATCH_UP_LAG) => setu_api::ComponentHealth::ok(
+                format!("anchor depth {} of {}", progress.local_max_anchor_depth, progress.network_max_anchor_depth),
+            ),
+            Ok(progress) => setu_api::ComponentHealth::failed(format!(
+                "{} anchors behind network depth {}",
+                progress.lag, progress.network_max_anchor_depth
+            )),
+            Err(_) => setu_api::ComponentHealth::failed("sync progress read timed out"),
+        };
+
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        let engine = consensus.engine();
+        match tokio::time::timeout(PROBE_TIMEOUT, engine.health(now_ms, MAX_ANCHOR_AGE_MS)).await {
+            Ok(health) => setu_api::HealthReport {
+                storage,
+                consensus: if health.stalled {
+                    setu_api::ComponentHealth::failed(format!(
+                        "no anchor finalized in {}ms with {} pending events (round {})",
+                        MAX_ANCHOR_AGE_MS, health.pending_events, health.round
+                    ))
+                } else {
+                    setu_api::ComponentHealth::ok(format!("round {}", health.round))
+                },
+                last_anchor_age_ms: health.last_anchor_age_ms,
+                sync_lag: health.finalization_lag,
+                catch_up,
+            },
+            Err(_) => setu_api::HealthReport {
+                storage,
+                consensus: setu_api::ComponentHealth::failed("consensus engine unresponsive"),
+                last_anchor_age_ms: None,
+                sync_lag: 0,
+                catch_up,
+            },
+        }
+    }
+
+    async fn export_dag_dot(&self, max_events: usize) -> Option<String> {
+        Some(self.consensus_validator.as_ref()?.export_dag_dot(max_events).await)
+    }
+
+    async fn get_causal_subgraph(
+        &self,
+        event_id: &str,
+        max_depth: usize,
+    ) -> Option<setu_api::GetCausalSubgraphResponse> {
+        let consensus = self.consensus_validator.as_ref()?;
+        let subgraph = consensus
+            .engine()
+            .get_causal_subgraph(&event_id.to_string(), max_depth)
+            .await?;
+
+        Some(setu_api::GetCausalSubgraphResponse {
+            root: subgraph.root,
+            max_depth,
+            nodes: subgraph
+                .nodes
+                .into_iter()
+                .map(|(event, distance)| setu_api::CausalNode {
+                    event_type: event.event_type.name().to_string(),
+                    creator: event.creator.clone(),
+                    vlc_time: event.vlc_snapshot.logical_time,
+                    event_id: event.id,
+                    distance,
+                })
+                .collect(),
+            edges: subgraph
+                .edges
+                .into_iter()
+                .map(|(child, parent)| setu_api::CausalEdge { child, parent })
+                .collect(),
+            truncated: subgraph.truncated,
+        })
+    }
+
+    async fn get_events_in_range(
+        &self,
+        from_ts: u64,
+        to_ts: u64,
+        after: Option<String>,
+        limit: usize,
+    ) -> Option<setu_api::GetEventsInRangeResponse> {
+        let event_store = self.consensus_validator.as_ref()?.event_store();
+        // One extra event tells us whether the window continues past `limit`
+        let mut events = event_store
+            .get_events_in_range(from_ts, to_ts, after.as_deref(), limit.saturating_add(1))
+            .await;
+        let truncated = events.len() > limit;
+        events.truncate(limit);
+        let (next_from, next_after) = match events.last() {
+            Some(last) if truncated => (Some(last.timestamp), Some(last.id.clone())),
+            _ => (None, None),
+        };
+
+        Some(setu_api::GetEventsInRangeResponse {
+            from: from_ts,
+            to: to_ts,
+            limit,
+            events: events
+                .into_iter()
+                .map(|event| setu_api::ExplorerEvent {
+                    event_type: event.event_type.name().to_string(),
+                    creator: event.creator.clone(),
+                    status: format!("{:?}", event.status),
+                    timestamp: event.timestamp,
+                    vlc_time: event.vlc_snapshot.logical_time,
+                    event_id: event.id,
+                })
+                .collect(),
+            truncated,
+            next_from,
+            next_after,
+            decode_errors: event_store.decode_errors(),
+        })
+    }
+
+    fn get_token_metadata(&self, coin_type: &str) -> Option<setu_api::GetTokenMetadataResponse> {
+        let provider = self.merkle_state_provider();
+        let metadata = provider.get_coin_metadata(coin_type)?;
+        Some(setu_api::GetTokenMetadataResponse {
+            holder_count: provider.coin_holder_count(&metadata.coin_type),
+            coin_type: metadata.coin_type,
+            symbol: metadata.symbol,
+            name: metadata.name,
+            decimals: metadata.decimals,
+        })
+    }
+
+    fn get_subnet_activity(&self, address: &str, subnet_id: &str) -> Option<setu_api::GetSubnetActivityResponse> {
+        let activity = self
+            .merkle_state_provider()
+            .get_user_subnet_activity(address, subnet_id)?;
+        let summary = activity.summary();
+        Some(setu_api::GetSubnetActivityResponse {
+            address: activity.user.to_string(),
+            subnet_id: activity.subnet_id.to_string(),
+            total_interactions: summary.total_events,
+            unique_users: summary.interaction_count,
+            interaction_counts: activity.interaction_counts,
+            recent_partners: summary.recent_interactions.iter().map(|a| a.to_string()).collect(),
+            joined_at: activity.joined_at,
+            last_activity: summary.last_interaction,
+        })
+    }
+
+    async fn get_anchor_detail(&self, anchor_id: &str) -> Option<setu_api::GetAnchorDetailResponse> {
+        let anchor_store = self.consensus_validator.as_ref()?.anchor_store();
+        let anchor = anchor_store.get(&anchor_id.to_string()).await?;
+        let previous = match &anchor.previous_anchor {
+            Some(prev_id) => anchor_store.get(prev_id).await,
+            None => None,
+        };
+        Some(setu_api::GetAnchorDetailResponse::new(&anchor, previous.as_ref()))
+    }
+
+    fn get_explorer_tip(&self) -> Option<setu_api::GetExplorerTipResponse> {
+        let tip = self.consensus_validator.as_ref()?.finalized_tip()?;
+        Some(setu_api::GetExplorerTipResponse {
+            anchor_id: tip.anchor_id,
+            depth: tip.depth,
+            global_state_root: tip.global_state_root,
+            timestamp: tip.timestamp,
+            proposer: tip.proposer,
+            proposer_signature: hex::encode(tip.proposer_signature),
+        })
+    }
+
+    async fn get_vote_certificate(&self, anchor_id: &str) -> Option<setu_api::GetVoteCertificateResponse> {
+        let cert = self.consensus_validator.as_ref()?.vote_certificate(anchor_id).await?;
+        Some(setu_api::GetVoteCertificateResponse::from(&cert))
+    }
+
+    async fn get_subnet_inclusion_proof(
+        &self,
+        anchor_id: &str,
+        subnet_id: &str,
+    ) -> Option<setu_api::GetSubnetInclusionProofResponse> {
+        let subnet = setu_types::SubnetId::from_hex(subnet_id).ok()?;
+        let anchor_store = self.consensus_validator.as_ref()?.anchor_store();
+        let anchor = anchor_store.get(&anchor_id.to_string()).await?;
+        Self::subnet_inclusion_proof(&anchor, &subnet)
+    }
+
+    fn get_explorer_subnets(&self) -> setu_api::GetExplorerSubnetsResponse {
+        let mut subnets: Vec<setu_api::ExplorerSubnet> = self
+            .get_all_subnets()
+            .into_iter()
+            .map(|s| setu_api::ExplorerSubnet {
+                subnet_id: s.subnet_id,
+                name: s.name,
+                owner: s.owner,
+                subnet_type: s.subnet_type,
+                token_symbol: s.token_symbol,
+                status: s.status,
+                registered_at: s.registered_at,
+            })
+            .collect();
+        subnets.sort_by(|a, b| a.subnet_id.cmp(&b.subnet_id));
+        setu_api::GetExplorerSubnetsResponse { subnets }
+    }
+
+    fn get_subnet_stats(&self, subnet_id: &str) -> Option<setu_api::GetSubnetStatsResponse> {
+        let info = self.get_subnet_info(subnet_id)?;
+        let stats = self.merkle_state_provider().get_subnet_stats(&info.subnet_id);
+        Some(setu_api::GetSubnetStatsResponse {
+            subnet_id: info.subnet_id,
+            object_count: stats.as_ref().map_or(0, |s| s.object_count),
+            state_root: stats.as_ref().map_or_else(String::new, |s| hex::encode(s.state_root)),
+            anchor_depth: stats.as_ref().map_or(0, |s| s.last_anchor),
+            token_supply: stats.as_ref().map_or(0, |s| s.token_supply),
+        })
+    }
+
+    fn get_subnet_throughput(&self, subnet_id: &str) -> Option<setu_api::GetSubnetThroughputResponse> {
+        let info = self.get_subnet_info(subnet_id)?;
+        let throughput = self.merkle_state_provider().get_subnet_throughput(&info.subnet_id);
+        Some(setu_api::GetSubnetThroughputResponse {
+            subnet_id: info.subnet_id,
+            event_count: throughput.as_ref().map_or(0, |t| t.event_count),
+            events_per_minute: throughput.as_ref().map_or(0, |t| t.events_per_minute),
+        })
+    }
+
+    fn get_account_balances(&self, addresses: &[String]) -> setu_api::GetAccountBalancesResponse {
+        setu_api::GetAccountBalancesResponse {
+            balances: self.merkle_state_provider().get_balances_for_addresses(addresses),
+        }
+    }
+
+    async fn get_event_statuses(&self, event_ids: &[String]) -> setu_api::GetEventStatusesResponse {
+        self.get_event_statuses(event_ids).await
+    }
+
+    fn rebuild_coin_index(&self) -> setu_api::RebuildCoinIndexResponse {
+        let provider = self.merkle_state_provider();
+        let indexed_count = provider.rebuild_coin_type_index();
+        let (indexed_addresses, indexed_entries) = provider.index_stats();
+        info!(
+            indexed_count,
+            indexed_addresses,
+            indexed_entries,
+            "Coin-type index rebuilt via admin API"
+        );
+        setu_api::RebuildCoinIndexResponse {
+            indexed_count,
+            indexed_addresses,
+            indexed_entries,
+        }
+    }
+
+    fn get_address_state(&self, address: &str) -> setu_api::GetAddressStateResponse {
+        let dump = self.merkle_state_provider().dump_address_state(address);
+
+        setu_api::GetAddressStateResponse {
+            global_state_root: hex::encode(dump.global_state_root),
+            coins: dump
+                .coins
+                .iter()
+                .map(|(coin, object)| setu_api::AdminStateCoin {
+                    coin_type: coin.coin_type.clone(),
+                    balance: coin.balance,
+                    version: coin.version,
+                    object: Self::admin_proven_object(object, &dump.subnet_roots),
+                })
+                .collect(),
+            profile: dump
+                .profile
+                .as_ref()
+                .map(|object| Self::admin_proven_object(object, &dump.subnet_roots)),
+            address: dump.address,
+        }
+    }
+
     fn get_balance(&self, account: &str) -> setu_api::GetBalanceResponse {
         self.get_balance(account)
     }
@@ -1673,6 +2424,21 @@ impl setu_api::ValidatorService for ValidatorNetworkService {
         self.get_object(key)
     }
 
+    fn get_object_with_proof(&self, object_id: &setu_types::ObjectId) -> Option<setu_api::GetObjectWithProofResponse> {
+        let provider = self.merkle_state_provider();
+        let (object, global_state_root) =
+            provider.get_proven_object_with_global_root(&provider.default_subnet(), object_id)?;
+        Some(setu_api::GetObjectWithProofResponse {
+            object_id: hex::encode(object.object_id.as_bytes()),
+            subnet_id: hex::encode(object.subnet_id.as_bytes()),
+            value: hex::encode(&object.value),
+            state_root: hex::encode(object.subnet_root),
+            global_state_root: hex::encode(global_state_root),
+            leaf_key: hex::encode(object.proof.leaf_key),
+            siblings: object.proof.siblings.iter().map(hex::encode).collect(),
+        })
+    }
+
     async fn submit_move_call(&self, request: setu_api::MoveCallRequest) -> setu_api::MoveCallResponse {
         self.submit_move_call(request).await
     }
@@ -2246,6 +3012,7 @@ async fn governance_resource_params_handler(
 mod tests {
     use super::*;
     use crate::governance::service::GovernanceServiceConfig;
+    use crate::TransferAmountBounds;
     use setu_rpc::{RegistrationHandler, UserRpcHandler};
 
     fn create_test_service() -> Arc<ValidatorNetworkService> {
@@ -2295,6 +3062,7 @@ mod tests {
             message: "forced submit failure".to_string(),
             event_id: Some("forced-event-id".to_string()),
             vlc_time: None,
+            correlation_id: None,
         }
     }
 
@@ -2393,7 +3161,7 @@ mod tests {
         );
         let shared = service.batch_task_preparer.merkle_state_provider().shared_state_manager();
         let mut gsm = shared.lock_write();
-        gsm.apply_state_change(setu_types::SubnetId::ROOT, &change);
+        gsm.apply_state_change(setu_types::SubnetId::ROOT, &change).unwrap();
         shared.publish_snapshot(&gsm);
     }
 
@@ -2424,11 +3192,11 @@ mod tests {
         gsm.apply_state_change(
             setu_types::SubnetId::ROOT,
             &setu_types::StateChange::insert(mod_key, module_bytes.to_vec()),
-        );
+        ).unwrap();
         gsm.apply_state_change(
             setu_types::SubnetId::ROOT,
             &setu_types::StateChange::insert(linkage_key, linkage_payload),
-        );
+        ).unwrap();
         shared.publish_snapshot(&gsm);
     }
 
@@ -2471,7 +3239,7 @@ mod tests {
         );
         let shared = service.batch_task_preparer.merkle_state_provider().shared_state_manager();
         let mut gsm = shared.lock_write();
-        gsm.apply_state_change(setu_types::SubnetId::GOVERNANCE, &change);
+        gsm.apply_state_change(setu_types::SubnetId::GOVERNANCE, &change).unwrap();
         shared.publish_snapshot(&gsm);
     }
 
@@ -2499,6 +3267,317 @@ mod tests {
         assert_eq!(service.validator_count(), 1);
     }
 
+    fn sample_transfer_request(amount: u64) -> SubmitTransferRequest {
+        SubmitTransferRequest {
+            from: "alice".to_string(),
+            to: "bob".to_string(),
+            amount,
+            transfer_type: "setu".to_string(),
+            preferred_solver: None,
+            shard_id: None,
+            subnet_id: None,
+            recipient_subnet_id: None,
+            resources: vec![],
+            fee: 0,
+            wait_for_finalization: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transfer_over_max_amount_rejected() {
+        let config = NetworkServiceConfig {
+            transfer_amount_bounds: TransferAmountBounds {
+                max_transfer_amount: 1_000,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let service = ValidatorNetworkService::new(
+            "test-validator".to_string(),
+            Arc::new(RouterManager::new()),
+            Arc::new(TaskPreparer::new_for_testing("test-validator".to_string())),
+            Arc::new(BatchTaskPreparer::new_for_testing("test-validator".to_string())),
+            config,
+        );
+
+        let response = service.submit_transfer(sample_transfer_request(1_001)).await;
+
+        assert!(!response.success);
+        assert!(response.message.contains("exceeds maximum 1000"), "{}", response.message);
+        assert!(response.processing_steps.iter().all(|s| s.step != "prepare_task"));
+        assert_eq!(service.coin_reservation_manager.reservation_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_zero_amount_transfer_rejected() {
+        let service = create_test_service();
+
+        let response = service.submit_transfer(sample_transfer_request(0)).await;
+
+        assert!(!response.success);
+        assert!(response.message.contains("greater than zero"), "{}", response.message);
+        assert!(response.processing_steps.iter().all(|s| s.step != "prepare_task"));
+    }
+
+    /// Solver stub that either rejects every task or executes it with no state changes.
+    struct ScriptedSolver {
+        reject_with: Option<String>,
+    }
+
+    #[async_trait::async_trait]
+    impl setu_transport::http::SolverHttpHandler for ScriptedSolver {
+        async fn execute_task(
+            &self,
+            request: setu_transport::http::ExecuteTaskRequest,
+        ) -> setu_transport::http::ExecuteTaskResponse {
+            use setu_transport::http::{AttestationDto, ExecuteTaskResponse, TeeExecutionResultDto};
+
+            if let Some(message) = &self.reject_with {
+                return ExecuteTaskResponse::error(message.clone(), 0);
+            }
+            let task_id = request.solver_task.task_id;
+            let result = TeeExecutionResultDto {
+                task_id,
+                subnet_id: "subnet-0".to_string(),
+                post_state_root: [0u8; 32],
+                state_changes: vec![],
+                events_processed: 1,
+                events_failed: 0,
+                gas_used: 0,
+                attestation: AttestationDto {
+                    enclave_id: "scripted".to_string(),
+                    timestamp: 0,
+                    task_id_binding: task_id,
+                    input_hash: [0u8; 32],
+                    pre_state_root: [0u8; 32],
+                    post_state_root: [0u8; 32],
+                    signature: vec![],
+                },
+                execution_time_us: 10,
+            };
+            ExecuteTaskResponse::success(result, "executed", 10)
+        }
+
+        async fn health(&self) -> setu_transport::http::HealthResponse {
+            setu_transport::http::HealthResponse::healthy("scripted", "test")
+        }
+
+        async fn info(&self) -> setu_transport::http::SolverInfoResponse {
+            setu_transport::http::SolverInfoResponse {
+                solver_id: "scripted".to_string(),
+                enclave: setu_transport::http::EnclaveInfoDto {
+                    id: "scripted".to_string(),
+                    version: "test".to_string(),
+                    platform: "mock".to_string(),
+                    is_simulated: true,
+                },
+                mode: "test".to_string(),
+            }
+        }
+    }
+
+    async fn spawn_scripted_solver(reject_with: Option<&str>) -> u16 {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let app = setu_transport::http::create_router(Arc::new(ScriptedSolver {
+            reject_with: reject_with.map(str::to_string),
+        }));
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        port
+    }
+
+    /// Serve a solver backed by the mock enclave, so tasks produce real state changes.
+    async fn spawn_enclave_solver(solver_id: &str) -> u16 {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let app = setu_transport::http::create_router(setu_solver::create_handler(
+            solver_id.to_string(),
+            Arc::new(setu_solver::TeeExecutor::new(solver_id.to_string())),
+        ));
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        port
+    }
+
+    #[tokio::test]
+    async fn rejected_task_is_redispatched_to_next_solver() {
+        let service = create_test_service();
+        let reject_port = spawn_scripted_solver(Some("solver overloaded")).await;
+        let accept_port = spawn_scripted_solver(None).await;
+        service.register_solver_internal(&setu_rpc::RegisterSolverRequest {
+            port: reject_port,
+            ..sample_solver_request("solver-reject")
+        });
+        service.register_solver_internal(&setu_rpc::RegisterSolverRequest {
+            port: accept_port,
+            ..sample_solver_request("solver-accept")
+        });
+
+        let response = service
+            .submit_transfer(SubmitTransferRequest {
+                preferred_solver: Some("solver-reject".to_string()),
+                ..sample_transfer_request(100)
+            })
+            .await;
+
+        assert!(response.success, "{}", response.message);
+        assert_eq!(response.solver_id.as_deref(), Some("solver-accept"));
+        assert!(response.processing_steps.iter().any(|s| s.step == "tee_execution"
+            && s.status == "failed"
+            && s.details.as_deref().is_some_and(|d| d.contains("solver overloaded"))));
+        let status = service.get_transfer_status(response.transfer_id.as_deref().unwrap()).await;
+        assert_eq!(status.solver_id.as_deref(), Some("solver-accept"));
+    }
+
+    #[tokio::test]
+    async fn transfer_fails_with_last_error_once_redispatch_retries_exhausted() {
+        let config = NetworkServiceConfig {
+            solver_redispatch_retries: 0,
+            ..Default::default()
+        };
+        let service = ValidatorNetworkService::new(
+            "test-validator".to_string(),
+            Arc::new(RouterManager::new()),
+            Arc::new(TaskPreparer::new_for_testing("test-validator".to_string())),
+            Arc::new(BatchTaskPreparer::new_for_testing("test-validator".to_string())),
+            config,
+        );
+        let reject_port = spawn_scripted_solver(Some("attestation failed")).await;
+        let accept_port = spawn_scripted_solver(None).await;
+        service.register_solver_internal(&setu_rpc::RegisterSolverRequest {
+            port: reject_port,
+            ..sample_solver_request("solver-reject")
+        });
+        service.register_solver_internal(&setu_rpc::RegisterSolverRequest {
+            port: accept_port,
+            ..sample_solver_request("solver-accept")
+        });
+
+        let response = service
+            .submit_transfer(SubmitTransferRequest {
+                preferred_solver: Some("solver-reject".to_string()),
+                ..sample_transfer_request(100)
+            })
+            .await;
+
+        assert!(!response.success);
+        assert_eq!(response.message, "TEE execution failed: attestation failed");
+        assert_eq!(response.solver_id.as_deref(), Some("solver-reject"));
+    }
+
+    #[tokio::test]
+    async fn rejected_batch_task_is_redispatched_to_next_solver() {
+        let service = create_test_service();
+        let reject_port = spawn_scripted_solver(Some("solver overloaded")).await;
+        let accept_port = spawn_scripted_solver(None).await;
+        service.register_solver_internal(&setu_rpc::RegisterSolverRequest {
+            port: reject_port,
+            ..sample_solver_request("solver-reject")
+        });
+        service.register_solver_internal(&setu_rpc::RegisterSolverRequest {
+            port: accept_port,
+            ..sample_solver_request("solver-accept")
+        });
+
+        let response = service
+            .submit_transfers_batch(SubmitTransfersBatchRequest {
+                transfers: vec![SubmitTransferRequest {
+                    preferred_solver: Some("solver-reject".to_string()),
+                    ..sample_transfer_request(100)
+                }],
+            })
+            .await;
+        assert_eq!(response.submitted, 1, "{}", response.message);
+        let transfer_id = response.results[0].transfer_id.clone().unwrap();
+
+        service.wait_for_pending_tee_tasks(Duration::from_secs(10)).await.unwrap();
+
+        let status = service.get_transfer_status(&transfer_id).await;
+        assert_eq!(status.status.as_deref(), Some("executed"));
+        assert_eq!(status.solver_id.as_deref(), Some("solver-accept"));
+        assert!(status.processing_steps.iter().any(|s| s.step == "tee_execution"
+            && s.status == "failed"
+            && s.details.as_deref().is_some_and(|d| d.contains("solver overloaded"))));
+    }
+
+    /// Seed `owner` with a coin that only exists in `subnet`'s SMT.
+    fn seed_subnet_coin(service: &ValidatorNetworkService, owner: &str, subnet: &str, amount: u64) {
+        let provider = service.merkle_state_provider();
+        setu_storage::state_provider::init_coin_with_provider(provider, owner, amount, subnet);
+        let shared = provider.shared_state_manager();
+        let gsm = shared.lock_write();
+        shared.publish_snapshot(&gsm);
+    }
+
+    #[tokio::test]
+    async fn transfer_within_subnet_selects_coins_from_that_subnet() {
+        let service = create_test_service();
+        seed_subnet_coin(&service, "subnet-a-sender", "transfer-subnet-a", 1_000);
+        let port = spawn_enclave_solver("solver-subnet-a").await;
+        service.register_solver_internal(&setu_rpc::RegisterSolverRequest {
+            port,
+            ..sample_solver_request("solver-subnet-a")
+        });
+        let subnet = setu_types::SubnetId::from_coin_type("transfer-subnet-a");
+        let shared = service.merkle_state_provider().shared_state_manager();
+        let (subnet_root_before, root_before) = {
+            let gsm = shared.lock_write();
+            (gsm.get_subnet_root(&subnet), gsm.get_subnet_root(&setu_types::SubnetId::ROOT))
+        };
+
+        let response = service
+            .submit_transfer(SubmitTransferRequest {
+                from: "subnet-a-sender".to_string(),
+                subnet_id: Some("transfer-subnet-a".to_string()),
+                recipient_subnet_id: Some("transfer-subnet-a".to_string()),
+                ..sample_transfer_request(400)
+            })
+            .await;
+
+        assert!(response.success, "{}", response.message);
+        assert!(response.processing_steps.iter().any(|s| s.step == "prepare_task"
+            && s.status == "completed"
+            && s.details.as_deref().is_some_and(|d| d.contains("1 coins reserved"))));
+
+        let event_id = response.event_id.unwrap();
+        let event = service
+            .get_events()
+            .into_iter()
+            .find(|e| e.id == event_id)
+            .expect("executed event should be recorded");
+        assert_eq!(event.subnet_id, Some(subnet));
+        assert!(!event.execution_result.as_ref().unwrap().state_changes.is_empty());
+
+        let mut gsm = shared.lock_write();
+        let summary = gsm.apply_committed_events(std::slice::from_ref(&event));
+        assert!(summary.failed_events.is_empty());
+        assert_ne!(gsm.get_subnet_root(&subnet), subnet_root_before);
+        assert_eq!(gsm.get_subnet_root(&setu_types::SubnetId::ROOT), root_before);
+    }
+
+    #[tokio::test]
+    async fn transfer_across_subnets_is_rejected_without_lock() {
+        let service = create_test_service();
+        seed_subnet_coin(&service, "subnet-a-crosser", "transfer-subnet-a", 1_000);
+
+        let response = service
+            .submit_transfer(SubmitTransferRequest {
+                from: "subnet-a-crosser".to_string(),
+                subnet_id: Some("transfer-subnet-a".to_string()),
+                recipient_subnet_id: Some("transfer-subnet-b".to_string()),
+                ..sample_transfer_request(400)
+            })
+            .await;
+
+        assert!(!response.success);
+        assert!(response.message.contains("require a cross-subnet lock"), "{}", response.message);
+        assert!(response.message.contains("cross-subnet transfer flow"), "{}", response.message);
+        assert!(response.processing_steps.iter().all(|s| s.step != "prepare_task"));
+    }
+
     #[tokio::test]
     async fn register_solver_submit_failure_does_not_activate_solver() {
         let service = create_test_service();
@@ -2529,6 +3608,522 @@ mod tests {
         assert_eq!(service.validator_count(), 0);
     }
 
+    #[tokio::test]
+    async fn test_explorer_lists_subnets_with_stats() {
+        use setu_api::ValidatorService;
+
+        let service = create_test_service();
+        for (subnet_id, token_symbol) in [("gaming-subnet", "GAME"), ("org-subnet", "")] {
+            service.add_subnet(SubnetInfo {
+                subnet_id: subnet_id.to_string(),
+                name: format!("{} name", subnet_id),
+                owner: "alice".to_string(),
+                subnet_type: "App".to_string(),
+                token_symbol: token_symbol.to_string(),
+                status: "active".to_string(),
+                registered_at: 1,
+            });
+        }
+        let provider = service.merkle_state_provider();
+        setu_storage::state_provider::mint_subnet_token(provider, "gaming-subnet", "alice", 700);
+        setu_storage::state_provider::mint_subnet_token(provider, "gaming-subnet", "bob", 300);
+        let shared = provider.shared_state_manager();
+        shared.publish_snapshot(&shared.lock_write());
+
+        let listed = service.get_explorer_subnets().subnets;
+        let ids: Vec<&str> = listed.iter().map(|s| s.subnet_id.as_str()).collect();
+        assert_eq!(ids, vec!["gaming-subnet", "org-subnet"]);
+        assert_eq!(listed[0].token_symbol, "GAME");
+        assert_eq!(listed[1].subnet_type, "App");
+
+        let gaming = service.get_subnet_stats("gaming-subnet").unwrap();
+        assert_eq!(gaming.object_count, 2);
+        assert_eq!(gaming.token_supply, 1000);
+        assert_eq!(gaming.state_root.len(), 64);
+
+        let org = service.get_subnet_stats("org-subnet").unwrap();
+        assert_eq!(org.object_count, 0);
+        assert_eq!(org.token_supply, 0);
+
+        assert!(service.get_subnet_stats("unknown-subnet").is_none());
+    }
+
+    #[test]
+    fn test_explorer_bulk_account_balances() {
+        use setu_api::ValidatorService;
+
+        let service = create_test_service();
+        let provider = service.merkle_state_provider();
+        setu_storage::state_provider::mint_subnet_token(provider, "bulk-subnet", "bulk-a", 10);
+        setu_storage::state_provider::mint_subnet_token(provider, "bulk-subnet", "bulk-b", 20);
+        setu_storage::state_provider::mint_subnet_token(provider, "bulk-subnet", "bulk-c", 30);
+        let shared = provider.shared_state_manager();
+        shared.publish_snapshot(&shared.lock_write());
+
+        let addresses: Vec<String> = ["bulk-a", "bulk-b", "bulk-c"].iter().map(|s| s.to_string()).collect();
+        let balances = service.get_account_balances(&addresses).balances;
+
+        assert_eq!(balances.len(), 3);
+        assert_eq!(balances["bulk-a"]["bulk-subnet"], 10);
+        assert_eq!(balances["bulk-b"]["bulk-subnet"], 20);
+        assert_eq!(balances["bulk-c"]["bulk-subnet"], 30);
+    }
+
+    #[test]
+    fn test_admin_rebuild_coin_index_restores_lookups() {
+        use setu_api::ValidatorService;
+        use setu_types::SubnetId;
+
+        let service = create_test_service();
+        let provider = service.merkle_state_provider();
+        let owner = setu_types::Address::normalize("rebuild-index-owner").to_string();
+        let coin_id = setu_storage::MerkleStateProvider::coin_object_id_with_type(&owner, "ROOT");
+        let coin = setu_types::CoinState::new(owner.clone(), 42);
+
+        // Write straight into the SMT, bypassing the index (simulated drift)
+        let shared = provider.shared_state_manager();
+        {
+            let mut gsm = shared.lock_write();
+            gsm.upsert_object(SubnetId::ROOT, coin_id, coin.to_bytes()).unwrap();
+            shared.publish_snapshot(&gsm);
+        }
+        let addresses = vec![owner.clone()];
+        assert!(service.get_account_balances(&addresses).balances[&owner].is_empty());
+
+        let resp = service.rebuild_coin_index();
+        assert!(resp.indexed_count >= 1);
+        assert!(resp.indexed_addresses >= 1);
+        assert_eq!(service.get_account_balances(&addresses).balances[&owner]["ROOT"], 42);
+    }
+
+    #[test]
+    fn test_admin_address_state_dump_includes_proofs() {
+        use setu_api::ValidatorService;
+        use setu_storage::StateProvider;
+        use setu_types::SubnetId;
+
+        let service = create_test_service();
+        let provider = service.merkle_state_provider();
+        let owner = setu_types::Address::normalize("state-dump-owner").to_string();
+        let root_coin = setu_storage::state_provider::init_coin_with_provider(provider, &owner, 500, "ROOT");
+        let game_coin = setu_storage::state_provider::mint_subnet_token(provider, "dump-subnet", &owner, 70);
+
+        let profile_key = format!("profile:{}", owner);
+        let profile_id = setu_types::ObjectId::new(
+            setu_types::hash_utils::setu_hash_with_domain(b"SETU_PROFILE:", profile_key.as_bytes()),
+        );
+        let profile_bytes = serde_json::to_vec(&serde_json::json!({ "display_name": "dumper" })).unwrap();
+        let shared = provider.shared_state_manager();
+        {
+            let mut gsm = shared.lock_write();
+            gsm.upsert_object(SubnetId::ROOT, *profile_id.as_bytes(), profile_bytes.clone()).unwrap();
+            shared.publish_snapshot(&gsm);
+        }
+
+        let resp = service.get_address_state(&owner);
+        assert_eq!(resp.address, owner);
+        assert_eq!(resp.global_state_root, hex::encode(provider.get_state_root()));
+
+        let verify = |object: &setu_api::AdminProvenObject| {
+            let hash32 = |s: &str| -> [u8; 32] { hex::decode(s).unwrap().try_into().unwrap() };
+            let proof = setu_storage::SimpleMerkleProof {
+                siblings: object.siblings.iter().map(|s| hash32(s)).collect(),
+                path_bits: vec![],
+                leaf_key: hash32(&object.leaf_key),
+                exists: true,
+            };
+            proof.verify_inclusion(&hash32(&object.subnet_root), &hex::decode(&object.value).unwrap())
+        };
+
+        let mut coin_ids: Vec<String> = resp.coins.iter().map(|c| c.object.object_id.clone()).collect();
+        coin_ids.sort();
+        let mut expected = vec![hex::encode(root_coin.as_bytes()), hex::encode(game_coin.as_bytes())];
+        expected.sort();
+        assert_eq!(coin_ids, expected);
+        for coin in &resp.coins {
+            assert!(verify(&coin.object), "coin {} proof must verify", coin.object.object_id);
+        }
+        assert_eq!(resp.coins.iter().map(|c| c.balance).sum::<u64>(), 570);
+
+        let profile = resp.profile.expect("profile should be dumped");
+        assert_eq!(profile.object_id, hex::encode(profile_id.as_bytes()));
+        assert_eq!(profile.value, hex::encode(&profile_bytes));
+        assert!(verify(&profile));
+
+        // Every subnet root is proven under the reported global root
+        let (_, subnet_roots) = provider.shared_state_manager().load_snapshot().compute_global_root_bytes();
+        let builder = consensus::AnchorMerkleRootsBuilder::new().with_committed_subnet_roots(&subnet_roots);
+        for object in resp.coins.iter().map(|c| &c.object).chain([&profile]) {
+            let subnet_id = SubnetId::from_hex(&object.subnet_id).unwrap();
+            let expected = builder.prove_subnet_in_global(&subnet_id).unwrap();
+            let subnet_proof = object.subnet_proof.as_ref().expect("subnet proof attached");
+            assert_eq!(subnet_proof.leaf_index, expected.index);
+            assert_eq!(
+                subnet_proof.siblings,
+                expected.siblings.iter().map(|h| hex::encode(h.as_bytes())).collect::<Vec<_>>()
+            );
+            assert_eq!(object.subnet_root, hex::encode(subnet_roots[&subnet_id]));
+        }
+        // ...and those roots aggregate to exactly the reported global root
+        assert_eq!(hex::encode(builder.build().global_state_root), resp.global_state_root);
+    }
+
+    #[tokio::test]
+    async fn test_get_object_with_proof_verifies_against_state_root() {
+        use axum::extract::{Path, Query, State};
+        use axum::http::StatusCode;
+        use setu_storage::StateProvider;
+
+        let service = create_test_service();
+        let provider = service.merkle_state_provider();
+        let owner = setu_types::Address::normalize("object-proof-owner").to_string();
+        let coin_id = setu_storage::state_provider::init_coin_with_provider(provider, &owner, 250, "ROOT");
+        let fetch = |key: String, with_proof: Option<bool>| {
+            setu_api::http_get_object(
+                State(Arc::clone(&service)),
+                Path(key),
+                Query(setu_api::GetObjectQuery { with_proof }),
+            )
+        };
+
+        let resp = fetch(hex::encode(coin_id.as_bytes()), Some(true))
+            .await
+            .expect("coin object should be found")
+            .0;
+        let hash32 = |s: &str| -> [u8; 32] { hex::decode(s).unwrap().try_into().unwrap() };
+        let value = hex::decode(&resp.value).unwrap();
+        assert_eq!(Some(value.clone()), provider.get_object(&coin_id));
+        assert_eq!(resp.global_state_root, hex::encode(provider.get_state_root()));
+
+        let proof = setu_storage::SimpleMerkleProof {
+            siblings: resp.siblings.iter().map(|s| hash32(s)).collect(),
+            path_bits: vec![],
+            leaf_key: hash32(&resp.leaf_key),
+            exists: true,
+        };
+        assert!(proof.verify_inclusion(&hash32(&resp.state_root), &value));
+        assert!(!proof.verify_inclusion(&hash32(&resp.state_root), b"tampered"));
+
+        let missing = fetch(hex::encode([7u8; 32]), Some(true)).await.unwrap_err();
+        assert_eq!(missing.0, StatusCode::NOT_FOUND);
+        let invalid = fetch("not-hex".to_string(), Some(true)).await.unwrap_err();
+        assert_eq!(invalid.0, StatusCode::BAD_REQUEST);
+        let legacy = fetch(hex::encode(coin_id.as_bytes()), None).await.unwrap_err();
+        assert_eq!(legacy.0, StatusCode::GONE);
+    }
+
+    #[tokio::test]
+    async fn test_consensus_config_reflects_startup_vlc_delta_threshold() {
+        use axum::extract::State;
+        use axum::http::StatusCode;
+
+        let consensus_config = crate::ConsensusValidatorConfig {
+            consensus: setu_types::ConsensusConfig {
+                vlc_delta_threshold: 37,
+                validator_count: 1,
+                ..Default::default()
+            },
+            is_leader: true,
+            ..Default::default()
+        };
+        let service = Arc::new(ValidatorNetworkService::with_consensus(
+            "validator-1".to_string(),
+            Arc::new(RouterManager::new()),
+            Arc::new(TaskPreparer::new_for_testing("validator-1".to_string())),
+            Arc::new(BatchTaskPreparer::new_for_testing("validator-1".to_string())),
+            Arc::new(ConsensusValidator::new(consensus_config)),
+            NetworkServiceConfig::default(),
+        ));
+
+        let resp = setu_api::http_get_consensus_config(State(service)).await.unwrap().0;
+        assert_eq!(resp.config.vlc_delta_threshold, 37);
+        assert_eq!(resp.active_validator_count, 1);
+        assert_eq!(resp.quorum, 1);
+        let json = serde_json::to_value(&resp).unwrap();
+        assert_eq!(json["vlc_delta_threshold"], 37);
+        assert_eq!(json["quorum"], 1);
+
+        let disabled = setu_api::http_get_consensus_config(State(create_test_service()))
+            .await
+            .unwrap_err();
+        assert_eq!(disabled.0, StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_consensus_status_served_by_http_router() {
+        let consensus_config = crate::ConsensusValidatorConfig {
+            consensus: setu_types::ConsensusConfig {
+                validator_count: 1,
+                ..Default::default()
+            },
+            is_leader: true,
+            ..Default::default()
+        };
+        let service = Arc::new(ValidatorNetworkService::with_consensus(
+            "validator-1".to_string(),
+            Arc::new(RouterManager::new()),
+            Arc::new(TaskPreparer::new_for_testing("validator-1".to_string())),
+            Arc::new(BatchTaskPreparer::new_for_testing("validator-1".to_string())),
+            Arc::new(ConsensusValidator::new(consensus_config)),
+            NetworkServiceConfig::default(),
+        ));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = service.http_router();
+        let server = tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let response = reqwest::get(format!("http://{}/api/v1/consensus/status", addr))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        let status: setu_api::GetConsensusStatusResponse = response.json().await.unwrap();
+        assert_eq!(status.round, 0);
+        assert!(status.is_leader);
+        assert_eq!(status.current_leader.as_deref(), Some("validator-1"));
+        assert_eq!(status.validator_count, 1);
+        assert_eq!(status.quorum, 1);
+
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_explorer_tip_tracks_latest_finalized_anchor() {
+        use axum::extract::State;
+        use axum::http::StatusCode;
+
+        let consensus_config = crate::ConsensusValidatorConfig {
+            consensus: setu_types::ConsensusConfig {
+                // Fold on every event so each submit finalizes an anchor
+                vlc_delta_threshold: 1,
+                validator_count: 1,
+                ..Default::default()
+            },
+            is_leader: true,
+            ..Default::default()
+        };
+        let consensus = Arc::new(ConsensusValidator::new(consensus_config));
+        let service = Arc::new(ValidatorNetworkService::with_consensus(
+            "validator-1".to_string(),
+            Arc::new(RouterManager::new()),
+            Arc::new(TaskPreparer::new_for_testing("validator-1".to_string())),
+            Arc::new(BatchTaskPreparer::new_for_testing("validator-1".to_string())),
+            consensus.clone(),
+            NetworkServiceConfig::default(),
+        ));
+
+        let missing = setu_api::http_get_explorer_tip(State(service.clone()))
+            .await
+            .unwrap_err();
+        assert_eq!(missing.0, StatusCode::NOT_FOUND);
+
+        for logical_time in 1..=3 {
+            let mut event = Event::new(
+                setu_types::EventType::Transfer,
+                vec![],
+                setu_vlc::VLCSnapshot {
+                    vector_clock: setu_vlc::VectorClock::new(),
+                    logical_time,
+                    physical_time: logical_time,
+                },
+                "solver-1".to_string(),
+            );
+            event.set_execution_result(setu_types::ExecutionResult::success());
+            consensus.submit_event(event).await.unwrap();
+        }
+
+        let latest = consensus.anchor_store().get_latest().await.unwrap();
+        let tip = setu_api::http_get_explorer_tip(State(service.clone())).await.unwrap().0;
+        assert_eq!(tip.anchor_id, latest.id);
+        assert_eq!(tip.depth, latest.depth);
+        assert_eq!(tip.global_state_root, latest.state_root);
+        assert!(!tip.proposer.is_empty());
+
+        // Solo validator: the proposer's own vote is the whole certificate
+        let cert = setu_api::http_get_vote_certificate(
+            State(service.clone()),
+            axum::extract::Path(latest.id.clone()),
+        )
+        .await
+        .unwrap()
+        .0;
+        assert_eq!(cert.anchor_id, latest.id);
+        assert_eq!(cert.approvals.len(), 1);
+        assert_eq!(cert.approvals[0].validator_id, tip.proposer);
+
+        let missing = setu_api::http_get_vote_certificate(
+            State(service),
+            axum::extract::Path("unknown-anchor".to_string()),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(missing.0, StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_explorer_subnet_inclusion_proof() {
+        use setu_types::SubnetId;
+
+        let app_a = SubnetId::new_app_simple(11);
+        let app_b = SubnetId::new_app_simple(12);
+        let builder = consensus::AnchorMerkleRootsBuilder::new()
+            .with_committed_subnet_roots(&HashMap::from([
+                (SubnetId::ROOT, [1u8; 32]),
+                (app_a, [2u8; 32]),
+                (app_b, [3u8; 32]),
+            ]));
+        let expected = builder.prove_subnet_in_global(&app_b).unwrap();
+        let anchor = setu_types::Anchor::with_merkle_roots(
+            vec!["event-proof".to_string()],
+            test_vlc_snapshot(),
+            builder.build(),
+            None,
+            1,
+        );
+
+        let resp = ValidatorNetworkService::subnet_inclusion_proof(&anchor, &app_b).unwrap();
+        let roots = anchor.merkle_roots.as_ref().unwrap();
+        assert_eq!(resp.subnet_id, hex::encode(app_b.as_bytes()));
+        assert_eq!(resp.subnet_root, hex::encode([3u8; 32]));
+        assert_eq!(resp.global_state_root, hex::encode(roots.global_state_root));
+        assert_eq!(resp.leaf_index, expected.index);
+        assert_eq!(resp.siblings.len(), expected.siblings.len());
+
+        let unknown = SubnetId::new_app_simple(13);
+        assert!(ValidatorNetworkService::subnet_inclusion_proof(&anchor, &unknown).is_none());
+    }
+
+    fn create_test_service_with_consensus() -> (Arc<ValidatorNetworkService>, Arc<ConsensusValidator>) {
+        let consensus_config = crate::ConsensusValidatorConfig {
+            consensus: setu_types::ConsensusConfig {
+                validator_count: 1,
+                ..Default::default()
+            },
+            is_leader: true,
+            ..Default::default()
+        };
+        let consensus = Arc::new(ConsensusValidator::new(consensus_config));
+        let service = Arc::new(ValidatorNetworkService::with_consensus(
+            "validator-1".to_string(),
+            Arc::new(RouterManager::new()),
+            Arc::new(TaskPreparer::new_for_testing("validator-1".to_string())),
+            Arc::new(BatchTaskPreparer::new_for_testing("validator-1".to_string())),
+            consensus.clone(),
+            NetworkServiceConfig::default(),
+        ));
+        (service, consensus)
+    }
+
+    fn finalized_test_anchor(event_id: &str) -> setu_types::Anchor {
+        setu_types::Anchor::new(
+            vec![event_id.to_string()],
+            test_vlc_snapshot(),
+            "state_root".to_string(),
+            None,
+            1,
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn wait_for_finalization_gives_read_your_writes_balance() {
+        let (service, consensus) = create_test_service_with_consensus();
+        let event_id = "ryw-transfer-event";
+        assert_eq!(service.get_balance("ryw-receiver").balance, 0);
+
+        let waiter = {
+            let service = Arc::clone(&service);
+            tokio::spawn(async move {
+                service.wait_for_event_finalization(event_id, Duration::from_secs(5)).await
+            })
+        };
+        // Nothing finalized yet: the waiter parks on the finalization channel
+        tokio::task::yield_now().await;
+        assert!(!waiter.is_finished());
+
+        // Simulate the CF apply path: state lands, the outcome sink records,
+        // then the anchor is announced
+        let provider = service.merkle_state_provider();
+        setu_storage::state_provider::mint_subnet_token(provider, "ryw-subnet", "ryw-receiver", 42);
+        let shared = provider.shared_state_manager();
+        shared.publish_snapshot(&shared.lock_write());
+        service.execution_outcomes.insert(
+            event_id.to_string(),
+            ExecutionOutcome::Applied { cf_id: "cf-ryw".to_string() },
+        );
+        consensus.engine().publish_finalized_anchor(&finalized_test_anchor(event_id));
+
+        let outcome = waiter.await.unwrap();
+        let mut response = SubmitTransferResponse {
+            success: true,
+            message: "accepted".to_string(),
+            transfer_id: Some("tx-ryw".to_string()),
+            event_id: Some(event_id.to_string()),
+            solver_id: None,
+            processing_steps: vec![],
+            correlation_id: None,
+        };
+        ValidatorNetworkService::apply_finalization_outcome(&mut response, outcome);
+
+        assert!(response.success);
+        assert_eq!(response.processing_steps.last().unwrap().status, "completed");
+        assert_eq!(service.get_balance("ryw-receiver").balance, 42);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn wait_for_finalization_times_out_without_outcome() {
+        let (service, consensus) = create_test_service_with_consensus();
+
+        // An unrelated finalization wakes the waiter without ending the wait
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            consensus.engine().publish_finalized_anchor(&finalized_test_anchor("other-event"));
+        });
+
+        let started = tokio::time::Instant::now();
+        let outcome = service
+            .wait_for_event_finalization("ryw-never-finalized", TRANSFER_FINALIZATION_TIMEOUT)
+            .await;
+        assert_eq!(outcome, FinalizationWait::TimedOut);
+        assert!(started.elapsed() >= TRANSFER_FINALIZATION_TIMEOUT);
+
+        // Times out but keeps the accepted response
+        let mut response = SubmitTransferResponse {
+            success: true,
+            message: "accepted".to_string(),
+            transfer_id: Some("tx-timeout".to_string()),
+            event_id: Some("ryw-never-finalized".to_string()),
+            solver_id: None,
+            processing_steps: vec![],
+            correlation_id: None,
+        };
+        ValidatorNetworkService::apply_finalization_outcome(&mut response, outcome);
+        assert!(response.success);
+        assert_eq!(response.processing_steps.last().unwrap().status, "timeout");
+
+        // Without consensus no outcome can arrive, so there is nothing to wait for
+        let started = tokio::time::Instant::now();
+        let outcome = create_test_service()
+            .wait_for_event_finalization("ryw-never-finalized", TRANSFER_FINALIZATION_TIMEOUT)
+            .await;
+        assert_eq!(outcome, FinalizationWait::ConsensusDisabled);
+        assert_eq!(started.elapsed(), Duration::ZERO);
+
+        // ...and the response says so instead of reporting a timeout
+        let mut response = SubmitTransferResponse {
+            success: true,
+            message: "accepted".to_string(),
+            transfer_id: Some("tx-no-consensus".to_string()),
+            event_id: Some("ryw-never-finalized".to_string()),
+            solver_id: None,
+            processing_steps: vec![],
+            correlation_id: None,
+        };
+        ValidatorNetworkService::apply_finalization_outcome(&mut response, outcome);
+        assert!(response.success);
+        assert_eq!(response.processing_steps.last().unwrap().status, "consensus_disabled");
+        assert!(!response.message.contains("not finalized within"), "{}", response.message);
+    }
+
     #[tokio::test]
     async fn register_subnet_submit_failure_does_not_activate_subnet() {
         let service = create_test_service();
@@ -2739,6 +4334,40 @@ mod tests {
         assert!(service.pending_events.read().is_empty());
     }
 
+    #[tokio::test]
+    async fn submit_event_returns_correlation_id_and_records_it_in_event_metadata() {
+        let service = create_test_service();
+        let mut event = Event::new(
+            setu_types::EventType::System,
+            vec![],
+            test_vlc_snapshot(),
+            "validator-correlation".to_string(),
+        );
+        event.set_execution_result(setu_types::ExecutionResult::success());
+
+        let response = service.submit_event(SubmitEventRequest { event }).await;
+
+        assert!(response.success, "{}", response.message);
+        let correlation_id = response.correlation_id.expect("response must carry a correlation id");
+        let event_id = response.event_id.expect("accepted event must have an id");
+        let view = service.get_event_by_id(&event_id).expect("event must be queryable");
+        assert_eq!(view.metadata.correlation_id.as_deref(), Some(correlation_id.as_str()));
+
+        // Every submission gets its own id
+        let rejected = service
+            .submit_event(SubmitEventRequest {
+                event: Event::new(
+                    setu_types::EventType::System,
+                    vec![],
+                    test_vlc_snapshot(),
+                    "validator-correlation".to_string(),
+                ),
+            })
+            .await;
+        assert!(!rejected.success);
+        assert_ne!(rejected.correlation_id, Some(correlation_id));
+    }
+
     #[test]
     fn finalized_applied_subnet_event_is_query_visible() {
         let service = create_test_service();
@@ -2812,6 +4441,42 @@ mod tests {
         assert!(service.get_subnet_info("subnet-stale").is_none());
     }
 
+    #[tokio::test]
+    async fn event_statuses_report_finalized_pending_and_unknown_ids() {
+        let (service, consensus) = create_test_service_with_consensus();
+        let event_at = |logical_time: u64| {
+            Event::new(
+                setu_types::EventType::System,
+                vec![],
+                setu_vlc::VLCSnapshot {
+                    vector_clock: setu_vlc::VectorClock::new(),
+                    logical_time,
+                    physical_time: logical_time,
+                },
+                "validator-1".to_string(),
+            )
+        };
+        let (finalized, pending) = (event_at(1), event_at(2));
+        let (finalized_id, pending_id) = (finalized.id.clone(), pending.id.clone());
+
+        service.events.insert(pending_id.clone(), pending);
+        let anchor = finalized_test_anchor(&finalized_id);
+        consensus.anchor_store().store(anchor.clone()).await.unwrap();
+        service.cache_finalized_event_for_query(finalized);
+
+        let ids = vec![finalized_id.clone(), pending_id.clone(), "missing".to_string()];
+        let statuses = service.get_event_statuses(&ids).await.statuses;
+        assert_eq!(statuses.len(), 3);
+
+        let finalized = statuses[&finalized_id].as_ref().expect("finalized event is known");
+        assert_eq!(finalized.status, "Finalized");
+        assert_eq!(finalized.anchor_id.as_deref(), Some(anchor.id.as_str()));
+        let pending = statuses[&pending_id].as_ref().expect("pending event is known");
+        assert_eq!(pending.status, "Pending");
+        assert!(pending.anchor_id.is_none());
+        assert!(statuses["missing"].is_none());
+    }
+
     #[test]
     fn live_governance_projection_skips_non_applied_registration() {
         let service = create_test_service_with_governance();
@@ -0,0 +1,176 @@
+//! STF execution latency histogram
+//!
+//! Solvers report `execution_time_us` for every STF run. This module
+//! aggregates those values into a fixed-bucket histogram so latency SLOs can
+//! be tracked without keeping individual samples. Recording is lock-free
+//! (one atomic increment per bucket), so it is safe on the TEE hot path.
+//!
+//! The histogram is rendered in the Prometheus text exposition format by
+//! `GET /api/v1/metrics`, together with p50/p90/p99 estimates.
+
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Bucket upper bounds in microseconds (inclusive). Values above the last
+/// bound land in the implicit `+Inf` bucket.
+pub const STF_LATENCY_BUCKETS_US: [u64; 14] = [
+    100, 250, 500, 1_000, 2_500, 5_000, 10_000, 25_000, 50_000, 100_000, 250_000, 500_000,
+    1_000_000, 5_000_000,
+];
+
+/// Metric name used in the Prometheus output
+const METRIC_NAME: &str = "setu_stf_execution_time_us";
+
+/// Fixed-bucket histogram of STF execution times
+#[derive(Debug)]
+pub struct StfLatencyHistogram {
+    /// Per-bucket (non-cumulative) counts; the last slot is `+Inf`
+    buckets: [AtomicU64; STF_LATENCY_BUCKETS_US.len() + 1],
+    count: AtomicU64,
+    sum_us: AtomicU64,
+    max_us: AtomicU64,
+}
+
+impl StfLatencyHistogram {
+    pub fn new() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            count: AtomicU64::new(0),
+            sum_us: AtomicU64::new(0),
+            max_us: AtomicU64::new(0),
+        }
+    }
+
+    /// Record one STF execution time
+    pub fn record(&self, execution_time_us: u64) {
+        let idx = STF_LATENCY_BUCKETS_US
+            .iter()
+            .position(|&bound| execution_time_us <= bound)
+            .unwrap_or(STF_LATENCY_BUCKETS_US.len());
+        self.buckets[idx].fetch_add(1, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_us.fetch_add(execution_time_us, Ordering::Relaxed);
+        self.max_us.fetch_max(execution_time_us, Ordering::Relaxed);
+    }
+
+    /// Number of recorded executions
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Non-cumulative count per bucket; the last entry is the `+Inf` bucket
+    pub fn bucket_counts(&self) -> Vec<u64> {
+        self.buckets.iter().map(|b| b.load(Ordering::Relaxed)).collect()
+    }
+
+    /// Estimate the `q` quantile (0.0..=1.0) in microseconds.
+    ///
+    /// Returns the upper bound of the bucket holding the target rank, so the
+    /// estimate never understates latency. For the `+Inf` bucket the largest
+    /// observed value is returned. Returns 0 when nothing was recorded.
+    pub fn percentile(&self, q: f64) -> u64 {
+        let counts = self.bucket_counts();
+        let total: u64 = counts.iter().sum();
+        if total == 0 {
+            return 0;
+        }
+        let rank = ((q.clamp(0.0, 1.0) * total as f64).ceil() as u64).max(1);
+
+        let mut seen = 0;
+        for (idx, count) in counts.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return STF_LATENCY_BUCKETS_US
+                    .get(idx)
+                    .copied()
+                    .unwrap_or_else(|| self.max_us.load(Ordering::Relaxed));
+            }
+        }
+        self.max_us.load(Ordering::Relaxed)
+    }
+
+    /// Render the histogram and its p50/p90/p99 in Prometheus text format
+    pub fn render_prometheus(&self) -> String {
+        let counts = self.bucket_counts();
+        let mut out = String::new();
+
+        let _ = writeln!(out, "# HELP {} STF execution time reported by solvers, in microseconds.", METRIC_NAME);
+        let _ = writeln!(out, "# TYPE {} histogram", METRIC_NAME);
+        let mut cumulative = 0;
+        for (bound, count) in STF_LATENCY_BUCKETS_US.iter().zip(&counts) {
+            cumulative += count;
+            let _ = writeln!(out, "{}_bucket{{le=\"{}\"}} {}", METRIC_NAME, bound, cumulative);
+        }
+        cumulative += counts[STF_LATENCY_BUCKETS_US.len()];
+        let _ = writeln!(out, "{}_bucket{{le=\"+Inf\"}} {}", METRIC_NAME, cumulative);
+        let _ = writeln!(out, "{}_sum {}", METRIC_NAME, self.sum_us.load(Ordering::Relaxed));
+        let _ = writeln!(out, "{}_count {}", METRIC_NAME, self.count());
+
+        let _ = writeln!(out, "# HELP {}_quantile Bucket-resolution STF execution time percentiles.", METRIC_NAME);
+        let _ = writeln!(out, "# TYPE {}_quantile gauge", METRIC_NAME);
+        for (label, q) in [("0.5", 0.5), ("0.9", 0.9), ("0.99", 0.99)] {
+            let _ = writeln!(out, "{}_quantile{{quantile=\"{}\"}} {}", METRIC_NAME, label, self.percentile(q));
+        }
+        out
+    }
+}
+
+impl Default for StfLatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_into_fixed_buckets() {
+        let hist = StfLatencyHistogram::new();
+        for us in [80, 100, 400, 900, 900, 3_000, 40_000, 7_000_000] {
+            hist.record(us);
+        }
+
+        let counts = hist.bucket_counts();
+        assert_eq!(counts.len(), STF_LATENCY_BUCKETS_US.len() + 1);
+        assert_eq!(counts[0], 2); // <= 100 (bounds are inclusive)
+        assert_eq!(counts[2], 1); // <= 500
+        assert_eq!(counts[3], 2); // <= 1_000
+        assert_eq!(counts[5], 1); // <= 5_000
+        assert_eq!(counts[8], 1); // <= 50_000
+        assert_eq!(counts[STF_LATENCY_BUCKETS_US.len()], 1); // +Inf
+        assert_eq!(hist.count(), 8);
+
+        let p50 = hist.percentile(0.5);
+        let p90 = hist.percentile(0.9);
+        let p99 = hist.percentile(0.99);
+        assert_eq!(p50, 1_000);
+        assert!(p90 >= p50);
+        assert!(p99 >= p90);
+        // The +Inf bucket reports the largest observed value
+        assert_eq!(p99, 7_000_000);
+    }
+
+    #[test]
+    fn test_empty_histogram() {
+        let hist = StfLatencyHistogram::new();
+        assert_eq!(hist.percentile(0.99), 0);
+        assert!(hist.render_prometheus().contains("setu_stf_execution_time_us_count 0"));
+    }
+
+    #[test]
+    fn test_prometheus_buckets_are_cumulative() {
+        let hist = StfLatencyHistogram::new();
+        hist.record(50);
+        hist.record(200);
+        hist.record(200);
+
+        let text = hist.render_prometheus();
+        assert!(text.contains("setu_stf_execution_time_us_bucket{le=\"100\"} 1\n"));
+        assert!(text.contains("setu_stf_execution_time_us_bucket{le=\"250\"} 3\n"));
+        assert!(text.contains("setu_stf_execution_time_us_bucket{le=\"+Inf\"} 3\n"));
+        assert!(text.contains("setu_stf_execution_time_us_sum 450\n"));
+        assert!(text.contains("setu_stf_execution_time_us_quantile{quantile=\"0.5\"} 250\n"));
+    }
+}
@@ -28,6 +28,7 @@
 //! even if the execution panics.
 
 use super::types::*;
+use super::stf_metrics::StfLatencyHistogram;
 use super::solver_client::{
     ExecuteTaskRequest, ExecuteTaskResponse,
     ExecuteBatchRequest, ExecuteBatchResponse,
@@ -183,6 +184,8 @@ pub struct TeeExecutor {
     semaphore: Arc<Semaphore>,
     /// Count of pending TEE tasks
     pending_count: Arc<AtomicU64>,
+    /// Histogram of solver-reported STF execution times
+    stf_latency: Arc<StfLatencyHistogram>,
     /// Coin reservation manager for preventing cross-batch double-spending
     coin_reservation_manager: Option<Arc<CoinReservationManager>>,
 
@@ -280,6 +283,7 @@ impl TeeExecutor {
             validator_id,
             semaphore,
             pending_count: Arc::new(AtomicU64::new(0)),
+            stf_latency: Arc::new(StfLatencyHistogram::new()),
             coin_reservation_manager: None,
             batch_tx,
             batch_collector_alive,
@@ -300,6 +304,11 @@ impl TeeExecutor {
         self.coin_reservation_manager.as_ref()
     }
 
+    /// Histogram of STF execution times reported by solvers
+    pub fn stf_latency(&self) -> &StfLatencyHistogram {
+        &self.stf_latency
+    }
+
     #[cfg(test)]
     pub fn force_next_consensus_submit_failure(&self, message: impl Into<String>) {
         *self.forced_consensus_submit_failure.write() = Some(message.into());
@@ -354,9 +363,16 @@ impl TeeExecutor {
         let use_batch = self.batch_tx.is_some()
             && self.batch_collector_alive.load(Ordering::Acquire);
 
-        if use_batch {
+        let result = if use_batch {
             let batch_tx = self.batch_tx.as_ref().unwrap();
 
+            // Guard first, so every early return below releases the reservation
+            let reservations = ReservationGuard::new_batch(
+                self.coin_reservation_manager.clone(),
+                reservations,
+                transfer_id.to_string(),
+            );
+
             let solver_base_url = match self.solver_info.get(solver_id) {
                 Some(info) => info.http_url(),
                 None => return Err(format!("Solver not found: {}", solver_id)),
@@ -377,11 +393,7 @@ impl TeeExecutor {
                 solver_base_url,
                 request,
                 event,
-                reservations: ReservationGuard::new_batch(
-                    self.coin_reservation_manager.clone(),
-                    reservations,
-                    transfer_id.to_string(),
-                ),
+                reservations,
                 result_tx,
             };
 
@@ -406,7 +418,12 @@ impl TeeExecutor {
         } else {
             // Batch disabled or collector dead: use direct single-task path
             self.execute_solver_direct(transfer_id, solver_id, task, reservations).await
+        };
+
+        if let Ok((_, execution_time_us, _, _)) = &result {
+            self.stf_latency.record(*execution_time_us);
         }
+        result
     }
 
     /// Execute a solver task inline with batch reservation support (direct path).
@@ -531,6 +548,21 @@ impl TeeExecutor {
         // reservation_guard drops here, releasing if not already released
     }
 
+    /// Spawn `task` in the background, counted in [`Self::pending_count`]
+    /// until it completes so [`Self::wait_for_pending_tasks`] waits for it.
+    pub fn spawn_pending<F>(&self, task: F)
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        let pending_count = Arc::clone(&self.pending_count);
+        pending_count.fetch_add(1, Ordering::Relaxed);
+
+        tokio::spawn(async move {
+            task.await;
+            pending_count.fetch_sub(1, Ordering::Relaxed);
+        });
+    }
+
     /// Spawn post-execution work (consensus + storage) as background task
     ///
     /// Public stable paths should prefer awaited `submit_executed_event()` so
@@ -624,7 +656,11 @@ impl TeeExecutor {
             return Err(message);
         }
 
+        // Task-prepared events are created by this validator; sign them now
+        // that the execution result is attached.
+        let mut event = event.clone();
         if let Some(consensus_validator) = consensus {
+            consensus_validator.sign_local_event(&mut event).await;
             if let Err(e) = consensus_validator.submit_event(event.clone()).await {
                 let message = format!("Consensus submission failed: {}", e);
                 error!(
@@ -641,7 +677,7 @@ impl TeeExecutor {
             );
         }
 
-        events_store.insert(event_id.clone(), event.clone());
+        events_store.insert(event_id.clone(), event);
         dag_events.write().push(event_id.clone());
         Self::update_tracker_success(
             transfer_status,
@@ -707,6 +743,7 @@ impl TeeExecutor {
         let validator_id = self.validator_id.clone();
         let reservation_mgr = self.coin_reservation_manager.clone();
         let forced_consensus_submit_failure = Arc::clone(&self.forced_consensus_submit_failure);
+        let stf_latency = Arc::clone(&self.stf_latency);
 
         tokio::spawn(async move {
             Self::execute_tee_task_internal(
@@ -725,6 +762,7 @@ impl TeeExecutor {
                 reservation_mgr,
                 reservation,
                 forced_consensus_submit_failure,
+                stf_latency,
             )
             .await;
         });
@@ -756,6 +794,7 @@ impl TeeExecutor {
         reservation_mgr: Option<Arc<CoinReservationManager>>,
         reservation: Option<ReservationHandle>,
         forced_consensus_submit_failure: Arc<RwLock<Option<String>>>,
+        stf_latency: Arc<StfLatencyHistogram>,
     ) {
         let task_id_hex = hex::encode(&task.task_id[..8]);
 
@@ -854,6 +893,8 @@ impl TeeExecutor {
                 match bincode::deserialize::<ExecuteTaskResponse>(&bytes) {
                     Ok(exec_resp) if exec_resp.success => {
                         if let Some(result_dto) = exec_resp.result {
+                            stf_latency.record(result_dto.execution_time_us);
+
                             // 5a. Success: Build ExecutionResult and set on Event
                             let execution_result = setu_types::event::ExecutionResult {
                                 success: result_dto.events_failed == 0,
@@ -13,13 +13,21 @@
 //! - Reduces lock acquisitions from 5-6N to just 2
 //! - Caches state_root computation (N → 1)
 //! - Detects same-sender overdraft conflicts
+//! - Dispatches in [`TransferMempool`] policy order (fee priority or per-sender fair)
+//!
+//! Single and batch submissions share the service's [`TransferMempool`]: each
+//! transfer holds a dispatch permit from preparation through TEE dispatch, so
+//! under load both paths queue behind the same in-flight cap.
 
 use super::types::*;
 use super::tee_executor::TeeExecutor;
-use crate::{RouterManager, TaskPreparer, BatchTaskPreparer};
-use crate::coin_reservation::CoinReservationManager;
+use crate::{RouterManager, TaskPreparer, TaskPrepareError, BatchTaskPreparer, TransferAmountBounds};
+use crate::coin_reservation::{CoinReservationManager, ReservationHandle};
+use crate::mempool::TransferMempool;
 use dashmap::DashMap;
-use setu_types::{Transfer, TransferType, AssignedVlc};
+use setu_api::{EstimateFailure, EstimateTransferRequest, EstimateTransferResponse, EstimatedCoin};
+use setu_types::{Anchor, Event, Transfer, TransferType, AssignedVlc};
+use setu_types::task::SolverTask;
 use setu_rpc::{
     GetTransferStatusResponse, ProcessingStep,
     SubmitTransferRequest, SubmitTransferResponse,
@@ -37,11 +45,14 @@ impl TransferHandler {
     /// Process a transfer submission request
     ///
     /// This is the main entry point for transfer processing:
+    /// 0. Check the amount against `amount_bounds`, then wait for a
+    ///    dispatch slot in the shared `mempool`
     /// 1. Assign VLC time
     /// 2. Create Transfer object
     /// 3. Prepare SolverTask (with coin reservation)
     /// 4. Route to solver
-    /// 5. Spawn async TEE execution
+    /// 5. Execute on the solver inline, re-dispatching to the router's next
+    ///    candidate up to `max_redispatch_retries` times if the solver rejects
     #[allow(clippy::too_many_arguments)]
     pub async fn submit_transfer(
         validator_id: &str,
@@ -54,6 +65,9 @@ impl TransferHandler {
         vlc_time: u64,
         request: SubmitTransferRequest,
         tee_executor: &TeeExecutor,
+        mempool: &Arc<TransferMempool>,
+        amount_bounds: &TransferAmountBounds,
+        max_redispatch_retries: usize,
     ) -> SubmitTransferResponse {
         let now = current_timestamp_secs();
         let transfer_id = format!(
@@ -74,6 +88,31 @@ impl TransferHandler {
             timestamp: now,
         });
 
+        // Step 1a: Amount sanity bounds, before anything is reserved
+        if let Err(e) = amount_bounds.check(request.amount) {
+            return Self::fail_transfer(
+                transfer_id,
+                &format!("Transfer rejected: {}", e),
+                steps,
+                now,
+                transfer_status,
+            );
+        }
+
+        // Step 1b: Subnet scope. Coin selection and crediting happen in one
+        // subnet's SMT, so a recipient in another subnet needs a lock.
+        let subnet_id = match Self::resolve_transfer_subnet(&request) {
+            Ok(subnet_id) => subnet_id,
+            Err(message) => {
+                return Self::fail_transfer(transfer_id, &message, steps, now, transfer_status);
+            }
+        };
+
+        // Step 1c: Wait for a dispatch slot. Queued transfers (from this path
+        // or a batch) are granted in mempool policy order; the permit is held
+        // until the solver has executed the task.
+        let _dispatch = mempool.admit_one(&request.from, request.fee).await;
+
         // Step 2: VLC Assignment
         let now_millis = current_timestamp_millis();
 
@@ -129,14 +168,6 @@ impl TransferHandler {
 
         // Step 4a: Prepare SolverTask WITH COIN RESERVATION
         // This prevents double-spend between concurrent single/batch API calls
-        let subnet_id = match &transfer.subnet_id {
-            Some(subnet_str) if subnet_str != "subnet-0" => {
-                warn!(subnet = %subnet_str, "Custom subnet not supported, using ROOT");
-                setu_types::SubnetId::ROOT
-            }
-            _ => setu_types::SubnetId::ROOT,
-        };
-
         let (solver_task, reservation_handles) = match task_preparer.prepare_transfer_task_with_reservation(
             &transfer, subnet_id, coin_reservation_manager
         ) {
@@ -166,7 +197,7 @@ impl TransferHandler {
         };
 
         // Step 4b: Route to solver
-        let solver_id = match router_manager.route_transfer(&transfer) {
+        let mut solver_id = match router_manager.route_transfer(&transfer) {
             Ok(id) => {
                 steps.push(ProcessingStep {
                     step: "route".to_string(),
@@ -174,7 +205,7 @@ impl TransferHandler {
                     details: Some(format!("Routed to: {}", id)),
                     timestamp: now,
                 });
-                Some(id)
+                id
             }
             Err(e) => {
                 // Release reservation on routing failure
@@ -195,7 +226,7 @@ impl TransferHandler {
             TransferTracker {
                 transfer_id: transfer_id.clone(),
                 status: "pending_tee_execution".to_string(),
-                solver_id: solver_id.clone(),
+                solver_id: Some(solver_id.clone()),
                 event_id: None,
                 processing_steps: steps.clone(),
                 created_at: now,
@@ -203,12 +234,10 @@ impl TransferHandler {
         );
 
         // Add to reverse index for O(1) lookup during TEE completion
-        if let Some(ref sid) = solver_id {
-            solver_pending_transfers
-                .entry(sid.clone())
-                .or_insert_with(Vec::new)
-                .push(transfer_id.clone());
-        }
+        solver_pending_transfers
+            .entry(solver_id.clone())
+            .or_insert_with(Vec::new)
+            .push(transfer_id.clone());
 
         // Step 6: Execute Solver INLINE (await) → release coin → submit consensus
         //
@@ -222,83 +251,311 @@ impl TransferHandler {
         // - Natural backpressure (HTTP connection blocks until Solver responds)
         // - No retry storm (coin released before HTTP response)
         // - No accepted-looking response until direct consensus submit succeeds
-        if let Some(ref sid) = solver_id {
-            match tee_executor.execute_solver_inline_batch(
-                &transfer_id, sid, solver_task, reservation_handles,
+        //
+        // Step 6a: If the solver rejects the task (overloaded, attestation failed, ...)
+        // the executor releases its reservation; the task is prepared again with a
+        // fresh reservation and re-dispatched to the router's next candidate, up to
+        // `max_redispatch_retries` times.
+        let reprepare = || {
+            task_preparer
+                .prepare_transfer_task_with_reservation(&transfer, subnet_id, coin_reservation_manager)
+                .map_err(|e| e.to_string())
+        };
+        let (event, execution_time_us, events_processed) = match Self::execute_with_redispatch(
+            &transfer,
+            &mut solver_id,
+            solver_task,
+            reservation_handles,
+            reprepare,
+            router_manager,
+            coin_reservation_manager,
+            transfer_status,
+            solver_pending_transfers,
+            tee_executor,
+            max_redispatch_retries,
+            &mut steps,
+            now,
+        ).await {
+            Ok(executed) => executed,
+            Err(last_error) => {
+                return SubmitTransferResponse {
+                    success: false,
+                    message: format!("TEE execution failed: {}", last_error),
+                    transfer_id: Some(transfer_id),
+                    event_id: None,
+                    solver_id: Some(solver_id),
+                    processing_steps: steps,
+                    correlation_id: None,
+                };
+            }
+        };
+
+        let event_id = match tee_executor.submit_executed_event(
+            &transfer_id,
+            &event,
+            execution_time_us,
+            events_processed,
+        ).await {
+            Ok(event_id) => event_id,
+            Err(e) => {
+                let mut failed_steps = steps.clone();
+                failed_steps.push(ProcessingStep {
+                    step: "consensus_submit".to_string(),
+                    status: "failed".to_string(),
+                    details: Some(e.clone()),
+                    timestamp: now,
+                });
+                return SubmitTransferResponse {
+                    success: false,
+                    message: e,
+                    transfer_id: Some(transfer_id),
+                    event_id: None,
+                    solver_id: Some(solver_id),
+                    processing_steps: failed_steps,
+                    correlation_id: None,
+                };
+            }
+        };
+
+        info!(transfer_id = %transfer_id, solver_id = %solver_id, "Transfer executed inline and submitted to consensus");
+
+        SubmitTransferResponse {
+            success: true,
+            message: "Transfer executed and accepted into consensus DAG; finality pending".to_string(),
+            transfer_id: Some(transfer_id),
+            event_id: Some(event_id),
+            solver_id: Some(solver_id),
+            processing_steps: steps,
+            correlation_id: None,
+        }
+    }
+
+    /// Execute `task` on `solver_id`, re-dispatching when the solver rejects it.
+    ///
+    /// After each rejection the task is prepared again through `reprepare`
+    /// (the executor has already released the previous reservation) and routed
+    /// to the router's next candidate, skipping every solver that rejected it,
+    /// up to `max_redispatch_retries` times. `solver_id`, `steps`, the tracker
+    /// and the solver reverse index follow each re-dispatch. Once retries are
+    /// exhausted the tracker is marked failed and the last solver error is
+    /// returned.
+    #[allow(clippy::too_many_arguments)]
+    async fn execute_with_redispatch<P>(
+        transfer: &Transfer,
+        solver_id: &mut String,
+        task: SolverTask,
+        reservation_handles: Vec<ReservationHandle>,
+        reprepare: P,
+        router_manager: &RouterManager,
+        coin_reservation_manager: &CoinReservationManager,
+        transfer_status: &DashMap<String, TransferTracker>,
+        solver_pending_transfers: &DashMap<String, Vec<String>>,
+        tee_executor: &TeeExecutor,
+        max_redispatch_retries: usize,
+        steps: &mut Vec<ProcessingStep>,
+        now: u64,
+    ) -> Result<(Event, u64, usize), String>
+    where
+        P: Fn() -> Result<(SolverTask, Vec<ReservationHandle>), String>,
+    {
+        let transfer_id = &transfer.id;
+        let mut solver_task = task;
+        let mut reservation_handles = reservation_handles;
+        let mut rejected_by: Vec<String> = Vec::new();
+
+        loop {
+            let last_error = match tee_executor.execute_solver_inline_batch(
+                transfer_id, solver_id, solver_task, reservation_handles,
             ).await {
                 Ok((event, execution_time_us, events_processed, _gas_used)) => {
-                    let event_id = match tee_executor.submit_executed_event(
-                        &transfer_id,
-                        &event,
-                        execution_time_us,
-                        events_processed,
-                    ).await {
-                        Ok(event_id) => event_id,
-                        Err(e) => {
-                            let mut failed_steps = steps.clone();
-                            failed_steps.push(ProcessingStep {
-                                step: "consensus_submit".to_string(),
-                                status: "failed".to_string(),
-                                details: Some(e.clone()),
-                                timestamp: now,
-                            });
-                            return SubmitTransferResponse {
-                                success: false,
-                                message: e,
-                                transfer_id: Some(transfer_id),
-                                event_id: None,
-                                solver_id,
-                                processing_steps: failed_steps,
-                            };
-                        }
-                    };
+                    return Ok((event, execution_time_us, events_processed));
+                }
+                Err(e) => e,
+            };
 
-                    info!(transfer_id = %transfer_id, solver_id = ?solver_id, "Transfer executed inline and submitted to consensus");
+            warn!(
+                transfer_id = %transfer_id,
+                solver_id = %solver_id,
+                attempt = rejected_by.len() + 1,
+                error = %last_error,
+                "Solver rejected task"
+            );
+            let rejected_step = ProcessingStep {
+                step: "tee_execution".to_string(),
+                status: "failed".to_string(),
+                details: Some(format!("{}: {}", solver_id, last_error)),
+                timestamp: now,
+            };
+            steps.push(rejected_step.clone());
+            if let Some(mut pending) = solver_pending_transfers.get_mut(solver_id.as_str()) {
+                pending.retain(|id| id != transfer_id);
+            }
+            rejected_by.push(solver_id.clone());
 
-                    SubmitTransferResponse {
-                        success: true,
-                        message: "Transfer executed and accepted into consensus DAG; finality pending".to_string(),
-                        transfer_id: Some(transfer_id),
-                        event_id: Some(event_id),
-                        solver_id,
-                        processing_steps: steps,
+            let redispatch = if rejected_by.len() > max_redispatch_retries {
+                Err(format!("retries exhausted after {} attempts", rejected_by.len()))
+            } else {
+                reprepare()
+                    .map_err(|e| format!("re-prepare failed: {}", e))
+                    .and_then(|(task, handles)| {
+                        match router_manager.route_transfer_excluding(transfer, &rejected_by) {
+                            Ok(id) => Ok((id, task, handles)),
+                            Err(e) => {
+                                coin_reservation_manager.release_batch(&handles);
+                                Err(format!("no alternate solver: {}", e))
+                            }
+                        }
+                    })
+            };
+
+            match redispatch {
+                Ok((next_solver, task, handles)) => {
+                    let route_step = ProcessingStep {
+                        step: "route".to_string(),
+                        status: "completed".to_string(),
+                        details: Some(format!("Re-dispatched to: {}", next_solver)),
+                        timestamp: now,
+                    };
+                    steps.push(route_step.clone());
+                    if let Some(mut tracker) = transfer_status.get_mut(transfer_id) {
+                        tracker.solver_id = Some(next_solver.clone());
+                        tracker.processing_steps.push(rejected_step);
+                        tracker.processing_steps.push(route_step);
                     }
+                    solver_pending_transfers
+                        .entry(next_solver.clone())
+                        .or_insert_with(Vec::new)
+                        .push(transfer_id.clone());
+                    *solver_id = next_solver;
+                    solver_task = task;
+                    reservation_handles = handles;
                 }
-                Err(e) => {
-                    error!(transfer_id = %transfer_id, error = %e, "Inline TEE execution failed");
+                Err(reason) => {
+                    error!(transfer_id = %transfer_id, error = %last_error, %reason, "TEE execution failed");
                     // Update existing tracker to failed
-                    if let Some(mut tracker) = transfer_status.get_mut(&transfer_id) {
+                    if let Some(mut tracker) = transfer_status.get_mut(transfer_id) {
                         tracker.status = "failed".to_string();
-                        tracker.processing_steps.push(ProcessingStep {
-                            step: "tee_execution".to_string(),
-                            status: "failed".to_string(),
-                            details: Some(e.clone()),
-                            timestamp: now,
-                        });
-                    }
-                    SubmitTransferResponse {
-                        success: false,
-                        message: format!("TEE execution failed: {}", e),
-                        transfer_id: Some(transfer_id),
-                        event_id: None,
-                        solver_id,
-                        processing_steps: steps,
+                        tracker.processing_steps.push(rejected_step);
                     }
+                    return Err(last_error);
                 }
             }
-        } else {
-            // Shouldn't reach here (routing above returns early on failure)
-            SubmitTransferResponse {
-                success: true,
-                message: "Transfer submitted".to_string(),
-                transfer_id: Some(transfer_id),
-                event_id: None,
-                solver_id,
-                processing_steps: steps,
+        }
+    }
+
+    /// Spawn TEE execution of one batch task, with the single path's
+    /// re-dispatch policy. A rejected task is prepared again as a one-transfer
+    /// batch so it takes a fresh reservation.
+    #[allow(clippy::too_many_arguments)]
+    fn spawn_batch_execution(
+        transfer: Transfer,
+        solver_id: String,
+        task: SolverTask,
+        reservation: Option<ReservationHandle>,
+        router_manager: &Arc<RouterManager>,
+        batch_preparer: &Arc<BatchTaskPreparer>,
+        coin_reservation_manager: &Arc<CoinReservationManager>,
+        transfer_status: &Arc<DashMap<String, TransferTracker>>,
+        solver_pending_transfers: &Arc<DashMap<String, Vec<String>>>,
+        tee_executor: &Arc<TeeExecutor>,
+        max_redispatch_retries: usize,
+        now: u64,
+    ) {
+        let router_manager = Arc::clone(router_manager);
+        let batch_preparer = Arc::clone(batch_preparer);
+        let coin_reservation_manager = Arc::clone(coin_reservation_manager);
+        let transfer_status = Arc::clone(transfer_status);
+        let solver_pending_transfers = Arc::clone(solver_pending_transfers);
+        let executor = Arc::clone(tee_executor);
+
+        tee_executor.spawn_pending(async move {
+            let mut solver_id = solver_id;
+            let mut steps = Vec::new();
+            let reprepare = || {
+                let mut prepared = batch_preparer.prepare_transfers_batch_with_reservation(
+                    std::slice::from_ref(&transfer),
+                    &coin_reservation_manager,
+                );
+                match prepared.tasks.pop() {
+                    Some(task) => {
+                        let handles = prepared.reservations.pop().flatten().into_iter().collect();
+                        Ok((task, handles))
+                    }
+                    None => Err(prepared
+                        .failures
+                        .pop()
+                        .map(|(_, e)| e.to_string())
+                        .unwrap_or_else(|| "no task prepared".to_string())),
+                }
+            };
+            let executed = Self::execute_with_redispatch(
+                &transfer,
+                &mut solver_id,
+                task,
+                reservation.into_iter().collect(),
+                reprepare,
+                &router_manager,
+                &coin_reservation_manager,
+                &transfer_status,
+                &solver_pending_transfers,
+                &executor,
+                max_redispatch_retries,
+                &mut steps,
+                now,
+            ).await;
+
+            // A failed execution has already marked the tracker failed
+            if let Ok((event, execution_time_us, events_processed)) = executed {
+                match executor
+                    .submit_executed_event(&transfer.id, &event, execution_time_us, events_processed)
+                    .await
+                {
+                    Ok(event_id) => info!(
+                        transfer_id = %transfer.id,
+                        solver_id = %solver_id,
+                        event_id = %event_id,
+                        "Batch transfer executed and submitted to consensus"
+                    ),
+                    Err(e) => error!(
+                        transfer_id = %transfer.id,
+                        error = %e,
+                        "Batch transfer consensus submission failed"
+                    ),
+                }
             }
+        });
+    }
+
+    /// Map a request's subnet name to its SMT: none, `"subnet-0"` and
+    /// `"ROOT"` are the root subnet, anything else maps like a coin type.
+    fn parse_subnet(subnet: Option<&str>) -> setu_types::SubnetId {
+        match subnet {
+            None | Some("subnet-0") => setu_types::SubnetId::ROOT,
+            Some(name) => setu_types::SubnetId::from_coin_type(name),
         }
     }
 
+    /// Resolve the subnet a transfer executes in, rejecting a recipient in a
+    /// different subnet: moving value across subnets goes through a
+    /// `CrossSubnetLock`, never a plain transfer.
+    fn resolve_transfer_subnet(
+        request: &SubmitTransferRequest,
+    ) -> Result<setu_types::SubnetId, String> {
+        let subnet_id = Self::parse_subnet(request.subnet_id.as_deref());
+        let Some(recipient_subnet) = request.recipient_subnet_id.as_deref() else {
+            return Ok(subnet_id);
+        };
+        if Self::parse_subnet(Some(recipient_subnet)) == subnet_id {
+            return Ok(subnet_id);
+        }
+        Err(format!(
+            "Cross-subnet transfer rejected: sender subnet {} differs from recipient subnet {}; \
+             transfers between subnets require a cross-subnet lock, use the cross-subnet transfer flow",
+            request.subnet_id.as_deref().unwrap_or("ROOT"),
+            recipient_subnet,
+        ))
+    }
+
     /// Create a failed transfer response
     fn fail_transfer(
         transfer_id: String,
@@ -335,6 +592,7 @@ impl TransferHandler {
             event_id: None,
             solver_id: None,
             processing_steps: steps,
+            correlation_id: None,
         }
     }
 
@@ -364,6 +622,101 @@ impl TransferHandler {
         }
     }
 
+    /// Build a status response from persisted storage
+    ///
+    /// Used when the in-memory tracker has no entry (e.g. after a restart).
+    /// A transfer whose event is contained in a persisted anchor is
+    /// `finalized`; an event stored without an anchor is still `executed`.
+    pub fn persisted_transfer_status(
+        transfer_id: &str,
+        event: &Event,
+        anchor: Option<&Anchor>,
+    ) -> GetTransferStatusResponse {
+        let (status, step) = match anchor {
+            Some(anchor) => (
+                "finalized",
+                ProcessingStep {
+                    step: "finalize".to_string(),
+                    status: "completed".to_string(),
+                    details: Some(format!("Anchor {} (depth {})", anchor.id, anchor.depth)),
+                    timestamp: anchor.timestamp / 1000,
+                },
+            ),
+            None => (
+                "executed",
+                ProcessingStep {
+                    step: "finalize".to_string(),
+                    status: "pending".to_string(),
+                    details: Some("Event persisted, no containing anchor yet".to_string()),
+                    timestamp: event.timestamp / 1000,
+                },
+            ),
+        };
+
+        GetTransferStatusResponse {
+            found: true,
+            transfer_id: transfer_id.to_string(),
+            status: Some(status.to_string()),
+            solver_id: None,
+            event_id: Some(event.id.clone()),
+            processing_steps: vec![step],
+        }
+    }
+
+    /// Dry-run a transfer: coin selection and fee, without reserving or submitting
+    pub fn estimate_transfer(
+        task_preparer: &TaskPreparer,
+        coin_reservation_manager: &CoinReservationManager,
+        request: EstimateTransferRequest,
+        amount_bounds: &TransferAmountBounds,
+    ) -> EstimateTransferResponse {
+        // Same subnet resolution as `submit_transfer`
+        let subnet_id = Self::parse_subnet(request.subnet_id.as_deref());
+
+        let estimate = amount_bounds.check(request.amount).and_then(|()| {
+            task_preparer.estimate_transfer(
+                &request.from, request.amount, subnet_id, coin_reservation_manager
+            )
+        });
+        match estimate {
+            Ok(estimate) => EstimateTransferResponse {
+                feasible: true,
+                selected_coins: estimate.selected_coins.iter()
+                    .map(|c| EstimatedCoin {
+                        object_id: hex::encode(c.object_id.as_bytes()),
+                        balance: c.balance,
+                        version: c.version,
+                    })
+                    .collect(),
+                total_fee: estimate.total_fee,
+                burn: estimate.total_burn,
+                resulting_balance: estimate.resulting_balance,
+                failure: None,
+            },
+            Err(e) => {
+                debug!(from = %request.from, amount = request.amount, error = %e, "Transfer estimate infeasible");
+                let failure = match e {
+                    TaskPrepareError::InsufficientBalance { required, available } => {
+                        EstimateFailure::InsufficientBalance { required, available }
+                    }
+                    TaskPrepareError::AllCoinsReserved { coin_count, .. } => {
+                        EstimateFailure::AllCoinsReserved { coin_count }
+                    }
+                    TaskPrepareError::NoCoinsFound(_) => EstimateFailure::NoCoins,
+                    other => EstimateFailure::InvalidRequest { message: other.to_string() },
+                };
+                EstimateTransferResponse {
+                    feasible: false,
+                    selected_coins: vec![],
+                    total_fee: 0,
+                    burn: 0,
+                    resulting_balance: 0,
+                    failure: Some(failure),
+                }
+            }
+        }
+    }
+
     // ============================================
     // Batch Transfer Processing (High-Throughput)
     // ============================================
@@ -379,6 +732,8 @@ impl TransferHandler {
     /// - Lock acquisitions: 2 (regardless of batch size) vs 5-6N for single
     /// - state_root calculations: 1 vs N
     /// - Same-sender overdraft detection built-in
+    /// - Tasks rejected by their solver are re-dispatched in the background,
+    ///   with the same `max_redispatch_retries` policy as the single path
     ///
     /// ## Performance
     ///
@@ -389,15 +744,18 @@ impl TransferHandler {
     #[allow(clippy::too_many_arguments)]
     pub async fn submit_transfers_batch(
         validator_id: &str,
-        router_manager: &RouterManager,
-        batch_preparer: &BatchTaskPreparer,
-        coin_reservation_manager: &CoinReservationManager,
+        router_manager: &Arc<RouterManager>,
+        batch_preparer: &Arc<BatchTaskPreparer>,
+        coin_reservation_manager: &Arc<CoinReservationManager>,
         transfer_status: &Arc<DashMap<String, TransferTracker>>,
         solver_pending_transfers: &Arc<DashMap<String, Vec<String>>>,
         transfer_counter: &AtomicU64,
         vlc_counter: &AtomicU64,
         request: SubmitTransfersBatchRequest,
-        tee_executor: &TeeExecutor,
+        tee_executor: &Arc<TeeExecutor>,
+        mempool: &Arc<TransferMempool>,
+        amount_bounds: &TransferAmountBounds,
+        max_redispatch_retries: usize,
     ) -> SubmitTransfersBatchResponse {
         let now = current_timestamp_secs();
         let batch_size = request.transfers.len();
@@ -443,6 +801,8 @@ impl TransferHandler {
         let now_millis = current_timestamp_millis();
         let mut transfers: Vec<Transfer> = Vec::with_capacity(batch_size);
         let mut transfer_id_map: Vec<String> = Vec::with_capacity(batch_size);
+        let mut rejected: Vec<(Transfer, TaskPrepareError)> = Vec::new();
+        let mut admitted: Vec<usize> = Vec::with_capacity(batch_size);
 
         for (idx, req) in request.transfers.iter().enumerate() {
             let transfer_id = format!(
@@ -490,133 +850,181 @@ impl TransferHandler {
                 "Added transfer to batch"
             );
 
+            // Out-of-bounds amounts never reach the mempool or preparation
+            match amount_bounds.check(req.amount) {
+                Ok(()) => admitted.push(idx),
+                Err(e) => rejected.push((transfer.clone(), e)),
+            }
             transfer_id_map.push(transfer_id);
             transfers.push(transfer);
         }
 
-        // Step 2: Batch prepare all tasks WITH COIN RESERVATION (2 lock acquisitions total!)
-        // This prevents cross-batch double-spending by reserving coins
-        let batch_result = batch_preparer.prepare_transfers_batch_with_reservation(
-            &transfers,
-            coin_reservation_manager,
+        // Step 1b: Admit into the shared mempool. Transfers are granted dispatch
+        // slots in policy order, interleaved with concurrent single and batch
+        // submissions, so higher-priority transfers reserve coins and reach the
+        // TEE executor first. `transfers` keeps request order for result indexing.
+        let mut admissions = mempool.admit(
+            admitted
+                .iter()
+                .map(|&idx| (idx, request.transfers[idx].from.as_str(), request.transfers[idx].fee)),
         );
 
-        info!(
-            total = batch_result.stats.total_transfers,
-            successful = batch_result.stats.successful,
-            failed = batch_result.stats.failed,
-            conflicts = batch_result.stats.same_sender_conflicts,
-            reserved = batch_result.reservations.iter().filter(|r| r.is_some()).count(),
-            "Batch task preparation with reservation completed"
-        );
-
-        // Step 3: Build results and spawn TEE tasks
         let mut results: Vec<BatchTransferResult> = Vec::with_capacity(batch_size);
         let mut submitted_count = 0;
         let mut failed_count = 0;
+        let mut stats = BatchPrepareStatsResponse::default();
+        let mut failures = rejected;
 
         // Track which transfer indices succeeded (for result ordering)
         let mut success_indices: std::collections::HashSet<usize> = std::collections::HashSet::new();
 
-        // Process successful tasks with their reservations
-        // Note: tasks and reservations are aligned by index
-        for (task_idx, task) in batch_result.tasks.into_iter().enumerate() {
-            // Get the corresponding reservation (if any)
-            let reservation = batch_result.reservations
-                .get(task_idx)
-                .and_then(|r| r.clone());
-
-            // Find the transfer index by matching transfer_id from event
-            let transfer_id = task.event.transfer
-                .as_ref()
-                .map(|t| t.id.clone())
-                .unwrap_or_default();
-            if let Some(idx) = transfer_id_map.iter().position(|id| id == &transfer_id) {
-                success_indices.insert(idx);
-
-                // Route to solver
-                match router_manager.route_transfer(&transfers[idx]) {
-                    Ok(solver_id) => {
-                        // Store status
-                        transfer_status.insert(
-                            transfer_id.clone(),
-                            TransferTracker {
-                                transfer_id: transfer_id.clone(),
-                                status: "pending_tee_execution".to_string(),
-                                solver_id: Some(solver_id.clone()),
-                                event_id: None,
-                                processing_steps: vec![ProcessingStep {
-                                    step: "batch_submit".to_string(),
-                                    status: "completed".to_string(),
-                                    details: Some(format!("Batch index: {}", idx)),
-                                    timestamp: now,
-                                }],
-                                created_at: now,
-                            },
-                        );
-
-                        // Add to reverse index
-                        solver_pending_transfers
-                            .entry(solver_id.clone())
-                            .or_insert_with(Vec::new)
-                            .push(transfer_id.clone());
-
-                        // Spawn TEE task with reservation (reservation will be released after task completion)
-                        tee_executor.spawn_tee_task_with_reservation(
-                            transfer_id.clone(), 
-                            solver_id.clone(), 
-                            task,
-                            reservation,
-                        );
-
-                        results.push(BatchTransferResult {
-                            index: idx,
-                            success: true,
-                            transfer_id: Some(transfer_id),
-                            solver_id: Some(solver_id),
-                            error: None,
-                        });
-                        submitted_count += 1;
-                    }
-                    Err(e) => {
-                        // Release reservation on routing failure
-                        if let Some(ref handle) = reservation {
-                            coin_reservation_manager.release(handle);
+        // Each round takes every grant available, so a batch larger than the
+        // mempool's in-flight cap is prepared in chunks as slots free up.
+        while let Some(first) = admissions.next().await {
+            let mut granted = vec![first];
+            while let Some(next) = admissions.try_next() {
+                granted.push(next);
+            }
+            let dispatch_order: Vec<Transfer> = granted
+                .iter()
+                .map(|(idx, _)| transfers[*idx].clone())
+                .collect();
+
+            // Step 2: Batch prepare the granted tasks WITH COIN RESERVATION (2 lock acquisitions per chunk)
+            // This prevents cross-batch double-spending by reserving coins
+            let batch_result = batch_preparer.prepare_transfers_batch_with_reservation(
+                &dispatch_order,
+                coin_reservation_manager,
+            );
+
+            info!(
+                total = batch_result.stats.total_transfers,
+                successful = batch_result.stats.successful,
+                failed = batch_result.stats.failed,
+                conflicts = batch_result.stats.same_sender_conflicts,
+                reserved = batch_result.reservations.iter().filter(|r| r.is_some()).count(),
+                pending = admissions.pending(),
+                "Batch task preparation with reservation completed"
+            );
+
+            stats.total_transfers += batch_result.stats.total_transfers;
+            stats.unique_sender_subnet_pairs += batch_result.stats.unique_sender_subnet_pairs;
+            stats.coins_selected += batch_result.stats.coins_selected;
+            stats.same_sender_conflicts += batch_result.stats.same_sender_conflicts;
+
+            // Step 3: Build results and spawn TEE tasks
+            // Process successful tasks with their reservations
+            // Note: tasks and reservations are aligned by index
+            for (task_idx, task) in batch_result.tasks.into_iter().enumerate() {
+                // Get the corresponding reservation (if any)
+                let reservation = batch_result.reservations
+                    .get(task_idx)
+                    .and_then(|r| r.clone());
+
+                // Find the transfer index by matching transfer_id from event
+                let transfer_id = task.event.transfer
+                    .as_ref()
+                    .map(|t| t.id.clone())
+                    .unwrap_or_default();
+                if let Some(idx) = transfer_id_map.iter().position(|id| id == &transfer_id) {
+                    success_indices.insert(idx);
+
+                    // Route to solver
+                    match router_manager.route_transfer(&transfers[idx]) {
+                        Ok(solver_id) => {
+                            // Store status
+                            transfer_status.insert(
+                                transfer_id.clone(),
+                                TransferTracker {
+                                    transfer_id: transfer_id.clone(),
+                                    status: "pending_tee_execution".to_string(),
+                                    solver_id: Some(solver_id.clone()),
+                                    event_id: None,
+                                    processing_steps: vec![ProcessingStep {
+                                        step: "batch_submit".to_string(),
+                                        status: "completed".to_string(),
+                                        details: Some(format!("Batch index: {}", idx)),
+                                        timestamp: now,
+                                    }],
+                                    created_at: now,
+                                },
+                            );
+
+                            // Add to reverse index
+                            solver_pending_transfers
+                                .entry(solver_id.clone())
+                                .or_insert_with(Vec::new)
+                                .push(transfer_id.clone());
+
+                            // Spawn TEE execution with the reservation. A task the solver
+                            // rejects is re-dispatched exactly as on the single path.
+                            Self::spawn_batch_execution(
+                                transfers[idx].clone(),
+                                solver_id.clone(),
+                                task,
+                                reservation,
+                                router_manager,
+                                batch_preparer,
+                                coin_reservation_manager,
+                                transfer_status,
+                                solver_pending_transfers,
+                                tee_executor,
+                                max_redispatch_retries,
+                                now,
+                            );
+
+                            results.push(BatchTransferResult {
+                                index: idx,
+                                success: true,
+                                transfer_id: Some(transfer_id),
+                                solver_id: Some(solver_id),
+                                error: None,
+                            });
+                            submitted_count += 1;
                         }
+                        Err(e) => {
+                            // Release reservation on routing failure
+                            if let Some(ref handle) = reservation {
+                                coin_reservation_manager.release(handle);
+                            }
 
-                        results.push(BatchTransferResult {
-                            index: idx,
-                            success: false,
-                            transfer_id: Some(transfer_id.clone()),
-                            solver_id: None,
-                            error: Some(format!("Routing failed: {}", e)),
-                        });
-                        failed_count += 1;
-
-                        // Update status
-                        transfer_status.insert(
-                            transfer_id.clone(),
-                            TransferTracker {
-                                transfer_id: transfer_id.clone(),
-                                status: "failed".to_string(),
+                            results.push(BatchTransferResult {
+                                index: idx,
+                                success: false,
+                                transfer_id: Some(transfer_id.clone()),
                                 solver_id: None,
-                                event_id: None,
-                                processing_steps: vec![ProcessingStep {
-                                    step: "route".to_string(),
+                                error: Some(format!("Routing failed: {}", e)),
+                            });
+                            failed_count += 1;
+
+                            // Update status
+                            transfer_status.insert(
+                                transfer_id.clone(),
+                                TransferTracker {
+                                    transfer_id: transfer_id.clone(),
                                     status: "failed".to_string(),
-                                    details: Some(e.to_string()),
-                                    timestamp: now,
-                                }],
-                                created_at: now,
-                            },
-                        );
+                                    solver_id: None,
+                                    event_id: None,
+                                    processing_steps: vec![ProcessingStep {
+                                        step: "route".to_string(),
+                                        status: "failed".to_string(),
+                                        details: Some(e.to_string()),
+                                        timestamp: now,
+                                    }],
+                                    created_at: now,
+                                },
+                            );
+                        }
                     }
                 }
             }
+
+            failures.extend(batch_result.failures);
+            // Dispatched; `granted` drops here and frees the slots
         }
 
-        // Process failures from batch preparation
-        for (failed_transfer, error) in batch_result.failures {
+        // Process amount-bound rejections and failures from batch preparation
+        for (failed_transfer, error) in failures {
             if let Some(idx) = transfer_id_map.iter().position(|id| id == &failed_transfer.id) {
                 if !success_indices.contains(&idx) {
                     let transfer_id = transfer_id_map[idx].clone();
@@ -669,12 +1077,7 @@ impl TransferHandler {
             submitted: submitted_count,
             failed: failed_count,
             results,
-            stats: BatchPrepareStatsResponse {
-                total_transfers: batch_result.stats.total_transfers,
-                unique_sender_subnet_pairs: batch_result.stats.unique_sender_subnet_pairs,
-                coins_selected: batch_result.stats.coins_selected,
-                same_sender_conflicts: batch_result.stats.same_sender_conflicts,
-            },
+            stats,
         }
     }
 }
@@ -1,5 +1,7 @@
 //! Types for network service
 
+use crate::mempool::MempoolPolicy;
+use crate::task_preparer::TransferAmountBounds;
 use setu_rpc::ProcessingStep;
 use std::net::SocketAddr;
 
@@ -47,6 +49,16 @@ pub struct NetworkServiceConfig {
     pub http_listen_addr: SocketAddr,
     /// Listen address for Anemo P2P
     pub p2p_listen_addr: SocketAddr,
+    /// Dispatch order for transfers queued in the mempool
+    pub mempool_policy: MempoolPolicy,
+    /// Transfers (single or batched) prepared and dispatched concurrently;
+    /// further submissions wait in the mempool
+    pub mempool_max_in_flight: usize,
+    /// Sanity bounds applied to submitted transfer amounts
+    pub transfer_amount_bounds: TransferAmountBounds,
+    /// How many times a task rejected by its solver is re-dispatched to the
+    /// router's next candidate before the transfer is marked failed
+    pub solver_redispatch_retries: usize,
 }
 
 impl Default for NetworkServiceConfig {
@@ -54,6 +66,10 @@ impl Default for NetworkServiceConfig {
         Self {
             http_listen_addr: "127.0.0.1:8080".parse().unwrap(),
             p2p_listen_addr: "127.0.0.1:9000".parse().unwrap(),
+            mempool_policy: MempoolPolicy::default(),
+            mempool_max_in_flight: 200,
+            transfer_amount_bounds: TransferAmountBounds::default(),
+            solver_redispatch_retries: 2,
         }
     }
 }
@@ -127,6 +143,14 @@ impl SolverInfo {
     }
 }
 
+/// Fresh correlation id for one submission
+///
+/// Attached to the submission's tracing span and returned to the client so
+/// one request can be followed across the pipeline's logs.
+pub fn new_correlation_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
 /// Helper to get current timestamp in seconds
 #[inline]
 pub fn current_timestamp_secs() -> u64 {
@@ -5,9 +5,10 @@
 
 use consensus::ConsensusEngine;
 use crate::protocol::NetworkEvent;
-use setu_storage::{AnchorStoreBackend, CFStoreBackend, EventStoreBackend};
+use setu_storage::{AnchorCommitBackend, AnchorStoreBackend, CFStoreBackend, EventStoreBackend};
 use setu_types::{ConsensusFrame, Event, Vote};
 use crate::persistence::FinalizationPersister;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::mpsc;
 use tracing::{debug, info, warn};
@@ -58,7 +59,14 @@ pub struct MessageRouter {
     /// Per-CF index-persistence retry counter (Layer D, retry-then-escalate).
     /// Initialized empty; entries are added on failure and removed on success.
     cf_index_retries: Arc<parking_lot::Mutex<std::collections::HashMap<setu_types::CFId, u32>>>,
-
+    /// Permissioned-mode creator allowlist (`None` = permissionless)
+    allowed_creators: Option<HashSet<String>>,
+    /// Registered creator keys for event signature checks (`None` = unchecked)
+    creator_public_keys: Option<HashMap<String, Vec<u8>>>,
+    /// Largest object value a peer event may write (genesis parameter)
+    max_object_bytes: usize,
+    /// Optional atomic anchor committer (see `ConsensusValidator::with_anchor_committer`)
+    anchor_committer: Option<Arc<dyn AnchorCommitBackend>>,
 }
 
 impl MessageRouter {
@@ -75,9 +83,66 @@ impl MessageRouter {
             anchor_store,
             cf_store,
             cf_index_retries: Arc::new(parking_lot::Mutex::new(std::collections::HashMap::new())),
+            allowed_creators: None,
+            creator_public_keys: None,
+            max_object_bytes: setu_storage::DEFAULT_MAX_OBJECT_BYTES,
+            anchor_committer: None,
         }
     }
     
+    /// Apply the permissioned-mode creator allowlist to events from peers
+    pub fn with_allowed_creators(mut self, allowed_creators: Option<HashSet<String>>) -> Self {
+        self.allowed_creators = allowed_creators;
+        self
+    }
+
+    /// Verify creator signatures on events from peers, like local submission does
+    pub fn with_creator_public_keys(mut self, creator_public_keys: Option<HashMap<String, Vec<u8>>>) -> Self {
+        self.creator_public_keys = creator_public_keys;
+        self
+    }
+
+    /// Reject peer events writing objects over `max_object_bytes`, like local submission does
+    pub fn with_max_object_bytes(mut self, max_object_bytes: usize) -> Self {
+        self.max_object_bytes = max_object_bytes;
+        self
+    }
+
+    /// Persist anchors finalized on this path through `committer`, like the validator does
+    pub fn with_anchor_committer(mut self, committer: Option<Arc<dyn AnchorCommitBackend>>) -> Self {
+        self.anchor_committer = committer;
+        self
+    }
+    
+    /// Admission checks for an event received from a peer
+    ///
+    /// Runs the same ID, creator allowlist, signature, VLC and object size checks as
+    /// `ConsensusValidator::submit_event` before the event reaches the DAG.
+    async fn check_peer_event(&self, event: &Event) -> Result<(), String> {
+        if !event.verify_id() {
+            return Err(format!("Event ID verification failed - possible tampering: {}", event.id));
+        }
+        crate::consensus_integration::check_creator_allowed(
+            self.allowed_creators.as_ref(),
+            &self.engine,
+            event,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        crate::consensus_integration::check_event_signature(
+            self.creator_public_keys.as_ref(),
+            &self.engine,
+            event,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        crate::consensus_integration::check_vlc_order(&self.engine, event)
+            .await
+            .map_err(|e| e.to_string())?;
+        crate::consensus_integration::check_object_sizes(self.max_object_bytes, event)
+            .map_err(|e| e.to_string())
+    }
+
     /// Start the message router event loop
     ///
     /// This spawns a task that consumes network events and routes them
@@ -112,6 +177,11 @@ impl MessageRouter {
             NetworkEvent::CFFinalized { peer_id, cf } => {
                 self.handle_cf_finalized(peer_id, cf).await;
             }
+            NetworkEvent::ValidatorSetChange { peer_id, change } => {
+                if let Err(e) = self.engine.receive_validator_set_change(&peer_id, change).await {
+                    warn!(from = %peer_id, error = %e, "Rejected validator set change announcement");
+                }
+            }
             NetworkEvent::PeerConnected { peer_id, node_info } => {
                 debug!(peer = %peer_id, node = ?node_info, "Peer connected");
                 self.handle_peer_connected(peer_id).await;
@@ -148,6 +218,10 @@ impl FinalizationPersister for MessageRouter {
     fn cf_index_retries(&self) -> &Arc<parking_lot::Mutex<std::collections::HashMap<setu_types::CFId, u32>>> {
         &self.cf_index_retries
     }
+
+    fn anchor_committer(&self) -> Option<&Arc<dyn AnchorCommitBackend>> {
+        self.anchor_committer.as_ref()
+    }
 }
 
 #[async_trait::async_trait]
@@ -159,12 +233,13 @@ impl NetworkEventHandler for MessageRouter {
             "Routing event to consensus engine"
         );
         
-        // Step 1: Verify event ID matches content (anti-tampering)
-        if !event.verify_id() {
+        // Step 1: ID, allowlist, signature and VLC checks shared with local submission
+        if let Err(e) = self.check_peer_event(&event).await {
             warn!(
                 event_id = %event.id,
                 from = %peer_id,
-                "Event ID verification failed - possible tampering, rejecting"
+                error = %e,
+                "Rejecting event from peer"
             );
             return;
         }
@@ -200,8 +275,16 @@ impl NetworkEventHandler for MessageRouter {
                                     "Fetched missing parent events"
                                 );
                                 
-                                // Add fetched parents to DAG
+                                // Add fetched parents to DAG, through the same checks
                                 for parent_event in fetched_events {
+                                    if let Err(e) = self.check_peer_event(&parent_event).await {
+                                        warn!(
+                                            event_id = %parent_event.id,
+                                            error = %e,
+                                            "Rejecting fetched parent event"
+                                        );
+                                        continue;
+                                    }
                                     if let Err(e) = self.engine.receive_event_from_network(parent_event).await {
                                         debug!(error = %e, "Failed to add fetched parent (may already exist)");
                                     }
@@ -459,4 +542,53 @@ mod tests {
         assert_eq!(events.len(), 1, "Event should be in DAG");
         assert_eq!(events[0].id, event_id);
     }
+    
+    #[tokio::test]
+    async fn test_handle_event_enforces_creator_allowlist() {
+        let engine = create_test_engine();
+        let (event_store, anchor_store, cf_store) = create_test_stores();
+        let router = MessageRouter::new(engine.clone(), event_store, anchor_store, cf_store)
+            .with_allowed_creators(Some(HashSet::from(["solver-1".to_string()])));
+        
+        // Unlisted and unsigned: dropped before reaching the DAG
+        let event = create_test_event();
+        let event_id = event.id.clone();
+        router.handle_event("peer-1".to_string(), event).await;
+        assert!(engine.get_events_by_ids(&[event_id]).await.is_empty());
+        
+        let allowed = Event::genesis(
+            "solver-1".to_string(),
+            VLCSnapshot {
+                vector_clock: VectorClock::new(),
+                logical_time: 0,
+                physical_time: 0,
+            },
+        );
+        let allowed_id = allowed.id.clone();
+        router.handle_event("peer-1".to_string(), allowed).await;
+        assert_eq!(engine.get_events_by_ids(&[allowed_id]).await.len(), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_handle_event_rejects_unsigned_peer_event() {
+        let solver_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let engine = create_test_engine();
+        let (event_store, anchor_store, cf_store) = create_test_stores();
+        let router = MessageRouter::new(engine.clone(), event_store, anchor_store, cf_store)
+            .with_creator_public_keys(Some(HashMap::from([(
+                "test-creator".to_string(),
+                solver_key.verifying_key().as_bytes().to_vec(),
+            )])));
+        
+        // Unsigned: dropped before reaching the DAG
+        let event = create_test_event();
+        let event_id = event.id.clone();
+        router.handle_event("peer-1".to_string(), event.clone()).await;
+        assert!(engine.get_events_by_ids(&[event_id.clone()]).await.is_empty());
+        
+        let mut signed = event;
+        signed.sign(&solver_key.to_bytes()).unwrap();
+        router.handle_event("peer-1".to_string(), signed).await;
+        assert_eq!(engine.get_events_by_ids(&[event_id]).await.len(), 1);
+    }
 }
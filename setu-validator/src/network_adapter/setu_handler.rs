@@ -204,6 +204,23 @@ where
                 Ok(None)
             }
             
+            SetuMessage::ValidatorSetChange { change, sender_id } => {
+                debug!(
+                    "Received ValidatorSetChange from {}: activation_round={}",
+                    sender_id, change.activation_round
+                );
+                if let Err(e) = self.event_tx.send(NetworkEvent::ValidatorSetChange {
+                    peer_id: sender_id,
+                    change,
+                }).await {
+                    warn!(
+                        error = %e,
+                        "Validator set change channel closed - system may be shutting down"
+                    );
+                }
+                Ok(None)
+            }
+
             // Response messages should not be received as requests
             SetuMessage::EventsResponse { .. } | SetuMessage::Pong { .. } => {
                 warn!("Received response message as request - ignoring");
@@ -17,10 +17,17 @@
 //! - Events are written BEFORE the anchor (anchor serves as commit marker)
 //! - If ANY event write fails critically, anchor is NOT written
 //! - On recovery, missing anchor indicates incomplete persistence → retry
+//!
+//! With an `AnchorCommitBackend` configured, events, finalized CF index
+//! entries, the anchor and its deferred SMT leaves and merkle roots are
+//! instead written in one atomic batch, so a partially persisted anchor
+//! cannot exist at all.
 
 use consensus::ConsensusEngine;
-use setu_storage::{AnchorStoreBackend, CFStoreBackend, EventStoreBackend};
-use setu_types::{Anchor, CFId};
+use setu_storage::{
+    AnchorCommit, AnchorCommitBackend, AnchorStoreBackend, CFStoreBackend, EventStoreBackend,
+};
+use setu_types::{Anchor, CFId, Event};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
@@ -106,6 +113,12 @@ pub trait FinalizationPersister: Send + Sync {
     /// initialized via `Default::default()`.
     fn cf_index_retries(&self) -> &Arc<parking_lot::Mutex<HashMap<CFId, u32>>>;
 
+    /// Optional atomic committer. When present, events, finalized CFs and the
+    /// anchor are written in a single batch instead of sequentially.
+    fn anchor_committer(&self) -> Option<&Arc<dyn AnchorCommitBackend>> {
+        None
+    }
+
     /// Persist all CFs queued by the engine since the last call.
     ///
     /// Layer D (retry-then-escalate, R3-VERIFY-1/9):
@@ -130,11 +143,18 @@ pub trait FinalizationPersister: Send + Sync {
         let mut stored: Vec<CFId> = Vec::new();
         for cf in pending {
             let cf_id = cf.id.clone();
-            let store_res = if self.cf_store().get(&cf_id).await.is_none() {
+            let mut store_res = if self.cf_store().get(&cf_id).await.is_none() {
                 self.cf_store().store(cf.clone()).await
             } else {
                 self.cf_store().mark_finalized(&cf_id).await
             };
+            // The CF's vote certificate is part of its index entry and shares
+            // its retry budget
+            if store_res.is_ok() {
+                if let Some(cert) = self.engine().vote_certificate(&cf.anchor.id) {
+                    store_res = self.cf_store().store_vote_certificate(cert).await;
+                }
+            }
 
             match store_res {
                 Ok(()) => {
@@ -244,6 +264,57 @@ pub trait FinalizationPersister: Send + Sync {
             });
         }
         
+        // 2-5. Persist events, CF index and anchor — either as one atomic
+        // WriteBatch, or sequentially with the anchor as commit marker
+        let events_stored = match self.anchor_committer() {
+            Some(committer) => {
+                self.commit_anchor_atomically(committer, anchor, events_with_depths).await?
+            }
+            None => self.persist_anchor_sequentially(anchor, events_with_depths).await?,
+        };
+
+        info!(
+            anchor_id = %anchor.id,
+            events_stored,
+            "Persisted finalized anchor with all events"
+        );
+        
+        // 6. Mark the anchor as persisted in engine (allows GC of in-memory data)
+        self.engine().mark_anchor_persisted(&anchor.id).await;
+        self.engine().publish_finalized_anchor(anchor);
+        
+        // 7. Trigger GC via DagManager.on_anchor_finalized()
+        // This moves events to RecentCache and removes those without active children
+        match self.engine().dag_manager().on_anchor_finalized(anchor).await {
+            Ok(gc_stats) => {
+                debug!(
+                    anchor_id = %anchor.id,
+                    removed = gc_stats.removed,
+                    retained = gc_stats.retained,
+                    "GC completed after finalization"
+                );
+            }
+            Err(e) => {
+                warn!(
+                    anchor_id = %anchor.id,
+                    error = %e,
+                    "GC failed after finalization (non-fatal, will retry on next finalization)"
+                );
+            }
+        }
+        
+        Ok(())
+    }
+
+    /// Steps 2-5 of `persist_finalized_anchor` without an anchor committer:
+    /// events, then CF index, then the anchor as commit marker.
+    ///
+    /// Returns the number of newly stored events.
+    async fn persist_anchor_sequentially(
+        &self,
+        anchor: &Anchor,
+        events_with_depths: Vec<(Event, u64)>,
+    ) -> PersistenceResult<usize> {
         // 2. Batch persist events with depth to EventStore (before anchor)
         // Uses optimized batch operation with single lock acquisition
         let total_events = events_with_depths.len();
@@ -312,37 +383,57 @@ pub trait FinalizationPersister: Send + Sync {
                 reason: e.to_string(),
             });
         }
-        
-        info!(
-            anchor_id = %anchor.id,
-            events_stored = batch_result.stored,
-            "Persisted finalized anchor with all events"
-        );
-        
-        // 6. Mark the anchor as persisted in engine (allows GC of in-memory data)
-        self.engine().mark_anchor_persisted(&anchor.id).await;
-        
-        // 7. Trigger GC via DagManager.on_anchor_finalized()
-        // This moves events to RecentCache and removes those without active children
-        match self.engine().dag_manager().on_anchor_finalized(anchor).await {
-            Ok(gc_stats) => {
-                debug!(
-                    anchor_id = %anchor.id,
-                    removed = gc_stats.removed,
-                    retained = gc_stats.retained,
-                    "GC completed after finalization"
-                );
+
+        Ok(batch_result.stored)
+    }
+
+    /// Steps 2-5 of `persist_finalized_anchor` with an anchor committer:
+    /// events, pending finalized CFs and the anchor go into one atomic write.
+    ///
+    /// A failure writes nothing, so the pending CFs stay queued and the whole
+    /// anchor is retried on the next call. Returns the number of newly stored
+    /// events.
+    async fn commit_anchor_atomically(
+        &self,
+        committer: &Arc<dyn AnchorCommitBackend>,
+        anchor: &Anchor,
+        events_with_depths: Vec<(Event, u64)>,
+    ) -> PersistenceResult<usize> {
+        let finalized_cfs = self.engine().peek_pending_finalized_cfs().await;
+        let cf_ids: Vec<CFId> = finalized_cfs.iter().map(|cf| cf.id.clone()).collect();
+        let vote_certificates = finalized_cfs
+            .iter()
+            .filter_map(|cf| self.engine().vote_certificate(&cf.anchor.id))
+            .collect();
+
+        let commit = AnchorCommit {
+            events: events_with_depths,
+            finalized_cfs,
+            vote_certificates,
+            anchor: anchor.clone(),
+        };
+        let stored = committer.commit_anchor(commit).await.map_err(|e| {
+            error!(
+                anchor_id = %anchor.id,
+                error = %e,
+                "Atomic anchor commit failed - nothing written"
+            );
+            PersistenceError::AnchorPersistenceFailed {
+                anchor_id: anchor.id.clone(),
+                reason: e.to_string(),
             }
-            Err(e) => {
-                warn!(
-                    anchor_id = %anchor.id,
-                    error = %e,
-                    "GC failed after finalization (non-fatal, will retry on next finalization)"
-                );
+        })?;
+
+        if !cf_ids.is_empty() {
+            {
+                let mut retries = self.cf_index_retries().lock();
+                for cf_id in &cf_ids {
+                    retries.remove(cf_id);
+                }
             }
+            self.engine().drain_pending_finalized_cfs(&cf_ids).await;
         }
-        
-        Ok(())
+        Ok(stored)
     }
 }
 
@@ -361,7 +452,7 @@ mod tests {
 
     use async_trait::async_trait;
     use setu_storage::CFStoreBackend;
-    use setu_types::{Anchor, CFId, ConsensusFrame, SetuError, SetuResult, VLCSnapshot};
+    use setu_types::{Anchor, AnchorId, CFId, ConsensusFrame, SetuError, SetuResult, VLCSnapshot, VoteCertificate};
     use std::sync::Arc;
 
     #[derive(Debug)]
@@ -387,6 +478,10 @@ mod tests {
             None
         }
         async fn mark_finalized(&self, _cf_id: &CFId) -> SetuResult<()> { Ok(()) }
+        async fn store_vote_certificate(&self, _cert: VoteCertificate) -> SetuResult<()> { Ok(()) }
+        async fn get_vote_certificate(&self, _anchor_id: &AnchorId) -> Option<VoteCertificate> {
+            None
+        }
         async fn get_pending(&self) -> Vec<ConsensusFrame> {
             Vec::new()
         }
@@ -12,6 +12,7 @@
 //! engine. The network adapter receives raw messages, deserializes them, and
 //! converts them into `NetworkEvent` variants for the consensus layer to process.
 
+use consensus::ValidatorSetChange;
 use setu_types::{ConsensusFrame, Event, NodeInfo, Vote};
 
 /// Network events that are sent to the application layer
@@ -54,6 +55,12 @@ pub enum NetworkEvent {
         peer_id: String,
         cf: ConsensusFrame,
     },
+
+    /// Received a validator set change announcement
+    ValidatorSetChange {
+        peer_id: String,
+        change: ValidatorSetChange,
+    },
 }
 
 impl NetworkEvent {
@@ -65,7 +72,8 @@ impl NetworkEvent {
             | NetworkEvent::EventReceived { peer_id, .. }
             | NetworkEvent::CFProposal { peer_id, .. }
             | NetworkEvent::VoteReceived { peer_id, .. }
-            | NetworkEvent::CFFinalized { peer_id, .. } => peer_id,
+            | NetworkEvent::CFFinalized { peer_id, .. }
+            | NetworkEvent::ValidatorSetChange { peer_id, .. } => peer_id,
         }
     }
 
@@ -84,6 +92,7 @@ impl NetworkEvent {
             NetworkEvent::CFProposal { .. }
                 | NetworkEvent::VoteReceived { .. }
                 | NetworkEvent::CFFinalized { .. }
+                | NetworkEvent::ValidatorSetChange { .. }
         )
     }
     
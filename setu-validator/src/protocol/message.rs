@@ -14,6 +14,7 @@
 //! message types would be defined in a separate validator crate, while the
 //! network layer remains unchanged.
 
+use consensus::ValidatorSetChange;
 use serde::{Deserialize, Serialize};
 use setu_types::{ConsensusFrame, Event, Vote};
 
@@ -70,6 +71,14 @@ pub enum SetuMessage {
         timestamp: u64,
         nonce: u64,
     },
+
+    /// Announcement of a validator set change scheduled for a future round
+    ///
+    /// Appended last so existing variant tags keep their wire encoding.
+    ValidatorSetChange {
+        change: ValidatorSetChange,
+        sender_id: String,
+    },
 }
 
 /// Message type identifier
@@ -85,6 +94,7 @@ pub enum MessageType {
     EventsResponse,
     Ping,
     Pong,
+    ValidatorSetChange,
 }
 
 impl SetuMessage {
@@ -99,6 +109,7 @@ impl SetuMessage {
             SetuMessage::EventsResponse { .. } => MessageType::EventsResponse,
             SetuMessage::Ping { .. } => MessageType::Ping,
             SetuMessage::Pong { .. } => MessageType::Pong,
+            SetuMessage::ValidatorSetChange { .. } => MessageType::ValidatorSetChange,
         }
     }
 
@@ -117,6 +128,7 @@ impl SetuMessage {
             SetuMessage::CFProposal { .. }
                 | SetuMessage::CFVote { .. }
                 | SetuMessage::CFFinalized { .. }
+                | SetuMessage::ValidatorSetChange { .. }
         )
     }
     
@@ -133,6 +145,7 @@ impl SetuMessage {
             SetuMessage::EventsResponse { .. } => "/setu/events_response",
             SetuMessage::Ping { .. } => "/ping",
             SetuMessage::Pong { .. } => "/pong",
+            SetuMessage::ValidatorSetChange { .. } => "/setu/validator_set_change",
         }
     }
 }
@@ -471,4 +484,22 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_validator_set_change_bincode_roundtrip() {
+        let msg = SetuMessage::ValidatorSetChange {
+            change: ValidatorSetChange::remove(7, "v3"),
+            sender_id: "v1".to_string(),
+        };
+        assert!(msg.is_consensus_message());
+
+        let bytes = bincode::serialize(&msg).expect("ValidatorSetChange serialize");
+        match bincode::deserialize::<SetuMessage>(&bytes).expect("ValidatorSetChange deserialize") {
+            SetuMessage::ValidatorSetChange { change, sender_id } => {
+                assert_eq!(sender_id, "v1");
+                assert!(change.same_as(&ValidatorSetChange::remove(7, "v3")));
+            }
+            _ => panic!("Wrong variant"),
+        }
+    }
 }
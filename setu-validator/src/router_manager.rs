@@ -11,13 +11,21 @@
 //!
 //! Routing respects subnet affinity: transactions for a subnet
 //! are only routed to solvers that permit that subnet.
+//!
+//! ## Solver Selection
+//!
+//! Among the candidates for a subnet, a solver is picked by the configured
+//! [`SolverSelectionStrategy`] (consistent hash by default). The randomized
+//! strategies draw from an injected `SelectionRng`, so a seeded RNG makes
+//! routing decisions reproducible.
 
 use setu_types::{Transfer, SubnetId};
 use parking_lot::RwLock;
 use setu_router_core::{
     UnifiedRouter,
     SolverInfo, SolverStatus,
-    ConsistentHashStrategy,
+    ConsistentHashStrategy, LoadBalancedStrategy, RandomStrategy,
+    SelectionRng,
     SolverStrategy,  // Import the trait
     RoutingContext,  // For unified routing
     ObjectId,        // For RoutingContext
@@ -43,6 +51,43 @@ pub enum RouterError {
     RoutingFailed(String),
 }
 
+/// How a solver is picked among the candidates for a subnet
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SolverSelectionStrategy {
+    /// Hash the subnet onto a ring, so a subnet sticks to one solver
+    #[default]
+    ConsistentHash,
+    /// Least-loaded solver, ties broken at random
+    LoadBalanced,
+    /// Uniformly random solver
+    Random,
+}
+
+impl SolverSelectionStrategy {
+    /// Build the strategy, drawing any randomness from `rng`
+    pub fn build(self, rng: Arc<dyn SelectionRng>) -> Arc<dyn SolverStrategy> {
+        match self {
+            Self::ConsistentHash => Arc::new(ConsistentHashStrategy::new()),
+            Self::LoadBalanced => Arc::new(LoadBalancedStrategy::new().with_rng(rng)),
+            Self::Random => Arc::new(RandomStrategy::with_rng(rng)),
+        }
+    }
+}
+
+impl std::str::FromStr for SolverSelectionStrategy {
+    type Err = String;
+
+    /// Parse `consistent_hash`, `load_balanced` or `random`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "consistent_hash" => Ok(Self::ConsistentHash),
+            "load_balanced" => Ok(Self::LoadBalanced),
+            "random" => Ok(Self::Random),
+            other => Err(format!("unknown solver selection strategy: {}", other)),
+        }
+    }
+}
+
 /// Solver connection info
 #[derive(Debug, Clone)]
 pub struct SolverConnection {
@@ -120,8 +165,8 @@ pub struct RouterManager {
     /// Solver channels for sending transfers
     solver_channels: Arc<RwLock<HashMap<String, mpsc::UnboundedSender<Transfer>>>>,
     
-    /// Consistent hash strategy for solver selection within a shard
-    consistent_hash: ConsistentHashStrategy,
+    /// Strategy for solver selection within a shard (consistent hash by default)
+    solver_strategy: Arc<dyn SolverStrategy>,
     
     /// Shard → Solver mapping index (for shard-based routing)
     /// G10: This index is rebuilt from solver_registry on startup/replay
@@ -137,7 +182,7 @@ impl RouterManager {
             router: UnifiedRouter::new(),
             solver_registry: Arc::new(RwLock::new(HashMap::new())),
             solver_channels: Arc::new(RwLock::new(HashMap::new())),
-            consistent_hash: ConsistentHashStrategy::new(),
+            solver_strategy: Arc::new(ConsistentHashStrategy::new()),
             shard_solvers: Arc::new(RwLock::new(HashMap::new())),
         }
     }
@@ -150,11 +195,18 @@ impl RouterManager {
             router: UnifiedRouter::with_shard_count(shard_count),
             solver_registry: Arc::new(RwLock::new(HashMap::new())),
             solver_channels: Arc::new(RwLock::new(HashMap::new())),
-            consistent_hash: ConsistentHashStrategy::new(),
+            solver_strategy: Arc::new(ConsistentHashStrategy::new()),
             shard_solvers: Arc::new(RwLock::new(HashMap::new())),
         }
     }
     
+    /// Pick solvers with `strategy` instead of the default consistent hash
+    pub fn with_solver_strategy(mut self, strategy: Arc<dyn SolverStrategy>) -> Self {
+        info!(strategy = strategy.name(), "Using solver selection strategy");
+        self.solver_strategy = strategy;
+        self
+    }
+    
     /// Register a solver
     pub fn register_solver(
         &self,
@@ -288,17 +340,31 @@ impl RouterManager {
     /// 
     /// 方案 B: Two-level subnet affinity routing:
     /// 1. Use UnifiedRouter to map subnet_id → ShardId (same subnet always → same shard)
-    /// 2. Select solver from shard_solvers[shard] using consistent hash
+    /// 2. Select solver from shard_solvers[shard] using the solver selection strategy
     /// 3. Fallback to all permitted solvers if no shard assignment
     /// 
     /// Also respects `permitted_subnets` filtering: solvers with non-empty
     /// permitted_subnets only serve listed subnets.
     pub fn route_transfer(&self, transfer: &Transfer) -> Result<String, RouterError> {
+        self.route_transfer_excluding(transfer, &[])
+    }
+
+    /// Route a transfer to a solver, skipping the solvers in `excluded`.
+    ///
+    /// Used to re-dispatch a task after a solver rejected it: each routing
+    /// priority behaves as in [`Self::route_transfer`], but excluded solvers
+    /// are never candidates, so the next solver in line is picked.
+    pub fn route_transfer_excluding(
+        &self,
+        transfer: &Transfer,
+        excluded: &[String],
+    ) -> Result<String, RouterError> {
         let subnet_id = transfer.get_subnet_id();
+        let is_excluded = |id: &str| excluded.iter().any(|e| e == id);
         
         // Priority 1: Manual solver selection (preferred_solver)
         // Check if preferred solver is available AND permits this subnet
-        if let Some(preferred) = &transfer.preferred_solver {
+        if let Some(preferred) = transfer.preferred_solver.as_ref().filter(|p| !is_excluded(p)) {
             if self.is_solver_available_for_subnet(preferred, &subnet_id) {
                 debug!(
                     transfer_id = %transfer.id,
@@ -330,10 +396,11 @@ impl RouterManager {
                 .filter_map(|id| self.solver_registry.read().get(id).cloned())
                 .filter(|s| s.is_available())
                 .filter(|s| self.solver_permits_subnet_inner(s, &subnet_id))
+                .filter(|s| !is_excluded(&s.id))
                 .collect();
             
             if !candidates.is_empty() {
-                // Use consistent hash among shard solvers with subnet_id as routing key
+                // Select among shard solvers with subnet_id as routing key
                 let routing_key = hex::encode(subnet_id.as_bytes());
                 let solver_infos: Vec<SolverInfo> = candidates.iter()
                     .map(|s| {
@@ -344,7 +411,7 @@ impl RouterManager {
                     })
                     .collect();
                 
-                let selected = self.consistent_hash
+                let selected = self.solver_strategy
                     .select(&solver_infos, &routing_key)
                     .map_err(|e| RouterError::RoutingFailed(e.to_string()))?;
                 
@@ -359,9 +426,10 @@ impl RouterManager {
             }
         }
         
-        // Priority 3: Fallback - consistent hash among all permitted solvers
+        // Priority 3: Fallback - select among all permitted solvers
         // This handles the case where no solvers are assigned to shards yet
-        let available_solvers = self.get_solvers_for_subnet(&subnet_id);
+        let mut available_solvers = self.get_solvers_for_subnet(&subnet_id);
+        available_solvers.retain(|s| !is_excluded(&s.id));
         
         if available_solvers.is_empty() {
             warn!(
@@ -372,7 +440,7 @@ impl RouterManager {
             return Err(RouterError::NoSolverAvailable);
         }
         
-        // Use subnet_id as routing key for consistent hash (ensures same subnet → same solver)
+        // Use subnet_id as routing key (under consistent hash: same subnet → same solver)
         let routing_key = hex::encode(subnet_id.as_bytes());
         let solver_infos: Vec<SolverInfo> = available_solvers
             .iter()
@@ -384,7 +452,7 @@ impl RouterManager {
             })
             .collect();
         
-        let selected = self.consistent_hash
+        let selected = self.solver_strategy
             .select(&solver_infos, &routing_key)
             .map_err(|e| RouterError::RoutingFailed(e.to_string()))?;
         
@@ -393,7 +461,8 @@ impl RouterManager {
             routing_key = %routing_key,
             solver_id = %selected.id,
             subnet_id = ?subnet_id,
-            "Using consistent hash routing (fallback, no shard assignment)"
+            strategy = self.solver_strategy.name(),
+            "Using fallback routing (no shard assignment)"
         );
         
         Ok(selected.id)
@@ -628,7 +697,27 @@ mod tests {
         let result = manager.route_transfer(&transfer);
         assert!(matches!(result, Err(RouterError::NoSolverAvailable)));
     }
-    
+
+    #[test]
+    fn test_route_transfer_excluding_picks_next_candidate() {
+        let manager = RouterManager::new();
+        let (tx1, _rx1) = mpsc::unbounded_channel();
+        let (tx2, _rx2) = mpsc::unbounded_channel();
+
+        manager.register_solver("solver-1".to_string(), "127.0.0.1:9001".to_string(), 100, tx1);
+        manager.register_solver("solver-2".to_string(), "127.0.0.1:9002".to_string(), 100, tx2);
+
+        let mut transfer = create_test_transfer("tx-1");
+        transfer.preferred_solver = Some("solver-1".to_string());
+
+        let excluded = vec!["solver-1".to_string()];
+        assert_eq!(manager.route_transfer_excluding(&transfer, &excluded).unwrap(), "solver-2");
+
+        let excluded = vec!["solver-1".to_string(), "solver-2".to_string()];
+        let result = manager.route_transfer_excluding(&transfer, &excluded);
+        assert!(matches!(result, Err(RouterError::NoSolverAvailable)));
+    }
+
     // ============================================
     // Subnet Affinity Routing Tests
     // ============================================
@@ -867,4 +956,35 @@ mod tests {
         let result = manager.route_transfer(&transfer).unwrap();
         assert_eq!(result, "solver-1", "Should fall back to solver-1 when solver-2 doesn't permit subnet_a");
     }
+
+    #[test]
+    fn test_seeded_random_strategy_routes_reproducibly() {
+        use setu_router_core::SeededSelectionRng;
+
+        let route_all = |seed: u64| {
+            let manager = RouterManager::new().with_solver_strategy(
+                SolverSelectionStrategy::Random.build(Arc::new(SeededSelectionRng::new(seed))),
+            );
+            for i in 1..=4 {
+                let (tx, _rx) = mpsc::unbounded_channel();
+                manager.register_solver(format!("solver-{}", i), format!("127.0.0.1:900{}", i), 100, tx);
+            }
+            (0..32)
+                .map(|i| manager.route_transfer(&create_test_transfer(&format!("tx-{}", i))).unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        let first = route_all(42);
+        assert_eq!(first, route_all(42));
+        let distinct: std::collections::HashSet<_> = first.iter().collect();
+        assert!(distinct.len() > 1, "random strategy should spread transfers across solvers");
+    }
+
+    #[test]
+    fn test_solver_selection_strategy_from_str() {
+        assert_eq!("consistent_hash".parse(), Ok(SolverSelectionStrategy::ConsistentHash));
+        assert_eq!("load_balanced".parse(), Ok(SolverSelectionStrategy::LoadBalanced));
+        assert_eq!(" random ".parse(), Ok(SolverSelectionStrategy::Random));
+        assert!("round_robin".parse::<SolverSelectionStrategy>().is_err());
+    }
 }
@@ -5,16 +5,16 @@
 
 use setu_types::task::{
     SolverTask, ResolvedInputs, ResolvedObject,
-    GasBudget, ReadSetEntry,
+    ReadSetEntry,
 };
-use setu_types::{Event, EventType, SubnetId, ObjectId};
+use setu_types::{Event, EventType, SubnetId, ObjectId, ZeroBalanceCoinPolicy};
 use setu_types::{flux_state_object_id, power_state_object_id};
 use setu_types::event::VLCSnapshot;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
-use super::{TaskPrepareError, CoinInfo, SimpleMerkleProof, BatchStateSnapshot, StateProvider};
+use super::{TaskPrepareError, CoinInfo, SimpleMerkleProof, BatchStateSnapshot, StateProvider, ParentSelector};
 use crate::coin_reservation::{CoinReservationManager, ReservationHandle};
 
 /// Result of batch task preparation
@@ -76,6 +76,12 @@ pub struct BatchPrepareStats {
 pub struct BatchTaskPreparer {
     validator_id: String,
     state_provider: Arc<setu_storage::MerkleStateProvider>,
+    /// Per-task execution deadline stamped on every prepared SolverTask
+    execution_deadline_ms: Option<u64>,
+    /// Zero-balance coin policy stamped on every prepared SolverTask
+    zero_balance_policy: ZeroBalanceCoinPolicy,
+    /// Parents for events whose coin has no recorded writer
+    parent_selector: Option<Arc<dyn ParentSelector>>,
 }
 
 impl BatchTaskPreparer {
@@ -87,9 +93,39 @@ impl BatchTaskPreparer {
         Self {
             validator_id,
             state_provider,
+            execution_deadline_ms: None,
+            zero_balance_policy: ZeroBalanceCoinPolicy::default(),
+            parent_selector: None,
         }
     }
 
+    /// Fall back to `selector` for parents when the coin has no writer
+    pub fn with_parent_selector(mut self, selector: Arc<dyn ParentSelector>) -> Self {
+        self.parent_selector = Some(selector);
+        self
+    }
+
+    /// Set the execution deadline carried by every prepared task
+    /// (`None` leaves the enclave's own limit in charge).
+    pub fn with_execution_deadline_ms(mut self, deadline_ms: Option<u64>) -> Self {
+        self.execution_deadline_ms = deadline_ms;
+        self
+    }
+
+    /// Set how the enclave treats sender coins a transfer drains to zero
+    pub fn with_zero_balance_policy(mut self, policy: ZeroBalanceCoinPolicy) -> Self {
+        self.zero_balance_policy = policy;
+        self
+    }
+
+    /// Stamp the configured execution deadline and zero-balance policy on a
+    /// prepared task
+    fn apply_task_config(&self, mut task: SolverTask) -> SolverTask {
+        task.execution_deadline_ms = self.execution_deadline_ms;
+        task.zero_balance_policy = self.zero_balance_policy;
+        task
+    }
+
     /// Get the underlying MerkleStateProvider
     pub fn merkle_state_provider(&self) -> &Arc<setu_storage::MerkleStateProvider> {
         &self.state_provider
@@ -599,6 +635,13 @@ impl BatchTaskPreparer {
             ));
         }
 
+        // Add UserSubnetActivity for both parties (subnet interaction tracking)
+        read_set.extend(super::subnet_activity_read_set(
+            self.state_provider.as_ref(),
+            transfer,
+            subnet_id,
+        ));
+
         // Add ResourceParams from GOVERNANCE subnet
         let rp_oid = setu_types::resource_params_object_id();
         if let Some(rp_data) = self.state_provider.get_object_from_subnet(
@@ -612,10 +655,10 @@ impl BatchTaskPreparer {
         }
 
         // Derive parent_ids from snapshot (NO LOCK)
-        let parent_ids = self.derive_dependencies_from_snapshot(&coin.object_id, snapshot);
+        let parent_ids = self.derive_dependencies_from_snapshot(&coin.object_id, snapshot)?;
 
         // Create Event
-        let event = self.create_event_from_transfer(transfer, parent_ids)?;
+        let event = self.create_event_from_transfer(transfer, parent_ids, subnet_id.clone())?;
 
         // Generate task_id using CACHED state_root
         let task_id = SolverTask::generate_task_id(&event, &pre_state_root);
@@ -623,7 +666,10 @@ impl BatchTaskPreparer {
         // Create SolverTask
         let task = SolverTask::new(task_id, event, resolved_inputs, pre_state_root, subnet_id.clone())
             .with_read_set(read_set)
-            .with_gas_budget(GasBudget::default());
+            .with_gas_budget(
+                super::fee_calculator(self.state_provider.as_ref()).transfer_gas_budget(transfer.amount, 1),
+            );
+        let task = self.apply_task_config(task);
 
         Ok(task)
     }
@@ -633,11 +679,12 @@ impl BatchTaskPreparer {
         &self,
         coin_object_id: &ObjectId,
         snapshot: &BatchStateSnapshot,
-    ) -> Vec<String> {
-        snapshot
-            .get_last_modifying_event(coin_object_id)
-            .map(|event_id| vec![event_id.clone()])
-            .unwrap_or_default()
+    ) -> Result<Vec<String>, TaskPrepareError> {
+        match (snapshot.get_last_modifying_event(coin_object_id), &self.parent_selector) {
+            (Some(event_id), _) => Ok(vec![event_id.clone()]),
+            (None, Some(selector)) => selector.select_parents(),
+            (None, None) => Ok(Vec::new()),
+        }
     }
 
     /// Create Event from Transfer with pre-derived parent_ids
@@ -645,11 +692,15 @@ impl BatchTaskPreparer {
         &self,
         transfer: &setu_types::Transfer,
         parent_ids: Vec<String>,
+        subnet_id: SubnetId,
     ) -> Result<Event, TaskPrepareError> {
         let vlc_snapshot = match &transfer.assigned_vlc {
             Some(vlc) => {
                 let mut snapshot = VLCSnapshot::for_node(vlc.validator_id.clone());
                 snapshot.logical_time = vlc.logical_time;
+                // The validator's own entry carries the logical time, so its
+                // later transfers causally follow this one
+                snapshot.vector_clock.set(&vlc.validator_id, snapshot.logical_time);
                 snapshot.physical_time = vlc.physical_time;
                 snapshot
             }
@@ -664,6 +715,7 @@ impl BatchTaskPreparer {
                 );
                 let mut snapshot = VLCSnapshot::for_node(self.validator_id.clone());
                 snapshot.logical_time = now_nanos;
+                snapshot.vector_clock.set(&self.validator_id, snapshot.logical_time);
                 snapshot.physical_time = now_nanos / 1_000_000;
                 snapshot
             }
@@ -676,8 +728,10 @@ impl BatchTaskPreparer {
             self.validator_id.clone(),
         );
 
-        event = event.with_transfer(transfer.clone());
+        event = event.with_transfer(transfer.clone()).with_subnet(subnet_id);
 
+        // Unsigned: the signature covers the execution result, so the event is
+        // signed with the node key after execution (`ConsensusValidator::sign_local_event`)
         Ok(event)
     }
 }
@@ -714,6 +768,27 @@ mod tests {
         }
     }
 
+    struct FixedParents(Vec<String>);
+
+    impl ParentSelector for FixedParents {
+        fn select_parents(&self) -> Result<Vec<String>, TaskPrepareError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn test_batch_prepare_falls_back_to_parent_selector() {
+        let preparer = BatchTaskPreparer::new_for_testing("validator-1".to_string())
+            .with_parent_selector(Arc::new(FixedParents(vec!["tip-a".to_string()])));
+        let transfer = Transfer::new("batch-tx-1", "alice", "bob", 100)
+            .with_type(TransferType::SetuTransfer);
+
+        let result = preparer.prepare_transfers_batch(&[transfer]);
+
+        assert_eq!(result.tasks.len(), 1);
+        assert_eq!(result.tasks[0].event.parent_ids, vec!["tip-a".to_string()]);
+    }
+
     #[test]
     fn test_batch_prepare_multiple_senders() {
         let preparer = BatchTaskPreparer::new_for_testing("validator-1".to_string());
@@ -46,16 +46,32 @@
 
 mod single;
 mod batch;
+mod stats;
 
 // Re-export main types
 pub use single::TaskPreparer;
 pub use batch::{BatchTaskPreparer, BatchPrepareResult, BatchPrepareStats};
+pub use stats::{PrepareStats, PrepareMetrics};
 
 // Re-export shared types from storage
 pub use setu_storage::{StateProvider, CoinInfo, SimpleMerkleProof, BatchStateSnapshot, BatchSnapshotStats};
 
 use setu_types::task::MerkleProof;
 
+/// Fallback parent source for events with no object dependencies
+///
+/// Prepared events take their parents from the last events that wrote their
+/// input objects. When none of the inputs has a recorded writer, the preparer
+/// asks the selector instead, which applies the configured
+/// [`ParentSelectionStrategy`](consensus::ParentSelectionStrategy) to the DAG tips.
+pub trait ParentSelector: Send + Sync {
+    /// Parents for a new event; empty only when the DAG has no tips
+    ///
+    /// Fails when the DAG cannot be read, so no event is prepared without
+    /// the parents it should have had.
+    fn select_parents(&self) -> Result<Vec<String>, TaskPrepareError>;
+}
+
 /// Maximum merge sources mirroring runtime's MAX_MERGE_SOURCES.
 pub const MAX_MERGE_SOURCES: usize = 50;
 
@@ -76,6 +92,54 @@ pub enum CoinSelectionResult {
     },
 }
 
+/// Result of a dry-run transfer, see `TaskPreparer::estimate_transfer`.
+#[derive(Debug, Clone)]
+pub struct TransferEstimate {
+    /// Coins the transfer would consume; the first is the spent (or merge target) coin
+    pub selected_coins: Vec<CoinInfo>,
+    /// Fee charged on top of the transferred amount
+    pub total_fee: u64,
+    /// Part of `total_fee` that is burned
+    pub total_burn: u64,
+    /// Sender's balance in the coin namespace after the amount and fee
+    pub resulting_balance: u64,
+}
+
+/// Sanity bounds on transfer amounts, checked before a transfer reaches
+/// task preparation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferAmountBounds {
+    /// Largest accepted amount; `u64::MAX` (the default) disables the check
+    pub max_transfer_amount: u64,
+    /// Reject zero-amount transfers, which are almost always client mistakes
+    pub reject_zero_amount: bool,
+}
+
+impl Default for TransferAmountBounds {
+    fn default() -> Self {
+        Self {
+            max_transfer_amount: u64::MAX,
+            reject_zero_amount: true,
+        }
+    }
+}
+
+impl TransferAmountBounds {
+    /// Check `amount` against the bounds
+    pub fn check(&self, amount: u64) -> Result<(), TaskPrepareError> {
+        if amount == 0 && self.reject_zero_amount {
+            return Err(TaskPrepareError::ZeroAmount);
+        }
+        if amount > self.max_transfer_amount {
+            return Err(TaskPrepareError::AmountExceedsMax {
+                amount,
+                max: self.max_transfer_amount,
+            });
+        }
+        Ok(())
+    }
+}
+
 /// Errors during task preparation
 #[derive(Debug, thiserror::Error, Clone)]
 pub enum TaskPrepareError {
@@ -100,6 +164,18 @@ pub enum TaskPrepareError {
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
+    /// The fallback parent selector could not read the DAG
+    #[error("Parent selection failed: {0}")]
+    ParentSelectionFailed(String),
+
+    /// Transfer amount is above the validator's configured `max_transfer_amount`
+    #[error("Transfer amount {amount} exceeds maximum {max}")]
+    AmountExceedsMax { amount: u64, max: u64 },
+
+    /// Zero-amount transfer, rejected when `reject_zero_amount` is set
+    #[error("Transfer amount must be greater than zero")]
+    ZeroAmount,
+
     #[error("Module not found: {0}")]
     ModuleNotFound(String),
 
@@ -224,6 +300,44 @@ pub(crate) fn to_enclave_proof(proof: &SimpleMerkleProof) -> MerkleProof {
     }
 }
 
+/// Read-set entries for the `UserSubnetActivity` of both transfer parties.
+///
+/// The runtime records an interaction on each party's activity in the
+/// subnet of the transferred token, ROOT included. The TEE needs the current
+/// records in the read_set, otherwise it would recreate them from scratch.
+pub(crate) fn subnet_activity_read_set(
+    state_provider: &dyn StateProvider,
+    transfer: &setu_types::Transfer,
+    subnet_id: &setu_types::SubnetId,
+) -> Vec<setu_types::task::ReadSetEntry> {
+    [&transfer.from, &transfer.to]
+        .into_iter()
+        .filter_map(|address| {
+            // Same canonical form the runtime keys the record by (`Address` display)
+            let canonical = setu_types::Address::normalize(address).to_string();
+            let oid = setu_types::user_subnet_activity_object_id(&canonical, subnet_id);
+            state_provider
+                .get_object_from_subnet(&oid, subnet_id)
+                .map(|data| setu_types::task::ReadSetEntry::new(format!("oid:{}", hex::encode(oid.as_bytes())), data))
+        })
+        .collect()
+}
+
+/// Fee calculator for the current `ResourceParams` in the GOVERNANCE SMT.
+///
+/// Falls back to the defaults (no fee) while governance has not stored any.
+/// Estimates and prepared tasks must both price transfers through this.
+pub(crate) fn fee_calculator(state_provider: &dyn StateProvider) -> setu_types::FeeCalculator {
+    let params = state_provider
+        .get_object_from_subnet(
+            &setu_types::resource_params_object_id(),
+            &setu_types::SubnetId::GOVERNANCE,
+        )
+        .and_then(|bytes| serde_json::from_slice::<setu_types::ResourceParams>(&bytes).ok())
+        .unwrap_or_default();
+    setu_types::FeeCalculator::new(&params)
+}
+
 // ============================================================================
 // Shared Test Utilities
 // ============================================================================
@@ -7,13 +7,14 @@ use setu_types::task::{
     SolverTask, ResolvedInputs, ResolvedObject,
     GasBudget, ReadSetEntry,
 };
-use setu_types::{Event, EventType, SubnetId, ObjectId};
+use setu_types::{Event, EventType, SubnetId, ObjectId, ZeroBalanceCoinPolicy};
 use setu_types::{flux_state_object_id, power_state_object_id};
 use setu_types::event::VLCSnapshot;
 use std::sync::Arc;
+use tokio::time::Instant;
 use tracing::{debug, info, warn};
 
-use super::{TaskPrepareError, CoinInfo, StateProvider};
+use super::{TaskPrepareError, CoinInfo, StateProvider, PrepareStats, PrepareMetrics, ParentSelector};
 
 /// SolverTask preparer for single transfers
 ///
@@ -32,6 +33,13 @@ use super::{TaskPrepareError, CoinInfo, StateProvider};
 pub struct TaskPreparer {
     validator_id: String,
     state_provider: Arc<dyn StateProvider>,
+    prepare_metrics: PrepareMetrics,
+    /// Per-task execution deadline stamped on every prepared SolverTask
+    execution_deadline_ms: Option<u64>,
+    /// Zero-balance coin policy stamped on every prepared SolverTask
+    zero_balance_policy: ZeroBalanceCoinPolicy,
+    /// Parents for events whose inputs have no recorded writer
+    parent_selector: Option<Arc<dyn ParentSelector>>,
 }
 
 impl TaskPreparer {
@@ -39,8 +47,39 @@ impl TaskPreparer {
         Self {
             validator_id,
             state_provider,
+            prepare_metrics: PrepareMetrics::new(),
+            execution_deadline_ms: None,
+            zero_balance_policy: ZeroBalanceCoinPolicy::default(),
+            parent_selector: None,
         }
     }
+
+    /// Fall back to `selector` for parents when no input object has a writer
+    pub fn with_parent_selector(mut self, selector: Arc<dyn ParentSelector>) -> Self {
+        self.parent_selector = Some(selector);
+        self
+    }
+
+    /// Set the execution deadline carried by every prepared task
+    /// (`None` leaves the enclave's own limit in charge).
+    pub fn with_execution_deadline_ms(mut self, deadline_ms: Option<u64>) -> Self {
+        self.execution_deadline_ms = deadline_ms;
+        self
+    }
+
+    /// Set how the enclave treats sender coins a transfer drains to zero
+    pub fn with_zero_balance_policy(mut self, policy: ZeroBalanceCoinPolicy) -> Self {
+        self.zero_balance_policy = policy;
+        self
+    }
+
+    /// Stamp the configured execution deadline and zero-balance policy on a
+    /// prepared task
+    fn apply_task_config(&self, mut task: SolverTask) -> SolverTask {
+        task.execution_deadline_ms = self.execution_deadline_ms;
+        task.zero_balance_policy = self.zero_balance_policy;
+        task
+    }
     
     /// Get the underlying state provider
     /// 
@@ -56,6 +95,11 @@ impl TaskPreparer {
         &self.validator_id
     }
     
+    /// Aggregated per-phase preparation timings
+    pub fn prepare_metrics(&self) -> &PrepareMetrics {
+        &self.prepare_metrics
+    }
+    
     /// Create a TaskPreparer with pre-initialized test accounts
     /// 
     /// This creates a real `MerkleStateProvider` backed by `GlobalStateManager`
@@ -121,10 +165,24 @@ impl TaskPreparer {
         transfer: &setu_types::Transfer,
         subnet_id: SubnetId,
     ) -> Result<SolverTask, TaskPrepareError> {
+        self.prepare_transfer_task_with_stats(transfer, subnet_id)
+            .map(|(task, _)| task)
+    }
+
+    /// Same as [`Self::prepare_transfer_task`], also returning the time spent
+    /// in each preparation phase. The breakdown is recorded into
+    /// [`Self::prepare_metrics`] on success.
+    pub fn prepare_transfer_task_with_stats(
+        &self,
+        transfer: &setu_types::Transfer,
+        subnet_id: SubnetId,
+    ) -> Result<(SolverTask, PrepareStats), TaskPrepareError> {
+        let started = Instant::now();
+        let mut stats = PrepareStats::default();
         let amount = transfer.amount;
         
-        // Use subnet_id as the coin namespace (1:1 binding)
-        // For ROOT subnet, use "ROOT" as the identifier
+        // Display name for logs; coins are matched to the subnet through
+        // `SubnetId::from_coin_type` (1:1 binding), not this string
         let subnet_id_str = if subnet_id == SubnetId::ROOT {
             "ROOT".to_string()
         } else {
@@ -141,11 +199,13 @@ impl TaskPreparer {
         );
         
         // Step 1: Select coins for sender filtered by subnet_id
-        let sender_coins = self.state_provider.get_coins_for_address_by_type(
+        let phase = Instant::now();
+        let sender_coins = self.state_provider.get_coins_for_address_in_subnet(
             &transfer.from,
-            &subnet_id_str,
+            &subnet_id,
         );
         let selection = self.select_coins_for_transfer(&sender_coins, amount)?;
+        stats.coin_selection = phase.elapsed();
 
         // Auto-escalate: NeedMerge → MergeThenTransfer
         match selection {
@@ -157,9 +217,12 @@ impl TaskPreparer {
                     source_count = sources.len(),
                     "Auto-escalating to MergeThenTransfer"
                 );
-                return self.prepare_merge_then_transfer_task(
-                    &target, &sources, recipient, amount, subnet_id,
-                );
+                let task = self.merge_then_transfer_task_timed(
+                    &target, &sources, recipient, amount, subnet_id, &mut stats,
+                )?;
+                stats.total = started.elapsed();
+                self.prepare_metrics.record(&stats);
+                return Ok((task, stats));
             }
             super::CoinSelectionResult::SingleCoin(ref selected_coin) => {
                 debug!(
@@ -178,6 +241,7 @@ impl TaskPreparer {
         };
         
         // Step 2: Build ResolvedObject and ResolvedInputs
+        let phase = Instant::now();
         let resolved_coin = ResolvedObject {
             object_id: selected_coin.object_id,
             object_type: "Coin".to_string(),
@@ -188,25 +252,29 @@ impl TaskPreparer {
         
         // Step 3: Derive event dependencies from input objects
         let input_objects: Vec<&ObjectId> = vec![&selected_coin.object_id];
-        let parent_ids = self.derive_dependencies(&input_objects);
+        let parent_ids = self.derive_dependencies(&input_objects)?;
+        stats.assembly += phase.elapsed();
         
         // Step 4: Build read_set with Merkle proof
         // Pass raw storage data (CoinState) so TEE can verify Merkle proof
         // TEE is responsible for converting CoinState → Object<CoinData>
-        let coin_data = self.state_provider.get_object(&selected_coin.object_id)
+        let phase = Instant::now();
+        let coin_data = self.state_provider.get_object_from_subnet(&selected_coin.object_id, &subnet_id)
             .ok_or(TaskPrepareError::ObjectNotFound(hex::encode(&selected_coin.object_id)))?;
+        stats.read_set += phase.elapsed();
         
-        let merkle_proof = self.state_provider.get_merkle_proof(&selected_coin.object_id);
+        let phase = Instant::now();
+        let merkle_proof = self.state_provider.get_merkle_proof_from_subnet(&selected_coin.object_id, &subnet_id)
+            .map(|p| bcs::to_bytes(&p).unwrap_or_default())
+            .unwrap_or_default();
+        stats.proof_generation += phase.elapsed();
         
+        let phase = Instant::now();
         let mut read_set = vec![
             ReadSetEntry::new(
                 format!("oid:{}", hex::encode(&selected_coin.object_id)),
                 coin_data,
-            ).with_proof(
-                merkle_proof
-                    .map(|p| bcs::to_bytes(&p).unwrap_or_default())
-                    .unwrap_or_default()
-            ),
+            ).with_proof(merkle_proof),
         ];
         
         // Add FluxState and PowerState for the sender (for Power/Flux in TEE)
@@ -225,6 +293,13 @@ impl TaskPreparer {
             ));
         }
 
+        // Add UserSubnetActivity for both parties (subnet interaction tracking)
+        read_set.extend(super::subnet_activity_read_set(
+            self.state_provider.as_ref(),
+            transfer,
+            &subnet_id,
+        ));
+
         // Add ResourceParams from GOVERNANCE subnet (for parameterized Power/Flux in TEE)
         let rp_oid = setu_types::resource_params_object_id();
         if let Some(rp_data) = self.state_provider.get_object_from_subnet(
@@ -236,9 +311,11 @@ impl TaskPreparer {
                 rp_data,
             ));
         }
+        stats.read_set += phase.elapsed();
         
         // Step 5: Create Event from Transfer with derived dependencies
-        let event = self.create_event_from_transfer(transfer, parent_ids)?;
+        let phase = Instant::now();
+        let event = self.create_event_from_transfer(transfer, parent_ids, subnet_id)?;
         
         // Step 6: Get pre-state root
         let pre_state_root = self.state_provider.get_state_root();
@@ -255,15 +332,21 @@ impl TaskPreparer {
             subnet_id,
         )
         .with_read_set(read_set)
-        .with_gas_budget(GasBudget::default());
+        .with_gas_budget(super::fee_calculator(self.state_provider.as_ref()).transfer_gas_budget(amount, 1));
+        let task = self.apply_task_config(task);
+        stats.assembly += phase.elapsed();
+        stats.total = started.elapsed();
+        self.prepare_metrics.record(&stats);
         
         info!(
             transfer_id = %transfer.id,
             task_id = %hex::encode(&task_id[..8]),
+            total_us = stats.total.as_micros() as u64,
+            proof_us = stats.proof_generation.as_micros() as u64,
             "SolverTask prepared successfully"
         );
         
-        Ok(task)
+        Ok((task, stats))
     }
 
     /// Prepare a SolverTask with coin reservation to prevent double-spend
@@ -290,9 +373,11 @@ impl TaskPreparer {
         subnet_id: SubnetId,
         reservation_mgr: &crate::coin_reservation::CoinReservationManager,
     ) -> Result<(SolverTask, Vec<crate::coin_reservation::ReservationHandle>), TaskPrepareError> {
+        let started = Instant::now();
+        let mut stats = PrepareStats::default();
         let amount = transfer.amount;
         
-        // Use subnet_id as the coin namespace (1:1 binding)
+        // Display name for logs; coins are matched via `SubnetId::from_coin_type`
         let subnet_id_str = if subnet_id == SubnetId::ROOT {
             "ROOT".to_string()
         } else {
@@ -309,9 +394,10 @@ impl TaskPreparer {
         );
         
         // Step 1: Get all coins for sender filtered by subnet_id
-        let sender_coins = self.state_provider.get_coins_for_address_by_type(
+        let phase = Instant::now();
+        let sender_coins = self.state_provider.get_coins_for_address_in_subnet(
             &transfer.from,
-            &subnet_id_str,
+            &subnet_id,
         );
         
         if sender_coins.is_empty() {
@@ -327,12 +413,7 @@ impl TaskPreparer {
             super::CoinSelectionResult::SingleCoin(_) => {
                 // --- Single coin path: try to reserve ANY eligible coin ---
                 // Collect all eligible coins (balance >= amount), sorted by balance ascending
-                let mut eligible: Vec<_> = sender_coins.iter()
-                    .filter(|c| c.balance >= amount)
-                    .cloned()
-                    .collect();
-                eligible.sort_by(|a, b| a.balance.cmp(&b.balance)
-                    .then_with(|| a.object_id.cmp(&b.object_id)));
+                let eligible = Self::eligible_single_coins(&sender_coins, amount);
 
                 // Try each eligible coin until one reservation succeeds
                 let (selected_coin, handle) = {
@@ -352,6 +433,7 @@ impl TaskPreparer {
                     })?
                 };
 
+                stats.coin_selection = phase.elapsed();
                 debug!(
                     object_id = ?selected_coin.object_id,
                     coin_balance = selected_coin.balance,
@@ -359,6 +441,7 @@ impl TaskPreparer {
                     "Selected and reserved single coin for transfer"
                 );
 
+                let phase = Instant::now();
                 let resolved_coin = setu_types::task::ResolvedObject {
                     object_id: selected_coin.object_id,
                     object_type: "Coin".to_string(),
@@ -367,20 +450,26 @@ impl TaskPreparer {
                 let resolved_inputs = setu_types::task::ResolvedInputs::transfer(resolved_coin.clone(), amount);
 
                 let input_objects: Vec<&setu_types::ObjectId> = vec![&selected_coin.object_id];
-                let parent_ids = self.derive_dependencies(&input_objects);
+                let parent_ids = self.derive_dependencies(&input_objects)?;
+                stats.assembly += phase.elapsed();
 
-                let coin_data = self.state_provider.get_object(&selected_coin.object_id)
+                let phase = Instant::now();
+                let coin_data = self.state_provider.get_object_from_subnet(&selected_coin.object_id, &subnet_id)
                     .ok_or(TaskPrepareError::ObjectNotFound(hex::encode(&selected_coin.object_id)))?;
-                let merkle_proof = self.state_provider.get_merkle_proof(&selected_coin.object_id);
+                stats.read_set += phase.elapsed();
+
+                let phase = Instant::now();
+                let merkle_proof = self.state_provider.get_merkle_proof_from_subnet(&selected_coin.object_id, &subnet_id)
+                    .map(|p| bcs::to_bytes(&p).unwrap_or_default())
+                    .unwrap_or_default();
+                stats.proof_generation += phase.elapsed();
+
+                let phase = Instant::now();
                 let mut read_set = vec![
                     setu_types::task::ReadSetEntry::new(
                         format!("oid:{}", hex::encode(&selected_coin.object_id)),
                         coin_data,
-                    ).with_proof(
-                        merkle_proof
-                            .map(|p| bcs::to_bytes(&p).unwrap_or_default())
-                            .unwrap_or_default()
-                    ),
+                    ).with_proof(merkle_proof),
                 ];
                 
                 // Add FluxState and PowerState for the sender
@@ -411,13 +500,22 @@ impl TaskPreparer {
                     ));
                 }
 
-                let event = self.create_event_from_transfer(transfer, parent_ids)?;
+                stats.read_set += phase.elapsed();
+
+                let phase = Instant::now();
+                let event = self.create_event_from_transfer(transfer, parent_ids, subnet_id)?;
                 let pre_state_root = self.state_provider.get_state_root();
                 let task_id = SolverTask::generate_task_id(&event, &pre_state_root);
 
                 let task = SolverTask::new(task_id, event, resolved_inputs, pre_state_root, subnet_id)
                     .with_read_set(read_set)
-                    .with_gas_budget(setu_types::task::GasBudget::default());
+                    .with_gas_budget(
+                        super::fee_calculator(self.state_provider.as_ref()).transfer_gas_budget(amount, 1),
+                    );
+                let task = self.apply_task_config(task);
+                stats.assembly += phase.elapsed();
+                stats.total = started.elapsed();
+                self.prepare_metrics.record(&stats);
 
                 info!(
                     transfer_id = %transfer.id,
@@ -443,6 +541,7 @@ impl TaskPreparer {
                         sender: transfer.from.clone(),
                         coin_count: 1 + sources.len(),
                     })?;
+                stats.coin_selection = phase.elapsed();
 
                 debug!(
                     transfer_id = %transfer.id,
@@ -452,8 +551,14 @@ impl TaskPreparer {
                 );
 
                 let recipient = setu_types::object::Address::normalize(&transfer.to);
-                match self.prepare_merge_then_transfer_task(&target, &sources, recipient, amount, subnet_id) {
-                    Ok(task) => Ok((task, handles)),
+                match self.merge_then_transfer_task_timed(
+                    &target, &sources, recipient, amount, subnet_id, &mut stats,
+                ) {
+                    Ok(task) => {
+                        stats.total = started.elapsed();
+                        self.prepare_metrics.record(&stats);
+                        Ok((task, handles))
+                    }
                     Err(e) => {
                         // Rollback reservations on task preparation failure
                         reservation_mgr.release_batch(&handles);
@@ -464,6 +569,85 @@ impl TaskPreparer {
         }
     }
     
+    /// Dry-run coin selection and fee computation for a transfer
+    ///
+    /// Follows `prepare_transfer_task_with_reservation` against the current
+    /// state, but only checks reservations instead of taking them and never
+    /// builds an event, so nothing is mutated. A transfer submitted right
+    /// after with the same inputs selects the same coins, unless another
+    /// request reserves them in between.
+    pub fn estimate_transfer(
+        &self,
+        from: &str,
+        amount: u64,
+        subnet_id: SubnetId,
+        reservation_mgr: &crate::coin_reservation::CoinReservationManager,
+    ) -> Result<super::TransferEstimate, TaskPrepareError> {
+        let subnet_id_str = if subnet_id == SubnetId::ROOT {
+            "ROOT".to_string()
+        } else {
+            subnet_id.to_string()
+        };
+
+        let sender_coins = self.state_provider.get_coins_for_address_in_subnet(from, &subnet_id);
+        if sender_coins.is_empty() {
+            return Err(TaskPrepareError::NoCoinsFound(
+                format!("sender {} has no coins in subnet {}", from, subnet_id_str)
+            ));
+        }
+
+        let selected_coins = match self.select_coins_for_transfer(&sender_coins, amount)? {
+            super::CoinSelectionResult::SingleCoin(_) => {
+                // Same candidates, in the same order, as the reserving path
+                let eligible = Self::eligible_single_coins(&sender_coins, amount);
+                let coin = eligible.iter()
+                    .find(|c| !reservation_mgr.is_reserved(&c.object_id))
+                    .cloned()
+                    .ok_or_else(|| TaskPrepareError::AllCoinsReserved {
+                        sender: from.to_string(),
+                        coin_count: eligible.len(),
+                    })?;
+                vec![coin]
+            }
+            super::CoinSelectionResult::NeedMerge { target, sources } => {
+                let mut coins = Vec::with_capacity(1 + sources.len());
+                coins.push(target);
+                coins.extend(sources);
+                // The merge path reserves all coins or none
+                if coins.iter().any(|c| reservation_mgr.is_reserved(&c.object_id)) {
+                    return Err(TaskPrepareError::AllCoinsReserved {
+                        sender: from.to_string(),
+                        coin_count: coins.len(),
+                    });
+                }
+                coins
+            }
+        };
+
+        // Same calculator and coin count as the prepared task's gas budget
+        let quote = super::fee_calculator(self.state_provider.as_ref())
+            .transfer_fee(amount, selected_coins.len());
+        let total_balance = sender_coins.iter().fold(0u64, |acc, c| acc.saturating_add(c.balance));
+
+        Ok(super::TransferEstimate {
+            selected_coins,
+            total_fee: quote.fee,
+            total_burn: quote.burn,
+            resulting_balance: total_balance.saturating_sub(amount).saturating_sub(quote.fee),
+        })
+    }
+
+    /// Coins that can cover `amount` alone, smallest first (ObjectId tie-break)
+    fn eligible_single_coins(coins: &[CoinInfo], amount: u64) -> Vec<CoinInfo> {
+        let mut eligible: Vec<_> = coins.iter()
+            .filter(|c| c.balance >= amount)
+            .cloned()
+            .collect();
+        eligible.sort_by(|a, b| a.balance.cmp(&b.balance)
+            .then_with(|| a.object_id.cmp(&b.object_id)));
+        eligible
+    }
+    
     /// Prepare a SolverTask for merging multiple coins into one.
     ///
     /// The target coin accumulates balances from all source coins.
@@ -500,9 +684,9 @@ impl TaskPreparer {
         all_ids.extend(source_coins.iter().map(|c| c.object_id));
 
         let input_refs: Vec<&ObjectId> = all_ids.iter().collect();
-        let parent_ids = self.derive_dependencies(&input_refs);
+        let parent_ids = self.derive_dependencies(&input_refs)?;
 
-        let read_set = self.build_read_set(&all_ids)?;
+        let read_set = self.build_read_set(&all_ids, subnet_id, &mut PrepareStats::default())?;
 
         let vlc_snapshot = self.generate_vlc_snapshot();
         let mut event = Event::new(
@@ -522,6 +706,7 @@ impl TaskPreparer {
         let task = SolverTask::new(task_id, event, resolved_inputs, pre_state_root, subnet_id)
             .with_read_set(read_set)
             .with_gas_budget(GasBudget::default());
+        let task = self.apply_task_config(task);
 
         info!(
             task_id = %hex::encode(&task_id[..8]),
@@ -569,9 +754,9 @@ impl TaskPreparer {
         let resolved_inputs = ResolvedInputs::split_coin(source_resolved, amounts.clone());
 
         let input_refs: Vec<&ObjectId> = vec![&source_coin.object_id];
-        let parent_ids = self.derive_dependencies(&input_refs);
+        let parent_ids = self.derive_dependencies(&input_refs)?;
 
-        let read_set = self.build_read_set(&[source_coin.object_id])?;
+        let read_set = self.build_read_set(&[source_coin.object_id], subnet_id, &mut PrepareStats::default())?;
 
         let vlc_snapshot = self.generate_vlc_snapshot();
         let mut event = Event::new(
@@ -591,6 +776,7 @@ impl TaskPreparer {
         let task = SolverTask::new(task_id, event, resolved_inputs, pre_state_root, subnet_id)
             .with_read_set(read_set)
             .with_gas_budget(GasBudget::default());
+        let task = self.apply_task_config(task);
 
         info!(
             task_id = %hex::encode(&task_id[..8]),
@@ -611,6 +797,27 @@ impl TaskPreparer {
         recipient: setu_types::object::Address,
         amount: u64,
         subnet_id: SubnetId,
+    ) -> Result<SolverTask, TaskPrepareError> {
+        self.merge_then_transfer_task_timed(
+            target_coin,
+            source_coins,
+            recipient,
+            amount,
+            subnet_id,
+            &mut PrepareStats::default(),
+        )
+    }
+
+    /// `prepare_merge_then_transfer_task`, adding read-set and proof time to
+    /// `stats` and attributing everything else to assembly.
+    fn merge_then_transfer_task_timed(
+        &self,
+        target_coin: &CoinInfo,
+        source_coins: &[CoinInfo],
+        recipient: setu_types::object::Address,
+        amount: u64,
+        subnet_id: SubnetId,
+        stats: &mut PrepareStats,
     ) -> Result<SolverTask, TaskPrepareError> {
         if source_coins.is_empty() {
             return Err(TaskPrepareError::InvalidInput(
@@ -618,6 +825,7 @@ impl TaskPreparer {
             ));
         }
 
+        let phase = Instant::now();
         // Verify merged balance will be sufficient
         let merged_balance: u64 = target_coin.balance
             + source_coins.iter().map(|c| c.balance).sum::<u64>();
@@ -652,10 +860,12 @@ impl TaskPreparer {
         all_ids.extend(source_coins.iter().map(|c| c.object_id));
 
         let input_refs: Vec<&ObjectId> = all_ids.iter().collect();
-        let parent_ids = self.derive_dependencies(&input_refs);
+        let parent_ids = self.derive_dependencies(&input_refs)?;
+        stats.assembly += phase.elapsed();
 
-        let read_set = self.build_read_set(&all_ids)?;
+        let read_set = self.build_read_set(&all_ids, subnet_id, stats)?;
 
+        let phase = Instant::now();
         let vlc_snapshot = self.generate_vlc_snapshot();
         let mut event = Event::new(
             EventType::CoinMergeThenTransfer,
@@ -675,7 +885,12 @@ impl TaskPreparer {
 
         let task = SolverTask::new(task_id, event, resolved_inputs, pre_state_root, subnet_id)
             .with_read_set(read_set)
-            .with_gas_budget(GasBudget::default());
+            .with_gas_budget(
+                super::fee_calculator(self.state_provider.as_ref())
+                    .transfer_gas_budget(amount, 1 + source_coins.len()),
+            );
+        let task = self.apply_task_config(task);
+        stats.assembly += phase.elapsed();
 
         info!(
             task_id = %hex::encode(&task_id[..8]),
@@ -688,24 +903,33 @@ impl TaskPreparer {
     }
 
     /// Build read_set entries for a list of object IDs.
+    ///
+    /// Objects and proofs are read from `subnet_id`'s SMT.
+    /// Object reads and proof generation are added to `stats` separately.
     fn build_read_set(
         &self,
         object_ids: &[ObjectId],
+        subnet_id: SubnetId,
+        stats: &mut PrepareStats,
     ) -> Result<Vec<ReadSetEntry>, TaskPrepareError> {
         let mut read_set = Vec::with_capacity(object_ids.len());
         for oid in object_ids {
-            let coin_data = self.state_provider.get_object(oid)
+            let phase = Instant::now();
+            let coin_data = self.state_provider.get_object_from_subnet(oid, &subnet_id)
                 .ok_or(TaskPrepareError::ObjectNotFound(hex::encode(oid)))?;
-            let merkle_proof = self.state_provider.get_merkle_proof(oid);
+            stats.read_set += phase.elapsed();
+
+            let phase = Instant::now();
+            let merkle_proof = self.state_provider.get_merkle_proof_from_subnet(oid, &subnet_id)
+                .map(|p| bcs::to_bytes(&p).unwrap_or_default())
+                .unwrap_or_default();
+            stats.proof_generation += phase.elapsed();
+
             read_set.push(
                 ReadSetEntry::new(
                     format!("oid:{}", hex::encode(oid)),
                     coin_data,
-                ).with_proof(
-                    merkle_proof
-                        .map(|p| bcs::to_bytes(&p).unwrap_or_default())
-                        .unwrap_or_default()
-                ),
+                ).with_proof(merkle_proof),
             );
         }
         Ok(read_set)
@@ -719,6 +943,7 @@ impl TaskPreparer {
             .as_nanos() as u64;
         let mut snapshot = VLCSnapshot::for_node(self.validator_id.clone());
         snapshot.logical_time = now_nanos;
+        snapshot.vector_clock.set(&self.validator_id, snapshot.logical_time);
         snapshot.physical_time = now_nanos / 1_000_000;
         snapshot
     }
@@ -749,6 +974,7 @@ impl TaskPreparer {
         coins: &[CoinInfo],
         amount: u64,
     ) -> Result<super::CoinSelectionResult, TaskPrepareError> {
+        // Also gated by `TransferAmountBounds`, but preparers can be called directly
         if amount == 0 {
             return Err(TaskPrepareError::InvalidInput(
                 "Transfer amount must be > 0".into(),
@@ -819,6 +1045,7 @@ impl TaskPreparer {
         &self,
         transfer: &setu_types::Transfer,
         parent_ids: Vec<String>,
+        subnet_id: SubnetId,
     ) -> Result<Event, TaskPrepareError> {
         // Use the VLC assigned by Validator (from transfer) to ensure unique event_id
         // If no assigned_vlc, fall back to timestamp-based VLC (but this shouldn't happen in production)
@@ -827,6 +1054,9 @@ impl TaskPreparer {
                 // Create VLCSnapshot with proper vector clock for the assigning validator
                 let mut snapshot = VLCSnapshot::for_node(vlc.validator_id.clone());
                 snapshot.logical_time = vlc.logical_time;
+                // Tick the stamping validator's entry to the logical time, so
+                // its later stamps causally follow this one
+                snapshot.vector_clock.set(&vlc.validator_id, snapshot.logical_time);
                 snapshot.physical_time = vlc.physical_time;
                 snapshot
             },
@@ -844,6 +1074,7 @@ impl TaskPreparer {
                 );
                 let mut snapshot = VLCSnapshot::for_node(self.validator_id.clone());
                 snapshot.logical_time = now_nanos;
+                snapshot.vector_clock.set(&self.validator_id, snapshot.logical_time);
                 snapshot.physical_time = now_nanos / 1_000_000; // Convert to milliseconds
                 snapshot
             }
@@ -857,8 +1088,10 @@ impl TaskPreparer {
         );
         
         // Attach transfer data (clone it)
-        event = event.with_transfer(transfer.clone());
+        event = event.with_transfer(transfer.clone()).with_subnet(subnet_id);
         
+        // Unsigned: the signature covers the execution result, so the event is
+        // signed with the node key after execution (`ConsensusValidator::sign_local_event`)
         Ok(event)
     }
     
@@ -866,7 +1099,7 @@ impl TaskPreparer {
     ///
     /// For each input object, find the last event that modified it.
     /// These events become the parent_ids (dependencies) of the new event.
-    fn derive_dependencies(&self, input_objects: &[&ObjectId]) -> Vec<String> {
+    fn derive_dependencies(&self, input_objects: &[&ObjectId]) -> Result<Vec<String>, TaskPrepareError> {
         let mut parent_ids = Vec::new();
         let mut seen = std::collections::HashSet::new();
         
@@ -884,13 +1117,24 @@ impl TaskPreparer {
             }
         }
         
+        if parent_ids.is_empty() {
+            if let Some(selector) = &self.parent_selector {
+                parent_ids = selector.select_parents()?;
+                debug!(
+                    fallback_count = parent_ids.len(),
+                    "No input object dependencies, using selected DAG parents"
+                );
+                return Ok(parent_ids);
+            }
+        }
+        
         debug!(
             input_count = input_objects.len(),
             dependency_count = parent_ids.len(),
             "Derived event dependencies from input objects"
         );
         
-        parent_ids
+        Ok(parent_ids)
     }
 
     // ========== Phase 4: MoveCall task preparation ==========
@@ -1176,7 +1420,7 @@ impl TaskPreparer {
             &call.input_object_ids.iter()
                 .chain(call.shared_object_ids.iter())
                 .collect::<Vec<_>>(),
-        );
+        )?;
 
         // 6. Build SolverTask
         let task_id = {
@@ -1197,6 +1441,8 @@ impl TaskPreparer {
             resolved_inputs,
             gas_budget: setu_types::task::GasBudget::default(),
             module_read_set,
+            execution_deadline_ms: self.execution_deadline_ms,
+            zero_balance_policy: self.zero_balance_policy,
         })
     }
 
@@ -1467,6 +1713,8 @@ impl TaskPreparer {
             resolved_inputs,
             gas_budget: setu_types::task::GasBudget::default(),
             module_read_set,
+            execution_deadline_ms: self.execution_deadline_ms,
+            zero_balance_policy: self.zero_balance_policy,
         })
     }
 
@@ -1811,6 +2059,177 @@ mod tests {
         assert!(!task.read_set.is_empty());
     }
     
+    struct FixedParents(Vec<String>);
+
+    impl ParentSelector for FixedParents {
+        fn select_parents(&self) -> Result<Vec<String>, TaskPrepareError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn test_transfer_without_object_dependencies_uses_parent_selector() {
+        let transfer = create_test_transfer();
+
+        // Seeded coins have no writer event, so there is nothing to derive
+        let task = TaskPreparer::new_for_testing("validator-1".to_string())
+            .with_parent_selector(Arc::new(FixedParents(vec!["tip-a".to_string()])))
+            .prepare_transfer_task(&transfer, SubnetId::ROOT)
+            .unwrap();
+        assert_eq!(task.event.parent_ids, vec!["tip-a".to_string()]);
+    }
+
+    struct UnreadableDag;
+
+    impl ParentSelector for UnreadableDag {
+        fn select_parents(&self) -> Result<Vec<String>, TaskPrepareError> {
+            Err(TaskPrepareError::ParentSelectionFailed("DAG busy".to_string()))
+        }
+    }
+
+    #[test]
+    fn test_parent_selection_failure_fails_preparation() {
+        let transfer = create_test_transfer();
+
+        // No parentless event is prepared when the DAG cannot be read
+        let result = TaskPreparer::new_for_testing("validator-1".to_string())
+            .with_parent_selector(Arc::new(UnreadableDag))
+            .prepare_transfer_task(&transfer, SubnetId::ROOT);
+        assert!(matches!(result, Err(TaskPrepareError::ParentSelectionFailed(_))));
+    }
+
+    #[test]
+    fn test_prepared_task_carries_configured_deadline() {
+        let transfer = create_test_transfer();
+
+        let task = TaskPreparer::new_for_testing("validator-1".to_string())
+            .prepare_transfer_task(&transfer, SubnetId::ROOT)
+            .unwrap();
+        assert_eq!(task.execution_deadline_ms, None);
+
+        let task = TaskPreparer::new_for_testing("validator-1".to_string())
+            .with_execution_deadline_ms(Some(250))
+            .prepare_transfer_task(&transfer, SubnetId::ROOT)
+            .unwrap();
+        assert_eq!(task.execution_deadline_ms, Some(250));
+    }
+
+    #[test]
+    fn test_prepared_task_carries_zero_balance_policy() {
+        let transfer = create_test_transfer();
+
+        let task = TaskPreparer::new_for_testing("validator-1".to_string())
+            .prepare_transfer_task(&transfer, SubnetId::ROOT)
+            .unwrap();
+        assert_eq!(task.zero_balance_policy, ZeroBalanceCoinPolicy::Keep);
+
+        let task = TaskPreparer::new_for_testing("validator-1".to_string())
+            .with_zero_balance_policy(ZeroBalanceCoinPolicy::Tombstone)
+            .prepare_transfer_task(&transfer, SubnetId::ROOT)
+            .unwrap();
+        assert_eq!(task.zero_balance_policy, ZeroBalanceCoinPolicy::Tombstone);
+    }
+    
+    /// Delegates to the test provider, but parks the first Merkle proof
+    /// request until the test releases it, so the test can move the paused
+    /// clock while proof generation is in progress.
+    struct GatedProofProvider {
+        inner: Arc<setu_storage::MerkleStateProvider>,
+        gate: std::sync::Mutex<Option<(std::sync::mpsc::Sender<()>, std::sync::mpsc::Receiver<()>)>>,
+    }
+
+    impl StateProvider for GatedProofProvider {
+        fn get_coins_for_address(&self, address: &str) -> Vec<CoinInfo> {
+            self.inner.get_coins_for_address(address)
+        }
+
+        fn get_coins_for_address_in_subnet(&self, address: &str, subnet_id: &SubnetId) -> Vec<CoinInfo> {
+            self.inner.get_coins_for_address_in_subnet(address, subnet_id)
+        }
+
+        fn get_object(&self, object_id: &ObjectId) -> Option<Vec<u8>> {
+            self.inner.get_object(object_id)
+        }
+
+        fn get_object_finalized(&self, object_id: &ObjectId) -> Option<Vec<u8>> {
+            self.inner.get_object_finalized(object_id)
+        }
+
+        fn get_object_from_subnet(&self, object_id: &ObjectId, subnet_id: &SubnetId) -> Option<Vec<u8>> {
+            self.inner.get_object_from_subnet(object_id, subnet_id)
+        }
+
+        fn get_state_root(&self) -> [u8; 32] {
+            self.inner.get_state_root()
+        }
+
+        fn get_merkle_proof(&self, object_id: &ObjectId) -> Option<setu_storage::SimpleMerkleProof> {
+            self.inner.get_merkle_proof(object_id)
+        }
+
+        fn get_merkle_proof_from_subnet(
+            &self,
+            object_id: &ObjectId,
+            subnet_id: &SubnetId,
+        ) -> Option<setu_storage::SimpleMerkleProof> {
+            let gate = self.gate.lock().unwrap().take();
+            if let Some((entered, release)) = gate {
+                entered.send(()).unwrap();
+                release.recv().unwrap();
+            }
+            self.inner.get_merkle_proof_from_subnet(object_id, subnet_id)
+        }
+
+        fn get_last_modifying_event(&self, object_id: &ObjectId) -> Option<String> {
+            self.inner.get_last_modifying_event(object_id)
+        }
+
+        fn get_raw(&self, key: &str) -> Option<Vec<u8>> {
+            self.inner.get_raw(key)
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_prepare_transfer_task_reports_phase_timings() {
+        let (entered_tx, entered_rx) = std::sync::mpsc::channel();
+        let (release_tx, release_rx) = std::sync::mpsc::channel();
+        let provider = GatedProofProvider {
+            inner: crate::task_preparer::create_test_state_provider(),
+            gate: std::sync::Mutex::new(Some((entered_tx, release_rx))),
+        };
+        let preparer = TaskPreparer::new("validator-1".to_string(), Arc::new(provider));
+        let transfer = create_test_transfer();
+
+        // The preparation blocks inside proof generation; the paused clock
+        // only moves when the test advances it
+        let prepare = tokio::task::spawn_blocking(move || {
+            let result = preparer.prepare_transfer_task_with_stats(&transfer, SubnetId::ROOT);
+            (preparer, result)
+        });
+        let proof_time = std::time::Duration::from_millis(40);
+        tokio::task::spawn_blocking(move || entered_rx.recv().unwrap()).await.unwrap();
+        tokio::time::advance(proof_time).await;
+        release_tx.send(()).unwrap();
+
+        let (preparer, result) = prepare.await.unwrap();
+        let (task, stats) = result.unwrap();
+
+        // The coin's Merkle proof was generated and timed on its own
+        assert!(task.read_set[0].proof.as_ref().is_some_and(|p| !p.is_empty()));
+        assert_eq!(stats.proof_generation, proof_time);
+        assert_eq!(stats.coin_selection, std::time::Duration::ZERO);
+        assert_eq!(stats.read_set, std::time::Duration::ZERO);
+        assert_eq!(stats.assembly, std::time::Duration::ZERO);
+
+        // Phases are disjoint slices of the total
+        assert_eq!(stats.phases_sum(), stats.total);
+
+        assert_eq!(preparer.prepare_metrics().count(), 1);
+        let text = preparer.prepare_metrics().render_prometheus();
+        assert!(text.contains("setu_task_prepare_phase_time_us_sum{phase=\"proof_generation\"} 40000\n"));
+        assert!(text.contains("setu_task_prepare_count 1\n"));
+    }
+    
     #[test]
     fn test_select_smallest_sufficient_coin() {
         let preparer = TaskPreparer::new_for_testing("validator-1".to_string());
@@ -1849,6 +2268,92 @@ mod tests {
             _ => panic!("Expected InsufficientBalance error"),
         }
     }
+    
+    #[test]
+    fn test_estimate_transfer_matches_submitted_transfer() {
+        let preparer = TaskPreparer::new_for_testing("validator-1".to_string());
+        let reservation_mgr = crate::coin_reservation::CoinReservationManager::with_default_ttl();
+        let transfer = create_test_transfer();
+        
+        let estimate = preparer
+            .estimate_transfer(&transfer.from, transfer.amount, SubnetId::ROOT, &reservation_mgr)
+            .unwrap();
+        // A dry run takes no reservations
+        assert_eq!(reservation_mgr.reservation_count(), 0);
+        
+        // Submitting the identical transfer spends exactly the estimated coins
+        let (task, handles) = preparer
+            .prepare_transfer_task_with_reservation(&transfer, SubnetId::ROOT, &reservation_mgr)
+            .unwrap();
+        let estimated: Vec<ObjectId> = estimate.selected_coins.iter().map(|c| c.object_id).collect();
+        let actual: Vec<ObjectId> = task.resolved_inputs.input_objects.iter().map(|o| o.object_id).collect();
+        assert_eq!(estimated, actual);
+        assert_eq!(estimate.total_fee, task.gas_budget.estimated_fee);
+        
+        let before: u64 = preparer
+            .state_provider()
+            .get_coins_for_address_by_type(&transfer.from, "ROOT")
+            .iter()
+            .map(|c| c.balance)
+            .sum();
+        assert_eq!(estimate.resulting_balance, before - transfer.amount - estimate.total_fee);
+        
+        // With that coin now reserved, the next estimate moves on to another one
+        let next = preparer
+            .estimate_transfer(&transfer.from, transfer.amount, SubnetId::ROOT, &reservation_mgr)
+            .unwrap();
+        assert_ne!(next.selected_coins[0].object_id, actual[0]);
+        
+        reservation_mgr.release_batch(&handles);
+    }
+
+    #[test]
+    fn test_estimate_fee_matches_prepared_task_fee() {
+        use setu_storage::{GlobalStateManager, SharedStateManager, MerkleStateProvider, init_coins_split};
+        use setu_types::{ResourceGovernanceMode, ResourceParams};
+        use std::sync::Arc;
+
+        let shared = Arc::new(SharedStateManager::new(GlobalStateManager::new()));
+        {
+            let mut gsm = shared.lock_write();
+            let params = ResourceParams {
+                gas_mode: ResourceGovernanceMode::Enabled,
+                gas_base_price: 3,
+                transfer_fee_rate_bps: 25,
+                ..ResourceParams::default()
+            };
+            gsm.upsert_object(
+                SubnetId::GOVERNANCE,
+                *setu_types::resource_params_object_id().as_bytes(),
+                serde_json::to_vec(&params).unwrap(),
+            ).unwrap();
+            // 4 coins of 25_000
+            init_coins_split(&mut gsm, "alice", 100_000, 4, "ROOT");
+            shared.publish_snapshot(&gsm);
+        }
+        let provider: Arc<dyn StateProvider> = Arc::new(MerkleStateProvider::new(shared));
+        let preparer = TaskPreparer::new("validator-test".to_string(), provider);
+        let reservation_mgr = crate::coin_reservation::CoinReservationManager::with_default_ttl();
+
+        // Single coin, exact coin, and 2- and 3-coin merges
+        for (i, (amount, coin_count)) in [(100, 1), (25_000, 1), (40_000, 2), (60_000, 3)].into_iter().enumerate() {
+            let transfer = Transfer::new(format!("fee-tx-{}", i), "alice", "bob", amount)
+                .with_type(TransferType::SetuTransfer);
+            let estimate = preparer
+                .estimate_transfer(&transfer.from, amount, SubnetId::ROOT, &reservation_mgr)
+                .unwrap();
+            let (task, handles) = preparer
+                .prepare_transfer_task_with_reservation(&transfer, SubnetId::ROOT, &reservation_mgr)
+                .unwrap();
+
+            assert_eq!(estimate.selected_coins.len(), coin_count);
+            assert_eq!(task.resolved_inputs.input_objects.len(), coin_count);
+            assert_eq!(estimate.total_fee, 3 * coin_count as u64 + amount * 25 / 10_000);
+            assert_eq!(estimate.total_burn, amount * 25 / 10_000);
+            assert_eq!(estimate.total_fee, task.gas_budget.estimated_fee, "amount {}", amount);
+            reservation_mgr.release_batch(&handles);
+        }
+    }
 
     // ── NeedMerge coin selection tests ──
 
@@ -1919,6 +2424,17 @@ mod tests {
         assert!(preparer.select_coins_for_transfer(&coins, 100).is_err());
     }
 
+    #[test]
+    fn test_select_coins_rejects_zero_amount() {
+        let preparer = TaskPreparer::new_for_testing("validator-1".to_string());
+        let coins = vec![make_coin(1, 100)];
+
+        assert!(matches!(
+            preparer.select_coins_for_transfer(&coins, 0),
+            Err(TaskPrepareError::InvalidInput(_))
+        ));
+    }
+
     #[test]
     fn test_select_coins_deterministic_tie_break() {
         let preparer = TaskPreparer::new_for_testing("validator-1".to_string());
@@ -1985,7 +2501,7 @@ mod tests {
         {
             let mut gsm = shared.lock_write();
             let sc = StateChange::insert(module_key.to_string(), module_bytes.to_vec());
-            gsm.apply_state_change(SubnetId::ROOT, &sc);
+            gsm.apply_state_change(SubnetId::ROOT, &sc).unwrap();
             shared.publish_snapshot(&gsm);
         }
         let provider: Arc<dyn StateProvider> = Arc::new(MerkleStateProvider::new(shared));
@@ -2134,12 +2650,12 @@ mod tests {
             );
             let key = format!("oid:{}", hex::encode(object_id.as_bytes()));
             let sc = StateChange::insert(key, env.to_bytes());
-            gsm.apply_state_change(SubnetId::ROOT, &sc);
+            gsm.apply_state_change(SubnetId::ROOT, &sc).unwrap();
 
             // Optionally store a module
             if let Some((mk, mb)) = module_key {
                 let sc = StateChange::insert(mk.to_string(), mb.to_vec());
-                gsm.apply_state_change(SubnetId::ROOT, &sc);
+                gsm.apply_state_change(SubnetId::ROOT, &sc).unwrap();
             }
 
             shared.publish_snapshot(&gsm);
@@ -2663,7 +3179,7 @@ mod tests {
                     format!("oid:{}", hex::encode(owned_id.as_bytes())),
                     owned_env.to_bytes(),
                 ),
-            );
+            ).unwrap();
             let shared_env = ObjectEnvelope::from_move_result(
                 shared_id, alice, 1,
                 setu_types::Ownership::Shared { initial_shared_version: 0 },
@@ -2675,11 +3191,11 @@ mod tests {
                     format!("oid:{}", hex::encode(shared_id.as_bytes())),
                     shared_env.to_bytes(),
                 ),
-            );
+            ).unwrap();
             gsm.apply_state_change(
                 SubnetId::ROOT,
                 &StateChange::insert(module_key.clone(), bytecode.clone()),
-            );
+            ).unwrap();
             shared_state.publish_snapshot(&gsm);
         }
         let provider: Arc<dyn StateProvider> = Arc::new(MerkleStateProvider::new(shared_state));
@@ -2772,7 +3288,7 @@ mod tests {
                 gsm.apply_state_change(
                     SubnetId::ROOT,
                     &StateChange::insert(pkey, parent_env.to_bytes()),
-                );
+                ).unwrap();
 
                 if let Some((df_oid, expected_parent, payload)) = df_entry {
                     let data = bcs::to_bytes(&payload).unwrap();
@@ -2791,14 +3307,14 @@ mod tests {
                     gsm.apply_state_change(
                         SubnetId::ROOT,
                         &StateChange::insert(dkey, df_env.to_bytes()),
-                    );
+                    ).unwrap();
                 }
 
                 let (mk, mb) = module_key;
                 gsm.apply_state_change(
                     SubnetId::ROOT,
                     &StateChange::insert(mk.to_string(), mb.to_vec()),
-                );
+                ).unwrap();
 
                 shared.publish_snapshot(&gsm);
             }
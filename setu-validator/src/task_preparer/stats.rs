@@ -0,0 +1,92 @@
+//! Per-phase timing for single-transfer task preparation
+//!
+//! [`PrepareStats`] breaks one `TaskPreparer` run into phases so a slow
+//! preparation can be attributed to coin selection, state reads, Merkle proof
+//! generation or task assembly. [`PrepareMetrics`] aggregates those
+//! breakdowns for `GET /api/v1/metrics`.
+
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Metric name used in the Prometheus output
+const METRIC_NAME: &str = "setu_task_prepare_phase_time_us";
+
+/// Time spent in each phase of one task preparation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PrepareStats {
+    /// Coin lookup, selection and reservation
+    pub coin_selection: Duration,
+    /// Reading read-set objects from state (excluding proofs)
+    pub read_set: Duration,
+    /// Generating and serializing Merkle proofs
+    pub proof_generation: Duration,
+    /// Dependency derivation, event creation and SolverTask construction
+    pub assembly: Duration,
+    /// Wall-clock time of the whole preparation
+    pub total: Duration,
+}
+
+impl PrepareStats {
+    /// Sum of the individual phases (excludes untracked overhead)
+    pub fn phases_sum(&self) -> Duration {
+        self.coin_selection + self.read_set + self.proof_generation + self.assembly
+    }
+
+    fn phases(&self) -> [(&'static str, Duration); 4] {
+        [
+            ("coin_selection", self.coin_selection),
+            ("read_set", self.read_set),
+            ("proof_generation", self.proof_generation),
+            ("assembly", self.assembly),
+        ]
+    }
+}
+
+/// Running totals of [`PrepareStats`] across successful preparations
+///
+/// Recording is lock-free (one atomic add per phase).
+#[derive(Debug, Default)]
+pub struct PrepareMetrics {
+    count: AtomicU64,
+    /// Per-phase sums in microseconds, in `PrepareStats::phases` order
+    phase_sum_us: [AtomicU64; 4],
+    total_sum_us: AtomicU64,
+}
+
+impl PrepareMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one preparation's breakdown
+    pub fn record(&self, stats: &PrepareStats) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        for (sum, (_, elapsed)) in self.phase_sum_us.iter().zip(stats.phases()) {
+            sum.fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+        }
+        self.total_sum_us
+            .fetch_add(stats.total.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// Number of recorded preparations
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Render per-phase totals in Prometheus text format
+    pub fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        let _ = writeln!(out, "# HELP {}_sum Time spent per SolverTask preparation phase, in microseconds.", METRIC_NAME);
+        let _ = writeln!(out, "# TYPE {}_sum counter", METRIC_NAME);
+        for (sum, (phase, _)) in self.phase_sum_us.iter().zip(PrepareStats::default().phases()) {
+            let _ = writeln!(out, "{}_sum{{phase=\"{}\"}} {}", METRIC_NAME, phase, sum.load(Ordering::Relaxed));
+        }
+        let _ = writeln!(out, "{}_sum{{phase=\"total\"}} {}", METRIC_NAME, self.total_sum_us.load(Ordering::Relaxed));
+        let _ = writeln!(out, "# HELP setu_task_prepare_count Successful SolverTask preparations.");
+        let _ = writeln!(out, "# TYPE setu_task_prepare_count counter");
+        let _ = writeln!(out, "setu_task_prepare_count {}", self.count());
+        out
+    }
+}
@@ -495,10 +495,15 @@ impl UserRpcHandler for ValidatorUserHandler {
             .filter(|(ct, _)| {
                 request.coin_type.as_ref().map_or(true, |filter| ct == filter)
             })
-            .map(|(coin_type, (balance, coin_count))| CoinBalance {
-                coin_type,
-                balance,
-                coin_count,
+            .map(|(coin_type, (balance, coin_count))| {
+                let metadata = self.network_service.merkle_state_provider().get_coin_metadata(&coin_type);
+                CoinBalance {
+                    coin_type,
+                    balance,
+                    coin_count,
+                    symbol: metadata.as_ref().map(|m| m.symbol.clone()),
+                    decimals: metadata.map(|m| m.decimals),
+                }
             })
             .collect();
 
@@ -642,6 +647,9 @@ impl UserRpcHandler for ValidatorUserHandler {
             preferred_solver: None,
             shard_id: None,
             subnet_id: None,
+            recipient_subnet_id: None,
+            fee: 0,
+            wait_for_finalization: false,
         };
         
         // Use existing transfer submission logic
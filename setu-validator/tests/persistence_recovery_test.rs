@@ -55,6 +55,7 @@ fn create_test_event(id: &str, creator: &str, parent_ids: Vec<String>) -> Event
         execution_result: None,
         status: EventStatus::Pending,
         vlc_snapshot: VLCSnapshot::new(),
+        signature: Vec::new(),
     }
 }
 
@@ -96,6 +97,7 @@ fn create_test_config() -> ConsensusValidatorConfig {
         is_leader: true,
         consensus: ConsensusConfig::default(),
         message_buffer_size: 100,
+        ..Default::default()
     }
 }
 
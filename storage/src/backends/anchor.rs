@@ -35,6 +35,12 @@ pub trait AnchorStoreBackend: Send + Sync + Debug {
     /// Get the full anchor chain (list of anchor IDs in order)
     async fn get_chain(&self) -> Vec<AnchorId>;
 
+    /// Get the ID of the stored anchor that finalized `event_id`
+    ///
+    /// Answered from an index written together with the anchor, so it
+    /// survives restarts and does not scan the chain.
+    async fn get_anchor_id_for_event(&self, event_id: &str) -> Option<AnchorId>;
+
     // =========================================================================
     // Recovery operations
     // =========================================================================
@@ -55,6 +61,21 @@ pub trait AnchorStoreBackend: Send + Sync + Debug {
     async fn get_by_depth_range(&self, _min_depth: u64, _max_depth: u64) -> Vec<Anchor> {
         vec![]
     }
+
+    // =========================================================================
+    // Durability
+    // =========================================================================
+
+    /// Flush buffered writes to disk (no-op for in-memory stores)
+    async fn flush(&self) -> SetuResult<()> {
+        Ok(())
+    }
+
+    /// Perform a real read against the backing store and surface any error
+    /// (used by health/readiness probes; in-memory stores cannot fail)
+    async fn probe(&self) -> SetuResult<()> {
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -89,6 +110,10 @@ impl AnchorStoreBackend for AnchorStore {
         AnchorStore::get_chain(self).await
     }
 
+    async fn get_anchor_id_for_event(&self, event_id: &str) -> Option<AnchorId> {
+        AnchorStore::get_anchor_id_for_event(self, event_id).await
+    }
+
     async fn get_recovery_state(&self) -> Option<([u8; 32], u64, u64, u64)> {
         AnchorStore::get_recovery_state(self).await
     }
@@ -0,0 +1,34 @@
+//! Atomic anchor commit backend
+//!
+//! Finalizing an anchor normally issues separate writes for its events, the
+//! finalized CF index and the anchor itself. A backend implementing this
+//! trait persists all of them in one atomic commit instead, so a crash can
+//! never leave an anchor partially persisted.
+
+use async_trait::async_trait;
+use setu_types::{Anchor, ConsensusFrame, Event, SetuResult, VoteCertificate};
+use std::fmt::Debug;
+
+/// Everything persisted when an anchor is finalized
+#[derive(Debug, Clone)]
+pub struct AnchorCommit {
+    /// Events folded into the anchor, with their DAG depths
+    pub events: Vec<(Event, u64)>,
+    /// CFs to index as finalized
+    pub finalized_cfs: Vec<ConsensusFrame>,
+    /// Vote certificates of `finalized_cfs`
+    pub vote_certificates: Vec<VoteCertificate>,
+    /// The anchor (including its merkle roots), written last as the commit marker
+    pub anchor: Anchor,
+}
+
+/// Backend that persists an [`AnchorCommit`] all-or-nothing
+#[async_trait]
+pub trait AnchorCommitBackend: Send + Sync + Debug {
+    /// Persist the whole commit atomically.
+    ///
+    /// On error nothing has been written. On success returns the number of
+    /// events whose bodies were newly stored (already-stored events only get
+    /// their depth indexes refreshed).
+    async fn commit_anchor(&self, commit: AnchorCommit) -> SetuResult<usize>;
+}
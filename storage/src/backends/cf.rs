@@ -4,7 +4,7 @@
 //! persistent (RocksDBCFStore) implementations at runtime.
 
 use async_trait::async_trait;
-use setu_types::{ConsensusFrame, CFId, SetuResult};
+use setu_types::{AnchorId, ConsensusFrame, CFId, SetuResult, VoteCertificate};
 use std::fmt::Debug;
 
 /// Backend trait for CFStore implementations
@@ -26,6 +26,12 @@ pub trait CFStoreBackend: Send + Sync + Debug {
     /// Mark a pending CF as finalized
     async fn mark_finalized(&self, cf_id: &CFId) -> SetuResult<()>;
 
+    /// Store the vote certificate of a finalized CF, keyed by its anchor
+    async fn store_vote_certificate(&self, cert: VoteCertificate) -> SetuResult<()>;
+
+    /// Get the vote certificate of the CF that finalized an anchor
+    async fn get_vote_certificate(&self, anchor_id: &AnchorId) -> Option<VoteCertificate>;
+
     // =========================================================================
     // Query operations
     // =========================================================================
@@ -44,6 +50,11 @@ pub trait CFStoreBackend: Send + Sync + Debug {
 
     /// Count pending CFs
     async fn pending_count(&self) -> usize;
+
+    /// Flush buffered writes to disk (no-op for in-memory stores)
+    async fn flush(&self) -> SetuResult<()> {
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -66,6 +77,14 @@ impl CFStoreBackend for CFStore {
         CFStore::mark_finalized(self, cf_id).await
     }
 
+    async fn store_vote_certificate(&self, cert: VoteCertificate) -> SetuResult<()> {
+        CFStore::store_vote_certificate(self, cert).await
+    }
+
+    async fn get_vote_certificate(&self, anchor_id: &AnchorId) -> Option<VoteCertificate> {
+        CFStore::get_vote_certificate(self, anchor_id).await
+    }
+
     async fn get_pending(&self) -> Vec<ConsensusFrame> {
         CFStore::get_pending(self).await
     }
@@ -107,6 +126,14 @@ impl CFStoreBackend for RocksDBCFStore {
         RocksDBCFStore::mark_finalized(self, cf_id).await
     }
 
+    async fn store_vote_certificate(&self, cert: VoteCertificate) -> SetuResult<()> {
+        RocksDBCFStore::store_vote_certificate(self, cert).await
+    }
+
+    async fn get_vote_certificate(&self, anchor_id: &AnchorId) -> Option<VoteCertificate> {
+        RocksDBCFStore::get_vote_certificate(self, anchor_id).await
+    }
+
     async fn get_pending(&self) -> Vec<ConsensusFrame> {
         RocksDBCFStore::get_pending(self).await
     }
@@ -126,4 +153,8 @@ impl CFStoreBackend for RocksDBCFStore {
     async fn pending_count(&self) -> usize {
         RocksDBCFStore::pending_count(self).await
     }
+
+    async fn flush(&self) -> SetuResult<()> {
+        RocksDBCFStore::flush(self).await
+    }
 }
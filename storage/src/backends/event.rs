@@ -69,6 +69,29 @@ pub trait EventStoreBackend: Send + Sync + Debug {
     /// Get events by creator
     async fn get_by_creator(&self, creator: &str) -> Vec<Event>;
 
+    /// Get the event carrying the given transfer, if it has been stored
+    async fn get_by_transfer_id(&self, transfer_id: &str) -> Option<Event>;
+
+    /// Get up to `limit` events with `from_ts <= timestamp <= to_ts` (ms),
+    /// ordered by `(timestamp, event_id)`
+    ///
+    /// With `after`, events at `from_ts` whose id sorts at or before it are
+    /// skipped, so `(from_ts, after)` resumes a listing exactly after its last
+    /// returned event even when several events share that timestamp.
+    async fn get_events_in_range(
+        &self,
+        from_ts: u64,
+        to_ts: u64,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Vec<Event>;
+
+    /// Number of stored events skipped by reads because they failed to decode
+    /// (always 0 for in-memory stores)
+    fn decode_errors(&self) -> u64 {
+        0
+    }
+
     /// Get events by status
     async fn get_by_status(&self, status: EventStatus) -> Vec<Event>;
 
@@ -105,6 +128,11 @@ pub trait EventStoreBackend: Send + Sync + Debug {
         // Default: not supported
         None
     }
+
+    /// Flush buffered writes to disk (no-op for in-memory stores)
+    async fn flush(&self) -> SetuResult<()> {
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -166,6 +194,20 @@ impl EventStoreBackend for EventStore {
         EventStore::get_by_creator(self, creator).await
     }
 
+    async fn get_by_transfer_id(&self, transfer_id: &str) -> Option<Event> {
+        EventStore::get_by_transfer_id(self, transfer_id).await
+    }
+
+    async fn get_events_in_range(
+        &self,
+        from_ts: u64,
+        to_ts: u64,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Vec<Event> {
+        EventStore::get_events_in_range(self, from_ts, to_ts, after, limit).await
+    }
+
     async fn get_by_status(&self, status: EventStatus) -> Vec<Event> {
         EventStore::get_by_status(self, status).await
     }
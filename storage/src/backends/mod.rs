@@ -5,10 +5,12 @@
 
 pub mod event;
 pub mod anchor;
+pub mod anchor_commit;
 pub mod cf;
 pub mod object;
 
 pub use event::EventStoreBackend;
 pub use anchor::AnchorStoreBackend;
+pub use anchor_commit::{AnchorCommit, AnchorCommitBackend};
 pub use cf::CFStoreBackend;
 pub use object::ObjectStore;
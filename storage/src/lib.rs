@@ -34,19 +34,20 @@ pub use types::*;
 
 // Backend traits
 pub use backends::{EventStoreBackend, AnchorStoreBackend, CFStoreBackend, ObjectStore};
+pub use backends::{AnchorCommit, AnchorCommitBackend};
 
 // Memory implementations
 pub use memory::{EventStore, AnchorStore, CFStore, MemoryObjectStore};
 
 // RocksDB types and implementations
-pub use rocks::{SetuDB, RocksDBConfig, ColumnFamily, StorageError};
-pub use rocks::{RocksDBEventStore, RocksDBAnchorStore, RocksDBCFStore};
-pub use rocks::{RocksObjectStore, RebuildIndexResult, RocksDBMerkleStore};
+pub use rocks::{SetuDB, RocksDBConfig, WalSyncMode, ValueFormat, ColumnFamily, StorageError};
+pub use rocks::{RocksDBEventStore, RocksDBAnchorStore, RocksDBCFStore, RocksDBAnchorCommitter};
+pub use rocks::{MerkleStoreStats, RocksObjectStore, RebuildIndexResult, RocksDBMerkleStore};
 
 // State management
-pub use state::{SubnetStateSMT, GlobalStateManager, StateApplySummary, StateApplyError, RecoverySummary, ConflictRecord};
+pub use state::{SubnetStateSMT, GlobalStateManager, StateApplySummary, StateApplyError, RecoverySummary, ConflictRecord, SubnetEventCounter, SubnetExport, DEFAULT_MAX_OBJECT_BYTES};
 pub use state::{B4StoreExt}; // B4 scheme combined storage trait (extended from setu_merkle::B4Store)
-pub use state::{StateProvider, MerkleStateProvider, CoinInfo, CoinState, SimpleMerkleProof};
+pub use state::{StateProvider, MerkleStateProvider, CoinInfo, CoinState, SimpleMerkleProof, SubnetStats, SubnetThroughput, ProvenObject, AddressStateDump};
 pub use state::{init_coin, init_coins_split, get_coin_state};
 pub use state::{BatchStateSnapshot, BatchSnapshotStats};
 pub use state::SharedStateManager;
@@ -54,6 +55,8 @@ pub use state::{ObjKey, WaitGuard, WatcherCaps, WatcherError, WatcherRegistry};
 
 // Re-export MerkleStore trait from setu-merkle for convenience
 pub use setu_merkle::storage::{MerkleStore, MerkleLeafStore, MerkleMetaStore, B4Store};
+// Disk-free MerkleStore backend (tests, memory-mode nodes)
+pub use setu_merkle::storage::InMemoryMerkleStore;
 
 // ============================================================================
 // Backward compatibility: module path aliases
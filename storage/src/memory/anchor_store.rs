@@ -1,5 +1,5 @@
 use dashmap::DashMap;
-use setu_types::{Anchor, AnchorId, SetuResult};
+use setu_types::{Anchor, AnchorId, EventId, SetuResult};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -13,6 +13,8 @@ pub struct AnchorStore {
     anchors: Arc<DashMap<AnchorId, Anchor>>,
     /// Chain maintains insertion order, requires RwLock for ordered access
     chain: Arc<RwLock<Vec<AnchorId>>>,
+    /// event_id -> ID of the anchor that finalized it
+    event_anchors: Arc<DashMap<EventId, AnchorId>>,
 }
 
 impl AnchorStore {
@@ -20,12 +22,17 @@ impl AnchorStore {
         Self {
             anchors: Arc::new(DashMap::new()),
             chain: Arc::new(RwLock::new(Vec::new())),
+            event_anchors: Arc::new(DashMap::new()),
         }
     }
 
     pub async fn store(&self, anchor: Anchor) -> SetuResult<()> {
         let anchor_id = anchor.id.clone();
 
+        for event_id in &anchor.event_ids {
+            self.event_anchors.insert(event_id.clone(), anchor_id.clone());
+        }
+
         // Insert into DashMap (lock-free)
         self.anchors.insert(anchor_id.clone(), anchor);
 
@@ -62,6 +69,11 @@ impl AnchorStore {
     pub async fn get_chain(&self) -> Vec<AnchorId> {
         self.chain.read().await.clone()
     }
+
+    /// Get the ID of the anchor that finalized `event_id`
+    pub async fn get_anchor_id_for_event(&self, event_id: &str) -> Option<AnchorId> {
+        self.event_anchors.get(event_id).map(|r| r.value().clone())
+    }
     
     /// Get the latest anchor_chain_root for recovery after restart
     /// Returns (anchor_chain_root, depth, total_count, last_fold_vlc)
@@ -122,6 +134,7 @@ impl Clone for AnchorStore {
         Self {
             anchors: Arc::clone(&self.anchors),
             chain: Arc::clone(&self.chain),
+            event_anchors: Arc::clone(&self.event_anchors),
         }
     }
 }
@@ -4,7 +4,7 @@
 //! supporting pending and finalized frame tracking with concurrent access.
 
 use dashmap::DashMap;
-use setu_types::{CFId, CFStatus, ConsensusFrame, SetuError, SetuResult};
+use setu_types::{AnchorId, CFId, CFStatus, ConsensusFrame, SetuError, SetuResult, VoteCertificate};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -16,11 +16,13 @@ use tokio::sync::RwLock;
 /// - `frames`: DashMap storing all consensus frames by ID (lock-free)
 /// - `pending`: Vector of pending (not yet finalized) frame IDs (ordered)
 /// - `finalized`: Vector of finalized frame IDs (ordered)
+/// - `certificates`: DashMap of vote certificates by anchor ID
 #[derive(Debug)]
 pub struct CFStore {
     frames: Arc<DashMap<CFId, ConsensusFrame>>,
     pending: Arc<RwLock<Vec<CFId>>>,
     finalized: Arc<RwLock<Vec<CFId>>>,
+    certificates: Arc<DashMap<AnchorId, VoteCertificate>>,
 }
 
 impl CFStore {
@@ -30,6 +32,7 @@ impl CFStore {
             frames: Arc::new(DashMap::new()),
             pending: Arc::new(RwLock::new(Vec::new())),
             finalized: Arc::new(RwLock::new(Vec::new())),
+            certificates: Arc::new(DashMap::new()),
         }
     }
 
@@ -85,6 +88,17 @@ impl CFStore {
         Ok(())
     }
 
+    /// Store the vote certificate of a finalized CF, keyed by its anchor
+    pub async fn store_vote_certificate(&self, cert: VoteCertificate) -> SetuResult<()> {
+        self.certificates.insert(cert.anchor_id.clone(), cert);
+        Ok(())
+    }
+
+    /// Get the vote certificate of the CF that finalized an anchor
+    pub async fn get_vote_certificate(&self, anchor_id: &AnchorId) -> Option<VoteCertificate> {
+        self.certificates.get(anchor_id).map(|r| r.value().clone())
+    }
+
     /// Get all pending consensus frames
     pub async fn get_pending(&self) -> Vec<ConsensusFrame> {
         let pending = self.pending.read().await;
@@ -130,6 +144,7 @@ impl Clone for CFStore {
             frames: Arc::clone(&self.frames),
             pending: Arc::clone(&self.pending),
             finalized: Arc::clone(&self.finalized),
+            certificates: Arc::clone(&self.certificates),
         }
     }
 }
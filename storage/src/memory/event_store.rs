@@ -20,12 +20,14 @@ use std::sync::Arc;
 /// - `events`: Primary storage (EventId -> Event)
 /// - `by_creator`: Creator index (Creator -> Vec<EventId>)
 /// - `by_status`: Status index (EventStatus -> Vec<EventId>)
+/// - `by_transfer`: Transfer index (TransferId -> EventId)
 /// - `depths`: Depth index (EventId -> u64)
 #[derive(Debug)]
 pub struct EventStore {
     events: Arc<DashMap<EventId, Event>>,
     by_creator: Arc<DashMap<String, Vec<EventId>>>,
     by_status: Arc<DashMap<EventStatus, Vec<EventId>>>,
+    by_transfer: Arc<DashMap<String, EventId>>,
     /// Depth index table - stores event depths separately from Event struct
     /// Design note: depth is a DAG topological property, not an intrinsic event property
     depths: Arc<DashMap<EventId, u64>>,
@@ -38,6 +40,7 @@ impl EventStore {
             events: Arc::new(DashMap::new()),
             by_creator: Arc::new(DashMap::new()),
             by_status: Arc::new(DashMap::new()),
+            by_transfer: Arc::new(DashMap::new()),
             depths: Arc::new(DashMap::new()),
         }
     }
@@ -49,6 +52,7 @@ impl EventStore {
         let status = event.status;
 
         // Insert into main store
+        self.index_transfer(&event);
         self.events.insert(event_id.clone(), event);
 
         // Update creator index
@@ -66,6 +70,12 @@ impl EventStore {
         Ok(())
     }
 
+    fn index_transfer(&self, event: &Event) {
+        if let Some(transfer) = &event.transfer {
+            self.by_transfer.insert(transfer.id.clone(), event.id.clone());
+        }
+    }
+
     /// Get an event by ID
     pub async fn get(&self, event_id: &EventId) -> Option<Event> {
         self.events.get(event_id).map(|r| r.value().clone())
@@ -117,6 +127,37 @@ impl EventStore {
             .unwrap_or_default()
     }
 
+    /// Get the event carrying a transfer
+    pub async fn get_by_transfer_id(&self, transfer_id: &str) -> Option<Event> {
+        let event_id = self.by_transfer.get(transfer_id)?.value().clone();
+        self.get(&event_id).await
+    }
+
+    /// Get up to `limit` events with `from_ts <= timestamp <= to_ts`, oldest
+    /// first, resuming after `(from_ts, after)` when a cursor id is given
+    pub async fn get_events_in_range(
+        &self,
+        from_ts: u64,
+        to_ts: u64,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Vec<Event> {
+        let mut events: Vec<Event> = self
+            .events
+            .iter()
+            .filter(|r| (from_ts..=to_ts).contains(&r.value().timestamp))
+            .filter(|r| match after {
+                Some(after) => r.value().timestamp > from_ts || r.value().id.as_str() > after,
+                None => true,
+            })
+            .map(|r| r.value().clone())
+            .collect();
+        // Same (timestamp, id) order as the RocksDB time index
+        events.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then_with(|| a.id.cmp(&b.id)));
+        events.truncate(limit);
+        events
+    }
+
     /// Get events by status
     pub async fn get_by_status(&self, status: EventStatus) -> Vec<Event> {
         self.by_status
@@ -215,6 +256,7 @@ impl EventStore {
         let status = event.status;
 
         // Store event
+        self.index_transfer(&event);
         self.events.insert(event_id.clone(), event);
 
         // Update creator index
@@ -273,6 +315,7 @@ impl EventStore {
             let creator = event.creator.clone();
             let status = event.status;
 
+            self.index_transfer(&event);
             self.events.insert(event_id.clone(), event);
 
             self.by_creator
@@ -309,6 +352,7 @@ impl Clone for EventStore {
             events: Arc::clone(&self.events),
             by_creator: Arc::clone(&self.by_creator),
             by_status: Arc::clone(&self.by_status),
+            by_transfer: Arc::clone(&self.by_transfer),
             depths: Arc::clone(&self.depths),
         }
     }
@@ -363,6 +407,57 @@ mod tests {
         assert_eq!(node1_events.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_get_by_transfer_id() {
+        let store = EventStore::new();
+        let event = create_event("node1")
+            .with_transfer(setu_types::Transfer::new("tx-1", "alice", "bob", 5));
+        let event_id = event.id.clone();
+
+        store.store_batch_with_depth(vec![(event, 3)]).await;
+        store.store(create_event("node1")).await.unwrap();
+
+        assert_eq!(store.get_by_transfer_id("tx-1").await.unwrap().id, event_id);
+        assert!(store.get_by_transfer_id("tx-2").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_events_in_range() {
+        let store = EventStore::new();
+        for timestamp in [300, 50, 200, 100, 500] {
+            let mut event = create_event(&format!("node-{}", timestamp));
+            event.timestamp = timestamp;
+            store.store(event).await.unwrap();
+        }
+
+        let in_window: Vec<u64> = store
+            .get_events_in_range(100, 300, None, 10)
+            .await
+            .iter()
+            .map(|e| e.timestamp)
+            .collect();
+        assert_eq!(in_window, vec![100, 200, 300]);
+        assert_eq!(store.get_events_in_range(100, 300, None, 1).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_events_in_range_pages_through_equal_timestamps() {
+        let store = EventStore::new();
+        for creator in ["node-a", "node-b", "node-c"] {
+            let mut event = create_event(creator);
+            event.timestamp = 100;
+            store.store(event).await.unwrap();
+        }
+
+        let first = store.get_events_in_range(100, 100, None, 2).await;
+        assert_eq!(first.len(), 2);
+        let rest = store
+            .get_events_in_range(100, 100, Some(first[1].id.as_str()), 2)
+            .await;
+        assert_eq!(rest.len(), 1);
+        assert!(first.iter().all(|e| e.id != rest[0].id));
+    }
+
     #[tokio::test]
     async fn test_update_status() {
         let store = EventStore::new();
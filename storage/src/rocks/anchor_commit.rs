@@ -0,0 +1,323 @@
+//! Atomic anchor persistence for RocksDB
+//!
+//! `RocksDBAnchorCommitter` stages the events, finalized CF index entries and
+//! anchor of one finalization into a single `WriteBatch` and writes it once.
+//! Given the node's state manager, it also stages the anchor's deferred B4
+//! merkle writes (leaves, subnet roots, global root) into that batch. Either
+//! every key lands or none does.
+//!
+//! The committer must share its `RocksDBCFStore` with the validator's CF
+//! backend: CF index sequence numbers are assigned in memory by that store.
+
+use crate::backends::{AnchorCommit, AnchorCommitBackend};
+use crate::rocks::core::{spawn_db_op, SetuDB};
+use crate::rocks::{RocksDBAnchorStore, RocksDBCFStore, RocksDBEventStore};
+use crate::state::SharedStateManager;
+use async_trait::async_trait;
+use rocksdb::WriteBatch;
+use setu_types::{SetuError, SetuResult};
+use std::sync::Arc;
+use tracing::debug;
+
+/// Commits a finalized anchor and everything it references in one WriteBatch
+#[derive(Clone)]
+pub struct RocksDBAnchorCommitter {
+    db: Arc<SetuDB>,
+    event_store: RocksDBEventStore,
+    cf_store: Arc<RocksDBCFStore>,
+    anchor_store: RocksDBAnchorStore,
+    /// State manager whose B4 writes are deferred to this committer
+    state: Option<Arc<SharedStateManager>>,
+}
+
+impl RocksDBAnchorCommitter {
+    /// Create a committer over `db`, sharing the validator's CF store
+    pub fn new(db: Arc<SetuDB>, cf_store: Arc<RocksDBCFStore>) -> Self {
+        Self {
+            event_store: RocksDBEventStore::from_shared(db.clone()),
+            anchor_store: RocksDBAnchorStore::from_shared(db.clone()),
+            db,
+            cf_store,
+            state: None,
+        }
+    }
+
+    /// Persist the B4 merkle writes of `state` with each anchor.
+    ///
+    /// Switches `state` to deferred B4 commits, so its merkle store must be
+    /// backed by the same `db`. Every finalized anchor must then go through
+    /// this committer, or its merkle writes only land with a later anchor.
+    pub fn with_state_manager(mut self, state: Arc<SharedStateManager>) -> Self {
+        state.lock_write().defer_b4_commits();
+        self.state = Some(state);
+        self
+    }
+
+    /// Stage and write `commit` on the calling thread
+    fn commit_blocking(&self, commit: &AnchorCommit) -> SetuResult<usize> {
+        // Merkle writes first: the state manager stages them into a B4 batch
+        let mut b4_batch: Box<dyn std::any::Any + Send> = Box::new(self.db.batch());
+        let merkle = match &self.state {
+            Some(state) => state
+                .lock_write()
+                .stage_deferred_b4(&mut b4_batch, &commit.anchor.id)
+                .map_err(|e| SetuError::StorageError(e.to_string()))?,
+            None => Vec::new(),
+        };
+        let batch = *b4_batch
+            .downcast::<WriteBatch>()
+            .expect("committer B4 batch is a WriteBatch");
+        let merkle_commits = merkle.len();
+
+        match self.write_with(batch, commit) {
+            Ok(stored) => {
+                debug!(
+                    anchor_id = %commit.anchor.id,
+                    events = commit.events.len(),
+                    events_stored = stored,
+                    cfs = commit.finalized_cfs.len(),
+                    merkle_commits,
+                    "Committed anchor atomically"
+                );
+                Ok(stored)
+            }
+            Err(e) => {
+                // Nothing was written: queue the merkle writes for the retry
+                if let Some(state) = &self.state {
+                    state.lock_write().restore_deferred_b4(merkle);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Stage events, the anchor, the CF index and the CFs' vote certificates
+    /// into `batch` and write it
+    fn write_with(&self, mut batch: WriteBatch, commit: &AnchorCommit) -> SetuResult<usize> {
+        let mut stored = 0;
+        for (event, depth) in &commit.events {
+            if self.event_store.stage_with_depth(&mut batch, event, *depth)? {
+                stored += 1;
+            }
+        }
+        self.anchor_store.stage(&mut batch, &commit.anchor)?;
+
+        // The CF store stages its index entries last and writes the batch, so
+        // sequence numbers are only assigned to a batch that lands.
+        self.cf_store
+            .commit_with_finalized(batch, &commit.finalized_cfs, &commit.vote_certificates)?;
+        Ok(stored)
+    }
+}
+
+impl std::fmt::Debug for RocksDBAnchorCommitter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RocksDBAnchorCommitter").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl AnchorCommitBackend for RocksDBAnchorCommitter {
+    async fn commit_anchor(&self, commit: AnchorCommit) -> SetuResult<usize> {
+        let this = self.clone();
+        spawn_db_op(move || this.commit_blocking(&commit)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rocks::{ColumnFamily, RocksDBMerkleStore};
+    use crate::state::{B4StoreExt, GlobalStateManager};
+    use setu_merkle::storage::{MerkleLeafStore, MerkleRootStore};
+    use setu_merkle::HashValue;
+    use setu_types::{Anchor, ConsensusFrame, Event, EventType, SubnetId, VLCSnapshot, Vote};
+
+    fn setup() -> (RocksDBAnchorCommitter, Arc<RocksDBCFStore>, tempfile::TempDir) {
+        let temp_dir = tempfile::tempdir().expect("temp dir must be created");
+        let db = Arc::new(SetuDB::open_default(temp_dir.path()).expect("test db must open"));
+        let cf_store = Arc::new(RocksDBCFStore::from_shared(db.clone()));
+        (RocksDBAnchorCommitter::new(db, cf_store.clone()), cf_store, temp_dir)
+    }
+
+    fn setup_with_state() -> (
+        RocksDBAnchorCommitter,
+        Arc<SharedStateManager>,
+        RocksDBMerkleStore,
+        tempfile::TempDir,
+    ) {
+        let temp_dir = tempfile::tempdir().expect("temp dir must be created");
+        let db = Arc::new(SetuDB::open_default(temp_dir.path()).expect("test db must open"));
+        let merkle_store: Arc<dyn B4StoreExt> = Arc::new(RocksDBMerkleStore::from_shared(db.clone()));
+        let state = Arc::new(SharedStateManager::new(GlobalStateManager::with_store(merkle_store)));
+        let cf_store = Arc::new(RocksDBCFStore::from_shared(db.clone()));
+        let committer = RocksDBAnchorCommitter::new(db.clone(), cf_store)
+            .with_state_manager(state.clone());
+        (committer, state, RocksDBMerkleStore::from_shared(db), temp_dir)
+    }
+
+    fn corrupt_stored_cf(committer: &RocksDBAnchorCommitter, cf_id: &str) {
+        let cf_handle = committer.db.inner()
+            .cf_handle(ColumnFamily::ConsensusFrames.name())
+            .expect("column family must exist");
+        committer.db.inner()
+            .put_cf(cf_handle, RocksDBCFStore::cf_key(&cf_id.to_string()), b"not-a-cf")
+            .expect("raw put must succeed");
+    }
+
+    fn test_commit(tag: &str) -> AnchorCommit {
+        let events: Vec<(Event, u64)> = (0..3)
+            .map(|i| {
+                let event = Event::new(
+                    EventType::System,
+                    Vec::new(),
+                    VLCSnapshot::new(),
+                    format!("{}-creator-{}", tag, i),
+                );
+                (event, i)
+            })
+            .collect();
+        let anchor = Anchor::new(
+            events.iter().map(|(e, _)| e.id.clone()).collect(),
+            VLCSnapshot::default(),
+            format!("{}-root", tag),
+            None,
+            1,
+        );
+        let mut cf = ConsensusFrame::new(anchor.clone(), "v1".to_string());
+        cf.add_vote(Vote::new("v1".to_string(), cf.id.clone(), true));
+        cf.finalize();
+        let certificate = cf.vote_certificate(|_| 1, 1);
+        AnchorCommit {
+            events,
+            finalized_cfs: vec![cf],
+            vote_certificates: vec![certificate],
+            anchor,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_commit_anchor_persists_everything() {
+        let (committer, cf_store, _dir) = setup();
+        let commit = test_commit("ok");
+
+        let stored = committer.commit_anchor(commit.clone()).await.unwrap();
+        assert_eq!(stored, 3);
+
+        for (event, depth) in &commit.events {
+            assert!(committer.event_store.exists(&event.id).await);
+            assert_eq!(committer.event_store.get_depth(&event.id).await, Some(*depth));
+        }
+        assert_eq!(cf_store.finalized_count().await, 1);
+        assert_eq!(
+            cf_store.get_vote_certificate(&commit.anchor.id).await.as_ref(),
+            commit.vote_certificates.first()
+        );
+        assert_eq!(committer.anchor_store.count().await, 1);
+        assert_eq!(committer.anchor_store.get_latest().await.unwrap().id, commit.anchor.id);
+    }
+
+    #[tokio::test]
+    async fn test_vote_certificate_survives_reopen() {
+        let (committer, cf_store, dir) = setup();
+        let commit = test_commit("reopen");
+        committer.commit_anchor(commit.clone()).await.unwrap();
+        drop((committer, cf_store));
+
+        let db = SetuDB::open_default(dir.path()).expect("test db must reopen");
+        let cf_store = RocksDBCFStore::new(db);
+        assert_eq!(
+            cf_store.get_vote_certificate(&commit.anchor.id).await.as_ref(),
+            commit.vote_certificates.first()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_commit_anchor_failure_mid_batch_writes_nothing() {
+        let (committer, cf_store, _dir) = setup();
+        let commit = test_commit("fail");
+
+        // Corrupt the stored CF so staging fails after the events were staged
+        corrupt_stored_cf(&committer, &commit.finalized_cfs[0].id);
+
+        assert!(committer.commit_anchor(commit.clone()).await.is_err());
+
+        for (event, _) in &commit.events {
+            assert!(!committer.event_store.exists(&event.id).await);
+            assert!(committer.event_store.get_depth(&event.id).await.is_none());
+        }
+        assert_eq!(cf_store.finalized_count().await, 0);
+        assert!(cf_store.get_vote_certificate(&commit.anchor.id).await.is_none());
+        assert_eq!(committer.anchor_store.count().await, 0);
+        assert!(committer.anchor_store.get(&commit.anchor.id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_commit_anchor_writes_deferred_merkle_state() {
+        let (committer, state, merkle, _dir) = setup_with_state();
+        let commit = test_commit("merkle");
+        let object_id = [7u8; 32];
+
+        {
+            let mut gsm = state.lock_write();
+            gsm.upsert_object(SubnetId::ROOT, object_id, b"value".to_vec()).unwrap();
+            gsm.commit_anchor(1, &commit.anchor.id).unwrap();
+            assert_eq!(gsm.deferred_b4_count(), 1);
+        }
+        // Deferred: nothing reaches the merkle store before the anchor commit
+        let subnet = SubnetId::ROOT;
+        assert!(merkle.get_leaf(subnet.as_bytes(), &HashValue::new(object_id)).unwrap().is_none());
+        assert!(merkle.get_global_root(1).unwrap().is_none());
+
+        committer.commit_anchor(commit.clone()).await.unwrap();
+
+        assert_eq!(
+            merkle.get_leaf(subnet.as_bytes(), &HashValue::new(object_id)).unwrap(),
+            Some(b"value".to_vec())
+        );
+        assert!(merkle.get_global_root(1).unwrap().is_some());
+        assert_eq!(state.lock_write().deferred_b4_count(), 0);
+        assert_eq!(committer.anchor_store.count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_commit_keeps_merkle_state_and_cf_sequence() {
+        let (committer, state, merkle, _dir) = setup_with_state();
+        let commit = test_commit("retry");
+        let object_id = [9u8; 32];
+        {
+            let mut gsm = state.lock_write();
+            gsm.upsert_object(SubnetId::ROOT, object_id, b"value".to_vec()).unwrap();
+            gsm.commit_anchor(1, &commit.anchor.id).unwrap();
+        }
+
+        corrupt_stored_cf(&committer, &commit.finalized_cfs[0].id);
+        assert!(committer.commit_anchor(commit.clone()).await.is_err());
+
+        // Neither the merkle writes nor a CF sequence number were consumed
+        let subnet = SubnetId::ROOT;
+        assert!(merkle.get_leaf(subnet.as_bytes(), &HashValue::new(object_id)).unwrap().is_none());
+        assert!(merkle.get_global_root(1).unwrap().is_none());
+        assert_eq!(state.lock_write().deferred_b4_count(), 1);
+        let finalized_seq = |db: &SetuDB| {
+            db.get_raw::<u64>(ColumnFamily::ConsensusFrames, b"meta:finalized_seq").unwrap()
+        };
+        assert_eq!(finalized_seq(&committer.db), None);
+
+        // Once the CF is readable again the retry lands everything at seq 0
+        let cf_handle = committer.db.inner()
+            .cf_handle(ColumnFamily::ConsensusFrames.name())
+            .expect("column family must exist");
+        committer.db.inner()
+            .delete_cf(cf_handle, RocksDBCFStore::cf_key(&commit.finalized_cfs[0].id))
+            .expect("raw delete must succeed");
+        committer.commit_anchor(commit.clone()).await.unwrap();
+
+        assert_eq!(finalized_seq(&committer.db), Some(1));
+        assert_eq!(
+            merkle.get_leaf(subnet.as_bytes(), &HashValue::new(object_id)).unwrap(),
+            Some(b"value".to_vec())
+        );
+        assert_eq!(state.lock_write().deferred_b4_count(), 0);
+    }
+}
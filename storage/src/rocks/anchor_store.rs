@@ -8,8 +8,12 @@
 //! 1. **Chain Ordering**: Uses `chain:{index}` keys for ordered chain storage
 //! 2. **Depth Index**: Uses `depth:{depth}` keys for depth-based lookups
 //! 3. **Latest Tracking**: Uses `meta:latest` for quick latest anchor access
-//! 4. **Atomic Batch Writes**: Uses WriteBatch for atomic store operations
-//! 5. **Async-safe I/O**: Uses `spawn_blocking` for disk operations
+//! 4. **Event Index**: Uses `event:{event_id}` keys to find the anchor that
+//!    finalized an event without scanning the chain
+//! 5. **Atomic Batch Writes**: The anchor, its `chain:{index}` and `event:`
+//!    entries and the `meta:count` bump go into one WriteBatch, so readers
+//!    never see a count that disagrees with the chain index
+//! 6. **Async-safe I/O**: Uses `spawn_blocking` for disk operations
 //!
 //! ## Column Family Layout
 //!
@@ -17,10 +21,12 @@
 //! - `anchor:{anchor_id}` -> Anchor (main anchor data)
 //! - `chain:{index}` -> AnchorId (ordered chain index, 0-based)
 //! - `depth:{depth}` -> AnchorId (depth lookup index)
+//! - `event:{event_id}` -> AnchorId (finalizing anchor of each event)
 //! - `meta:latest` -> AnchorId (latest anchor ID)
 //! - `meta:count` -> u64 (total anchor count)
 
 use crate::rocks::core::{SetuDB, ColumnFamily, spawn_db_op};
+use rocksdb::WriteBatch;
 use setu_types::{Anchor, AnchorId, SetuResult, SetuError};
 use std::sync::Arc;
 use tracing::{debug, warn};
@@ -30,6 +36,7 @@ mod key_prefix {
     pub const ANCHOR: &[u8] = b"anchor:";
     pub const CHAIN: &[u8] = b"chain:";
     pub const DEPTH: &[u8] = b"depth:";
+    pub const EVENT: &[u8] = b"event:";
 }
 
 /// Metadata keys
@@ -41,17 +48,29 @@ mod meta_key {
 /// RocksDB-backed AnchorStore implementation
 pub struct RocksDBAnchorStore {
     db: Arc<SetuDB>,
+    /// Test seam: fail staging after the anchor key, before the chain entry.
+    #[cfg(test)]
+    forced_stage_failure: Arc<std::sync::atomic::AtomicBool>,
 }
 
 impl RocksDBAnchorStore {
     /// Create a new RocksDBAnchorStore with an owned SetuDB
     pub fn new(db: SetuDB) -> Self {
-        Self { db: Arc::new(db) }
+        Self::from_shared(Arc::new(db))
     }
     
     /// Create from a shared SetuDB instance
     pub fn from_shared(db: Arc<SetuDB>) -> Self {
-        Self { db }
+        Self {
+            db,
+            #[cfg(test)]
+            forced_stage_failure: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+        }
+    }
+
+    #[cfg(test)]
+    fn force_next_stage_failure(&self) {
+        self.forced_stage_failure.store(true, std::sync::atomic::Ordering::SeqCst);
     }
     
     /// Get the underlying database reference
@@ -84,6 +103,13 @@ impl RocksDBAnchorStore {
         key
     }
 
+    fn event_key(event_id: &str) -> Vec<u8> {
+        let mut key = Vec::with_capacity(key_prefix::EVENT.len() + event_id.len());
+        key.extend_from_slice(key_prefix::EVENT);
+        key.extend_from_slice(event_id.as_bytes());
+        key
+    }
+
     // =========================================================================
     // Core Storage Operations
     // =========================================================================
@@ -92,49 +118,76 @@ impl RocksDBAnchorStore {
     /// 
     /// Uses spawn_blocking for the disk write to avoid blocking the async runtime.
     pub async fn store(&self, anchor: Anchor) -> SetuResult<()> {
-        let db = self.db.clone();
-        let anchor_id = anchor.id.clone();
-        let depth = anchor.depth;
-        
-        // Get current count for chain index
-        let count = self.count().await as u64;
+        let this = self.clone();
         
         // Perform the blocking batch write on the blocking thread pool
         spawn_db_op(move || {
-            let mut batch = db.batch();
-            
-            // Store anchor
-            let anchor_key = Self::anchor_key(&anchor_id);
-            db.batch_put_raw(&mut batch, ColumnFamily::Anchors, &anchor_key, &anchor)
+            let mut batch = this.db.batch();
+            let index = this.stage(&mut batch, &anchor)?;
+            this.db.write_batch(batch)
                 .map_err(|e| SetuError::StorageError(e.to_string()))?;
             
-            // Store chain index
-            let chain_key = Self::chain_key(count);
-            db.batch_put_raw(&mut batch, ColumnFamily::Anchors, &chain_key, &anchor_id)
-                .map_err(|e| SetuError::StorageError(e.to_string()))?;
-            
-            // Store depth index
-            let depth_key = Self::depth_key(depth);
-            db.batch_put_raw(&mut batch, ColumnFamily::Anchors, &depth_key, &anchor_id)
-                .map_err(|e| SetuError::StorageError(e.to_string()))?;
-            
-            // Update latest
-            db.batch_put_raw(&mut batch, ColumnFamily::Anchors, meta_key::LATEST, &anchor_id)
-                .map_err(|e| SetuError::StorageError(e.to_string()))?;
-            
-            // Update count
-            let new_count = count + 1;
-            db.batch_put_raw(&mut batch, ColumnFamily::Anchors, meta_key::COUNT, &new_count)
-                .map_err(|e| SetuError::StorageError(e.to_string()))?;
-            
-            db.write_batch(batch)
-                .map_err(|e| SetuError::StorageError(e.to_string()))?;
-            
-            debug!(anchor_id = %anchor_id, depth = depth, index = count, "Persisted anchor to RocksDB");
+            debug!(anchor_id = %anchor.id, depth = anchor.depth, index, "Persisted anchor to RocksDB");
             Ok(())
         }).await
     }
     
+    /// Add the writes of `store(anchor)` to a caller-owned batch without
+    /// committing it, so the anchor lands atomically with other writes.
+    ///
+    /// Returns the chain index the anchor was staged at.
+    pub fn stage(&self, batch: &mut WriteBatch, anchor: &Anchor) -> SetuResult<u64> {
+        let count = self.db
+            .get_raw::<u64>(ColumnFamily::Anchors, meta_key::COUNT)
+            .map_err(|e| SetuError::StorageError(e.to_string()))?
+            .unwrap_or(0);
+        self.stage_at(batch, anchor, count)?;
+        Ok(count)
+    }
+    
+    /// Stage an anchor as chain entry `index`
+    fn stage_at(&self, batch: &mut WriteBatch, anchor: &Anchor, index: u64) -> SetuResult<()> {
+        let db = &self.db;
+        let anchor_id = &anchor.id;
+        
+        // Store anchor
+        let anchor_key = Self::anchor_key(anchor_id);
+        db.batch_put_raw(batch, ColumnFamily::Anchors, &anchor_key, anchor)
+            .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        
+        #[cfg(test)]
+        if self.forced_stage_failure.swap(false, std::sync::atomic::Ordering::SeqCst) {
+            return Err(SetuError::StorageError("forced anchor stage failure".to_string()));
+        }
+        
+        // Store chain index
+        let chain_key = Self::chain_key(index);
+        db.batch_put_raw(batch, ColumnFamily::Anchors, &chain_key, anchor_id)
+            .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        
+        // Store depth index
+        let depth_key = Self::depth_key(anchor.depth);
+        db.batch_put_raw(batch, ColumnFamily::Anchors, &depth_key, anchor_id)
+            .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        
+        // Index the anchor's events
+        for event_id in &anchor.event_ids {
+            db.batch_put_raw(batch, ColumnFamily::Anchors, &Self::event_key(event_id), anchor_id)
+                .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        }
+        
+        // Update latest
+        db.batch_put_raw(batch, ColumnFamily::Anchors, meta_key::LATEST, anchor_id)
+            .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        
+        // Update count
+        let new_count = index + 1;
+        db.batch_put_raw(batch, ColumnFamily::Anchors, meta_key::COUNT, &new_count)
+            .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
     // =========================================================================
     // Query Operations
     // =========================================================================
@@ -177,6 +230,14 @@ impl RocksDBAnchorStore {
         }
     }
     
+    /// Get the ID of the anchor that finalized `event_id`
+    pub async fn get_anchor_id_for_event(&self, event_id: &str) -> Option<AnchorId> {
+        self.db
+            .get_raw(ColumnFamily::Anchors, &Self::event_key(event_id))
+            .ok()
+            .flatten()
+    }
+    
     /// Get total anchor count
     pub async fn count(&self) -> usize {
         self.db
@@ -270,6 +331,8 @@ impl Clone for RocksDBAnchorStore {
     fn clone(&self) -> Self {
         Self {
             db: Arc::clone(&self.db),
+            #[cfg(test)]
+            forced_stage_failure: Arc::clone(&self.forced_stage_failure),
         }
     }
 }
@@ -315,6 +378,10 @@ impl AnchorStoreBackend for RocksDBAnchorStore {
         RocksDBAnchorStore::get_chain(self).await
     }
 
+    async fn get_anchor_id_for_event(&self, event_id: &str) -> Option<AnchorId> {
+        RocksDBAnchorStore::get_anchor_id_for_event(self, event_id).await
+    }
+
     async fn get_recovery_state(&self) -> Option<([u8; 32], u64, u64, u64)> {
         RocksDBAnchorStore::get_recovery_state(self).await
     }
@@ -332,4 +399,103 @@ impl AnchorStoreBackend for RocksDBAnchorStore {
         }
         anchors
     }
+
+    async fn flush(&self) -> SetuResult<()> {
+        let db = self.db.clone();
+        spawn_db_op(move || db.flush().map_err(|e| SetuError::StorageError(e.to_string()))).await
+    }
+
+    async fn probe(&self) -> SetuResult<()> {
+        let db = self.db.clone();
+        spawn_db_op(move || {
+            db.get_raw::<u64>(ColumnFamily::Anchors, meta_key::COUNT)
+                .map(|_| ())
+                .map_err(|e| SetuError::StorageError(e.to_string()))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use setu_types::VLCSnapshot;
+
+    fn setup() -> (RocksDBAnchorStore, tempfile::TempDir) {
+        let temp_dir = tempfile::tempdir().expect("temp dir must be created");
+        let db = SetuDB::open_default(temp_dir.path()).expect("test db must open");
+        (RocksDBAnchorStore::new(db), temp_dir)
+    }
+
+    fn test_anchor(depth: u64) -> Anchor {
+        Anchor::new(
+            vec![format!("event-{}", depth)],
+            VLCSnapshot::default(),
+            format!("root-{}", depth),
+            None,
+            depth,
+        )
+    }
+
+    /// Count of `chain:{index}` keys actually present in the column family
+    fn indexed_entries(store: &RocksDBAnchorStore) -> usize {
+        (0..16u64)
+            .filter(|i| {
+                store.db
+                    .get_raw::<AnchorId>(ColumnFamily::Anchors, &RocksDBAnchorStore::chain_key(*i))
+                    .ok()
+                    .flatten()
+                    .is_some()
+            })
+            .count()
+    }
+
+    #[tokio::test]
+    async fn test_probe_reads_from_disk() {
+        let (store, _dir) = setup();
+        AnchorStoreBackend::probe(&store).await.unwrap();
+
+        // A truncated count record is a read failure the probe must report.
+        store.db.put_raw(ColumnFamily::Anchors, meta_key::COUNT, &0u8).unwrap();
+        assert!(AnchorStoreBackend::probe(&store).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_failure_between_writes_leaves_no_partial_chain_update() {
+        let (store, _dir) = setup();
+        store.store(test_anchor(0)).await.unwrap();
+
+        let failed = test_anchor(1);
+        store.force_next_stage_failure();
+        assert!(store.store(failed.clone()).await.is_err());
+
+        assert!(store.get(&failed.id).await.is_none());
+        assert_eq!(store.count().await, 1);
+        assert_eq!(indexed_entries(&store), 1);
+        assert_eq!(store.get_chain().await.len(), 1);
+
+        let next = test_anchor(2);
+        store.store(next.clone()).await.unwrap();
+
+        assert_eq!(store.count().await, 2);
+        assert_eq!(indexed_entries(&store), 2);
+        assert_eq!(store.get_chain().await.last(), Some(&next.id));
+        assert_eq!(store.get_latest().await.unwrap().id, next.id);
+        assert!(store.get_anchor_id_for_event("event-1").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_event_index_survives_reopen() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let anchor = test_anchor(3);
+        {
+            let store = RocksDBAnchorStore::new(SetuDB::open_default(temp_dir.path()).unwrap());
+            store.store(test_anchor(2)).await.unwrap();
+            store.store(anchor.clone()).await.unwrap();
+        }
+
+        let store = RocksDBAnchorStore::new(SetuDB::open_default(temp_dir.path()).unwrap());
+        assert_eq!(store.get_anchor_id_for_event("event-3").await, Some(anchor.id));
+        assert!(store.get_anchor_id_for_event("event-9").await.is_none());
+    }
 }
@@ -17,11 +17,12 @@
 //! - `finalized:{seq}:{cf_id}` -> () (finalized index, seq is finalization order)
 //! - `meta:pending_seq` -> u64 (next pending sequence number)
 //! - `meta:finalized_seq` -> u64 (next finalized sequence number)
+//! - `cert:{anchor_id}` -> VoteCertificate (certificate of the finalized CF)
 
-use crate::rocks::core::{SetuDB, ColumnFamily};
-use setu_types::{ConsensusFrame, CFId, CFStatus, SetuResult, SetuError};
-use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use crate::rocks::core::{SetuDB, ColumnFamily, spawn_db_op};
+use rocksdb::WriteBatch;
+use setu_types::{AnchorId, ConsensusFrame, CFId, CFStatus, SetuResult, SetuError, VoteCertificate};
+use std::sync::{Arc, Mutex, MutexGuard};
 use tracing::debug;
 
 /// Key prefixes for different data types in ConsensusFrames CF
@@ -31,15 +32,26 @@ mod key_prefix {
     pub const FINALIZED: &[u8] = b"finalized:";
     pub const META_PENDING_SEQ: &[u8] = b"meta:pending_seq";
     pub const META_FINALIZED_SEQ: &[u8] = b"meta:finalized_seq";
+    pub const CERT: &[u8] = b"cert:";
+}
+
+/// Next free positions in the pending and finalized indexes
+#[derive(Debug, Clone, Copy)]
+struct IndexSeqs {
+    /// Counter for pending CF insertion order
+    pending: u64,
+    /// Counter for finalized CF order
+    finalized: u64,
 }
 
 /// RocksDB-backed CFStore implementation
 pub struct RocksDBCFStore {
     db: Arc<SetuDB>,
-    /// Counter for pending CF insertion order
-    pending_seq: AtomicU64,
-    /// Counter for finalized CF order
-    finalized_seq: AtomicU64,
+    /// Index sequence counters, held across each index write.
+    ///
+    /// Writers stage against a copy and publish it only after their batch is
+    /// written, so a failed write never consumes a sequence number.
+    seqs: Arc<Mutex<IndexSeqs>>,
 }
 
 impl RocksDBCFStore {
@@ -64,8 +76,7 @@ impl RocksDBCFStore {
         
         Self {
             db,
-            pending_seq: AtomicU64::new(pending_seq),
-            finalized_seq: AtomicU64::new(finalized_seq),
+            seqs: Arc::new(Mutex::new(IndexSeqs { pending: pending_seq, finalized: finalized_seq })),
         }
     }
     
@@ -73,18 +84,45 @@ impl RocksDBCFStore {
     pub fn db(&self) -> &SetuDB {
         &self.db
     }
+
+    fn lock_seqs(&self) -> MutexGuard<'_, IndexSeqs> {
+        self.seqs.lock().expect("CF index sequence mutex poisoned")
+    }
+
+    /// Write `batch` with the index entries staged by `stage`, advancing the
+    /// sequence counters only if the write succeeds.
+    fn write_indexed<F>(&self, mut batch: WriteBatch, stage: F) -> SetuResult<()>
+    where
+        F: FnOnce(&mut WriteBatch, &mut IndexSeqs) -> SetuResult<()>,
+    {
+        let mut seqs = self.lock_seqs();
+        let mut next = *seqs;
+        stage(&mut batch, &mut next)?;
+        self.db
+            .write_batch(batch)
+            .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        *seqs = next;
+        Ok(())
+    }
     
     // =========================================================================
     // Key Construction Helpers
     // =========================================================================
     
-    fn cf_key(cf_id: &CFId) -> Vec<u8> {
+    pub(crate) fn cf_key(cf_id: &CFId) -> Vec<u8> {
         let mut key = Vec::with_capacity(key_prefix::CF.len() + cf_id.len());
         key.extend_from_slice(key_prefix::CF);
         key.extend_from_slice(cf_id.as_bytes());
         key
     }
     
+    fn cert_key(anchor_id: &AnchorId) -> Vec<u8> {
+        let mut key = Vec::with_capacity(key_prefix::CERT.len() + anchor_id.len());
+        key.extend_from_slice(key_prefix::CERT);
+        key.extend_from_slice(anchor_id.as_bytes());
+        key
+    }
+    
     fn pending_key(seq: u64, cf_id: &CFId) -> Vec<u8> {
         // Format: pending:{seq:016x}:{cf_id}
         let seq_str = format!("{:016x}", seq);
@@ -131,41 +169,44 @@ impl RocksDBCFStore {
 
     /// Store a consensus frame
     pub async fn store(&self, cf: ConsensusFrame) -> SetuResult<()> {
-        let cf_id = cf.id.clone();
-        let is_finalized = cf.status == CFStatus::Finalized;
+        self.write_indexed(self.db.batch(), |batch, seqs| self.stage_store(batch, &cf, seqs))?;
         
-        let mut batch = self.db.batch();
+        debug!(cf_id = %cf.id, finalized = cf.status == CFStatus::Finalized, "Stored CF to RocksDB");
+        Ok(())
+    }
+    
+    /// Stage a new CF and its pending/finalized index entry
+    fn stage_store(&self, batch: &mut WriteBatch, cf: &ConsensusFrame, seqs: &mut IndexSeqs) -> SetuResult<()> {
+        let cf_id = &cf.id;
         
         // Store CF
-        let cf_key = Self::cf_key(&cf_id);
-        self.db.batch_put_raw(&mut batch, ColumnFamily::ConsensusFrames, &cf_key, &cf)
+        let cf_key = Self::cf_key(cf_id);
+        self.db.batch_put_raw(batch, ColumnFamily::ConsensusFrames, &cf_key, cf)
             .map_err(|e| SetuError::StorageError(e.to_string()))?;
         
         // Add to appropriate index
-        if is_finalized {
-            let seq = self.finalized_seq.fetch_add(1, Ordering::SeqCst);
-            let finalized_key = Self::finalized_key(seq, &cf_id);
-            self.db.batch_put_raw(&mut batch, ColumnFamily::ConsensusFrames, &finalized_key, &())
+        if cf.status == CFStatus::Finalized {
+            let seq = seqs.finalized;
+            seqs.finalized += 1;
+            let finalized_key = Self::finalized_key(seq, cf_id);
+            self.db.batch_put_raw(batch, ColumnFamily::ConsensusFrames, &finalized_key, &())
                 .map_err(|e| SetuError::StorageError(e.to_string()))?;
             
             // Update sequence counter
-            self.db.batch_put_raw(&mut batch, ColumnFamily::ConsensusFrames, key_prefix::META_FINALIZED_SEQ, &(seq + 1))
+            self.db.batch_put_raw(batch, ColumnFamily::ConsensusFrames, key_prefix::META_FINALIZED_SEQ, &(seq + 1))
                 .map_err(|e| SetuError::StorageError(e.to_string()))?;
         } else {
-            let seq = self.pending_seq.fetch_add(1, Ordering::SeqCst);
-            let pending_key = Self::pending_key(seq, &cf_id);
-            self.db.batch_put_raw(&mut batch, ColumnFamily::ConsensusFrames, &pending_key, &())
+            let seq = seqs.pending;
+            seqs.pending += 1;
+            let pending_key = Self::pending_key(seq, cf_id);
+            self.db.batch_put_raw(batch, ColumnFamily::ConsensusFrames, &pending_key, &())
                 .map_err(|e| SetuError::StorageError(e.to_string()))?;
             
             // Update sequence counter
-            self.db.batch_put_raw(&mut batch, ColumnFamily::ConsensusFrames, key_prefix::META_PENDING_SEQ, &(seq + 1))
+            self.db.batch_put_raw(batch, ColumnFamily::ConsensusFrames, key_prefix::META_PENDING_SEQ, &(seq + 1))
                 .map_err(|e| SetuError::StorageError(e.to_string()))?;
         }
         
-        self.db.write_batch(batch)
-            .map_err(|e| SetuError::StorageError(e.to_string()))?;
-        
-        debug!(cf_id = %cf_id, finalized = is_finalized, "Stored CF to RocksDB");
         Ok(())
     }
     
@@ -180,7 +221,7 @@ impl RocksDBCFStore {
     /// Mark a pending CF as finalized
     pub async fn mark_finalized(&self, cf_id: &CFId) -> SetuResult<()> {
         // Get current CF
-        let mut cf = match self.get(cf_id).await {
+        let cf = match self.get(cf_id).await {
             Some(cf) => cf,
             None => return Err(SetuError::InvalidData(format!("CF not found: {}", cf_id))),
         };
@@ -189,15 +230,20 @@ impl RocksDBCFStore {
             return Ok(()); // Already finalized
         }
         
+        self.write_indexed(self.db.batch(), |batch, seqs| self.stage_mark_finalized(batch, cf, seqs))
+    }
+    
+    /// Stage the move of a stored pending CF into the finalized index
+    fn stage_mark_finalized(&self, batch: &mut WriteBatch, mut cf: ConsensusFrame, seqs: &mut IndexSeqs) -> SetuResult<()> {
+        let cf_id = cf.id.clone();
+        
         // Update status
         cf.finalize();
         
-        let mut batch = self.db.batch();
-        
         // Update CF
-        let cf_key = Self::cf_key(cf_id);
+        let cf_key = Self::cf_key(&cf_id);
         self.db
-            .batch_put_raw(&mut batch, ColumnFamily::ConsensusFrames, &cf_key, &cf)
+            .batch_put_raw(batch, ColumnFamily::ConsensusFrames, &cf_key, &cf)
             .map_err(|e| SetuError::StorageError(format!("Failed to update CF status: {}", e)))?;
         
         // Remove from pending index (need to find the key)
@@ -208,29 +254,84 @@ impl RocksDBCFStore {
         
         for key in pending_keys {
             if let Some(id) = Self::extract_cf_id_from_index_key(&key, key_prefix::PENDING) {
-                if &id == cf_id {
-                    let _ = self.db.batch_delete_raw(&mut batch, ColumnFamily::ConsensusFrames, &key);
+                if id == cf_id {
+                    let _ = self.db.batch_delete_raw(batch, ColumnFamily::ConsensusFrames, &key);
                     break;
                 }
             }
         }
         
         // Add to finalized index
-        let seq = self.finalized_seq.fetch_add(1, Ordering::SeqCst);
-        let finalized_key = Self::finalized_key(seq, cf_id);
+        let seq = seqs.finalized;
+        seqs.finalized += 1;
+        let finalized_key = Self::finalized_key(seq, &cf_id);
         self.db
-            .batch_put_raw(&mut batch, ColumnFamily::ConsensusFrames, &finalized_key, &())
+            .batch_put_raw(batch, ColumnFamily::ConsensusFrames, &finalized_key, &())
             .map_err(|e| SetuError::StorageError(format!("Failed to update finalized CF index: {}", e)))?;
         
         // Update sequence counter
         self.db
-            .batch_put_raw(&mut batch, ColumnFamily::ConsensusFrames, key_prefix::META_FINALIZED_SEQ, &(seq + 1))
+            .batch_put_raw(batch, ColumnFamily::ConsensusFrames, key_prefix::META_FINALIZED_SEQ, &(seq + 1))
             .map_err(|e| SetuError::StorageError(format!("Failed to update finalized CF sequence: {}", e)))?;
         
+        Ok(())
+    }
+    
+    /// Write a caller-staged `batch` together with the writes that index
+    /// each of `cfs` as finalized: a new CF is stored, a stored pending CF is
+    /// marked finalized, and an already finalized CF is left alone. The
+    /// `certificates` of those CFs are written in the same batch.
+    ///
+    /// Sequence numbers are assigned under the index lock and only advance
+    /// once the whole batch is written.
+    pub(crate) fn commit_with_finalized(
+        &self,
+        batch: WriteBatch,
+        cfs: &[ConsensusFrame],
+        certificates: &[VoteCertificate],
+    ) -> SetuResult<()> {
+        self.write_indexed(batch, |batch, seqs| {
+            for cf in cfs {
+                self.stage_finalized(batch, cf, seqs)?;
+            }
+            for cert in certificates {
+                self.stage_vote_certificate(batch, cert)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn stage_finalized(&self, batch: &mut WriteBatch, cf: &ConsensusFrame, seqs: &mut IndexSeqs) -> SetuResult<()> {
+        let stored = self.db
+            .get_raw::<ConsensusFrame>(ColumnFamily::ConsensusFrames, &Self::cf_key(&cf.id))
+            .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        match stored {
+            None => self.stage_store(batch, cf, seqs),
+            Some(stored) if stored.status == CFStatus::Finalized => Ok(()),
+            Some(stored) => self.stage_mark_finalized(batch, stored, seqs),
+        }
+    }
+    
+    /// Store the vote certificate of a finalized CF, keyed by its anchor
+    pub async fn store_vote_certificate(&self, cert: VoteCertificate) -> SetuResult<()> {
+        let mut batch = self.db.batch();
+        self.stage_vote_certificate(&mut batch, &cert)?;
         self.db
             .write_batch(batch)
-            .map_err(|e| SetuError::StorageError(format!("Failed to write mark_finalized batch: {}", e)))?;
-        Ok(())
+            .map_err(|e| SetuError::StorageError(e.to_string()))
+    }
+
+    fn stage_vote_certificate(&self, batch: &mut WriteBatch, cert: &VoteCertificate) -> SetuResult<()> {
+        self.db
+            .batch_put_raw(batch, ColumnFamily::ConsensusFrames, &Self::cert_key(&cert.anchor_id), cert)
+            .map_err(|e| SetuError::StorageError(format!("Failed to store vote certificate: {}", e)))
+    }
+
+    /// Get the vote certificate of the CF that finalized an anchor
+    pub async fn get_vote_certificate(&self, anchor_id: &AnchorId) -> Option<VoteCertificate> {
+        self.db.get_raw(ColumnFamily::ConsensusFrames, &Self::cert_key(anchor_id))
+            .ok()
+            .flatten()
     }
     
     /// Get all pending consensus frames
@@ -303,24 +404,30 @@ impl RocksDBCFStore {
             .map(|keys| keys.len())
             .unwrap_or(0)
     }
+
+    /// Flush buffered CF writes to disk
+    pub async fn flush(&self) -> SetuResult<()> {
+        let db = self.db.clone();
+        spawn_db_op(move || db.flush().map_err(|e| SetuError::StorageError(e.to_string()))).await
+    }
 }
 
 impl Clone for RocksDBCFStore {
     fn clone(&self) -> Self {
         Self {
             db: Arc::clone(&self.db),
-            pending_seq: AtomicU64::new(self.pending_seq.load(Ordering::SeqCst)),
-            finalized_seq: AtomicU64::new(self.finalized_seq.load(Ordering::SeqCst)),
+            seqs: Arc::clone(&self.seqs),
         }
     }
 }
 
 impl std::fmt::Debug for RocksDBCFStore {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let seqs = *self.lock_seqs();
         f.debug_struct("RocksDBCFStore")
             .field("db", &"<SetuDB>")
-            .field("pending_seq", &self.pending_seq.load(Ordering::SeqCst))
-            .field("finalized_seq", &self.finalized_seq.load(Ordering::SeqCst))
+            .field("pending_seq", &seqs.pending)
+            .field("finalized_seq", &seqs.finalized)
             .finish()
     }
 }
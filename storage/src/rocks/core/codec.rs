@@ -0,0 +1,72 @@
+//! Value serialization format for RocksDB storage
+//!
+//! Values are BCS by default: compact and canonical. `Json` stores the same
+//! values as JSON so a debug deployment can inspect them with generic
+//! tooling. Keys are unaffected.
+//!
+//! The format is recorded under [`VALUE_FORMAT_KEY`] in the `merkle_meta`
+//! column family the first time a database is opened; reopening it with a
+//! different format is refused. Databases created before the marker existed
+//! are stamped with whatever format they are next opened with.
+
+use serde::{de::DeserializeOwned, Serialize};
+use std::str::FromStr;
+
+use super::error::Result;
+use super::StorageError;
+
+/// `merkle_meta` key of the on-disk format marker (prefix 0x04 keeps it clear
+/// of the Merkle store's 0x01–0x03 keys)
+pub const VALUE_FORMAT_KEY: &[u8] = b"\x04value_format";
+
+/// How values are serialized into RocksDB
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValueFormat {
+    /// Binary Canonical Serialization (production)
+    #[default]
+    Bcs,
+    /// Human-readable JSON (debugging)
+    Json,
+}
+
+impl ValueFormat {
+    /// Serialize a value in this format
+    pub fn encode<V: Serialize + ?Sized>(self, value: &V) -> Result<Vec<u8>> {
+        match self {
+            Self::Bcs => bcs::to_bytes(value).map_err(|e| StorageError::serialization(e.to_string())),
+            Self::Json => {
+                serde_json::to_vec(value).map_err(|e| StorageError::serialization(e.to_string()))
+            }
+        }
+    }
+
+    /// Marker stored under [`VALUE_FORMAT_KEY`]
+    pub fn marker(self) -> &'static [u8] {
+        match self {
+            Self::Bcs => b"bcs",
+            Self::Json => b"json",
+        }
+    }
+
+    /// Deserialize a value written in this format
+    pub fn decode<V: DeserializeOwned>(self, bytes: &[u8]) -> Result<V> {
+        match self {
+            Self::Bcs => bcs::from_bytes(bytes).map_err(|e| StorageError::deserialization(e.to_string())),
+            Self::Json => {
+                serde_json::from_slice(bytes).map_err(|e| StorageError::deserialization(e.to_string()))
+            }
+        }
+    }
+}
+
+impl FromStr for ValueFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "bcs" => Ok(Self::Bcs),
+            "json" => Ok(Self::Json),
+            other => Err(format!("unknown value format: {}", other)),
+        }
+    }
+}
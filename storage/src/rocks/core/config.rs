@@ -1,4 +1,59 @@
 use std::path::PathBuf;
+use std::str::FromStr;
+
+use super::ValueFormat;
+
+/// When the write-ahead log is fsync'd to disk
+///
+/// Every mode writes the WAL, so all modes survive a process crash. They
+/// differ in what survives an OS crash or power loss:
+///
+/// - `AlwaysSync`: every write is fsync'd before it returns. Nothing
+///   acknowledged is lost, at the cost of one fsync per write.
+/// - `PeriodicSync`: a background thread fsyncs the WAL every `interval_ms`.
+///   At most the last interval of acknowledged writes can be lost.
+/// - `NoSync`: the WAL is never explicitly fsync'd; the OS flushes it when it
+///   chooses. Highest throughput, unbounded loss window. This is RocksDB's
+///   default behaviour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WalSyncMode {
+    /// fsync the WAL on every write
+    AlwaysSync,
+    /// fsync the WAL from a background thread every `interval_ms`
+    PeriodicSync { interval_ms: u64 },
+    /// Leave WAL syncing to the OS
+    #[default]
+    NoSync,
+}
+
+impl WalSyncMode {
+    /// Write options applied to every write under this mode
+    pub fn write_options(self) -> rocksdb::WriteOptions {
+        let mut opts = rocksdb::WriteOptions::default();
+        opts.set_sync(matches!(self, Self::AlwaysSync));
+        opts
+    }
+}
+
+impl FromStr for WalSyncMode {
+    type Err = String;
+
+    /// Parses `always`, `none`, or `periodic:<interval_ms>`
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim().to_ascii_lowercase();
+        match s.as_str() {
+            "always" => Ok(Self::AlwaysSync),
+            "none" => Ok(Self::NoSync),
+            _ => match s.strip_prefix("periodic:") {
+                Some(ms) => match ms.parse::<u64>() {
+                    Ok(interval_ms) if interval_ms > 0 => Ok(Self::PeriodicSync { interval_ms }),
+                    _ => Err(format!("invalid periodic WAL sync interval: {}", ms)),
+                },
+                None => Err(format!("unknown WAL sync mode: {}", s)),
+            },
+        }
+    }
+}
 
 /// Configuration for RocksDB
 #[derive(Debug, Clone)]
@@ -23,6 +78,14 @@ pub struct RocksDBConfig {
     
     /// Cache size for block cache (default: 512MB)
     pub block_cache_size: usize,
+    
+    /// Serialization format for stored values (default: BCS).
+    /// Must match the format the database was written with.
+    pub value_format: ValueFormat,
+    
+    /// When the WAL is fsync'd (default: `NoSync`). See [`WalSyncMode`]
+    /// for the durability tradeoff.
+    pub wal_sync_mode: WalSyncMode,
 }
 
 impl Default for RocksDBConfig {
@@ -35,6 +98,8 @@ impl Default for RocksDBConfig {
             target_file_size_base: 64 * 1024 * 1024,  // 64MB
             enable_statistics: true,
             block_cache_size: 512 * 1024 * 1024,  // 512MB
+            value_format: ValueFormat::Bcs,
+            wal_sync_mode: WalSyncMode::NoSync,
         }
     }
 }
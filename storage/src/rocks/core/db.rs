@@ -1,15 +1,20 @@
 use bincode::Encode;
-use rocksdb::{IteratorMode, WriteBatch, DB};
+use rocksdb::{IteratorMode, WriteBatch, WriteOptions, DB};
 use serde::{de::DeserializeOwned, Serialize};
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
 
 use super::error::Result;
-use super::{ColumnFamily, RocksDBConfig, StorageError};
+use super::codec::VALUE_FORMAT_KEY;
+use super::{ColumnFamily, RocksDBConfig, StorageError, ValueFormat, WalSyncMode};
 
 /// Main database wrapper for Setu
 pub struct SetuDB {
     db: Arc<DB>,
+    value_format: ValueFormat,
+    wal_sync_mode: WalSyncMode,
+    write_opts: Arc<WriteOptions>,
 }
 
 impl SetuDB {
@@ -18,9 +23,53 @@ impl SetuDB {
         let opts = config.to_options();
         let cfs = ColumnFamily::descriptors();
 
-        let db = DB::open_cf_descriptors(&opts, &config.path, cfs)?;
+        let db = Arc::new(DB::open_cf_descriptors(&opts, &config.path, cfs)?);
+        Self::check_value_format(&db, config.value_format)?;
 
-        Ok(Self { db: Arc::new(db) })
+        if let WalSyncMode::PeriodicSync { interval_ms } = config.wal_sync_mode {
+            Self::spawn_wal_syncer(Arc::downgrade(&db), Duration::from_millis(interval_ms));
+        }
+
+        Ok(Self {
+            db,
+            value_format: config.value_format,
+            wal_sync_mode: config.wal_sync_mode,
+            write_opts: Arc::new(config.wal_sync_mode.write_options()),
+        })
+    }
+
+    /// Record `format` on first open; refuse a database written in another format
+    fn check_value_format(db: &DB, format: ValueFormat) -> Result<()> {
+        let cf_name = ColumnFamily::MerkleMeta.name();
+        let cf = db
+            .cf_handle(cf_name)
+            .ok_or_else(|| StorageError::cf_not_found(cf_name))?;
+        match db.get_cf(cf, VALUE_FORMAT_KEY)? {
+            None => db.put_cf(cf, VALUE_FORMAT_KEY, format.marker())?,
+            Some(stored) if stored == format.marker() => {}
+            Some(stored) => {
+                return Err(StorageError::invalid_data(format!(
+                    "database values are stored as {} but it was opened as {}",
+                    String::from_utf8_lossy(&stored),
+                    String::from_utf8_lossy(format.marker()),
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// fsync the WAL every `interval` until the database is dropped
+    fn spawn_wal_syncer(db: Weak<DB>, interval: Duration) {
+        std::thread::Builder::new()
+            .name("setu-db-wal-sync".to_string())
+            .spawn(move || loop {
+                std::thread::sleep(interval);
+                let Some(db) = db.upgrade() else { break };
+                if let Err(e) = db.flush_wal(true) {
+                    tracing::warn!(error = %e, "Periodic WAL sync failed");
+                }
+            })
+            .expect("failed to spawn WAL sync thread");
     }
 
     /// Open a database at the given path with default config
@@ -29,6 +78,16 @@ impl SetuDB {
         Self::open(config)
     }
 
+    /// The format stored values are serialized in
+    pub fn value_format(&self) -> ValueFormat {
+        self.value_format
+    }
+
+    /// When the WAL is fsync'd
+    pub fn wal_sync_mode(&self) -> WalSyncMode {
+        self.wal_sync_mode
+    }
+
     /// Get a reference to the underlying RocksDB instance
     pub fn inner(&self) -> &DB {
         &self.db
@@ -47,14 +106,14 @@ impl SetuDB {
             .map_err(|e| StorageError::serialization(e.to_string()))
     }
 
-    /// Serialize a value using BCS (Binary Canonical Serialization)
-    fn encode_value<V: Serialize>(value: &V) -> Result<Vec<u8>> {
-        bcs::to_bytes(value).map_err(|e| StorageError::serialization(e.to_string()))
+    /// Serialize a value in the configured format
+    fn encode_value<V: Serialize>(&self, value: &V) -> Result<Vec<u8>> {
+        self.value_format.encode(value)
     }
 
-    /// Deserialize a value using BCS
-    fn decode_value<V: DeserializeOwned>(bytes: &[u8]) -> Result<V> {
-        bcs::from_bytes(bytes).map_err(|e| StorageError::deserialization(e.to_string()))
+    /// Deserialize a value in the configured format
+    fn decode_value<V: DeserializeOwned>(&self, bytes: &[u8]) -> Result<V> {
+        self.value_format.decode(bytes)
     }
 
     /// Put a key-value pair into a column family
@@ -65,9 +124,9 @@ impl SetuDB {
     {
         let cf_handle = self.cf_handle(cf)?;
         let key_bytes = Self::encode_key(key)?;
-        let value_bytes = Self::encode_value(value)?;
+        let value_bytes = self.encode_value(value)?;
 
-        self.db.put_cf(cf_handle, key_bytes, value_bytes)?;
+        self.db.put_cf_opt(cf_handle, key_bytes, value_bytes, &self.write_opts)?;
         Ok(())
     }
 
@@ -81,7 +140,7 @@ impl SetuDB {
         let key_bytes = Self::encode_key(key)?;
 
         match self.db.get_cf(cf_handle, key_bytes)? {
-            Some(bytes) => Ok(Some(Self::decode_value(&bytes)?)),
+            Some(bytes) => Ok(Some(self.decode_value(&bytes)?)),
             None => Ok(None),
         }
     }
@@ -94,7 +153,7 @@ impl SetuDB {
         let cf_handle = self.cf_handle(cf)?;
         let key_bytes = Self::encode_key(key)?;
 
-        self.db.delete_cf(cf_handle, key_bytes)?;
+        self.db.delete_cf_opt(cf_handle, key_bytes, &self.write_opts)?;
         Ok(())
     }
 
@@ -125,7 +184,7 @@ impl SetuDB {
         results
             .into_iter()
             .map(|result| match result? {
-                Some(bytes) => Ok(Some(Self::decode_value(&bytes)?)),
+                Some(bytes) => Ok(Some(self.decode_value(&bytes)?)),
                 None => Ok(None),
             })
             .collect()
@@ -144,11 +203,17 @@ impl SetuDB {
         V: Serialize,
     {
         let cf_handle = self.cf_handle(cf)?;
-        let value_bytes = Self::encode_value(value)?;
-        self.db.put_cf(cf_handle, key, value_bytes)?;
+        let value_bytes = self.encode_value(value)?;
+        self.db.put_cf_opt(cf_handle, key, value_bytes, &self.write_opts)?;
         Ok(())
     }
 
+    /// Get the undecoded value bytes by raw byte key
+    pub fn get_raw_bytes(&self, cf: ColumnFamily, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let cf_handle = self.cf_handle(cf)?;
+        Ok(self.db.get_cf(cf_handle, key)?)
+    }
+
     /// Get a value by raw byte key
     pub fn get_raw<V>(&self, cf: ColumnFamily, key: &[u8]) -> Result<Option<V>>
     where
@@ -156,7 +221,7 @@ impl SetuDB {
     {
         let cf_handle = self.cf_handle(cf)?;
         match self.db.get_cf(cf_handle, key)? {
-            Some(bytes) => Ok(Some(Self::decode_value(&bytes)?)),
+            Some(bytes) => Ok(Some(self.decode_value(&bytes)?)),
             None => Ok(None),
         }
     }
@@ -164,7 +229,7 @@ impl SetuDB {
     /// Delete by raw byte key
     pub fn delete_raw(&self, cf: ColumnFamily, key: &[u8]) -> Result<()> {
         let cf_handle = self.cf_handle(cf)?;
-        self.db.delete_cf(cf_handle, key)?;
+        self.db.delete_cf_opt(cf_handle, key, &self.write_opts)?;
         Ok(())
     }
 
@@ -182,7 +247,7 @@ impl SetuDB {
     {
         let cf_handle = self.cf_handle(cf)?;
         let key_bytes = Self::encode_key(key)?;
-        let value_bytes = Self::encode_value(value)?;
+        let value_bytes = self.encode_value(value)?;
 
         batch.put_cf(cf_handle, key_bytes, value_bytes);
         Ok(())
@@ -212,7 +277,7 @@ impl SetuDB {
         V: Serialize,
     {
         let cf_handle = self.cf_handle(cf)?;
-        let value_bytes = Self::encode_value(value)?;
+        let value_bytes = self.encode_value(value)?;
         batch.put_cf(cf_handle, key, value_bytes);
         Ok(())
     }
@@ -279,9 +344,32 @@ impl SetuDB {
             }))
     }
 
+    /// Iterate over key-value pairs with raw byte keys in `[start, end)`.
+    /// Seeks directly to `start`, so the cost is proportional to the range size
+    /// rather than the column family size.
+    pub fn range_iterator(
+        &self,
+        cf: ColumnFamily,
+        start: &[u8],
+        end: &[u8],
+    ) -> Result<
+        impl Iterator<Item = std::result::Result<(Box<[u8]>, Box<[u8]>), rocksdb::Error>> + '_,
+    > {
+        let cf_handle = self.cf_handle(cf)?;
+        let end_owned = end.to_vec();
+
+        Ok(self
+            .db
+            .iterator_cf(cf_handle, IteratorMode::From(start, rocksdb::Direction::Forward))
+            .take_while(move |result| match result {
+                Ok((k, _)) => k.as_ref() < end_owned.as_slice(),
+                Err(_) => false,
+            }))
+    }
+
     /// Write a batch atomically
     pub fn write_batch(&self, batch: WriteBatch) -> Result<()> {
-        self.db.write(batch)?;
+        self.db.write_opt(batch, &self.write_opts)?;
         Ok(())
     }
 
@@ -301,7 +389,7 @@ impl SetuDB {
                 let key = bincode::decode_from_slice(&key_bytes, bincode::config::standard())
                     .map_err(|e| StorageError::deserialization(e.to_string()))?
                     .0;
-                let value = Self::decode_value(&value_bytes)?;
+                let value = self.decode_value(&value_bytes)?;
                 Ok((key, value))
             }))
     }
@@ -319,14 +407,14 @@ impl SetuDB {
             .iterator_cf(cf_handle, IteratorMode::Start)
             .map(|result| {
                 let (_key_bytes, value_bytes) = result?;
-                let value = Self::decode_value(&value_bytes)?;
+                let value = self.decode_value(&value_bytes)?;
                 Ok(value)
             }))
     }
 
     /// Iterate with a prefix.
     ///
-    /// Keys are bincode-decoded, values are BCS-decoded.
+    /// Keys are bincode-decoded, values are decoded in the configured format.
     /// An explicit `take_while` enforces the prefix boundary — RocksDB's
     /// `prefix_iterator_cf` without a configured `prefix_extractor` only
     /// seeks to the prefix position but does NOT stop at the prefix boundary,
@@ -358,14 +446,16 @@ impl SetuDB {
                 let key = bincode::decode_from_slice(&key_bytes, bincode::config::standard())
                     .map_err(|e| StorageError::deserialization(e.to_string()))?
                     .0;
-                let value = Self::decode_value(&value_bytes)?;
+                let value = self.decode_value(&value_bytes)?;
                 Ok((key, value))
             }))
     }
 
-    /// Flush the database to disk
+    /// Flush the memtables of every column family to disk
     pub fn flush(&self) -> Result<()> {
-        self.db.flush()?;
+        for cf in ColumnFamily::all() {
+            self.db.flush_cf(self.cf_handle(cf)?)?;
+        }
         Ok(())
     }
 
@@ -388,6 +478,9 @@ impl Clone for SetuDB {
     fn clone(&self) -> Self {
         Self {
             db: Arc::clone(&self.db),
+            value_format: self.value_format,
+            wal_sync_mode: self.wal_sync_mode,
+            write_opts: Arc::clone(&self.write_opts),
         }
     }
 }
@@ -413,6 +506,15 @@ mod tests {
         age: u32,
     }
 
+    fn setup_test_db_with_format(format: ValueFormat) -> (SetuDB, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+        let config = RocksDBConfig {
+            value_format: format,
+            ..RocksDBConfig::new(temp_dir.path())
+        };
+        (SetuDB::open(config).unwrap(), temp_dir)
+    }
+
     fn setup_test_db() -> (SetuDB, TempDir) {
         let temp_dir = TempDir::new().unwrap();
         let db = SetuDB::open_default(temp_dir.path()).unwrap();
@@ -520,4 +622,120 @@ mod tests {
 
         assert_eq!(keys, vec![b"depth:abc".to_vec()]);
     }
+
+    #[test]
+    fn test_value_format_round_trip() {
+        let mut subnet_roots = std::collections::HashMap::new();
+        subnet_roots.insert(setu_types::SubnetId::ROOT, [0x44; 32]);
+        let roots = setu_types::AnchorMerkleRoots {
+            events_root: [0x11; 32],
+            global_state_root: [0x22; 32],
+            anchor_chain_root: [0x33; 32],
+            subnet_roots,
+        };
+        let value = TestValue {
+            name: "Carol".to_string(),
+            age: 41,
+        };
+
+        for format in [ValueFormat::Bcs, ValueFormat::Json] {
+            let (db, _temp) = setup_test_db_with_format(format);
+            assert_eq!(db.value_format(), format);
+
+            db.put_raw(ColumnFamily::Objects, b"value", &value).unwrap();
+            db.put_raw(ColumnFamily::Anchors, b"roots", &roots).unwrap();
+            let read: Option<TestValue> = db.get_raw(ColumnFamily::Objects, b"value").unwrap();
+            assert_eq!(read, Some(value.clone()));
+            let read: Option<setu_types::AnchorMerkleRoots> =
+                db.get_raw(ColumnFamily::Anchors, b"roots").unwrap();
+            assert_eq!(read, Some(roots.clone()));
+
+            let cf = db.cf_handle(ColumnFamily::Objects).unwrap();
+            let raw = db.inner().get_cf(cf, b"value").unwrap().unwrap();
+            match format {
+                ValueFormat::Bcs => assert!(serde_json::from_slice::<serde_json::Value>(&raw).is_err()),
+                ValueFormat::Json => {
+                    let json: serde_json::Value = serde_json::from_slice(&raw).unwrap();
+                    assert_eq!(json, serde_json::json!({ "name": "Carol", "age": 41 }));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_reopen_with_other_value_format_refused() {
+        let temp_dir = TempDir::new().unwrap();
+        let open = |format| {
+            SetuDB::open(RocksDBConfig {
+                value_format: format,
+                ..RocksDBConfig::new(temp_dir.path())
+            })
+        };
+
+        let db = open(ValueFormat::Json).unwrap();
+        db.put_raw(ColumnFamily::Objects, b"value", &TestValue { name: "Dan".to_string(), age: 7 })
+            .unwrap();
+        drop(db);
+
+        let err = open(ValueFormat::Bcs).err().expect("format mismatch must be refused");
+        assert!(err.to_string().contains("json"), "{}", err);
+
+        let db = open(ValueFormat::Json).unwrap();
+        let read: Option<TestValue> = db.get_raw(ColumnFamily::Objects, b"value").unwrap();
+        assert_eq!(read.unwrap().age, 7);
+    }
+
+    #[test]
+    fn test_value_format_from_str() {
+        assert_eq!("bcs".parse(), Ok(ValueFormat::Bcs));
+        assert_eq!("JSON".parse(), Ok(ValueFormat::Json));
+        assert!("yaml".parse::<ValueFormat>().is_err());
+        assert_eq!(RocksDBConfig::default().value_format, ValueFormat::Bcs);
+    }
+
+    #[test]
+    fn test_writes_readable_under_each_wal_sync_mode() {
+        for mode in [
+            WalSyncMode::AlwaysSync,
+            WalSyncMode::PeriodicSync { interval_ms: 10 },
+            WalSyncMode::NoSync,
+        ] {
+            let temp_dir = TempDir::new().unwrap();
+            let config = RocksDBConfig {
+                wal_sync_mode: mode,
+                ..RocksDBConfig::new(temp_dir.path())
+            };
+            let db = SetuDB::open(config).unwrap();
+            assert_eq!(db.wal_sync_mode(), mode);
+
+            let value = TestValue { name: format!("{:?}", mode), age: 1 };
+            db.put(ColumnFamily::Objects, &TestKey { id: 1 }, &value).unwrap();
+
+            let mut batch = db.batch();
+            db.batch_put(&mut batch, ColumnFamily::Objects, &TestKey { id: 2 }, &value)
+                .unwrap();
+            db.write_batch(batch).unwrap();
+
+            db.delete(ColumnFamily::Objects, &TestKey { id: 1 }).unwrap();
+
+            let deleted: Option<TestValue> = db.get(ColumnFamily::Objects, &TestKey { id: 1 }).unwrap();
+            let batched: Option<TestValue> = db.get(ColumnFamily::Objects, &TestKey { id: 2 }).unwrap();
+            assert_eq!(deleted, None, "mode {:?}", mode);
+            assert_eq!(batched, Some(value), "mode {:?}", mode);
+        }
+    }
+
+    #[test]
+    fn test_wal_sync_mode_from_str() {
+        assert_eq!("always".parse(), Ok(WalSyncMode::AlwaysSync));
+        assert_eq!("None".parse(), Ok(WalSyncMode::NoSync));
+        assert_eq!(
+            "periodic:250".parse(),
+            Ok(WalSyncMode::PeriodicSync { interval_ms: 250 })
+        );
+        assert!("periodic:0".parse::<WalSyncMode>().is_err());
+        assert!("periodic:x".parse::<WalSyncMode>().is_err());
+        assert!("sometimes".parse::<WalSyncMode>().is_err());
+        assert_eq!(RocksDBConfig::default().wal_sync_mode, WalSyncMode::NoSync);
+    }
 }
@@ -3,6 +3,7 @@
 //! This module provides the foundational components for RocksDB storage:
 //! - `SetuDB`: Main database wrapper with column family support
 //! - `RocksDBConfig`: Configuration options
+//! - `ValueFormat`: Value serialization format (BCS or JSON)
 //! - `ColumnFamily`: Column family definitions
 //! - `StorageError`: Rich error types with context
 //! - Async helpers for blocking operations
@@ -10,11 +11,13 @@
 pub mod db;
 pub mod error;
 pub mod config;
+pub mod codec;
 pub mod column_family;
 pub mod async_wrapper;
 
 pub use db::SetuDB;
 pub use error::{StorageError, StorageErrorKind, StorageOperation, StorageResultExt, IntoSetuResult};
-pub use config::RocksDBConfig;
+pub use config::{RocksDBConfig, WalSyncMode};
+pub use codec::ValueFormat;
 pub use column_family::ColumnFamily;
 pub use async_wrapper::{spawn_db_op, spawn_db_op_result, BlockingDbWrapper};
@@ -10,6 +10,7 @@
 //! 3. **Atomic Batch Writes**: Uses WriteBatch for atomic store_with_depth operations
 //! 4. **API Compatible**: Maintains the same async API as in-memory EventStore
 //! 5. **Index Tables**: Stores by_creator and by_status as separate key prefixes
+//! 6. **Time Index**: Uses `time:{timestamp_be}:{event_id}` so a timestamp window is one range scan
 //!
 //! ## Column Family Layout
 //!
@@ -19,11 +20,15 @@
 //! - `depthidx:{depth:08x}:{event_id}` -> () (depth reverse index for range queries)
 //! - `creator:{creator}:{event_id}` -> () (creator index)
 //! - `status:{status}:{event_id}` -> () (status index)
+//! - `xfer:{transfer_id}` -> EventId (transfer index, for post-restart status lookups)
+//! - `time:{timestamp:u64 BE}:{event_id}` -> () (time index for range queries)
 
-use crate::rocks::core::{ColumnFamily, SetuDB};
+use crate::rocks::core::{spawn_db_op, ColumnFamily, SetuDB, StorageError, ValueFormat};
 use crate::types::BatchStoreResult;
+use rocksdb::WriteBatch;
 use setu_types::{Event, EventId, EventStatus, SetuError, SetuResult};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tracing::{debug, error, warn};
 
@@ -34,22 +39,34 @@ mod key_prefix {
     pub const DEPTH_IDX: &[u8] = b"depthidx:";
     pub const CREATOR: &[u8] = b"creator:";
     pub const STATUS: &[u8] = b"status:";
+    pub const TRANSFER: &[u8] = b"xfer:";
+    pub const TIME: &[u8] = b"time:";
 }
 
 /// RocksDB-backed EventStore implementation
 pub struct RocksDBEventStore {
     db: Arc<SetuDB>,
+    /// Records skipped by read paths because their body failed to decode
+    decode_errors: Arc<AtomicU64>,
 }
 
 impl RocksDBEventStore {
     /// Create a new RocksDBEventStore with an owned SetuDB
     pub fn new(db: SetuDB) -> Self {
-        Self { db: Arc::new(db) }
+        Self::from_shared(Arc::new(db))
     }
 
     /// Create from a shared SetuDB instance
     pub fn from_shared(db: Arc<SetuDB>) -> Self {
-        Self { db }
+        Self {
+            db,
+            decode_errors: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Number of stored events skipped so far because they failed to decode
+    pub fn decode_errors(&self) -> u64 {
+        self.decode_errors.load(Ordering::Relaxed)
     }
 
     /// Get the underlying database reference
@@ -108,6 +125,30 @@ impl RocksDBEventStore {
         key
     }
 
+    fn transfer_key(transfer_id: &str) -> Vec<u8> {
+        let mut key = Vec::with_capacity(key_prefix::TRANSFER.len() + transfer_id.len());
+        key.extend_from_slice(key_prefix::TRANSFER);
+        key.extend_from_slice(transfer_id.as_bytes());
+        key
+    }
+
+    /// Time index key: `time:{timestamp:u64 BE}:{event_id}` → ()
+    /// Big-endian timestamps sort lexicographically in numeric order.
+    fn time_key(timestamp: u64, event_id: &EventId) -> Vec<u8> {
+        let mut key = Self::time_bound(timestamp);
+        key.push(b':');
+        key.extend_from_slice(event_id.as_bytes());
+        key
+    }
+
+    /// `time:{timestamp:u64 BE}` — sorts before every time key at `timestamp`
+    fn time_bound(timestamp: u64) -> Vec<u8> {
+        let mut key = Vec::with_capacity(key_prefix::TIME.len() + 8);
+        key.extend_from_slice(key_prefix::TIME);
+        key.extend_from_slice(&timestamp.to_be_bytes());
+        key
+    }
+
     fn creator_prefix(creator: &str) -> Vec<u8> {
         let mut prefix = Vec::with_capacity(key_prefix::CREATOR.len() + creator.len() + 1);
         prefix.extend_from_slice(key_prefix::CREATOR);
@@ -125,10 +166,25 @@ impl RocksDBEventStore {
         prefix
     }
 
+    /// Read and decode an event body.
+    ///
+    /// BCS bodies written before events carried a signature lack its tail
+    /// bytes, so they are decoded through `Event::from_bytes`.
+    fn read_event(&self, event_key: &[u8]) -> Result<Option<Event>, StorageError> {
+        let Some(bytes) = self.db.get_raw_bytes(ColumnFamily::Events, event_key)? else {
+            return Ok(None);
+        };
+        match self.db.value_format() {
+            ValueFormat::Bcs => Event::from_bytes(&bytes)
+                .map(Some)
+                .ok_or_else(|| StorageError::deserialization("undecodable BCS event body")),
+            format => format.decode(&bytes).map(Some),
+        }
+    }
+
     fn get_indexed_event_for_replay(&self, event_id: &EventId) -> SetuResult<Event> {
         let event_key = Self::event_key(event_id);
-        self.db
-            .get_raw::<Event>(ColumnFamily::Events, &event_key)
+        self.read_event(&event_key)
             .map_err(|e| SetuError::StorageError(e.to_string()))?
             .ok_or_else(|| {
                 SetuError::StorageError(format!(
@@ -144,82 +200,108 @@ impl RocksDBEventStore {
 
     /// Store an event (without depth)
     pub async fn store(&self, event: Event) -> SetuResult<()> {
-        let event_id = event.id.clone();
-        let creator = event.creator.clone();
-        let status = event.status;
-
         let mut batch = self.db.batch();
+        self.stage_event_body(&mut batch, &event)?;
 
-        // Store event
-        let event_key = Self::event_key(&event_id);
         self.db
-            .batch_put_raw(&mut batch, ColumnFamily::Events, &event_key, &event)
+            .write_batch(batch)
             .map_err(|e| SetuError::StorageError(e.to_string()))?;
 
-        // Store creator index
-        let creator_key = Self::creator_key(&creator, &event_id);
-        self.db
-            .batch_put_raw(&mut batch, ColumnFamily::Events, &creator_key, &())
-            .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        Ok(())
+    }
 
-        // Store status index
-        let status_key = Self::status_key(status, &event_id);
-        self.db
-            .batch_put_raw(&mut batch, ColumnFamily::Events, &status_key, &())
-            .map_err(|e| SetuError::StorageError(e.to_string()))?;
+    /// Store an event with its depth (atomic operation)
+    ///
+    /// This is the primary method used during anchor finalization.
+    pub async fn store_with_depth(&self, event: Event, depth: u64) -> SetuResult<()> {
+        let mut batch = self.db.batch();
+        self.stage_event_body(&mut batch, &event)?;
+        self.stage_depth_keys(&mut batch, &event.id, depth)?;
 
         self.db
             .write_batch(batch)
             .map_err(|e| SetuError::StorageError(e.to_string()))?;
 
+        debug!(event_id = %event.id, depth = depth, "Persisted event with depth to RocksDB");
         Ok(())
     }
 
-    /// Store an event with its depth (atomic operation)
+    /// Add the writes of `store_with_depth` to a caller-owned batch without
+    /// committing it, so the event lands atomically with other writes.
     ///
-    /// This is the primary method used during anchor finalization.
-    pub async fn store_with_depth(&self, event: Event, depth: u64) -> SetuResult<()> {
-        let event_id = event.id.clone();
-        let creator = event.creator.clone();
-        let status = event.status;
+    /// As in `store_batch_with_depth`, an event that is already stored keeps
+    /// its body and only has its depth keys upserted. Returns `false` in that
+    /// case.
+    pub fn stage_with_depth(
+        &self,
+        batch: &mut WriteBatch,
+        event: &Event,
+        depth: u64,
+    ) -> SetuResult<bool> {
+        let exists = self
+            .db
+            .exists_raw(ColumnFamily::Events, &Self::event_key(&event.id))
+            .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        if !exists {
+            self.stage_event_body(batch, event)?;
+        }
+        self.stage_depth_keys(batch, &event.id, depth)?;
+        Ok(!exists)
+    }
 
-        let mut batch = self.db.batch();
+    /// Stage the event body plus its creator, status, transfer and time indexes
+    fn stage_event_body(&self, batch: &mut WriteBatch, event: &Event) -> SetuResult<()> {
+        let event_id = &event.id;
 
         // Store event
-        let event_key = Self::event_key(&event_id);
+        let event_key = Self::event_key(event_id);
         self.db
-            .batch_put_raw(&mut batch, ColumnFamily::Events, &event_key, &event)
+            .batch_put_raw(batch, ColumnFamily::Events, &event_key, event)
             .map_err(|e| SetuError::StorageError(e.to_string()))?;
 
-        // Store depth
-        let depth_key = Self::depth_key(&event_id);
+        // Store creator index
+        let creator_key = Self::creator_key(&event.creator, event_id);
         self.db
-            .batch_put_raw(&mut batch, ColumnFamily::Events, &depth_key, &depth)
+            .batch_put_raw(batch, ColumnFamily::Events, &creator_key, &())
             .map_err(|e| SetuError::StorageError(e.to_string()))?;
 
-        // Store depth reverse index (for efficient range queries)
-        let depth_idx_key = Self::depth_idx_key(depth, &event_id);
+        // Store status index
+        let status_key = Self::status_key(event.status, event_id);
         self.db
-            .batch_put_raw(&mut batch, ColumnFamily::Events, &depth_idx_key, &())
+            .batch_put_raw(batch, ColumnFamily::Events, &status_key, &())
             .map_err(|e| SetuError::StorageError(e.to_string()))?;
 
-        // Store creator index
-        let creator_key = Self::creator_key(&creator, &event_id);
+        // Store transfer index
+        if let Some(transfer) = &event.transfer {
+            let transfer_key = Self::transfer_key(&transfer.id);
+            self.db
+                .batch_put_raw(batch, ColumnFamily::Events, &transfer_key, event_id)
+                .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        }
+
+        // Store time index
+        let time_key = Self::time_key(event.timestamp, event_id);
         self.db
-            .batch_put_raw(&mut batch, ColumnFamily::Events, &creator_key, &())
+            .batch_put_raw(batch, ColumnFamily::Events, &time_key, &())
             .map_err(|e| SetuError::StorageError(e.to_string()))?;
 
-        // Store status index
-        let status_key = Self::status_key(status, &event_id);
+        Ok(())
+    }
+
+    /// Stage the depth value and depth reverse index of an event
+    fn stage_depth_keys(&self, batch: &mut WriteBatch, event_id: &EventId, depth: u64) -> SetuResult<()> {
+        // Store depth
+        let depth_key = Self::depth_key(event_id);
         self.db
-            .batch_put_raw(&mut batch, ColumnFamily::Events, &status_key, &())
+            .batch_put_raw(batch, ColumnFamily::Events, &depth_key, &depth)
             .map_err(|e| SetuError::StorageError(e.to_string()))?;
 
+        // Store depth reverse index (for efficient range queries)
+        let depth_idx_key = Self::depth_idx_key(depth, event_id);
         self.db
-            .write_batch(batch)
+            .batch_put_raw(batch, ColumnFamily::Events, &depth_idx_key, &())
             .map_err(|e| SetuError::StorageError(e.to_string()))?;
 
-        debug!(event_id = %event_id, depth = depth, "Persisted event with depth to RocksDB");
         Ok(())
     }
 
@@ -269,6 +351,8 @@ impl RocksDBEventStore {
 
             let creator = event.creator.clone();
             let status = event.status;
+            let transfer_id = event.transfer.as_ref().map(|t| t.id.clone());
+            let timestamp = event.timestamp;
 
             // Store event
             let event_key = Self::event_key(&event_id);
@@ -325,6 +409,30 @@ impl RocksDBEventStore {
                 continue;
             }
 
+            // Store time index
+            let time_key = Self::time_key(timestamp, &event_id);
+            if let Err(e) =
+                self.db
+                    .batch_put_raw(&mut batch, ColumnFamily::Events, &time_key, &())
+            {
+                result.failed += 1;
+                result.failed_errors.push((event_id, e.to_string()));
+                continue;
+            }
+
+            // Store transfer index
+            if let Some(transfer_id) = transfer_id {
+                let transfer_key = Self::transfer_key(&transfer_id);
+                if let Err(e) =
+                    self.db
+                        .batch_put_raw(&mut batch, ColumnFamily::Events, &transfer_key, &event_id)
+                {
+                    result.failed += 1;
+                    result.failed_errors.push((event_id, e.to_string()));
+                    continue;
+                }
+            }
+
             result.stored += 1;
         }
 
@@ -345,10 +453,7 @@ impl RocksDBEventStore {
     /// Get an event by ID
     pub async fn get(&self, event_id: &EventId) -> Option<Event> {
         let event_key = Self::event_key(event_id);
-        self.db
-            .get_raw(ColumnFamily::Events, &event_key)
-            .ok()
-            .flatten()
+        self.read_event(&event_key).ok().flatten()
     }
 
     /// Get multiple events by ID
@@ -357,10 +462,15 @@ impl RocksDBEventStore {
             .iter()
             .filter_map(|id| {
                 let event_key = Self::event_key(id);
-                self.db
-                    .get_raw::<Event>(ColumnFamily::Events, &event_key)
-                    .ok()
-                    .flatten()
+                match self.read_event(&event_key) {
+                    Ok(event) => event,
+                    Err(e) => {
+                        // Skip the bad record so one corrupt body doesn't hide the rest
+                        warn!(event_id = %id, error = %e, "Skipping undecodable event");
+                        self.decode_errors.fetch_add(1, Ordering::Relaxed);
+                        None
+                    }
+                }
             })
             .collect()
     }
@@ -489,6 +599,73 @@ impl RocksDBEventStore {
         self.get_many(&event_ids).await
     }
 
+    /// Get the event carrying a transfer (uses the transfer index)
+    pub async fn get_by_transfer_id(&self, transfer_id: &str) -> Option<Event> {
+        let event_id = self
+            .db
+            .get_raw::<EventId>(ColumnFamily::Events, &Self::transfer_key(transfer_id))
+            .ok()
+            .flatten()?;
+        self.get(&event_id).await
+    }
+
+    /// Get up to `limit` events with `from_ts <= timestamp <= to_ts`, oldest first
+    /// (uses a range scan over the time index)
+    ///
+    /// With `after`, the scan starts just past `time:{from_ts}:{after}`, so the
+    /// `(timestamp, event_id)` of the last returned event is a stable cursor.
+    pub async fn get_events_in_range(
+        &self,
+        from_ts: u64,
+        to_ts: u64,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Vec<Event> {
+        if from_ts > to_ts || limit == 0 {
+            return Vec::new();
+        }
+
+        let start = match after {
+            Some(after) => {
+                // The smallest key sorting after the cursor's own time key
+                let mut start = Self::time_key(from_ts, &after.to_string());
+                start.push(0);
+                start
+            }
+            None => Self::time_bound(from_ts),
+        };
+        let end = match to_ts.checked_add(1) {
+            Some(next) => Self::time_bound(next),
+            None => {
+                // No timestamp lies past u64::MAX; bound by the next prefix instead
+                let mut end = key_prefix::TIME.to_vec();
+                *end.last_mut().expect("prefix is non-empty") += 1;
+                end
+            }
+        };
+
+        let iter = match self.db.range_iterator(ColumnFamily::Events, &start, &end) {
+            Ok(iter) => iter,
+            Err(_) => return Vec::new(),
+        };
+
+        // Key format: time:{timestamp:u64 BE}:{event_id}
+        let id_offset = key_prefix::TIME.len() + 8 + 1;
+        let event_ids: Vec<EventId> = iter
+            .filter_map(|result| match result {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    warn!(error = %e, "Skipping unreadable time index entry");
+                    None
+                }
+            })
+            .filter_map(|(key, _)| String::from_utf8(key.get(id_offset..)?.to_vec()).ok())
+            .take(limit)
+            .collect();
+
+        self.get_many(&event_ids).await
+    }
+
     /// Get events by status (uses prefix scan)
     pub async fn get_by_status(&self, status: EventStatus) -> Vec<Event> {
         let prefix = Self::status_prefix(status);
@@ -546,6 +723,7 @@ impl Clone for RocksDBEventStore {
     fn clone(&self) -> Self {
         Self {
             db: Arc::clone(&self.db),
+            decode_errors: Arc::clone(&self.decode_errors),
         }
     }
 }
@@ -554,6 +732,7 @@ impl std::fmt::Debug for RocksDBEventStore {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RocksDBEventStore")
             .field("db", &"<SetuDB>")
+            .field("decode_errors", &self.decode_errors())
             .finish()
     }
 }
@@ -618,6 +797,24 @@ impl EventStoreBackend for RocksDBEventStore {
         RocksDBEventStore::get_by_creator(self, creator).await
     }
 
+    async fn get_by_transfer_id(&self, transfer_id: &str) -> Option<Event> {
+        RocksDBEventStore::get_by_transfer_id(self, transfer_id).await
+    }
+
+    async fn get_events_in_range(
+        &self,
+        from_ts: u64,
+        to_ts: u64,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Vec<Event> {
+        RocksDBEventStore::get_events_in_range(self, from_ts, to_ts, after, limit).await
+    }
+
+    fn decode_errors(&self) -> u64 {
+        RocksDBEventStore::decode_errors(self)
+    }
+
     async fn get_by_status(&self, status: EventStatus) -> Vec<Event> {
         RocksDBEventStore::get_by_status(self, status).await
     }
@@ -755,6 +952,11 @@ impl EventStoreBackend for RocksDBEventStore {
 
         max_depth
     }
+
+    async fn flush(&self) -> SetuResult<()> {
+        let db = self.db.clone();
+        spawn_db_op(move || db.flush().map_err(|e| SetuError::StorageError(e.to_string()))).await
+    }
 }
 
 #[cfg(test)]
@@ -775,6 +977,157 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn reads_events_stored_before_signatures() {
+        let temp_dir = tempfile::tempdir().expect("temp dir must be created");
+        let store = RocksDBEventStore::new(
+            SetuDB::open_default(temp_dir.path()).expect("test db must open"),
+        );
+        let event = test_event("pre-upgrade");
+        store.store(event.clone()).await.unwrap();
+
+        // An unsigned event's BCS ends with the empty signature's length byte;
+        // dropping it yields the encoding older nodes wrote.
+        let mut legacy = bcs::to_bytes(&event).unwrap();
+        assert_eq!(legacy.pop(), Some(0));
+        raw_put_bytes(
+            store.db(),
+            ColumnFamily::Events,
+            &RocksDBEventStore::event_key(&event.id),
+            &legacy,
+        );
+
+        let read = store.get(&event.id).await.expect("legacy event must decode");
+        assert_eq!(read.id, event.id);
+        assert!(read.signature.is_empty());
+        assert_eq!(store.get_many(&[event.id.clone()]).await.len(), 1);
+        assert_eq!(store.decode_errors(), 0);
+    }
+
+    #[tokio::test]
+    async fn transfer_index_resolves_persisted_events() {
+        let temp_dir = tempfile::tempdir().expect("temp dir must be created");
+        let store = RocksDBEventStore::new(
+            SetuDB::open_default(temp_dir.path()).expect("test db must open"),
+        );
+        let batched = test_event("solver-1")
+            .with_transfer(setu_types::Transfer::new("tx-batched", "alice", "bob", 10));
+        let single = test_event("solver-1")
+            .with_transfer(setu_types::Transfer::new("tx-single", "alice", "bob", 20));
+        let (batched_id, single_id) = (batched.id.clone(), single.id.clone());
+
+        let result = store.store_batch_with_depth(vec![(batched, 1)]).await;
+        assert_eq!(result.stored, 1);
+        store.store_with_depth(single, 2).await.unwrap();
+
+        assert_eq!(store.get_by_transfer_id("tx-batched").await.unwrap().id, batched_id);
+        assert_eq!(store.get_by_transfer_id("tx-single").await.unwrap().id, single_id);
+        assert!(store.get_by_transfer_id("tx-unknown").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn time_range_returns_only_in_window_events_in_timestamp_order() {
+        let temp_dir = tempfile::tempdir().expect("temp dir must be created");
+        let store = RocksDBEventStore::new(
+            SetuDB::open_default(temp_dir.path()).expect("test db must open"),
+        );
+        let at = |timestamp: u64| {
+            let mut event = test_event(&format!("solver-{}", timestamp));
+            event.timestamp = timestamp;
+            event
+        };
+
+        store.store(at(300)).await.unwrap();
+        store.store(at(50)).await.unwrap();
+        store.store_with_depth(at(200), 1).await.unwrap();
+        let result = store
+            .store_batch_with_depth(vec![(at(100), 2), (at(500), 2), (at(299), 2)])
+            .await;
+        assert_eq!(result.stored, 3);
+
+        let timestamps = |events: Vec<Event>| events.iter().map(|e| e.timestamp).collect::<Vec<_>>();
+        assert_eq!(timestamps(store.get_events_in_range(100, 300, None, 10).await), vec![100, 200, 299, 300]);
+        assert_eq!(timestamps(store.get_events_in_range(100, 300, None, 2).await), vec![100, 200]);
+        assert_eq!(timestamps(store.get_events_in_range(400, u64::MAX, None, 10).await), vec![500]);
+        assert!(store.get_events_in_range(301, 499, None, 10).await.is_empty());
+        assert!(store.get_events_in_range(300, 100, None, 10).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn time_range_cursor_pages_through_events_sharing_a_timestamp() {
+        let temp_dir = tempfile::tempdir().expect("temp dir must be created");
+        let store = RocksDBEventStore::new(
+            SetuDB::open_default(temp_dir.path()).expect("test db must open"),
+        );
+        for creator in ["solver-a", "solver-b", "solver-c"] {
+            let mut event = test_event(creator);
+            event.timestamp = 100;
+            store.store(event).await.unwrap();
+        }
+        let mut later = test_event("solver-d");
+        later.timestamp = 200;
+        store.store(later.clone()).await.unwrap();
+
+        // Page by (timestamp, event_id) of the last event returned
+        let mut seen = Vec::new();
+        let mut cursor: Option<(u64, EventId)> = None;
+        loop {
+            let (from, after) = match &cursor {
+                Some((ts, id)) => (*ts, Some(id.as_str())),
+                None => (0, None),
+            };
+            let page = store.get_events_in_range(from, u64::MAX, after, 2).await;
+            let Some(last) = page.last() else { break };
+            cursor = Some((last.timestamp, last.id.clone()));
+            seen.extend(page.into_iter().map(|e| e.id));
+        }
+
+        assert_eq!(seen.len(), 4);
+        let unique: std::collections::HashSet<_> = seen.iter().collect();
+        assert_eq!(unique.len(), 4);
+        assert_eq!(seen.last(), Some(&later.id));
+    }
+
+    #[tokio::test]
+    async fn time_range_skips_undecodable_event_and_counts_it() {
+        let temp_dir = tempfile::tempdir().expect("temp dir must be created");
+        let store = RocksDBEventStore::new(
+            SetuDB::open_default(temp_dir.path()).expect("test db must open"),
+        );
+        let at = |timestamp: u64| {
+            let mut event = test_event(&format!("solver-{}", timestamp));
+            event.timestamp = timestamp;
+            event
+        };
+        for timestamp in [100, 200, 300] {
+            store.store(at(timestamp)).await.unwrap();
+        }
+
+        let corrupt_id: EventId = "corrupt-event".to_string();
+        raw_put_bytes(
+            store.db(),
+            ColumnFamily::Events,
+            &RocksDBEventStore::event_key(&corrupt_id),
+            b"not-a-bcs-event",
+        );
+        raw_put_bytes(
+            store.db(),
+            ColumnFamily::Events,
+            &RocksDBEventStore::time_key(150, &corrupt_id),
+            &[],
+        );
+
+        let timestamps: Vec<u64> = store
+            .get_events_in_range(0, u64::MAX, None, 10)
+            .await
+            .iter()
+            .map(|e| e.timestamp)
+            .collect();
+        assert_eq!(timestamps, vec![100, 200, 300]);
+        assert_eq!(store.decode_errors(), 1);
+        assert_eq!(store.clone().decode_errors(), 1);
+    }
+
     #[tokio::test]
     async fn depth_range_replay_errors_on_corrupt_event_body() {
         let temp_dir = tempfile::tempdir().expect("temp dir must be created");
@@ -42,7 +42,9 @@ use setu_merkle::sparse::SparseMerkleNode;
 use setu_merkle::HashValue;
 use rocksdb::WriteBatch;
 use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
+use tracing::warn;
 
 /// Key for storing Merkle nodes: (subnet_id, node_hash)
 #[derive(Clone, Debug, bincode::Encode, bincode::Decode)]
@@ -52,7 +54,7 @@ struct NodeKey {
 }
 
 /// Key for storing Merkle roots: (subnet_id, anchor_id)
-#[derive(Clone, Debug, bincode::Encode, bincode::Decode)]
+#[derive(Clone, Debug, bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]
 struct RootKey {
     subnet_id: [u8; 32],
     anchor_id: u64,
@@ -138,6 +140,17 @@ pub struct RocksDBMerkleStore {
     db: Arc<SetuDB>,
     /// In-memory cache of registered subnet IDs (P1 optimization)
     registered_subnets_cache: Arc<RwLock<HashSet<SubnetId>>>,
+    /// Leaf records skipped by `load_all_leaves` because they failed to decode
+    decode_errors: Arc<AtomicU64>,
+}
+
+/// Point-in-time counters for a [`RocksDBMerkleStore`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MerkleStoreStats {
+    /// Number of subnets in the registry cache
+    pub registered_subnets: usize,
+    /// Leaf records skipped during loads because they failed to decode
+    pub decode_errors: u64,
 }
 
 impl RocksDBMerkleStore {
@@ -146,6 +159,7 @@ impl RocksDBMerkleStore {
         let store = Self {
             db: Arc::new(db),
             registered_subnets_cache: Arc::new(RwLock::new(HashSet::new())),
+            decode_errors: Arc::new(AtomicU64::new(0)),
         };
         // Load existing registered subnets into cache
         if let Ok(subnets) = store.list_registered_subnets() {
@@ -162,6 +176,7 @@ impl RocksDBMerkleStore {
         let store = Self {
             db,
             registered_subnets_cache: Arc::new(RwLock::new(HashSet::new())),
+            decode_errors: Arc::new(AtomicU64::new(0)),
         };
         // Load existing registered subnets into cache
         if let Ok(subnets) = store.list_registered_subnets() {
@@ -184,6 +199,14 @@ impl RocksDBMerkleStore {
         &self.db
     }
 
+    /// Snapshot of the store counters.
+    pub fn stats(&self) -> MerkleStoreStats {
+        MerkleStoreStats {
+            registered_subnets: self.registered_subnets_cache.read().unwrap().len(),
+            decode_errors: self.decode_errors.load(Ordering::Relaxed),
+        }
+    }
+
     /// Convert HashValue to bytes array
     fn hash_to_bytes(hash: &HashValue) -> [u8; 32] {
         let mut bytes = [0u8; 32];
@@ -401,9 +424,7 @@ impl MerkleRootStore for RocksDBMerkleStore {
 
 impl MerkleStore for RocksDBMerkleStore {
     fn flush(&self) -> MerkleResult<()> {
-        // RocksDB handles flushing internally
-        // For explicit flush, we could call db.flush() but SetuDB doesn't expose it yet
-        Ok(())
+        self.db.flush().map_err(Self::to_merkle_error)
     }
 
     fn checkpoint(&self, _anchor_id: AnchorId) -> MerkleResult<()> {
@@ -417,6 +438,30 @@ impl MerkleStore for RocksDBMerkleStore {
         // This is a no-op for now - full implementation would iterate and delete
         Ok(0)
     }
+
+    fn prune_subnet_before(&self, subnet_id: &SubnetId, anchor_id: AnchorId) -> MerkleResult<u64> {
+        // Root keys are (subnet_id, anchor_id), so one prefix scan covers the subnet.
+        // Other MerkleRoots entries (latest-anchor pointers) fail to decode as
+        // RootKey/root pairs and are skipped.
+        let iter = self
+            .db
+            .prefix_iter::<_, RootKey, [u8; 32]>(ColumnFamily::MerkleRoots, subnet_id)
+            .map_err(Self::to_merkle_error)?;
+
+        let mut batch = self.db.batch();
+        let mut pruned = 0u64;
+        for (root_key, _) in iter.flatten() {
+            if &root_key.subnet_id != subnet_id || root_key.anchor_id >= anchor_id {
+                continue;
+            }
+            self.db
+                .batch_delete(&mut batch, ColumnFamily::MerkleRoots, &root_key)
+                .map_err(Self::to_merkle_error)?;
+            pruned += 1;
+        }
+        self.db.write_batch(batch).map_err(Self::to_merkle_error)?;
+        Ok(pruned)
+    }
 }
 
 impl MerkleLeafStore for RocksDBMerkleStore {
@@ -462,6 +507,7 @@ impl MerkleLeafStore for RocksDBMerkleStore {
         // Use typed prefix iteration: keys are bincode-decoded to LeafKey,
         // values are BCS-decoded (matching the write path via `batch_put`/`encode_value`).
         // `prefix_iter` internally enforces the prefix boundary via `take_while`.
+        // Records that fail to decode are skipped and counted in `stats()`.
         let mut result = HashMap::new();
 
         let iter = self
@@ -470,7 +516,19 @@ impl MerkleLeafStore for RocksDBMerkleStore {
             .map_err(Self::to_merkle_error)?;
 
         for item in iter {
-            let (leaf_key, value) = item.map_err(Self::to_merkle_error)?;
+            let (leaf_key, value) = match item {
+                Ok(entry) => entry,
+                Err(e) => {
+                    // Skip the bad record so one corrupt leaf doesn't block recovery
+                    warn!(
+                        subnet_id = %hex::encode(subnet_id),
+                        error = %e,
+                        "Skipping undecodable merkle leaf"
+                    );
+                    self.decode_errors.fetch_add(1, Ordering::Relaxed);
+                    continue;
+                }
+            };
             // Defensive check: prefix_iter's take_while should already guarantee
             // this, but verify subnet_id to guard against bincode field reordering
             // or any future layout change.
@@ -934,6 +992,23 @@ mod tests {
         assert_eq!(store.get_latest_global_root().unwrap(), Some((200, root2)));
     }
 
+    #[test]
+    fn test_prune_subnet_before() {
+        let (store, _temp_dir) = create_test_store();
+        let pruned_subnet = test_subnet(7);
+        let other_subnet = test_subnet(8);
+
+        for anchor_id in 1..=3 {
+            store.put_subnet_root(&pruned_subnet, anchor_id, &test_hash(anchor_id as u8)).unwrap();
+            store.put_subnet_root(&other_subnet, anchor_id, &test_hash(anchor_id as u8)).unwrap();
+        }
+
+        assert_eq!(store.prune_subnet_before(&pruned_subnet, 3).unwrap(), 2);
+        assert_eq!(store.list_anchors(&pruned_subnet, 1, 3).unwrap(), vec![3]);
+        assert_eq!(store.list_anchors(&other_subnet, 1, 3).unwrap(), vec![1, 2, 3]);
+        assert_eq!(store.get_latest_subnet_root(&pruned_subnet).unwrap(), Some((3, test_hash(3))));
+    }
+
     #[test]
     fn test_batch_put_nodes() {
         let (store, _temp_dir) = create_test_store();
@@ -977,4 +1052,87 @@ mod tests {
         assert_eq!(subnets, vec![registered]);
         assert!(!subnets.contains(&anchor_only));
     }
+
+    #[test]
+    fn test_deleted_leaf_yields_non_inclusion_proof_against_updated_root() {
+        use setu_merkle::IncrementalSparseMerkleTree;
+
+        let (store, _temp_dir) = create_test_store();
+        let subnet_id = test_subnet(3);
+        // 0x00.. and 0x01.. share a 7-bit prefix, so deleting one collapses a chain
+        let (kept, deleted, other) = (test_hash(0x00), test_hash(0x01), test_hash(0x80));
+
+        let mut tree = IncrementalSparseMerkleTree::new();
+        for key in [kept, deleted, other] {
+            tree.insert(key, key.as_bytes().to_vec());
+        }
+        let changes = tree.take_changes();
+        let upserts: Vec<_> = changes.upserts.iter().map(|(k, v)| (k, v.as_slice())).collect();
+        store.batch_put_leaves(&subnet_id, &upserts).unwrap();
+
+        let root_before = tree.root();
+        tree.get_proof(&deleted)
+            .verify_inclusion(&root_before, &deleted, deleted.as_bytes())
+            .unwrap();
+
+        tree.remove(&deleted);
+        let changes = tree.take_changes();
+        assert_eq!(changes.deletes, vec![deleted]);
+        let deletes: Vec<_> = changes.deletes.iter().collect();
+        store.batch_delete_leaves(&subnet_id, &deletes).unwrap();
+
+        let root_after = tree.root();
+        assert_ne!(root_after, root_before);
+        let proof = tree.get_proof(&deleted);
+        proof.verify_non_inclusion(&root_after, &deleted).unwrap();
+        assert!(proof.verify_non_inclusion(&root_before, &deleted).is_err());
+        tree.get_proof(&kept)
+            .verify_inclusion(&root_after, &kept, kept.as_bytes())
+            .unwrap();
+
+        // The persisted leaves rebuild the same tree as the incremental removal
+        let recovered =
+            IncrementalSparseMerkleTree::from_leaves(store.load_all_leaves(&subnet_id).unwrap());
+        assert!(!store.has_leaf(&subnet_id, &deleted).unwrap());
+        assert_eq!(recovered.root(), root_after);
+        recovered
+            .get_proof(&deleted)
+            .verify_non_inclusion(&root_after, &deleted)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_load_all_leaves_skips_corrupt_entries() {
+        let (store, _temp) = create_test_store();
+        let subnet_id = test_subnet(7);
+        let good = [(test_hash(1), b"one".to_vec()), (test_hash(2), b"two".to_vec())];
+        let good_refs: Vec<_> = good.iter().map(|(k, v)| (k, v.as_slice())).collect();
+        store.batch_put_leaves(&subnet_id, &good_refs).unwrap();
+
+        // An unterminated ULEB length prefix cannot be BCS-decoded as Vec<u8>
+        let corrupt_key = bincode::encode_to_vec(
+            &LeafKey {
+                subnet_id,
+                object_id: [3u8; 32],
+            },
+            bincode::config::standard(),
+        )
+        .unwrap();
+        let cf_handle = store
+            .db()
+            .inner()
+            .cf_handle(ColumnFamily::MerkleLeaves.name())
+            .unwrap();
+        store
+            .db()
+            .inner()
+            .put_cf(cf_handle, &corrupt_key, [0xff, 0xff, 0xff])
+            .unwrap();
+
+        let leaves = store.load_all_leaves(&subnet_id).unwrap();
+        assert_eq!(leaves.len(), 2);
+        assert_eq!(leaves.get(&test_hash(1)), Some(&b"one".to_vec()));
+        assert_eq!(leaves.get(&test_hash(2)), Some(&b"two".to_vec()));
+        assert_eq!(store.stats().decode_errors, 1);
+    }
 }
@@ -5,6 +5,7 @@
 //! ## Structure
 //! - `core/`: Foundation infrastructure (SetuDB, config, errors)
 //! - Store implementations: event_store, anchor_store, cf_store, object_store, merkle_store
+//! - `anchor_commit`: atomic anchor persistence across the event/CF/anchor stores
 
 // Core infrastructure
 pub mod core;
@@ -15,9 +16,10 @@ pub mod anchor_store;
 pub mod cf_store;
 pub mod object_store;
 pub mod merkle_store;
+pub mod anchor_commit;
 
 // Re-export core types for convenience
-pub use core::{SetuDB, RocksDBConfig, ColumnFamily, StorageError, StorageErrorKind, StorageOperation, StorageResultExt, IntoSetuResult};
+pub use core::{SetuDB, RocksDBConfig, WalSyncMode, ValueFormat, ColumnFamily, StorageError, StorageErrorKind, StorageOperation, StorageResultExt, IntoSetuResult};
 pub use core::{spawn_db_op, spawn_db_op_result, BlockingDbWrapper};
 
 // Re-export store implementations
@@ -25,4 +27,5 @@ pub use event_store::RocksDBEventStore;
 pub use anchor_store::RocksDBAnchorStore;
 pub use cf_store::RocksDBCFStore;
 pub use object_store::{RocksObjectStore, RebuildIndexResult};
-pub use merkle_store::RocksDBMerkleStore;
+pub use merkle_store::{MerkleStoreStats, RocksDBMerkleStore};
+pub use anchor_commit::RocksDBAnchorCommitter;
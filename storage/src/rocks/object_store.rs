@@ -11,13 +11,28 @@ use setu_types::{
 };
 use tracing::{debug, warn, error, info, instrument};
 
+/// Key prefix for retained object versions in the `Objects` column family
+const OBJECT_VERSION_PREFIX: &str = "objver:";
+
 pub struct RocksObjectStore {
     db: SetuDB,
+    /// Number of versions kept per object (`None` = history disabled)
+    version_retention: Option<usize>,
 }
 
 impl RocksObjectStore {
     pub fn new(db: SetuDB) -> Self {
-        Self { db }
+        Self { db, version_retention: None }
+    }
+    
+    /// Retain the last `retention` versions of every coin written through this store
+    ///
+    /// History is opt-in. Each stored or updated coin is also written under
+    /// `objver:{object_id}:{version}`, and versions beyond the newest
+    /// `retention` are pruned in the same batch. A retention of 0 is treated as 1.
+    pub fn with_version_retention(mut self, retention: usize) -> Self {
+        self.version_retention = Some(retention.max(1));
+        self
     }
     
     pub fn open(path: impl AsRef<std::path::Path>) -> SetuResult<Self> {
@@ -69,6 +84,69 @@ impl RocksObjectStore {
             .map(|opt| opt.unwrap_or_default())
     }
     
+    /// Version history key: `objver:{object_id}:{version:016x}`
+    ///
+    /// The fixed-width version keeps keys in numeric order under a prefix scan.
+    fn object_version_key(object_id: &ObjectId, version: u64) -> Vec<u8> {
+        format!("{}{}:{:016x}", OBJECT_VERSION_PREFIX, hex::encode(object_id.as_bytes()), version).into_bytes()
+    }
+    
+    /// Prefix for all retained versions of an object: `objver:{object_id}:`
+    fn object_version_prefix(object_id: &ObjectId) -> Vec<u8> {
+        format!("{}{}:", OBJECT_VERSION_PREFIX, hex::encode(object_id.as_bytes())).into_bytes()
+    }
+    
+    /// Add a history entry for `coin` to the batch and prune versions beyond retention
+    fn batch_record_version(&self, batch: &mut WriteBatch, coin: &Coin) -> SetuResult<()> {
+        let Some(retention) = self.version_retention else {
+            return Ok(());
+        };
+        let id = &coin.metadata.id;
+        let version = coin.metadata.version;
+        
+        self.db.batch_put_raw(batch, ColumnFamily::Objects, &Self::object_version_key(id, version), coin)
+            .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        
+        let mut versions = self.list_versions(id)?;
+        if !versions.contains(&version) {
+            versions.push(version);
+            versions.sort_unstable();
+        }
+        let excess = versions.len().saturating_sub(retention);
+        for old in &versions[..excess] {
+            self.db.batch_delete_raw(batch, ColumnFamily::Objects, &Self::object_version_key(id, *old))
+                .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        }
+        Ok(())
+    }
+    
+    /// Get a retained historical version of a coin
+    ///
+    /// Returns `None` if the version was never recorded or has been pruned.
+    pub fn get_object_version(&self, object_id: &ObjectId, version: u64) -> SetuResult<Option<Coin>> {
+        self.db.get_raw(ColumnFamily::Objects, &Self::object_version_key(object_id, version))
+            .map_err(|e| SetuError::StorageError(e.to_string()))
+    }
+    
+    /// List retained versions of an object, oldest first
+    pub fn list_versions(&self, object_id: &ObjectId) -> SetuResult<Vec<u64>> {
+        let prefix = Self::object_version_prefix(object_id);
+        let keys = self.db.prefix_scan_keys(ColumnFamily::Objects, &prefix)
+            .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        
+        keys.iter()
+            .map(|key| {
+                std::str::from_utf8(&key[prefix.len()..])
+                    .ok()
+                    .and_then(|v| u64::from_str_radix(v, 16).ok())
+                    .ok_or_else(|| SetuError::StorageError(format!(
+                        "Malformed object version key: {}",
+                        String::from_utf8_lossy(key)
+                    )))
+            })
+            .collect()
+    }
+    
     /// Create a composite key for subnet activity: user_bytes + subnet_bytes
     fn make_subnet_activity_key(user: &Address, subnet_id: &SubnetId) -> Vec<u8> {
         let mut key = Vec::with_capacity(64);
@@ -491,6 +569,8 @@ impl ObjectStore for RocksObjectStore {
             self.batch_add_to_owner_type_index(&mut batch, owner, &coin.data.coin_type, &id)?;
         }
         
+        self.batch_record_version(&mut batch, coin)?;
+        
         // Commit all changes atomically
         self.db.write_batch(batch)
             .map_err(|e| SetuError::StorageError(e.to_string()))?;
@@ -543,6 +623,7 @@ impl ObjectStore for RocksObjectStore {
         // Add coin update to batch
         self.db.batch_put(&mut batch, ColumnFamily::Coins, &coin.metadata.id, coin)
             .map_err(|e| SetuError::StorageError(e.to_string()))?;
+        self.batch_record_version(&mut batch, coin)?;
         
         // Commit all changes atomically
         self.db.write_batch(batch)
@@ -820,6 +901,41 @@ mod tests {
         assert!(store.get_coin(&coin_id).unwrap().is_none());
     }
     
+    #[test]
+    fn test_coin_version_history() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = RocksObjectStore::open(temp_dir.path()).unwrap().with_version_retention(3);
+        let alice = Address::from_str_id("alice");
+        let mut coin = Coin::new(alice, 1000);
+        let coin_id = coin.metadata.id;
+        store.store_coin(&coin).unwrap();
+        
+        // Three updates: versions 2, 3, 4 with balances 1100, 1200, 1300
+        for _ in 0..3 {
+            coin.data.balance.deposit(setu_types::Balance::new(100)).unwrap();
+            coin.increment_version();
+            store.update_coin(&coin).unwrap();
+        }
+        
+        // Retention 3 drops the initial version
+        assert_eq!(store.list_versions(&coin_id).unwrap(), vec![2, 3, 4]);
+        assert!(store.get_object_version(&coin_id, 1).unwrap().is_none());
+        for (version, balance) in [(2, 1100), (3, 1200), (4, 1300)] {
+            let historical = store.get_object_version(&coin_id, version).unwrap().unwrap();
+            assert_eq!(historical.metadata.version, version);
+            assert_eq!(historical.value(), balance);
+        }
+        assert_eq!(store.get_coin(&coin_id).unwrap().unwrap().value(), 1300);
+    }
+    
+    #[test]
+    fn test_version_history_disabled_by_default() {
+        let (store, _temp) = setup_test_store();
+        let coin = Coin::new(Address::from_str_id("alice"), 1000);
+        store.store_coin(&coin).unwrap();
+        assert!(store.list_versions(&coin.metadata.id).unwrap().is_empty());
+    }
+    
     #[test]
     fn test_profile_crud() {
         let (store, _temp) = setup_test_store();
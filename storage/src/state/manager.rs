@@ -21,15 +21,18 @@
 use setu_merkle::{
     HashValue, IncrementalSparseMerkleTree, LeafChanges, SparseMerkleProof,
     B4Store, MerkleStore,
-    SubnetAggregationTree, SubnetStateEntry,
+    SubnetAggregationTree,
 };
 use serde::{Deserialize, Serialize};
 use setu_types::{SubnetId, AnchorMerkleRoots};
 use setu_types::event::{Event, StateChange, ExecutionResult};
 use setu_types::envelope::{detect_and_parse, StorageFormat};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
+/// Default object size cap; networks set theirs in genesis
+pub use setu_types::DEFAULT_MAX_OBJECT_BYTES;
+
 // ============================================================================
 // DIAG (Phase 1 instrumentation for consensus-root-self-consistency FDP)
 // ============================================================================
@@ -288,6 +291,41 @@ pub struct GlobalStateManager {
     /// writes — leaders and followers both go through this single hook
     /// (design.md §4.4 A').
     version_watcher: Option<Arc<crate::state::version_watcher::WatcherRegistry>>,
+    /// Committed event counters per subnet, fed by `apply_committed_events`.
+    ///
+    /// Only events whose state changes were applied are counted; failed and
+    /// conflicted events are not.
+    subnet_event_counts: HashMap<SubnetId, SubnetEventCounter>,
+    /// Aggregation tree from the last `compute_global_root`, so the next call
+    /// only rehashes subnets whose root changed since.
+    aggregation_cache: std::sync::Mutex<SubnetAggregationTree>,
+    /// Largest object value accepted by `upsert_object`/`apply_state_change`.
+    ///
+    /// Every object is rehashed and carried in proofs on each write, so an
+    /// unbounded object (e.g. a growing RelationGraph) slows both.
+    max_object_bytes: usize,
+    /// When set, anchor commits queue their B4 writes in `deferred_b4`
+    /// instead of writing them, so the anchor committer can land them in the
+    /// same WriteBatch as the anchor itself.
+    defer_b4: bool,
+    /// B4 writes of anchors committed here but not yet persisted, in commit order
+    deferred_b4: VecDeque<DeferredB4Commit>,
+}
+
+/// B4 writes of one anchor commit, held back until the anchor is persisted
+pub struct DeferredB4Commit {
+    /// Id of the anchor whose commit produced these writes
+    anchor_key: String,
+    anchor_id: u64,
+    subnets: Vec<DeferredSubnetCommit>,
+    global_root: HashValue,
+}
+
+struct DeferredSubnetCommit {
+    subnet_id: SubnetId,
+    changes: LeafChanges,
+    registered: bool,
+    root: HashValue,
 }
 
 /// Extended B4Store trait that combines all required storage capabilities.
@@ -315,7 +353,7 @@ impl<T: B4Store + MerkleStore> B4StoreExt for T {}
 /// **Unsafe use case** (will lose data):
 /// ```ignore
 /// let mut manager = GlobalStateManager::new();
-/// manager.upsert_object(subnet, key, value);  // Marks as dirty
+/// manager.upsert_object(subnet, key, value)?;  // Marks as dirty
 /// let cloned = manager.clone();               // Dirty tracking lost!
 /// // cloned.commit() would NOT persist the upsert
 /// ```
@@ -332,6 +370,13 @@ impl Clone for GlobalStateManager {
             // Clones are throw-away snapshots — wakeup notifications are scoped
             // to the canonical instance only.
             version_watcher: None,
+            // Events applied to a clone were never committed
+            subnet_event_counts: HashMap::new(),
+            aggregation_cache: std::sync::Mutex::new(self.cached_aggregation()),
+            max_object_bytes: self.max_object_bytes,
+            // Clones cannot commit, so they never hold B4 writes
+            defer_b4: false,
+            deferred_b4: VecDeque::new(),
         }
     }
 }
@@ -351,6 +396,7 @@ impl GlobalStateManager {
     /// | coin_type_index | ❌ cleared | ✅ preserved |
     /// | owner_object_index | ❌ cleared | ✅ preserved |
     /// | modification_tracker | ❌ cleared | ✅ preserved |
+    /// | subnet_event_counts | ❌ cleared | ✅ preserved |
     ///
     /// ## Performance
     /// - subnet_states: O(N_subnets), each SMT internal im::HashMap O(1) clone (currently N=1)
@@ -366,6 +412,12 @@ impl GlobalStateManager {
             // Read snapshots do not fire wakeups; the canonical instance owns
             // the watcher.
             version_watcher: None,
+            subnet_event_counts: self.subnet_event_counts.clone(),
+            aggregation_cache: std::sync::Mutex::new(self.cached_aggregation()),
+            max_object_bytes: self.max_object_bytes,
+            // Pending B4 writes belong to the write instance that persists them
+            defer_b4: false,
+            deferred_b4: VecDeque::new(),
         }
     }
 
@@ -385,6 +437,11 @@ impl GlobalStateManager {
             owner_object_index: HashMap::new(),
             modification_tracker: HashMap::new(),
             version_watcher: None,
+            subnet_event_counts: HashMap::new(),
+            aggregation_cache: std::sync::Mutex::new(SubnetAggregationTree::default()),
+            max_object_bytes: DEFAULT_MAX_OBJECT_BYTES,
+            defer_b4: false,
+            deferred_b4: VecDeque::new(),
         }
     }
     
@@ -407,6 +464,28 @@ impl GlobalStateManager {
     ) {
         self.version_watcher = Some(watcher);
     }
+
+    /// Set the per-object size cap enforced on writes
+    pub fn set_max_object_bytes(&mut self, max_object_bytes: usize) {
+        self.max_object_bytes = max_object_bytes;
+    }
+
+    /// Current per-object size cap in bytes
+    pub fn max_object_bytes(&self) -> usize {
+        self.max_object_bytes
+    }
+
+    /// Reject a value larger than `max_object_bytes`
+    fn check_object_size(&self, object_id: &HashValue, value: &[u8]) -> Result<(), StateApplyError> {
+        if value.len() > self.max_object_bytes {
+            return Err(StateApplyError::ObjectTooLarge {
+                object_id: *object_id.as_bytes(),
+                size: value.len(),
+                max: self.max_object_bytes,
+            });
+        }
+        Ok(())
+    }
     /// Get or create a subnet's SMT
     pub fn get_subnet_mut(&mut self, subnet_id: SubnetId) -> &mut SubnetStateSMT {
         self.subnet_states
@@ -441,36 +520,53 @@ impl GlobalStateManager {
     }
     
     /// Insert or update an object in a subnet
+    ///
+    /// Returns the new subnet root, or `StateApplyError::ObjectTooLarge` if
+    /// `value` exceeds `max_object_bytes` (nothing is written).
     pub fn upsert_object(
         &mut self,
         subnet_id: SubnetId,
         object_id: [u8; 32],
         value: Vec<u8>,
-    ) -> [u8; 32] {
-        self.get_subnet_mut(subnet_id).upsert_raw(object_id, value)
+    ) -> Result<[u8; 32], StateApplyError> {
+        self.check_object_size(&HashValue::new(object_id), &value)?;
+        Ok(self.get_subnet_mut(subnet_id).upsert_raw(object_id, value))
     }
     
     /// Compute global state root by aggregating all subnets
+    ///
+    /// The aggregation tree is cached between calls and only the subnets
+    /// whose root changed are rehashed, so an anchor touching one subnet does
+    /// not pay for all of them. The root is identical to a full rebuild.
     pub fn compute_global_root(&self) -> (HashValue, HashMap<SubnetId, HashValue>) {
-        let entries: Vec<SubnetStateEntry> = self.subnet_states
+        let subnet_roots: HashMap<SubnetId, HashValue> = self.subnet_states
             .iter()
-            .map(|(id, smt)| SubnetStateEntry::new(*id.as_bytes(), smt.root()))
+            .map(|(id, smt)| (*id, smt.root()))
             .collect();
         
-        if entries.is_empty() {
+        if subnet_roots.is_empty() {
             return (HashValue::zero(), HashMap::new());
         }
         
-        let tree = SubnetAggregationTree::build(entries.clone());
-        let global_root = tree.root();
-        
-        let subnet_roots: HashMap<SubnetId, HashValue> = self.subnet_states
+        let leaf_roots: HashMap<[u8; 32], HashValue> = subnet_roots
             .iter()
-            .map(|(id, smt)| (*id, smt.root()))
+            .map(|(id, root)| (*id.as_bytes(), *root))
             .collect();
+        let global_root = self.aggregation_cache
+            .lock()
+            .expect("aggregation cache mutex poisoned")
+            .sync_roots(&leaf_roots);
         
         (global_root, subnet_roots)
     }
+
+    /// Copy of the cached aggregation tree, for clones to start warm
+    fn cached_aggregation(&self) -> SubnetAggregationTree {
+        self.aggregation_cache
+            .lock()
+            .expect("aggregation cache mutex poisoned")
+            .clone()
+    }
     
     /// Compute global state root as raw bytes
     pub fn compute_global_root_bytes(&self) -> ([u8; 32], HashMap<SubnetId, [u8; 32]>) {
@@ -518,79 +614,181 @@ impl GlobalStateManager {
     ///
     /// All operations use a **single WriteBatch** to guarantee atomicity.
     pub fn commit(&mut self, anchor_id: u64) -> setu_merkle::MerkleResult<()> {
+        self.commit_inner(anchor_id, None)
+    }
+
+    /// Commit current state for the anchor `anchor_key` (see [`commit`](Self::commit)).
+    ///
+    /// With [`defer_b4_commits`](Self::defer_b4_commits) enabled the B4
+    /// writes are queued under `anchor_key` instead of written, and land when
+    /// the anchor committer persists that anchor.
+    pub fn commit_anchor(&mut self, anchor_id: u64, anchor_key: &str) -> setu_merkle::MerkleResult<()> {
+        self.commit_inner(anchor_id, Some(anchor_key))
+    }
+
+    fn commit_inner(&mut self, anchor_id: u64, anchor_key: Option<&str>) -> setu_merkle::MerkleResult<()> {
         // Update last anchor for all subnets
         for smt in self.subnet_states.values_mut() {
             smt.set_last_anchor(anchor_id);
         }
         
         // Persist to storage if available (B4 scheme)
-        if let Some(ref store) = self.store {
-            // ⭐ Create a SINGLE WriteBatch for all operations
-            let mut batch = store.begin_batch()?;
-            
-            // Phase 1: Persist all dirty leaves for each subnet
-            for (subnet_id, smt) in &mut self.subnet_states {
-                if smt.has_pending_changes() {
-                    let changes = smt.take_changes();
-                    
-                    // Batch put upserted leaves (into WriteBatch, not committed yet)
-                    if !changes.upserts.is_empty() {
-                        let upserts: Vec<_> = changes.upserts
-                            .iter()
-                            .map(|(k, v)| (k, v.as_slice()))
-                            .collect();
-                        store.batch_put_leaves_to_batch(
-                            &mut batch,
-                            subnet_id.as_bytes(),
-                            &upserts,
-                        )?;
-                    }
-                    
-                    // Batch delete removed leaves (into WriteBatch, not committed yet)
-                    if !changes.deletes.is_empty() {
-                        let deletes: Vec<_> = changes.deletes.iter().collect();
-                        store.batch_delete_leaves_to_batch(
-                            &mut batch,
-                            subnet_id.as_bytes(),
-                            &deletes,
-                        )?;
-                    }
+        if let Some(store) = self.store.clone() {
+            let commit = self.collect_b4_commit(anchor_id, anchor_key.unwrap_or_default());
+            match anchor_key {
+                Some(_) if self.defer_b4 => {
+                    tracing::debug!(
+                        anchor_id,
+                        anchor_key = %commit.anchor_key,
+                        queued = self.deferred_b4.len() + 1,
+                        "B4 commit deferred to anchor persistence"
+                    );
+                    self.deferred_b4.push_back(commit);
                 }
-                
-                // Register subnet if it has data (into WriteBatch)
-                if smt.object_count() > 0 {
-                    store.batch_register_subnet(&mut batch, subnet_id.as_bytes())?;
+                _ => {
+                    // ⭐ Create a SINGLE WriteBatch for all operations
+                    let mut batch = store.begin_batch()?;
+                    Self::stage_b4_commit(store.as_ref(), &mut batch, &commit)?;
+
+                    // ⭐ Atomic commit: all or nothing
+                    store.commit_batch(batch)?;
+
+                    tracing::debug!(
+                        anchor_id,
+                        subnet_count = self.subnet_states.len(),
+                        "B4 commit completed atomically"
+                    );
                 }
-                
-                // Store subnet root (into WriteBatch)
-                store.batch_put_subnet_root(
-                    &mut batch,
-                    subnet_id.as_bytes(),
-                    anchor_id,
-                    &smt.root(),
-                )?;
-                
-                // Store last anchor for subnet (into WriteBatch)
-                store.batch_set_last_anchor(&mut batch, subnet_id.as_bytes(), anchor_id)?;
             }
-            
-            // Phase 2: Store global root (into WriteBatch)
-            let (global_root, _) = self.compute_global_root();
-            store.batch_put_global_root(&mut batch, anchor_id, &global_root)?;
-            
-            // ⭐ Atomic commit: all or nothing
-            store.commit_batch(batch)?;
-            
-            tracing::debug!(
-                anchor_id,
-                subnet_count = self.subnet_states.len(),
-                "B4 commit completed atomically"
-            );
         }
         
         self.current_anchor = anchor_id;
         Ok(())
     }
+
+    /// Take every dirty leaf, subnet root and the global root for `anchor_id`
+    fn collect_b4_commit(&mut self, anchor_id: u64, anchor_key: &str) -> DeferredB4Commit {
+        let subnets = self
+            .subnet_states
+            .iter_mut()
+            .map(|(subnet_id, smt)| {
+                let changes = if smt.has_pending_changes() {
+                    smt.take_changes()
+                } else {
+                    LeafChanges::default()
+                };
+                DeferredSubnetCommit {
+                    subnet_id: *subnet_id,
+                    changes,
+                    registered: smt.object_count() > 0,
+                    root: smt.root(),
+                }
+            })
+            .collect();
+        let (global_root, _) = self.compute_global_root();
+        DeferredB4Commit {
+            anchor_key: anchor_key.to_string(),
+            anchor_id,
+            subnets,
+            global_root,
+        }
+    }
+
+    /// Stage the leaves, roots and metadata of `commit` into `batch`
+    fn stage_b4_commit(
+        store: &dyn B4StoreExt,
+        batch: &mut Box<dyn std::any::Any + Send>,
+        commit: &DeferredB4Commit,
+    ) -> setu_merkle::MerkleResult<()> {
+        let anchor_id = commit.anchor_id;
+
+        // Phase 1: Persist all dirty leaves for each subnet
+        for subnet in &commit.subnets {
+            let subnet_id = subnet.subnet_id.as_bytes();
+
+            // Batch put upserted leaves (into WriteBatch, not committed yet)
+            if !subnet.changes.upserts.is_empty() {
+                let upserts: Vec<_> = subnet.changes.upserts
+                    .iter()
+                    .map(|(k, v)| (k, v.as_slice()))
+                    .collect();
+                store.batch_put_leaves_to_batch(batch, subnet_id, &upserts)?;
+            }
+
+            // Batch delete removed leaves (into WriteBatch, not committed yet)
+            if !subnet.changes.deletes.is_empty() {
+                let deletes: Vec<_> = subnet.changes.deletes.iter().collect();
+                store.batch_delete_leaves_to_batch(batch, subnet_id, &deletes)?;
+            }
+
+            // Register subnet if it has data (into WriteBatch)
+            if subnet.registered {
+                store.batch_register_subnet(batch, subnet_id)?;
+            }
+
+            // Store subnet root and last anchor for subnet (into WriteBatch)
+            store.batch_put_subnet_root(batch, subnet_id, anchor_id, &subnet.root)?;
+            store.batch_set_last_anchor(batch, subnet_id, anchor_id)?;
+        }
+
+        // Phase 2: Store global root (into WriteBatch)
+        store.batch_put_global_root(batch, anchor_id, &commit.global_root)
+    }
+
+    /// Queue B4 writes of anchor commits instead of writing them.
+    ///
+    /// Enabled when an anchor committer persists anchors: it stages the
+    /// queued writes into its own WriteBatch via
+    /// [`stage_deferred_b4`](Self::stage_deferred_b4), so merkle state and the
+    /// anchor land together or not at all.
+    pub fn defer_b4_commits(&mut self) {
+        self.defer_b4 = true;
+    }
+
+    /// Stage the deferred B4 writes up to and including `anchor_key` into
+    /// `batch`, removing them from the queue.
+    ///
+    /// Older queued commits are staged first, so a commit whose anchor was
+    /// never persisted on its own still lands before any newer state. Returns
+    /// the staged commits; hand them to
+    /// [`restore_deferred_b4`](Self::restore_deferred_b4) if the batch is not
+    /// written. Nothing is staged when `anchor_key` is not queued.
+    pub fn stage_deferred_b4(
+        &mut self,
+        batch: &mut Box<dyn std::any::Any + Send>,
+        anchor_key: &str,
+    ) -> setu_merkle::MerkleResult<Vec<DeferredB4Commit>> {
+        let Some(store) = self.store.clone() else {
+            return Ok(Vec::new());
+        };
+        let Some(pos) = self.deferred_b4.iter().position(|c| c.anchor_key == anchor_key) else {
+            return Ok(Vec::new());
+        };
+        let taken: Vec<_> = self.deferred_b4.drain(..=pos).collect();
+        let staged = taken
+            .iter()
+            .try_for_each(|commit| Self::stage_b4_commit(store.as_ref(), batch, commit));
+        match staged {
+            Ok(()) => Ok(taken),
+            Err(e) => {
+                self.restore_deferred_b4(taken);
+                Err(e)
+            }
+        }
+    }
+
+    /// Put commits taken by [`stage_deferred_b4`](Self::stage_deferred_b4)
+    /// back at the front of the queue, in their original order.
+    pub fn restore_deferred_b4(&mut self, commits: Vec<DeferredB4Commit>) {
+        for commit in commits.into_iter().rev() {
+            self.deferred_b4.push_front(commit);
+        }
+    }
+
+    /// Number of anchor commits whose B4 writes are still queued
+    pub fn deferred_b4_count(&self) -> usize {
+        self.deferred_b4.len()
+    }
     
     /// Recover state from persisted data (B4 scheme: startup recovery).
     ///
@@ -604,6 +802,7 @@ impl GlobalStateManager {
     /// 3. Reconstruct SMT from leaves using `IncrementalSparseMerkleTree::from_leaves()`
     /// 4. Verify reconstructed root matches persisted root (consistency check)
     /// 5. Restore last anchor info from MerkleMeta
+    /// 6. Rebuild the owner/coin-type indexes from the recovered objects
     ///
     /// ## Returns
     ///
@@ -695,9 +894,14 @@ impl GlobalStateManager {
             self.subnet_states.insert(SubnetId::ROOT, SubnetStateSMT::new(SubnetId::ROOT));
         }
         
+        // Indexes are in-memory only; without this, non-ROOT coins stay
+        // invisible to owner/type lookups until something else rebuilds them.
+        summary.objects_indexed = self.rebuild_coin_type_index();
+        
         tracing::info!(
             subnets = summary.subnets_recovered,
             leaves = summary.total_leaves,
+            indexed = summary.objects_indexed,
             anchor = self.current_anchor,
             "Recovery complete"
         );
@@ -705,6 +909,99 @@ impl GlobalStateManager {
         Ok(summary)
     }
 
+    /// Prune one subnet's persisted root history before `before_anchor`.
+    ///
+    /// Other subnets and the global roots are left intact, so proofs for any
+    /// anchor still retained by those stay verifiable. The subnet's latest
+    /// committed root is always kept, even when it predates `before_anchor`,
+    /// since it remains the subnet's current root. Returns the number of
+    /// roots removed.
+    pub fn prune_subnet(
+        &self,
+        subnet_id: SubnetId,
+        before_anchor: u64,
+    ) -> setu_merkle::MerkleResult<u64> {
+        let store = match &self.store {
+            Some(s) => s,
+            None => {
+                tracing::warn!("prune_subnet() called without storage backend");
+                return Ok(0);
+            }
+        };
+        
+        let cutoff = match store.get_latest_subnet_root(subnet_id.as_bytes())? {
+            Some((latest, _)) => before_anchor.min(latest),
+            None => return Ok(0),
+        };
+        let pruned = store.prune_subnet_before(subnet_id.as_bytes(), cutoff)?;
+        tracing::info!(?subnet_id, cutoff, pruned, "Pruned subnet root history");
+        Ok(pruned)
+    }
+
+    /// Export one subnet's committed state for import on another node.
+    ///
+    /// Reads the persisted leaves via `load_all_leaves` together with the
+    /// subnet's latest committed root, so uncommitted changes are not part of
+    /// the export. Requires a storage backend.
+    pub fn export_subnet(&self, subnet_id: SubnetId) -> setu_merkle::MerkleResult<SubnetExport> {
+        let store = self.store.as_ref().ok_or_else(|| {
+            setu_merkle::MerkleError::StorageError(
+                "export_subnet() requires a storage backend".to_string(),
+            )
+        })?;
+
+        let leaves = store.load_all_leaves(subnet_id.as_bytes())?;
+        let root = match store.get_latest_subnet_root(subnet_id.as_bytes())? {
+            Some((_, root)) => root,
+            // Never committed: only the empty tree is consistent with no leaves
+            None => SubnetStateSMT::new(subnet_id).root(),
+        };
+
+        let mut leaves: Vec<([u8; 32], Vec<u8>)> = leaves
+            .into_iter()
+            .map(|(object_id, value)| (*object_id.as_bytes(), value))
+            .collect();
+        leaves.sort_by(|a, b| a.0.cmp(&b.0));
+
+        tracing::info!(?subnet_id, leaves = leaves.len(), root = %root, "Exported subnet state");
+        Ok(SubnetExport { subnet_id, root, leaves })
+    }
+
+    /// Import a subnet exported by [`export_subnet`](Self::export_subnet).
+    ///
+    /// Rebuilds the subnet's SMT from the exported leaves and rejects the
+    /// import with `ConsistencyError` if the recomputed root differs from the
+    /// exported one. The target subnet must be empty. Imported leaves are
+    /// tracked as dirty, so the next `commit` persists them. Returns the
+    /// verified root.
+    pub fn import_subnet(&mut self, export: SubnetExport) -> setu_merkle::MerkleResult<HashValue> {
+        let subnet_id = export.subnet_id;
+        if self.subnet_states.get(&subnet_id).is_some_and(|smt| !smt.is_empty()) {
+            return Err(setu_merkle::MerkleError::InvalidInput(format!(
+                "subnet {:?} already has state",
+                subnet_id
+            )));
+        }
+
+        let mut smt = SubnetStateSMT::new(subnet_id);
+        for (object_id, value) in export.leaves {
+            smt.upsert_raw(object_id, value);
+        }
+        if smt.root() != export.root {
+            return Err(setu_merkle::MerkleError::ConsistencyError(format!(
+                "subnet {:?} import root {} does not match exported root {}",
+                subnet_id,
+                smt.root(),
+                export.root
+            )));
+        }
+
+        self.subnet_states.insert(subnet_id, smt);
+        self.rebuild_coin_type_index();
+        tracing::info!(?subnet_id, root = %export.root, "Imported subnet state");
+        Ok(export.root)
+    }
+
     /// Check if any subnet has uncommitted changes.
     pub fn has_pending_changes(&self) -> bool {
         self.subnet_states.values().any(|smt| smt.has_pending_changes())
@@ -886,6 +1183,23 @@ impl GlobalStateManager {
         (address_count, total_entries)
     }
 
+    /// Number of addresses holding at least one coin of `coin_type`.
+    ///
+    /// Derived from the owner object index, so deleted (tombstoned) coins stop
+    /// counting as soon as their delete is applied. Zero-balance coins that
+    /// were kept still count.
+    pub fn coin_holder_count(&self, coin_type: &str) -> usize {
+        self.owner_object_index
+            .values()
+            .filter(|objects| {
+                objects.iter().any(|(_, type_tag)| {
+                    type_tag == coin_type
+                        || extract_coin_type_from_tag(type_tag).as_deref() == Some(coin_type)
+                })
+            })
+            .count()
+    }
+
     // =========================================================================
     // Modification Tracking (object_id → last modifying event_id)
     // =========================================================================
@@ -914,7 +1228,11 @@ impl GlobalStateManager {
     /// ## Key Format Support
     /// 
     /// - `"oid:{hex}"`: Direct ObjectId hex (from TEE output) → decode directly
-    /// - Other formats: Hash the key to create a 32-byte ObjectId (legacy)
+    /// - Known metadata namespaces (`"mod:"`, `"user:"`, ...): hash the key
+    /// - Anything else: `StateApplyError::InvalidKeyFormat`, nothing applied
+    /// 
+    /// A new value larger than `max_object_bytes` is rejected with
+    /// `StateApplyError::ObjectTooLarge`.
     /// 
     /// The "oid:" prefix allows TEE outputs to specify exact SMT keys,
     /// ensuring state changes are applied to the correct objects.
@@ -922,7 +1240,7 @@ impl GlobalStateManager {
         &mut self,
         subnet_id: SubnetId,
         change: &StateChange,
-    ) -> ApplyResult {
+    ) -> Result<ApplyResult, StateApplyError> {
         // DIAG (H3 probe): fires if a write reaches this function outside the
         // authoritative `apply_committed_events` call graph. Any hit is a
         // candidate root-cause for `consensus-root-self-consistency` — the
@@ -941,9 +1259,12 @@ impl GlobalStateManager {
             }
         }
 
-        let object_id = Self::parse_state_change_key(&change.key);
+        let object_id = Self::try_parse_state_change_key(&change.key)?;
+        if let Some(value) = &change.new_value {
+            self.check_object_size(&object_id, value)?;
+        }
         
-        match &change.new_value {
+        let result = match &change.new_value {
             Some(value) => {
                 // Insert or update — SMT operation first, then index updates
                 let root = {
@@ -987,7 +1308,8 @@ impl GlobalStateManager {
                     existed,
                 }
             }
-        }
+        };
+        Ok(result)
     }
     
     /// Generalized index update — supports both ObjectEnvelope and legacy CoinState.
@@ -1060,17 +1382,18 @@ impl GlobalStateManager {
 
     /// Apply all state changes from an ExecutionResult to a subnet
     ///
-    /// Returns the new subnet root after applying all changes.
+    /// Returns the new subnet root after applying all changes. Stops at the
+    /// first change with a malformed key; earlier changes stay applied.
     pub fn apply_execution_result(
         &mut self,
         subnet_id: SubnetId,
         result: &ExecutionResult,
-    ) -> [u8; 32] {
+    ) -> Result<[u8; 32], StateApplyError> {
         for change in &result.state_changes {
             let target = change.target_subnet.unwrap_or(subnet_id);
-            self.apply_state_change(target, change);
+            self.apply_state_change(target, change)?;
         }
-        self.get_subnet_mut(subnet_id).root_bytes()
+        Ok(self.get_subnet_mut(subnet_id).root_bytes())
     }
     
     /// Apply all committed events' execution results to the state
@@ -1121,18 +1444,9 @@ impl GlobalStateManager {
 
         let mut summary = StateApplySummary::new();
         
-        // Sort events by VLC for deterministic ordering
+        // Sort events canonically for deterministic ordering
         let mut sorted_events = events.to_vec();
-        sorted_events.sort_by(|a, b| {
-            // Primary sort by VLC logical_time
-            match a.vlc_snapshot.logical_time.cmp(&b.vlc_snapshot.logical_time) {
-                std::cmp::Ordering::Equal => {
-                    // Tie-breaker: sort by event_id lexicographically
-                    a.id.cmp(&b.id)
-                }
-                other => other,
-            }
-        });
+        setu_types::sort_events_canonically(&mut sorted_events);
         
         'event_loop: for event in &sorted_events {
             let subnet_id = event.get_subnet_id();
@@ -1161,7 +1475,32 @@ impl GlobalStateManager {
                 let mut pending_writes: HashMap<(SubnetId, HashValue), Option<Vec<u8>>> = HashMap::new();
                 for change in &result.state_changes {
                     let target = change.target_subnet.unwrap_or(subnet_id);
-                    let object_id = Self::parse_state_change_key(&change.key);
+                    // Validate every key before anything is applied so a
+                    // malformed key rejects the whole event, not a suffix of it.
+                    let object_id = match Self::try_parse_state_change_key(&change.key) {
+                        Ok(object_id) => object_id,
+                        Err(e) => {
+                            tracing::error!(
+                                event_id = %event.id,
+                                error = %e,
+                                "Malformed state change key, skipping event"
+                            );
+                            summary.failed_events.push(event.id.clone());
+                            continue 'event_loop;
+                        }
+                    };
+                    // Same for oversized values
+                    if let Some(value) = &change.new_value {
+                        if let Err(e) = self.check_object_size(&object_id, value) {
+                            tracing::error!(
+                                event_id = %event.id,
+                                error = %e,
+                                "Oversized object in state change, skipping event"
+                            );
+                            summary.failed_events.push(event.id.clone());
+                            continue 'event_loop;
+                        }
+                    }
 
                     if let Some(ref expected_old) = change.old_value {
                         // Check pending_writes first (prior change within same event),
@@ -1241,7 +1580,15 @@ impl GlobalStateManager {
                 let changes_count = result.state_changes.len();
                 
                 // Apply all state changes for this event
-                let new_root = self.apply_execution_result(subnet_id, result);
+                // Keys were validated above, so this cannot fail part-way
+                let new_root = match self.apply_execution_result(subnet_id, result) {
+                    Ok(root) => root,
+                    Err(e) => {
+                        tracing::error!(event_id = %event.id, error = %e, "Failed to apply event state changes");
+                        summary.failed_events.push(event.id.clone());
+                        continue;
+                    }
+                };
                 
                 // Update modification_tracker: record event_id for each modified object
                 for change in &result.state_changes {
@@ -1280,6 +1627,17 @@ impl GlobalStateManager {
             watcher.notify_objects(oids);
         }
 
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        for (subnet_id, stats) in &summary.subnet_stats {
+            self.subnet_event_counts
+                .entry(*subnet_id)
+                .or_default()
+                .record(stats.event_count as u64, now_ms);
+        }
+
         // Phase 4 audit probe (design.md §6.1 item 2): exit half. Log the
         // post-apply root so pre/post pairs can be compared across every
         // call site, not just DAG-BFT leader/follower paths.
@@ -1300,6 +1658,11 @@ impl GlobalStateManager {
         summary
     }
     
+    /// Committed event counter for a subnet, if it has committed any events
+    pub fn subnet_event_counter(&self, subnet_id: &SubnetId) -> Option<&SubnetEventCounter> {
+        self.subnet_event_counts.get(subnet_id)
+    }
+
     /// Apply events and compute final anchor merkle roots
     ///
     /// This is the complete flow for Anchor creation:
@@ -1351,48 +1714,58 @@ impl GlobalStateManager {
     /// MUST use the exact same hashing logic as `apply_committed_events` so the
     /// two layers agree on what "same key" means. Do not reduce visibility.
     pub fn parse_state_change_key(key: &str) -> HashValue {
-        if let Some(hex_str) = key.strip_prefix("oid:") {
-            if let Ok(bytes) = hex::decode(hex_str) {
-                if bytes.len() == 32 {
-                    return HashValue::from_slice(&bytes).expect("32 bytes");
-                }
+        match Self::try_parse_state_change_key(key) {
+            Ok(object_id) => object_id,
+            Err(e) => {
+                // Malformed key — this is a bug in the calling code
+                tracing::error!(key = %key, error = %e, "Invalid state change key");
+                // Fallback: use BLAKE3 hash to produce a deterministic value
+                // This path should never be hit in correct code after key format unification
+                debug_assert!(
+                    false,
+                    "parse_state_change_key: unexpected key format '{}'. All keys should use known prefixes.",
+                    key
+                );
+                let hash = setu_types::hash_utils::setu_hash(key.as_bytes());
+                HashValue::from_slice(&hash).expect("32 bytes")
             }
-            // Invalid hex under oid: prefix — log error but return a deterministic value
-            tracing::error!(
-                key = %key,
-                "Invalid oid: format — hex decode failed or wrong length"
-            );
-        } else if key.starts_with("mod:") {
-            // Module bytecode key: "mod:{hex_addr}::{module_name}"
-            // Hash with BLAKE3 — consistent with MerkleStateProvider::get_raw_data()
-            let hash = setu_types::hash_utils::setu_hash(key.as_bytes());
-            return HashValue::from_slice(&hash).expect("32 bytes");
-        } else if key.starts_with("user:") || key.starts_with("solver:") || key.starts_with("validator:") || key.starts_with("event:") || key.starts_with("linkage:") {
-            // Known non-object metadata key prefixes.
-            // These don't have a native 32-byte ObjectId, so we hash the key.
-            // "event:" keys are produced by MockTeeEnclave::record_event_processed()
-            // to track processed events in the state tree.
-            // "linkage:" keys (B5 / Phase 8) hold per-package latest+history
-            // pointers for the fresh-address upgrade path; both `linkage:latest:`
-            // and `linkage:hist:` flavours land here.
-            let hash = setu_types::hash_utils::setu_hash(key.as_bytes());
-            return HashValue::from_slice(&hash).expect("32 bytes");
-        } else {
-            // Unknown prefix — this is a bug in the calling code
-            tracing::error!(
-                key = %key,
-                "Unknown key prefix — expected 'oid:', 'user:', 'solver:', 'validator:', 'event:', 'linkage:', or 'mod:' prefix."
-            );
         }
-        // Fallback: use BLAKE3 hash to produce a deterministic value
-        // This path should never be hit in correct code after key format unification
-        debug_assert!(
-            false,
-            "parse_state_change_key: unexpected key format '{}'. All keys should use known prefixes.",
-            key
-        );
-        let hash = setu_types::hash_utils::setu_hash(key.as_bytes());
-        HashValue::from_slice(&hash).expect("32 bytes")
+    }
+
+    /// Strict form of `parse_state_change_key`: validates the key namespace.
+    ///
+    /// - `"oid:{hex}"`: must be exactly 32 hex-encoded bytes, used as-is.
+    /// - `"mod:"`, `"user:"`, `"solver:"`, `"validator:"`, `"event:"`,
+    ///   `"linkage:"`: metadata keys without a native ObjectId; the whole key
+    ///   is hashed. `"event:"` keys come from
+    ///   `MockTeeEnclave::record_event_processed()`, `"linkage:"` keys (B5 /
+    ///   Phase 8) from the fresh-address upgrade path.
+    ///
+    /// New namespaces (e.g. `"obj:"`) must be added here before any producer
+    /// emits them. Anything else is `StateApplyError::InvalidKeyFormat`.
+    pub fn try_parse_state_change_key(key: &str) -> Result<HashValue, StateApplyError> {
+        let (namespace, rest) = key.split_once(':').ok_or_else(|| {
+            StateApplyError::InvalidKeyFormat(format!("'{}' has no namespace prefix", key))
+        })?;
+        match namespace {
+            "oid" => hex::decode(rest)
+                .ok()
+                .and_then(|bytes| HashValue::from_slice(&bytes).ok())
+                .ok_or_else(|| {
+                    StateApplyError::InvalidKeyFormat(format!(
+                        "'{}' is not 'oid:' followed by 32 hex-encoded bytes",
+                        key
+                    ))
+                }),
+            "mod" | "user" | "solver" | "validator" | "event" | "linkage" => {
+                let hash = setu_types::hash_utils::setu_hash(key.as_bytes());
+                Ok(HashValue::from_slice(&hash).expect("32 bytes"))
+            }
+            _ => Err(StateApplyError::InvalidKeyFormat(format!(
+                "'{}' has unknown namespace '{}:'",
+                key, namespace
+            ))),
+        }
     }
 }
 
@@ -1429,6 +1802,14 @@ pub enum ApplyResult {
 pub enum StateApplyError {
     CommitFailed(String),
     InvalidStateChange(String),
+    /// `StateChange.key` does not use a recognized namespace prefix
+    InvalidKeyFormat(String),
+    /// Object value exceeds the configured `max_object_bytes`
+    ObjectTooLarge {
+        object_id: [u8; 32],
+        size: usize,
+        max: usize,
+    },
 }
 
 impl std::fmt::Display for StateApplyError {
@@ -1436,12 +1817,31 @@ impl std::fmt::Display for StateApplyError {
         match self {
             StateApplyError::CommitFailed(msg) => write!(f, "Commit failed: {}", msg),
             StateApplyError::InvalidStateChange(msg) => write!(f, "Invalid state change: {}", msg),
+            StateApplyError::InvalidKeyFormat(msg) => write!(f, "Invalid state change key: {}", msg),
+            StateApplyError::ObjectTooLarge { object_id, size, max } => write!(
+                f,
+                "Object {} is {} bytes, exceeding the {}-byte limit",
+                hex::encode(object_id),
+                size,
+                max
+            ),
         }
     }
 }
 
 impl std::error::Error for StateApplyError {}
 
+/// One subnet's committed key-value state and root, produced by
+/// `GlobalStateManager::export_subnet`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubnetExport {
+    pub subnet_id: SubnetId,
+    /// Latest committed subnet root
+    pub root: HashValue,
+    /// (object_id, value) pairs, sorted by object_id
+    pub leaves: Vec<([u8; 32], Vec<u8>)>,
+}
+
 /// Summary of B4 recovery operation
 #[derive(Debug, Clone, Default)]
 pub struct RecoverySummary {
@@ -1451,6 +1851,8 @@ pub struct RecoverySummary {
     pub total_leaves: usize,
     /// Number of subnets with root hash mismatches (data still recovered)
     pub root_mismatches: usize,
+    /// Number of objects added to the owner/coin-type indexes
+    pub objects_indexed: usize,
 }
 
 /// Summary of state changes applied during anchor processing
@@ -1458,7 +1860,7 @@ pub struct RecoverySummary {
 pub struct StateApplySummary {
     /// Changes per subnet: (event_count, total_changes, final_root)
     pub subnet_stats: HashMap<SubnetId, SubnetApplyStats>,
-    /// Events that failed execution (skipped)
+    /// Events that failed execution or carry malformed state change keys (skipped)
     pub failed_events: Vec<String>,
     /// Events rejected due to stale read (old_value mismatch with current state).
     /// R5: each record carries the first conflicting object key ("oid:{hex}", G11)
@@ -1516,6 +1918,58 @@ impl StateApplySummary {
     }
 }
 
+/// Length of the window `SubnetEventCounter` estimates throughput over
+const THROUGHPUT_WINDOW_MS: u64 = 60_000;
+/// Granularity of the throughput window
+const THROUGHPUT_BUCKET_MS: u64 = 1_000;
+
+/// Running count of committed events for one subnet, with a rolling
+/// one-minute window for throughput estimates.
+#[derive(Debug, Clone, Default)]
+pub struct SubnetEventCounter {
+    total: u64,
+    /// (bucket start ms, events) in ascending bucket order
+    buckets: VecDeque<(u64, u64)>,
+}
+
+impl SubnetEventCounter {
+    /// Record `count` events committed at `now_ms`
+    pub fn record(&mut self, count: u64, now_ms: u64) {
+        if count == 0 {
+            return;
+        }
+        self.total += count;
+        let bucket = now_ms - now_ms % THROUGHPUT_BUCKET_MS;
+        match self.buckets.back_mut() {
+            Some((start, events)) if *start >= bucket => *events += count,
+            _ => self.buckets.push_back((bucket, count)),
+        }
+        self.prune(now_ms);
+    }
+
+    /// Events committed since startup
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Events committed in the minute up to `now_ms`
+    pub fn events_per_minute(&self, now_ms: u64) -> u64 {
+        let cutoff = now_ms.saturating_sub(THROUGHPUT_WINDOW_MS);
+        self.buckets
+            .iter()
+            .filter(|(start, _)| *start >= cutoff)
+            .map(|(_, events)| events)
+            .sum()
+    }
+
+    fn prune(&mut self, now_ms: u64) {
+        let cutoff = now_ms.saturating_sub(THROUGHPUT_WINDOW_MS);
+        while self.buckets.front().is_some_and(|(start, _)| *start < cutoff) {
+            self.buckets.pop_front();
+        }
+    }
+}
+
 impl Default for GlobalStateManager {
     fn default() -> Self {
         Self::new()
@@ -1568,11 +2022,11 @@ mod tests {
         // Add object to ROOT subnet
         let object_id = [1u8; 32];
         let value = vec![2u8; 32];
-        manager.upsert_object(SubnetId::ROOT, object_id, value);
+        manager.upsert_object(SubnetId::ROOT, object_id, value).unwrap();
         
         // Create app subnet
         let app_subnet = SubnetId::from_str_id("my-app");
-        manager.upsert_object(app_subnet, [3u8; 32], vec![4u8; 32]);
+        manager.upsert_object(app_subnet, [3u8; 32], vec![4u8; 32]).unwrap();
         
         assert_eq!(manager.subnet_count(), 3); // ROOT + GOVERNANCE + app
         
@@ -1593,6 +2047,42 @@ mod tests {
         assert_eq!(anchor_roots.subnet_roots.len(), 3);
     }
     
+    #[test]
+    fn test_incremental_global_root_matches_full_recompute() {
+        let mut manager = GlobalStateManager::new();
+        // ROOT + GOVERNANCE + 8 app subnets = 10
+        let app_subnets: Vec<SubnetId> = (1..=8u8)
+            .map(|i| SubnetId::from_str_id(&format!("incremental-{}", i)))
+            .collect();
+        for (i, subnet) in app_subnets.iter().enumerate() {
+            manager.upsert_object(*subnet, [i as u8 + 1; 32], vec![i as u8; 8]).unwrap();
+        }
+        assert_eq!(manager.subnet_count(), 10);
+
+        let full_recompute = |manager: &GlobalStateManager| {
+            let (_, roots) = manager.compute_global_root();
+            SubnetAggregationTree::build(
+                roots
+                    .iter()
+                    .map(|(id, root)| setu_merkle::SubnetStateEntry::new(*id.as_bytes(), *root))
+                    .collect(),
+            )
+            .root()
+        };
+
+        let (before, _) = manager.compute_global_root();
+        assert_eq!(before, full_recompute(&manager));
+
+        // Change one subnet's root; the cached tree rehashes only that leaf
+        manager.upsert_object(app_subnets[3], [0xEE; 32], b"changed".to_vec()).unwrap();
+        let (after, roots) = manager.compute_global_root();
+        assert_ne!(after, before);
+        assert_eq!(roots[&app_subnets[3]], manager.get_subnet_root(&app_subnets[3]).unwrap());
+        assert_eq!(after, full_recompute(&manager));
+        // A clone starting from the warm cache agrees too
+        assert_eq!(manager.clone().compute_global_root().0, after);
+    }
+
     #[test]
     fn test_cannot_remove_root_subnet() {
         let mut manager = GlobalStateManager::new();
@@ -1674,7 +2164,7 @@ mod tests {
         let bytecode = vec![0xCA, 0xFE, 0xBA, 0xBE];
         let sc = StateChange::insert("mod:0xdead::counter".to_string(), bytecode.clone());
 
-        manager.apply_state_change(SubnetId::ROOT, &sc);
+        manager.apply_state_change(SubnetId::ROOT, &sc).unwrap();
 
         // Verify the data was inserted into the ROOT SMT
         let expected_hash = setu_types::hash_utils::setu_hash(b"mod:0xdead::counter");
@@ -1683,6 +2173,222 @@ mod tests {
         assert_eq!(smt.get(&hash_value), Some(&bytecode));
     }
     
+    #[test]
+    fn test_apply_state_change_oid_key() {
+        use setu_types::event::StateChange;
+
+        let mut manager = GlobalStateManager::new();
+        let object_id = [0x42u8; 32];
+        let sc = StateChange::insert(format!("oid:{}", hex::encode(object_id)), vec![1, 2, 3]);
+
+        let result = manager.apply_state_change(SubnetId::ROOT, &sc).unwrap();
+        assert!(matches!(result, ApplyResult::Updated { object_id: id, .. } if id == object_id));
+        let hash_value = HashValue::from_slice(&object_id).unwrap();
+        assert_eq!(manager.root_subnet().get(&hash_value), Some(&vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_apply_state_change_rejects_malformed_keys() {
+        use setu_types::event::StateChange;
+
+        let mut manager = GlobalStateManager::new();
+        for key in ["coin:alice", "no-namespace", "oid:not-valid-hex", "oid:abcd"] {
+            let sc = StateChange::insert(key.to_string(), vec![1]);
+            let result = manager.apply_state_change(SubnetId::ROOT, &sc);
+            assert!(
+                matches!(result, Err(StateApplyError::InvalidKeyFormat(_))),
+                "key {} should be rejected",
+                key
+            );
+        }
+        assert_eq!(manager.root_subnet().object_count(), 0);
+    }
+
+    #[test]
+    fn test_object_size_cap_on_writes() {
+        use setu_types::event::StateChange;
+
+        let mut manager = GlobalStateManager::new();
+        manager.set_max_object_bytes(64);
+
+        // At the limit: accepted
+        manager.upsert_object(SubnetId::ROOT, [0x01; 32], vec![0u8; 64]).unwrap();
+        let sc = StateChange::insert(format!("oid:{}", hex::encode([0x02u8; 32])), vec![0u8; 64]);
+        manager.apply_state_change(SubnetId::ROOT, &sc).unwrap();
+        assert_eq!(manager.root_subnet().object_count(), 2);
+        let root_before = manager.root_subnet().root();
+
+        // One byte over: rejected, nothing written
+        let result = manager.upsert_object(SubnetId::ROOT, [0x03; 32], vec![0u8; 65]);
+        assert!(matches!(
+            result,
+            Err(StateApplyError::ObjectTooLarge { size: 65, max: 64, object_id }) if object_id == [0x03; 32]
+        ));
+        let sc = StateChange::insert(format!("oid:{}", hex::encode([0x02u8; 32])), vec![0u8; 65]);
+        assert!(matches!(
+            manager.apply_state_change(SubnetId::ROOT, &sc),
+            Err(StateApplyError::ObjectTooLarge { size: 65, max: 64, .. })
+        ));
+        assert_eq!(manager.root_subnet().object_count(), 2);
+        assert_eq!(manager.root_subnet().root(), root_before);
+    }
+
+    #[test]
+    fn test_prune_subnet_keeps_other_subnet_history() {
+        use setu_merkle::storage::{InMemoryMerkleStore, MerkleRootStore};
+
+        let store = Arc::new(InMemoryMerkleStore::new());
+        let mut manager = GlobalStateManager::with_store(store.clone());
+        let dormant = SubnetId::new_app_simple(21);
+        let active = SubnetId::new_app_simple(22);
+
+        manager.upsert_object(dormant, [1u8; 32], vec![1]).unwrap();
+        for anchor in 1..=3u64 {
+            manager.upsert_object(active, [anchor as u8; 32], vec![anchor as u8]).unwrap();
+            manager.commit(anchor).unwrap();
+        }
+        let dormant_root = store.get_subnet_root(dormant.as_bytes(), 1).unwrap().unwrap();
+
+        let pruned = manager.prune_subnet(dormant, 3).unwrap();
+        assert_eq!(pruned, 2);
+
+        // Pruned subnet keeps only its current root
+        assert!(store.get_subnet_root(dormant.as_bytes(), 1).unwrap().is_none());
+        assert!(store.get_subnet_root(dormant.as_bytes(), 2).unwrap().is_none());
+        assert_eq!(store.get_subnet_root(dormant.as_bytes(), 3).unwrap(), Some(dormant_root));
+
+        // The other subnet and the global roots are untouched
+        for anchor in 1..=3u64 {
+            assert!(store.get_subnet_root(active.as_bytes(), anchor).unwrap().is_some());
+            assert!(store.get_global_root(anchor).unwrap().is_some());
+        }
+
+        // Never prunes past the subnet's latest root
+        assert_eq!(manager.prune_subnet(dormant, 100).unwrap(), 0);
+        assert_eq!(store.get_subnet_root(dormant.as_bytes(), 3).unwrap(), Some(dormant_root));
+    }
+
+    #[test]
+    fn test_export_import_subnet_round_trip() {
+        use setu_merkle::storage::InMemoryMerkleStore;
+
+        let mut source = GlobalStateManager::with_store(Arc::new(InMemoryMerkleStore::new()));
+        let subnet = SubnetId::new_app_simple(31);
+        for i in 1..=20u8 {
+            source.upsert_object(subnet, [i; 32], vec![i; i as usize]).unwrap();
+        }
+        source.upsert_object(SubnetId::ROOT, [0xAA; 32], vec![1]).unwrap();
+        source.commit(1).unwrap();
+        // Uncommitted writes are not exported
+        source.upsert_object(subnet, [0xFF; 32], vec![0xFF]).unwrap();
+
+        let export = source.export_subnet(subnet).unwrap();
+        assert_eq!(export.leaves.len(), 20);
+        let committed_root = export.root;
+
+        let target_store = Arc::new(InMemoryMerkleStore::new());
+        let mut target = GlobalStateManager::with_store(target_store.clone());
+        assert_eq!(target.import_subnet(export.clone()).unwrap(), committed_root);
+        assert_eq!(target.get_subnet_root(&subnet), Some(committed_root));
+        assert_eq!(target.get_subnet(&subnet).unwrap().object_count(), 20);
+        assert!(target.get_subnet(&SubnetId::ROOT).unwrap().is_empty());
+
+        // Imported leaves are persisted by the next commit
+        target.commit(1).unwrap();
+        assert_eq!(target.export_subnet(subnet).unwrap().leaves, export.leaves);
+
+        // Tampered leaves fail root verification and leave nothing behind
+        let mut tampered = export.clone();
+        tampered.leaves[0].1.push(0);
+        let mut fresh = GlobalStateManager::new();
+        assert!(matches!(
+            fresh.import_subnet(tampered),
+            Err(setu_merkle::MerkleError::ConsistencyError(_))
+        ));
+        assert!(!fresh.has_subnet(&subnet));
+
+        // Importing over existing state is refused
+        assert!(matches!(
+            target.import_subnet(export),
+            Err(setu_merkle::MerkleError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_apply_committed_events_skips_event_with_malformed_key() {
+        use setu_types::event::{Event, EventType, ExecutionResult, StateChange, VLCSnapshot};
+
+        let mut manager = GlobalStateManager::new();
+        let valid = StateChange::insert(format!("oid:{}", "11".repeat(32)), vec![1]);
+        let malformed = StateChange::insert("coin:alice".to_string(), vec![2]);
+
+        let mut event = Event::new(EventType::Transfer, vec![], VLCSnapshot::default(), "solver".to_string());
+        event.set_execution_result(ExecutionResult {
+            success: true,
+            message: None,
+            state_changes: vec![valid, malformed],
+        });
+
+        let summary = manager.apply_committed_events(&[event.clone()]);
+        assert_eq!(summary.failed_events, vec![event.id]);
+        assert_eq!(summary.total_events, 0);
+        // The valid change in the same event was not applied either
+        assert_eq!(manager.root_subnet().object_count(), 0);
+    }
+
+    #[test]
+    fn test_apply_committed_events_counts_events_per_subnet() {
+        use setu_types::event::{Event, EventType, ExecutionResult, StateChange, VLCSnapshot};
+
+        let app_a = SubnetId::new_app_simple(31);
+        let app_b = SubnetId::new_app_simple(32);
+        let committed = |subnet: SubnetId, key_byte: u8, success: bool| {
+            let mut event = Event::new(EventType::Transfer, vec![], VLCSnapshot::default(), "solver".to_string())
+                .with_subnet(subnet);
+            event.set_execution_result(ExecutionResult {
+                success,
+                message: None,
+                state_changes: vec![StateChange::insert(
+                    format!("oid:{}", hex::encode([key_byte; 32])),
+                    vec![key_byte],
+                )],
+            });
+            event
+        };
+
+        let mut manager = GlobalStateManager::new();
+        manager.apply_committed_events(&[
+            committed(app_a, 1, true),
+            committed(app_a, 2, true),
+            committed(app_b, 3, true),
+            // Failed executions are not counted
+            committed(app_b, 4, false),
+        ]);
+        manager.apply_committed_events(&[committed(app_a, 5, true)]);
+
+        assert_eq!(manager.subnet_event_counter(&app_a).unwrap().total(), 3);
+        assert_eq!(manager.subnet_event_counter(&app_b).unwrap().total(), 1);
+        assert!(manager.subnet_event_counter(&SubnetId::ROOT).is_none());
+
+        // Read snapshots serve the counters; throw-away clones do not
+        let snapshot = manager.clone_for_read_snapshot();
+        assert_eq!(snapshot.subnet_event_counter(&app_a).unwrap().total(), 3);
+        assert!(manager.clone().subnet_event_counter(&app_a).is_none());
+    }
+
+    #[test]
+    fn test_subnet_event_counter_rolling_window() {
+        let mut counter = SubnetEventCounter::default();
+        counter.record(4, 10_000);
+        counter.record(6, 40_500);
+        assert_eq!(counter.events_per_minute(41_000), 10);
+        // The first bucket falls out of the window; the total is kept
+        assert_eq!(counter.events_per_minute(75_000), 6);
+        counter.record(1, 120_000);
+        assert_eq!(counter.events_per_minute(120_000), 1);
+        assert_eq!(counter.total(), 11);
+    }
+
     #[test]
     fn test_apply_committed_events_conflict_detection() {
         use setu_types::event::{Event, EventType, ExecutionResult, StateChange, VLCSnapshot};
@@ -1693,7 +2399,7 @@ mod tests {
         let coin_bytes = [0xAA; 32];
         let coin_key = format!("oid:{}", hex::encode(coin_bytes));
         let initial_value = vec![1u8; 64]; // balance = 1000 (conceptual)
-        manager.upsert_object(SubnetId::ROOT, coin_bytes, initial_value.clone());
+        manager.upsert_object(SubnetId::ROOT, coin_bytes, initial_value.clone()).unwrap();
         
         // Create two events that both read the SAME initial state (double-spend)
         let new_value_t1 = vec![2u8; 64]; // balance = 500 after T1
@@ -1779,8 +2485,8 @@ mod tests {
         let value_a = vec![1u8; 64];
         let value_b = vec![2u8; 64];
         
-        manager.upsert_object(SubnetId::ROOT, coin_a_bytes, value_a.clone());
-        manager.upsert_object(SubnetId::ROOT, coin_b_bytes, value_b.clone());
+        manager.upsert_object(SubnetId::ROOT, coin_a_bytes, value_a.clone()).unwrap();
+        manager.upsert_object(SubnetId::ROOT, coin_b_bytes, value_b.clone()).unwrap();
         
         let new_a = vec![10u8; 64];
         let new_b = vec![20u8; 64];
@@ -1874,7 +2580,7 @@ mod tests {
         let key = format!("oid:{}", hex::encode(shared_oid));
         // Simulate an initial envelope-bytes blob at version v=1
         let env_v1 = vec![0xE1; 80];
-        manager.upsert_object(SubnetId::ROOT, shared_oid, env_v1.clone());
+        manager.upsert_object(SubnetId::ROOT, shared_oid, env_v1.clone()).unwrap();
 
         // Two concurrent MoveCall events that both read env_v1 and try to
         // produce an updated envelope. After T1 applies, the SMT value moves
@@ -1928,8 +2634,8 @@ mod tests {
         let key_b = format!("oid:{}", hex::encode(shared_b));
         let env_a = vec![0x01; 48];
         let env_b = vec![0x02; 48];
-        manager.upsert_object(SubnetId::ROOT, shared_a, env_a.clone());
-        manager.upsert_object(SubnetId::ROOT, shared_b, env_b.clone());
+        manager.upsert_object(SubnetId::ROOT, shared_a, env_a.clone()).unwrap();
+        manager.upsert_object(SubnetId::ROOT, shared_b, env_b.clone()).unwrap();
 
         let new_a = vec![0x11; 48];
         let new_b = vec![0x22; 48];
@@ -2019,7 +2725,7 @@ mod tests {
         let value = make_coin_envelope(alice, 1000, "ROOT");
         
         let sc = StateChange::insert(key, value);
-        manager.apply_state_change(SubnetId::ROOT, &sc);
+        manager.apply_state_change(SubnetId::ROOT, &sc).unwrap();
         
         // owner_object_index should have alice's entry
         let objects = manager.get_coin_objects_for_address(&alice.to_string());
@@ -2043,7 +2749,7 @@ mod tests {
         let value = make_custom_envelope(alice, "0xcafe::game::Sword");
         
         let sc = StateChange::insert(key, value);
-        manager.apply_state_change(SubnetId::ROOT, &sc);
+        manager.apply_state_change(SubnetId::ROOT, &sc).unwrap();
         
         // owner_object_index should have alice's entry with the custom type_tag
         let objects = manager.get_coin_objects_for_address(&alice.to_string());
@@ -2068,7 +2774,7 @@ mod tests {
         let value = cs.to_bytes();
         
         let sc = StateChange::insert(key, value);
-        manager.apply_state_change(SubnetId::ROOT, &sc);
+        manager.apply_state_change(SubnetId::ROOT, &sc).unwrap();
         
         // owner_object_index should have entry
         let objects = manager.get_coin_objects_for_address(&alice_hex);
@@ -2092,7 +2798,7 @@ mod tests {
         
         // Insert first
         let sc_insert = StateChange::insert(key.clone(), value.clone());
-        manager.apply_state_change(SubnetId::ROOT, &sc_insert);
+        manager.apply_state_change(SubnetId::ROOT, &sc_insert).unwrap();
         assert_eq!(manager.get_coin_objects_for_address(&alice.to_string()).len(), 1);
         
         // Delete with old_value provided
@@ -2102,12 +2808,49 @@ mod tests {
             new_value: None,
             target_subnet: None,
         };
-        manager.apply_state_change(SubnetId::ROOT, &sc_delete);
+        manager.apply_state_change(SubnetId::ROOT, &sc_delete).unwrap();
         
         // owner_object_index should be empty for alice
         assert!(manager.get_coin_objects_for_address(&alice.to_string()).is_empty());
     }
     
+    #[test]
+    fn test_tombstoned_coin_leaves_holders_and_proves_absent() {
+        use setu_types::event::StateChange;
+        let mut manager = GlobalStateManager::new();
+        
+        let alice = setu_types::Address::from_str_id("alice");
+        let bob = setu_types::Address::from_str_id("bob");
+        let drained_id = [0xD0; 32];
+        let drained_key = format!("oid:{}", hex::encode(drained_id));
+        let drained = make_coin_envelope(alice, 1000, "ROOT");
+        
+        manager.apply_state_change(SubnetId::ROOT, &StateChange::insert(drained_key.clone(), drained.clone())).unwrap();
+        manager.apply_state_change(
+            SubnetId::ROOT,
+            &StateChange::insert(format!("oid:{}", hex::encode([0xB0; 32])), make_coin_envelope(bob, 1000, "ROOT")),
+        ).unwrap();
+        assert_eq!(manager.coin_holder_count("ROOT"), 2);
+        
+        // Draining transfer under the tombstone policy: the coin is deleted
+        let tombstone = StateChange {
+            key: drained_key,
+            old_value: Some(drained),
+            new_value: None,
+            target_subnet: None,
+        };
+        manager.apply_state_change(SubnetId::ROOT, &tombstone).unwrap();
+        
+        assert_eq!(manager.coin_holder_count("ROOT"), 1);
+        assert!(manager.get_coin_objects_for_address(&alice.to_string()).is_empty());
+        
+        let smt = manager.get_subnet(&SubnetId::ROOT).unwrap();
+        let key = HashValue::from_slice(&drained_id).unwrap();
+        smt.prove(&key)
+            .verify_non_inclusion(&smt.root(), &key)
+            .expect("tombstoned coin must be provably absent");
+    }
+    
     #[test]
     fn test_apply_state_change_owner_transfer_envelope() {
         use setu_types::event::StateChange;
@@ -2123,12 +2866,12 @@ mod tests {
         
         // Insert as alice
         let sc_insert = StateChange::insert(key.clone(), old_value.clone());
-        manager.apply_state_change(SubnetId::ROOT, &sc_insert);
+        manager.apply_state_change(SubnetId::ROOT, &sc_insert).unwrap();
         assert_eq!(manager.get_coin_objects_for_address(&alice.to_string()).len(), 1);
         
         // Transfer to bob (update with old_value)
         let sc_transfer = StateChange::update(key, old_value, new_value);
-        manager.apply_state_change(SubnetId::ROOT, &sc_transfer);
+        manager.apply_state_change(SubnetId::ROOT, &sc_transfer).unwrap();
         
         // Alice should have no objects
         assert!(manager.get_coin_objects_for_address(&alice.to_string()).is_empty());
@@ -2147,15 +2890,15 @@ mod tests {
         
         // Insert a legacy CoinState directly into SMT
         let cs = CoinState::new(alice.to_string(), 500);
-        manager.upsert_object(SubnetId::ROOT, [0x01; 32], cs.to_bytes());
+        manager.upsert_object(SubnetId::ROOT, [0x01; 32], cs.to_bytes()).unwrap();
         
         // Insert an ObjectEnvelope directly into SMT
         let env_bytes = make_coin_envelope(bob, 2000, "ROOT");
-        manager.upsert_object(SubnetId::ROOT, [0x02; 32], env_bytes);
+        manager.upsert_object(SubnetId::ROOT, [0x02; 32], env_bytes).unwrap();
         
         // Insert a custom Move object envelope
         let custom_bytes = make_custom_envelope(alice, "0xcafe::nft::Token");
-        manager.upsert_object(SubnetId::ROOT, [0x03; 32], custom_bytes);
+        manager.upsert_object(SubnetId::ROOT, [0x03; 32], custom_bytes).unwrap();
         
         // Rebuild from scratch
         let count = manager.rebuild_coin_type_index();
@@ -2203,7 +2946,7 @@ mod tests {
         
         let bytecode = vec![0xCA, 0xFE, 0xBA, 0xBE];
         let sc = StateChange::insert("mod:0xdead::counter".to_string(), bytecode);
-        manager.apply_state_change(SubnetId::ROOT, &sc);
+        manager.apply_state_change(SubnetId::ROOT, &sc).unwrap();
         
         // No index entries should be created for mod: keys
         assert!(manager.owner_object_index.is_empty());
@@ -14,10 +14,10 @@ pub mod shared;
 pub mod speculative_overlay;
 pub mod version_watcher;
 
-pub use manager::{SubnetStateSMT, GlobalStateManager, StateApplySummary, StateApplyError, RecoverySummary, B4StoreExt, ConflictRecord};
+pub use manager::{SubnetStateSMT, GlobalStateManager, StateApplySummary, StateApplyError, RecoverySummary, B4StoreExt, ConflictRecord, SubnetEventCounter, SubnetExport, DeferredB4Commit, DEFAULT_MAX_OBJECT_BYTES};
 pub use provider::{
     StateProvider, MerkleStateProvider,
-    CoinInfo, CoinState, SimpleMerkleProof,
+    CoinInfo, CoinState, SimpleMerkleProof, SubnetStats, SubnetThroughput, ProvenObject, AddressStateDump,
     init_coin, init_coins_split, get_coin_state,
 };
 pub use batch_snapshot::{BatchStateSnapshot, BatchSnapshotStats};
@@ -24,9 +24,12 @@
 
 use crate::state::manager::GlobalStateManager;
 use crate::state::shared::SharedStateManager;
-use setu_merkle::{HashValue, SparseMerkleProof};
-use setu_types::{ObjectId, SubnetId};
-use std::collections::HashMap;
+use setu_merkle::{empty_subtree_hash, HashValue, SparseMerkleProof};
+use setu_types::{
+    coin_metadata_object_id, user_subnet_activity_object_id, CoinMetadata, ObjectId, SubnetId,
+    UserSubnetActivity,
+};
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, RwLock};
 use tracing::debug;
 
@@ -51,11 +54,64 @@ pub struct CoinInfo {
     pub coin_type: String,
 }
 
+/// Summary of one subnet's committed SMT
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubnetStats {
+    pub subnet_id: SubnetId,
+    /// Number of objects (leaves) in the subnet SMT
+    pub object_count: u64,
+    /// Current SMT root
+    pub state_root: [u8; 32],
+    /// Anchor that last committed changes to this subnet
+    pub last_anchor: u64,
+    /// Total balance of all coins of the subnet's own token
+    pub token_supply: u64,
+}
+
+/// Committed event throughput of one subnet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubnetThroughput {
+    pub subnet_id: SubnetId,
+    /// Events committed to the subnet since startup
+    pub event_count: u64,
+    /// Events committed in the last minute
+    pub events_per_minute: u64,
+}
+
+/// An object read from a committed subnet SMT, with its inclusion proof
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvenObject {
+    pub object_id: ObjectId,
+    pub subnet_id: SubnetId,
+    pub value: Vec<u8>,
+    /// Root of the subnet SMT the proof verifies against
+    pub subnet_root: [u8; 32],
+    pub proof: SimpleMerkleProof,
+    /// Event that last modified the object, if tracked
+    pub last_modified_by: Option<String>,
+}
+
+/// Everything the committed state holds on an address's coins and profile,
+/// read from one snapshot (see [`MerkleStateProvider::dump_address_state`])
+#[derive(Debug, Clone)]
+pub struct AddressStateDump {
+    /// Canonical hex address
+    pub address: String,
+    pub global_state_root: [u8; 32],
+    /// Subnet roots aggregated into `global_state_root`, for proving each
+    /// object's `subnet_root` under it
+    pub subnet_roots: HashMap<SubnetId, [u8; 32]>,
+    pub coins: Vec<(CoinInfo, ProvenObject)>,
+    pub profile: Option<ProvenObject>,
+}
+
 /// Merkle proof in a simple, serializable format
 /// 
 /// This is the format used for passing proofs between components.
 /// It's simpler than SparseMerkleProof and easily serializable.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+/// [`to_compact_bytes`](Self::to_compact_bytes) gives a denser encoding for
+/// cross-node transfer.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct SimpleMerkleProof {
     /// Sibling hashes on the path from leaf to root
     pub siblings: Vec<[u8; 32]>,
@@ -77,6 +133,115 @@ impl SimpleMerkleProof {
             exists: false,
         }
     }
+
+    /// Check that this proves `value` at `leaf_key` under `root`
+    pub fn verify_inclusion(&self, root: &[u8; 32], value: &[u8]) -> bool {
+        let key = HashValue::new(self.leaf_key);
+        let leaf = setu_merkle::sparse::SparseMerkleLeafNode {
+            key,
+            value_hash: setu_merkle::hash::hash_value(value),
+        };
+        let siblings = self.siblings.iter().map(|s| HashValue::new(*s)).collect();
+        SparseMerkleProof::new(siblings, Some(leaf))
+            .verify_inclusion(&HashValue::new(*root), &key, value)
+            .is_ok()
+    }
+
+    /// Encode the proof compactly for transfer between nodes.
+    ///
+    /// Layout (lengths little-endian):
+    /// `flags:u8 | leaf_key:[u8;32] | n_siblings:u16 | n_bits:u16 |
+    /// path bitfield | sibling presence bitmap | non-zero siblings`.
+    /// Bitfields are LSB-first and padded to whole bytes. Siblings equal to
+    /// the empty-subtree hash of their level are marked absent in the
+    /// presence bitmap and not written; sparse trees are mostly those.
+    pub fn to_compact_bytes(&self) -> Vec<u8> {
+        let n_siblings = u16::try_from(self.siblings.len()).expect("proof depth exceeds u16");
+        let n_bits = u16::try_from(self.path_bits.len()).expect("proof depth exceeds u16");
+        let present: Vec<bool> = self
+            .siblings
+            .iter()
+            .enumerate()
+            .map(|(level, s)| s != empty_subtree_hash(level).as_bytes())
+            .collect();
+
+        let mut out = Vec::with_capacity(
+            37 + bitfield_len(self.path_bits.len())
+                + bitfield_len(self.siblings.len())
+                + 32 * present.iter().filter(|p| **p).count(),
+        );
+        out.push(self.exists as u8);
+        out.extend_from_slice(&self.leaf_key);
+        out.extend_from_slice(&n_siblings.to_le_bytes());
+        out.extend_from_slice(&n_bits.to_le_bytes());
+        pack_bits(&self.path_bits, &mut out);
+        pack_bits(&present, &mut out);
+        for (sibling, _) in self.siblings.iter().zip(&present).filter(|(_, p)| **p) {
+            out.extend_from_slice(sibling);
+        }
+        out
+    }
+
+    /// Decode a proof produced by [`to_compact_bytes`](Self::to_compact_bytes).
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self, &'static str> {
+        let mut rest = bytes;
+        let mut take = |len: usize| -> Result<&[u8], &'static str> {
+            if rest.len() < len {
+                return Err("compact proof truncated");
+            }
+            let (head, tail) = rest.split_at(len);
+            rest = tail;
+            Ok(head)
+        };
+
+        let exists = match take(1)?[0] {
+            0 => false,
+            1 => true,
+            _ => return Err("invalid compact proof flags"),
+        };
+        let leaf_key: [u8; 32] = take(32)?.try_into().expect("length checked");
+        let n_siblings = u16::from_le_bytes(take(2)?.try_into().expect("length checked")) as usize;
+        let n_bits = u16::from_le_bytes(take(2)?.try_into().expect("length checked")) as usize;
+        let path_bits = unpack_bits(take(bitfield_len(n_bits))?, n_bits);
+        let present = unpack_bits(take(bitfield_len(n_siblings))?, n_siblings);
+        let siblings = present
+            .iter()
+            .enumerate()
+            .map(|(level, p)| match p {
+                true => Ok(take(32)?.try_into().expect("length checked")),
+                false => Ok(*empty_subtree_hash(level).as_bytes()),
+            })
+            .collect::<Result<Vec<[u8; 32]>, &'static str>>()?;
+        if !rest.is_empty() {
+            return Err("trailing bytes after compact proof");
+        }
+
+        Ok(Self {
+            siblings,
+            path_bits,
+            leaf_key,
+            exists,
+        })
+    }
+}
+
+fn bitfield_len(bits: usize) -> usize {
+    bits.div_ceil(8)
+}
+
+fn pack_bits(bits: &[bool], out: &mut Vec<u8>) {
+    for chunk in bits.chunks(8) {
+        out.push(
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0u8, |byte, (i, bit)| byte | ((*bit as u8) << i)),
+        );
+    }
+}
+
+fn unpack_bits(bytes: &[u8], len: usize) -> Vec<bool> {
+    (0..len).map(|i| bytes[i / 8] & (1 << (i % 8)) != 0).collect()
 }
 
 // ============================================================================
@@ -107,6 +272,17 @@ pub trait StateProvider: Send + Sync {
             .collect()
     }
 
+    /// Get coins owned by an address whose coin type lives in `subnet_id`.
+    ///
+    /// Coin types are mapped with [`SubnetId::from_coin_type`], so both the
+    /// raw subnet name ("gaming-subnet") and its hex form select the same coins.
+    fn get_coins_for_address_in_subnet(&self, address: &str, subnet_id: &SubnetId) -> Vec<CoinInfo> {
+        self.get_coins_for_address(address)
+            .into_iter()
+            .filter(|c| SubnetId::from_coin_type(&c.coin_type) == *subnet_id)
+            .collect()
+    }
+
     /// Get object data by ID
     fn get_object(&self, object_id: &ObjectId) -> Option<Vec<u8>>;
 
@@ -146,6 +322,13 @@ pub trait StateProvider: Send + Sync {
         self.get_object(object_id)
     }
 
+    /// Get Merkle proof for an object against a specific subnet SMT.
+    ///
+    /// Default implementation delegates to `get_merkle_proof()` (ignores subnet).
+    fn get_merkle_proof_from_subnet(&self, object_id: &ObjectId, _subnet_id: &SubnetId) -> Option<SimpleMerkleProof> {
+        self.get_merkle_proof(object_id)
+    }
+
     /// Get raw storage data by string key.
     ///
     /// Used for loading module bytecode via `"mod:{hex_addr}::{module_name}"` keys.
@@ -196,6 +379,23 @@ impl MerkleStateProvider {
         }
     }
 
+    /// View of the same state whose `StateProvider` object and proof reads
+    /// target `subnet_id` instead of this provider's default subnet.
+    ///
+    /// Cheap: shares the state manager and modification tracker.
+    pub fn for_subnet(&self, subnet_id: SubnetId) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+            default_subnet: subnet_id,
+            modification_tracker: Arc::clone(&self.modification_tracker),
+        }
+    }
+
+    /// Subnet that `StateProvider` object and proof reads target
+    pub fn default_subnet(&self) -> SubnetId {
+        self.default_subnet
+    }
+
     /// Get the underlying shared state manager
     pub fn shared_state_manager(&self) -> Arc<SharedStateManager> {
         Arc::clone(&self.shared)
@@ -287,10 +487,269 @@ impl MerkleStateProvider {
         snapshot.coin_type_index_stats()
     }
 
+    /// Number of addresses holding a coin of `coin_type` in the published
+    /// snapshot. Tombstoned coins no longer count.
+    pub fn coin_holder_count(&self, coin_type: &str) -> usize {
+        self.shared.load_snapshot().coin_holder_count(coin_type)
+    }
+
+    /// Look up the display metadata (symbol, decimals) for a coin type.
+    ///
+    /// Entries live in the ROOT SMT under `coin_metadata_object_id(coin_type)`
+    /// and are written when a subnet registers its token. Returns `None` for
+    /// coin types that were never registered.
+    pub fn get_coin_metadata(&self, coin_type: &str) -> Option<CoinMetadata> {
+        let object_id = coin_metadata_object_id(coin_type);
+        self.get_object_from_subnet(object_id.as_bytes(), &SubnetId::ROOT)
+            .and_then(|bytes| CoinMetadata::from_bytes(&bytes))
+    }
+
+    /// Look up a user's interaction record in a subnet.
+    ///
+    /// `subnet_id_str` accepts the same forms as `resolve_subnet_id`. The
+    /// record lives in that subnet's SMT and is written by transfers of the
+    /// subnet's token. Returns `None` if the user never transacted there.
+    pub fn get_user_subnet_activity(&self, address: &str, subnet_id_str: &str) -> Option<UserSubnetActivity> {
+        let subnet_id = Self::resolve_subnet_id(subnet_id_str);
+        let object_id = user_subnet_activity_object_id(&resolve_owner_address(address), &subnet_id);
+        self.get_object_from_subnet(object_id.as_bytes(), &subnet_id)
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+    }
+
+    /// Summarize a subnet's committed SMT (speculative overlay excluded).
+    ///
+    /// `subnet_id_str` accepts the same forms as `resolve_subnet_id`; it is
+    /// also the coin type whose balances make up `token_supply`. Returns
+    /// `None` if the subnet has no SMT yet.
+    pub fn get_subnet_stats(&self, subnet_id_str: &str) -> Option<SubnetStats> {
+        let subnet_id = Self::resolve_subnet_id(subnet_id_str);
+        let snapshot = self.shared.load_snapshot();
+        let smt = snapshot.get_subnet(&subnet_id)?;
+        let token_supply = smt
+            .iter_objects()
+            .filter_map(|(_, bytes)| CoinState::from_bytes(bytes))
+            .filter(|coin| coin.coin_type == subnet_id_str)
+            .fold(0u64, |total, coin| total.saturating_add(coin.balance));
+        Some(SubnetStats {
+            subnet_id,
+            object_count: smt.object_count(),
+            state_root: smt.root_bytes(),
+            last_anchor: smt.last_anchor(),
+            token_supply,
+        })
+    }
+
+    /// Committed event count and rolling one-minute rate of a subnet.
+    ///
+    /// Returns `None` if the subnet has no SMT. Counters live in memory and
+    /// restart from zero with the node.
+    pub fn get_subnet_throughput(&self, subnet_id_str: &str) -> Option<SubnetThroughput> {
+        let subnet_id = Self::resolve_subnet_id(subnet_id_str);
+        let snapshot = self.shared.load_snapshot();
+        snapshot.get_subnet(&subnet_id)?;
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        let counter = snapshot.subnet_event_counter(&subnet_id);
+        Some(SubnetThroughput {
+            subnet_id,
+            event_count: counter.map_or(0, |c| c.total()),
+            events_per_minute: counter.map_or(0, |c| c.events_per_minute(now_ms)),
+        })
+    }
+
+    /// Read a committed object with its Merkle proof against its subnet root.
+    ///
+    /// Bypasses the speculative overlay. Returns `None` if the subnet or the
+    /// object does not exist.
+    pub fn get_proven_object(&self, subnet_id: &SubnetId, object_id: &ObjectId) -> Option<ProvenObject> {
+        let snapshot = self.shared.load_snapshot();
+        self.proven_object_in(&snapshot, subnet_id, object_id)
+    }
+
+    /// Like [`get_proven_object`](Self::get_proven_object), plus the global
+    /// state root computed from the same snapshot.
+    pub fn get_proven_object_with_global_root(
+        &self,
+        subnet_id: &SubnetId,
+        object_id: &ObjectId,
+    ) -> Option<(ProvenObject, [u8; 32])> {
+        let snapshot = self.shared.load_snapshot();
+        let object = self.proven_object_in(&snapshot, subnet_id, object_id)?;
+        let (global_state_root, _) = snapshot.compute_global_root_bytes();
+        Some((object, global_state_root))
+    }
+
+    /// Every coin and the profile of `address` with proofs and last
+    /// modifying events.
+    ///
+    /// Diagnostic view that gathers the coin, proof and modification-tracker
+    /// lookups for one address from a single snapshot, so every proof
+    /// verifies against the returned roots.
+    pub fn dump_address_state(&self, address: &str) -> AddressStateDump {
+        let snapshot = self.shared.load_snapshot();
+        let address = resolve_owner_address(address);
+        let coins = Self::coins_for_address_in(&snapshot, &address)
+            .into_iter()
+            .filter_map(|coin| {
+                let subnet_id = Self::resolve_subnet_id(&coin.coin_type);
+                let object = self.proven_object_in(&snapshot, &subnet_id, &coin.object_id)?;
+                Some((coin, object))
+            })
+            .collect();
+
+        // Same derivation as InfraExecutor::execute_profile_update
+        let profile_key = format!("profile:{}", address);
+        let profile_object_id = ObjectId::new(setu_types::hash_utils::setu_hash_with_domain(
+            b"SETU_PROFILE:",
+            profile_key.as_bytes(),
+        ));
+        let profile = self.proven_object_in(&snapshot, &SubnetId::ROOT, &profile_object_id);
+
+        let (global_state_root, subnet_roots) = snapshot.compute_global_root_bytes();
+        AddressStateDump {
+            address,
+            global_state_root,
+            subnet_roots,
+            coins,
+            profile,
+        }
+    }
+
+    fn proven_object_in(
+        &self,
+        snapshot: &GlobalStateManager,
+        subnet_id: &SubnetId,
+        object_id: &ObjectId,
+    ) -> Option<ProvenObject> {
+        let smt = snapshot.get_subnet(subnet_id)?;
+        let key = HashValue::from_slice(object_id.as_bytes()).ok()?;
+        let value = smt.get(&key)?.clone();
+        Some(ProvenObject {
+            object_id: *object_id,
+            subnet_id: *subnet_id,
+            value,
+            subnet_root: smt.root_bytes(),
+            proof: Self::convert_proof(&key, &smt.prove(&key)),
+            last_modified_by: snapshot
+                .get_last_modifying_event(object_id.as_bytes())
+                .cloned()
+                .or_else(|| {
+                    let tracker = self.modification_tracker.read().unwrap();
+                    tracker.get(object_id.as_bytes()).cloned()
+                }),
+        })
+    }
+
+    /// Coins of `address` whose coin type lives in `subnet_id`'s SMT.
+    pub fn get_coins_for_address_in_subnet(&self, address: &str, subnet_id: &SubnetId) -> Vec<CoinInfo> {
+        let snapshot = self.shared.load_snapshot();
+        Self::coins_for_address_in(&snapshot, address)
+            .into_iter()
+            .filter(|coin| Self::resolve_subnet_id(&coin.coin_type) == *subnet_id)
+            .collect()
+    }
+
+    /// Merkle proof of `object_id` against `subnet_id`'s committed SMT root.
+    pub fn get_merkle_proof_in_subnet(&self, object_id: &ObjectId, subnet_id: &SubnetId) -> Option<SimpleMerkleProof> {
+        let key = HashValue::from_slice(object_id.as_bytes()).ok()?;
+        let proof = self.get_proof_from_subnet(object_id.as_bytes(), subnet_id)?;
+        Some(Self::convert_proof(&key, &proof))
+    }
+
+    /// Balances per coin type for each address, read from one snapshot.
+    ///
+    /// Uses the owner → coin index, so the cost is proportional to the coins
+    /// the addresses own rather than to the size of the state. Addresses
+    /// without coins map to an empty map.
+    pub fn get_balances_for_addresses(
+        &self,
+        addresses: &[String],
+    ) -> BTreeMap<String, BTreeMap<String, u64>> {
+        let snapshot = self.shared.load_snapshot();
+        addresses
+            .iter()
+            .map(|address| {
+                let mut balances: BTreeMap<String, u64> = BTreeMap::new();
+                for coin in Self::coins_for_address_in(&snapshot, address) {
+                    let total = balances.entry(coin.coin_type).or_default();
+                    *total = total.saturating_add(coin.balance);
+                }
+                (address.clone(), balances)
+            })
+            .collect()
+    }
+
     // ------------------------------------------------------------------------
     // Helper methods
     // ------------------------------------------------------------------------
 
+    /// Coins owned by `address` in `snapshot`, via the owner → coin index.
+    fn coins_for_address_in(snapshot: &GlobalStateManager, address: &str) -> Vec<CoinInfo> {
+        // Canonicalize address to lowercase hex format ("0x...").
+        let addr_hex = resolve_owner_address(address);
+        
+        // Use owner_coin_index to find all (object_id, coin_type) pairs for this owner.
+        let coin_objects = snapshot.get_coin_objects_for_address(&addr_hex);
+        
+        if coin_objects.is_empty() {
+            // Fallback: try deterministic ROOT subnet coin id
+            let coin_object_id = Self::coin_object_id(&addr_hex);
+            let target_subnet = SubnetId::ROOT;
+            let hash = match HashValue::from_slice(&coin_object_id) {
+                Ok(h) => h,
+                Err(_) => return vec![],
+            };
+            if let Some(smt) = snapshot.get_subnet(&target_subnet) {
+                if let Some(data) = smt.get(&hash).cloned() {
+                    if let Some(coin_state) = CoinState::from_bytes(&data) {
+                        return vec![CoinInfo {
+                            object_id: ObjectId::new(coin_object_id),
+                            owner: coin_state.owner,
+                            balance: coin_state.balance,
+                            version: coin_state.version,
+                            coin_type: coin_state.coin_type,
+                        }];
+                    }
+                }
+            }
+            debug!(address = %address, addr_hex = %addr_hex, "No coins found for address");
+            return vec![];
+        }
+
+        // Look up each coin object from its subnet SMT — using the same snapshot
+        let mut coins = Vec::new();
+        for (object_id_bytes, coin_type) in coin_objects {
+            let target_subnet = Self::resolve_subnet_id(&coin_type);
+            let hash = match HashValue::from_slice(&object_id_bytes) {
+                Ok(h) => h,
+                Err(_) => continue,
+            };
+            if let Some(smt) = snapshot.get_subnet(&target_subnet) {
+                if let Some(data) = smt.get(&hash).cloned() {
+                    if let Some(coin_state) = CoinState::from_bytes(&data) {
+                        // Only include if still owned by this address
+                        if coin_state.owner == addr_hex {
+                            coins.push(CoinInfo {
+                                object_id: ObjectId::new(object_id_bytes),
+                                owner: coin_state.owner,
+                                balance: coin_state.balance,
+                                version: coin_state.version,
+                                coin_type: coin_state.coin_type,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        if coins.is_empty() {
+            debug!(address = %address, addr_hex = %addr_hex, "No coins found for address (all transferred?)");
+        }
+        coins
+    }
+
     /// Generate object ID for a coin owned by an address with specific coin type
     ///
     /// Accepts canonical hex form ("0x" + 64 hex chars). In test builds,
@@ -349,12 +808,12 @@ impl MerkleStateProvider {
         snapshot.get_subnet(subnet_id)?.get(&hv).cloned()
     }
 
-    /// Get object from the default subnet (ROOT), merged with overlay.
+    /// Get object from the default subnet, merged with overlay.
     fn get_object_internal(&self, object_id_bytes: &[u8; 32]) -> Option<Vec<u8>> {
         self.get_object_from_subnet(object_id_bytes, &self.default_subnet)
     }
 
-    /// Get object from the default subnet (ROOT), SMT-only (overlay bypassed).
+    /// Get object from the default subnet, SMT-only (overlay bypassed).
     fn get_object_internal_finalized(&self, object_id_bytes: &[u8; 32]) -> Option<Vec<u8>> {
         self.get_object_from_subnet_finalized(object_id_bytes, &self.default_subnet)
     }
@@ -366,7 +825,7 @@ impl MerkleStateProvider {
         snapshot.get_subnet(subnet_id).map(|smt| smt.prove(&hash))
     }
 
-    /// Get Merkle proof from the default subnet (ROOT)
+    /// Get Merkle proof from the default subnet
     fn get_proof_internal(&self, object_id_bytes: &[u8; 32]) -> Option<SparseMerkleProof> {
         self.get_proof_from_subnet(object_id_bytes, &self.default_subnet)
     }
@@ -383,82 +842,15 @@ impl MerkleStateProvider {
     /// this canonical mapping landed coins in ROOT SMT while reads looked
     /// in the app SMT.
     pub fn resolve_subnet_id(subnet_id_str: &str) -> SubnetId {
-        if subnet_id_str == "ROOT" {
-            SubnetId::ROOT
-        } else {
-            SubnetId::from_hex(subnet_id_str).unwrap_or_else(|_| {
-                SubnetId::from_str_id(subnet_id_str)
-            })
-        }
+        SubnetId::from_coin_type(subnet_id_str)
     }
 }
 
 impl StateProvider for MerkleStateProvider {
     fn get_coins_for_address(&self, address: &str) -> Vec<CoinInfo> {
-        // Canonicalize address to lowercase hex format ("0x...").
-        let addr_hex = resolve_owner_address(address);
-        
         // Single snapshot for the entire method — guarantees cross-read consistency
         let snapshot = self.shared.load_snapshot();
-        
-        // Use owner_coin_index to find all (object_id, coin_type) pairs for this owner.
-        let coin_objects = snapshot.get_coin_objects_for_address(&addr_hex);
-        
-        if coin_objects.is_empty() {
-            // Fallback: try deterministic ROOT subnet coin id
-            let coin_object_id = Self::coin_object_id(&addr_hex);
-            let target_subnet = SubnetId::ROOT;
-            let hash = match HashValue::from_slice(&coin_object_id) {
-                Ok(h) => h,
-                Err(_) => return vec![],
-            };
-            if let Some(smt) = snapshot.get_subnet(&target_subnet) {
-                if let Some(data) = smt.get(&hash).cloned() {
-                    if let Some(coin_state) = CoinState::from_bytes(&data) {
-                        return vec![CoinInfo {
-                            object_id: ObjectId::new(coin_object_id),
-                            owner: coin_state.owner,
-                            balance: coin_state.balance,
-                            version: coin_state.version,
-                            coin_type: coin_state.coin_type,
-                        }];
-                    }
-                }
-            }
-            debug!(address = %address, addr_hex = %addr_hex, "No coins found for address");
-            return vec![];
-        }
-
-        // Look up each coin object from its subnet SMT — using the same snapshot
-        let mut coins = Vec::new();
-        for (object_id_bytes, coin_type) in coin_objects {
-            let target_subnet = Self::resolve_subnet_id(&coin_type);
-            let hash = match HashValue::from_slice(&object_id_bytes) {
-                Ok(h) => h,
-                Err(_) => continue,
-            };
-            if let Some(smt) = snapshot.get_subnet(&target_subnet) {
-                if let Some(data) = smt.get(&hash).cloned() {
-                    if let Some(coin_state) = CoinState::from_bytes(&data) {
-                        // Only include if still owned by this address
-                        if coin_state.owner == addr_hex {
-                            coins.push(CoinInfo {
-                                object_id: ObjectId::new(object_id_bytes),
-                                owner: coin_state.owner,
-                                balance: coin_state.balance,
-                                version: coin_state.version,
-                                coin_type: coin_state.coin_type,
-                            });
-                        }
-                    }
-                }
-            }
-        }
-
-        if coins.is_empty() {
-            debug!(address = %address, addr_hex = %addr_hex, "No coins found for address (all transferred?)");
-        }
-        coins
+        Self::coins_for_address_in(&snapshot, address)
     }
 
     fn get_object(&self, object_id: &ObjectId) -> Option<Vec<u8>> {
@@ -500,6 +892,14 @@ impl StateProvider for MerkleStateProvider {
             .get_subnet_object(subnet_id, object_id.as_bytes())
     }
 
+    fn get_coins_for_address_in_subnet(&self, address: &str, subnet_id: &SubnetId) -> Vec<CoinInfo> {
+        MerkleStateProvider::get_coins_for_address_in_subnet(self, address, subnet_id)
+    }
+
+    fn get_merkle_proof_from_subnet(&self, object_id: &ObjectId, subnet_id: &SubnetId) -> Option<SimpleMerkleProof> {
+        self.get_merkle_proof_in_subnet(object_id, subnet_id)
+    }
+
     fn get_raw(&self, key: &str) -> Option<Vec<u8>> {
         self.get_raw_data(key)
     }
@@ -563,11 +963,9 @@ pub fn init_coin_with_type(
         })
     };
     
-    state_manager.upsert_object(
-        target_subnet,
-        object_id_bytes,
-        coin_state.to_bytes(),
-    );
+    state_manager
+        .upsert_object(target_subnet, object_id_bytes, coin_state.to_bytes())
+        .expect("coin state fits the object size limit");
     
     // Register in GSM's coin_type_index for efficient queries
     state_manager.register_coin_object(&owner_hex, subnet_id, object_id_bytes);
@@ -690,7 +1088,9 @@ pub fn init_coins_split(
             subnet_id.to_string(),
         );
 
-        state_manager.upsert_object(target_subnet, object_id_bytes, coin_state.to_bytes());
+        state_manager
+            .upsert_object(target_subnet, object_id_bytes, coin_state.to_bytes())
+            .expect("coin state fits the object size limit");
         state_manager.register_coin_object(&owner_hex, subnet_id, object_id_bytes);
 
         ids.push(ObjectId::new(object_id_bytes));
@@ -760,6 +1160,136 @@ mod tests {
         assert_ne!(root, [0u8; 32]);
     }
 
+    #[test]
+    fn test_get_coin_metadata() {
+        let meta = CoinMetadata::new("gaming-subnet", "GAME", "Gaming Subnet", 6);
+        let shared = make_shared_with_init(|gsm| {
+            gsm.upsert_object(
+                SubnetId::ROOT,
+                *coin_metadata_object_id("gaming-subnet").as_bytes(),
+                meta.to_bytes(),
+            ).unwrap();
+        });
+        let provider = MerkleStateProvider::new(shared);
+
+        assert_eq!(provider.get_coin_metadata("gaming-subnet"), Some(meta));
+        assert!(provider.get_coin_metadata("unknown-subnet").is_none());
+    }
+
+    #[test]
+    fn test_get_user_subnet_activity() {
+        use setu_types::{Address, InteractionType, SubnetInteraction};
+
+        let alice = Address::normalize("alice");
+        let bob = Address::normalize("bob");
+        let subnet_id = SubnetId::from_str_id("gaming-subnet");
+        let mut activity = UserSubnetActivity::new_at(alice, subnet_id, 1000);
+        activity.record_interaction(SubnetInteraction::new_at(bob, InteractionType::Transfer, 1000));
+
+        let oid = user_subnet_activity_object_id(&alice.to_string(), &subnet_id);
+        let bytes = serde_json::to_vec(&activity).unwrap();
+        let shared = make_shared_with_init(|gsm| {
+            gsm.upsert_object(subnet_id, *oid.as_bytes(), bytes).unwrap();
+        });
+        let provider = MerkleStateProvider::new(shared);
+
+        let found = provider.get_user_subnet_activity("alice", "gaming-subnet").unwrap();
+        assert_eq!(found.interaction_count(&InteractionType::Transfer), 1);
+        assert!(provider.get_user_subnet_activity("bob", "gaming-subnet").is_none());
+        assert!(provider.get_user_subnet_activity("alice", "ROOT").is_none());
+    }
+
+    #[test]
+    fn test_get_subnet_stats() {
+        let shared = make_shared(GlobalStateManager::new());
+        let provider = MerkleStateProvider::new(Arc::clone(&shared));
+        mint_subnet_token(&provider, "gaming-subnet", "alice", 700);
+        init_coin_with_provider(&provider, "bob", 300, "gaming-subnet");
+        {
+            let gsm = shared.lock_write();
+            shared.publish_snapshot(&gsm);
+        }
+
+        let stats = provider.get_subnet_stats("gaming-subnet").unwrap();
+        assert_eq!(stats.subnet_id, SubnetId::from_str_id("gaming-subnet"));
+        assert_eq!(stats.object_count, 2);
+        assert_eq!(stats.token_supply, 1000);
+        assert_ne!(stats.state_root, [0u8; 32]);
+        assert!(provider.get_subnet_stats("unknown-subnet").is_none());
+    }
+
+    #[test]
+    fn test_get_balances_for_addresses() {
+        let shared = make_shared(GlobalStateManager::new());
+        let provider = MerkleStateProvider::new(Arc::clone(&shared));
+        init_coin_with_provider(&provider, "alice", 500, "ROOT");
+        mint_subnet_token(&provider, "gaming-subnet", "alice", 70);
+        init_coin_with_provider(&provider, "bob", 300, "ROOT");
+        {
+            let gsm = shared.lock_write();
+            shared.publish_snapshot(&gsm);
+        }
+
+        let addresses: Vec<String> = ["alice", "bob", "carol"].iter().map(|s| s.to_string()).collect();
+        let balances = provider.get_balances_for_addresses(&addresses);
+
+        assert_eq!(balances.len(), 3);
+        assert_eq!(balances["alice"]["ROOT"], 500);
+        assert_eq!(balances["alice"]["gaming-subnet"], 70);
+        assert_eq!(balances["bob"].len(), 1);
+        assert_eq!(balances["bob"]["ROOT"], 300);
+        assert!(balances["carol"].is_empty());
+    }
+
+    #[test]
+    fn test_genesis_seeds_multiple_coin_types_per_account() {
+        let owner = format!("0x{}", "ab".repeat(32));
+        let legacy = format!("0x{}", "cd".repeat(32));
+        let json = format!(
+            r#"{{
+                "chain_id": "setu-test",
+                "accounts": [
+                    {{ "address": "{owner}", "name": "multi", "balances": [
+                        {{ "coin_type": "SETU", "amount": 1000 }},
+                        {{ "coin_type": "USDC", "amount": 250 }}
+                    ] }},
+                    {{ "address": "{legacy}", "balance": 40 }}
+                ]
+            }}"#
+        );
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), json).unwrap();
+        let genesis = setu_types::GenesisConfig::load(file.path().to_str().unwrap()).unwrap();
+
+        let shared = make_shared(GlobalStateManager::new());
+        let provider = MerkleStateProvider::new(Arc::clone(&shared));
+        {
+            let mut gsm = shared.lock_write();
+            for change in genesis.build_state_changes().unwrap() {
+                let subnet = change.target_subnet.unwrap_or(SubnetId::ROOT);
+                gsm.apply_state_change(subnet, &change).unwrap();
+            }
+            shared.publish_snapshot(&gsm);
+        }
+
+        let mut coins: Vec<(String, u64)> = provider
+            .get_coins_for_address(&owner)
+            .into_iter()
+            .map(|c| (c.coin_type, c.balance))
+            .collect();
+        coins.sort();
+        assert_eq!(coins, vec![("SETU".to_string(), 1000), ("USDC".to_string(), 250)]);
+        let mut types = provider.get_coin_types_for_address(&owner);
+        types.sort();
+        assert_eq!(types, vec!["SETU".to_string(), "USDC".to_string()]);
+
+        // Single-balance accounts still get one coin in the genesis subnet type
+        let legacy_coins = provider.get_coins_for_address(&legacy);
+        assert_eq!(legacy_coins.len(), 1);
+        assert_eq!(legacy_coins[0].balance, 40);
+        assert_eq!(legacy_coins[0].coin_type, "ROOT");
+    }
+
     #[test]
     fn test_modification_tracking() {
         let shared = make_shared(GlobalStateManager::new());
@@ -877,6 +1407,35 @@ mod tests {
         assert_eq!(entry_count, 5);   // total coin type entries
     }
 
+    #[test]
+    fn test_recover_rebuilds_coin_type_index() {
+        use crate::state::manager::B4StoreExt;
+        use crate::RocksDBMerkleStore;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store: Arc<dyn B4StoreExt> =
+            Arc::new(RocksDBMerkleStore::open(temp_dir.path()).unwrap());
+
+        let mut gsm = GlobalStateManager::with_store(Arc::clone(&store));
+        init_coin_with_type(&mut gsm, "alice", 1000, "ROOT");
+        init_coin_with_type(&mut gsm, "alice", 500, "defi-subnet");
+        init_coin_with_type(&mut gsm, "bob", 300, "gaming-subnet");
+        gsm.commit(1).unwrap();
+        drop(gsm);
+
+        // Simulated restart: fresh manager over the same store, no manual rebuild
+        let mut recovered = GlobalStateManager::with_store(store);
+        let summary = recovered.recover().unwrap();
+        assert_eq!(summary.objects_indexed, 3);
+        let provider = MerkleStateProvider::new(make_shared(recovered));
+
+        let alice_defi = provider.get_coins_for_address_by_type("alice", "defi-subnet");
+        assert_eq!(alice_defi.len(), 1);
+        assert_eq!(alice_defi[0].balance, 500);
+        assert_eq!(provider.get_coins_for_address_by_type("alice", "ROOT")[0].balance, 1000);
+        assert_eq!(provider.get_coins_for_address_by_type("bob", "gaming-subnet")[0].balance, 300);
+    }
+
     #[test]
     fn test_rebuild_index_empty_tree() {
         let shared = make_shared(GlobalStateManager::new());
@@ -915,7 +1474,7 @@ mod tests {
                 "mod:0xdead::counter".to_string(),
                 vec![0xCA, 0xFE],
             );
-            gsm.apply_state_change(SubnetId::ROOT, &sc);
+            gsm.apply_state_change(SubnetId::ROOT, &sc).unwrap();
         });
         // Must publish so MerkleStateProvider can see the data
         {
@@ -1042,7 +1601,7 @@ mod tests {
 
         let shared = make_shared_with_init(|gsm| {
             let sc = StateChange::insert(oid_key_str(0xB0), b"smt_bytes".to_vec());
-            gsm.apply_state_change(SubnetId::ROOT, &sc);
+            gsm.apply_state_change(SubnetId::ROOT, &sc).unwrap();
         });
         {
             let gsm = shared.lock_write();
@@ -1052,4 +1611,116 @@ mod tests {
         let data = provider.get_object(&ObjectId::new([0xB0u8; 32]));
         assert_eq!(data, Some(b"smt_bytes".to_vec()));
     }
+
+    #[test]
+    fn test_subnet_scoped_reads() {
+        let shared = make_shared(GlobalStateManager::new());
+        let provider = MerkleStateProvider::new(Arc::clone(&shared));
+        let root_coin = init_coin_with_provider(&provider, "alice", 500, "ROOT");
+        let game_coin = mint_subnet_token(&provider, "gaming-subnet", "alice", 70);
+        {
+            let gsm = shared.lock_write();
+            shared.publish_snapshot(&gsm);
+        }
+        let game = MerkleStateProvider::resolve_subnet_id("gaming-subnet");
+
+        let root_coins = provider.get_coins_for_address_in_subnet("alice", &SubnetId::ROOT);
+        assert_eq!(root_coins.len(), 1);
+        assert_eq!((root_coins[0].object_id, root_coins[0].balance), (root_coin, 500));
+        let game_coins = provider.get_coins_for_address_in_subnet("alice", &game);
+        assert_eq!(game_coins.len(), 1);
+        assert_eq!((game_coins[0].object_id, game_coins[0].balance), (game_coin, 70));
+
+        // The ROOT provider cannot see the app coin; a subnet view can
+        assert!(provider.get_object(&game_coin).is_none());
+        let view = provider.for_subnet(game);
+        assert_eq!(view.default_subnet(), game);
+        let data = view.get_object(&game_coin).unwrap();
+        assert!(view.get_object(&root_coin).is_none());
+
+        let game_root = shared.load_snapshot().get_subnet(&game).unwrap().root_bytes();
+        let proof = provider.get_merkle_proof_in_subnet(&game_coin, &game).unwrap();
+        assert!(proof.verify_inclusion(&game_root, &data));
+        assert_eq!(view.get_merkle_proof(&game_coin), Some(proof));
+        let root_root = shared.load_snapshot().get_subnet(&SubnetId::ROOT).unwrap().root_bytes();
+        assert!(!provider.get_merkle_proof(&game_coin).unwrap().verify_inclusion(&root_root, &data));
+    }
+
+    #[test]
+    fn test_dump_address_state_proofs_verify() {
+        let shared = make_shared(GlobalStateManager::new());
+        let provider = MerkleStateProvider::new(Arc::clone(&shared));
+        let root_coin = init_coin_with_provider(&provider, "alice", 500, "ROOT");
+        let game_coin = mint_subnet_token(&provider, "gaming-subnet", "alice", 70);
+        init_coin_with_provider(&provider, "bob", 300, "ROOT");
+        let profile_id = ObjectId::new([0x5Au8; 32]);
+        {
+            let mut gsm = shared.lock_write();
+            gsm.upsert_object(SubnetId::ROOT, *profile_id.as_bytes(), br#"{"display_name":"alice"}"#.to_vec()).unwrap();
+            shared.publish_snapshot(&gsm);
+        }
+        provider.record_modifications("event-7", &[*root_coin.as_bytes()]);
+
+        let dump = provider.dump_address_state("alice");
+        assert_eq!(dump.coins.len(), 2);
+        assert_eq!(dump.global_state_root, provider.get_state_root());
+        for (coin, object) in &dump.coins {
+            assert_eq!(coin.object_id, object.object_id);
+            assert!(object.proof.verify_inclusion(&object.subnet_root, &object.value));
+            assert!(!object.proof.verify_inclusion(&object.subnet_root, b"tampered"));
+        }
+        let (root_entry, game_entry) = if dump.coins[0].0.object_id == root_coin {
+            (&dump.coins[0], &dump.coins[1])
+        } else {
+            (&dump.coins[1], &dump.coins[0])
+        };
+        assert_eq!(root_entry.0.balance, 500);
+        assert_eq!(root_entry.1.last_modified_by.as_deref(), Some("event-7"));
+        assert_eq!(game_entry.0.object_id, game_coin);
+        assert_eq!(game_entry.0.balance, 70);
+        assert_ne!(game_entry.1.subnet_id, SubnetId::ROOT);
+
+        let profile = provider.get_proven_object(&SubnetId::ROOT, &profile_id).unwrap();
+        assert!(profile.proof.verify_inclusion(&profile.subnet_root, &profile.value));
+        assert!(provider.get_proven_object(&SubnetId::ROOT, &ObjectId::new([0x5Bu8; 32])).is_none());
+    }
+
+    #[test]
+    fn test_compact_proof_round_trip_and_size() {
+        use setu_merkle::hash::hash_value;
+        use setu_merkle::sparse::SparseMerkleLeafNode;
+        use setu_merkle::SparseMerkleTree;
+
+        // Two keys sharing a 19-bit prefix give a 20-level proof
+        let key = HashValue::new([0u8; 32]);
+        let mut neighbour = [0u8; 32];
+        neighbour[2] = 0x10;
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(key, b"value".to_vec());
+        tree.insert(HashValue::new(neighbour), b"other".to_vec());
+
+        let proof = MerkleStateProvider::convert_proof(&key, &tree.get_proof(&key));
+        assert_eq!(proof.siblings.len(), 20);
+        // Only the neighbour leaf at the bottom is a real sibling
+        let empty = *empty_subtree_hash(0).as_bytes();
+        assert_eq!(proof.siblings.iter().filter(|s| **s == empty).count(), 19);
+
+        let compact = proof.to_compact_bytes();
+        let bcs_bytes = bcs::to_bytes(&proof).unwrap();
+        // BCS: 1 + 20*32 siblings, 1 + 20 path bytes, 32 key, 1 flag
+        assert_eq!(bcs_bytes.len(), 695);
+        // Compact: 1 flag, 32 key, 2 + 2 lengths, 3 + 3 bitfields, 1*32 siblings
+        assert_eq!(compact.len(), 75);
+
+        let decoded = SimpleMerkleProof::from_compact_bytes(&compact).unwrap();
+        assert_eq!(decoded, proof);
+        assert!(SimpleMerkleProof::from_compact_bytes(&compact[..compact.len() - 1]).is_err());
+
+        // The round-tripped proof still verifies against the tree root
+        let smt_proof = SparseMerkleProof::new(
+            decoded.siblings.iter().map(|s| HashValue::new(*s)).collect(),
+            Some(SparseMerkleLeafNode { key, value_hash: hash_value(b"value") }),
+        );
+        smt_proof.verify_inclusion(&tree.root(), &key, b"value").unwrap();
+    }
 }
@@ -0,0 +1,256 @@
+//! MerkleStore conformance tests
+//!
+//! Every check below runs against both `InMemoryMerkleStore` and
+//! `RocksDBMerkleStore`, so the in-memory store can stand in for RocksDB in
+//! unit tests and memory-mode nodes without behavioral drift.
+//!
+//! `prune_before` is deliberately not covered: the RocksDB store does not
+//! implement it yet (always returns 0).
+
+use setu_merkle::sparse::SparseMerkleNode;
+use setu_merkle::storage::{MerkleNodeStore, MerkleRootStore, SubnetId};
+use setu_merkle::HashValue;
+use setu_storage::{
+    B4Store, B4StoreExt, GlobalStateManager, MerkleLeafStore, MerkleMetaStore, MerkleStore,
+};
+use std::sync::Arc;
+
+fn hash(byte: u8) -> HashValue {
+    HashValue::new([byte; 32])
+}
+
+fn subnet(byte: u8) -> SubnetId {
+    [byte; 32]
+}
+
+fn node(byte: u8) -> SparseMerkleNode {
+    SparseMerkleNode::Internal {
+        left: hash(byte),
+        right: hash(byte + 1),
+    }
+}
+
+/// Run each check once per store backend
+macro_rules! conformance_tests {
+    ($($check:ident),* $(,)?) => {
+        mod in_memory {
+            $(
+                #[test]
+                fn $check() {
+                    super::$check(std::sync::Arc::new(setu_storage::InMemoryMerkleStore::new()));
+                }
+            )*
+        }
+
+        mod rocksdb {
+            $(
+                #[test]
+                fn $check() {
+                    let temp_dir = tempfile::TempDir::new().unwrap();
+                    let store = setu_storage::RocksDBMerkleStore::open(temp_dir.path()).unwrap();
+                    super::$check(std::sync::Arc::new(store));
+                }
+            )*
+        }
+    };
+}
+
+conformance_tests!(
+    node_round_trip,
+    subnet_root_versions,
+    global_root_versions,
+    prune_subnet_roots,
+    leaf_round_trip,
+    subnet_registry_and_meta,
+    write_batch_atomicity,
+    write_batch_applies_leaf_ops_in_order,
+    state_manager_recovers_from_store,
+);
+
+fn node_round_trip(store: Arc<dyn B4StoreExt>) {
+    let (subnet1, subnet2) = (subnet(1), subnet(2));
+    let key = hash(3);
+
+    assert!(!store.has_node(&subnet1, &key).unwrap());
+    assert!(store.get_node(&subnet1, &key).unwrap().is_none());
+
+    store.put_node(&subnet1, &key, &node(3)).unwrap();
+    store.put_node(&subnet2, &key, &node(4)).unwrap();
+    assert_eq!(store.get_node(&subnet1, &key).unwrap(), Some(node(3)));
+    assert_eq!(store.get_node(&subnet2, &key).unwrap(), Some(node(4)));
+
+    // Deletes are scoped to one subnet
+    store.delete_node(&subnet1, &key).unwrap();
+    assert!(!store.has_node(&subnet1, &key).unwrap());
+    assert!(store.has_node(&subnet2, &key).unwrap());
+
+    let nodes: Vec<_> = (10..15).map(|i| (hash(i), node(i))).collect();
+    store.batch_put_nodes(&subnet1, &nodes).unwrap();
+    for (key, expected) in &nodes {
+        assert_eq!(store.get_node(&subnet1, key).unwrap().as_ref(), Some(expected));
+    }
+}
+
+fn subnet_root_versions(store: Arc<dyn B4StoreExt>) {
+    let (subnet1, subnet2) = (subnet(1), subnet(2));
+
+    assert!(store.get_latest_subnet_root(&subnet1).unwrap().is_none());
+
+    store.put_subnet_root(&subnet1, 10, &hash(10)).unwrap();
+    store.put_subnet_root(&subnet1, 20, &hash(20)).unwrap();
+    // An older anchor never replaces the latest root
+    store.put_subnet_root(&subnet1, 15, &hash(15)).unwrap();
+    store.put_subnet_root(&subnet2, 30, &hash(30)).unwrap();
+
+    assert_eq!(store.get_subnet_root(&subnet1, 15).unwrap(), Some(hash(15)));
+    assert!(store.get_subnet_root(&subnet1, 30).unwrap().is_none());
+    assert_eq!(store.get_latest_subnet_root(&subnet1).unwrap(), Some((20, hash(20))));
+    assert_eq!(store.get_latest_subnet_root(&subnet2).unwrap(), Some((30, hash(30))));
+
+    assert_eq!(store.list_anchors(&subnet1, 0, 100).unwrap(), vec![10, 15, 20]);
+    assert_eq!(store.list_anchors(&subnet1, 11, 19).unwrap(), vec![15]);
+    assert_eq!(store.list_anchors(&subnet2, 0, 29).unwrap(), Vec::<u64>::new());
+}
+
+fn global_root_versions(store: Arc<dyn B4StoreExt>) {
+    assert!(store.get_latest_global_root().unwrap().is_none());
+
+    store.put_global_root(100, &hash(1)).unwrap();
+    store.put_global_root(200, &hash(2)).unwrap();
+    store.put_global_root(150, &hash(3)).unwrap();
+
+    assert_eq!(store.get_global_root(100).unwrap(), Some(hash(1)));
+    assert!(store.get_global_root(101).unwrap().is_none());
+    assert_eq!(store.get_latest_global_root().unwrap(), Some((200, hash(2))));
+}
+
+fn prune_subnet_roots(store: Arc<dyn B4StoreExt>) {
+    let (pruned, other) = (subnet(7), subnet(8));
+    for anchor_id in 1..=3u64 {
+        store.put_subnet_root(&pruned, anchor_id, &hash(anchor_id as u8)).unwrap();
+        store.put_subnet_root(&other, anchor_id, &hash(anchor_id as u8)).unwrap();
+        store.put_global_root(anchor_id, &hash(anchor_id as u8)).unwrap();
+    }
+
+    assert_eq!(store.prune_subnet_before(&pruned, 3).unwrap(), 2);
+    assert_eq!(store.list_anchors(&pruned, 1, 3).unwrap(), vec![3]);
+    assert_eq!(store.list_anchors(&other, 1, 3).unwrap(), vec![1, 2, 3]);
+    assert_eq!(store.get_latest_subnet_root(&pruned).unwrap(), Some((3, hash(3))));
+    assert_eq!(store.get_global_root(1).unwrap(), Some(hash(1)));
+}
+
+fn leaf_round_trip(store: Arc<dyn B4StoreExt>) {
+    let (subnet1, subnet2) = (subnet(1), subnet(2));
+    let (a, b, c) = (hash(0xA0), hash(0xB0), hash(0xC0));
+
+    store.batch_put_leaves(&subnet1, &[(&a, b"a".as_slice()), (&b, b"b".as_slice())]).unwrap();
+    store.batch_put_leaves(&subnet2, &[(&c, b"c".as_slice())]).unwrap();
+    // Upserts overwrite
+    store.batch_put_leaves(&subnet1, &[(&a, b"a2".as_slice())]).unwrap();
+
+    assert_eq!(store.get_leaf(&subnet1, &a).unwrap(), Some(b"a2".to_vec()));
+    assert!(store.has_leaf(&subnet1, &b).unwrap());
+    assert!(!store.has_leaf(&subnet1, &c).unwrap());
+    assert_eq!(store.leaf_count(&subnet1).unwrap(), 2);
+    assert_eq!(store.leaf_count(&subnet2).unwrap(), 1);
+
+    store.batch_delete_leaves(&subnet1, &[&b]).unwrap();
+    assert!(store.get_leaf(&subnet1, &b).unwrap().is_none());
+
+    let leaves = store.load_all_leaves(&subnet1).unwrap();
+    assert_eq!(leaves.len(), 1);
+    assert_eq!(leaves.get(&a), Some(&b"a2".to_vec()));
+    assert_eq!(store.load_all_leaves(&subnet(9)).unwrap().len(), 0);
+}
+
+fn subnet_registry_and_meta(store: Arc<dyn B4StoreExt>) {
+    let (registered, leaves_only) = (subnet(0x11), subnet(0x22));
+    let key = hash(1);
+
+    store.register_subnet(&registered).unwrap();
+    store.batch_put_leaves(&leaves_only, &[(&key, b"v".as_slice())]).unwrap();
+    store.set_last_anchor(&leaves_only, 99).unwrap();
+
+    // Both the registry and `list_subnets` ignore unregistered subnets
+    assert!(store.is_subnet_registered(&registered).unwrap());
+    assert!(!store.is_subnet_registered(&leaves_only).unwrap());
+    assert_eq!(store.list_registered_subnets().unwrap(), vec![registered]);
+    assert_eq!(store.list_subnets().unwrap(), vec![registered]);
+
+    store.unregister_subnet(&registered).unwrap();
+    assert!(store.list_registered_subnets().unwrap().is_empty());
+
+    assert_eq!(store.get_last_anchor(&leaves_only).unwrap(), Some(99));
+    assert!(store.get_last_anchor(&registered).unwrap().is_none());
+
+    store.set_meta("schema_version", &[1, 2]).unwrap();
+    assert_eq!(store.get_meta("schema_version").unwrap(), Some(vec![1, 2]));
+    assert!(store.get_meta("missing").unwrap().is_none());
+}
+
+fn write_batch_atomicity(store: Arc<dyn B4StoreExt>) {
+    let subnet_id = subnet(5);
+    let (a, b) = (hash(1), hash(2));
+    store.batch_put_leaves(&subnet_id, &[(&b, b"old".as_slice())]).unwrap();
+
+    let mut batch = store.begin_batch().unwrap();
+    store.batch_put_leaves_to_batch(&mut batch, &subnet_id, &[(&a, b"new".as_slice())]).unwrap();
+    store.batch_delete_leaves_to_batch(&mut batch, &subnet_id, &[&b]).unwrap();
+    store.batch_register_subnet(&mut batch, &subnet_id).unwrap();
+    store.batch_set_last_anchor(&mut batch, &subnet_id, 7).unwrap();
+    store.batch_put_subnet_root(&mut batch, &subnet_id, 7, &hash(70)).unwrap();
+    store.batch_put_global_root(&mut batch, 7, &hash(77)).unwrap();
+
+    // Nothing is visible before commit
+    assert!(!store.has_leaf(&subnet_id, &a).unwrap());
+    assert!(store.has_leaf(&subnet_id, &b).unwrap());
+    assert!(!store.is_subnet_registered(&subnet_id).unwrap());
+    assert!(store.get_last_anchor(&subnet_id).unwrap().is_none());
+    assert!(store.get_latest_subnet_root(&subnet_id).unwrap().is_none());
+    assert!(store.get_latest_global_root().unwrap().is_none());
+
+    store.commit_batch(batch).unwrap();
+
+    assert_eq!(store.get_leaf(&subnet_id, &a).unwrap(), Some(b"new".to_vec()));
+    assert!(!store.has_leaf(&subnet_id, &b).unwrap());
+    assert_eq!(store.list_registered_subnets().unwrap(), vec![subnet_id]);
+    assert_eq!(store.get_last_anchor(&subnet_id).unwrap(), Some(7));
+    assert_eq!(store.get_latest_subnet_root(&subnet_id).unwrap(), Some((7, hash(70))));
+    assert_eq!(store.get_latest_global_root().unwrap(), Some((7, hash(77))));
+}
+
+fn write_batch_applies_leaf_ops_in_order(store: Arc<dyn B4StoreExt>) {
+    let subnet_id = subnet(6);
+    let (put_then_delete, delete_then_put) = (hash(1), hash(2));
+
+    let mut batch = store.begin_batch().unwrap();
+    store.batch_put_leaves_to_batch(&mut batch, &subnet_id, &[(&put_then_delete, b"x".as_slice())]).unwrap();
+    store.batch_delete_leaves_to_batch(&mut batch, &subnet_id, &[&put_then_delete]).unwrap();
+    store.batch_delete_leaves_to_batch(&mut batch, &subnet_id, &[&delete_then_put]).unwrap();
+    store.batch_put_leaves_to_batch(&mut batch, &subnet_id, &[(&delete_then_put, b"y".as_slice())]).unwrap();
+    store.commit_batch(batch).unwrap();
+
+    assert!(!store.has_leaf(&subnet_id, &put_then_delete).unwrap());
+    assert_eq!(store.get_leaf(&subnet_id, &delete_then_put).unwrap(), Some(b"y".to_vec()));
+}
+
+fn state_manager_recovers_from_store(store: Arc<dyn B4StoreExt>) {
+    let subnet_id = setu_types::SubnetId::new_app_simple(3);
+
+    let mut manager = GlobalStateManager::with_store(store.clone());
+    for i in 1..=4u8 {
+        manager.upsert_object(subnet_id, [i; 32], vec![i]).unwrap();
+    }
+    manager.commit(1).unwrap();
+    manager.upsert_object(subnet_id, [9u8; 32], vec![9]).unwrap();
+    manager.commit(2).unwrap();
+    let expected_root = manager.get_subnet_root(&subnet_id).unwrap();
+
+    // A fresh manager on the same store rebuilds identical state
+    let mut recovered = GlobalStateManager::with_store(store);
+    let summary = recovered.recover().unwrap();
+    assert_eq!(summary.root_mismatches, 0);
+    assert_eq!(recovered.current_anchor(), 2);
+    assert_eq!(recovered.get_subnet_root(&subnet_id), Some(expected_root));
+    assert_eq!(recovered.get_subnet(&subnet_id).unwrap().object_count(), 5);
+}
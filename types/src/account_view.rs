@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     object::{ObjectId, Address},
     profile::{Profile, Credential},
-    coin::Coin,
+    coin::{Coin, CoinMetadata},
     relation::RelationGraph,
 };
 
@@ -43,6 +43,11 @@ pub struct AccountView {
     /// All owned RelationGraph objects
     pub graphs: Vec<RelationGraph>,
     
+    /// Display metadata (symbol, decimals) for the coin types held.
+    /// Empty when the view was built without a coin type registry lookup.
+    #[serde(default)]
+    pub coin_metadata: Vec<CoinMetadata>,
+    
     // ========== Computed Fields ==========
     
     /// Total balance (sum of all Coins)
@@ -86,6 +91,7 @@ impl AccountView {
             credentials,
             coins,
             graphs,
+            coin_metadata: Vec::new(),
             total_balance,
             coin_count,
             valid_credential_count,
@@ -94,6 +100,12 @@ impl AccountView {
         }
     }
     
+    /// Attach coin type display metadata (builder pattern)
+    pub fn with_coin_metadata(mut self, coin_metadata: Vec<CoinMetadata>) -> Self {
+        self.coin_metadata = coin_metadata;
+        self
+    }
+    
     /// Create empty view for an address
     pub fn empty(address: Address) -> Self {
         Self::new(address, None, vec![], vec![], vec![])
@@ -178,6 +190,15 @@ impl AccountView {
             .sum()
     }
     
+    /// Get total balance by coin type, rendered with the type's decimals.
+    ///
+    /// Returns `None` if no metadata is attached for the coin type.
+    pub fn display_balance_by_type(&self, coin_type: &str) -> Option<String> {
+        self.coin_metadata.iter()
+            .find(|m| m.coin_type == coin_type)
+            .map(|m| m.format_amount(self.balance_by_type(coin_type)))
+    }
+    
     /// Get list of Coins available for payment (sorted by balance, largest first)
     pub fn get_payable_coins(&self, amount: u64) -> Option<Vec<&Coin>> {
         let mut sorted_coins: Vec<&Coin> = self.coins.iter().collect();
@@ -253,7 +274,7 @@ mod tests {
     use crate::{
         profile::create_profile,
         profile::create_kyc_credential,
-        coin::create_coin,
+        coin::{create_coin, create_typed_coin},
         relation::create_social_graph,
         object::generate_object_id,
     };
@@ -338,6 +359,18 @@ mod tests {
         assert!(!view.has_balance(1001));
     }
     
+    #[test]
+    fn test_display_balance_with_decimals() {
+        let alice = Address::from_str_id("alice");
+        let coins = vec![create_typed_coin(alice.clone(), 1_000_000, "gaming-subnet")];
+        
+        let view = AccountView::new(alice, None, vec![], coins, vec![])
+            .with_coin_metadata(vec![CoinMetadata::new("gaming-subnet", "GAME", "Gaming", 6)]);
+        
+        assert_eq!(view.display_balance_by_type("gaming-subnet"), Some("1.000000".to_string()));
+        assert_eq!(view.display_balance_by_type("ROOT"), None);
+    }
+    
     #[test]
     fn test_get_payable_coins() {
         let alice = Address::from_str_id("alice");
@@ -0,0 +1,285 @@
+//! BCS golden vectors for persisted consensus types.
+//!
+//! `Event`, `Anchor` and `ConsensusFrame` are stored with BCS, and their ids
+//! are derived from a subset of their fields. A reordered or inserted field
+//! changes the stored bytes; a change to an id preimage changes every id
+//! recomputed after the upgrade, so `verify_id()` starts rejecting data that
+//! is already on disk. Each test below builds a fixed instance (no wall-clock
+//! timestamps, single-entry maps only) and pins both the exact BCS bytes and
+//! the derived id.
+//!
+//! ## Updating the vectors
+//!
+//! A failure here means the wire or id format changed. That is never a
+//! routine refactor: stored DAG data will no longer round-trip. Only update
+//! the constants as part of an intentional, reviewed format change (with a
+//! migration or a new tail variant, see design.md §3 R1g). Run
+//! `cargo test -p setu-types bcs_golden -- --nocapture` and copy the
+//! `actual` values printed by the failing assertion into the constants.
+
+use crate::consensus::{Anchor, CFStatus, ConsensusFrame};
+use crate::event::{Event, EventStatus, EventType, VLCSnapshot};
+use crate::merkle::AnchorMerkleRoots;
+use crate::registration::TaskSubmission;
+use crate::subnet::SubnetId;
+use crate::transfer::{Transfer, VectorClock};
+use std::collections::HashMap;
+
+/// Fixed timestamp used by every vector (2026-01-01T00:00:00Z in ms).
+const FIXED_TS: u64 = 1_767_225_600_000;
+
+fn fixed_vlc(node: &str, logical_time: u64) -> VLCSnapshot {
+    let mut clock = VectorClock::new();
+    clock.set(node, logical_time);
+    VLCSnapshot {
+        vector_clock: clock,
+        logical_time,
+        physical_time: FIXED_TS,
+    }
+}
+
+/// Pin an event's timestamp and re-derive its id.
+fn freeze(mut event: Event) -> Event {
+    event.timestamp = FIXED_TS;
+    event.recompute_id();
+    event
+}
+
+fn genesis_event() -> Event {
+    freeze(Event::genesis("validator-1".to_string(), fixed_vlc("validator-1", 0)))
+}
+
+fn transfer_event() -> Event {
+    let transfer = Transfer::new("tx-1", "alice", "bob", 1_000)
+        .with_resources(vec!["coin:alice".to_string()])
+        .with_power(7);
+    freeze(Event::transfer(
+        transfer,
+        vec![genesis_event().id],
+        fixed_vlc("validator-1", 1),
+        "validator-1".to_string(),
+    ))
+}
+
+fn task_submit_event() -> Event {
+    let task = TaskSubmission::new("task-1", "compute", "alice").with_payload(vec![0xAB, 0xCD]);
+    freeze(Event::task_submit(
+        task,
+        vec![genesis_event().id],
+        fixed_vlc("validator-1", 2),
+        "validator-1".to_string(),
+    ))
+}
+
+fn fixed_anchor() -> Anchor {
+    let mut subnet_roots = HashMap::new();
+    subnet_roots.insert(SubnetId::ROOT, [0x44; 32]);
+    let merkle_roots = AnchorMerkleRoots {
+        events_root: [0x11; 32],
+        global_state_root: [0x22; 32],
+        anchor_chain_root: [0x33; 32],
+        subnet_roots,
+    };
+    let event_ids = vec![transfer_event().id, task_submit_event().id];
+    let vlc_snapshot = fixed_vlc("validator-1", 3);
+    let state_root = hex::encode(merkle_roots.global_state_root);
+    Anchor {
+        id: Anchor::compute_id(
+            1,
+            Some(&"00".repeat(32)),
+            &merkle_roots.events_root,
+            &merkle_roots.global_state_root,
+        ),
+        event_ids,
+        vlc_snapshot,
+        state_root,
+        merkle_roots: Some(merkle_roots),
+        previous_anchor: Some("00".repeat(32)),
+        depth: 1,
+        timestamp: FIXED_TS,
+    }
+}
+
+fn fixed_cf() -> ConsensusFrame {
+    let anchor = fixed_anchor();
+    let proposer = "validator-1".to_string();
+    ConsensusFrame {
+        id: ConsensusFrame::compute_id(&anchor, &proposer, FIXED_TS),
+        anchor,
+        proposer,
+        status: CFStatus::Proposed,
+        votes: HashMap::new(),
+        created_at: FIXED_TS,
+        finalized_at: None,
+    }
+}
+
+fn assert_golden(name: &str, actual_bytes: &[u8], expected_hex: &str) {
+    let actual = hex::encode(actual_bytes);
+    assert_eq!(
+        actual, expected_hex,
+        "{} BCS layout drift detected. See the module docs before updating this vector.",
+        name
+    );
+}
+
+const GENESIS_EVENT_ID: &str = "afd3bedb915b9c6146f0070d924e4caf2df3d2fd6fe264bf37fa492fa7a0c86e";
+const GENESIS_EVENT_BCS: &str = concat!(
+    "40616664336265646239313562396336313436663030373064393234653463616632646633643266",
+    "64366665323634626633376661343932666137613063383665000001000000000000000000000000",
+    "00000000000000000000000000000000000000000000010b76616c696461746f722d310000000000",
+    "000000000000000000000000a8da769b0100000b76616c696461746f722d31000000a8da769b0100",
+    "0000",
+);
+
+const TRANSFER_EVENT_ID: &str = "bbd35d194e8b4de6400d0e59f73fbb6c48eb3b76c76762657b71cce8371ced57";
+const TRANSFER_EVENT_BCS: &str = concat!(
+    "40626264333564313934653862346465363430306430653539663733666262366334386562336237",
+    "36633736373632363537623731636365383337316365643537020140616664336265646239313562",
+    "39633631343666303037306439323465346361663264663364326664366665323634626633376661",
+    "34393266613761306338366500010474782d3105616c69636503626f62e80300000000000000010a",
+    "636f696e3a616c696365070000000000000000000000020474782d3105616c69636503626f62e803",
+    "00000000000000010a636f696e3a616c696365070000000000000000000000010b76616c69646174",
+    "6f722d310100000000000000010000000000000000a8da769b0100000b76616c696461746f722d31",
+    "000000a8da769b01000000",
+);
+
+/// `GENESIS_EVENT_BCS` as written before events carried a signature
+const GENESIS_EVENT_UNSIGNED_BCS: &str = concat!(
+    "40616664336265646239313562396336313436663030373064393234653463616632646633643266",
+    "64366665323634626633376661343932666137613063383665000001000000000000000000000000",
+    "00000000000000000000000000000000000000000000010b76616c696461746f722d310000000000",
+    "000000000000000000000000a8da769b0100000b76616c696461746f722d31000000a8da769b0100",
+    "00",
+);
+
+/// `TRANSFER_EVENT_BCS` as written before events carried a signature
+const TRANSFER_EVENT_UNSIGNED_BCS: &str = concat!(
+    "40626264333564313934653862346465363430306430653539663733666262366334386562336237",
+    "36633736373632363537623731636365383337316365643537020140616664336265646239313562",
+    "39633631343666303037306439323465346361663264663364326664366665323634626633376661",
+    "34393266613761306338366500010474782d3105616c69636503626f62e80300000000000000010a",
+    "636f696e3a616c696365070000000000000000000000020474782d3105616c69636503626f62e803",
+    "00000000000000010a636f696e3a616c696365070000000000000000000000010b76616c69646174",
+    "6f722d310100000000000000010000000000000000a8da769b0100000b76616c696461746f722d31",
+    "000000a8da769b010000",
+);
+
+const TASK_SUBMIT_EVENT_ID: &str = "93c10ca633ce037a1a81044a435b23760528a99007d5c57ae3f41a3ffd18e5b4";
+const TASK_SUBMIT_EVENT_BCS: &str = concat!(
+    "40393363313063613633336365303337613161383130343461343335623233373630353238613939",
+    "303037643563353761653366343161336666643138653562340a0140616664336265646239313562",
+    "39633631343666303037306439323465346361663264663364326664366665323634626633376661",
+    "34393266613761306338366500000a067461736b2d3107636f6d7075746505616c69636502abcd01",
+    "0b76616c696461746f722d310200000000000000020000000000000000a8da769b0100000b76616c",
+    "696461746f722d31000000a8da769b01000000",
+);
+
+const ANCHOR_ID: &str = "1566af9041ad4a9c2413bd14ca4ecfd92fc2c1341167d70a8a4a66029594b565";
+const ANCHOR_HASH: &str = "65ca05476e3f38fab16f9724491a2da715e60a0a660b8aa403b33040a4cddcc9";
+const ANCHOR_BCS: &str = concat!(
+    "40313536366166393034316164346139633234313362643134636134656366643932666332633133",
+    "34313136376437306138613461363630323935393462353635024062626433356431393465386234",
+    "64653634303064306535396637336662623663343865623362373663373637363236353762373163",
+    "63653833373163656435374039336331306361363333636530333761316138313034346134333562",
+    "323337363035323861393930303764356335376165336634316133666664313865356234010b7661",
+    "6c696461746f722d310300000000000000030000000000000000a8da769b01000040323232323232",
+    "32323232323232323232323232323232323232323232323232323232323232323232323232323232",
+    "32323232323232323232323232323232323201111111111111111111111111111111111111111111",
+    "11111111111111111111112222222222222222222222222222222222222222222222222222222222",
+    "22222233333333333333333333333333333333333333333333333333333333333333330100000000",
+    "00000000000000000000000000000000000000000000000000000000444444444444444444444444",
+    "44444444444444444444444444444444444444440140303030303030303030303030303030303030",
+    "30303030303030303030303030303030303030303030303030303030303030303030303030303030",
+    "303030303030010000000000000000a8da769b010000",
+);
+
+const CF_ID: &str = "fb55918056025bf97781c760f7870c1107ff2bcb63cae0d490c3de7750beacf8";
+const CF_BCS: &str = concat!(
+    "40666235353931383035363032356266393737383163373630663738373063313130376666326263",
+    "62363363616530643439306333646537373530626561636638403135363661663930343161643461",
+    "39633234313362643134636134656366643932666332633133343131363764373061386134613636",
+    "30323935393462353635024062626433356431393465386234646536343030643065353966373366",
+    "62623663343865623362373663373637363236353762373163636538333731636564353740393363",
+    "31306361363333636530333761316138313034346134333562323337363035323861393930303764",
+    "356335376165336634316133666664313865356234010b76616c696461746f722d31030000000000",
+    "0000030000000000000000a8da769b01000040323232323232323232323232323232323232323232",
+    "32323232323232323232323232323232323232323232323232323232323232323232323232323232",
+    "32323201111111111111111111111111111111111111111111111111111111111111111122222222",
+    "22222222222222222222222222222222222222222222222222222222333333333333333333333333",
+    "33333333333333333333333333333333333333330100000000000000000000000000000000000000",
+    "00000000000000000000000000444444444444444444444444444444444444444444444444444444",
+    "44444444440140303030303030303030303030303030303030303030303030303030303030303030",
+    "30303030303030303030303030303030303030303030303030303030303030010000000000000000",
+    "a8da769b0100000b76616c696461746f722d31000000a8da769b01000000",
+);
+
+#[test]
+fn golden_genesis_event() {
+    let event = genesis_event();
+    assert_eq!(event.event_type, EventType::Genesis);
+    assert_eq!(event.status, EventStatus::Pending);
+    assert_eq!(event.id, GENESIS_EVENT_ID, "genesis event id drifted");
+    assert_golden("Event(Genesis)", &bcs::to_bytes(&event).unwrap(), GENESIS_EVENT_BCS);
+}
+
+#[test]
+fn golden_transfer_event() {
+    let event = transfer_event();
+    assert_eq!(event.id, TRANSFER_EVENT_ID, "transfer event id drifted");
+    assert_golden("Event(Transfer)", &bcs::to_bytes(&event).unwrap(), TRANSFER_EVENT_BCS);
+}
+
+#[test]
+fn golden_task_submit_event() {
+    let event = task_submit_event();
+    assert_eq!(event.id, TASK_SUBMIT_EVENT_ID, "task-submit event id drifted");
+    assert_golden("Event(TaskSubmit)", &bcs::to_bytes(&event).unwrap(), TASK_SUBMIT_EVENT_BCS);
+}
+
+#[test]
+fn golden_anchor() {
+    let anchor = fixed_anchor();
+    assert_eq!(anchor.id, ANCHOR_ID, "anchor id drifted");
+    assert_eq!(hex::encode(anchor.compute_hash()), ANCHOR_HASH, "anchor chain hash drifted");
+    assert_golden("Anchor", &bcs::to_bytes(&anchor).unwrap(), ANCHOR_BCS);
+}
+
+#[test]
+fn golden_consensus_frame() {
+    let cf = fixed_cf();
+    assert!(cf.verify_id());
+    assert_eq!(cf.id, CF_ID, "consensus frame id drifted");
+    assert_golden("ConsensusFrame", &bcs::to_bytes(&cf).unwrap(), CF_BCS);
+}
+
+#[test]
+fn golden_vectors_round_trip() {
+    for event in [genesis_event(), transfer_event(), task_submit_event()] {
+        let bytes = bcs::to_bytes(&event).unwrap();
+        let decoded: Event = bcs::from_bytes(&bytes).unwrap();
+        assert!(decoded.verify_id());
+        assert_eq!(bcs::to_bytes(&decoded).unwrap(), bytes);
+    }
+    let cf_bytes = bcs::to_bytes(&fixed_cf()).unwrap();
+    let decoded: ConsensusFrame = bcs::from_bytes(&cf_bytes).unwrap();
+    assert!(decoded.verify_id());
+    assert_eq!(bcs::to_bytes(&decoded).unwrap(), cf_bytes);
+}
+
+
+#[test]
+fn golden_unsigned_events_decode() {
+    for (event, legacy_hex) in [
+        (genesis_event(), GENESIS_EVENT_UNSIGNED_BCS),
+        (transfer_event(), TRANSFER_EVENT_UNSIGNED_BCS),
+    ] {
+        let legacy = hex::decode(legacy_hex).unwrap();
+        assert!(bcs::from_bytes::<Event>(&legacy).is_err());
+
+        let decoded = Event::from_bytes(&legacy).expect("pre-signature event must decode");
+        assert!(decoded.verify_id());
+        assert!(decoded.signature.is_empty());
+        assert_eq!(bcs::to_bytes(&decoded).unwrap(), bcs::to_bytes(&event).unwrap());
+    }
+}
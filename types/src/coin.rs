@@ -45,6 +45,21 @@ pub struct CoinState {
     /// Subnet ID that owns this coin type (1 subnet : 1 token)
     /// For ROOT subnet, this is "ROOT". For other subnets, it's the subnet_id.
     pub coin_type: String,
+    /// Frozen by the freeze authority: the coin cannot be transferred until
+    /// unfrozen. Only written when set, so unfrozen coins keep the original
+    /// four-field encoding (and their existing state roots).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub frozen: bool,
+}
+
+/// `CoinState` encoding from before `frozen` existed; also what an unfrozen
+/// coin serializes to today.
+#[derive(Deserialize)]
+struct LegacyCoinState {
+    owner: String,
+    balance: u64,
+    version: u64,
+    coin_type: String,
 }
 
 impl CoinState {
@@ -60,6 +75,7 @@ impl CoinState {
             balance,
             version: 1,
             coin_type,
+            frozen: false,
         }
     }
     
@@ -69,8 +85,21 @@ impl CoinState {
     }
     
     /// Deserialize from BCS bytes
+    ///
+    /// Accepts both the frozen encoding and the four-field encoding used by
+    /// unfrozen (and pre-freeze) coins. Use this rather than `bcs::from_bytes`.
     pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
-        bcs::from_bytes(bytes).ok()
+        if let Ok(state) = bcs::from_bytes(bytes) {
+            return Some(state);
+        }
+        let legacy: LegacyCoinState = bcs::from_bytes(bytes).ok()?;
+        Some(Self {
+            owner: legacy.owner,
+            balance: legacy.balance,
+            version: legacy.version,
+            coin_type: legacy.coin_type,
+            frozen: false,
+        })
     }
 }
 
@@ -115,6 +144,100 @@ impl std::fmt::Display for CoinType {
     }
 }
 
+// ============================================================================
+// CoinMetadata - Coin Type Registry Entry
+// ============================================================================
+
+/// Display metadata for a coin type (symbol, name, decimals).
+///
+/// Balances are always stored as raw `u64` base units; decimals only affect
+/// how amounts are rendered to users. One entry exists per coin type and is
+/// written to the ROOT SMT (BCS serialized) when the subnet registers its token.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CoinMetadata {
+    /// Coin type (subnet_id) this entry describes
+    pub coin_type: String,
+    /// Display symbol (e.g., "SETU", "GAME")
+    pub symbol: String,
+    /// Human-readable name
+    pub name: String,
+    /// Number of decimal places in one display unit
+    pub decimals: u8,
+}
+
+impl CoinMetadata {
+    pub fn new(
+        coin_type: impl Into<String>,
+        symbol: impl Into<String>,
+        name: impl Into<String>,
+        decimals: u8,
+    ) -> Self {
+        Self {
+            coin_type: coin_type.into(),
+            symbol: symbol.into(),
+            name: name.into(),
+            decimals,
+        }
+    }
+
+    /// Serialize to BCS bytes for storage
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("CoinMetadata BCS serialization should not fail")
+    }
+
+    /// Deserialize from BCS bytes
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bcs::from_bytes(bytes).ok()
+    }
+
+    /// Render a raw base-unit amount using this coin's decimals.
+    ///
+    /// `1_000_000` with 6 decimals → `"1.000000"`; with 0 decimals the
+    /// amount is returned unchanged.
+    pub fn format_amount(&self, amount: u64) -> String {
+        if self.decimals == 0 {
+            return amount.to_string();
+        }
+        let digits = format!("{:0>width$}", amount, width = self.decimals as usize + 1);
+        let (whole, frac) = digits.split_at(digits.len() - self.decimals as usize);
+        format!("{}.{}", whole, frac)
+    }
+}
+
+/// ObjectId of the `CoinMetadata` entry for a coin type.
+///
+/// ObjectId = BLAKE3("SETU_COIN_META:" || coin_type)
+pub fn coin_metadata_object_id(coin_type: &str) -> ObjectId {
+    ObjectId::new(crate::hash_utils::setu_hash_with_domain(b"SETU_COIN_META:", coin_type.as_bytes()))
+}
+
+/// What happens to a sender coin that a transfer drains to zero balance.
+///
+/// A validator setting: the task preparers stamp it on every `SolverTask`
+/// and the enclave applies it to each transfer it executes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ZeroBalanceCoinPolicy {
+    /// Keep the empty coin object with its owner (default).
+    #[default]
+    Keep,
+    /// Delete the coin with a `Delete` state change, so it leaves the owner
+    /// index and the SMT (where its absence is provable by non-inclusion).
+    Tombstone,
+}
+
+impl std::str::FromStr for ZeroBalanceCoinPolicy {
+    type Err = String;
+
+    /// Parse `keep` or `tombstone`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "keep" => Ok(Self::Keep),
+            "tombstone" => Ok(Self::Tombstone),
+            other => Err(format!("unknown zero-balance coin policy: {}", other)),
+        }
+    }
+}
+
 /// Balance is a value type that encapsulates token amount
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Balance {
@@ -235,6 +358,7 @@ impl Coin {
             balance: self.data.balance.value(),
             version: self.metadata.version,
             coin_type: self.data.coin_type.as_str().to_string(),
+            frozen: self.metadata.frozen,
         }
     }
     
@@ -412,6 +536,20 @@ mod tests {
         assert!(balance.withdraw(2000).is_err());
     }
     
+    #[test]
+    fn test_coin_metadata_format_amount() {
+        let meta = CoinMetadata::new("gaming-subnet", "GAME", "Game Token", 6);
+        assert_eq!(meta.format_amount(1_000_000), "1.000000");
+        assert_eq!(meta.format_amount(1_500), "0.001500");
+        assert_eq!(meta.format_amount(0), "0.000000");
+
+        let whole = CoinMetadata::new("ROOT", "SETU", "Setu", 0);
+        assert_eq!(whole.format_amount(42), "42");
+
+        let bytes = meta.to_bytes();
+        assert_eq!(CoinMetadata::from_bytes(&bytes), Some(meta));
+    }
+    
     #[test]
     fn test_balance_overflow() {
         let mut balance = Balance::new(u64::MAX - 100);
@@ -479,6 +617,35 @@ mod tests {
         assert_eq!(recovered.coin_type, "gaming-subnet");
     }
     
+    #[test]
+    fn test_coin_state_frozen_encoding_is_backward_compatible() {
+        #[derive(Serialize)]
+        struct PreFreezeCoinState {
+            owner: String,
+            balance: u64,
+            version: u64,
+            coin_type: String,
+        }
+        let legacy = bcs::to_bytes(&PreFreezeCoinState {
+            owner: "alice".to_string(),
+            balance: 5000,
+            version: 3,
+            coin_type: "ROOT".to_string(),
+        })
+        .unwrap();
+
+        // Unfrozen coins keep the pre-freeze bytes, and those bytes still decode
+        let mut state = CoinState::new("alice".to_string(), 5000);
+        state.version = 3;
+        assert_eq!(state.to_bytes(), legacy);
+        assert_eq!(CoinState::from_bytes(&legacy), Some(state.clone()));
+
+        state.frozen = true;
+        let frozen_bytes = state.to_bytes();
+        assert_eq!(frozen_bytes.len(), legacy.len() + 1);
+        assert_eq!(CoinState::from_bytes(&frozen_bytes), Some(state));
+    }
+    
     #[test]
     fn test_coin_to_coin_state_bytes_compatibility() {
         // Critical test: Verify runtime output (Coin -> BCS) can be parsed by storage layer
@@ -41,7 +41,8 @@ impl Anchor {
             .unwrap()
             .as_millis() as u64;
 
-        let id = Self::compute_id(&event_ids, &vlc_snapshot, &state_root, timestamp);
+        let (events_root, global_state_root) = Self::legacy_roots(&event_ids, &state_root);
+        let id = Self::compute_id(depth, previous_anchor.as_ref(), &events_root, &global_state_root);
 
         Self {
             id,
@@ -70,7 +71,12 @@ impl Anchor {
 
         // Use global_state_root as the legacy state_root
         let state_root = hex::encode(&merkle_roots.global_state_root);
-        let id = Self::compute_id(&event_ids, &vlc_snapshot, &state_root, timestamp);
+        let id = Self::compute_id(
+            depth,
+            previous_anchor.as_ref(),
+            &merkle_roots.events_root,
+            &merkle_roots.global_state_root,
+        );
 
         Self {
             id,
@@ -84,21 +90,48 @@ impl Anchor {
         }
     }
 
-    fn compute_id(
-        event_ids: &[EventId],
-        vlc_snapshot: &VLCSnapshot,
-        state_root: &str,
-        timestamp: u64,
+    /// Derive the anchor id from its chain position and Merkle commitments.
+    ///
+    /// The preimage is `(depth, previous_anchor, events_root, global_state_root)`
+    /// and nothing else. Node-local fields (`timestamp`, the proposer, VLC
+    /// physical time) are deliberately excluded so every validator folding
+    /// the same CF derives the same id and finalization can compare ids
+    /// across nodes.
+    pub(crate) fn compute_id(
+        depth: u64,
+        previous_anchor: Option<&AnchorId>,
+        events_root: &[u8; 32],
+        global_state_root: &[u8; 32],
     ) -> AnchorId {
         let mut hasher = blake3::Hasher::new();
         hasher.update(b"SETU_ANCHOR_ID:");
+        hasher.update(&depth.to_le_bytes());
+        match previous_anchor {
+            Some(prev) => {
+                hasher.update(&[1]);
+                hasher.update(&(prev.len() as u64).to_le_bytes());
+                hasher.update(prev.as_bytes());
+            }
+            None => {
+                hasher.update(&[0]);
+            }
+        }
+        hasher.update(events_root);
+        hasher.update(global_state_root);
+        hex::encode(hasher.finalize().as_bytes())
+    }
+
+    /// Stand-in roots for anchors built without `AnchorMerkleRoots`: a digest
+    /// of the event ids and of the legacy `state_root` string.
+    fn legacy_roots(event_ids: &[EventId], state_root: &str) -> ([u8; 32], [u8; 32]) {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(b"SETU_LEGACY_EVENTS:");
         for event_id in event_ids {
+            hasher.update(&(event_id.len() as u64).to_le_bytes());
             hasher.update(event_id.as_bytes());
         }
-        hasher.update(&vlc_snapshot.logical_time.to_le_bytes());
-        hasher.update(state_root.as_bytes());
-        hasher.update(&timestamp.to_le_bytes());
-        hex::encode(hasher.finalize().as_bytes())
+        let events_root = *hasher.finalize().as_bytes();
+        (events_root, *blake3::hash(state_root.as_bytes()).as_bytes())
     }
 
     pub fn event_count(&self) -> usize {
@@ -303,7 +336,7 @@ impl ConsensusFrame {
         }
     }
 
-    fn compute_id(anchor: &Anchor, proposer: &str, timestamp: u64) -> CFId {
+    pub(crate) fn compute_id(anchor: &Anchor, proposer: &str, timestamp: u64) -> CFId {
         let mut hasher = blake3::Hasher::new();
         hasher.update(b"SETU_CF_ID:");
         hasher.update(anchor.id.as_bytes());
@@ -371,6 +404,63 @@ impl ConsensusFrame {
         let expected_id = Self::compute_id(&self.anchor, &self.proposer, self.created_at);
         self.id == expected_id
     }
+
+    /// Summarize the approving votes, weighted by `voting_power`.
+    ///
+    /// `total_power` is the validator set's total voting power at the time
+    /// the certificate is taken.
+    pub fn vote_certificate(
+        &self,
+        voting_power: impl Fn(&str) -> u128,
+        total_power: u128,
+    ) -> VoteCertificate {
+        let mut approvals: Vec<CertifiedVote> = self
+            .votes
+            .values()
+            .filter(|vote| vote.approve)
+            .map(|vote| CertifiedVote {
+                validator_id: vote.validator_id.clone(),
+                voting_power: voting_power(&vote.validator_id),
+                signature: vote.signature.clone(),
+            })
+            .collect();
+        approvals.sort_by(|a, b| a.validator_id.cmp(&b.validator_id));
+
+        VoteCertificate {
+            cf_id: self.id.clone(),
+            anchor_id: self.anchor.id.clone(),
+            approved_power: approvals.iter().map(|a| a.voting_power).sum(),
+            total_power,
+            approvals,
+        }
+    }
+}
+
+/// Evidence that a finalized CF met quorum: who approved it and with how
+/// much voting power.
+///
+/// Taken at finalization against the validator set in effect at that moment,
+/// so later stake or membership changes do not rewrite it. Kept outside
+/// `ConsensusFrame`, whose BCS layout is pinned (see `bcs_golden`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteCertificate {
+    pub cf_id: CFId,
+    pub anchor_id: AnchorId,
+    /// Approving votes, sorted by validator id
+    pub approvals: Vec<CertifiedVote>,
+    /// Sum of the approvers' voting power
+    pub approved_power: u128,
+    /// Total voting power of the validator set
+    pub total_power: u128,
+}
+
+/// One approving vote within a [`VoteCertificate`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CertifiedVote {
+    pub validator_id: String,
+    pub voting_power: u128,
+    /// The vote's signature (empty if unsigned)
+    pub signature: Vec<u8>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -380,6 +470,48 @@ pub struct ConsensusConfig {
     pub max_events_per_cf: usize,
     pub cf_timeout_ms: u64,
     pub validator_count: usize,
+    /// Maximum events held in the in-memory DAG before local submissions are
+    /// refused with `SetuError::Overloaded` (0 = unlimited)
+    #[serde(default = "default_max_dag_events")]
+    pub max_dag_events: usize,
+    /// Maximum not-yet-finalized events before local submissions are refused
+    /// with `SetuError::Overloaded` (0 = unlimited)
+    #[serde(default = "default_max_pending_events")]
+    pub max_pending_events: usize,
+    /// Finalized CFs kept in `ConsensusManager` memory. Older ones are
+    /// evicted once their anchors are persisted; unpersisted CFs are never
+    /// dropped, so memory can exceed this while persistence lags.
+    #[serde(default = "default_finalized_cf_retention")]
+    pub finalized_cf_retention: usize,
+    /// Longest a leader waits between folds while events are pending. Once
+    /// it elapses a CF is proposed even if the VLC delta is still below
+    /// `vlc_delta_threshold` (0 = fold on VLC delta only)
+    #[serde(default = "default_max_fold_interval_ms")]
+    pub max_fold_interval_ms: u64,
+    /// Most parents an event may reference. Events over the cap are rejected
+    /// by the DAG and parent selection never picks more (0 = unlimited)
+    #[serde(default = "default_max_parents_per_event")]
+    pub max_parents_per_event: usize,
+}
+
+fn default_max_dag_events() -> usize {
+    100_000
+}
+
+fn default_max_pending_events() -> usize {
+    50_000
+}
+
+fn default_finalized_cf_retention() -> usize {
+    1000
+}
+
+fn default_max_fold_interval_ms() -> u64 {
+    5000
+}
+
+fn default_max_parents_per_event() -> usize {
+    64
 }
 
 impl Default for ConsensusConfig {
@@ -390,6 +522,11 @@ impl Default for ConsensusConfig {
             max_events_per_cf: 1000,
             cf_timeout_ms: 5000,
             validator_count: 3,
+            max_dag_events: default_max_dag_events(),
+            max_pending_events: default_max_pending_events(),
+            finalized_cf_retention: default_finalized_cf_retention(),
+            max_fold_interval_ms: default_max_fold_interval_ms(),
+            max_parents_per_event: default_max_parents_per_event(),
         }
     }
 }
@@ -418,6 +555,32 @@ mod tests {
         assert_eq!(anchor.event_count(), 2);
     }
 
+    #[test]
+    fn test_anchor_id_is_independent_of_node_local_fields() {
+        let roots = AnchorMerkleRoots::with_roots([1; 32], [2; 32], [3; 32]);
+        let events = vec!["event1".to_string(), "event2".to_string()];
+        let prev = Some("anchor-4".to_string());
+
+        let a = Anchor::with_merkle_roots(
+            events.clone(),
+            create_vlc_snapshot(),
+            roots.clone(),
+            prev.clone(),
+            5,
+        );
+        // A second validator folds the same CF later, with its own clock
+        let mut other_vlc = create_vlc_snapshot();
+        other_vlc.physical_time = 99_999;
+        let mut b = Anchor::with_merkle_roots(events, other_vlc, roots.clone(), prev.clone(), 5);
+        b.timestamp = a.timestamp + 1_000;
+
+        assert_eq!(a.id, Anchor::compute_id(5, prev.as_ref(), &[1; 32], &[2; 32]));
+        assert_eq!(a.id, b.id);
+
+        let next_depth = Anchor::with_merkle_roots(vec![], create_vlc_snapshot(), roots, prev, 6);
+        assert_ne!(a.id, next_depth.id);
+    }
+
     #[test]
     fn test_cf_voting() {
         let anchor = Anchor::new(
@@ -20,6 +20,10 @@ use crate::coin::{CoinState, CoinData, CoinType, Balance};
 /// Magic number: first 2 BCS bytes of ObjectEnvelope = [0x53, 0x45] ("SE" little-endian).
 pub const ENVELOPE_MAGIC: u16 = 0x4553;
 
+/// Domain tag of the envelope digest. Distinct from the `Object` digest
+/// (`SETU_OBJ_DIGEST:`), which hashes a different preimage.
+pub const ENVELOPE_DIGEST_DOMAIN: &[u8] = b"SETU_ENV_DIGEST:";
+
 /// Universal object envelope — wraps any Move object or legacy Coin.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ObjectEnvelope {
@@ -31,6 +35,21 @@ pub struct ObjectEnvelope {
     pub type_tag: String,
     /// BCS-serialized Move struct data
     pub data: Vec<u8>,
+    /// Coin frozen by the freeze authority (mirrors `CoinState::frozen`).
+    /// Only written when set, so unfrozen envelopes keep the original
+    /// encoding; decode with [`ObjectEnvelope::from_bytes`], not `bcs::from_bytes`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub frozen: bool,
+}
+
+/// `ObjectEnvelope` encoding from before `frozen` existed; also what an
+/// unfrozen envelope serializes to today.
+#[derive(Deserialize)]
+struct LegacyObjectEnvelope {
+    magic: u16,
+    metadata: EnvelopeMetadata,
+    type_tag: String,
+    data: Vec<u8>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -39,7 +58,8 @@ pub struct EnvelopeMetadata {
     pub owner: Address,
     pub version: u64,
     pub ownership: Ownership,
-    /// BLAKE3 digest of `data` (domain: "SETU_OBJ_DIGEST:")
+    /// BLAKE3 digest of `id || version || owner || data`
+    /// (domain: [`ENVELOPE_DIGEST_DOMAIN`])
     pub digest: ObjectDigest,
 }
 
@@ -53,12 +73,13 @@ impl ObjectEnvelope {
         type_tag: String,
         bcs_data: Vec<u8>,
     ) -> Self {
-        let digest = Self::compute_digest(&bcs_data);
+        let digest = Self::compute_digest(&id, version, &owner, &bcs_data);
         Self {
             magic: ENVELOPE_MAGIC,
             metadata: EnvelopeMetadata { id, owner, version, ownership, digest },
             type_tag,
             data: bcs_data,
+            frozen: false,
         }
     }
 
@@ -67,14 +88,16 @@ impl ObjectEnvelope {
         let coin_bcs = bcs::to_bytes(&obj.data)
             .map_err(|e| format!("BCS serialize CoinData: {e}"))?;
         let owner = obj.metadata.owner.unwrap_or(Address::ZERO);
-        Ok(Self::from_move_result(
+        let mut env = Self::from_move_result(
             obj.metadata.id,
             owner,
             obj.metadata.version,
             obj.metadata.ownership,
             format!("0x1::coin::Coin<0x1::setu::{}>", obj.data.coin_type.as_str()),
             coin_bcs,
-        ))
+        );
+        env.frozen = obj.metadata.frozen;
+        Ok(env)
     }
 
     /// Build from legacy `CoinState` bytes.
@@ -86,14 +109,16 @@ impl ObjectEnvelope {
         };
         let bcs_data = bcs::to_bytes(&coin_data)
             .map_err(|e| format!("BCS serialize CoinData: {e}"))?;
-        Ok(Self::from_move_result(
+        let mut env = Self::from_move_result(
             id,
             owner,
             cs.version,
             Ownership::AddressOwner(owner),
             format!("0x1::coin::Coin<0x1::setu::{}>", cs.coin_type),
             bcs_data,
-        ))
+        );
+        env.frozen = cs.frozen;
+        Ok(env)
     }
 
     /// Try converting back to `Object<CoinData>` (backward compat).
@@ -109,6 +134,7 @@ impl ObjectEnvelope {
                 ownership: self.metadata.ownership,
                 created_at: 0,
                 updated_at: 0,
+                frozen: self.frozen,
             },
             data: coin_data,
         })
@@ -120,13 +146,42 @@ impl ObjectEnvelope {
     }
 
     /// Deserialize from BCS bytes.
+    ///
+    /// Accepts both the frozen encoding and the encoding used by unfrozen
+    /// (and pre-freeze) envelopes. Envelopes stored under an older digest
+    /// definition come back with the digest recomputed, so they migrate
+    /// lazily the next time they are read and rewritten.
     pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
-        bcs::from_bytes(bytes).ok()
+        let mut env = match bcs::from_bytes::<Self>(bytes) {
+            Ok(env) => env,
+            Err(_) => {
+                let legacy: LegacyObjectEnvelope = bcs::from_bytes(bytes).ok()?;
+                Self {
+                    magic: legacy.magic,
+                    metadata: legacy.metadata,
+                    type_tag: legacy.type_tag,
+                    data: legacy.data,
+                    frozen: false,
+                }
+            }
+        };
+        env.metadata.digest = env.current_digest();
+        Some(env)
+    }
+
+    /// Digest of this envelope under the current definition
+    pub fn current_digest(&self) -> ObjectDigest {
+        Self::compute_digest(&self.metadata.id, self.metadata.version, &self.metadata.owner, &self.data)
     }
 
-    fn compute_digest(data: &[u8]) -> ObjectDigest {
+    /// Hashes the header fields along with the data, so two objects (or two
+    /// versions of one object) with equal contents never share a digest.
+    fn compute_digest(id: &ObjectId, version: u64, owner: &Address, data: &[u8]) -> ObjectDigest {
         let mut hasher = blake3::Hasher::new();
-        hasher.update(b"SETU_OBJ_DIGEST:");
+        hasher.update(ENVELOPE_DIGEST_DOMAIN);
+        hasher.update(id.as_bytes());
+        hasher.update(&version.to_le_bytes());
+        hasher.update(owner.as_bytes());
         hasher.update(data);
         ObjectDigest::new(*hasher.finalize().as_bytes())
     }
@@ -218,6 +273,82 @@ mod tests {
         assert_eq!(coin_obj.data.balance.value(), 2000);
     }
 
+    #[test]
+    fn test_envelope_carries_frozen_flag() {
+        let owner = Address::from_str_id("erin");
+        let mut cs = CoinState::new(owner.to_string(), 300);
+        cs.frozen = true;
+        let id = ObjectId::new([4u8; 32]);
+
+        let env = ObjectEnvelope::from_legacy_coin_state(id, &cs).unwrap();
+        assert!(env.frozen);
+        let restored = ObjectEnvelope::from_bytes(&env.to_bytes()).expect("frozen envelope decodes");
+        assert_eq!(restored, env);
+
+        // Envelope -> Object -> envelope keeps the flag
+        let coin = restored.try_as_coin_object().unwrap();
+        assert!(coin.metadata.frozen);
+        assert!(ObjectEnvelope::from_coin_object(&coin).unwrap().frozen);
+
+        // Unfrozen envelopes keep the pre-freeze bytes
+        let unfrozen = sample_envelope();
+        let mut bytes = unfrozen.to_bytes();
+        assert!(!ObjectEnvelope::from_bytes(&bytes).unwrap().frozen);
+        let mut frozen = unfrozen.clone();
+        frozen.frozen = true;
+        bytes.push(1);
+        assert_eq!(frozen.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_envelope_digest_covers_header() {
+        let env = sample_envelope();
+        let digest_with = |id, version, owner| {
+            ObjectEnvelope::from_move_result(
+                id,
+                owner,
+                version,
+                Ownership::AddressOwner(owner),
+                env.type_tag.clone(),
+                env.data.clone(),
+            )
+            .metadata
+            .digest
+        };
+        let (id, owner) = (env.metadata.id, env.metadata.owner);
+        assert_eq!(digest_with(id, 1, owner), env.metadata.digest);
+
+        // Same data, different id / version / owner
+        assert_ne!(digest_with(ObjectId::new([2u8; 32]), 1, owner), env.metadata.digest);
+        assert_ne!(digest_with(id, 2, owner), env.metadata.digest);
+        assert_ne!(digest_with(id, 1, Address::from_str_id("bob")), env.metadata.digest);
+    }
+
+    #[test]
+    fn test_envelope_digest_domain_differs_from_object_digest() {
+        let mut coin = crate::create_coin(Address::from_str_id("dave"), 750);
+        coin.compute_digest();
+        let env = ObjectEnvelope::from_coin_object(&coin).unwrap();
+        assert_ne!(env.metadata.digest, coin.metadata.digest);
+    }
+
+    #[test]
+    fn test_stale_digest_recomputed_on_decode() {
+        let env = sample_envelope();
+        // Digest as written before the header was hashed under its own domain
+        let mut stale = env.clone();
+        stale.metadata.digest = ObjectDigest::new(
+            *blake3::Hasher::new()
+                .update(b"SETU_OBJ_DIGEST:")
+                .update(&env.data)
+                .finalize()
+                .as_bytes(),
+        );
+        let decoded = ObjectEnvelope::from_bytes(&stale.to_bytes()).unwrap();
+        assert_eq!(decoded.metadata.digest, env.metadata.digest);
+        assert_eq!(decoded, env);
+    }
+
     #[test]
     fn test_envelope_try_as_coin_object() {
         let owner = Address::from_str_id("dave");
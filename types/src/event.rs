@@ -505,9 +505,60 @@ pub struct Event {
     
     /// Creation timestamp (milliseconds since epoch)
     pub timestamp: u64,
+
+    /// Creator's ed25519 signature over `signing_message()`. Empty if unsigned.
+    ///
+    /// Tail field: it is not part of the id preimage, and keeping it last
+    /// leaves every preceding field's BCS offset unchanged.
+    #[serde(default)]
+    pub signature: Vec<u8>,
+}
+
+/// `Event` encoding from before `signature` existed. BCS does not honour
+/// `#[serde(default)]`, so events stored by older nodes only decode through
+/// this shape; see [`Event::from_bytes`].
+#[derive(Deserialize)]
+struct LegacyEvent {
+    id: EventId,
+    event_type: EventType,
+    parent_ids: Vec<EventId>,
+    subnet_id: Option<crate::subnet::SubnetId>,
+    transfer: Option<Transfer>,
+    payload: EventPayload,
+    vlc_snapshot: VLCSnapshot,
+    creator: String,
+    status: EventStatus,
+    execution_result: Option<ExecutionResult>,
+    timestamp: u64,
 }
 
 impl Event {
+    /// Deserialize from BCS bytes
+    ///
+    /// Accepts both the current encoding and the unsigned encoding written
+    /// before `signature` existed (decoded with an empty signature). Use this
+    /// rather than `bcs::from_bytes` for stored events.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if let Ok(event) = bcs::from_bytes(bytes) {
+            return Some(event);
+        }
+        let legacy: LegacyEvent = bcs::from_bytes(bytes).ok()?;
+        Some(Self {
+            id: legacy.id,
+            event_type: legacy.event_type,
+            parent_ids: legacy.parent_ids,
+            subnet_id: legacy.subnet_id,
+            transfer: legacy.transfer,
+            payload: legacy.payload,
+            vlc_snapshot: legacy.vlc_snapshot,
+            creator: legacy.creator,
+            status: legacy.status,
+            execution_result: legacy.execution_result,
+            timestamp: legacy.timestamp,
+            signature: Vec::new(),
+        })
+    }
+
     /// Create a new event
     pub fn new(
         event_type: EventType,
@@ -543,6 +594,7 @@ impl Event {
             status: EventStatus::Pending,
             execution_result: None,
             timestamp,
+            signature: Vec::new(),
         }
     }
 
@@ -759,6 +811,104 @@ impl Event {
         computed == self.id
     }
 
+    /// Hash of the event's logical content: type, parents, payload and
+    /// execution result.
+    ///
+    /// Unlike `id`, this excludes timestamp, VLC snapshot and status, so
+    /// resubmitting the same logical event (e.g. a solver retry) yields the
+    /// same hash under a different id. Parents are hashed in sorted order as
+    /// a BCS vector, so each id is length-prefixed and ids cannot run into
+    /// each other.
+    pub fn content_hash(&self) -> [u8; 32] {
+        let mut parent_ids: Vec<&EventId> = self.parent_ids.iter().collect();
+        parent_ids.sort();
+
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(b"SETU_EVENT_CONTENT:");
+        hasher.update(&bcs::to_bytes(&self.event_type).expect("EventType BCS serialization should not fail"));
+        hasher.update(&bcs::to_bytes(&parent_ids).expect("parent id BCS serialization should not fail"));
+        hasher.update(&bcs::to_bytes(&self.payload).expect("EventPayload BCS serialization should not fail"));
+        hasher.update(
+            &bcs::to_bytes(&self.execution_result).expect("ExecutionResult BCS serialization should not fail"),
+        );
+        *hasher.finalize().as_bytes()
+    }
+
+    /// Canonical total order over events: VLC logical_time, then VLC
+    /// physical_time, then event id.
+    ///
+    /// Every place that needs a deterministic event order (execution batches,
+    /// anchor building, state apply and replay) must use this, since
+    /// validators only agree on state if they apply events in the same order.
+    pub fn canonical_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.vlc_snapshot
+            .logical_time
+            .cmp(&other.vlc_snapshot.logical_time)
+            .then_with(|| self.vlc_snapshot.physical_time.cmp(&other.vlc_snapshot.physical_time))
+            .then_with(|| self.id.cmp(&other.id))
+    }
+
+    /// Sign the event with the creator's private key (ed25519)
+    ///
+    /// Must be called after the id and content are final: any later change
+    /// to the payload, parents or execution result invalidates the signature.
+    pub fn sign(&mut self, private_key: &[u8]) -> Result<(), String> {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        if private_key.len() != 32 {
+            return Err(format!("Invalid private key length: expected 32, got {}", private_key.len()));
+        }
+
+        let signing_key = SigningKey::from_bytes(
+            private_key.try_into().map_err(|_| "Failed to convert key")?
+        );
+
+        let signature = signing_key.sign(&self.signing_message());
+        self.signature = signature.to_bytes().to_vec();
+
+        Ok(())
+    }
+
+    /// Verify the event signature with the creator's public key (ed25519)
+    ///
+    /// Returns true if the signature is valid for the given public key.
+    pub fn verify_signature(&self, public_key: &[u8]) -> bool {
+        use ed25519_dalek::{Verifier, VerifyingKey, Signature as Ed25519Signature};
+
+        if self.signature.is_empty() {
+            return false;
+        }
+
+        let Ok(public_key) = <&[u8; 32]>::try_from(public_key) else {
+            return false;
+        };
+        let Ok(verifying_key) = VerifyingKey::from_bytes(public_key) else {
+            return false;
+        };
+        let Ok(signature) = Ed25519Signature::from_slice(&self.signature) else {
+            return false;
+        };
+
+        verifying_key.verify(&self.signing_message(), &signature).is_ok()
+    }
+
+    /// Create the deterministic message for signing
+    ///
+    /// Message format: protocol_id || id || creator || content_hash
+    ///
+    /// The id already commits to the creator, but it is included explicitly
+    /// so a signature never verifies for an event re-attributed to another
+    /// creator, even if the id is not checked first.
+    fn signing_message(&self) -> Vec<u8> {
+        let mut message = Vec::new();
+        // Protocol domain separator (version 1)
+        message.extend_from_slice(b"SETU_EVENT_V1");
+        message.extend_from_slice(self.id.as_bytes());
+        message.extend_from_slice(self.creator.as_bytes());
+        message.extend_from_slice(&self.content_hash());
+        message
+    }
+
     /// Recompute and update the event ID based on current fields.
     ///
     /// Must be called after modifying `creator`, `timestamp`, or `vlc_snapshot`
@@ -966,6 +1116,11 @@ impl Event {
     }
 }
 
+/// Sort events by [`Event::canonical_cmp`]
+pub fn sort_events_canonically(events: &mut [Event]) {
+    events.sort_by(Event::canonical_cmp);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -991,6 +1146,33 @@ mod tests {
         assert_eq!(event.status, EventStatus::Pending);
     }
 
+    #[test]
+    fn test_content_hash_separates_parent_ids() {
+        let with_parents = |parents: &[&str]| {
+            Event::new(
+                EventType::Transfer,
+                parents.iter().map(|p| p.to_string()).collect(),
+                create_vlc_snapshot(),
+                "node1".to_string(),
+            )
+        };
+
+        // Same concatenated bytes, different parent sets
+        assert_ne!(
+            with_parents(&["ab", "c"]).content_hash(),
+            with_parents(&["a", "bc"]).content_hash()
+        );
+        assert_ne!(
+            with_parents(&["abc"]).content_hash(),
+            with_parents(&["ab", "c"]).content_hash()
+        );
+        // Parent order still does not matter
+        assert_eq!(
+            with_parents(&["a", "bc"]).content_hash(),
+            with_parents(&["bc", "a"]).content_hash()
+        );
+    }
+
     #[test]
     fn test_genesis_event() {
         let event = Event::genesis("node1".to_string(), create_vlc_snapshot());
@@ -1132,6 +1314,61 @@ mod tests {
         assert_eq!(result.state_changes.len(), 1);
     }
 
+    #[test]
+    fn test_signed_event_verifies_with_creator_key() {
+        let creator_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let mut event = Event::new(
+            EventType::Transfer,
+            vec![],
+            create_vlc_snapshot(),
+            "solver-1".to_string(),
+        );
+        assert!(!event.verify_signature(creator_key.verifying_key().as_bytes()));
+
+        event.sign(&creator_key.to_bytes()).unwrap();
+        assert!(event.verify_signature(creator_key.verifying_key().as_bytes()));
+
+        // Content changes after signing invalidate the signature
+        event.set_execution_result(ExecutionResult::failure("tampered"));
+        assert!(!event.verify_signature(creator_key.verifying_key().as_bytes()));
+    }
+
+    #[test]
+    fn test_forged_creator_signature_rejected() {
+        let victim_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let attacker_key = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+
+        // Attacker builds a well-formed event attributed to the victim
+        let mut forged = Event::new(
+            EventType::Transfer,
+            vec![],
+            create_vlc_snapshot(),
+            "solver-1".to_string(),
+        );
+        forged.sign(&attacker_key.to_bytes()).unwrap();
+        assert!(forged.verify_id());
+        assert!(!forged.verify_signature(victim_key.verifying_key().as_bytes()));
+
+        // Re-attributing a legitimately signed event breaks the signature too
+        let mut event = Event::new(
+            EventType::Transfer,
+            vec![],
+            create_vlc_snapshot(),
+            "solver-2".to_string(),
+        );
+        event.sign(&attacker_key.to_bytes()).unwrap();
+        event.creator = "solver-1".to_string();
+        event.recompute_id();
+        assert!(!event.verify_signature(attacker_key.verifying_key().as_bytes()));
+    }
+
+    #[test]
+    fn test_event_sign_rejects_bad_key_length() {
+        let mut event = Event::genesis("node1".to_string(), create_vlc_snapshot());
+        assert!(event.sign(&[1u8; 16]).is_err());
+        assert!(event.signature.is_empty());
+    }
+
     // ── Governance tests ──
 
     #[test]
@@ -1394,4 +1631,63 @@ mod tests {
              unless you are creating a fresh payload type."
         );
     }
+
+    #[test]
+    fn test_canonical_cmp_is_strict_total_order() {
+        use std::cmp::Ordering;
+
+        let at = |creator: &str, logical_time: u64, physical_time: u64| {
+            Event::new(
+                EventType::Transfer,
+                vec![],
+                VLCSnapshot { vector_clock: VectorClock::new(), logical_time, physical_time },
+                creator.to_string(),
+            )
+        };
+        // Ties on logical time, on both times, and on physical time alone
+        let sample = vec![
+            at("n1", 2, 100),
+            at("n2", 2, 100),
+            at("n1", 2, 50),
+            at("n1", 1, 900),
+            at("n3", 3, 10),
+            at("n2", 1, 900),
+        ];
+
+        for a in &sample {
+            assert_eq!(a.canonical_cmp(a), Ordering::Equal);
+            for b in &sample {
+                assert_eq!(a.canonical_cmp(b), b.canonical_cmp(a).reverse());
+                if a.id != b.id {
+                    assert_ne!(a.canonical_cmp(b), Ordering::Equal);
+                }
+                for c in &sample {
+                    if a.canonical_cmp(b) == Ordering::Less && b.canonical_cmp(c) == Ordering::Less {
+                        assert_eq!(a.canonical_cmp(c), Ordering::Less);
+                    }
+                }
+            }
+        }
+
+        let ids = |events: &[Event]| events.iter().map(|e| e.id.clone()).collect::<Vec<_>>();
+        let mut expected = sample.clone();
+        sort_events_canonically(&mut expected);
+        let times: Vec<(u64, u64)> = expected
+            .iter()
+            .map(|e| (e.vlc_snapshot.logical_time, e.vlc_snapshot.physical_time))
+            .collect();
+        assert_eq!(times[..3], [(1, 900), (1, 900), (2, 50)]);
+        assert_eq!(times[3..], [(2, 100), (2, 100), (3, 10)]);
+
+        // Every input order sorts to the same sequence
+        for shift in 0..sample.len() {
+            let mut events = sample.clone();
+            events.rotate_left(shift);
+            sort_events_canonically(&mut events);
+            assert_eq!(ids(&events), ids(&expected));
+            events.reverse();
+            sort_events_canonically(&mut events);
+            assert_eq!(ids(&events), ids(&expected));
+        }
+    }
 }
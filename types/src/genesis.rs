@@ -3,7 +3,12 @@
 //! Defines the structure of genesis.json and provides utilities
 //! to build Genesis Events with proper state changes.
 
+use crate::coin::{deterministic_coin_id_from_str, deterministic_genesis_coin_id, CoinState};
+use crate::event::StateChange;
+use crate::object::Address;
+use crate::subnet::SubnetId;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// Genesis configuration loaded from genesis.json
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,8 +30,18 @@ pub struct GenesisConfig {
     /// Initial validator set (static configuration for multi-validator)
     #[serde(default)]
     pub validators: Vec<GenesisValidator>,
+
+    /// Largest serialized object value any write may store (default 1 MiB)
+    ///
+    /// A network parameter: every validator must enforce the same cap, or
+    /// they would disagree on which events apply.
+    #[serde(default = "default_max_object_bytes")]
+    pub max_object_bytes: usize,
 }
 
+/// Default cap on a single object's serialized value (1 MiB)
+pub const DEFAULT_MAX_OBJECT_BYTES: usize = 1024 * 1024;
+
 /// A single account entry in genesis.json
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenesisAccount {
@@ -37,9 +52,17 @@ pub struct GenesisAccount {
     #[serde(default)]
     pub name: Option<String>,
 
-    /// Initial balance in the smallest unit
+    /// Initial balance in the smallest unit, in the genesis `subnet_id` coin type
+    ///
+    /// Single-balance form kept for existing genesis files; ignored when
+    /// `balances` is non-empty.
+    #[serde(default)]
     pub balance: u64,
 
+    /// Initial balances per coin type; one coin object set per entry
+    #[serde(default)]
+    pub balances: Vec<GenesisBalance>,
+
     /// Number of coin objects to create for this account (default: 1)
     ///
     /// When > 1, the total balance is split evenly across N coin objects.
@@ -52,6 +75,32 @@ pub struct GenesisAccount {
     pub coins_per_account: u32,
 }
 
+/// One coin type balance of a genesis account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenesisBalance {
+    /// Coin type (e.g. "ROOT", "USDC")
+    pub coin_type: String,
+    /// Amount in the smallest unit
+    pub amount: u64,
+}
+
+impl GenesisAccount {
+    /// Balances to seed as `(coin_type, amount)`
+    ///
+    /// Falls back to the single `balance` in `default_coin_type` when no
+    /// `balances` are listed.
+    pub fn coin_balances(&self, default_coin_type: &str) -> Vec<(String, u64)> {
+        if self.balances.is_empty() {
+            vec![(default_coin_type.to_string(), self.balance)]
+        } else {
+            self.balances
+                .iter()
+                .map(|b| (b.coin_type.clone(), b.amount))
+                .collect()
+        }
+    }
+}
+
 /// A validator entry in genesis.json
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenesisValidator {
@@ -74,6 +123,10 @@ fn default_coins_per_account() -> u32 {
     1
 }
 
+fn default_max_object_bytes() -> usize {
+    DEFAULT_MAX_OBJECT_BYTES
+}
+
 impl GenesisConfig {
     /// Load genesis configuration from a JSON file
     pub fn load(path: &str) -> Result<Self, GenesisError> {
@@ -85,9 +138,66 @@ impl GenesisConfig {
         if config.accounts.is_empty() {
             return Err(GenesisError::NoAccounts);
         }
+        for account in &config.accounts {
+            let mut seen = HashSet::new();
+            for balance in &account.balances {
+                if !seen.insert(balance.coin_type.as_str()) {
+                    return Err(GenesisError::DuplicateCoinType(
+                        account.address.clone(),
+                        balance.coin_type.clone(),
+                    ));
+                }
+            }
+        }
 
         Ok(config)
     }
+
+    /// Build the coin-creating state changes for every genesis account
+    ///
+    /// Creates one coin object per (account, coin_type), split into
+    /// `coins_per_account` objects when > 1. Coin IDs are deterministic so
+    /// every validator produces identical state. Non-ROOT coin types carry
+    /// their subnet in `target_subnet`; applying the changes registers each
+    /// coin in the owner and coin_type indexes.
+    pub fn build_state_changes(&self) -> Result<Vec<StateChange>, GenesisError> {
+        let mut state_changes = Vec::new();
+        for account in &self.accounts {
+            let owner_hex = Address::from_hex(&account.address)
+                .map_err(|_| GenesisError::InvalidAddress(account.address.clone()))?
+                .to_string();
+            let num_coins = account.coins_per_account.max(1) as u64;
+
+            for (coin_type, amount) in account.coin_balances(&self.subnet_id) {
+                // Coins live in the SMT of the subnet bound to their type
+                let target_subnet = match SubnetId::from_coin_type(&coin_type) {
+                    SubnetId::ROOT => None,
+                    subnet => Some(subnet),
+                };
+                let balance_per_coin = amount / num_coins;
+                // Last coin absorbs the rounding remainder
+                let remainder = amount - balance_per_coin * (num_coins - 1);
+
+                for idx in 0..num_coins {
+                    let coin_balance = if idx == num_coins - 1 { remainder } else { balance_per_coin };
+                    // Index 0 uses the legacy deterministic_coin_id for compatibility
+                    let object_id = if idx == 0 {
+                        deterministic_coin_id_from_str(&owner_hex, &coin_type)
+                    } else {
+                        deterministic_genesis_coin_id(&owner_hex, &coin_type, idx as u32)
+                    };
+                    let coin_state = CoinState::new_with_type(owner_hex.clone(), coin_balance, coin_type.clone());
+                    state_changes.push(StateChange {
+                        key: format!("oid:{}", hex::encode(object_id.as_bytes())),
+                        old_value: None,
+                        new_value: Some(coin_state.to_bytes()),
+                        target_subnet,
+                    });
+                }
+            }
+        }
+        Ok(state_changes)
+    }
 }
 
 /// Errors during genesis processing
@@ -101,4 +211,10 @@ pub enum GenesisError {
 
     #[error("Genesis config has no accounts")]
     NoAccounts,
+
+    #[error("Genesis account '{0}' has an invalid hex address")]
+    InvalidAddress(String),
+
+    #[error("Genesis account '{0}' lists coin type '{1}' more than once")]
+    DuplicateCoinType(String, String),
 }
@@ -84,8 +84,30 @@ pub fn chain_hash(prev_root: &Hash, new_hash: &Hash) -> Hash {
 ///
 /// Each change is serialized as: key_bytes || presence(old) || old_value || presence(new) || new_value
 /// where presence is `[1u8]` if Some, `[0u8]` if None.
+///
+/// Changes are hashed in the given order; attestation paths use
+/// [`compute_sorted_write_set_commitment`].
 pub fn compute_write_set_commitment(
     changes: &[(String, Option<Vec<u8>>, Option<Vec<u8>>)],
+) -> Hash {
+    hash_write_set(changes)
+}
+
+/// Compute write-set commitment with changes ordered by key.
+///
+/// Two executors producing the same writes in a different internal order
+/// get the same commitment. The sort is stable, so repeated writes to one
+/// key keep their relative (last-write-wins) order.
+pub fn compute_sorted_write_set_commitment(
+    changes: &[(String, Option<Vec<u8>>, Option<Vec<u8>>)],
+) -> Hash {
+    let mut sorted: Vec<_> = changes.iter().collect();
+    sorted.sort_by(|a, b| a.0.cmp(&b.0));
+    hash_write_set(sorted)
+}
+
+fn hash_write_set<'a>(
+    changes: impl IntoIterator<Item = &'a (String, Option<Vec<u8>>, Option<Vec<u8>>)>,
 ) -> Hash {
     let mut hasher = blake3::Hasher::new();
     hasher.update(b"SETU_WRITE_SET_COMMITMENT:");
@@ -24,6 +24,9 @@ pub mod genesis;        // Genesis configuration
 pub mod resource;       // Resource types: FluxState, PowerState, governance
 pub mod governance;     // Governance proposal types
 
+#[cfg(test)]
+mod bcs_golden;         // BCS golden vectors for Event / Anchor / ConsensusFrame
+
 // Re-export VLC types from setu-vlc (single source of truth)
 pub use setu_vlc::{VectorClock, VLCSnapshot};
 
@@ -44,6 +47,7 @@ pub use event::{
     Event, EventId, EventStatus, EventType, EventPayload,
     ExecutionResult, StateChange,
     MoveCallPayload, MovePublishPayload,
+    sort_events_canonically,
 };
 
 // State key format helpers
@@ -56,14 +60,14 @@ pub use execution_outcome::ExecutionOutcome;
 pub use dynamic_field::{DfAccessMode, DfFieldValue, derive_df_oid};
 
 // Export from consensus module
-pub use consensus::{Anchor, AnchorId, ConsensusFrame, CFId, CFStatus, Vote, ConsensusConfig};
+pub use consensus::{Anchor, AnchorId, ConsensusFrame, CFId, CFStatus, Vote, ConsensusConfig, VoteCertificate, CertifiedVote};
 pub use node::*;
 
 // ========== Object Model Exports ==========
 pub use object::{Object, ObjectId, Address, ObjectDigest, ObjectType, ObjectMetadata, Ownership, generate_object_id};
 
 // Coin related
-pub use coin::{Coin, CoinType, CoinData, CoinState, Balance, create_coin, create_typed_coin, deterministic_coin_id, deterministic_coin_id_from_str, deterministic_genesis_coin_id, coin_id_from_tx, create_coin_with_id};
+pub use coin::{Coin, CoinType, CoinData, CoinState, CoinMetadata, coin_metadata_object_id, Balance, ZeroBalanceCoinPolicy, create_coin, create_typed_coin, deterministic_coin_id, deterministic_coin_id_from_str, deterministic_genesis_coin_id, coin_id_from_tx, create_coin_with_id};
 
 // Profile & Credential related
 pub use profile::{
@@ -86,6 +90,7 @@ pub use subnet::{
     SubnetId, SubnetType, SubnetConfig, UserSubnetMembership, CrossSubnetContext,
     // Subnet interaction tracking
     InteractionType, SubnetInteraction, LocalRelation, UserSubnetActivity,
+    user_subnet_activity_object_id,
 };
 
 // Merkle tree types
@@ -99,7 +104,7 @@ pub use merkle::{
 pub use account_view::AccountView;
 
 // Genesis config
-pub use genesis::{GenesisConfig, GenesisAccount, GenesisError};
+pub use genesis::{GenesisConfig, GenesisAccount, GenesisBalance, GenesisError, DEFAULT_MAX_OBJECT_BYTES};
 
 // Governance types
 pub use governance::{
@@ -116,7 +121,7 @@ pub use resource::{
 };
 
 // Envelope types
-pub use envelope::{ObjectEnvelope, EnvelopeMetadata, StorageFormat, detect_and_parse, ENVELOPE_MAGIC};
+pub use envelope::{ObjectEnvelope, EnvelopeMetadata, StorageFormat, detect_and_parse, ENVELOPE_MAGIC, ENVELOPE_DIGEST_DOMAIN};
 
 // Task types for Validator → Solver communication
 pub use task::{
@@ -124,7 +129,7 @@ pub use task::{
     ReadSetEntry, MerkleProof,
     Attestation, AttestationType, AttestationData,
     AttestationError, AttestationResult, VerifiedAttestation,
-    GasBudget, GasUsage,
+    GasBudget, GasUsage, FeeCalculator, FeeQuote,
 };
 
 // Error types
@@ -144,6 +149,17 @@ pub enum SetuError {
     #[error("Invalid transfer: {0}")]
     InvalidTransfer(String),
     
+    /// Backpressure: the node is at capacity; the caller should retry later
+    #[error("Overloaded: {0}")]
+    Overloaded(String),
+    
     #[error("Other error: {0}")]
     Other(String),
 }
+
+impl SetuError {
+    /// Whether the same request may succeed if retried later
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, SetuError::Overloaded(_))
+    }
+}
@@ -190,9 +190,45 @@ pub struct AnchorMerkleRoots {
     /// Root of the Anchor Chain Tree (append-only history)
     pub anchor_chain_root: HashValue,
     /// Individual subnet state roots (for parallel verification)
+    #[serde(with = "subnet_roots_serde")]
     pub subnet_roots: HashMap<SubnetId, HashValue>,
 }
 
+/// `subnet_roots` encoding
+///
+/// JSON object keys must be strings, so human-readable formats key the map
+/// by the hex subnet id. Binary formats keep the derived encoding, leaving
+/// BCS bytes (and anchor ids) unchanged.
+mod subnet_roots_serde {
+    use super::{HashMap, HashValue, SubnetId};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        roots: &HashMap<SubnetId, HashValue>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_map(roots.iter().map(|(id, root)| (hex::encode(id.as_bytes()), root)))
+        } else {
+            roots.serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<SubnetId, HashValue>, D::Error> {
+        if deserializer.is_human_readable() {
+            HashMap::<String, HashValue>::deserialize(deserializer)?
+                .into_iter()
+                .map(|(id, root)| SubnetId::from_hex(&id).map(|id| (id, root)).map_err(D::Error::custom))
+                .collect()
+        } else {
+            HashMap::deserialize(deserializer)
+        }
+    }
+}
+
 impl AnchorMerkleRoots {
     /// Create empty Merkle roots
     pub fn new() -> Self {
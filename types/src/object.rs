@@ -289,6 +289,10 @@ pub struct ObjectMetadata {
     pub ownership: Ownership,
     pub created_at: u64,
     pub updated_at: u64,
+    /// Set by the coin freeze authority; frozen coins cannot be transferred.
+    /// Mirrors `CoinState::frozen`.
+    #[serde(default)]
+    pub frozen: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -327,6 +331,7 @@ impl<T: Serialize + Clone> Object<T> {
                 ownership: Ownership::AddressOwner(owner),
                 created_at: timestamp,
                 updated_at: timestamp,
+                frozen: false,
             },
             data,
         };
@@ -352,6 +357,7 @@ impl<T: Serialize + Clone> Object<T> {
                 },
                 created_at: now,
                 updated_at: now,
+                frozen: false,
             },
             data,
         };
@@ -375,6 +381,7 @@ impl<T: Serialize + Clone> Object<T> {
                 ownership: Ownership::Immutable,
                 created_at: now,
                 updated_at: now,
+                frozen: false,
             },
             data,
         };
@@ -415,6 +422,9 @@ impl<T: Serialize + Clone> Object<T> {
     }
     
     /// Compute and update the object digest
+    ///
+    /// Hashes `id || version || bcs(data)` under `SETU_OBJ_DIGEST:`; the
+    /// envelope digest uses its own domain (`ENVELOPE_DIGEST_DOMAIN`).
     pub fn compute_digest(&mut self) {
         let mut hasher = blake3::Hasher::new();
         hasher.update(b"SETU_OBJ_DIGEST:");
@@ -29,10 +29,12 @@
 //! - AccountView stays lightweight and focused on owned objects
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt;
 
-use crate::object::Address;
+use crate::hash_utils::setu_hash_with_domain;
+use crate::object::{Address, ObjectId};
+use crate::relation::SubnetInteractionSummary;
 
 /// Subnet type classification
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -168,6 +170,18 @@ impl SubnetId {
         Ok(Self(arr))
     }
     
+    /// Resolve the subnet a coin type belongs to (1 subnet : 1 token binding).
+    ///
+    /// `"ROOT"` maps to `SubnetId::ROOT`; a 32-byte hex string is taken
+    /// verbatim; any other name is hashed with `from_str_id`.
+    pub fn from_coin_type(coin_type: &str) -> Self {
+        if coin_type == "ROOT" {
+            Self::ROOT
+        } else {
+            Self::from_hex(coin_type).unwrap_or_else(|_| Self::from_str_id(coin_type))
+        }
+    }
+    
     pub fn as_bytes(&self) -> &[u8; 32] {
         &self.0
     }
@@ -372,6 +386,8 @@ pub enum InteractionType {
     Follow,
     /// Endorsement/recommendation
     Endorse,
+    /// Committed coin transfer between two users
+    Transfer,
     /// Custom interaction type
     Custom(String),
 }
@@ -384,6 +400,7 @@ impl InteractionType {
             InteractionType::Collaborate => "collaborate",
             InteractionType::Follow => "follow",
             InteractionType::Endorse => "endorse",
+            InteractionType::Transfer => "transfer",
             InteractionType::Custom(s) => s.as_str(),
         }
     }
@@ -397,6 +414,7 @@ impl From<&str> for InteractionType {
             "collaborate" => InteractionType::Collaborate,
             "follow" => InteractionType::Follow,
             "endorse" => InteractionType::Endorse,
+            "transfer" => InteractionType::Transfer,
             other => InteractionType::Custom(other.to_string()),
         }
     }
@@ -424,10 +442,18 @@ impl SubnetInteraction {
             .unwrap()
             .as_millis() as u64;
         
+        Self::new_at(with_user, interaction_type, now)
+    }
+    
+    /// Create an interaction with an explicit timestamp.
+    ///
+    /// Use this on the execution path, where the timestamp must come from the
+    /// execution context so every validator derives the same state.
+    pub fn new_at(with_user: Address, interaction_type: InteractionType, timestamp: u64) -> Self {
         Self {
             with_user,
             interaction_type,
-            timestamp: now,
+            timestamp,
             metadata: None,
             event_id: None,
         }
@@ -511,6 +537,9 @@ pub struct UserSubnetActivity {
     pub unique_users_count: u64,
     /// Last activity timestamp
     pub last_activity: u64,
+    /// Interaction count per type, keyed by `InteractionType::as_str()`
+    #[serde(default)]
+    pub interaction_counts: BTreeMap<String, u64>,
 }
 
 impl UserSubnetActivity {
@@ -523,15 +552,21 @@ impl UserSubnetActivity {
             .unwrap()
             .as_millis() as u64;
         
+        Self::new_at(user, subnet_id, now)
+    }
+    
+    /// Create an empty activity record that starts at `timestamp`.
+    pub fn new_at(user: Address, subnet_id: SubnetId, timestamp: u64) -> Self {
         Self {
             user,
             subnet_id,
-            joined_at: now,
+            joined_at: timestamp,
             recent_interactions: Vec::new(),
             local_relations: Vec::new(),
             total_interaction_count: 0,
             unique_users_count: 0,
-            last_activity: now,
+            last_activity: timestamp,
+            interaction_counts: BTreeMap::new(),
         }
     }
     
@@ -550,10 +585,40 @@ impl UserSubnetActivity {
         if self.recent_interactions.len() >= Self::MAX_RECENT_INTERACTIONS {
             self.recent_interactions.remove(0);
         }
+        let timestamp = interaction.timestamp;
+        *self.interaction_counts
+            .entry(interaction.interaction_type.as_str().to_string())
+            .or_insert(0) += 1;
         self.recent_interactions.push(interaction);
         
         self.total_interaction_count += 1;
-        self.touch();
+        // Use the interaction's own timestamp so replaying the same
+        // interactions always yields the same record.
+        self.last_activity = self.last_activity.max(timestamp);
+    }
+    
+    /// Number of recorded interactions of the given type
+    pub fn interaction_count(&self, interaction_type: &InteractionType) -> u64 {
+        self.interaction_counts
+            .get(interaction_type.as_str())
+            .copied()
+            .unwrap_or(0)
+    }
+    
+    /// Condense this record into the summary kept on `UserRelationNetwork`
+    pub fn summary(&self) -> SubnetInteractionSummary {
+        let mut recent: Vec<Address> = Vec::new();
+        for interaction in &self.recent_interactions {
+            if !recent.contains(&interaction.with_user) {
+                recent.push(interaction.with_user);
+            }
+        }
+        SubnetInteractionSummary {
+            interaction_count: self.unique_users_count,
+            recent_interactions: recent,
+            last_interaction: self.last_activity,
+            total_events: self.total_interaction_count,
+        }
     }
     
     /// Add or update a local relation
@@ -613,6 +678,18 @@ impl UserSubnetActivity {
     }
 }
 
+/// Compute deterministic ObjectId for a user's `UserSubnetActivity` in a subnet.
+///
+/// Key: BLAKE3("SETU_SUBNET_ACTIVITY:" || canonical_address || subnet_id)
+/// Address is lowercased to ensure canonical form, as for `flux_state_object_id`.
+pub fn user_subnet_activity_object_id(address: &str, subnet_id: &SubnetId) -> ObjectId {
+    let canonical = address.to_ascii_lowercase();
+    let mut preimage = Vec::with_capacity(canonical.len() + 32);
+    preimage.extend_from_slice(canonical.as_bytes());
+    preimage.extend_from_slice(subnet_id.as_bytes());
+    ObjectId::new(setu_hash_with_domain(b"SETU_SUBNET_ACTIVITY:", &preimage))
+}
+
 /// Cross-subnet transaction marker
 /// 
 /// When a transaction involves multiple subnets, it needs special handling.
@@ -694,4 +771,37 @@ mod tests {
         assert!(!ctx2.is_single_subnet());
         assert!(ctx2.requires_2pc);
     }
+    
+    #[test]
+    fn test_subnet_activity_counts_by_type() {
+        let alice = Address::from_str_id("alice");
+        let bob = Address::from_str_id("bob");
+        let carol = Address::from_str_id("carol");
+        let gaming = SubnetId::from_str_id("gaming");
+        
+        let mut activity = UserSubnetActivity::new_at(alice, gaming, 1000);
+        activity.record_interaction(SubnetInteraction::new_at(bob, InteractionType::Transfer, 1100));
+        activity.record_interaction(SubnetInteraction::new_at(bob, InteractionType::Chat, 1200));
+        activity.record_interaction(SubnetInteraction::new_at(carol, InteractionType::Transfer, 1300));
+        
+        assert_eq!(activity.interaction_count(&InteractionType::Transfer), 2);
+        assert_eq!(activity.interaction_count(&InteractionType::Chat), 1);
+        assert_eq!(activity.interaction_count(&InteractionType::Trade), 0);
+        
+        let summary = activity.summary();
+        assert_eq!(summary.total_events, 3);
+        assert_eq!(summary.interaction_count, 2);
+        assert_eq!(summary.recent_interactions, vec![bob, carol]);
+        assert_eq!(summary.last_interaction, 1300);
+        
+        // Object id is per (user, subnet) and ignores address case
+        assert_eq!(
+            user_subnet_activity_object_id("0xABCD", &gaming),
+            user_subnet_activity_object_id("0xabcd", &gaming),
+        );
+        assert_ne!(
+            user_subnet_activity_object_id("0xabcd", &gaming),
+            user_subnet_activity_object_id("0xabcd", &SubnetId::ROOT),
+        );
+    }
 }
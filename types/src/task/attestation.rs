@@ -289,6 +289,26 @@ impl Attestation {
         self.attestation_data.as_ref().map(|d| &d.task_id)
     }
     
+    /// Check that this attestation was produced for `expected_task_id`
+    ///
+    /// The task_id is part of `user_data`, so the structured data must both
+    /// hash to `user_data` and carry the expected task_id. This stops an
+    /// attestation for one task being replayed for another task with an
+    /// identical state transition.
+    pub fn verify_task_binding(&self, expected_task_id: &[u8; 32]) -> AttestationResult<()> {
+        let data = self.attestation_data.as_ref().ok_or(AttestationError::TaskIdMismatch)?;
+        if !data.verify(&self.user_data) {
+            return Err(AttestationError::UserDataMismatch {
+                expected: hex::encode(data.to_user_data()),
+                actual: self.user_data_hex(),
+            });
+        }
+        if data.task_id != *expected_task_id {
+            return Err(AttestationError::TaskIdMismatch);
+        }
+        Ok(())
+    }
+    
     /// Set attestation data (for binding after creation)
     pub fn with_attestation_data(mut self, data: AttestationData) -> Self {
         self.attestation_data = Some(data);
@@ -331,4 +351,27 @@ mod tests {
         let att = Attestation::mock([0u8; 32]);
         assert!(att.is_mock());
     }
+    
+    #[test]
+    fn test_verify_task_binding() {
+        let data = AttestationData::new([1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]);
+        let att = Attestation::mock_with_data(data);
+        
+        assert!(att.verify_task_binding(&[1u8; 32]).is_ok());
+        assert!(matches!(
+            att.verify_task_binding(&[9u8; 32]),
+            Err(AttestationError::TaskIdMismatch)
+        ));
+        
+        // Swapping the structured task_id breaks the user_data binding
+        let mut swapped = att.clone();
+        swapped.attestation_data.as_mut().unwrap().task_id = [9u8; 32];
+        assert!(matches!(
+            swapped.verify_task_binding(&[9u8; 32]),
+            Err(AttestationError::UserDataMismatch { .. })
+        ));
+        
+        // Attestations without structured data cannot prove a binding
+        assert!(Attestation::mock([0u8; 32]).verify_task_binding(&[0u8; 32]).is_err());
+    }
 }
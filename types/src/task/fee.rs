@@ -0,0 +1,140 @@
+//! Deterministic transfer fee calculation.
+//!
+//! The transfer estimate and task preparation both price a transfer through
+//! [`FeeCalculator`], so a dry-run quote always matches the gas budget the
+//! executed task carries.
+
+use serde::{Deserialize, Serialize};
+
+use super::gas::GasBudget;
+use crate::resource::{ResourceGovernanceMode, ResourceParams};
+
+/// Gas units charged per input coin (each is read, proven and rewritten)
+pub const GAS_UNITS_PER_INPUT_COIN: u64 = 1;
+
+/// Basis-point denominator for `transfer_fee_rate_bps`
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Fee owed by a transfer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeQuote {
+    /// Total charged to the sender on top of the amount
+    pub fee: u64,
+    /// Part of `fee` removed from supply (the proportional transfer fee)
+    pub burn: u64,
+}
+
+/// Prices transfers from the governance `ResourceParams`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeCalculator {
+    /// Gas price, `None` while gas governance is not enabled
+    gas_price: Option<u64>,
+    transfer_fee_rate_bps: u32,
+}
+
+impl Default for FeeCalculator {
+    fn default() -> Self {
+        Self::new(&ResourceParams::default())
+    }
+}
+
+impl FeeCalculator {
+    pub fn new(params: &ResourceParams) -> Self {
+        Self {
+            gas_price: (params.gas_mode == ResourceGovernanceMode::Enabled)
+                .then_some(params.gas_base_price),
+            transfer_fee_rate_bps: params.transfer_fee_rate_bps,
+        }
+    }
+
+    /// Fee for moving `amount` out of `coin_count` input coins
+    ///
+    /// `fee = gas_price * coin_count * GAS_UNITS_PER_INPUT_COIN
+    ///      + amount * transfer_fee_rate_bps / 10_000`, of which the
+    /// proportional part is burned. Saturates instead of overflowing.
+    pub fn transfer_fee(&self, amount: u64, coin_count: usize) -> FeeQuote {
+        let gas_fee = Self::gas_units(coin_count).saturating_mul(self.gas_price.unwrap_or(0));
+        let burn = (amount as u128 * self.transfer_fee_rate_bps as u128 / BPS_DENOMINATOR)
+            .min(u64::MAX as u128) as u64;
+        FeeQuote {
+            fee: gas_fee.saturating_add(burn),
+            burn,
+        }
+    }
+
+    /// Gas budget carrying [`transfer_fee`](Self::transfer_fee) for a prepared task
+    ///
+    /// Gas is still unmetered, so `max_gas_units` stays unlimited.
+    pub fn transfer_gas_budget(&self, amount: u64, coin_count: usize) -> GasBudget {
+        let quote = self.transfer_fee(amount, coin_count);
+        GasBudget {
+            max_gas_units: u64::MAX,
+            gas_price: self.gas_price,
+            estimated_fee: quote.fee,
+            burned_fee: quote.burn,
+        }
+    }
+
+    fn gas_units(coin_count: usize) -> u64 {
+        (coin_count as u64).saturating_mul(GAS_UNITS_PER_INPUT_COIN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(gas_mode: ResourceGovernanceMode, gas_base_price: u64, rate_bps: u32) -> ResourceParams {
+        ResourceParams {
+            gas_mode,
+            gas_base_price,
+            transfer_fee_rate_bps: rate_bps,
+            ..ResourceParams::default()
+        }
+    }
+
+    #[test]
+    fn test_default_params_are_free() {
+        let calc = FeeCalculator::default();
+        assert_eq!(calc.transfer_fee(1_000_000, 5), FeeQuote::default());
+        assert_eq!(calc.transfer_gas_budget(1_000_000, 5).estimated_fee, GasBudget::default().estimated_fee);
+    }
+
+    #[test]
+    fn test_gas_charged_per_coin_only_when_enabled() {
+        let enabled = FeeCalculator::new(&params(ResourceGovernanceMode::Enabled, 7, 0));
+        assert_eq!(enabled.transfer_fee(100, 1), FeeQuote { fee: 7, burn: 0 });
+        assert_eq!(enabled.transfer_fee(100, 4), FeeQuote { fee: 28, burn: 0 });
+
+        let dry_run = FeeCalculator::new(&params(ResourceGovernanceMode::DryRun, 7, 0));
+        assert_eq!(dry_run.transfer_fee(100, 4).fee, 0);
+        assert_eq!(dry_run.transfer_gas_budget(100, 4).gas_price, None);
+    }
+
+    #[test]
+    fn test_rate_fee_is_burned() {
+        let calc = FeeCalculator::new(&params(ResourceGovernanceMode::Enabled, 2, 50));
+        // 0.5% of 10_000 = 50 burned, plus 2 * 3 gas
+        assert_eq!(calc.transfer_fee(10_000, 3), FeeQuote { fee: 56, burn: 50 });
+        // Rounds down
+        assert_eq!(calc.transfer_fee(199, 1), FeeQuote { fee: 2, burn: 0 });
+    }
+
+    #[test]
+    fn test_gas_budget_matches_quote() {
+        let calc = FeeCalculator::new(&params(ResourceGovernanceMode::Enabled, 3, 25));
+        for (amount, coins) in [(1, 1), (40_000, 2), (u64::MAX, 50)] {
+            let budget = calc.transfer_gas_budget(amount, coins);
+            assert_eq!(budget.fee_quote(), calc.transfer_fee(amount, coins));
+            assert_eq!(budget.gas_price, Some(3));
+        }
+    }
+
+    #[test]
+    fn test_extreme_inputs_saturate() {
+        let calc = FeeCalculator::new(&params(ResourceGovernanceMode::Enabled, u64::MAX, 10_000));
+        let quote = calc.transfer_fee(u64::MAX, usize::MAX);
+        assert_eq!(quote.burn, u64::MAX);
+        assert_eq!(quote.fee, u64::MAX);
+    }
+}
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::fee::FeeQuote;
+
 /// Gas budget for execution
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GasBudget {
@@ -13,6 +15,10 @@ pub struct GasBudget {
     
     /// Estimated total fee
     pub estimated_fee: u64,
+    
+    /// Part of `estimated_fee` burned instead of credited to the fee collector
+    #[serde(default)]
+    pub burned_fee: u64,
 }
 
 impl Default for GasBudget {
@@ -22,6 +28,7 @@ impl Default for GasBudget {
             max_gas_units: u64::MAX,
             gas_price: None,
             estimated_fee: 0,
+            burned_fee: 0,
         }
     }
 }
@@ -33,6 +40,15 @@ impl GasBudget {
             max_gas_units,
             gas_price: Some(gas_price),
             estimated_fee: max_gas_units.saturating_mul(gas_price),
+            burned_fee: 0,
+        }
+    }
+    
+    /// Transfer fee this budget charges, with its burned part
+    pub fn fee_quote(&self) -> FeeQuote {
+        FeeQuote {
+            fee: self.estimated_fee,
+            burn: self.burned_fee.min(self.estimated_fee),
         }
     }
     
@@ -8,6 +8,7 @@
 //! - `solver_task` - SolverTask and related types
 //! - `attestation` - TEE attestation types
 //! - `gas` - Gas budget and usage types
+//! - `fee` - Deterministic transfer fee calculation
 //!
 //! ## Design Goals
 //!
@@ -17,6 +18,7 @@
 mod solver_task;
 mod attestation;
 mod gas;
+mod fee;
 
 // Re-export all types
 pub use solver_task::{
@@ -28,3 +30,4 @@ pub use attestation::{
     AttestationError, AttestationResult, VerifiedAttestation,
 };
 pub use gas::{GasBudget, GasUsage};
+pub use fee::{FeeCalculator, FeeQuote, GAS_UNITS_PER_INPUT_COIN};
@@ -10,7 +10,7 @@
 //! - Supports multiple objects via `Vec<ResolvedObject>` for future MergeCoins/SplitCoin
 
 use serde::{Deserialize, Serialize};
-use crate::{Event, ObjectId, SubnetId};
+use crate::{Event, ObjectId, SubnetId, ZeroBalanceCoinPolicy};
 use crate::dynamic_field::DfAccessMode;
 use super::gas::GasBudget;
 
@@ -50,6 +50,16 @@ pub struct SolverTask {
     /// Empty for non-MoveCall operations.
     #[serde(default)]
     pub module_read_set: Vec<ReadSetEntry>,
+
+    /// Optional per-task execution deadline in milliseconds.
+    /// The enclave applies the smaller of this and its own
+    /// `max_execution_time_ms`; `None` means the enclave limit alone.
+    #[serde(default)]
+    pub execution_deadline_ms: Option<u64>,
+
+    /// What the enclave does with a sender coin the transfer drains to zero
+    #[serde(default)]
+    pub zero_balance_policy: ZeroBalanceCoinPolicy,
 }
 
 impl SolverTask {
@@ -70,6 +80,8 @@ impl SolverTask {
             subnet_id,
             gas_budget: GasBudget::default(),
             module_read_set: Vec::new(),
+            execution_deadline_ms: None,
+            zero_balance_policy: ZeroBalanceCoinPolicy::default(),
         }
     }
     
@@ -96,6 +108,12 @@ impl SolverTask {
         self.gas_budget = gas_budget;
         self
     }
+    
+    /// Set a per-task execution deadline (milliseconds)
+    pub fn with_execution_deadline(mut self, deadline_ms: u64) -> Self {
+        self.execution_deadline_ms = Some(deadline_ms);
+        self
+    }
 }
 
 /// Resolved input object references